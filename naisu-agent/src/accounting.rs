@@ -0,0 +1,321 @@
+//! Solver PnL accounting
+//!
+//! Every fulfillment is recorded with the capital it deployed, the gas it
+//! paid, the APY spread the solver kept (market APY minus offered APY) and
+//! any priority tip the user paid.
+//! The realized return is filled in later, once the position is settled.
+//! Records roll up into daily or weekly PnL periods, exportable as CSV.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate};
+use naisu_core::FailedAttempt;
+use serde::{Deserialize, Serialize};
+
+// ─── Records ─────────────────────────────────────────────────────────────────
+
+/// Accounting entry for one fulfillment (amounts in MIST / base units)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulfillmentRecord {
+    pub intent_id: String,
+    pub solver: String,
+    pub protocol: String,
+    pub digest: String,
+    /// Unix millis
+    pub timestamp_ms: u64,
+    /// Capital deployed into the position
+    pub capital: u64,
+    /// Net gas paid (may be negative after storage rebates)
+    pub gas_paid: i64,
+    /// APY offered to the user (bps)
+    pub offered_apy_bps: u64,
+    /// Market APY at fulfillment time (bps)
+    pub market_apy_bps: u64,
+    /// Priority tip paid by the user
+    #[serde(default)]
+    pub tip: u64,
+    /// Return realized once the position settled, if it has
+    #[serde(default)]
+    pub realized_return: Option<i64>,
+    /// Dry run from `--simulate`: nothing was executed, the figures are
+    /// projections
+    #[serde(default)]
+    pub simulated: bool,
+    /// Solvers that won first but failed, when this one took over
+    #[serde(default)]
+    pub failed_attempts: Vec<FailedAttempt>,
+}
+
+impl FulfillmentRecord {
+    /// APY spread captured by the solver (bps)
+    pub fn spread_bps(&self) -> u64 {
+        self.market_apy_bps.saturating_sub(self.offered_apy_bps)
+    }
+
+    /// Annualized return the spread is expected to yield
+    pub fn expected_annual_return(&self) -> u64 {
+        (self.capital as u128 * self.spread_bps() as u128 / 10_000) as u64
+    }
+
+    /// Realized return plus tip, minus gas
+    pub fn net_pnl(&self) -> i64 {
+        self.realized_return.unwrap_or(0) + self.tip as i64 - self.gas_paid
+    }
+}
+
+// ─── Reports ─────────────────────────────────────────────────────────────────
+
+/// Report bucket size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    #[default]
+    Daily,
+    /// ISO weeks, starting Monday
+    Weekly,
+}
+
+impl Granularity {
+    fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Granularity::Daily => date,
+            Granularity::Weekly => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Granularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "daily" | "day" => Ok(Granularity::Daily),
+            "weekly" | "week" => Ok(Granularity::Weekly),
+            _ => Err(format!("Unknown period: {}", s)),
+        }
+    }
+}
+
+/// Aggregated PnL for one period
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PnlPeriod {
+    /// First day of the period (YYYY-MM-DD, UTC)
+    pub period_start: String,
+    pub fulfillments: u64,
+    pub capital_deployed: u64,
+    pub gas_paid: i64,
+    pub expected_annual_return: u64,
+    /// Realized return of settled fulfillments
+    pub realized_return: i64,
+    /// Priority tips received
+    pub tips: u64,
+    /// Fulfillments still awaiting settlement
+    pub unsettled: u64,
+    pub net_pnl: i64,
+}
+
+/// PnL report for a solver
+#[derive(Debug, Clone, Serialize)]
+pub struct PnlReport {
+    pub solver: String,
+    pub granularity: Granularity,
+    pub periods: Vec<PnlPeriod>,
+    pub total: PnlPeriod,
+}
+
+impl PnlReport {
+    /// CSV with a header row and one row per period
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "period_start,fulfillments,capital_deployed,gas_paid,expected_annual_return,realized_return,tips,unsettled,net_pnl\n",
+        );
+        for p in &self.periods {
+            out.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{}\n",
+                p.period_start,
+                p.fulfillments,
+                p.capital_deployed,
+                p.gas_paid,
+                p.expected_annual_return,
+                p.realized_return,
+                p.tips,
+                p.unsettled,
+                p.net_pnl
+            ));
+        }
+        out
+    }
+}
+
+// ─── Ledger ──────────────────────────────────────────────────────────────────
+
+/// In-memory fulfillment ledger
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    records: Vec<FulfillmentRecord>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, record: FulfillmentRecord) {
+        self.records.push(record);
+    }
+
+    /// Set the realized return for a fulfillment, by transaction digest
+    pub fn settle(&mut self, digest: &str, realized_return: i64) -> bool {
+        match self.records.iter_mut().find(|r| r.digest == digest) {
+            Some(record) => {
+                record.realized_return = Some(realized_return);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn records(&self) -> &[FulfillmentRecord] {
+        &self.records
+    }
+
+    /// Records of one solver (case-insensitive name match)
+    pub fn records_for<'a>(
+        &'a self,
+        solver: &'a str,
+    ) -> impl Iterator<Item = &'a FulfillmentRecord> + 'a {
+        self.records
+            .iter()
+            .filter(move |r| r.solver.eq_ignore_ascii_case(solver))
+    }
+
+    /// Aggregate a solver's records into periods, oldest first
+    pub fn report(&self, solver: &str, granularity: Granularity) -> PnlReport {
+        let mut buckets: BTreeMap<NaiveDate, PnlPeriod> = BTreeMap::new();
+        let mut total = PnlPeriod {
+            period_start: "total".to_string(),
+            ..Default::default()
+        };
+
+        for record in self.records_for(solver) {
+            let date = DateTime::from_timestamp_millis(record.timestamp_ms as i64)
+                .map(|dt| dt.date_naive())
+                .unwrap_or_default();
+            let start = granularity.period_start(date);
+
+            let period = buckets.entry(start).or_insert_with(|| PnlPeriod {
+                period_start: start.format("%Y-%m-%d").to_string(),
+                ..Default::default()
+            });
+            accumulate(period, record);
+            accumulate(&mut total, record);
+        }
+
+        PnlReport {
+            solver: solver.to_string(),
+            granularity,
+            periods: buckets.into_values().collect(),
+            total,
+        }
+    }
+}
+
+fn accumulate(period: &mut PnlPeriod, record: &FulfillmentRecord) {
+    period.fulfillments += 1;
+    period.capital_deployed += record.capital;
+    period.gas_paid += record.gas_paid;
+    period.expected_annual_return += record.expected_annual_return();
+    period.tips += record.tip;
+    match record.realized_return {
+        Some(realized) => period.realized_return += realized,
+        None => period.unsettled += 1,
+    }
+    period.net_pnl += record.net_pnl();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-01 was a Monday
+    const MON: u64 = 1_704_067_200_000;
+    const DAY: u64 = 86_400_000;
+
+    fn record(digest: &str, timestamp_ms: u64) -> FulfillmentRecord {
+        FulfillmentRecord {
+            intent_id: format!("intent-{}", digest),
+            solver: "ScallopSolver".to_string(),
+            protocol: "scallop".to_string(),
+            digest: digest.to_string(),
+            timestamp_ms,
+            capital: 1_000_000_000,
+            gas_paid: 2_000_000,
+            offered_apy_bps: 820,
+            market_apy_bps: 850,
+            tip: 0,
+            realized_return: None,
+            simulated: false,
+            failed_attempts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_spread_and_expected_return() {
+        let r = record("a", MON);
+        assert_eq!(r.spread_bps(), 30);
+        assert_eq!(r.expected_annual_return(), 3_000_000);
+        assert_eq!(r.net_pnl(), -2_000_000);
+    }
+
+    #[test]
+    fn test_tips_count_toward_pnl() {
+        let mut ledger = Ledger::new();
+        ledger.record(FulfillmentRecord {
+            tip: 2_500_000,
+            ..record("a", MON)
+        });
+        ledger.record(record("b", MON));
+
+        let report = ledger.report("ScallopSolver", Granularity::Daily);
+        assert_eq!(report.total.tips, 2_500_000);
+        assert_eq!(report.total.net_pnl, -1_500_000);
+    }
+
+    #[test]
+    fn test_daily_and_weekly_report() {
+        let mut ledger = Ledger::new();
+        ledger.record(record("a", MON));
+        ledger.record(record("b", MON + DAY));
+        ledger.record(record("c", MON + 7 * DAY));
+        assert!(ledger.settle("a", 5_000_000));
+        assert!(!ledger.settle("missing", 1));
+
+        let daily = ledger.report("scallopsolver", Granularity::Daily);
+        assert_eq!(daily.periods.len(), 3);
+        assert_eq!(daily.periods[0].period_start, "2024-01-01");
+        assert_eq!(daily.periods[0].net_pnl, 3_000_000);
+        assert_eq!(daily.total.unsettled, 2);
+
+        let weekly = ledger.report("ScallopSolver", Granularity::Weekly);
+        assert_eq!(weekly.periods.len(), 2);
+        assert_eq!(weekly.periods[0].fulfillments, 2);
+        assert_eq!(weekly.periods[1].period_start, "2024-01-08");
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let mut ledger = Ledger::new();
+        ledger.record(record("a", MON));
+
+        let csv = ledger.report("ScallopSolver", Granularity::Daily).to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("period_start,"));
+        assert_eq!(
+            lines[1],
+            "2024-01-01,1,1000000000,2000000,3000000,0,0,1,-2000000"
+        );
+    }
+}
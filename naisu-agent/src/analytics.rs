@@ -0,0 +1,356 @@
+//! Intent analytics
+//!
+//! Marketplace statistics computed from intents and the fulfillments
+//! solvers reported for them: counts, volume, realized APY,
+//! time-to-fulfillment percentiles, protocol market share, protocol fees
+//! and a per-solver leaderboard. Everything is restricted to intents created inside a
+//! reporting window.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+const HOUR_MS: u64 = 3_600_000;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+/// Reporting window, counted back from now
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Window {
+    #[serde(rename = "1h")]
+    Hour,
+    #[serde(rename = "24h")]
+    Day,
+    #[serde(rename = "7d")]
+    Week,
+    #[serde(rename = "30d")]
+    Month,
+    #[default]
+    All,
+}
+
+impl Window {
+    /// Earliest creation time inside the window (unix millis)
+    pub fn since(&self, now_ms: u64) -> u64 {
+        let span = match self {
+            Window::Hour => HOUR_MS,
+            Window::Day => DAY_MS,
+            Window::Week => 7 * DAY_MS,
+            Window::Month => 30 * DAY_MS,
+            Window::All => return 0,
+        };
+        now_ms.saturating_sub(span)
+    }
+}
+
+impl FromStr for Window {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "1h" | "hour" => Ok(Window::Hour),
+            "24h" | "1d" | "day" => Ok(Window::Day),
+            "7d" | "week" => Ok(Window::Week),
+            "30d" | "month" => Ok(Window::Month),
+            "all" => Ok(Window::All),
+            _ => Err(format!("Unknown window: {} (1h, 24h, 7d, 30d, all)", s)),
+        }
+    }
+}
+
+/// Where an intent stands, for counting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleStatus {
+    Open,
+    Fulfilled,
+    /// Expired, cancelled, ...
+    Closed,
+}
+
+/// A solver's reported fulfillment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FulfillmentSample {
+    pub solver: String,
+    pub protocol: String,
+    pub apy_bps: u64,
+    /// Protocol fee paid (MIST)
+    pub fee: u64,
+    /// Unix millis
+    pub reported_at: u64,
+}
+
+/// One intent as input to the analytics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntentSample {
+    /// Unix millis
+    pub created_at: u64,
+    /// Base units (MIST)
+    pub amount: u64,
+    pub status: SampleStatus,
+    pub fulfillment: Option<FulfillmentSample>,
+}
+
+impl IntentSample {
+    /// Milliseconds from creation to the fulfillment report
+    fn time_to_fulfill(&self) -> Option<u64> {
+        let f = self.fulfillment.as_ref()?;
+        f.reported_at.checked_sub(self.created_at)
+    }
+}
+
+/// Time-to-fulfillment distribution (milliseconds)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Percentiles {
+    pub p50: Option<u64>,
+    pub p90: Option<u64>,
+    pub p99: Option<u64>,
+}
+
+impl Percentiles {
+    fn of(mut values: Vec<u64>) -> Self {
+        values.sort_unstable();
+        let pick = |p: usize| {
+            if values.is_empty() {
+                return None;
+            }
+            // Nearest rank
+            let rank = (p * values.len()).div_ceil(100).max(1);
+            values.get(rank - 1).copied()
+        };
+        Self {
+            p50: pick(50),
+            p90: pick(90),
+            p99: pick(99),
+        }
+    }
+}
+
+/// A protocol's share of fulfilled volume
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProtocolShare {
+    pub protocol: String,
+    pub fulfillments: u64,
+    pub volume: u64,
+    /// Fraction of fulfilled volume (0.0 - 1.0)
+    pub share: f64,
+}
+
+/// Marketplace statistics for a window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IntentAnalytics {
+    pub window: Window,
+    pub total_intents: u64,
+    pub open_intents: u64,
+    pub fulfilled_intents: u64,
+    /// Base units (MIST)
+    pub total_volume: u64,
+    /// Mean realized APY over reported fulfillments (bps)
+    pub avg_apy_bps: Option<f64>,
+    /// Protocol fees on reported fulfillments (MIST)
+    pub total_fees: u64,
+    pub time_to_fulfill_ms: Percentiles,
+    /// Largest share first
+    pub market_share: Vec<ProtocolShare>,
+}
+
+/// One solver's standing
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SolverStanding {
+    pub solver: String,
+    pub fulfillments: u64,
+    pub volume: u64,
+    /// Protocol fees paid (MIST)
+    pub fees: u64,
+    pub avg_apy_bps: f64,
+    pub median_time_to_fulfill_ms: Option<u64>,
+}
+
+fn in_window(samples: &[IntentSample], window: Window, now_ms: u64) -> Vec<&IntentSample> {
+    let since = window.since(now_ms);
+    samples.iter().filter(|s| s.created_at >= since).collect()
+}
+
+/// Compute statistics over the intents created inside `window`
+pub fn compute(samples: &[IntentSample], window: Window, now_ms: u64) -> IntentAnalytics {
+    let samples = in_window(samples, window, now_ms);
+    let count = |status: SampleStatus| samples.iter().filter(|s| s.status == status).count() as u64;
+
+    let reported: Vec<&IntentSample> = samples
+        .iter()
+        .copied()
+        .filter(|s| s.fulfillment.is_some())
+        .collect();
+    let avg_apy_bps = (!reported.is_empty()).then(|| {
+        reported
+            .iter()
+            .filter_map(|s| s.fulfillment.as_ref())
+            .map(|f| f.apy_bps as f64)
+            .sum::<f64>()
+            / reported.len() as f64
+    });
+
+    let mut by_protocol: HashMap<&str, (u64, u64)> = HashMap::new();
+    for sample in &reported {
+        let f = sample
+            .fulfillment
+            .as_ref()
+            .expect("filtered on fulfillment");
+        let entry = by_protocol.entry(f.protocol.as_str()).or_default();
+        entry.0 += 1;
+        entry.1 += sample.amount;
+    }
+    let fulfilled_volume: u64 = by_protocol.values().map(|(_, v)| v).sum();
+    let mut market_share: Vec<ProtocolShare> = by_protocol
+        .into_iter()
+        .map(|(protocol, (fulfillments, volume))| ProtocolShare {
+            protocol: protocol.to_string(),
+            fulfillments,
+            volume,
+            share: if fulfilled_volume == 0 {
+                0.0
+            } else {
+                volume as f64 / fulfilled_volume as f64
+            },
+        })
+        .collect();
+    market_share.sort_by(|a, b| {
+        b.volume
+            .cmp(&a.volume)
+            .then_with(|| a.protocol.cmp(&b.protocol))
+    });
+
+    IntentAnalytics {
+        window,
+        total_intents: samples.len() as u64,
+        open_intents: count(SampleStatus::Open),
+        fulfilled_intents: count(SampleStatus::Fulfilled),
+        total_volume: samples.iter().map(|s| s.amount).sum(),
+        avg_apy_bps,
+        total_fees: reported
+            .iter()
+            .filter_map(|s| s.fulfillment.as_ref())
+            .map(|f| f.fee)
+            .sum(),
+        time_to_fulfill_ms: Percentiles::of(
+            reported
+                .iter()
+                .filter_map(|s| s.time_to_fulfill())
+                .collect(),
+        ),
+        market_share,
+    }
+}
+
+/// Solvers ranked by fulfillments, then volume
+pub fn leaderboard(samples: &[IntentSample], window: Window, now_ms: u64) -> Vec<SolverStanding> {
+    let mut by_solver: HashMap<&str, Vec<&IntentSample>> = HashMap::new();
+    for sample in in_window(samples, window, now_ms) {
+        if let Some(f) = &sample.fulfillment {
+            by_solver.entry(f.solver.as_str()).or_default().push(sample);
+        }
+    }
+
+    let mut standings: Vec<SolverStanding> = by_solver
+        .into_iter()
+        .map(|(solver, samples)| {
+            let apy: u64 = samples
+                .iter()
+                .filter_map(|s| s.fulfillment.as_ref())
+                .map(|f| f.apy_bps)
+                .sum();
+            SolverStanding {
+                solver: solver.to_string(),
+                fulfillments: samples.len() as u64,
+                volume: samples.iter().map(|s| s.amount).sum(),
+                fees: samples
+                    .iter()
+                    .filter_map(|s| s.fulfillment.as_ref())
+                    .map(|f| f.fee)
+                    .sum(),
+                avg_apy_bps: apy as f64 / samples.len() as f64,
+                median_time_to_fulfill_ms: Percentiles::of(
+                    samples.iter().filter_map(|s| s.time_to_fulfill()).collect(),
+                )
+                .p50,
+            }
+        })
+        .collect();
+    standings.sort_by(|a, b| {
+        b.fulfillments
+            .cmp(&a.fulfillments)
+            .then(b.volume.cmp(&a.volume))
+            .then_with(|| a.solver.cmp(&b.solver))
+    });
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(
+        created_at: u64,
+        amount: u64,
+        fulfilled: Option<(&str, &str, u64, u64)>,
+    ) -> IntentSample {
+        IntentSample {
+            created_at,
+            amount,
+            status: if fulfilled.is_some() {
+                SampleStatus::Fulfilled
+            } else {
+                SampleStatus::Open
+            },
+            fulfillment: fulfilled.map(|(solver, protocol, apy_bps, after)| FulfillmentSample {
+                solver: solver.to_string(),
+                protocol: protocol.to_string(),
+                apy_bps,
+                fee: amount / 100,
+                reported_at: created_at + after,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_compute_stats() {
+        let now = 10 * DAY_MS;
+        let samples = vec![
+            sample(now - HOUR_MS, 300, Some(("A", "Scallop", 800, 1_000))),
+            sample(now - 2 * HOUR_MS, 100, Some(("B", "Navi", 700, 3_000))),
+            sample(now - 3 * HOUR_MS, 50, None),
+            sample(now - 9 * DAY_MS, 1_000, Some(("A", "Scallop", 900, 2_000))),
+        ];
+
+        let day = compute(&samples, Window::Day, now);
+        assert_eq!(day.total_intents, 3);
+        assert_eq!(day.open_intents, 1);
+        assert_eq!(day.fulfilled_intents, 2);
+        assert_eq!(day.total_volume, 450);
+        assert_eq!(day.avg_apy_bps, Some(750.0));
+        assert_eq!(day.total_fees, 4);
+        assert_eq!(day.time_to_fulfill_ms.p50, Some(1_000));
+        assert_eq!(day.time_to_fulfill_ms.p90, Some(3_000));
+        assert_eq!(day.market_share[0].protocol, "Scallop");
+        assert_eq!(day.market_share[0].share, 0.75);
+
+        let all = compute(&samples, Window::All, now);
+        assert_eq!(all.total_intents, 4);
+    }
+
+    #[test]
+    fn test_leaderboard_order() {
+        let now = DAY_MS;
+        let samples = vec![
+            sample(now - 10, 100, Some(("B", "Navi", 700, 5))),
+            sample(now - 20, 100, Some(("A", "Scallop", 800, 5))),
+            sample(now - 30, 100, Some(("A", "Scallop", 900, 5))),
+        ];
+        let board = leaderboard(&samples, Window::All, now);
+        assert_eq!(board[0].solver, "A");
+        assert_eq!(board[0].avg_apy_bps, 850.0);
+        assert_eq!(board[0].fees, 2);
+        assert_eq!(board[1].solver, "B");
+        assert_eq!("7d".parse::<Window>(), Ok(Window::Week));
+    }
+}
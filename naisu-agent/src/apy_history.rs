@@ -0,0 +1,211 @@
+//! APY history
+//!
+//! Periodic APY samples per protocol and asset, kept for a retention
+//! period. Protocols are sampled independently, so `compare` averages
+//! every series onto one time grid before returning it for charting.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::Serialize;
+
+use crate::analytics::Window;
+
+const MINUTE_MS: u64 = 60_000;
+const HOUR_MS: u64 = 60 * MINUTE_MS;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+/// Samples older than this are dropped (90 days)
+pub const DEFAULT_RETENTION_MS: u64 = 90 * DAY_MS;
+
+/// One APY reading
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ApySample {
+    /// Unix millis
+    pub timestamp: u64,
+    /// Percent
+    pub apy: f64,
+}
+
+/// A series on the comparison grid
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlignedSeries {
+    pub protocol: String,
+    pub asset: String,
+    /// Mean APY per bucket (percent), `None` where nothing was sampled
+    pub apy: Vec<Option<f64>>,
+}
+
+/// Series aligned on shared buckets
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Comparison {
+    pub bucket_ms: u64,
+    /// Bucket start times (unix millis)
+    pub timestamps: Vec<u64>,
+    pub series: Vec<AlignedSeries>,
+}
+
+/// Bucket width that keeps a window's chart at 24-168 points
+pub fn bucket_ms(window: Window) -> u64 {
+    match window {
+        Window::Hour => 5 * MINUTE_MS,
+        Window::Day => HOUR_MS,
+        Window::Week => 6 * HOUR_MS,
+        Window::Month | Window::All => DAY_MS,
+    }
+}
+
+/// Protocols are keyed lowercase, assets uppercase
+fn key(protocol: &str, asset: &str) -> (String, String) {
+    (protocol.to_lowercase(), asset.to_uppercase())
+}
+
+/// APY samples per (protocol, asset)
+#[derive(Debug, Clone)]
+pub struct ApyHistory {
+    series: BTreeMap<(String, String), VecDeque<ApySample>>,
+    retention_ms: u64,
+}
+
+impl Default for ApyHistory {
+    fn default() -> Self {
+        Self {
+            series: BTreeMap::new(),
+            retention_ms: DEFAULT_RETENTION_MS,
+        }
+    }
+}
+
+impl ApyHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_retention(mut self, retention_ms: u64) -> Self {
+        self.retention_ms = retention_ms;
+        self
+    }
+
+    /// Record `apy` (percent) at `timestamp` (unix millis)
+    pub fn record(&mut self, protocol: &str, asset: &str, apy: f64, timestamp: u64) {
+        let cutoff = timestamp.saturating_sub(self.retention_ms);
+        let samples = self.series.entry(key(protocol, asset)).or_default();
+        samples.push_back(ApySample { timestamp, apy });
+        while samples.front().is_some_and(|s| s.timestamp < cutoff) {
+            samples.pop_front();
+        }
+    }
+
+    /// Samples at or after `since`, oldest first
+    pub fn series(&self, protocol: &str, asset: &str, since: u64) -> Vec<ApySample> {
+        self.series
+            .get(&key(protocol, asset))
+            .map(|s| s.iter().filter(|s| s.timestamp >= since).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Every (protocol, asset) with samples
+    pub fn keys(&self) -> Vec<(String, String)> {
+        self.series.keys().cloned().collect()
+    }
+
+    /// Requested series over `window`, bucketed onto one grid
+    ///
+    /// Empty `protocols` or `assets` means all recorded ones. Requested
+    /// pairs with no samples are still returned, all `None`.
+    pub fn compare(
+        &self,
+        protocols: &[String],
+        assets: &[String],
+        window: Window,
+        now_ms: u64,
+    ) -> Comparison {
+        let bucket_ms = bucket_ms(window);
+        let keys: Vec<_> = if protocols.is_empty() || assets.is_empty() {
+            self.keys()
+                .into_iter()
+                .filter(|(p, a)| {
+                    (protocols.is_empty() || protocols.iter().any(|x| x.eq_ignore_ascii_case(p)))
+                        && (assets.is_empty() || assets.iter().any(|x| x.eq_ignore_ascii_case(a)))
+                })
+                .collect()
+        } else {
+            protocols
+                .iter()
+                .flat_map(|p| assets.iter().map(move |a| key(p, a)))
+                .collect()
+        };
+
+        let since = match window {
+            Window::All => keys
+                .iter()
+                .filter_map(|k| self.series.get(k)?.front().map(|s| s.timestamp))
+                .min()
+                .unwrap_or(now_ms),
+            window => window.since(now_ms),
+        };
+        let start = since - since % bucket_ms;
+        let buckets = ((now_ms.saturating_sub(start)) / bucket_ms + 1) as usize;
+        let timestamps = (0..buckets).map(|i| start + i as u64 * bucket_ms).collect();
+
+        let series = keys
+            .into_iter()
+            .map(|(protocol, asset)| {
+                let mut sums = vec![(0.0, 0u32); buckets];
+                for s in self.series(&protocol, &asset, start) {
+                    if let Some(bucket) = sums.get_mut(((s.timestamp - start) / bucket_ms) as usize)
+                    {
+                        bucket.0 += s.apy;
+                        bucket.1 += 1;
+                    }
+                }
+                AlignedSeries {
+                    apy: sums
+                        .into_iter()
+                        .map(|(sum, n)| (n > 0).then(|| sum / n as f64))
+                        .collect(),
+                    protocol,
+                    asset,
+                }
+            })
+            .collect();
+
+        Comparison {
+            bucket_ms,
+            timestamps,
+            series,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_aligns_series() {
+        let mut history = ApyHistory::new().with_retention(2 * DAY_MS);
+        let now = 10 * DAY_MS;
+        // Dropped once a newer sample puts it past retention
+        history.record("Navi", "USDC", 1.0, now - 3 * DAY_MS);
+        // Scallop sampled twice in one hour, Navi only in the last
+        history.record("Scallop", "usdc", 7.0, now - 23 * HOUR_MS);
+        history.record("Scallop", "USDC", 8.0, now - 23 * HOUR_MS + MINUTE_MS);
+        history.record("Navi", "USDC", 6.5, now);
+        assert_eq!(history.series("navi", "usdc", 0).len(), 1);
+
+        let protocols = vec![
+            "scallop".to_string(),
+            "navi".to_string(),
+            "cetus".to_string(),
+        ];
+        let comparison = history.compare(&protocols, &["USDC".to_string()], Window::Day, now);
+        assert_eq!(comparison.bucket_ms, HOUR_MS);
+        assert_eq!(comparison.timestamps.len(), 25);
+
+        let scallop = &comparison.series[0];
+        assert_eq!(scallop.apy[1], Some(7.5));
+        assert_eq!(scallop.apy[24], None);
+        assert_eq!(comparison.series[1].apy[24], Some(6.5));
+        assert!(comparison.series[2].apy.iter().all(Option::is_none));
+    }
+}
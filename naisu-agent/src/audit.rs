@@ -0,0 +1,224 @@
+//! Audit log
+//!
+//! Append-only record of state changes: intent status transitions, bids,
+//! network switches and admin actions, each with its actor and the state
+//! before and after. Every entry is also emitted as a tracing event with
+//! target `audit`. Entries are never edited; past the capacity the oldest
+//! are evicted.
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Entries kept before the oldest are evicted
+pub const DEFAULT_AUDIT_CAPACITY: usize = 10_000;
+
+/// Entries returned when a query sets no limit
+pub const DEFAULT_QUERY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// An intent was created or changed status
+    IntentStatus,
+    BidInserted,
+    BidReplaced,
+    NetworkSwitch,
+    /// Kill switch, config reload, strategy registry, recovery, payouts
+    Admin,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::IntentStatus => "intent_status",
+            AuditAction::BidInserted => "bid_inserted",
+            AuditAction::BidReplaced => "bid_replaced",
+            AuditAction::NetworkSwitch => "network_switch",
+            AuditAction::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "intent_status" => Ok(AuditAction::IntentStatus),
+            "bid_inserted" => Ok(AuditAction::BidInserted),
+            "bid_replaced" => Ok(AuditAction::BidReplaced),
+            "network_switch" => Ok(AuditAction::NetworkSwitch),
+            "admin" => Ok(AuditAction::Admin),
+            _ => Err(format!("Unknown audit action: {}", s)),
+        }
+    }
+}
+
+/// One recorded change
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEntry {
+    /// Position in the log, from 1
+    pub seq: u64,
+    /// Unix millis
+    pub timestamp: u64,
+    /// Wallet, solver name, `admin`, ...
+    pub actor: String,
+    pub action: AuditAction,
+    /// Intent ID, network or admin operation
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// Which entries a query returns
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditFilter {
+    pub action: Option<AuditAction>,
+    pub actor: Option<String>,
+    pub target: Option<String>,
+    /// Unix millis
+    pub since: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+impl AuditFilter {
+    fn matches(&self, entry: &AuditEntry) -> bool {
+        self.action.is_none_or(|a| a == entry.action)
+            && self.actor.as_ref().is_none_or(|a| *a == entry.actor)
+            && self.target.as_ref().is_none_or(|t| *t == entry.target)
+            && self.since.is_none_or(|s| entry.timestamp >= s)
+    }
+}
+
+#[derive(Debug)]
+pub struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    next_seq: u64,
+    capacity: usize,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            next_seq: 1,
+            capacity: DEFAULT_AUDIT_CAPACITY,
+        }
+    }
+}
+
+impl AuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Append an entry; returns its sequence number
+    pub fn record(
+        &mut self,
+        actor: &str,
+        action: AuditAction,
+        target: &str,
+        before: Option<Value>,
+        after: Option<Value>,
+        timestamp: u64,
+    ) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        tracing::info!(
+            target: "audit",
+            seq,
+            actor,
+            action = action.as_str(),
+            target_id = target,
+            before = ?before,
+            after = ?after,
+            "State changed"
+        );
+
+        self.entries.push_back(AuditEntry {
+            seq,
+            timestamp,
+            actor: actor.to_string(),
+            action,
+            target: target.to_string(),
+            before,
+            after,
+        });
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+        seq
+    }
+
+    /// Matching entries, newest first
+    pub fn query(&self, filter: &AuditFilter) -> Vec<AuditEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|e| filter.matches(e))
+            .take(filter.limit.unwrap_or(DEFAULT_QUERY_LIMIT))
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_record_and_query() {
+        let mut log = AuditLog::new().with_capacity(3);
+        log.record(
+            "0xuser",
+            AuditAction::IntentStatus,
+            "i-1",
+            None,
+            Some(json!({ "status": "pending" })),
+            10,
+        );
+        log.record("solver-a", AuditAction::BidInserted, "i-1", None, None, 20);
+        log.record("admin", AuditAction::Admin, "kill_switch", None, None, 30);
+        let seq = log.record("api", AuditAction::NetworkSwitch, "mainnet", None, None, 40);
+        assert_eq!(seq, 4);
+        // First entry evicted
+        assert_eq!(log.len(), 3);
+
+        let all = log.query(&AuditFilter::default());
+        assert_eq!(all.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![4, 3, 2]);
+
+        let bids = log.query(&AuditFilter {
+            target: Some("i-1".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(bids.len(), 1);
+        assert_eq!(bids[0].action, AuditAction::BidInserted);
+
+        let recent = log.query(&AuditFilter {
+            since: Some(30),
+            limit: Some(1),
+            ..Default::default()
+        });
+        assert_eq!(recent[0].action, AuditAction::NetworkSwitch);
+        assert_eq!("bid_replaced".parse(), Ok(AuditAction::BidReplaced));
+    }
+}
@@ -0,0 +1,252 @@
+//! Protocol availability prober
+//!
+//! `Protocol::is_available` is a static guess. The prober checks that a
+//! protocol's package and config objects actually exist on the network and
+//! caches the result, so solvers are only built for protocols that can be
+//! used and the API reports real availability.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use naisu_sui::{SuiClient, SuiClientError};
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::config::{Network, Protocol, SharedConfig};
+
+/// Probe outcome
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Availability {
+    Available,
+    /// Package or a config object is missing
+    Unavailable,
+    /// The probe couldn't reach the network
+    Unknown,
+}
+
+/// Result of probing one protocol
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeResult {
+    pub protocol: &'static str,
+    pub availability: Availability,
+    /// Unix millis
+    pub checked_at: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip)]
+    checked: Option<Instant>,
+}
+
+impl ProbeResult {
+    fn new(protocol: Protocol, availability: Availability, reason: Option<String>) -> Self {
+        Self {
+            protocol: protocol.name(),
+            availability,
+            checked_at: chrono::Utc::now().timestamp_millis() as u64,
+            reason,
+            checked: Some(Instant::now()),
+        }
+    }
+}
+
+/// Cached probe results for one network
+#[derive(Debug, Clone, Default)]
+pub struct AvailabilityCache {
+    inner: Arc<RwLock<HashMap<Protocol, ProbeResult>>>,
+}
+
+impl AvailabilityCache {
+    pub fn get(&self, protocol: Protocol) -> Option<ProbeResult> {
+        self.inner
+            .read()
+            .expect("availability lock poisoned")
+            .get(&protocol)
+            .cloned()
+    }
+
+    /// Probed availability, `None` if never probed or unknown
+    pub fn is_available(&self, protocol: Protocol) -> Option<bool> {
+        match self.get(protocol)?.availability {
+            Availability::Available => Some(true),
+            Availability::Unavailable => Some(false),
+            Availability::Unknown => None,
+        }
+    }
+
+    /// Known availability of every probed protocol
+    pub fn snapshot(&self) -> HashMap<Protocol, bool> {
+        self.inner
+            .read()
+            .expect("availability lock poisoned")
+            .iter()
+            .filter_map(|(p, r)| match r.availability {
+                Availability::Available => Some((*p, true)),
+                Availability::Unavailable => Some((*p, false)),
+                Availability::Unknown => None,
+            })
+            .collect()
+    }
+
+    /// Whether every entry is younger than `ttl` (false when empty)
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        let inner = self.inner.read().expect("availability lock poisoned");
+        !inner.is_empty()
+            && inner
+                .values()
+                .all(|r| r.checked.is_some_and(|at| at.elapsed() < ttl))
+    }
+
+    /// Store a result; an `Unknown` probe keeps a previous definite answer
+    fn store(&self, protocol: Protocol, result: ProbeResult) {
+        let mut inner = self.inner.write().expect("availability lock poisoned");
+        let keep_previous = result.availability == Availability::Unknown
+            && inner
+                .get(&protocol)
+                .is_some_and(|prev| prev.availability != Availability::Unknown);
+        if !keep_previous {
+            inner.insert(protocol, result);
+        }
+    }
+}
+
+/// Probes one network's protocols
+pub struct AvailabilityProber {
+    network: Network,
+    client: SuiClient,
+    config: SharedConfig,
+    cache: AvailabilityCache,
+    interval: Duration,
+}
+
+impl AvailabilityProber {
+    pub fn new(network: Network, client: SuiClient, config: SharedConfig) -> Self {
+        Self {
+            network,
+            client,
+            config,
+            cache: AvailabilityCache::default(),
+            interval: Duration::from_secs(
+                std::env::var("AVAILABILITY_PROBE_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(300),
+            ),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Handle to the results (stays valid after `run` is spawned)
+    pub fn cache(&self) -> AvailabilityCache {
+        self.cache.clone()
+    }
+
+    /// Check the protocol's package and config objects exist
+    pub async fn probe(&self, protocol: Protocol) -> ProbeResult {
+        let Some(config) = self.config.get().protocol_config(protocol, self.network) else {
+            return ProbeResult::new(
+                protocol,
+                Availability::Unavailable,
+                Some(format!("No config for {}", self.network.name())),
+            );
+        };
+
+        let ids = std::iter::once(config.package_id.clone())
+            .chain(config.config_objects.iter().map(|(_, id)| id.clone()));
+        for id in ids {
+            match self.client.get_object(&id).await {
+                Ok(_) => {}
+                Err(SuiClientError::ObjectNotFound(_)) => {
+                    return ProbeResult::new(
+                        protocol,
+                        Availability::Unavailable,
+                        Some(format!("Object {} not found", id)),
+                    );
+                }
+                Err(e) => {
+                    return ProbeResult::new(protocol, Availability::Unknown, Some(e.to_string()));
+                }
+            }
+        }
+
+        ProbeResult::new(protocol, Availability::Available, None)
+    }
+
+    /// Probe every protocol supported on the network and cache the results
+    pub async fn probe_all(&self) -> Vec<ProbeResult> {
+        let mut results = Vec::new();
+        for protocol in self.network.supported_protocols() {
+            let result = self.probe(protocol).await;
+            if let Some(reason) = &result.reason {
+                warn!(
+                    "⚠️  {} on {}: {:?} ({})",
+                    protocol.name(),
+                    self.network.name(),
+                    result.availability,
+                    reason
+                );
+            }
+            self.cache.store(protocol, result.clone());
+            results.push(result);
+        }
+        results
+    }
+
+    /// Re-probe if the cache is older than `ttl`
+    pub async fn refresh_if_stale(&self, ttl: Duration) {
+        if !self.cache.is_fresh(ttl) {
+            self.probe_all().await;
+        }
+    }
+
+    /// Re-probe forever
+    pub async fn run(self) {
+        info!(
+            "🩺 Availability prober: {} every {:?}",
+            self.network.name(),
+            self.interval
+        );
+        loop {
+            tokio::time::sleep(self.interval).await;
+            self.probe_all().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_keeps_previous_answer() {
+        let cache = AvailabilityCache::default();
+        assert!(!cache.is_fresh(Duration::from_secs(60)));
+
+        cache.store(
+            Protocol::Scallop,
+            ProbeResult::new(Protocol::Scallop, Availability::Available, None),
+        );
+        cache.store(
+            Protocol::Scallop,
+            ProbeResult::new(
+                Protocol::Scallop,
+                Availability::Unknown,
+                Some("timeout".to_string()),
+            ),
+        );
+        assert_eq!(cache.is_available(Protocol::Scallop), Some(true));
+
+        cache.store(
+            Protocol::Navi,
+            ProbeResult::new(Protocol::Navi, Availability::Unknown, None),
+        );
+        assert_eq!(cache.is_available(Protocol::Navi), None);
+        assert_eq!(cache.snapshot().len(), 1);
+        assert!(cache.is_fresh(Duration::from_secs(60)));
+    }
+}
@@ -0,0 +1,255 @@
+//! Solver wallet balance monitor
+//!
+//! Fulfillments fail (often only at gas selection) once the solver wallet
+//! runs dry. This task polls the SUI balance, alerts when it drops below a
+//! threshold, and on networks with a faucet requests more gas automatically.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use naisu_sui::SuiClient;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::config::Network;
+use crate::notify::{Alert, AlertLevel, NotificationHub};
+
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// Balance monitor settings
+#[derive(Debug, Clone)]
+pub struct BalanceMonitorConfig {
+    /// Solver wallet address
+    pub address: String,
+    pub network: Network,
+    /// Alert below this balance (MIST)
+    pub threshold: u64,
+    pub interval: Duration,
+    /// Request faucet funds when low (ignored without a faucet)
+    pub auto_refill: bool,
+    /// Minimum time between faucet requests (faucets rate-limit)
+    pub refill_cooldown: Duration,
+}
+
+impl BalanceMonitorConfig {
+    /// Load from `SOLVER_ADDRESS`, `LOW_BALANCE_THRESHOLD`,
+    /// `BALANCE_CHECK_INTERVAL_SECS` and `AUTO_REFILL` (default: on for testnet)
+    pub fn from_env(network: Network) -> Option<Self> {
+        let address = std::env::var("SOLVER_ADDRESS").ok()?;
+        Some(Self::for_address(address, network))
+    }
+
+    /// Monitor `address`, taking the remaining settings from env
+    pub fn for_address(address: impl Into<String>, network: Network) -> Self {
+        let address = address.into();
+        let parse = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok());
+
+        Self {
+            address,
+            network,
+            threshold: parse("LOW_BALANCE_THRESHOLD").unwrap_or(1_000_000_000),
+            interval: Duration::from_secs(parse("BALANCE_CHECK_INTERVAL_SECS").unwrap_or(60)),
+            auto_refill: std::env::var("AUTO_REFILL")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(network == Network::Testnet),
+            refill_cooldown: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// One faucet request
+#[derive(Debug, Clone, Serialize)]
+pub struct RefillRecord {
+    pub timestamp_ms: u64,
+    pub balance_before: u64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Shared view of the refill history
+pub type RefillHistory = Arc<Mutex<Vec<RefillRecord>>>;
+
+/// Polls the solver balance, alerting and refilling as needed
+pub struct BalanceMonitor {
+    config: BalanceMonitorConfig,
+    client: SuiClient,
+    notifier: NotificationHub,
+    http: reqwest::Client,
+    history: RefillHistory,
+    last_refill: Option<Instant>,
+    /// Whether a low-balance alert is outstanding (avoid repeating it every poll)
+    alerted: bool,
+}
+
+impl BalanceMonitor {
+    pub fn new(config: BalanceMonitorConfig, client: SuiClient, notifier: NotificationHub) -> Self {
+        Self {
+            config,
+            client,
+            notifier,
+            http: reqwest::Client::new(),
+            history: Arc::new(Mutex::new(Vec::new())),
+            last_refill: None,
+            alerted: false,
+        }
+    }
+
+    /// Handle to the refill history (stays valid after `run` is spawned)
+    pub fn history(&self) -> RefillHistory {
+        self.history.clone()
+    }
+
+    /// Current SUI balance of the solver wallet
+    pub async fn balance(&self) -> Result<u64> {
+        let coins = self
+            .client
+            .get_coins(&self.config.address, Some(SUI_COIN_TYPE))
+            .await?;
+        Ok(coins
+            .iter()
+            .map(|c| c.balance.parse::<u64>().unwrap_or(0))
+            .sum())
+    }
+
+    /// Run one check: alert when low, refill if allowed
+    pub async fn check_once(&mut self) -> Result<u64> {
+        let balance = self.balance().await?;
+
+        if balance >= self.config.threshold {
+            if self.alerted {
+                self.alerted = false;
+                self.notifier
+                    .send(Alert::new(
+                        AlertLevel::Info,
+                        "Solver balance recovered",
+                        format!("{} now holds {} MIST", self.config.address, balance),
+                    ))
+                    .await;
+            }
+            return Ok(balance);
+        }
+
+        if !self.alerted {
+            self.alerted = true;
+            self.notifier
+                .send(Alert::new(
+                    AlertLevel::Warning,
+                    "Solver balance low",
+                    format!(
+                        "{} holds {} MIST (threshold {})",
+                        self.config.address, balance, self.config.threshold
+                    ),
+                ))
+                .await;
+        }
+
+        if let Some(faucet) = self.config.network.faucet_url() {
+            if self.config.auto_refill
+                && refill_due(
+                    self.last_refill,
+                    Instant::now(),
+                    self.config.refill_cooldown,
+                )
+            {
+                self.refill(faucet, balance).await;
+            }
+        }
+
+        Ok(balance)
+    }
+
+    async fn refill(&mut self, faucet: &str, balance_before: u64) {
+        self.last_refill = Some(Instant::now());
+        info!("🚰 Requesting faucet funds for {}", self.config.address);
+
+        let result = self
+            .http
+            .post(faucet)
+            .json(&faucet_request(&self.config.address))
+            .send()
+            .await
+            .and_then(|r| r.error_for_status());
+
+        let record = RefillRecord {
+            timestamp_ms: chrono::Utc::now().timestamp_millis() as u64,
+            balance_before,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        };
+
+        let alert = match &record.error {
+            None => Alert::new(
+                AlertLevel::Info,
+                "Faucet refill requested",
+                format!("Requested testnet SUI for {}", self.config.address),
+            ),
+            Some(e) => Alert::new(
+                AlertLevel::Critical,
+                "Faucet refill failed",
+                format!("{}: {}", self.config.address, e),
+            ),
+        };
+
+        self.history
+            .lock()
+            .expect("refill history lock poisoned")
+            .push(record);
+        self.notifier.send(alert).await;
+    }
+
+    /// Poll forever
+    pub async fn run(mut self) {
+        info!(
+            "💰 Balance monitor: {} (threshold {} MIST, auto-refill {})",
+            self.config.address, self.config.threshold, self.config.auto_refill
+        );
+
+        loop {
+            if let Err(e) = self.check_once().await {
+                warn!("⚠️  Balance check failed: {}", e);
+            }
+            tokio::time::sleep(self.config.interval).await;
+        }
+    }
+}
+
+/// Faucet v2 request body
+fn faucet_request(recipient: &str) -> serde_json::Value {
+    serde_json::json!({ "FixedAmountRequest": { "recipient": recipient } })
+}
+
+fn refill_due(last: Option<Instant>, now: Instant, cooldown: Duration) -> bool {
+    match last {
+        Some(last) => now.duration_since(last) >= cooldown,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refill_cooldown() {
+        let now = Instant::now();
+        let cooldown = Duration::from_secs(60);
+
+        assert!(refill_due(None, now, cooldown));
+        assert!(!refill_due(Some(now), now, cooldown));
+        assert!(refill_due(Some(now), now + cooldown, cooldown));
+    }
+
+    #[test]
+    fn test_faucet_request_body() {
+        let body = faucet_request("0xabc");
+        assert_eq!(body["FixedAmountRequest"]["recipient"], "0xabc");
+    }
+
+    #[test]
+    fn test_only_testnet_has_faucet() {
+        assert!(Network::Testnet.faucet_url().is_some());
+        assert!(Network::Mainnet.faucet_url().is_none());
+    }
+}
@@ -0,0 +1,320 @@
+//! Batch fulfillment
+//!
+//! Small intents won by the same solver can share one PTB: the gas coin is
+//! split once into every leg's amount, then each leg is deposited and sent
+//! to its user. The solver pays for one transaction instead of one per
+//! intent.
+//!
+//! A PTB is all-or-nothing, so a batch that never reached the chain fills
+//! none of its intents and the daemon falls back to fulfilling them one by
+//! one. Once submitted, its legs are never re-run. A successful batch is
+//! split back into one outcome per intent (see `split_outcome`).
+
+use naisu_sui::{CreatedObject, FulfillmentOutcome, GasCostSummary};
+use serde::Deserialize;
+
+use crate::solver::IntentRequest;
+
+/// Default most intents per batch
+pub const DEFAULT_MAX_LEGS: usize = 8;
+
+/// Upper bound on legs (PTBs allow 1024 commands, a leg takes ~3)
+pub const MAX_LEGS: usize = 256;
+
+// ─── Config ──────────────────────────────────────────────────────────────────
+
+/// `[batching]` section of the agent config
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BatchConfig {
+    pub enabled: bool,
+    /// Most intents in one PTB
+    pub max_legs: usize,
+    /// Only intents up to this amount are batched (MIST, `None` = any)
+    pub max_amount: Option<u64>,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_legs: DEFAULT_MAX_LEGS,
+            max_amount: None,
+        }
+    }
+}
+
+impl BatchConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(2..=MAX_LEGS).contains(&self.max_legs) {
+            return Err(format!("max_legs must be between 2 and {}", MAX_LEGS));
+        }
+        Ok(())
+    }
+
+    /// Whether an intent of `amount` may join a batch
+    pub fn batchable(&self, amount: u64) -> bool {
+        self.enabled && self.max_amount.is_none_or(|max| amount <= max)
+    }
+
+    /// Group items into batches and single fulfillments
+    ///
+    /// `key` names the batch an item can join (its winning solver), or
+    /// `None` if it must be fulfilled alone. Arrival order is kept within
+    /// each batch; a group of one is fulfilled alone.
+    pub fn plan<T>(
+        &self,
+        items: Vec<T>,
+        key: impl Fn(&T) -> Option<String>,
+        amount: impl Fn(&T) -> u64,
+    ) -> BatchPlan<T> {
+        let mut groups: Vec<(String, Vec<T>)> = Vec::new();
+        let mut singles = Vec::new();
+
+        for item in items {
+            let key = key(&item).filter(|_| self.batchable(amount(&item)));
+            match key {
+                Some(key) => match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, group)) => group.push(item),
+                    None => groups.push((key, vec![item])),
+                },
+                None => singles.push(item),
+            }
+        }
+
+        let mut batches = Vec::new();
+        for (_, mut group) in groups {
+            while !group.is_empty() {
+                let rest = group.split_off(group.len().min(self.max_legs));
+                let chunk = std::mem::replace(&mut group, rest);
+                if chunk.len() == 1 {
+                    singles.extend(chunk);
+                } else {
+                    batches.push(chunk);
+                }
+            }
+        }
+
+        BatchPlan { batches, singles }
+    }
+}
+
+/// Result of `BatchConfig::plan`
+#[derive(Debug)]
+pub struct BatchPlan<T> {
+    /// Two or more items each, fulfilled in one PTB
+    pub batches: Vec<Vec<T>>,
+    pub singles: Vec<T>,
+}
+
+// ─── Legs ────────────────────────────────────────────────────────────────────
+
+/// One intent's part of a batch PTB
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchLeg {
+    pub intent_id: String,
+    /// Receives the leg's deposit
+    pub user: String,
+    /// MIST
+    pub amount: u64,
+}
+
+impl From<&IntentRequest> for BatchLeg {
+    fn from(intent: &IntentRequest) -> Self {
+        Self {
+            intent_id: intent.id.clone(),
+            user: intent.user.clone(),
+            amount: intent.amount,
+        }
+    }
+}
+
+/// Split a batch outcome into one outcome per leg, in leg order
+///
+/// Each leg gets the objects created for its user (shared out in order when
+/// several legs have the same user) and an even share of the gas, with the
+/// remainder on the first leg. All outcomes carry the batch digest.
+pub fn split_outcome(outcome: &FulfillmentOutcome, legs: &[BatchLeg]) -> Vec<FulfillmentOutcome> {
+    let n = legs.len().max(1) as u64;
+    let gas = &outcome.gas_used;
+    let share = |total: u64, i: usize| total / n + if i == 0 { total % n } else { 0 };
+
+    legs.iter()
+        .enumerate()
+        .map(|(i, leg)| {
+            // Position of this leg among the legs for the same user
+            let same_user: Vec<usize> = (0..legs.len())
+                .filter(|j| legs[*j].user == leg.user)
+                .collect();
+            let nth = same_user.iter().position(|j| *j == i).unwrap_or(0);
+            let owned: Vec<&CreatedObject> = outcome.created_for(&leg.user);
+            let per_leg = owned.len().div_ceil(same_user.len());
+
+            FulfillmentOutcome {
+                digest: outcome.digest.clone(),
+                created_objects: owned
+                    .into_iter()
+                    .skip(nth * per_leg)
+                    .take(per_leg)
+                    .cloned()
+                    .collect(),
+                gas_used: GasCostSummary {
+                    computation_cost: share(gas.computation_cost, i),
+                    storage_cost: share(gas.storage_cost, i),
+                    storage_rebate: share(gas.storage_rebate, i),
+                    non_refundable_storage_fee: share(gas.non_refundable_storage_fee, i),
+                },
+                status: outcome.status.clone(),
+            }
+        })
+        .collect()
+}
+
+/// One outcome per leg from what a landed batch returned
+///
+/// A count mismatch still means every leg landed in the batch transaction,
+/// so each leg gets the batch digest (without per-leg objects or gas).
+/// `None` when there is no outcome to take the digest from.
+pub fn align_outcomes(
+    outcomes: Vec<FulfillmentOutcome>,
+    legs: usize,
+) -> Option<Vec<FulfillmentOutcome>> {
+    if outcomes.len() == legs {
+        return Some(outcomes);
+    }
+    let first = outcomes.first()?;
+    let shared = FulfillmentOutcome {
+        digest: first.digest.clone(),
+        created_objects: vec![],
+        gas_used: GasCostSummary::default(),
+        status: first.status.clone(),
+    };
+    Some(vec![shared; legs])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_sui::{ExecutionStatus, Owner};
+
+    fn leg(id: &str, user: &str, amount: u64) -> BatchLeg {
+        BatchLeg {
+            intent_id: id.to_string(),
+            user: user.to_string(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_plan_groups_by_solver() {
+        let config = BatchConfig {
+            enabled: true,
+            max_legs: 2,
+            max_amount: Some(10),
+        };
+        // (solver, amount)
+        let won = vec![
+            ("staking", 1),
+            ("scallop", 2),
+            ("staking", 3),
+            ("staking", 50), // Too large to batch
+            ("staking", 4),
+            ("cetus", 5),
+        ];
+        let plan = config.plan(
+            won,
+            |(solver, _)| (*solver != "cetus").then(|| solver.to_string()),
+            |(_, amount)| *amount,
+        );
+
+        assert_eq!(plan.batches, vec![vec![("staking", 1), ("staking", 3)]]);
+        assert_eq!(
+            plan.singles,
+            vec![
+                ("staking", 50),
+                ("cetus", 5),
+                ("staking", 4),
+                ("scallop", 2)
+            ]
+        );
+
+        let disabled = BatchConfig::default().plan(vec![1, 2], |_| Some("s".into()), |a| *a);
+        assert!(disabled.batches.is_empty());
+        assert_eq!(
+            BatchConfig {
+                max_legs: 1,
+                ..Default::default()
+            }
+            .validate(),
+            Err("max_legs must be between 2 and 256".to_string())
+        );
+    }
+
+    #[test]
+    fn test_split_outcome_attributes_legs() {
+        let created = |id: &str, user: &str| CreatedObject {
+            object_id: id.to_string(),
+            object_type: "0x3::staking_pool::StakedSui".to_string(),
+            owner: Owner::AddressOwner(user.to_string()),
+            version: 1,
+        };
+        let outcome = FulfillmentOutcome {
+            digest: "0xdigest".to_string(),
+            created_objects: vec![
+                created("0xa1", "0xalice"),
+                created("0xb1", "0xbob"),
+                created("0xa2", "0xalice"),
+                created("0xchange", "0xsolver"),
+            ],
+            gas_used: GasCostSummary {
+                computation_cost: 3_001,
+                storage_cost: 900,
+                storage_rebate: 300,
+                non_refundable_storage_fee: 0,
+            },
+            status: ExecutionStatus::Success,
+        };
+        let legs = [
+            leg("i1", "0xalice", 10),
+            leg("i2", "0xbob", 20),
+            leg("i3", "0xalice", 30),
+        ];
+
+        let split = split_outcome(&outcome, &legs);
+        let ids: Vec<Vec<&str>> = split
+            .iter()
+            .map(|o| {
+                o.created_objects
+                    .iter()
+                    .map(|c| c.object_id.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(ids, vec![vec!["0xa1"], vec!["0xb1"], vec!["0xa2"]]);
+        assert!(split.iter().all(|o| o.digest == "0xdigest"));
+
+        assert_eq!(split[0].gas_used.computation_cost, 1_001);
+        assert_eq!(split[1].gas_used.computation_cost, 1_000);
+        let total: i64 = split.iter().map(|o| o.gas_used.net()).sum();
+        assert_eq!(total, outcome.gas_used.net());
+    }
+
+    #[test]
+    fn test_align_outcomes_keeps_batch_digest() {
+        let outcome = |digest: &str| FulfillmentOutcome {
+            digest: digest.to_string(),
+            created_objects: vec![],
+            gas_used: GasCostSummary::default(),
+            status: ExecutionStatus::Success,
+        };
+
+        let aligned = align_outcomes(vec![outcome("0xbatch")], 3).unwrap();
+        assert_eq!(aligned.len(), 3);
+        assert!(aligned.iter().all(|o| o.digest == "0xbatch"));
+
+        let matched = align_outcomes(vec![outcome("0xa"), outcome("0xb")], 2).unwrap();
+        assert_eq!(matched[1].digest, "0xb");
+
+        assert!(align_outcomes(vec![], 2).is_none());
+    }
+}
@@ -0,0 +1,346 @@
+//! Naisu Demo - End-to-end testnet flow
+//!
+//! Scripts one intent through the whole pipeline on testnet:
+//! 1. Creates an on-chain intent from the active `sui` CLI wallet
+//! 2. Runs the auction among the testnet solvers
+//! 3. Fulfills it through the StakingSolver
+//! 4. Verifies the StakedSui the user received
+//! 5. Cancels the intent to reclaim its locked coin
+//!
+//! and prints a status timeline. Any failed step exits non-zero, so the
+//! binary doubles as an integration test against testnet.
+//!
+//! Native staking fulfills from the active wallet and leaves the StakedSui
+//! with the sender, so the demo wallet plays both user and solver. It needs
+//! about twice the intent amount plus gas.
+//!
+//! Run: cargo run -p naisu-agent --bin naisu-demo -- --amount 1
+//!
+//! Flags: `--amount <SUI>` (default 1, the minimum stake), `--min-apy <bps>`
+//! (default 500), `--deadline <secs>` (default 600), `--config <file.toml>`,
+//! `--keep-open` to leave the intent open afterwards.
+
+use std::env;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context};
+use dotenvy::dotenv;
+use naisu_agent::config::{AgentConfig, Network, Protocol};
+use naisu_agent::executor::real_executor::{
+    execute_create_intent, execute_user_cancel, CreateIntentParams,
+};
+use naisu_agent::notify::NotificationHub;
+use naisu_agent::solver::{Bid, IntentRequest};
+use naisu_agent::solver_factory::SolverFactory;
+use naisu_agent::verify::{Expected, SettlementVerifier, VerificationStatus};
+use naisu_core::{format_sui, FulfilledObject, FulfillmentReport};
+use naisu_sui::{
+    FulfillmentOutcome, IntentObject, IntentPackage, SuiClient, SuiConfig, YieldIntentObject,
+};
+use tracing::{info, warn};
+
+/// Solver the demo fulfills through
+const DEMO_SOLVER: &str = "StakingSolver";
+
+/// CLI Arguments
+#[derive(Debug)]
+struct Args {
+    /// MIST
+    amount: u64,
+    min_apy: u64,
+    deadline_secs: u64,
+    config: AgentConfig,
+    keep_open: bool,
+}
+
+impl Args {
+    fn parse() -> anyhow::Result<Self> {
+        let args: Vec<String> = env::args().collect();
+        let value = |flag: &str| {
+            args.iter()
+                .position(|a| a == flag)
+                .and_then(|i| args.get(i + 1))
+        };
+
+        let amount = match value("--amount") {
+            Some(sui) => {
+                let sui: f64 = sui.parse().context("--amount takes SUI, e.g. 1.5")?;
+                (sui * 1e9).round() as u64
+            }
+            None => 1_000_000_000,
+        };
+        let min_apy = match value("--min-apy") {
+            Some(bps) => bps.parse().context("--min-apy takes basis points")?,
+            None => 500,
+        };
+        let deadline_secs = match value("--deadline") {
+            Some(secs) => secs.parse().context("--deadline takes seconds")?,
+            None => 600,
+        };
+
+        let config = AgentConfig::load(value("--config").map(Path::new))?;
+        config.validate()?;
+
+        Ok(Self {
+            amount,
+            min_apy,
+            deadline_secs,
+            config,
+            keep_open: args.iter().any(|a| a == "--keep-open"),
+        })
+    }
+}
+
+// ─── Timeline ────────────────────────────────────────────────────────────────
+
+/// Steps of the run, with the time each completed
+struct Timeline {
+    started: Instant,
+    steps: Vec<(Duration, &'static str, String)>,
+}
+
+impl Timeline {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            steps: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, status: &'static str, detail: impl Into<String>) {
+        let detail = detail.into();
+        info!("✅ {}: {}", status, detail);
+        self.steps.push((self.started.elapsed(), status, detail));
+    }
+
+    fn print(&self) {
+        println!("\n📜 Status timeline");
+        for (elapsed, status, detail) in &self.steps {
+            println!(
+                "  +{:>6.1}s  {:<12} {}",
+                elapsed.as_secs_f64(),
+                status,
+                detail
+            );
+        }
+    }
+}
+
+// ─── Flow ────────────────────────────────────────────────────────────────────
+
+fn testnet_client(config: &AgentConfig) -> SuiClient {
+    let mut sui_config = SuiConfig::testnet();
+    sui_config.rpc_url = config.rpc_url(Network::Testnet);
+    SuiClient::new(sui_config)
+}
+
+/// Read the new intent back, waiting for the node to index it
+async fn read_intent(client: &SuiClient, intent_id: &str) -> anyhow::Result<YieldIntentObject> {
+    let mut attempt = 1;
+    loop {
+        match client.get_yield_intent(intent_id).await {
+            Ok(intent) => return Ok(intent),
+            Err(e) if attempt >= 5 => return Err(e.into()),
+            Err(e) => {
+                warn!(
+                    "⚠️  Intent {} not readable yet ({}), retrying",
+                    intent_id, e
+                );
+                attempt += 1;
+                tokio::time::sleep(Duration::from_secs(2)).await;
+            }
+        }
+    }
+}
+
+fn ensure_success(outcome: &FulfillmentOutcome, label: &str) -> anyhow::Result<()> {
+    if outcome.is_success() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} transaction {} failed: {:?}",
+            label,
+            outcome.digest,
+            outcome.status
+        ))
+    }
+}
+
+async fn run(args: &Args, timeline: &mut Timeline) -> anyhow::Result<()> {
+    let network = Network::Testnet;
+    let explorer = network.explorer();
+    let tx_link = |digest: &str| match &explorer {
+        Some(explorer) => explorer.tx(digest),
+        None => digest.to_string(),
+    };
+    let package = IntentPackage::new(&args.config.intent_package(network)?);
+    let client = testnet_client(&args.config);
+
+    // 1. Create the intent
+    let created = execute_create_intent(CreateIntentParams {
+        package: package.clone(),
+        amount: args.amount,
+        min_apy: args.min_apy,
+        deadline_secs: args.deadline_secs,
+        target_protocol: Protocol::NativeStaking.name().to_string(),
+    })
+    .await?;
+    ensure_success(&created, "Create intent")?;
+    let intent_id = created
+        .created_of_type("::intent::YieldIntent")
+        .map(|o| o.object_id.clone())
+        .ok_or_else(|| anyhow!("{} created no YieldIntent", created.digest))?;
+    timeline.record(
+        "created",
+        format!("{} ({})", intent_id, tx_link(&created.digest)),
+    );
+
+    let on_chain = read_intent(&client, &intent_id).await?;
+    if !on_chain.is_open() {
+        return Err(anyhow!("Intent {} is not open", intent_id));
+    }
+    timeline.record(
+        "open",
+        format!(
+            "{} SUI from {}, min {} bps",
+            format_sui(on_chain.amount),
+            on_chain.user,
+            on_chain.min_apy
+        ),
+    );
+    let intent = IntentRequest {
+        id: on_chain.object_id.clone(),
+        user: on_chain.user.clone(),
+        amount: on_chain.amount,
+        min_apy: on_chain.min_apy,
+        deadline: on_chain.deadline,
+        allowed_protocols: Some(vec![Protocol::NativeStaking]),
+        tip: None,
+        max_risk: None,
+        stable_only: false,
+    };
+
+    // 2. Auction
+    let solvers = SolverFactory::with_config(network, args.config.clone()).create_solvers();
+    let mut bids: Vec<Bid> = Vec::new();
+    for solver in &solvers {
+        if let Some(bid) = solver.evaluate(&intent, 0.08).await {
+            info!("📊 {} bid: {} bps", solver.name(), bid.apy);
+            bids.push(bid);
+        }
+    }
+    let bid_count = bids.len();
+    let winner = args
+        .config
+        .selection
+        .select(bids, intent.min_apy)
+        .ok_or_else(|| anyhow!("No solver bid on intent {}", intent.id))?;
+    if winner.solver_name != DEMO_SOLVER {
+        return Err(anyhow!(
+            "{} won the auction, expected {}",
+            winner.solver_name,
+            DEMO_SOLVER
+        ));
+    }
+    timeline.record(
+        "auctioned",
+        format!(
+            "{} won at {} bps ({} bid(s))",
+            winner.solver_name, winner.apy, bid_count
+        ),
+    );
+
+    // 3. Fulfill
+    let solver = solvers
+        .iter()
+        .find(|s| s.name() == DEMO_SOLVER)
+        .ok_or_else(|| anyhow!("{} is not running on testnet", DEMO_SOLVER))?;
+    let outcome = solver.fulfill(&intent).await?;
+    ensure_success(&outcome, "Fulfillment")?;
+    timeline.record("fulfilled", tx_link(&outcome.digest));
+
+    // 4. Verify the StakedSui
+    let report = FulfillmentReport {
+        digest: outcome.digest.clone(),
+        solver: winner.solver_name.clone(),
+        protocol: Protocol::NativeStaking.name().to_string(),
+        realized_apy_bps: winner.apy,
+        created_objects: outcome
+            .created_objects
+            .iter()
+            .map(|o| FulfilledObject {
+                object_id: o.object_id.clone(),
+                object_type: o.object_type.clone(),
+            })
+            .collect(),
+        reported_at: 0,
+        user: Some(intent.user.clone()),
+        amount: Some(intent.amount),
+        fee: None,
+        gas_used: Some(outcome.gas_used.net()),
+        failed_attempts: Vec::new(),
+    };
+    let verifier = SettlementVerifier::new(testnet_client(&args.config), NotificationHub::new());
+    let verification = verifier
+        .verify(
+            &intent.id,
+            &report,
+            &Expected {
+                user: intent.user.clone(),
+                amount: intent.amount,
+            },
+        )
+        .await;
+    if verification.status != VerificationStatus::Verified {
+        return Err(anyhow!(
+            "Verification {:?}: {}",
+            verification.status,
+            verification.problems.join("; ")
+        ));
+    }
+    timeline.record(
+        "verified",
+        format!(
+            "StakedSui {} owned by {}",
+            verification.object_id.as_deref().unwrap_or("?"),
+            intent.user
+        ),
+    );
+
+    // 5. Reclaim the locked coin (staking doesn't consume the intent)
+    if args.keep_open {
+        return Ok(());
+    }
+    let cancelled = execute_user_cancel(
+        &package,
+        IntentObject::new(&intent.id, 1, &on_chain.coin_type),
+    )
+    .await?;
+    ensure_success(&cancelled, "Cancel intent")?;
+    timeline.record("reclaimed", tx_link(&cancelled.digest));
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let args = Args::parse()?;
+    info!("🎬 Naisu testnet demo");
+    info!(
+        "   Amount: {} SUI, min APY: {} bps, deadline: {}s",
+        format_sui(args.amount),
+        args.min_apy,
+        args.deadline_secs
+    );
+
+    let mut timeline = Timeline::new();
+    let result = run(&args, &mut timeline).await;
+    if let Err(e) = &result {
+        timeline.record("failed", e.to_string());
+    }
+    timeline.print();
+    result
+}
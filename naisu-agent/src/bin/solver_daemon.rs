@@ -3,175 +3,907 @@
 //! This daemon:
 //! 1. Polls for YieldIntent shared objects on testnet/mainnet
 //! 2. Evaluates intents with appropriate solvers for the network
-//! 3. Executes winning PTB to fulfill intents
+//! 3. Executes winning PTBs to fulfill intents, several intents at a time
+//!
+//! Intents fulfilled or cancelled on chain (by anyone) are tracked from their
+//! events; the daemon stops working on an intent as soon as it sees it close.
+//!
+//! Each solver has a cap on fulfillments in flight, and a network's wallet
+//! submits one transaction at a time with a pause in between (`[throttle]`,
+//! see `Throttle`), so bursts don't trip equivocation or locked objects.
 //!
 //! Run: cargo run -p naisu-agent --bin solver-daemon -- --network testnet
 //!
+//! `--network` also takes `devnet`, `localnet`, or a full node URL for a
+//! custom network (with `--explorer-url <url>` for transaction links).
+//!
+//! Multi-network mode: `--network testnet,mainnet` (or `--network all`) runs
+//! one independent pipeline per network in this process, each with its own
+//! solvers, event cursors, wallet (`[networks.<net>] wallet`, which its
+//! solvers send fulfillments from), risk state and metrics. A failing pipeline
+//! stops alone; Ctrl+C drains and stops all of them.
+//!
+//! Protocol configs reload without a restart: on SIGHUP the config file is
+//! re-read, and the upgrade watcher applies on-chain package upgrades. Each
+//! pipeline rebuilds its solvers when the shared config changes.
+//!
+//! Every eligible bid and the picked winner are posted to the API's solver
+//! endpoints as the auction runs, for the frontend's live view.
+//!
+//! Solver tuning set through the admin API (`SolverOverrides`) is polled
+//! alongside the kill switch and layered over the shared config the same way,
+//! as are feature flags (`FeatureOverrides`). Mainnet intents are only
+//! evaluated until `mainnet_fulfillment` is on.
+//!
+//! With `NAISU_API_URL` set, each pipeline sends a heartbeat every
+//! `[heartbeat] interval_secs` (solver states, wallet balance, last
+//! fulfillment) so the API can tell live solvers from dead ones.
+//!
+//! With `[gas_pool]` enabled, fulfillments pay gas from a pool of dedicated
+//! coins kept topped up in the background, never from principal.
+//!
+//! Config: `--config <file.toml>` (see `agent.example.toml` and `AgentConfig`),
+//! layered as defaults → file → env → CLI flags.
+//!
+//! Simulation: `--simulate` dry-runs every fulfillment PTB instead of
+//! executing it. Dry runs are recorded as simulated fulfillments with their
+//! projected gas and returns, counted apart from real ones in the metrics,
+//! and never reported to the API.
+//!
+//! Polling flags: `--poll-interval`, `--poll-jitter`,
+//! `--max-backoff`, `--fast-poll-interval`, `--fast-poll-count` (see `PollingConfig`)
+//!
 //! # Network Routes
 //! - Testnet: StakingSolver, DeepBookSolver (when implemented)
 //! - Mainnet: CetusSolver, ScallopSolver, NaviSolver, StakingSolver, DeepBookSolver
 
-use naisu_agent::bots::{CetusSolver, DeepBookSolver, NaviSolver, ScallopSolver, StakingSolver};
-use naisu_agent::config::Network;
-use naisu_agent::solver::{select_winner, Bid, IntentRequest, Solver};
-use std::collections::HashSet;
+use naisu_agent::accounting::{FulfillmentRecord, Ledger};
+use naisu_agent::availability::{AvailabilityCache, AvailabilityProber};
+use naisu_agent::balance_monitor::{BalanceMonitor, BalanceMonitorConfig};
+use naisu_agent::batch::{align_outcomes, BatchConfig};
+use naisu_agent::config::{AgentConfig, MvrClient, Network, Protocol, SharedConfig};
+use naisu_agent::events::{EventParser, IntentEvent};
+use naisu_agent::executor::gas_pool::GasPoolRebalancer;
+use naisu_agent::executor::multisig::multisig_from_env;
+use naisu_agent::executor::real_executor::{
+    is_simulating, register_executor, set_simulate, SOLVER_ADDRESS,
+};
+use naisu_agent::executor::signer::signer_from_env;
+use naisu_agent::executor::SuiExecutor;
+use naisu_agent::feature_flags::FeatureOverrides;
+use naisu_agent::gas::GasOracle;
+use naisu_agent::heartbeat::{self, Heartbeat, SolverState, SolverStatus};
+use naisu_agent::metrics::NetworkMetrics;
+use naisu_agent::notify::NotificationHub;
+use naisu_agent::polling::PollSchedule;
+use naisu_agent::registry::SolverRegistry;
+use naisu_agent::risk::RiskManager;
+use naisu_agent::selection::SelectionPolicy;
+use naisu_agent::solver::{Bid, IntentRequest, Solver, SolverError};
+use naisu_agent::solver_factory::SolverFactory;
+use naisu_agent::solver_overrides::SolverOverrides;
+use naisu_agent::throttle::Throttle;
+use naisu_agent::tracker::{Closure, IntentTracker, OpenIntents};
+use naisu_agent::upgrade_watch::UpgradeWatcher;
+use naisu_core::{
+    format_sui, FailedAttempt, FulfilledObject, FulfillmentReport, Secret, SuiNetwork,
+};
+use naisu_sui::{
+    FulfillmentOutcome, SuiClient, SuiClientError, SuiConfig, SuiEvent, SuiTransportKind,
+};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration;
-use tracing::{error, info, warn};
+use tokio::sync::{watch, Notify, Semaphore};
+use tracing::{error, info, info_span, warn, Instrument};
 
 use dotenvy::dotenv;
 use std::env;
+use std::path::{Path, PathBuf};
 
-/// Get intent package from environment variables based on network
-fn get_intent_package(network: Network) -> String {
-    dotenv().ok(); // Load .env file if present
+/// Exposure caps from `[solvers.<name>] max_exposure`, for the running solvers
+fn set_exposure_caps(
+    risk: &RiskManager,
+    solvers: &[Box<dyn Solver + Send + Sync>],
+    config: &AgentConfig,
+) {
+    for solver in solvers {
+        risk.set_protocol_cap(solver.name(), config.solver(solver.name()).max_exposure);
+    }
+}
 
-    match network {
-        Network::Testnet => {
-            env::var("TESTNET_INTENT_PACKAGE").expect("TESTNET_INTENT_PACKAGE must be set in .env")
-        }
-        Network::Mainnet => {
-            env::var("MAINNET_INTENT_PACKAGE").expect("MAINNET_INTENT_PACKAGE must be set in .env")
+/// Build the Sui client for a network (transport from SUI_TRANSPORT, default JSON-RPC)
+fn build_sui_client(network: Network, config: &AgentConfig) -> SuiClient {
+    let transport = env::var("SUI_TRANSPORT")
+        .ok()
+        .and_then(|t| t.parse::<SuiTransportKind>().ok())
+        .unwrap_or_default();
+
+    let mut sui_config = match network {
+        Network::Mainnet => SuiConfig::mainnet(),
+        Network::Devnet => {
+            let mut devnet = SuiConfig::testnet();
+            devnet.network = SuiNetwork::Devnet;
+            devnet.graphql_url = SuiNetwork::Devnet.graphql_url().to_string();
+            devnet
         }
+        // Localnet and custom nodes: testnet defaults with the node's own RPC
+        _ => SuiConfig::testnet(),
+    };
+    sui_config.rpc_url = config.rpc_url(network);
+
+    SuiClient::new(sui_config.with_transport(transport))
+}
+
+/// Sign a network's fulfillments with the `SOLVER_SIGNER` key (local, KMS or
+/// remote) or the multisig treasury (`SOLVER_MULTISIG_*`, see
+/// `multisig_from_env`), and submit them through its `SuiClient`
+///
+/// With neither, fulfillments sign with the Sui CLI keystore.
+async fn register_signer(network: Network, config: &AgentConfig) -> anyhow::Result<()> {
+    let signer = match env::var("SOLVER_SIGNER") {
+        Ok(_) => Some(signer_from_env().await?),
+        Err(_) => None,
+    };
+    let multisig = multisig_from_env(signer.clone())?;
+    let (address, label) = match (&multisig, &signer) {
+        (Some(wallet), _) => (
+            wallet.address(),
+            format!("multisig (threshold {})", wallet.public_key().threshold),
+        ),
+        (None, Some(signer)) => (signer.address(), signer.label()),
+        (None, None) => return Ok(()),
+    };
+
+    let mut executor = SuiExecutor::new(&config.rpc_url(network), &address)
+        .with_client(build_sui_client(network, config));
+    if let Some(signer) = signer {
+        executor = executor.with_signer(signer);
+    }
+    // Takes precedence over the signer, which then only co-signs
+    if let Some(wallet) = multisig {
+        executor = executor.with_multisig(wallet);
     }
+
+    let wallet = config
+        .wallet_address(network)
+        .unwrap_or_else(|| SOLVER_ADDRESS.to_string());
+    if wallet == executor.address() {
+        info!("🔐 {:?} fulfillments sign with {}", network, label);
+    } else {
+        warn!(
+            "🔐 Signer {} controls {}, not the {:?} wallet {}: the CLI keystore signs instead",
+            label,
+            executor.address(),
+            network,
+            wallet
+        );
+    }
+    register_executor(Arc::new(executor));
+    Ok(())
 }
 
 /// CLI Arguments
 #[derive(Debug)]
 struct Args {
-    network: Network,
+    networks: Vec<Network>,
+    config: AgentConfig,
+    config_path: Option<PathBuf>,
+    /// Dry-run fulfillments only
+    simulate: bool,
 }
 
 impl Args {
-    fn parse() -> Self {
+    fn parse() -> anyhow::Result<Self> {
         let args: Vec<String> = std::env::args().collect();
 
-        let network = args
+        let networks = match args
             .iter()
             .position(|a| a == "--network" || a == "-n")
             .and_then(|i| args.get(i + 1))
-            .and_then(|n| n.parse().ok())
-            .unwrap_or(Network::Testnet);
+        {
+            Some(value) => parse_networks(value).map_err(anyhow::Error::msg)?,
+            None => vec![Network::Testnet],
+        };
+
+        // Explorer for a custom (URL) network
+        let networks = match args
+            .iter()
+            .position(|a| a == "--explorer-url")
+            .and_then(|i| args.get(i + 1))
+        {
+            Some(explorer) => networks
+                .into_iter()
+                .map(|n| match n {
+                    Network::Custom { rpc_url, .. } => Network::custom(rpc_url, explorer.as_str()),
+                    other => other,
+                })
+                .collect(),
+            None => networks,
+        };
+
+        // defaults → TOML → env, then CLI flags on top
+        let config_path = args
+            .iter()
+            .position(|a| a == "--config" || a == "-c")
+            .and_then(|i| args.get(i + 1));
+        let mut config = AgentConfig::load(config_path.map(Path::new))?;
+        config.polling = config.polling.with_args(&args);
+        config.validate()?;
+        // Fail at startup rather than on the first poll
+        for network in &networks {
+            config.intent_package(*network)?;
+        }
 
-        Self { network }
+        Ok(Self {
+            networks,
+            config,
+            config_path: config_path.map(PathBuf::from),
+            simulate: args.iter().any(|a| a == "--simulate"),
+        })
     }
 }
 
-/// Recent intent tracker (avoid duplicates)
-struct SolverDaemon {
+/// Parse `testnet`, `mainnet,testnet` or `all` (duplicates dropped)
+fn parse_networks(value: &str) -> Result<Vec<Network>, String> {
+    if value.eq_ignore_ascii_case("all") {
+        return Ok(vec![Network::Testnet, Network::Mainnet]);
+    }
+
+    let mut networks = Vec::new();
+    for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        let network: Network = name.parse()?;
+        if !networks.contains(&network) {
+            networks.push(network);
+        }
+    }
+    if networks.is_empty() {
+        return Err("--network needs at least one network".to_string());
+    }
+    Ok(networks)
+}
+
+/// Default number of intents fulfilled concurrently
+const DEFAULT_MAX_CONCURRENT_FULFILLMENTS: usize = 4;
+
+/// Events read per page while catching up on an event type
+const EVENT_PAGE_SIZE: usize = 50;
+
+/// Pages read per event type in one poll; the rest waits for the next one
+const MAX_EVENT_PAGES: usize = 10;
+
+/// Evaluation and fulfillment state shared by all intent tasks
+struct Fulfiller {
     network: Network,
-    solvers: Vec<Box<dyn Solver + Send + Sync>>,
-    processed_intents: HashSet<String>,
-    sui_client: reqwest::Client,
+    /// Swapped when the shared config changes
+    solvers: RwLock<Arc<Vec<Box<dyn Solver + Send + Sync>>>>,
+    /// Swapped with the solvers
+    selection: RwLock<SelectionPolicy>,
+    /// Solver wallet (`[wallet] address` or SOLVER_ADDRESS)
+    wallet_address: Option<String>,
+    sui_client: SuiClient,
+    /// Reference gas price and per-solver gas usage, shared with the solvers
+    gas: Arc<GasOracle>,
+    /// Per-solver in-flight slots and the wallet's submission queue
+    throttle: Throttle,
+    risk: RiskManager,
+    ledger: Mutex<Ledger>,
+    /// Fee and batching settings are read from here at fulfillment time
+    config: SharedConfig,
+    /// Naisu API base URL (kill switch, fulfillment reports, heartbeats)
+    api_url: Option<String>,
+    /// Sent as `x-solver-key` with intent fulfillment reports
+    solver_api_key: Option<Secret>,
+    http: reqwest::Client,
+    /// Signalled after each successful fulfillment (triggers fast polling)
+    fulfilled: Notify,
+    metrics: Arc<NetworkMetrics>,
+    /// Intents still open on chain
+    open: OpenIntents,
+}
+
+/// One network's pipeline: poll loop plus its fulfillment tasks
+struct SolverDaemon {
+    fulfiller: Arc<Fulfiller>,
+    intent_package: String,
+    rpc_url: String,
+    /// Intents already handed to a fulfillment task
+    tracker: IntentTracker,
+    /// Parses polled events and keeps the ones that fail
+    events: EventParser,
+    /// Cursor after the last event read, per event name
+    event_cursors: HashMap<&'static str, String>,
+    /// Bounds concurrent fulfillment tasks
+    permits: Arc<Semaphore>,
+    max_concurrent: usize,
+    /// Flips to true when this pipeline should stop
+    shutdown: watch::Receiver<bool>,
+    schedule: PollSchedule,
+    /// Whether the kill switch is currently engaged by the API
+    remote_halt: bool,
+    config: SharedConfig,
+    /// Config generation the current solvers were built from
+    config_generation: u64,
+    availability: AvailabilityCache,
+    /// Probed availability the current solvers were built from
+    available: HashMap<Protocol, bool>,
 }
 
 impl SolverDaemon {
-    fn new(network: Network) -> Self {
-        // Create solvers based on network
-        let solvers: Vec<Box<dyn Solver + Send + Sync>> = match network {
-            Network::Testnet => {
-                vec![
-                    Box::new(StakingSolver::new()),
-                    Box::new(DeepBookSolver::new()),
-                ]
-            }
-            Network::Mainnet => {
-                vec![
-                    Box::new(StakingSolver::new()),
-                    Box::new(ScallopSolver::new()),
-                    Box::new(NaviSolver::new()),
-                    Box::new(CetusSolver::new(Network::Mainnet)),
-                    Box::new(DeepBookSolver::new()),
-                ]
+    fn new(
+        network: Network,
+        shared: SharedConfig,
+        metrics: Arc<NetworkMetrics>,
+        availability: AvailabilityCache,
+        shutdown: watch::Receiver<bool>,
+    ) -> anyhow::Result<Self> {
+        let config_generation = shared.generation();
+        let config = &shared.get();
+        let available = availability.snapshot();
+        let gas = Arc::new(GasOracle::new());
+        let solvers = SolverFactory::with_config(network, config.clone())
+            .with_availability(available.clone())
+            .with_gas_oracle(gas.clone())
+            .create_solvers();
+        let risk = RiskManager::from_env();
+        set_exposure_caps(&risk, &solvers, config);
+
+        let mut throttle = Throttle::new(config.throttle(network));
+        for (name, tuning) in &config.solvers {
+            if let Some(limit) = tuning.max_in_flight {
+                throttle = throttle.with_solver_limit(name, limit);
             }
-        };
+        }
 
-        Self {
-            network,
-            solvers,
-            processed_intents: HashSet::new(),
-            sui_client: reqwest::Client::new(),
+        let max_concurrent = env::var("MAX_CONCURRENT_FULFILLMENTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_FULFILLMENTS);
+
+        Ok(Self {
+            fulfiller: Arc::new(Fulfiller {
+                network,
+                solvers: RwLock::new(Arc::new(solvers)),
+                selection: RwLock::new(config.selection.clone()),
+                wallet_address: config.wallet_address(network),
+                sui_client: build_sui_client(network, config),
+                gas,
+                throttle,
+                risk,
+                ledger: Mutex::new(Ledger::new()),
+                config: shared.clone(),
+                api_url: env::var("NAISU_API_URL").ok(),
+                solver_api_key: Secret::from_env("SOLVER_API_KEY")?,
+                http: reqwest::Client::new(),
+                fulfilled: Notify::new(),
+                metrics,
+                open: OpenIntents::new(),
+            }),
+            tracker: IntentTracker::new(),
+            events: EventParser::new(),
+            event_cursors: HashMap::new(),
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent,
+            shutdown,
+            schedule: PollSchedule::new(config.polling.clone()),
+            remote_halt: false,
+            intent_package: config.intent_package(network)?,
+            rpc_url: config.rpc_url(network),
+            config: shared,
+            config_generation,
+            availability,
+            available,
+        })
+    }
+
+    /// Track the reference gas price solvers bid with
+    async fn refresh_gas_price(&self) {
+        let fulfiller = &self.fulfiller;
+        if let Err(e) = fulfiller.gas.refresh(&fulfiller.sui_client).await {
+            warn!("⚠️  Failed to fetch reference gas price: {}", e);
         }
     }
 
-    /// Get RPC URL for current network
-    fn rpc_url(&self) -> &'static str {
-        self.network.rpc_url()
+    /// Rebuild solvers if the shared config or probed availability changed
+    fn refresh_solvers(&mut self) {
+        let generation = self.config.generation();
+        let available = self.availability.snapshot();
+        if generation == self.config_generation && available == self.available {
+            return;
+        }
+        self.config_generation = generation;
+        self.available = available.clone();
+
+        let network = self.fulfiller.network;
+        let config = self.config.get();
+        *self
+            .fulfiller
+            .selection
+            .write()
+            .expect("selection lock poisoned") = config.selection.clone();
+        let solvers = SolverFactory::with_config(network, config.clone())
+            .with_availability(available)
+            .with_gas_oracle(self.fulfiller.gas.clone())
+            .create_solvers();
+        set_exposure_caps(&self.fulfiller.risk, &solvers, &config);
+        info!(
+            "🔄 Config or availability changed, reloaded {} solver(s)",
+            solvers.len()
+        );
+        *self
+            .fulfiller
+            .solvers
+            .write()
+            .expect("solvers lock poisoned") = Arc::new(solvers);
     }
 
-    /// Get intent package for current network
-    fn intent_package(&self) -> String {
-        get_intent_package(self.network)
+    /// `name` events emitted since the last call, oldest first
+    ///
+    /// Resumes from the cursor the previous call stopped at, so every event
+    /// is read once however many there are. A failed page keeps what was
+    /// read before it.
+    async fn poll_events(&mut self, name: &'static str) -> Result<Vec<SuiEvent>, SuiClientError> {
+        let event_type = format!("{}::intent::{}", self.intent_package, name);
+        let mut events = Vec::new();
+        for _ in 0..MAX_EVENT_PAGES {
+            let cursor = self.event_cursors.get(name).map(String::as_str);
+            let page = match self
+                .fulfiller
+                .sui_client
+                .query_events(&event_type, cursor, EVENT_PAGE_SIZE)
+                .await
+            {
+                Ok(page) => page,
+                Err(e) if events.is_empty() => return Err(e),
+                Err(e) => {
+                    warn!("⚠️  Failed to page {} events: {}", name, e);
+                    break;
+                }
+            };
+            events.extend(page.data);
+            if let Some(cursor) = page.next_cursor {
+                self.event_cursors.insert(name, cursor);
+            }
+            if !page.has_next_page {
+                break;
+            }
+        }
+        Ok(events)
     }
 
-    /// Poll for YieldIntent objects (existing + new)
-    async fn poll_intents(
-        &mut self,
-        _include_existing: bool,
-    ) -> anyhow::Result<Vec<IntentRequest>> {
-        // Query for YieldIntent shared objects
-        let query = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "suix_queryEvents",
-            "params": [{
-                "MoveEventType": format!("{}::intent::IntentCreated", self.intent_package())
-            }, null, 10]
-        });
+    /// Mark intents fulfilled or cancelled on chain as closed
+    ///
+    /// Closures are read before creations on each poll, so an intent that
+    /// was created and closed between two polls is never bid on.
+    async fn ingest_closures(&mut self) {
+        for name in ["IntentFulfilled", "IntentCancelled"] {
+            let events = match self.poll_events(name).await {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("⚠️  Failed to poll {} events: {}", name, e);
+                    continue;
+                }
+            };
 
-        let response = self
-            .sui_client
-            .post(self.rpc_url())
-            .json(&query)
-            .send()
-            .await?;
+            let open = &self.fulfiller.open;
+            for event in &events {
+                let (intent_id, closure) = match self.events.parse(event) {
+                    Some(IntentEvent::Fulfilled(e)) => {
+                        (e.intent_id, Closure::Fulfilled { solver: e.solver })
+                    }
+                    Some(IntentEvent::Cancelled(e)) => {
+                        (e.intent_id, Closure::Cancelled { reason: e.reason })
+                    }
+                    _ => continue,
+                };
+                if open.close(&intent_id, closure.clone()) {
+                    info!("🔒 Intent {} closed: {}", intent_id, closure);
+                }
+            }
+        }
+    }
 
-        let result: serde_json::Value = response.json().await?;
+    /// Poll for intents created since the last poll
+    ///
+    /// The first poll reads from the start of the package's history, so
+    /// intents created while the daemon was down are picked up.
+    async fn poll_intents(&mut self) -> anyhow::Result<Vec<IntentRequest>> {
+        self.ingest_closures().await;
+
+        let events = self.poll_events("IntentCreated").await?;
 
         // Parse intents from events
         let mut intents = Vec::new();
 
-        if let Some(data) = result.get("result") {
-            if let Some(events) = data.get("data") {
-                for event in events.as_array().unwrap_or(&vec![]) {
-                    if let Some(intent) = self.parse_intent_event(event).await {
-                        if !self.processed_intents.contains(&intent.id) {
-                            intents.push(intent);
-                        }
-                    }
+        for event in &events {
+            if let Some(IntentEvent::Created(created)) = self.events.parse(event) {
+                let intent = IntentRequest::from(created);
+                if !self.tracker.is_new(&intent.id) {
+                    continue;
+                }
+                if self.fulfiller.open.open(&intent.id) {
+                    intents.push(intent);
+                } else {
+                    // Closed before we ever saw it open
+                    self.tracker.mark_processed(intent.id);
                 }
             }
         }
 
+        // Highest tip first, so urgent intents get the free slots
+        intents.sort_by_key(|i| std::cmp::Reverse(i.tip_bps()));
         Ok(intents)
     }
 
-    /// Parse IntentCreated event from suix_queryEvents format
-    async fn parse_intent_event(&self, event: &serde_json::Value) -> Option<IntentRequest> {
-        // Parse event data from parsedJson field
-        let parsed = event.get("parsedJson")?;
+    /// Mirror the API kill switch into the local one
+    ///
+    /// Only releases a halt the API itself engaged, so SOLVER_KILL_SWITCH
+    /// stays in force until the daemon is restarted without it.
+    async fn sync_kill_switch(&mut self) {
+        let Some(api_url) = &self.fulfiller.api_url else {
+            return;
+        };
 
-        let id = parsed.get("intent_id")?.as_str()?.to_string();
-        let user = parsed.get("user")?.as_str()?.to_string();
+        let url = format!("{}/api/v1/admin/kill-switch", api_url.trim_end_matches('/'));
+        let body: serde_json::Value = match self.fulfiller.http.get(&url).send().await {
+            Ok(resp) => match resp.json().await {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("⚠️  Invalid kill switch response: {}", e);
+                    return;
+                }
+            },
+            Err(e) => {
+                warn!("⚠️  Failed to fetch kill switch: {}", e);
+                return;
+            }
+        };
 
-        // Parse amount (can be string or number)
-        let amount_str = parsed.get("amount")?.as_str()?;
-        let amount = amount_str.parse::<u64>().ok()?;
+        let engaged = body["data"]["engaged"].as_bool().unwrap_or(false);
+        let switch = self.fulfiller.risk.kill_switch();
+        if engaged {
+            let reason = body["data"]["reason"].as_str().unwrap_or("admin API");
+            switch.engage(reason);
+            self.remote_halt = true;
+        } else if self.remote_halt {
+            switch.release();
+            self.remote_halt = false;
+        }
+    }
 
-        // Parse min_apy
-        let min_apy_str = parsed.get("min_apy")?.as_str()?;
-        let min_apy = min_apy_str.parse::<u64>().ok()?;
+    /// Layer solver tuning set through the admin API over the shared config
+    ///
+    /// Only writes when something the API set isn't in force yet, so a
+    /// config file reload gets the overrides back on the next poll.
+    async fn sync_solver_overrides(&self) {
+        let Some(api_url) = &self.fulfiller.api_url else {
+            return;
+        };
 
-        // Parse deadline
-        let deadline_str = parsed.get("deadline")?.as_str()?;
-        let deadline = deadline_str.parse::<u64>().ok()?;
+        let url = format!(
+            "{}/api/v1/admin/solvers/config",
+            api_url.trim_end_matches('/')
+        );
+        let body: serde_json::Value = match self.fulfiller.http.get(&url).send().await {
+            Ok(resp) => match resp.json().await {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("⚠️  Invalid solver config response: {}", e);
+                    return;
+                }
+            },
+            Err(e) => {
+                warn!("⚠️  Failed to fetch solver config: {}", e);
+                return;
+            }
+        };
+        let overrides: SolverOverrides = match serde_json::from_value(body["data"].clone()) {
+            Ok(overrides) => overrides,
+            Err(e) => {
+                warn!("⚠️  Invalid solver config: {}", e);
+                return;
+            }
+        };
 
-        Some(IntentRequest {
-            id,
-            user,
-            amount,
-            min_apy,
-            deadline,
+        if !overrides.is_applied(&self.config.get()) {
+            info!(
+                "🎛️  Applying solver config v{} from the API",
+                overrides.version
+            );
+            self.config.update(|config| overrides.apply(config));
+        }
+    }
+
+    /// Layer feature flags set through the admin API over the shared config
+    async fn sync_feature_flags(&self) {
+        let Some(api_url) = &self.fulfiller.api_url else {
+            return;
+        };
+
+        let url = format!("{}/api/v1/admin/features", api_url.trim_end_matches('/'));
+        let body: serde_json::Value = match self.fulfiller.http.get(&url).send().await {
+            Ok(resp) => match resp.json().await {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("⚠️  Invalid feature flags response: {}", e);
+                    return;
+                }
+            },
+            Err(e) => {
+                warn!("⚠️  Failed to fetch feature flags: {}", e);
+                return;
+            }
+        };
+        let overrides: FeatureOverrides =
+            match serde_json::from_value(body["data"]["overrides"].clone()) {
+                Ok(overrides) => overrides,
+                Err(e) => {
+                    warn!("⚠️  Invalid feature flags: {}", e);
+                    return;
+                }
+            };
+
+        if !overrides.is_applied(&self.config.get()) {
+            info!(
+                "🚦 Applying feature flags v{} from the API",
+                overrides.version
+            );
+            self.config.update(|config| overrides.apply(config));
+        }
+    }
+
+    /// Spawn a fulfillment task, waiting for a free slot if all are busy
+    async fn spawn_intent(&mut self, intent: IntentRequest) {
+        let ids = vec![intent.id.clone()];
+        self.spawn_work(ids, |fulfiller| async move {
+            fulfiller.process_intent(&intent).await;
         })
+        .await;
+    }
+
+    /// Pick winners for a poll's intents, then fill those won by the same
+    /// batching solver together
+    async fn spawn_batched(&mut self, intents: Vec<IntentRequest>, batching: &BatchConfig) {
+        let mut won = Vec::new();
+        for intent in intents {
+            let ranked = self.fulfiller.rank_bids(&intent).await;
+            if ranked.is_empty() {
+                self.tracker.mark_processed(intent.id);
+            } else {
+                won.push((intent, ranked));
+            }
+        }
+
+        let solvers = self.fulfiller.solvers();
+        let plan = batching.plan(
+            won,
+            |(_, ranked)| {
+                let winner = &ranked[0];
+                solvers
+                    .iter()
+                    .any(|s| s.name() == winner.solver_name && s.supports_batching())
+                    .then(|| winner.solver_name.clone())
+            },
+            |(intent, _)| intent.amount,
+        );
+
+        for legs in plan.batches {
+            let ids = legs.iter().map(|(intent, _)| intent.id.clone()).collect();
+            self.spawn_work(ids, |fulfiller| async move {
+                fulfiller.fulfill_batch(legs).await;
+            })
+            .await;
+        }
+        for (intent, ranked) in plan.singles {
+            let ids = vec![intent.id.clone()];
+            self.spawn_work(ids, |fulfiller| async move {
+                fulfiller.fulfill_ranked(&intent, ranked).await;
+            })
+            .await;
+        }
+    }
+
+    /// Run `work` on its own task once a slot is free, marking `intent_ids`
+    /// in flight until it finishes
+    async fn spawn_work<F, Fut>(&mut self, intent_ids: Vec<String>, work: F)
+    where
+        F: FnOnce(Arc<Fulfiller>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let permit = match self.permits.clone().acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => return, // semaphore closed: shutting down
+        };
+
+        let Some(claim) = self.tracker.claim(intent_ids) else {
+            return;
+        };
+
+        let task = work(self.fulfiller.clone());
+        tokio::spawn(
+            async move {
+                // Both release when the task ends, even if it panics
+                let _permit = permit;
+                let _claim = claim;
+                task.await;
+            }
+            .in_current_span(),
+        );
+    }
+
+    /// Wait for running fulfillment tasks, then refuse new ones
+    async fn drain(&self) {
+        let running = self.max_concurrent - self.permits.available_permits();
+        if running > 0 {
+            info!("   Waiting for {} in-flight fulfillment(s)...", running);
+        }
+        if let Ok(all) = self.permits.acquire_many(self.max_concurrent as u32).await {
+            all.forget();
+        }
+        self.permits.close();
+    }
+
+    /// Main loop
+    async fn run(&mut self) -> anyhow::Result<()> {
+        info!("🤖 Solver Daemon starting...");
+        info!("   Network: {:?}", self.fulfiller.network);
+        info!("   Intent Package: {}", self.intent_package);
+        info!("   RPC: {}", self.rpc_url);
+        info!(
+            "   Transport: {}",
+            self.fulfiller.sui_client.transport_name()
+        );
+        let solvers = self.fulfiller.solvers();
+        info!("   Solvers: {}", solvers.len());
+        info!(
+            "   Max concurrent fulfillments: {}",
+            self.permits.available_permits()
+        );
+
+        let polling = self.schedule.config();
+        info!(
+            "   Polling: every {}s (±{}%), backoff up to {}s, {} fast polls at {}s",
+            polling.interval_secs,
+            polling.jitter_pct,
+            polling.max_backoff_secs,
+            polling.fast_poll_count,
+            polling.fast_interval_secs
+        );
+
+        for solver in solvers.iter() {
+            info!(
+                "     - {} (up to {} in flight)",
+                solver.name(),
+                self.fulfiller.throttle.solver_limit(solver.name())
+            );
+        }
+
+        if self.fulfiller.risk.kill_switch().is_engaged() {
+            warn!("   Kill switch engaged: evaluating only, no fulfillment");
+        }
+        if self.fulfiller.network == Network::Mainnet
+            && !self.config.get().features.mainnet_fulfillment
+        {
+            warn!("   Feature mainnet_fulfillment off: evaluating only, no fulfillment");
+        }
+
+        let heartbeats = self.spawn_heartbeats();
+
+        while !*self.shutdown.borrow() {
+            self.sync_kill_switch().await;
+            self.sync_solver_overrides().await;
+            self.sync_feature_flags().await;
+            self.refresh_solvers();
+            self.refresh_gas_price().await;
+
+            info!("\n📡 Polling for new intents...");
+
+            let polled = self.poll_intents().await;
+            self.fulfiller.metrics.record_poll(polled.is_ok());
+            match polled {
+                Ok(intents) => {
+                    self.schedule.on_success();
+                    self.fulfiller.metrics.record_intents(intents.len());
+                    if intents.is_empty() {
+                        info!("   No new intents");
+                    } else {
+                        info!("   Found {} new intent(s)", intents.len());
+
+                        let batching = self.config.get().batching;
+                        if batching.enabled {
+                            self.spawn_batched(intents, &batching).await;
+                        } else {
+                            for intent in intents {
+                                self.spawn_intent(intent).await;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.schedule.on_error();
+                    error!("❌ Failed to poll intents: {}", e);
+                }
+            }
+
+            // Wait before next poll; a fulfillment cuts the wait short
+            let delay = self.schedule.next_delay();
+            if self.schedule.is_backing_off() {
+                warn!("   Backing off for {:?}", delay);
+            }
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = self.fulfiller.fulfilled.notified() => {
+                    self.schedule.on_fulfillment();
+                }
+                _ = self.shutdown.changed() => {}
+            }
+        }
+
+        info!("👋 Stopping {:?} pipeline", self.fulfiller.network);
+        let dead_letters = self.events.dead_letters().len();
+        if dead_letters > 0 {
+            warn!(
+                "   {} event(s) failed to parse (dead-lettered)",
+                dead_letters
+            );
+        }
+        if let Some(heartbeats) = heartbeats {
+            heartbeats.abort();
+        }
+        self.drain().await;
+        Ok(())
+    }
+
+    /// Start the heartbeat ticker, if enabled and there is an API to report to
+    fn spawn_heartbeats(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let config = self.config.get().heartbeat;
+        if !config.enabled || self.fulfiller.api_url.is_none() {
+            return None;
+        }
+
+        info!("   Heartbeat: every {}s", config.interval_secs);
+        let fulfiller = self.fulfiller.clone();
+        let interval = Duration::from_secs(config.interval_secs);
+        Some(tokio::spawn(
+            async move {
+                let mut ticker = tokio::time::interval(interval);
+                loop {
+                    ticker.tick().await;
+                    fulfiller.send_heartbeat(config.interval_secs).await;
+                }
+            }
+            .in_current_span(),
+        ))
+    }
+}
+
+impl Fulfiller {
+    /// Current solver set (stays valid across a reload)
+    fn solvers(&self) -> Arc<Vec<Box<dyn Solver + Send + Sync>>> {
+        self.solvers.read().expect("solvers lock poisoned").clone()
+    }
+
+    /// Solver SUI balance, if a wallet address is configured
+    async fn treasury_balance(&self) -> Option<u64> {
+        let address = self.wallet_address.as_deref()?;
+        match self
+            .sui_client
+            .get_coins(address, Some("0x2::sui::SUI"))
+            .await
+        {
+            Ok(coins) => Some(
+                coins
+                    .iter()
+                    .map(|c| c.balance.parse::<u64>().unwrap_or(0))
+                    .sum(),
+            ),
+            Err(e) => {
+                warn!("⚠️  Failed to fetch treasury balance: {}", e);
+                None
+            }
+        }
     }
 
     /// Evaluate and bid on an intent
@@ -179,7 +911,7 @@ impl SolverDaemon {
         let mut bids = Vec::new();
 
         // Get bids from each solver
-        for solver in &self.solvers {
+        for solver in self.solvers().iter() {
             // Use solver-specific APY estimate
             let market_apy = 0.08; // 8% default
 
@@ -197,115 +929,705 @@ impl SolverDaemon {
         bids
     }
 
-    /// Execute winning fulfillment
-    async fn execute_winning_bid(&self, intent: &IntentRequest, bids: Vec<Bid>) {
-        if let Some(winner) = select_winner(bids, intent.min_apy) {
-            info!("🏆 Winner: {} with {} bps", winner.solver_name, winner.apy);
-
-            // Find the winning solver
-            let solver = self.solvers.iter().find(|s| s.name() == winner.solver_name);
-
-            match solver {
-                Some(s) => match s.fulfill(intent).await {
-                    Ok(tx_digest) => {
-                        info!("✅ Intent fulfilled! TX: {}", tx_digest);
-                        info!("   View: {}/tx/{}", self.network.explorer_url(), tx_digest);
+    /// Whether an intent is still open, logging it if it closed
+    fn still_open(&self, intent: &IntentRequest) -> bool {
+        match self.open.closure(&intent.id) {
+            Some(closure) => {
+                info!(
+                    "⏭️  Intent {} {} mid-auction, dropping it",
+                    intent.id, closure
+                );
+                self.metrics.record_preempted();
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// Evaluate an intent and fulfill the winning bid
+    async fn process_intent(&self, intent: &IntentRequest) {
+        let ranked = self.rank_bids(intent).await;
+        self.fulfill_ranked(intent, ranked).await;
+    }
+
+    /// Evaluate an intent and rank the bids to try, winner first, then
+    /// its fallbacks
+    async fn rank_bids(&self, intent: &IntentRequest) -> Vec<Bid> {
+        info!("\n🎯 Processing Intent: {}", intent.id);
+        info!("   User: {}", intent.user);
+        info!(
+            "   Amount: {} MIST ({} SUI)",
+            intent.amount,
+            format_sui(intent.amount)
+        );
+        info!("   Min APY: {} bps", intent.min_apy);
+
+        // Get bids
+        let bids = self.evaluate_intent(intent).await;
+        if !self.still_open(intent) {
+            return Vec::new();
+        }
+
+        if bids.is_empty() {
+            info!("   No bids placed for {}", intent.id);
+            self.metrics.record_no_bid();
+            return Vec::new();
+        }
+
+        // Re-check the risk ceiling here so a solver that ignores it can't win
+        let registry = SolverRegistry::builtin();
+        let bids: Vec<Bid> = bids
+            .into_iter()
+            .filter(|bid| {
+                let within = intent.max_risk.is_none()
+                    || registry.get(&bid.solver_name).is_some_and(|d| {
+                        d.protocols
+                            .iter()
+                            .all(|p| intent.within_risk(p.risk_tier()))
+                    });
+                if !within {
+                    warn!(
+                        "🛡️  Dropping {} bid: above the intent's risk ceiling",
+                        bid.solver_name
+                    );
+                }
+                within
+            })
+            .collect();
+
+        let policy = self
+            .selection
+            .read()
+            .expect("selection lock poisoned")
+            .clone();
+        let ranked = policy.cascade(bids.clone(), intent.min_apy);
+        match ranked.first() {
+            Some(winner) => info!("🏆 Winner: {} with {} bps", winner.solver_name, winner.apy),
+            None => info!("ℹ️ No winning bid for intent {}", intent.id),
+        }
+        self.report_bids(intent, &bids, &ranked);
+        ranked
+    }
+
+    /// Post an auction's bids and its winner to the API in the background
+    ///
+    /// Dry runs aren't posted, like their fulfillments.
+    fn report_bids(&self, intent: &IntentRequest, bids: &[Bid], ranked: &[Bid]) {
+        let Some(api_url) = &self.api_url else {
+            return;
+        };
+        if is_simulating() {
+            return;
+        }
+
+        let api_url = api_url.trim_end_matches('/').to_string();
+        let intent_id = intent.id.clone();
+        let timestamp = chrono::Utc::now().timestamp_millis() as u64;
+        let registry = SolverRegistry::builtin();
+        let entries: Vec<serde_json::Value> = bids
+            .iter()
+            .map(|bid| {
+                let protocol = registry
+                    .get(&bid.solver_name)
+                    .and_then(|d| d.protocols.first())
+                    .map_or(bid.solver_name.as_str(), |p| p.name());
+                serde_json::json!({
+                    "intent_id": intent_id,
+                    "solver_name": bid.solver_name,
+                    "protocol": protocol,
+                    "offered_apy": bid.apy,
+                    "profit_bps": bid.profit_bps,
+                    "tip_bps": bid.tip_bps,
+                    "timestamp": timestamp,
+                    "confidence": bid.confidence,
+                })
+            })
+            .collect();
+        let winner = ranked.first().map(|winner| {
+            serde_json::json!({
+                "solver_name": winner.solver_name,
+                "offered_apy": winner.apy,
+                "fallbacks": ranked[1..].iter().map(|b| &b.solver_name).collect::<Vec<_>>(),
+            })
+        });
+
+        let http = self.http.clone();
+        tokio::spawn(
+            async move {
+                let bids_url = format!("{}/api/v1/solvers/bids", api_url);
+                for entry in entries {
+                    match http.post(&bids_url).json(&entry).send().await {
+                        // A re-bid below the solver's active bid: nothing new
+                        Ok(resp) if resp.status() == reqwest::StatusCode::CONFLICT => {}
+                        Ok(resp) => {
+                            if let Err(e) = resp.error_for_status() {
+                                warn!("⚠️  Failed to post bid: {}", e);
+                            }
+                        }
+                        Err(e) => warn!("⚠️  Failed to post bid: {}", e),
                     }
-                    Err(e) => {
-                        error!("❌ Fulfillment failed: {}", e);
+                }
+                if let Some(winner) = winner {
+                    let url = format!("{}/api/v1/solvers/bids/{}/winner", api_url, intent_id);
+                    let result = http
+                        .post(&url)
+                        .json(&winner)
+                        .send()
+                        .await
+                        .and_then(|resp| resp.error_for_status());
+                    if let Err(e) = result {
+                        warn!("⚠️  Failed to post winner of {}: {}", intent_id, e);
                     }
-                },
-                None => {
-                    warn!("Winning solver not found: {}", winner.solver_name);
                 }
             }
-        } else {
-            info!("ℹ️ No winning bid for intent {}", intent.id);
+            .in_current_span(),
+        );
+    }
+
+    /// Fulfill with the best bid, moving down the ranking while winners
+    /// fail; the fulfillment that lands records the attempts before it
+    async fn fulfill_ranked(&self, intent: &IntentRequest, ranked: Vec<Bid>) {
+        let mut failed = Vec::new();
+        let mut bids = ranked.into_iter().peekable();
+        while let Some(bid) = bids.next() {
+            let (solver, apy_bps) = (bid.solver_name.clone(), bid.apy);
+            let error = match self.fulfill_bid(intent, bid, &failed).await {
+                Err(e) if e.allows_fallback() => e,
+                _ => return,
+            };
+            if let SolverError::InsufficientLiquidity(reason) = &error {
+                warn!("💧 {} declined: {}", solver, reason);
+            }
+            if bids.peek().is_none() {
+                warn!("   No fallback left for intent {}", intent.id);
+                return;
+            }
+            info!("   Falling back to the next best bid");
+            failed.push(FailedAttempt {
+                solver,
+                apy_bps,
+                error: error.to_string(),
+            });
         }
     }
 
-    /// Main loop
-    async fn run(&mut self) -> anyhow::Result<()> {
-        info!("🤖 Solver Daemon starting...");
-        info!("   Network: {:?}", self.network);
-        info!("   Intent Package: {}", self.intent_package());
-        info!("   RPC: {}", self.rpc_url());
-        info!("   Solvers: {}", self.solvers.len());
+    /// Reserve risk exposure for a won intent
+    async fn reserve(&self, intent: &IntentRequest, winner: &Bid) -> bool {
+        // Dry runs spend nothing, so simulating on mainnet needs no flag
+        if self.network == Network::Mainnet
+            && !is_simulating()
+            && !self.config.get().features.mainnet_fulfillment
+        {
+            warn!("🚧 Mainnet fulfillment is off (feature mainnet_fulfillment): evaluating only");
+            self.metrics.record_blocked();
+            return false;
+        }
 
-        for solver in &self.solvers {
-            info!("     - {}", solver.name());
+        // Reserve exposure up front so concurrent tasks can't overshoot caps
+        match self.risk.reserve(
+            &winner.solver_name,
+            intent.amount,
+            self.treasury_balance().await,
+        ) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("🛡️  Fulfillment blocked by risk controls: {}", e);
+                self.metrics.record_blocked();
+                false
+            }
         }
+    }
 
-        loop {
-            info!("\n📡 Polling for new intents...");
+    /// Execute winning fulfillment
+    ///
+    /// Failures are logged and accounted here; the solver's error is
+    /// returned so the caller can fall back to another bid.
+    async fn fulfill_bid(
+        &self,
+        intent: &IntentRequest,
+        winner: Bid,
+        failed_attempts: &[FailedAttempt],
+    ) -> Result<(), SolverError> {
+        // Wait for a slot first, so an intent that closed meanwhile is dropped
+        let _slot = self.throttle.acquire(&winner.solver_name).await;
+        if !self.still_open(intent) || !self.reserve(intent, &winner).await {
+            return Ok(());
+        }
 
-            match self.poll_intents(false).await {
-                Ok(intents) => {
-                    if intents.is_empty() {
-                        info!("   No new intents");
-                    } else {
-                        info!("   Found {} new intent(s)", intents.len());
+        let solvers = self.solvers();
+        let Some(solver) = solvers.iter().find(|s| s.name() == winner.solver_name) else {
+            warn!("Winning solver not found: {}", winner.solver_name);
+            self.risk
+                .cancel_reservation(&winner.solver_name, intent.amount);
+            return Ok(());
+        };
 
-                        for intent in intents {
-                            info!("\n🎯 Processing Intent: {}", intent.id);
-                            info!("   User: {}", intent.user);
-                            info!(
-                                "   Amount: {} MIST ({} SUI)",
-                                intent.amount,
-                                intent.amount / 1_000_000_000
-                            );
-                            info!("   Min APY: {} bps", intent.min_apy);
-
-                            // Mark as processed
-                            self.processed_intents.insert(intent.id.clone());
-
-                            // Get bids
-                            let bids = self.evaluate_intent(&intent).await;
-
-                            if bids.is_empty() {
-                                info!("   No bids placed");
-                                continue;
-                            }
+        match self.throttle.submit(solver.fulfill(intent)).await {
+            Ok(outcome) => {
+                self.risk.complete_reservation(intent.amount);
+                self.gas.record(solver.name(), &outcome.gas_used);
+                self.record_fulfillment(intent, &winner, solver.name(), &outcome, failed_attempts)
+                    .await;
+                Ok(())
+            }
+            // On chain: whether it filled is unknown, so the exposure stays
+            Err(e @ SolverError::Submitted { .. }) => {
+                self.risk.complete_reservation(intent.amount);
+                error!("❌ Fulfillment failed after submission: {}", e);
+                warn!("   Not falling back: intent {} may be filled", intent.id);
+                self.metrics.record_failure();
+                Err(e)
+            }
+            Err(e) => {
+                self.risk
+                    .cancel_reservation(&winner.solver_name, intent.amount);
+                // Nothing was submitted, so this isn't a failed fulfillment
+                if !matches!(e, SolverError::InsufficientLiquidity(_)) {
+                    error!("❌ Fulfillment failed: {}", e);
+                    self.metrics.record_failure();
+                }
+                Err(e)
+            }
+        }
+    }
 
-                            // Execute winning bid
-                            self.execute_winning_bid(&intent, bids).await;
-                        }
-                    }
+    /// Fulfill intents won by one solver in a single PTB
+    ///
+    /// A batch that failed before submission fills nothing, so its intents
+    /// are retried one by one; a submitted one is never re-run.
+    async fn fulfill_batch(&self, legs: Vec<(IntentRequest, Vec<Bid>)>) {
+        let mut reserved = Vec::new();
+        for (intent, ranked) in legs {
+            if self.still_open(&intent) && self.reserve(&intent, &ranked[0]).await {
+                reserved.push((intent, ranked));
+            }
+        }
+        if reserved.len() < 2 {
+            for (intent, ranked) in reserved {
+                self.risk
+                    .cancel_reservation(&ranked[0].solver_name, intent.amount);
+                self.fulfill_ranked(&intent, ranked).await;
+            }
+            return;
+        }
+
+        let solver_name = reserved[0].1[0].solver_name.clone();
+        let solvers = self.solvers();
+        let solver = solvers.iter().find(|s| s.name() == solver_name);
+        let intents: Vec<IntentRequest> = reserved.iter().map(|(i, _)| i.clone()).collect();
+        info!(
+            "📦 Batching {} intent(s) for {}",
+            intents.len(),
+            solver_name
+        );
+
+        let result = match solver {
+            // The slot is released before any one-by-one fallback takes its own
+            Some(s) => {
+                let _slot = self.throttle.acquire(&solver_name).await;
+                self.throttle.submit(s.fulfill_batch(&intents)).await
+            }
+            None => Err(SolverError::IntentUnavailable(format!(
+                "solver {} not loaded",
+                solver_name
+            ))),
+        };
+        let expected = reserved.len();
+        let result = result.and_then(|outcomes| {
+            if outcomes.len() != expected {
+                error!(
+                    "❌ Batch returned {} outcome(s) for {} intent(s)",
+                    outcomes.len(),
+                    expected
+                );
+            }
+            align_outcomes(outcomes, expected).ok_or_else(|| SolverError::Submitted {
+                digest: "unknown".to_string(),
+                reason: "batch returned no outcome".to_string(),
+            })
+        });
+        match result {
+            // Batch legs share gas, so they aren't fed to the gas oracle:
+            // bids are priced for a lone fulfillment
+            Ok(outcomes) => {
+                for ((intent, ranked), outcome) in reserved.iter().zip(&outcomes) {
+                    self.risk.complete_reservation(intent.amount);
+                    self.record_fulfillment(intent, &ranked[0], &solver_name, outcome, &[])
+                        .await;
                 }
-                Err(e) => {
-                    error!("❌ Failed to poll intents: {}", e);
+            }
+            // On chain: a leg may be filled, so none is run again
+            Err(e @ SolverError::Submitted { .. }) => {
+                error!("❌ Batch failed after submission: {}", e);
+                for (intent, _) in &reserved {
+                    self.risk.complete_reservation(intent.amount);
+                    warn!("   Not falling back: intent {} may be filled", intent.id);
+                    self.metrics.record_failure();
+                }
+            }
+            Err(e) => {
+                error!("❌ Batch fulfillment failed: {}", e);
+                warn!("   Falling back to one fulfillment per intent");
+                for (intent, ranked) in reserved {
+                    self.risk
+                        .cancel_reservation(&ranked[0].solver_name, intent.amount);
+                    self.fulfill_ranked(&intent, ranked).await;
                 }
             }
+        }
+    }
 
-            // Wait before next poll
-            tokio::time::sleep(Duration::from_secs(10)).await;
+    /// Log, account and report a successful fulfillment
+    async fn record_fulfillment(
+        &self,
+        intent: &IntentRequest,
+        winner: &Bid,
+        solver_name: &str,
+        outcome: &FulfillmentOutcome,
+        failed_attempts: &[FailedAttempt],
+    ) {
+        let simulated = is_simulating();
+        if simulated {
+            info!("🧪 Intent {} dry run succeeded", intent.id);
+        } else {
+            info!("✅ Intent {} fulfilled! TX: {}", intent.id, outcome.digest);
+        }
+        for object in &outcome.created_objects {
+            info!("   Created: {} ({})", object.object_id, object.object_type);
+        }
+        info!("   Gas: {} MIST", outcome.gas_used.net());
+        if let Some(explorer) = self.network.explorer().filter(|_| !simulated) {
+            info!("   View: {}", explorer.tx(&outcome.digest));
+        }
+
+        // Report the protocol, not the solver, so the API can look up which
+        // receipt to verify
+        let protocol = SolverRegistry::builtin()
+            .get(solver_name)
+            .and_then(|d| d.protocols.first())
+            .map(|p| p.name().to_string())
+            .unwrap_or_else(|| solver_name.to_string());
+        let report = FulfillmentReport {
+            digest: outcome.digest.clone(),
+            solver: winner.solver_name.clone(),
+            protocol,
+            realized_apy_bps: winner.apy,
+            created_objects: outcome
+                .created_objects
+                .iter()
+                .map(|o| FulfilledObject {
+                    object_id: o.object_id.clone(),
+                    object_type: o.object_type.clone(),
+                })
+                .collect(),
+            reported_at: 0,
+            user: Some(intent.user.clone()),
+            amount: Some(intent.amount),
+            fee: Some(self.config.get().fees.quote(intent.amount).fee).filter(|fee| *fee > 0),
+            gas_used: Some(outcome.gas_used.net()),
+            failed_attempts: failed_attempts.to_vec(),
+        };
+        let record = FulfillmentRecord {
+            intent_id: intent.id.clone(),
+            solver: winner.solver_name.clone(),
+            protocol: solver_name.to_string(),
+            digest: outcome.digest.clone(),
+            timestamp_ms: chrono::Utc::now().timestamp_millis() as u64,
+            capital: intent.amount,
+            gas_paid: outcome.gas_used.net(),
+            offered_apy_bps: winner.apy,
+            market_apy_bps: winner.apy + winner.profit_bps as u64,
+            tip: intent.tip_amount(),
+            realized_return: None,
+            simulated,
+            failed_attempts: failed_attempts.to_vec(),
+        };
+
+        self.fulfilled.notify_one();
+        if record.simulated {
+            info!(
+                "🧪 Simulated: projected {} MIST/year for the solver",
+                record.expected_annual_return()
+            );
+            self.metrics.record_simulated();
+        } else {
+            self.metrics.record_fulfillment();
+            self.report_fulfillment(&record).await;
+            self.report_to_intent(&intent.id, &report).await;
+        }
+        self.ledger
+            .lock()
+            .expect("ledger lock poisoned")
+            .record(record);
+    }
+
+    /// Current state of each solver, for a heartbeat
+    fn solver_statuses(&self) -> Vec<SolverStatus> {
+        let halted = self.risk.kill_switch().is_engaged();
+        let last = heartbeat::last_fulfillments(
+            self.ledger.lock().expect("ledger lock poisoned").records(),
+        );
+
+        self.solvers()
+            .iter()
+            .map(|solver| {
+                let name = solver.name();
+                let available = self.throttle.available(name);
+                let state = if halted {
+                    SolverState::Halted
+                } else if available == 0 {
+                    SolverState::Busy
+                } else {
+                    SolverState::Active
+                };
+                SolverStatus {
+                    name: name.to_string(),
+                    state,
+                    in_flight: self.throttle.solver_limit(name).saturating_sub(available),
+                    last_fulfillment_ms: last.get(name).copied(),
+                }
+            })
+            .collect()
+    }
+
+    /// Tell the API this pipeline is alive
+    async fn send_heartbeat(&self, interval_secs: u64) {
+        let Some(api_url) = &self.api_url else {
+            return;
+        };
+
+        let heartbeat = Heartbeat {
+            network: self.network.name().to_string(),
+            wallet: self.wallet_address.clone(),
+            wallet_balance: self.treasury_balance().await,
+            interval_secs,
+            solvers: self.solver_statuses(),
+        };
+        let url = format!("{}/api/v1/solvers/heartbeat", api_url.trim_end_matches('/'));
+        let result = self
+            .http
+            .post(&url)
+            .json(&heartbeat)
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status());
+        if let Err(e) = result {
+            warn!("⚠️  Failed to send heartbeat: {}", e);
+        }
+    }
+
+    /// Send a fulfillment to the API for PnL accounting (if configured)
+    async fn report_fulfillment(&self, record: &FulfillmentRecord) {
+        let Some(api_url) = &self.api_url else {
+            return;
+        };
+
+        let url = format!(
+            "{}/api/v1/solvers/{}/fulfillments",
+            api_url.trim_end_matches('/'),
+            record.solver
+        );
+        if let Err(e) = self.http.post(&url).json(record).send().await {
+            warn!("⚠️  Failed to report fulfillment: {}", e);
+        }
+    }
+
+    /// Tell the API the intent is fulfilled (needs SOLVER_API_KEY)
+    async fn report_to_intent(&self, intent_id: &str, report: &FulfillmentReport) {
+        let (Some(api_url), Some(key)) = (&self.api_url, &self.solver_api_key) else {
+            return;
+        };
+
+        let url = format!(
+            "{}/api/v1/intents/{}/fulfillment",
+            api_url.trim_end_matches('/'),
+            intent_id
+        );
+        let result = self
+            .http
+            .post(&url)
+            .header("x-solver-key", key.expose())
+            .json(report)
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status());
+        if let Err(e) = result {
+            warn!("⚠️  Failed to report fulfillment of {}: {}", intent_id, e);
         }
     }
 }
 
+/// How often per-network metrics are logged
+const METRICS_LOG_INTERVAL: Duration = Duration::from_secs(300);
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Parse arguments
-    let args = Args::parse();
+    // Parse arguments (after .env so env overrides apply)
+    dotenv().ok();
+    let args = Args::parse()?;
 
     // Setup tracing
     tracing_subscriber::fmt().with_env_filter("info").init();
 
     info!("Starting Naisu Solver Daemon");
-    info!("Network: {:?}", args.network);
+    info!("Networks: {:?}", args.networks);
+    if args.simulate {
+        set_simulate(true);
+        warn!("🧪 Simulation mode: fulfillments are dry-run only, no funds are spent");
+    }
+
+    // Refresh protocol package addresses from the Move Registry
+    let mut config = args.config.clone();
+    if env::var("MVR_RESOLVE")
+        .map(|v| v != "false" && v != "0")
+        .unwrap_or(true)
+    {
+        for &network in &args.networks {
+            let Some(mvr) = MvrClient::for_network(network) else {
+                continue;
+            };
+            for r in config.resolve_packages(network, &mvr).await {
+                if let Some(resolved) = r.resolved.as_ref().filter(|_| !r.drifted()) {
+                    info!(
+                        "   {} ({}) up to date: {}",
+                        r.mvr_name,
+                        network.name(),
+                        resolved
+                    );
+                }
+            }
+        }
+    }
+    let shared = SharedConfig::new(config, args.config_path.clone());
+
+    // SIGHUP re-reads the config file; pipelines pick it up on their next poll
+    #[cfg(unix)]
+    {
+        let shared = shared.clone();
+        tokio::spawn(async move {
+            use tokio::signal::unix::{signal, SignalKind};
+            let Ok(mut hangup) = signal(SignalKind::hangup()) else {
+                return;
+            };
+            while hangup.recv().await.is_some() {
+                match shared.reload() {
+                    Ok(()) => info!("🔄 Config reloaded"),
+                    Err(e) => error!("❌ Config reload failed, keeping current config: {}", e),
+                }
+            }
+        });
+    }
+
+    // Keep dedicated gas coins topped up (dry runs spend nothing, so not
+    // when simulating). The pool is process-wide, so it holds coins of the
+    // first network's wallet.
+    let gas_pool = shared.get().gas_pool;
+    if gas_pool.enabled && !args.simulate {
+        let wallet = args
+            .networks
+            .first()
+            .and_then(|&network| args.config.wallet_address(network))
+            .unwrap_or_else(|| SOLVER_ADDRESS.to_string());
+        tokio::spawn(
+            GasPoolRebalancer::new(gas_pool, wallet)
+                .with_shared_config(shared.clone())
+                .run(),
+        );
+    }
+
+    let mut pipelines = Vec::new();
+    let mut all_metrics = Vec::new();
+
+    for &network in &args.networks {
+        let span = info_span!("pipeline", network = ?network);
+
+        // Watch this network's solver wallet in the background
+        match args.config.wallet_address(network) {
+            Some(address) => {
+                let monitor = BalanceMonitor::new(
+                    BalanceMonitorConfig::for_address(address, network),
+                    build_sui_client(network, &args.config),
+                    NotificationHub::from_env(),
+                );
+                tokio::spawn(monitor.run().instrument(span.clone()));
+            }
+            None => warn!("No wallet for {:?}, balance monitor disabled", network),
+        }
+
+        // Apply protocol package upgrades as they land on-chain
+        let watcher = UpgradeWatcher::new(
+            network,
+            shared.clone(),
+            build_sui_client(network, &args.config),
+            NotificationHub::from_env(),
+        );
+        tokio::spawn(watcher.run().instrument(span.clone()));
+
+        // Probe which protocols are actually deployed before building solvers
+        let prober = AvailabilityProber::new(
+            network,
+            build_sui_client(network, &args.config),
+            shared.clone(),
+        );
+        prober.probe_all().instrument(span.clone()).await;
+        let availability = prober.cache();
+        tokio::spawn(prober.run().instrument(span.clone()));
+
+        register_signer(network, &args.config)
+            .instrument(span.clone())
+            .await?;
+
+        let metrics = Arc::new(NetworkMetrics::new(network));
+        let (stop, shutdown) = watch::channel(false);
+        let mut daemon = SolverDaemon::new(
+            network,
+            shared.clone(),
+            metrics.clone(),
+            availability,
+            shutdown,
+        )?;
 
-    // Create and run daemon
-    let mut daemon = SolverDaemon::new(args.network);
+        // Each pipeline runs on its own; an error ends only that network
+        let handle = tokio::spawn(
+            async move {
+                if let Err(e) = daemon.run().await {
+                    error!("❌ Pipeline stopped: {}", e);
+                }
+            }
+            .instrument(span),
+        );
+        pipelines.push((network, stop, handle));
+        all_metrics.push(metrics);
+    }
 
-    // Handle Ctrl+C
-    let shutdown = tokio::spawn(async move {
-        tokio::signal::ctrl_c().await.ok();
-        info!("\n👋 Shutting down solver daemon...");
+    // Periodic per-network metrics
+    let reporter_metrics = all_metrics.clone();
+    let reporter = tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(METRICS_LOG_INTERVAL);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            for metrics in &reporter_metrics {
+                info!("📈 {}", metrics.snapshot());
+            }
+        }
     });
 
-    // Run daemon
-    tokio::select! {
-        result = daemon.run() => result,
-        _ = shutdown => Ok(()),
+    // Handle Ctrl+C: stop every pipeline and let in-flight work finish
+    tokio::signal::ctrl_c().await.ok();
+    info!("\n👋 Shutting down solver daemon...");
+    for (_, stop, _) in &pipelines {
+        stop.send(true).ok();
+    }
+    for (network, _, handle) in pipelines {
+        if let Err(e) = handle.await {
+            error!("{:?} pipeline panicked: {}", network, e);
+        }
+    }
+    reporter.abort();
+
+    for metrics in &all_metrics {
+        info!("📈 {}", metrics.snapshot());
     }
+    Ok(())
 }
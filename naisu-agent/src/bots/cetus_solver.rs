@@ -21,9 +21,20 @@
 //! - Config Package: `0x95b8d278b876cae22206131fb9724f701c9444515813042f54f0a426c9a3bc2f`
 //! - Integrate Package: `0x996c4d9480708fb8b92aa7acf819fb0497b5ec8e65ba06601cae2fb6db3312c3`
 
-use crate::config::network::{Network, Protocol, ProtocolConfig};
-use crate::executor::real_executor::{execute_cetus_fulfillment, CetusFulfillmentParams};
+use std::sync::Arc;
+
+use crate::config::agent::SolverTuning;
+use crate::config::mvr::PUBLISHED_AT;
+use crate::config::network::{AmountLimits, Network, Protocol, ProtocolConfig};
+use crate::executor::real_executor::{
+    execute_cetus_fulfillment, CetusFulfillmentParams, SOLVER_ADDRESS,
+};
+use crate::fees::FeeConfig;
+use crate::gas::GasOracle;
+use crate::registry::SolverDescriptor;
 use crate::solver::{calculate_bid, Bid, IntentRequest, Solver, SolverConfig, SolverError};
+use naisu_core::format_sui;
+use naisu_sui::FulfillmentOutcome;
 
 /// Cetus protocol constants (TESTNET - MVR v5)
 pub const CETUS_TESTNET_PACKAGE: &str =
@@ -63,8 +74,13 @@ pub const TESTNET_USDC: &str =
 /// Cetus protocol solver
 pub struct CetusSolver {
     config: SolverConfig,
+    gas: Arc<GasOracle>,
+    limits: AmountLimits,
     network: Network,
     protocol_config: Option<ProtocolConfig>,
+    fees: FeeConfig,
+    /// Wallet fulfillments are paid from
+    wallet: String,
 }
 
 impl CetusSolver {
@@ -78,33 +94,110 @@ impl CetusSolver {
                 gas_cost_bps: 20,   // Higher gas for multi-step PTB
                 max_slippage_bps: 100,
             },
+            gas: Arc::default(),
+            limits: Protocol::Cetus.default_limits(),
             network,
             protocol_config,
+            fees: FeeConfig::default(),
+            wallet: SOLVER_ADDRESS.to_string(),
         }
     }
 
+    /// Registry entry
+    pub fn descriptor() -> SolverDescriptor {
+        SolverDescriptor {
+            name: "CetusSolver",
+            protocols: &[Protocol::Cetus],
+            supports: |network| network == Network::Mainnet,
+            experimental: false,
+            build: |ctx| {
+                Box::new(
+                    CetusSolver::new(ctx.network)
+                        .with_protocol_config(
+                            ctx.config.protocol_config(Protocol::Cetus, ctx.network),
+                        )
+                        .with_tuning(&ctx.config.solver("CetusSolver"))
+                        .with_fees(ctx.config.fees.clone())
+                        .with_wallet(ctx.wallet())
+                        .with_gas_oracle(ctx.gas.clone())
+                        .with_limits(ctx.config.amount_limits(Protocol::Cetus, ctx.network)),
+                )
+            },
+        }
+    }
+
+    /// Apply `[solvers.<name>]` overrides from the agent config
+    pub fn with_tuning(mut self, tuning: &SolverTuning) -> Self {
+        self.config = tuning.apply(self.config);
+        self
+    }
+
+    /// Bid with live gas costs
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Pay fulfillments from `wallet` instead of the built-in solver wallet
+    pub fn with_wallet(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet = wallet.into();
+        self
+    }
+
+    /// Only bid on intent amounts within `limits`
+    pub fn with_limits(mut self, limits: AmountLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Use a protocol config with overrides applied
+    pub fn with_protocol_config(mut self, protocol_config: Option<ProtocolConfig>) -> Self {
+        self.protocol_config = protocol_config;
+        self
+    }
+
+    /// Route the protocol fee to the treasury on fulfillment
+    pub fn with_fees(mut self, fees: FeeConfig) -> Self {
+        self.fees = fees;
+        self
+    }
+
     /// Get current market APY in basis points
     /// CLMM can offer 10-15% APY depending on volume and range
     fn get_market_apy_bps(&self) -> u64 {
         match self.network {
-            Network::Testnet => 1200, // 12% (simulated)
             Network::Mainnet => 1500, // 15% (based on historical data)
+            _ => 1200,                // 12% (simulated)
         }
     }
 
     /// Get the appropriate package address for the network
     fn get_package(&self) -> &'static str {
         match self.network {
-            Network::Testnet => CETUS_TESTNET_PACKAGE,
             Network::Mainnet => CETUS_MAINNET_PACKAGE,
+            _ => CETUS_TESTNET_PACKAGE,
         }
     }
 
+    /// Package to call: the latest `published_at` (possibly resolved via
+    /// MVR), falling back to the original package ID
+    fn call_package(&self) -> String {
+        self.protocol_config
+            .as_ref()
+            .and_then(|c| {
+                c.config_objects
+                    .iter()
+                    .find(|(name, _)| name == PUBLISHED_AT)
+            })
+            .map(|(_, id)| id.clone())
+            .unwrap_or_else(|| self.get_package().to_string())
+    }
+
     /// Get the pools object ID
     fn get_pools_id(&self) -> &'static str {
         match self.network {
-            Network::Testnet => CETUS_TESTNET_POOLS_ID,
             Network::Mainnet => CETUS_MAINNET_POOLS_ID,
+            _ => CETUS_TESTNET_POOLS_ID,
         }
     }
 
@@ -112,8 +205,8 @@ impl CetusSolver {
     #[allow(dead_code)]
     fn get_global_config(&self) -> &'static str {
         match self.network {
-            Network::Testnet => CETUS_TESTNET_GLOBAL_CONFIG,
             Network::Mainnet => CETUS_MAINNET_GLOBAL_CONFIG,
+            _ => CETUS_TESTNET_GLOBAL_CONFIG,
         }
     }
 
@@ -121,8 +214,8 @@ impl CetusSolver {
     #[allow(dead_code)]
     fn get_integrate_package(&self) -> &'static str {
         match self.network {
-            Network::Testnet => CETUS_TESTNET_INTEGRATE,
             Network::Mainnet => CETUS_MAINNET_INTEGRATE,
+            _ => CETUS_TESTNET_INTEGRATE,
         }
     }
 
@@ -139,6 +232,14 @@ impl Solver for CetusSolver {
     }
 
     async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
+        if !intent.allows(Protocol::Cetus) {
+            return None;
+        }
+        // Decline here rather than fail mid-execution
+        if !self.limits.contains(intent.amount) {
+            return None;
+        }
+
         // Check if Cetus is available on this network
         if !self.is_available() {
             tracing::debug!("Cetus not available on {:?}", self.network);
@@ -147,21 +248,26 @@ impl Solver for CetusSolver {
 
         let market_apy_bps = self.get_market_apy_bps();
 
+        let tip_bps = intent.tip_bps();
         calculate_bid(
             market_apy_bps,
             intent.min_apy,
-            self.config.gas_cost_bps,
+            self.gas
+                .gas_cost_bps(self.name(), intent.amount)
+                .unwrap_or(self.config.gas_cost_bps),
             self.config.min_profit_bps,
+            tip_bps,
         )
         .map(|apy| Bid {
             solver_name: self.name().to_string(),
             apy,
-            profit_bps: self.config.min_profit_bps,
+            profit_bps: (market_apy_bps - apy) as u16,
+            tip_bps,
             confidence: 0.85, // Slightly lower due to IL risk and two-step process
         })
     }
 
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError> {
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
         if !self.is_available() {
             return Err(SolverError::FulfillmentFailed(format!(
                 "Cetus not available on {:?}",
@@ -173,8 +279,8 @@ impl Solver for CetusSolver {
         tracing::info!("   Network: {:?}", self.network);
         tracing::info!("   Intent ID: {}", intent.id);
         tracing::info!("   User: {}", intent.user);
-        tracing::info!("   Amount: {} SUI", intent.amount / 1_000_000_000);
-        tracing::info!("   Package: {}", self.get_package());
+        tracing::info!("   Amount: {} SUI", format_sui(intent.amount));
+        tracing::info!("   Package: {}", self.call_package());
         tracing::info!("   Pools ID: {}", self.get_pools_id());
 
         // Calculate price range for the position
@@ -184,31 +290,36 @@ impl Solver for CetusSolver {
         let tick_upper = 2000;
 
         let params = CetusFulfillmentParams {
+            wallet: self.wallet.clone(),
             intent_id: intent.id.clone(),
             user_address: intent.user.clone(),
             amount: intent.amount,
-            cetus_core: self.get_package().to_string(),
+            cetus_core: self.call_package(),
             cetus_factory: self.get_pools_id().to_string(),
             tick_lower,
             tick_upper,
+            fee: self.fees.transfer(intent.amount),
         };
 
         match execute_cetus_fulfillment(params).await {
-            Ok(tx_digest) => {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ CETUS FULFILLMENT REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
                 tracing::info!("✅ CETUS FULFILLMENT SUCCESS!");
-                tracing::info!("   TX Digest: {}", tx_digest);
+                tracing::info!("   TX Digest: {}", outcome.digest);
+                tracing::info!("   Created objects: {}", outcome.created_objects.len());
 
-                let explorer = match self.network {
-                    Network::Testnet => "suiscan.xyz/testnet",
-                    Network::Mainnet => "suiscan.xyz/mainnet",
-                };
-                tracing::info!("   View: https://{}/tx/{}", explorer, tx_digest);
+                if let Some(explorer) = self.network.explorer() {
+                    tracing::info!("   View: {}", explorer.tx(&outcome.digest));
+                }
 
-                Ok(tx_digest)
+                Ok(outcome)
             }
             Err(e) => {
                 tracing::error!("❌ CETUS FULFILLMENT FAILED: {}", e);
-                Err(SolverError::FulfillmentFailed(e.to_string()))
+                Err(SolverError::from_executor(e))
             }
         }
     }
@@ -255,6 +366,10 @@ mod tests {
             amount: 1_000_000_000,
             min_apy: 800, // 8%
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         let bid = solver.evaluate(&intent, 0.12).await;
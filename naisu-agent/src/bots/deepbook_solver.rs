@@ -11,16 +11,36 @@
 //! - Package: `0x000000000000000000000000000000000000000000000000000000000000dee9`
 //! - Module: clob_v2
 
+use std::sync::Arc;
+
+use crate::config::agent::SolverTuning;
+use crate::config::network::{AmountLimits, Network, Protocol};
+use crate::gas::GasOracle;
+use crate::registry::SolverDescriptor;
 use crate::solver::{calculate_bid, Bid, IntentRequest, Solver, SolverConfig, SolverError};
+use naisu_core::{format_sui, to_decimal, SUI_DECIMALS};
+use naisu_sui::adapters::deepbook::{DeepBookAdapter, DEFAULT_DEPTH, SUI_USDC_POOL};
+use naisu_sui::FulfillmentOutcome;
 
 /// DeepBook protocol constants (MAINNET - VERIFIED)
 /// Source: Sui Native (0xdee9)
 pub const DEEPBOOK_PACKAGE: &str =
     "0x000000000000000000000000000000000000000000000000000000000000dee9";
 
+/// Static market-making APY when no order book is available (5.0%)
+const FALLBACK_APY_BPS: u64 = 500;
+
+/// Times quoted capital is assumed to fill both sides per day
+const DEFAULT_TURNS_PER_DAY: f64 = 0.5;
+
 /// DeepBook protocol solver
 pub struct DeepBookSolver {
     config: SolverConfig,
+    gas: Arc<GasOracle>,
+    limits: AmountLimits,
+    /// Live SUI/USDC depth; the static APY is used without it
+    book: Option<DeepBookAdapter>,
+    turns_per_day: f64,
 }
 
 impl Default for DeepBookSolver {
@@ -38,13 +58,84 @@ impl DeepBookSolver {
                 gas_cost_bps: 15,
                 max_slippage_bps: 50,
             },
+            gas: Arc::default(),
+            limits: Protocol::DeepBook.default_limits(),
+            book: None,
+            turns_per_day: DEFAULT_TURNS_PER_DAY,
+        }
+    }
+
+    /// Registry entry
+    pub fn descriptor() -> SolverDescriptor {
+        SolverDescriptor {
+            name: "DeepBookSolver",
+            protocols: &[Protocol::DeepBook],
+            supports: |network| matches!(network, Network::Testnet | Network::Mainnet),
+            experimental: true,
+            build: |ctx| {
+                Box::new(
+                    DeepBookSolver::new()
+                        .with_order_book(match ctx.network {
+                            Network::Mainnet => DeepBookAdapter::new(),
+                            _ => DeepBookAdapter::testnet(),
+                        })
+                        .with_tuning(&ctx.config.solver("DeepBookSolver"))
+                        .with_gas_oracle(ctx.gas.clone())
+                        .with_limits(ctx.config.amount_limits(Protocol::DeepBook, ctx.network)),
+                )
+            },
         }
     }
 
-    /// Get current market APY in basis points
-    /// DeepBook market making: ~5% APY from spreads
-    fn get_market_apy_bps(&self) -> u64 {
-        500 // 5.0%
+    /// Apply `[solvers.<name>]` overrides from the agent config
+    pub fn with_tuning(mut self, tuning: &SolverTuning) -> Self {
+        self.config = tuning.apply(self.config);
+        self
+    }
+
+    /// Bid with live gas costs
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Only bid on intent amounts within `limits`
+    pub fn with_limits(mut self, limits: AmountLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Price market making from the live SUI/USDC book
+    pub fn with_order_book(mut self, book: DeepBookAdapter) -> Self {
+        self.book = Some(book);
+        self
+    }
+
+    /// How often quoted capital is assumed to round-trip per day
+    pub fn with_turns_per_day(mut self, turns: f64) -> Self {
+        self.turns_per_day = turns;
+        self
+    }
+
+    /// Market-making APY in basis points for quoting `amount` (MIST)
+    ///
+    /// From the book's spread and depth when available, else ~5%.
+    async fn get_market_apy_bps(&self, amount: u64) -> u64 {
+        let Some(adapter) = &self.book else {
+            return FALLBACK_APY_BPS;
+        };
+        let size = to_decimal(amount, SUI_DECIMALS);
+        match adapter.get_order_book(SUI_USDC_POOL, DEFAULT_DEPTH).await {
+            Ok(book) => match book.market_making_apy(size, self.turns_per_day) {
+                Some(apy) => (apy * 10_000.0) as u64,
+                // Deeper than the fetched book: too large to quote
+                None => 0,
+            },
+            Err(e) => {
+                tracing::warn!("DeepBook order book unavailable: {}", e);
+                FALLBACK_APY_BPS
+            }
+        }
     }
 }
 
@@ -55,27 +146,40 @@ impl Solver for DeepBookSolver {
     }
 
     async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
-        let market_apy_bps = self.get_market_apy_bps();
+        if !intent.allows(Protocol::DeepBook) {
+            return None;
+        }
+        // Decline here rather than fail mid-execution
+        if !self.limits.contains(intent.amount) {
+            return None;
+        }
+
+        let market_apy_bps = self.get_market_apy_bps(intent.amount).await;
 
+        let tip_bps = intent.tip_bps();
         calculate_bid(
             market_apy_bps,
             intent.min_apy,
-            self.config.gas_cost_bps,
+            self.gas
+                .gas_cost_bps(self.name(), intent.amount)
+                .unwrap_or(self.config.gas_cost_bps),
             self.config.min_profit_bps,
+            tip_bps,
         )
         .map(|apy| Bid {
             solver_name: self.name().to_string(),
             apy,
-            profit_bps: self.config.min_profit_bps,
+            profit_bps: (market_apy_bps - apy) as u16,
+            tip_bps,
             confidence: 0.88, // Market making has variable returns
         })
     }
 
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError> {
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
         tracing::info!("🔥 DEEPBOOK SOLVER EXECUTING REAL TRANSACTION!");
         tracing::info!("   Intent ID: {}", intent.id);
         tracing::info!("   User: {}", intent.user);
-        tracing::info!("   Amount: {} SUI", intent.amount / 1_000_000_000);
+        tracing::info!("   Amount: {} SUI", format_sui(intent.amount));
         tracing::info!("   Package: {}", DEEPBOOK_PACKAGE);
 
         // DeepBook CLOB requires:
@@ -121,6 +225,10 @@ mod tests {
             amount: 1_000_000_000,
             min_apy: 400, // 4%
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         let bid = solver.evaluate(&intent, 0.05).await;
@@ -1,15 +1,42 @@
 //! Concrete solver implementations
 //!
 //! Each solver is a separate bot that competes to fulfill intents.
+//! A new bot only needs a module here and an entry in `builtin()`.
+//! Every bot but staking is behind its protocol's Cargo feature.
 
+#[cfg(feature = "cetus")]
 pub mod cetus_solver;
+#[cfg(feature = "deepbook")]
 pub mod deepbook_solver;
+#[cfg(feature = "navi")]
 pub mod navi_solver;
+#[cfg(feature = "scallop")]
 pub mod scallop_solver;
 pub mod staking_solver;
 
+#[cfg(feature = "cetus")]
 pub use cetus_solver::CetusSolver;
+#[cfg(feature = "deepbook")]
 pub use deepbook_solver::DeepBookSolver;
+#[cfg(feature = "navi")]
 pub use navi_solver::NaviSolver;
+#[cfg(feature = "scallop")]
 pub use scallop_solver::ScallopSolver;
 pub use staking_solver::StakingSolver;
+
+use crate::registry::SolverDescriptor;
+
+/// Bots compiled into the agent, in bidding order
+pub fn builtin() -> Vec<SolverDescriptor> {
+    vec![
+        StakingSolver::descriptor(),
+        #[cfg(feature = "scallop")]
+        ScallopSolver::descriptor(),
+        #[cfg(feature = "navi")]
+        NaviSolver::descriptor(),
+        #[cfg(feature = "cetus")]
+        CetusSolver::descriptor(),
+        #[cfg(feature = "deepbook")]
+        DeepBookSolver::descriptor(),
+    ]
+}
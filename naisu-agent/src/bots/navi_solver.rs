@@ -16,8 +16,19 @@
 //! Navi (Account):   Deposit SUI → Account position tracked in protocol
 //! ```
 
-use crate::executor::real_executor::{execute_navi_fulfillment, NaviFulfillmentParams};
+use std::sync::Arc;
+
+use crate::config::agent::SolverTuning;
+use crate::config::network::{AmountLimits, Network, Protocol};
+use crate::executor::real_executor::{
+    execute_navi_fulfillment, NaviFulfillmentParams, SOLVER_ADDRESS,
+};
+use crate::gas::GasOracle;
+use crate::registry::SolverDescriptor;
 use crate::solver::{calculate_bid, Bid, IntentRequest, Solver, SolverConfig, SolverError};
+use naisu_core::{format_sui, to_decimal, Asset, SUI_DECIMALS};
+use naisu_sui::adapters::NaviAdapter;
+use naisu_sui::FulfillmentOutcome;
 
 /// Navi protocol constants (MAINNET - VERIFIED)
 pub const NAVI_PACKAGE: &str = "0xee0041239b89564ce870a7dec5ddc5d114367ab94a1137e90aa0633cb76518e0";
@@ -26,9 +37,18 @@ pub const NAVI_STORAGE: &str = "0xbb4e2f4b6205c2e2a2db47aeb4f830796ec7c005f88537
 /// Navi SUI asset ID
 pub const NAVI_SUI_ASSET_ID: u8 = 0;
 
+/// Static supply APY when no market data is available (8.0%)
+const FALLBACK_APY_BPS: u64 = 800;
+
 /// Navi protocol solver
 pub struct NaviSolver {
     config: SolverConfig,
+    gas: Arc<GasOracle>,
+    limits: AmountLimits,
+    /// Wallet fulfillments are paid from
+    wallet: String,
+    /// Live market data; the static APY is used without it
+    rates: Option<NaviAdapter>,
 }
 
 impl Default for NaviSolver {
@@ -46,13 +66,106 @@ impl NaviSolver {
                 gas_cost_bps: 10,
                 max_slippage_bps: 50,
             },
+            gas: Arc::default(),
+            limits: Protocol::Navi.default_limits(),
+            wallet: SOLVER_ADDRESS.to_string(),
+            rates: None,
+        }
+    }
+
+    /// Registry entry
+    pub fn descriptor() -> SolverDescriptor {
+        SolverDescriptor {
+            name: "NaviSolver",
+            protocols: &[Protocol::Navi],
+            supports: |network| network == Network::Mainnet,
+            experimental: false,
+            build: |ctx| {
+                Box::new(
+                    NaviSolver::new()
+                        .with_market_data(NaviAdapter::new())
+                        .with_tuning(&ctx.config.solver("NaviSolver"))
+                        .with_gas_oracle(ctx.gas.clone())
+                        .with_wallet(ctx.wallet())
+                        .with_limits(ctx.config.amount_limits(Protocol::Navi, ctx.network)),
+                )
+            },
+        }
+    }
+
+    /// Apply `[solvers.<name>]` overrides from the agent config
+    pub fn with_tuning(mut self, tuning: &SolverTuning) -> Self {
+        self.config = tuning.apply(self.config);
+        self
+    }
+
+    /// Bid with live gas costs
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Pay fulfillments from `wallet` instead of the built-in solver wallet
+    pub fn with_wallet(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet = wallet.into();
+        self
+    }
+
+    /// Only bid on intent amounts within `limits`
+    pub fn with_limits(mut self, limits: AmountLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Bid against live Navi rates
+    pub fn with_market_data(mut self, rates: NaviAdapter) -> Self {
+        self.rates = Some(rates);
+        self
+    }
+
+    /// Supply APY in basis points once `amount` (MIST) is deposited
+    ///
+    /// Bids price in the rate this deposit leaves behind, which for a
+    /// large intent sits well below the current one.
+    async fn get_market_apy_bps(&self, amount: u64) -> u64 {
+        let Some(rates) = &self.rates else {
+            return FALLBACK_APY_BPS;
+        };
+        let deposit = to_decimal(amount, SUI_DECIMALS);
+        match rates
+            .projected_supply_apy(Asset::Sui.symbol_on("navi"), deposit)
+            .await
+        {
+            Ok(apy) => (apy * 100.0) as u64,
+            Err(e) => {
+                tracing::warn!("Navi rates unavailable: {}", e);
+                FALLBACK_APY_BPS
+            }
         }
     }
 
-    /// Get current market APY in basis points
-    /// Navi typically offers ~8% APY on SUI deposits
-    fn get_market_apy_bps(&self) -> u64 {
-        800 // 8.0%
+    /// Refuse a deposit of `amount` (MIST) the SUI reserve can't safely absorb
+    ///
+    /// Without market data the deposit goes ahead unchecked.
+    async fn check_liquidity(&self, amount: u64) -> Result<(), SolverError> {
+        let Some(rates) = &self.rates else {
+            return Ok(());
+        };
+        let deposit = to_decimal(amount, SUI_DECIMALS);
+        match rates
+            .can_absorb(Asset::Sui.symbol_on("navi"), deposit)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(SolverError::InsufficientLiquidity(format!(
+                "Navi SUI reserve cannot take {} SUI",
+                format_sui(amount)
+            ))),
+            Err(e) => {
+                tracing::warn!("Navi liquidity unknown, skipping the check: {}", e);
+                Ok(())
+            }
+        }
     }
 }
 
@@ -63,34 +176,49 @@ impl Solver for NaviSolver {
     }
 
     async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
-        let market_apy_bps = self.get_market_apy_bps();
+        if !intent.allows(Protocol::Navi) {
+            return None;
+        }
+        // Decline here rather than fail mid-execution
+        if !self.limits.contains(intent.amount) {
+            return None;
+        }
+
+        let market_apy_bps = self.get_market_apy_bps(intent.amount).await;
 
+        let tip_bps = intent.tip_bps();
         calculate_bid(
             market_apy_bps,
             intent.min_apy,
-            self.config.gas_cost_bps,
+            self.gas
+                .gas_cost_bps(self.name(), intent.amount)
+                .unwrap_or(self.config.gas_cost_bps),
             self.config.min_profit_bps,
+            tip_bps,
         )
         .map(|apy| Bid {
             solver_name: self.name().to_string(),
             apy,
-            profit_bps: self.config.min_profit_bps,
+            profit_bps: (market_apy_bps - apy) as u16,
+            tip_bps,
             confidence: 0.95,
         })
     }
 
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError> {
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
         tracing::info!("🔥 NAVI SOLVER EXECUTING REAL TRANSACTION!");
         tracing::info!("   Intent ID: {}", intent.id);
         tracing::info!("   User: {}", intent.user);
-        tracing::info!("   Amount: {} SUI", intent.amount / 1_000_000_000);
+        tracing::info!("   Amount: {} SUI", format_sui(intent.amount));
         tracing::info!("   Package: {}", NAVI_PACKAGE);
+        self.check_liquidity(intent.amount).await?;
 
         // Note: Navi is account-based, so we need a different approach
         // Option 1: Create account, deposit, transfer account cap to user
         // Option 2: Use wrapper contract that tokenizes Navi positions
 
         let params = NaviFulfillmentParams {
+            wallet: self.wallet.clone(),
             intent_id: intent.id.clone(),
             user_address: intent.user.clone(),
             amount: intent.amount,
@@ -100,15 +228,22 @@ impl Solver for NaviSolver {
         };
 
         match execute_navi_fulfillment(params).await {
-            Ok(tx_digest) => {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ NAVI FULFILLMENT REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
                 tracing::info!("✅ NAVI FULFILLMENT SUCCESS!");
-                tracing::info!("   TX Digest: {}", tx_digest);
-                tracing::info!("   View: https://suiscan.xyz/mainnet/tx/{}", tx_digest);
-                Ok(tx_digest)
+                tracing::info!("   TX Digest: {}", outcome.digest);
+                tracing::info!("   Created objects: {}", outcome.created_objects.len());
+                if let Some(explorer) = Network::Mainnet.explorer() {
+                    tracing::info!("   View: {}", explorer.tx(&outcome.digest));
+                }
+                Ok(outcome)
             }
             Err(e) => {
                 tracing::error!("❌ NAVI FULFILLMENT FAILED: {}", e);
-                Err(SolverError::FulfillmentFailed(e.to_string()))
+                Err(SolverError::from_executor(e))
             }
         }
     }
@@ -145,6 +280,10 @@ mod tests {
             amount: 1_000_000_000,
             min_apy: 750,
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         let bid = solver.evaluate(&intent, 0.080).await;
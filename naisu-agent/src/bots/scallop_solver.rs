@@ -17,12 +17,29 @@
 //! 1. mint::mint(Coin<SUI>) -> Coin<sSUI>
 //!    Deposit SUI to Scallop, receive sSUI (yield-bearing token)
 //!
-//! 2. intent::fulfill_intent(YieldIntent, Coin<sSUI>)
+//! 2. intent::fulfill_intent<SUI, sSUI>(YieldIntent, Coin<sSUI>, "scallop", apy)
 //!    Transfer sSUI to user, fulfill intent
 //! ```
+//!
+//! Without an intent package configured for the network, step 2 is a plain
+//! transfer of the sSUI to the user.
+
+use std::sync::Arc;
 
-use crate::executor::real_executor::{execute_scallop_fulfillment, ScallopFulfillmentParams};
+use crate::batch::{split_outcome, BatchLeg};
+use crate::config::agent::SolverTuning;
+use crate::config::network::{AmountLimits, Network, Protocol};
+use crate::executor::real_executor::{
+    execute_batch_fulfillment, execute_scallop_fulfillment, BatchDeposit, BatchFulfillmentParams,
+    IntentFulfillment, ScallopFulfillmentParams, SOLVER_ADDRESS,
+};
+use crate::fees::FeeConfig;
+use crate::gas::GasOracle;
+use crate::registry::SolverDescriptor;
 use crate::solver::{calculate_bid, Bid, IntentRequest, Solver, SolverConfig, SolverError};
+use naisu_core::{format_sui, to_decimal, Asset, SuiNetwork, SUI_DECIMALS};
+use naisu_sui::adapters::ScallopAdapter;
+use naisu_sui::{FulfillmentOutcome, IntentObject, IntentPackage};
 
 /// Scallop protocol constants (MAINNET - VERIFIED)
 /// Source: https://github.com/scallop-io/sui-lending-protocol
@@ -37,9 +54,21 @@ pub const SCALLOP_VERSION: &str =
 /// sSUI coin type (Scallop's yield-bearing SUI token)
 pub const SSUI_COIN_TYPE: &str = "0xd384ded6b9e7f4d2c4c9007b0291ef88fbfed8e709bce83d2da69de2d79d013d::s_coin::sCoin<0x2::sui::SUI>";
 
+/// Static supply APY when no market data is available (8.5%)
+const FALLBACK_APY_BPS: u64 = 850;
+
 /// Scallop protocol solver
 pub struct ScallopSolver {
     config: SolverConfig,
+    gas: Arc<GasOracle>,
+    limits: AmountLimits,
+    fees: FeeConfig,
+    /// Wallet fulfillments are paid from
+    wallet: String,
+    /// Live market data; the static APY is used without it
+    rates: Option<ScallopAdapter>,
+    /// Package intents are fulfilled through
+    intent_package: Option<IntentPackage>,
 }
 
 impl Default for ScallopSolver {
@@ -57,13 +86,123 @@ impl ScallopSolver {
                 gas_cost_bps: 10,
                 max_slippage_bps: 50,
             },
+            gas: Arc::default(),
+            limits: Protocol::Scallop.default_limits(),
+            rates: None,
+            fees: FeeConfig::default(),
+            wallet: SOLVER_ADDRESS.to_string(),
+            intent_package: None,
+        }
+    }
+
+    /// Registry entry
+    pub fn descriptor() -> SolverDescriptor {
+        SolverDescriptor {
+            name: "ScallopSolver",
+            protocols: &[Protocol::Scallop],
+            supports: |network| network == Network::Mainnet,
+            experimental: false,
+            build: |ctx| {
+                let mut solver = ScallopSolver::new()
+                    .with_market_data(ScallopAdapter::new())
+                    .with_tuning(&ctx.config.solver("ScallopSolver"))
+                    .with_fees(ctx.config.fees.clone())
+                    .with_wallet(ctx.wallet())
+                    .with_gas_oracle(ctx.gas.clone())
+                    .with_limits(ctx.config.amount_limits(Protocol::Scallop, ctx.network));
+                if let Ok(package) = ctx.config.intent_package(ctx.network) {
+                    solver = solver.with_intent_package(IntentPackage::new(&package));
+                }
+                Box::new(solver)
+            },
+        }
+    }
+
+    /// Apply `[solvers.<name>]` overrides from the agent config
+    pub fn with_tuning(mut self, tuning: &SolverTuning) -> Self {
+        self.config = tuning.apply(self.config);
+        self
+    }
+
+    /// Bid with live gas costs
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Pay fulfillments from `wallet` instead of the built-in solver wallet
+    pub fn with_wallet(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet = wallet.into();
+        self
+    }
+
+    /// Only bid on intent amounts within `limits`
+    pub fn with_limits(mut self, limits: AmountLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Route the protocol fee to the treasury on fulfillment
+    pub fn with_fees(mut self, fees: FeeConfig) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Fulfill intents through the intent package
+    pub fn with_intent_package(mut self, package: IntentPackage) -> Self {
+        self.intent_package = Some(package);
+        self
+    }
+
+    /// Bid against live Scallop rates
+    pub fn with_market_data(mut self, rates: ScallopAdapter) -> Self {
+        self.rates = Some(rates);
+        self
+    }
+
+    /// Supply APY in basis points once `amount` (MIST) is deposited
+    ///
+    /// A large deposit lowers utilization and with it the rate, so bids
+    /// are made against the projected APY, not the current one.
+    async fn get_market_apy_bps(&self, amount: u64) -> u64 {
+        let Some(rates) = &self.rates else {
+            return FALLBACK_APY_BPS;
+        };
+        let deposit = to_decimal(amount, SUI_DECIMALS);
+        match rates
+            .projected_supply_apy(Asset::Sui.symbol_on("scallop"), deposit)
+            .await
+        {
+            Ok(apy) => (apy * 100.0) as u64,
+            Err(e) => {
+                tracing::warn!("Scallop rates unavailable: {}", e);
+                FALLBACK_APY_BPS
+            }
         }
     }
 
-    /// Get current market APY in basis points
-    /// Scallop typically offers ~8.5% APY on SUI deposits
-    fn get_market_apy_bps(&self) -> u64 {
-        850 // 8.5% - In production, fetch from Scallop API
+    /// Refuse a deposit of `amount` (MIST) the SUI market can't safely absorb
+    ///
+    /// Without market data the deposit goes ahead unchecked.
+    async fn check_liquidity(&self, amount: u64) -> Result<(), SolverError> {
+        let Some(rates) = &self.rates else {
+            return Ok(());
+        };
+        let deposit = to_decimal(amount, SUI_DECIMALS);
+        match rates
+            .can_absorb(Asset::Sui.symbol_on("scallop"), deposit)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(SolverError::InsufficientLiquidity(format!(
+                "Scallop SUI market cannot take {} SUI",
+                format_sui(amount)
+            ))),
+            Err(e) => {
+                tracing::warn!("Scallop liquidity unknown, skipping the check: {}", e);
+                Ok(())
+            }
+        }
     }
 }
 
@@ -74,49 +213,134 @@ impl Solver for ScallopSolver {
     }
 
     async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
-        let market_apy_bps = self.get_market_apy_bps();
+        if !intent.allows(Protocol::Scallop) {
+            return None;
+        }
+        // Decline here rather than fail mid-execution
+        if !self.limits.contains(intent.amount) {
+            return None;
+        }
+
+        let market_apy_bps = self.get_market_apy_bps(intent.amount).await;
 
+        let tip_bps = intent.tip_bps();
         calculate_bid(
             market_apy_bps,
             intent.min_apy,
-            self.config.gas_cost_bps,
+            self.gas
+                .gas_cost_bps(self.name(), intent.amount)
+                .unwrap_or(self.config.gas_cost_bps),
             self.config.min_profit_bps,
+            tip_bps,
         )
         .map(|apy| Bid {
             solver_name: self.name().to_string(),
             apy,
-            profit_bps: self.config.min_profit_bps,
+            profit_bps: (market_apy_bps - apy) as u16,
+            tip_bps,
             confidence: 0.95, // High confidence for direct protocol
         })
     }
 
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError> {
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
         tracing::info!("🔥 SCALLOP SOLVER EXECUTING REAL TRANSACTION!");
         tracing::info!("   Intent ID: {}", intent.id);
         tracing::info!("   User: {}", intent.user);
-        tracing::info!("   Amount: {} SUI", intent.amount / 1_000_000_000);
+        tracing::info!("   Amount: {} SUI", format_sui(intent.amount));
         tracing::info!("   Package: {}", SCALLOP_PACKAGE);
+        self.check_liquidity(intent.amount).await?;
+
+        // The APY reported on chain is what the bid offered
+        let fulfillment = match &self.intent_package {
+            Some(package) => Some(IntentFulfillment {
+                package: package.clone(),
+                // The CLI looks up the shared version itself
+                intent: IntentObject::new(
+                    &intent.id,
+                    1,
+                    Asset::Sui
+                        .coin_type(SuiNetwork::Mainnet)
+                        .expect("SUI exists on every network"),
+                ),
+                apy: self
+                    .evaluate(intent, 0.0)
+                    .await
+                    .map_or(intent.min_apy, |bid| bid.apy),
+            }),
+            None => None,
+        };
 
         // Execute real Scallop fulfillment
         let params = ScallopFulfillmentParams {
+            wallet: self.wallet.clone(),
             intent_id: intent.id.clone(),
             user_address: intent.user.clone(),
             amount: intent.amount,
             scallop_package: SCALLOP_PACKAGE.to_string(),
             scallop_market: SCALLOP_MARKET.to_string(),
             scallop_version: SCALLOP_VERSION.to_string(),
+            fee: self.fees.transfer(intent.amount),
+            fulfillment,
         };
 
         match execute_scallop_fulfillment(params).await {
-            Ok(tx_digest) => {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ SCALLOP FULFILLMENT REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
                 tracing::info!("✅ SCALLOP FULFILLMENT SUCCESS!");
-                tracing::info!("   TX Digest: {}", tx_digest);
-                tracing::info!("   View: https://suiscan.xyz/mainnet/tx/{}", tx_digest);
-                Ok(tx_digest)
+                tracing::info!("   TX Digest: {}", outcome.digest);
+                tracing::info!("   Created objects: {}", outcome.created_objects.len());
+                if let Some(explorer) = Network::Mainnet.explorer() {
+                    tracing::info!("   View: {}", explorer.tx(&outcome.digest));
+                }
+                Ok(outcome)
             }
             Err(e) => {
                 tracing::error!("❌ SCALLOP FULFILLMENT FAILED: {}", e);
-                Err(SolverError::FulfillmentFailed(e.to_string()))
+                Err(SolverError::from_executor(e))
+            }
+        }
+    }
+
+    fn supports_batching(&self) -> bool {
+        true
+    }
+
+    async fn fulfill_batch(
+        &self,
+        intents: &[IntentRequest],
+    ) -> Result<Vec<FulfillmentOutcome>, SolverError> {
+        tracing::info!("🔥 SCALLOP SOLVER BATCHING {} INTENTS", intents.len());
+        self.check_liquidity(intents.iter().map(|i| i.amount).sum())
+            .await?;
+
+        let legs: Vec<BatchLeg> = intents.iter().map(BatchLeg::from).collect();
+        let amounts: Vec<u64> = intents.iter().map(|i| i.amount).collect();
+        let params = BatchFulfillmentParams {
+            wallet: self.wallet.clone(),
+            legs: legs.clone(),
+            deposit: BatchDeposit::Scallop {
+                package: SCALLOP_PACKAGE.to_string(),
+                market: SCALLOP_MARKET.to_string(),
+                version: SCALLOP_VERSION.to_string(),
+            },
+            fee: self.fees.batch_transfer(&amounts),
+        };
+
+        match execute_batch_fulfillment(params).await {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ SCALLOP BATCH REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
+                tracing::info!("✅ SCALLOP BATCH SUCCESS: {}", outcome.digest);
+                Ok(split_outcome(&outcome, &legs))
+            }
+            Err(e) => {
+                tracing::error!("❌ SCALLOP BATCH FAILED: {}", e);
+                Err(SolverError::from_executor(e))
             }
         }
     }
@@ -156,6 +380,10 @@ mod tests {
             amount: 1_000_000_000, // 1 SUI
             min_apy: 750,          // 7.5%
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         // Market APY 8.5%, should be profitable
@@ -9,8 +9,21 @@
 //! 3. Get StakedSui object
 //! 4. Transfer StakedSui to user via intent fulfillment
 
-use crate::executor::real_executor::{execute_staking_fulfillment, FulfillmentParams};
+use std::sync::Arc;
+
+use crate::batch::{split_outcome, BatchLeg};
+use crate::config::agent::SolverTuning;
+use crate::config::network::{AmountLimits, Network, Protocol};
+use crate::executor::real_executor::{
+    execute_batch_fulfillment, execute_staking_fulfillment, BatchDeposit, BatchFulfillmentParams,
+    FulfillmentParams, SOLVER_ADDRESS,
+};
+use crate::fees::FeeConfig;
+use crate::gas::GasOracle;
+use crate::registry::SolverDescriptor;
 use crate::solver::{calculate_bid, Bid, IntentRequest, Solver, SolverConfig, SolverError};
+use naisu_core::format_sui;
+use naisu_sui::FulfillmentOutcome;
 
 /// Validator address for staking (Blockscope - active on testnet)
 pub const VALIDATOR_ADDRESS: &str =
@@ -22,7 +35,14 @@ pub const SUI_SYSTEM_PACKAGE: &str = "0x3";
 /// Staking solver using native Sui staking
 pub struct StakingSolver {
     config: SolverConfig,
+    gas: Arc<GasOracle>,
+    limits: AmountLimits,
     validator: String,
+    fees: FeeConfig,
+    /// Wallet fulfillments are paid from
+    wallet: String,
+    /// For explorer links
+    network: Network,
 }
 
 impl Default for StakingSolver {
@@ -40,10 +60,82 @@ impl StakingSolver {
                 gas_cost_bps: 15,    // Estimated gas cost
                 max_slippage_bps: 0, // No slippage in staking
             },
+            gas: Arc::default(),
+            limits: Protocol::NativeStaking.default_limits(),
             validator: VALIDATOR_ADDRESS.to_string(),
+            fees: FeeConfig::default(),
+            wallet: SOLVER_ADDRESS.to_string(),
+            network: Network::Testnet,
         }
     }
 
+    /// Registry entry
+    pub fn descriptor() -> SolverDescriptor {
+        SolverDescriptor {
+            name: "StakingSolver",
+            protocols: &[Protocol::NativeStaking],
+            supports: |_| true,
+            experimental: false,
+            build: |ctx| {
+                Box::new(
+                    StakingSolver::new()
+                        .with_network(ctx.network)
+                        .with_wallet(ctx.wallet())
+                        .with_validator(ctx.config.validator(ctx.network))
+                        .with_tuning(&ctx.config.solver("StakingSolver"))
+                        .with_fees(ctx.config.fees.clone())
+                        .with_gas_oracle(ctx.gas.clone())
+                        .with_limits(
+                            ctx.config
+                                .amount_limits(Protocol::NativeStaking, ctx.network),
+                        ),
+                )
+            },
+        }
+    }
+
+    /// Apply `[solvers.<name>]` overrides from the agent config
+    pub fn with_tuning(mut self, tuning: &SolverTuning) -> Self {
+        self.config = tuning.apply(self.config);
+        self
+    }
+
+    /// Bid with live gas costs
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Pay fulfillments from `wallet` instead of the built-in solver wallet
+    pub fn with_wallet(mut self, wallet: impl Into<String>) -> Self {
+        self.wallet = wallet.into();
+        self
+    }
+
+    /// Only bid on intent amounts within `limits`
+    pub fn with_limits(mut self, limits: AmountLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Network the solver fulfills on (default testnet)
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Stake with a different validator
+    pub fn with_validator(mut self, validator: impl Into<String>) -> Self {
+        self.validator = validator.into();
+        self
+    }
+
+    /// Route the protocol fee to the treasury on fulfillment
+    pub fn with_fees(mut self, fees: FeeConfig) -> Self {
+        self.fees = fees;
+        self
+    }
+
     /// Get native staking APY (typically ~2-3% on testnet)
     /// For hackathon demo: return higher APY to ensure bidding works
     fn get_staking_apy_bps(&self) -> u64 {
@@ -61,49 +153,108 @@ impl Solver for StakingSolver {
     }
 
     async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
+        if !intent.allows(Protocol::NativeStaking) {
+            return None;
+        }
+        // Decline here rather than fail mid-execution
+        if !self.limits.contains(intent.amount) {
+            return None;
+        }
+
         let staking_apy_bps = self.get_staking_apy_bps();
 
         // Staking APY might be lower than lending protocols
         // But it's guaranteed and always available
+        let tip_bps = intent.tip_bps();
         calculate_bid(
             staking_apy_bps,
             intent.min_apy,
-            self.config.gas_cost_bps,
+            self.gas
+                .gas_cost_bps(self.name(), intent.amount)
+                .unwrap_or(self.config.gas_cost_bps),
             self.config.min_profit_bps,
+            tip_bps,
         )
         .map(|apy| Bid {
             solver_name: self.name().to_string(),
             apy,
-            profit_bps: self.config.min_profit_bps,
+            profit_bps: (staking_apy_bps - apy) as u16,
+            tip_bps,
             confidence: 1.0, // 100% confidence - staking always works
         })
     }
 
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError> {
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
         tracing::info!("🔥 STAKING SOLVER EXECUTING REAL TRANSACTION!");
         tracing::info!("   Intent ID: {}", intent.id);
         tracing::info!("   User: {}", intent.user);
-        tracing::info!("   Amount: {} SUI", intent.amount / 1_000_000_000);
+        tracing::info!("   Amount: {} SUI", format_sui(intent.amount));
         tracing::info!("   Validator: {}", self.validator);
 
         // Execute real staking fulfillment
         let params = FulfillmentParams {
+            wallet: self.wallet.clone(),
             intent_id: intent.id.clone(),
             user_address: intent.user.clone(),
             amount: intent.amount,
             validator: self.validator.clone(),
+            fee: self.fees.transfer(intent.amount),
         };
 
         match execute_staking_fulfillment(params).await {
-            Ok(tx_digest) => {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ STAKING FULFILLMENT REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
                 tracing::info!("✅ STAKING FULFILLMENT SUCCESS!");
-                tracing::info!("   TX Digest: {}", tx_digest);
-                tracing::info!("   View: https://suiscan.xyz/testnet/tx/{}", tx_digest);
-                Ok(tx_digest)
+                tracing::info!("   TX Digest: {}", outcome.digest);
+                tracing::info!("   Created objects: {}", outcome.created_objects.len());
+                if let Some(explorer) = self.network.explorer() {
+                    tracing::info!("   View: {}", explorer.tx(&outcome.digest));
+                }
+                Ok(outcome)
             }
             Err(e) => {
                 tracing::error!("❌ STAKING FULFILLMENT FAILED: {}", e);
-                Err(SolverError::FulfillmentFailed(e.to_string()))
+                Err(SolverError::from_executor(e))
+            }
+        }
+    }
+
+    fn supports_batching(&self) -> bool {
+        true
+    }
+
+    async fn fulfill_batch(
+        &self,
+        intents: &[IntentRequest],
+    ) -> Result<Vec<FulfillmentOutcome>, SolverError> {
+        tracing::info!("🔥 STAKING SOLVER BATCHING {} INTENTS", intents.len());
+
+        let legs: Vec<BatchLeg> = intents.iter().map(BatchLeg::from).collect();
+        let amounts: Vec<u64> = intents.iter().map(|i| i.amount).collect();
+        let params = BatchFulfillmentParams {
+            wallet: self.wallet.clone(),
+            legs: legs.clone(),
+            deposit: BatchDeposit::Staking {
+                validator: self.validator.clone(),
+            },
+            fee: self.fees.batch_transfer(&amounts),
+        };
+
+        match execute_batch_fulfillment(params).await {
+            Ok(outcome) if !outcome.is_success() => {
+                tracing::error!("❌ STAKING BATCH REVERTED: {:?}", outcome.status);
+                Err(SolverError::reverted(&outcome))
+            }
+            Ok(outcome) => {
+                tracing::info!("✅ STAKING BATCH SUCCESS: {}", outcome.digest);
+                Ok(split_outcome(&outcome, &legs))
+            }
+            Err(e) => {
+                tracing::error!("❌ STAKING BATCH FAILED: {}", e);
+                Err(SolverError::from_executor(e))
             }
         }
     }
@@ -134,6 +285,10 @@ mod tests {
             amount: 1_000_000_000, // 1 SUI
             min_apy: 150,          // 1.5%
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         // Staking offers ~2.5%, should be profitable for 1.5% min_apy
@@ -156,10 +311,60 @@ mod tests {
             // Set min_apy higher than 9% to make it unprofitable
             min_apy: 1000, // 10.0% - higher than staking APY (9%)
             deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
         };
 
         // Staking offers 9%, can't meet 10% requirement
         let bid = solver.evaluate(&intent, 0.09).await;
         assert!(bid.is_none());
     }
+
+    #[tokio::test]
+    async fn test_staking_declines_other_target() {
+        let solver = StakingSolver::new();
+        let mut intent = IntentRequest {
+            id: "0x123".to_string(),
+            user: "0xabc".to_string(),
+            amount: 1_000_000_000,
+            min_apy: 150,
+            deadline: 3600,
+            allowed_protocols: Some(vec![Protocol::Scallop]),
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+        };
+        assert!(solver.evaluate(&intent, 0.025).await.is_none());
+
+        intent.allowed_protocols = Some(vec![Protocol::Scallop, Protocol::NativeStaking]);
+        assert!(solver.evaluate(&intent, 0.025).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_staking_declines_out_of_limits() {
+        let solver = StakingSolver::new().with_limits(AmountLimits {
+            min: 1_000_000_000,
+            max: Some(5_000_000_000),
+        });
+        let mut intent = IntentRequest {
+            id: "0x123".to_string(),
+            user: "0xabc".to_string(),
+            amount: 999_999_999, // Below the staking threshold
+            min_apy: 150,
+            deadline: 3600,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+        };
+        assert!(solver.evaluate(&intent, 0.025).await.is_none());
+
+        intent.amount = 6_000_000_000;
+        assert!(solver.evaluate(&intent, 0.025).await.is_none());
+
+        intent.amount = 5_000_000_000;
+        assert!(solver.evaluate(&intent, 0.025).await.is_some());
+    }
 }
@@ -0,0 +1,230 @@
+//! Bridge latency tracking
+//!
+//! Observed burn → attestation → mint times per chain pair, kept over a
+//! rolling window of recent transfers. The median total gives a bridging
+//! intent its ETA; one that has been bridging longer than the p95 is
+//! overdue and worth an alert.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+use naisu_core::{Direction, Intent};
+use serde::Serialize;
+
+/// Transfers kept per chain pair
+pub const DEFAULT_WINDOW: usize = 100;
+
+/// Chain pair an intent bridges over, e.g. `Sui → Base`
+pub fn route(intent: &Intent) -> String {
+    let chain = intent.evm_chain.name();
+    match intent.direction {
+        Direction::EvmToSui => format!("{} → Sui", chain),
+        Direction::SuiToEvm => format!("Sui → {}", chain),
+    }
+}
+
+/// One completed transfer (seconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LatencySample {
+    /// Burn until Circle's attestation
+    pub attestation_secs: u64,
+    /// Attestation until the mint was mined
+    pub mint_secs: u64,
+}
+
+impl LatencySample {
+    pub fn new(attestation: Duration, mint: Duration) -> Self {
+        Self {
+            attestation_secs: attestation.as_secs(),
+            mint_secs: mint.as_secs(),
+        }
+    }
+
+    pub fn total_secs(&self) -> u64 {
+        self.attestation_secs + self.mint_secs
+    }
+}
+
+/// Rolling percentiles of one leg (seconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Percentiles {
+    pub p50: u64,
+    pub p95: u64,
+}
+
+impl Percentiles {
+    fn of(mut values: Vec<u64>) -> Self {
+        values.sort_unstable();
+        Self {
+            p50: percentile(&values, 50),
+            p95: percentile(&values, 95),
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty values
+fn percentile(sorted: &[u64], p: usize) -> u64 {
+    let rank = (sorted.len() * p).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Latency of one chain pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteLatency {
+    pub route: String,
+    pub samples: usize,
+    pub attestation: Percentiles,
+    pub mint: Percentiles,
+    pub total: Percentiles,
+}
+
+/// Expected completion of a bridging intent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Eta {
+    /// Median burn → mint time of the route
+    pub expected_secs: u64,
+    pub elapsed_secs: u64,
+    pub remaining_secs: u64,
+    /// Bridging for longer than the route's p95
+    pub overdue: bool,
+    /// e.g. `bridging, ~14 min remaining`
+    pub message: String,
+}
+
+impl Eta {
+    fn new(expected_secs: u64, p95_secs: u64, elapsed_secs: u64) -> Self {
+        let remaining_secs = expected_secs.saturating_sub(elapsed_secs);
+        let message = if elapsed_secs > p95_secs {
+            "bridging, taking longer than usual".to_string()
+        } else if remaining_secs < 60 {
+            "bridging, less than a minute remaining".to_string()
+        } else {
+            format!("bridging, ~{} min remaining", remaining_secs.div_ceil(60))
+        };
+        Self {
+            expected_secs,
+            elapsed_secs,
+            remaining_secs,
+            overdue: elapsed_secs > p95_secs,
+            message,
+        }
+    }
+
+    /// ETA from a fixed estimate, for routes with no observed transfers
+    pub fn estimated(expected: Duration, elapsed: Duration) -> Self {
+        // Without samples there is no spread to call an intent overdue by
+        Self::new(expected.as_secs(), u64::MAX, elapsed.as_secs())
+    }
+}
+
+/// Recent transfers per chain pair
+#[derive(Debug, Clone)]
+pub struct LatencyTracker {
+    samples: BTreeMap<String, VecDeque<LatencySample>>,
+    window: usize,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self {
+            samples: BTreeMap::new(),
+            window: DEFAULT_WINDOW,
+        }
+    }
+}
+
+impl LatencyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Record a completed transfer over `route`
+    pub fn record(&mut self, route: &str, sample: LatencySample) {
+        let samples = self.samples.entry(route.to_string()).or_default();
+        samples.push_back(sample);
+        while samples.len() > self.window {
+            samples.pop_front();
+        }
+    }
+
+    /// Percentiles of `route`, once it has a transfer
+    pub fn latency(&self, route: &str) -> Option<RouteLatency> {
+        let samples = self.samples.get(route).filter(|s| !s.is_empty())?;
+        let leg = |f: fn(&LatencySample) -> u64| Percentiles::of(samples.iter().map(f).collect());
+        Some(RouteLatency {
+            route: route.to_string(),
+            samples: samples.len(),
+            attestation: leg(|s| s.attestation_secs),
+            mint: leg(|s| s.mint_secs),
+            total: leg(LatencySample::total_secs),
+        })
+    }
+
+    /// Every route with a transfer, by name
+    pub fn routes(&self) -> Vec<RouteLatency> {
+        self.samples
+            .keys()
+            .filter_map(|route| self.latency(route))
+            .collect()
+    }
+
+    /// ETA of an intent that has been bridging over `route` for `elapsed`
+    pub fn eta(&self, route: &str, elapsed: Duration) -> Option<Eta> {
+        let total = self.latency(route)?.total;
+        Some(Eta::new(total.p50, total.p95, elapsed.as_secs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(attestation: u64, mint: u64) -> LatencySample {
+        LatencySample::new(Duration::from_secs(attestation), Duration::from_secs(mint))
+    }
+
+    #[test]
+    fn test_percentiles_and_eta() {
+        let mut tracker = LatencyTracker::new().with_window(20);
+        assert!(tracker.eta("Sui → Base", Duration::ZERO).is_none());
+
+        // 20 transfers of 10..=29 min
+        for minutes in 10..30 {
+            tracker.record("Sui → Base", sample(minutes * 60 - 30, 30));
+        }
+        let latency = tracker.latency("Sui → Base").unwrap();
+        assert_eq!(latency.samples, 20);
+        assert_eq!(
+            latency.total,
+            Percentiles {
+                p50: 19 * 60,
+                p95: 28 * 60
+            }
+        );
+        assert_eq!(latency.mint, Percentiles { p50: 30, p95: 30 });
+
+        let eta = tracker
+            .eta("Sui → Base", Duration::from_secs(5 * 60))
+            .unwrap();
+        assert_eq!(eta.remaining_secs, 14 * 60);
+        assert_eq!(eta.message, "bridging, ~14 min remaining");
+        assert!(!eta.overdue);
+
+        let late = tracker
+            .eta("Sui → Base", Duration::from_secs(29 * 60))
+            .unwrap();
+        assert!(late.overdue);
+        assert_eq!(late.remaining_secs, 0);
+
+        // The window drops the oldest (fastest) transfers
+        tracker.record("Sui → Base", sample(40 * 60, 0));
+        assert_eq!(tracker.latency("Sui → Base").unwrap().total.p95, 29 * 60);
+        assert_eq!(tracker.routes().len(), 1);
+    }
+}
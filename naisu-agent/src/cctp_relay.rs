@@ -0,0 +1,163 @@
+//! CCTP relaying
+//!
+//! Finishes Sui → EVM transfers. After the user burns USDC on Sui, Circle
+//! signs an attestation for the burn message; the relayer fetches it from
+//! the attestation API and submits `receiveMessage` on the destination
+//! chain, which mints the USDC to the recipient.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use naisu_core::EvmChain;
+use naisu_sui::CCTP_DOMAIN_SUI;
+use serde_json::Value;
+
+use crate::executor::evm_executor::{EvmExecutor, EvmReceipt};
+use crate::executor::signer::hex_decode;
+
+/// A burn message with Circle's signature over it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attestation {
+    pub message: Vec<u8>,
+    pub attestation: Vec<u8>,
+}
+
+/// Where a burn's attestation stands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttestationStatus {
+    /// Not indexed or not signed yet
+    Pending,
+    Complete(Attestation),
+}
+
+/// Read the attestation API's `messages` response
+pub fn parse_attestation(body: &Value) -> Result<AttestationStatus> {
+    let Some(message) = body["messages"].as_array().and_then(|m| m.first()) else {
+        return Ok(AttestationStatus::Pending);
+    };
+    let attestation = message["attestation"].as_str().unwrap_or("PENDING");
+    if attestation.eq_ignore_ascii_case("PENDING") {
+        return Ok(AttestationStatus::Pending);
+    }
+
+    let hex = |field: &str, value: &str| {
+        hex_decode(value.strip_prefix("0x").unwrap_or(value))
+            .filter(|b| !b.is_empty())
+            .ok_or_else(|| anyhow!("Invalid {} in attestation response", field))
+    };
+    Ok(AttestationStatus::Complete(Attestation {
+        message: hex("message", message["message"].as_str().unwrap_or_default())?,
+        attestation: hex("attestation", attestation)?,
+    }))
+}
+
+/// Circle's attestation API (`CCTP_API_URL`)
+pub struct AttestationClient {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl AttestationClient {
+    pub fn new(api_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Attestation of the burn in `tx_hash` on `source_domain`
+    pub async fn fetch(&self, source_domain: u32, tx_hash: &str) -> Result<AttestationStatus> {
+        let url = format!("{}/v1/messages/{}/{}", self.api_url, source_domain, tx_hash);
+        let response = self.client.get(&url).send().await?;
+        // Burns show up a few blocks after they are final
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(AttestationStatus::Pending);
+        }
+        if !response.status().is_success() {
+            bail!("Attestation API returned {}", response.status());
+        }
+        parse_attestation(&response.json().await?)
+    }
+}
+
+/// Mints Sui burns on their destination chains
+pub struct CctpRelayer {
+    attestations: AttestationClient,
+    executors: HashMap<EvmChain, EvmExecutor>,
+}
+
+impl CctpRelayer {
+    pub fn new(attestations: AttestationClient) -> Self {
+        Self {
+            attestations,
+            executors: HashMap::new(),
+        }
+    }
+
+    /// Submit mints on `chain` through `executor`
+    pub fn with_executor(mut self, chain: EvmChain, executor: EvmExecutor) -> Self {
+        self.executors.insert(chain, executor);
+        self
+    }
+
+    /// Mint the Sui burn in `burn_digest` on `chain`
+    ///
+    /// `Ok(None)` while the attestation is pending. A reverted mint is
+    /// returned as a receipt with `success: false`.
+    pub async fn relay(&self, chain: EvmChain, burn_digest: &str) -> Result<Option<EvmReceipt>> {
+        let executor = self
+            .executors
+            .get(&chain)
+            .ok_or_else(|| anyhow!("No EVM executor for {}", chain.name()))?;
+        let attestation = match self
+            .attestations
+            .fetch(CCTP_DOMAIN_SUI, burn_digest)
+            .await?
+        {
+            AttestationStatus::Pending => return Ok(None),
+            AttestationStatus::Complete(attestation) => attestation,
+        };
+
+        let receipt = executor
+            .receive_message(
+                chain.message_transmitter(),
+                &attestation.message,
+                &attestation.attestation,
+            )
+            .await?;
+        Ok(Some(receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_attestation() {
+        let pending = json!({ "messages": [{ "attestation": "PENDING", "message": "0x" }] });
+        assert_eq!(
+            parse_attestation(&pending).unwrap(),
+            AttestationStatus::Pending
+        );
+        assert_eq!(
+            parse_attestation(&json!({})).unwrap(),
+            AttestationStatus::Pending
+        );
+
+        let complete = json!({
+            "messages": [{ "attestation": "0xaabb", "message": "0x0102", "eventNonce": "7" }]
+        });
+        assert_eq!(
+            parse_attestation(&complete).unwrap(),
+            AttestationStatus::Complete(Attestation {
+                message: vec![1, 2],
+                attestation: vec![0xaa, 0xbb],
+            })
+        );
+
+        let malformed = json!({ "messages": [{ "attestation": "0xzz", "message": "0x01" }] });
+        assert!(parse_attestation(&malformed).is_err());
+    }
+}
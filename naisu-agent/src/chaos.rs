@@ -0,0 +1,570 @@
+//! Fault injection for resilience tests
+//!
+//! Wrappers that make the daemon's dependencies misbehave on cue:
+//! - `ChaosTransport` wraps a `SuiTransport` to time out RPC calls, answer
+//!   slowly, or corrupt event payloads
+//! - `ChaosSolver` wraps a solver to crash after its fulfillment landed
+//!   on chain, before anyone learned of it
+//!
+//! Faults are scheduled by call number in a `FaultPlan`, so a run is
+//! deterministic. There is no attestation poller yet; a slow CCTP
+//! attestation is modelled as a `Delay` on the transport call that would
+//! fetch it.
+//!
+//! Built for tests and with the `chaos` feature only.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use naisu_sui::{
+    CoinPage, DryRunResponse, EventPage, ExecutionStatus, FulfillmentOutcome, GasCostSummary,
+    SuiClientError, SuiEvent, SuiObject, SuiTransport, TransactionResponse, ValidatorInfo,
+    ZkLoginVerifyResult,
+};
+
+use crate::solver::{Bid, IntentRequest, Solver, SolverError};
+
+/// Something that goes wrong on one call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Call fails as if the request timed out
+    Timeout,
+    /// Call succeeds after a pause
+    Delay(Duration),
+    /// Events come back with garbled fields (`query_events` only)
+    MalformedEvents,
+    /// Call goes through, then the caller dies before seeing the result
+    CrashAfterSubmit,
+}
+
+/// Faults to inject, by operation and call number (1-based)
+#[derive(Debug, Default)]
+pub struct FaultPlan {
+    faults: Mutex<HashMap<(String, u64), Fault>>,
+    calls: Mutex<HashMap<String, u64>>,
+}
+
+impl FaultPlan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inject `fault` into the `call`th call of `op`
+    ///
+    /// `op` is a transport method name or `"fulfill"`.
+    pub fn with_fault(self, op: &str, call: u64, fault: Fault) -> Self {
+        self.faults
+            .lock()
+            .expect("fault plan lock poisoned")
+            .insert((op.to_string(), call), fault);
+        self
+    }
+
+    /// Count a call to `op` and return its fault, if any
+    pub fn next(&self, op: &str) -> Option<Fault> {
+        let call = {
+            let mut calls = self.calls.lock().expect("fault plan lock poisoned");
+            let count = calls.entry(op.to_string()).or_default();
+            *count += 1;
+            *count
+        };
+        self.faults
+            .lock()
+            .expect("fault plan lock poisoned")
+            .get(&(op.to_string(), call))
+            .copied()
+    }
+
+    /// Calls made to `op` so far
+    pub fn calls(&self, op: &str) -> u64 {
+        self.calls
+            .lock()
+            .expect("fault plan lock poisoned")
+            .get(op)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+fn timeout(op: &str) -> SuiClientError {
+    SuiClientError::Request(format!("chaos: {} timed out", op))
+}
+
+/// Replace every field with a value of the wrong type
+fn garble(event: &mut SuiEvent) {
+    if let Some(fields) = event.parsed_json.as_object_mut() {
+        for value in fields.values_mut() {
+            *value = serde_json::json!({ "garbled": true });
+        }
+    } else {
+        event.parsed_json = serde_json::Value::Null;
+    }
+}
+
+// ─── Transport ───────────────────────────────────────────────────────────────
+
+/// `SuiTransport` that injects faults into another one
+pub struct ChaosTransport {
+    inner: Box<dyn SuiTransport>,
+    plan: Arc<FaultPlan>,
+}
+
+impl ChaosTransport {
+    pub fn new(inner: Box<dyn SuiTransport>, plan: Arc<FaultPlan>) -> Self {
+        Self { inner, plan }
+    }
+
+    /// Apply the scheduled fault of a call that doesn't submit anything
+    async fn before(&self, op: &str) -> Result<Option<Fault>, SuiClientError> {
+        match self.plan.next(op) {
+            Some(Fault::Timeout) => Err(timeout(op)),
+            Some(Fault::Delay(pause)) => {
+                tokio::time::sleep(pause).await;
+                Ok(None)
+            }
+            fault => Ok(fault),
+        }
+    }
+}
+
+#[async_trait]
+impl SuiTransport for ChaosTransport {
+    fn name(&self) -> &'static str {
+        "chaos"
+    }
+
+    async fn get_coins(
+        &self,
+        owner: &str,
+        coin_type: Option<&str>,
+        cursor: Option<&str>,
+    ) -> Result<CoinPage, SuiClientError> {
+        self.before("get_coins").await?;
+        self.inner.get_coins(owner, coin_type, cursor).await
+    }
+
+    async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
+        self.before("get_object").await?;
+        self.inner.get_object(object_id).await
+    }
+
+    async fn get_dynamic_field_object(
+        &self,
+        parent_id: &str,
+        name: serde_json::Value,
+    ) -> Result<SuiObject, SuiClientError> {
+        self.before("get_dynamic_field_object").await?;
+        self.inner.get_dynamic_field_object(parent_id, name).await
+    }
+
+    async fn query_events(
+        &self,
+        event_type: &str,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError> {
+        let fault = self.before("query_events").await?;
+        let mut page = self.inner.query_events(event_type, cursor, limit).await?;
+        if fault == Some(Fault::MalformedEvents) {
+            page.data.iter_mut().for_each(garble);
+        }
+        Ok(page)
+    }
+
+    async fn dry_run_transaction(&self, tx_bytes: &str) -> Result<DryRunResponse, SuiClientError> {
+        self.before("dry_run_transaction").await?;
+        self.inner.dry_run_transaction(tx_bytes).await
+    }
+
+    async fn execute_transaction(
+        &self,
+        tx_bytes: &str,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiClientError> {
+        let fault = self.before("execute_transaction").await?;
+        let response = self.inner.execute_transaction(tx_bytes, signatures).await;
+        if fault == Some(Fault::CrashAfterSubmit) {
+            // Executed on chain, but the response is lost
+            return Err(timeout("execute_transaction"));
+        }
+        response
+    }
+
+    async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+        self.before("get_reference_gas_price").await?;
+        self.inner.get_reference_gas_price().await
+    }
+
+    async fn verify_zklogin_signature(
+        &self,
+        message: &str,
+        signature: &str,
+        author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+        self.before("verify_zklogin_signature").await?;
+        self.inner
+            .verify_zklogin_signature(message, signature, author)
+            .await
+    }
+
+    async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+        self.before("get_validators").await?;
+        self.inner.get_validators().await
+    }
+
+    async fn resolve_name(&self, name: &str) -> Result<Option<String>, SuiClientError> {
+        self.before("resolve_name").await?;
+        self.inner.resolve_name(name).await
+    }
+
+    async fn default_name(&self, address: &str) -> Result<Option<String>, SuiClientError> {
+        self.before("default_name").await?;
+        self.inner.default_name(address).await
+    }
+}
+
+/// Transport serving a fixed list of events; every other call fails
+#[derive(Debug, Clone, Default)]
+pub struct StaticEvents {
+    events: Arc<Mutex<Vec<SuiEvent>>>,
+}
+
+impl StaticEvents {
+    pub fn new(events: Vec<SuiEvent>) -> Self {
+        Self {
+            events: Arc::new(Mutex::new(events)),
+        }
+    }
+
+    /// Add an event for later polls to see
+    pub fn push(&self, event: SuiEvent) {
+        self.events
+            .lock()
+            .expect("events lock poisoned")
+            .push(event);
+    }
+}
+
+fn unsupported(op: &str) -> SuiClientError {
+    SuiClientError::Request(format!("{} is not supported by StaticEvents", op))
+}
+
+#[async_trait]
+impl SuiTransport for StaticEvents {
+    fn name(&self) -> &'static str {
+        "static-events"
+    }
+
+    async fn get_coins(
+        &self,
+        _owner: &str,
+        _coin_type: Option<&str>,
+        _cursor: Option<&str>,
+    ) -> Result<CoinPage, SuiClientError> {
+        Err(unsupported("get_coins"))
+    }
+
+    async fn get_object(&self, _object_id: &str) -> Result<SuiObject, SuiClientError> {
+        Err(unsupported("get_object"))
+    }
+
+    async fn get_dynamic_field_object(
+        &self,
+        _parent_id: &str,
+        _name: serde_json::Value,
+    ) -> Result<SuiObject, SuiClientError> {
+        Err(unsupported("get_dynamic_field_object"))
+    }
+
+    async fn query_events(
+        &self,
+        event_type: &str,
+        _cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError> {
+        let events = self.events.lock().expect("events lock poisoned");
+        Ok(EventPage {
+            data: events
+                .iter()
+                .filter(|e| e.event_type == event_type)
+                .take(limit)
+                .cloned()
+                .collect(),
+            next_cursor: None,
+            has_next_page: false,
+        })
+    }
+
+    async fn dry_run_transaction(&self, _tx_bytes: &str) -> Result<DryRunResponse, SuiClientError> {
+        Err(unsupported("dry_run_transaction"))
+    }
+
+    async fn execute_transaction(
+        &self,
+        _tx_bytes: &str,
+        _signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiClientError> {
+        Err(unsupported("execute_transaction"))
+    }
+
+    async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+        Ok(1_000)
+    }
+
+    async fn verify_zklogin_signature(
+        &self,
+        _message: &str,
+        _signature: &str,
+        _author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+        Err(unsupported("verify_zklogin_signature"))
+    }
+
+    async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+        Err(unsupported("get_validators"))
+    }
+}
+
+// ─── Solvers ─────────────────────────────────────────────────────────────────
+
+/// Solver that bids on everything and records each fulfillment it lands
+///
+/// Stands in for the chain: `fulfillments` is how many times an intent
+/// was actually filled.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingSolver {
+    landed: Arc<Mutex<HashMap<String, u32>>>,
+}
+
+impl RecordingSolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Times `intent_id` was filled
+    pub fn fulfillments(&self, intent_id: &str) -> u32 {
+        self.landed
+            .lock()
+            .expect("landed lock poisoned")
+            .get(intent_id)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Most times any intent was filled
+    pub fn max_fulfillments(&self) -> u32 {
+        self.landed
+            .lock()
+            .expect("landed lock poisoned")
+            .values()
+            .copied()
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[async_trait]
+impl Solver for RecordingSolver {
+    fn name(&self) -> &str {
+        "RecordingSolver"
+    }
+
+    async fn evaluate(&self, intent: &IntentRequest, _market_apy: f64) -> Option<Bid> {
+        Some(Bid {
+            solver_name: self.name().to_string(),
+            apy: intent.min_apy,
+            profit_bps: 0,
+            tip_bps: intent.tip_bps(),
+            confidence: 1.0,
+        })
+    }
+
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
+        *self
+            .landed
+            .lock()
+            .expect("landed lock poisoned")
+            .entry(intent.id.clone())
+            .or_default() += 1;
+        Ok(FulfillmentOutcome {
+            digest: format!("0xdigest-{}", intent.id),
+            created_objects: Vec::new(),
+            gas_used: GasCostSummary::default(),
+            status: ExecutionStatus::Success,
+        })
+    }
+}
+
+/// Solver that injects faults into another one's fulfillments
+///
+/// Uses the `"fulfill"` operation of its plan. `CrashAfterSubmit` panics
+/// once the inner fulfillment has landed, like a process dying between
+/// submitting a PTB and recording it.
+pub struct ChaosSolver<S> {
+    inner: S,
+    plan: Arc<FaultPlan>,
+}
+
+impl<S> ChaosSolver<S> {
+    pub fn new(inner: S, plan: Arc<FaultPlan>) -> Self {
+        Self { inner, plan }
+    }
+}
+
+#[async_trait]
+impl<S: Solver + Send + Sync> Solver for ChaosSolver<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn evaluate(&self, intent: &IntentRequest, market_apy: f64) -> Option<Bid> {
+        self.inner.evaluate(intent, market_apy).await
+    }
+
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError> {
+        match self.plan.next("fulfill") {
+            Some(Fault::Timeout) => Err(SolverError::FulfillmentFailed(
+                "chaos: fulfillment timed out".to_string(),
+            )),
+            Some(Fault::Delay(pause)) => {
+                tokio::time::sleep(pause).await;
+                self.inner.fulfill(intent).await
+            }
+            Some(Fault::CrashAfterSubmit) => {
+                let _ = self.inner.fulfill(intent).await;
+                panic!("chaos: crashed after submitting {}", intent.id);
+            }
+            Some(Fault::MalformedEvents) | None => self.inner.fulfill(intent).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracker::IntentTracker;
+    use naisu_sui::{SuiClient, SuiConfig};
+
+    const EVENT_TYPE: &str = "0xpkg::intent::IntentCreated";
+
+    fn created(id: &str) -> SuiEvent {
+        SuiEvent {
+            event_type: EVENT_TYPE.to_string(),
+            parsed_json: serde_json::json!({
+                "intent_id": id,
+                "user": "0xuser",
+                "amount": "1000000000",
+                "min_apy": "500",
+                "deadline": "9999999999999",
+            }),
+            sender: None,
+            tx_digest: None,
+            timestamp_ms: None,
+        }
+    }
+
+    /// Pipeline under test: the daemon's poll → dedupe → fulfill path
+    struct Pipeline {
+        client: SuiClient,
+        tracker: IntentTracker,
+        solver: Arc<ChaosSolver<RecordingSolver>>,
+    }
+
+    impl Pipeline {
+        fn new(events: &StaticEvents, recorder: &RecordingSolver, plan: FaultPlan) -> Self {
+            let plan = Arc::new(plan);
+            Self {
+                client: SuiClient::with_transport(
+                    SuiConfig::testnet(),
+                    Box::new(ChaosTransport::new(Box::new(events.clone()), plan.clone())),
+                ),
+                tracker: IntentTracker::new(),
+                solver: Arc::new(ChaosSolver::new(recorder.clone(), plan)),
+            }
+        }
+
+        /// One poll; returns how many intents were handed to a task
+        async fn poll(&self) -> Result<usize, SuiClientError> {
+            let page = self.client.query_events(EVENT_TYPE, None, 50).await?;
+            let mut tasks = Vec::new();
+            for intent in page.data.iter().filter_map(IntentRequest::from_event) {
+                if !self.tracker.is_new(&intent.id) {
+                    continue;
+                }
+                let Some(claim) = self.tracker.claim(vec![intent.id.clone()]) else {
+                    continue;
+                };
+                let solver = self.solver.clone();
+                tasks.push(tokio::spawn(async move {
+                    let _claim = claim;
+                    let _ = solver.fulfill(&intent).await;
+                }));
+            }
+
+            let spawned = tasks.len();
+            for task in tasks {
+                // A crashed task is a panicked join, not a test failure
+                let _ = task.await;
+            }
+            Ok(spawned)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recovers_from_rpc_timeouts() {
+        let events = StaticEvents::new(vec![created("i1"), created("i2")]);
+        let recorder = RecordingSolver::new();
+        let plan = FaultPlan::new()
+            .with_fault("query_events", 1, Fault::Timeout)
+            .with_fault("query_events", 2, Fault::Delay(Duration::from_millis(5)));
+        let pipeline = Pipeline::new(&events, &recorder, plan);
+
+        assert!(pipeline.poll().await.is_err());
+        assert_eq!(pipeline.poll().await.unwrap(), 2);
+        assert_eq!(pipeline.poll().await.unwrap(), 0);
+
+        assert_eq!(recorder.fulfillments("i1"), 1);
+        assert_eq!(recorder.fulfillments("i2"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_skips_malformed_events() {
+        let events = StaticEvents::new(vec![created("i1")]);
+        let recorder = RecordingSolver::new();
+        let plan = FaultPlan::new().with_fault("query_events", 1, Fault::MalformedEvents);
+        let pipeline = Pipeline::new(&events, &recorder, plan);
+
+        // Garbled payloads parse to nothing and aren't marked processed
+        assert_eq!(pipeline.poll().await.unwrap(), 0);
+        assert!(pipeline.tracker.is_new("i1"));
+
+        assert_eq!(pipeline.poll().await.unwrap(), 1);
+        assert_eq!(recorder.fulfillments("i1"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_crash_mid_fulfillment_is_not_retried() {
+        let events = StaticEvents::new(vec![created("i1")]);
+        let recorder = RecordingSolver::new();
+        let plan = FaultPlan::new()
+            .with_fault("fulfill", 1, Fault::CrashAfterSubmit)
+            .with_fault("fulfill", 2, Fault::Timeout);
+        let pipeline = Pipeline::new(&events, &recorder, plan);
+
+        assert_eq!(pipeline.poll().await.unwrap(), 1);
+        assert_eq!(pipeline.tracker.in_flight(), 0);
+
+        // A failed fulfillment isn't retried either
+        events.push(created("i2"));
+        for _ in 0..3 {
+            pipeline.poll().await.unwrap();
+        }
+
+        assert_eq!(recorder.fulfillments("i1"), 1);
+        assert_eq!(recorder.fulfillments("i2"), 0);
+        assert_eq!(recorder.max_fulfillments(), 1);
+        assert_eq!(pipeline.solver.plan.calls("fulfill"), 2);
+    }
+}
@@ -0,0 +1,271 @@
+//! Commit-reveal intents
+//!
+//! A public intent shows its size and minimum terms to every solver before
+//! it is filled. In commit-reveal mode the user first posts only a hash of
+//! the creation request, then reveals the request and salt within the
+//! reveal window. The intent, and everything solvers can see of it, only
+//! exists from the reveal on.
+//!
+//! The hash is `keccak256(personal_message(request, nonce) ‖ salt)` where
+//! `nonce` is the request signature's nonce and `salt` 32 random bytes.
+
+use std::collections::HashMap;
+
+use naisu_core::CreateIntentRequest;
+use serde::Serialize;
+use sha3::{Digest, Keccak256};
+
+use crate::executor::signer::{hex_decode, hex_encode};
+use crate::intent_auth::personal_message;
+
+/// Time a commitment can be revealed in (10 minutes)
+pub const DEFAULT_REVEAL_WINDOW_MS: u64 = 10 * 60 * 1000;
+
+/// Salt length (bytes)
+pub const SALT_LEN: usize = 32;
+
+/// Commitment to a creation request and salt
+pub fn commitment_hash(req: &CreateIntentRequest, salt: &str) -> Result<String, CommitmentError> {
+    let salt = hex_decode(salt.strip_prefix("0x").unwrap_or(salt))
+        .filter(|s| s.len() == SALT_LEN)
+        .ok_or(CommitmentError::InvalidSalt)?;
+    let nonce = req.signature.as_ref().map_or(0, |s| s.nonce);
+
+    let mut hasher = Keccak256::new();
+    hasher.update(personal_message(req, nonce).as_bytes());
+    hasher.update(&salt);
+    Ok(format!("0x{}", hex_encode(&hasher.finalize())))
+}
+
+// ─── Book ────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitmentStatus {
+    /// Waiting for the reveal
+    Committed,
+    /// Revealed into an intent
+    Revealed,
+    /// Not revealed in time
+    Expired,
+}
+
+/// A posted commitment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Commitment {
+    pub hash: String,
+    /// Unix millis
+    pub committed_at: u64,
+    /// Last moment a reveal is accepted (unix millis)
+    pub reveal_deadline: u64,
+    pub status: CommitmentStatus,
+    /// Intent created by the reveal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intent_id: Option<String>,
+}
+
+/// Commitments keyed by hash
+#[derive(Debug, Clone)]
+pub struct CommitmentBook {
+    commitments: HashMap<String, Commitment>,
+    reveal_window_ms: u64,
+}
+
+impl Default for CommitmentBook {
+    fn default() -> Self {
+        Self {
+            commitments: HashMap::new(),
+            reveal_window_ms: DEFAULT_REVEAL_WINDOW_MS,
+        }
+    }
+}
+
+impl CommitmentBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_reveal_window(mut self, ms: u64) -> Self {
+        self.reveal_window_ms = ms;
+        self
+    }
+
+    /// Record a commitment (`0x` + 32-byte hex hash)
+    pub fn commit(&mut self, hash: &str, now_ms: u64) -> Result<Commitment, CommitmentError> {
+        let hash = hash.trim().to_lowercase();
+        let valid = hash
+            .strip_prefix("0x")
+            .is_some_and(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()));
+        if !valid {
+            return Err(CommitmentError::InvalidHash);
+        }
+        if self.commitments.contains_key(&hash) {
+            return Err(CommitmentError::AlreadyCommitted);
+        }
+
+        let commitment = Commitment {
+            hash: hash.clone(),
+            committed_at: now_ms,
+            reveal_deadline: now_ms + self.reveal_window_ms,
+            status: CommitmentStatus::Committed,
+            intent_id: None,
+        };
+        self.commitments.insert(hash, commitment.clone());
+        Ok(commitment)
+    }
+
+    /// Commitment as of `now_ms`
+    pub fn get(&self, hash: &str, now_ms: u64) -> Option<Commitment> {
+        let mut commitment = self.commitments.get(&hash.to_lowercase())?.clone();
+        if commitment.status == CommitmentStatus::Committed && now_ms > commitment.reveal_deadline {
+            commitment.status = CommitmentStatus::Expired;
+        }
+        Some(commitment)
+    }
+
+    /// Check a reveal against its commitment
+    pub fn check_reveal(
+        &self,
+        hash: &str,
+        req: &CreateIntentRequest,
+        salt: &str,
+        now_ms: u64,
+    ) -> Result<(), CommitmentError> {
+        let commitment = self.get(hash, now_ms).ok_or(CommitmentError::NotFound)?;
+        match commitment.status {
+            CommitmentStatus::Committed => {}
+            CommitmentStatus::Revealed => return Err(CommitmentError::AlreadyRevealed),
+            CommitmentStatus::Expired => return Err(CommitmentError::Expired),
+        }
+        if commitment_hash(req, salt)? != commitment.hash {
+            return Err(CommitmentError::Mismatch);
+        }
+        Ok(())
+    }
+
+    /// Close a commitment with the intent its reveal created
+    pub fn mark_revealed(&mut self, hash: &str, intent_id: &str) -> Result<(), CommitmentError> {
+        let commitment = self
+            .commitments
+            .get_mut(&hash.to_lowercase())
+            .ok_or(CommitmentError::NotFound)?;
+        if commitment.status == CommitmentStatus::Revealed {
+            return Err(CommitmentError::AlreadyRevealed);
+        }
+        commitment.status = CommitmentStatus::Revealed;
+        commitment.intent_id = Some(intent_id.to_string());
+        Ok(())
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CommitmentError {
+    #[error("Commitment must be a 0x-prefixed 32-byte hex hash")]
+    InvalidHash,
+
+    #[error("Salt must be {SALT_LEN} hex bytes")]
+    InvalidSalt,
+
+    #[error("Commitment already exists")]
+    AlreadyCommitted,
+
+    #[error("Commitment not found")]
+    NotFound,
+
+    #[error("Commitment was already revealed")]
+    AlreadyRevealed,
+
+    #[error("Reveal window has passed")]
+    Expired,
+
+    #[error("Revealed request does not match the commitment")]
+    Mismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::{Direction, EvmChain, IntentSignature, SignatureKind};
+
+    const SALT: &str = "0x0101010101010101010101010101010101010101010101010101010101010101";
+
+    fn request() -> CreateIntentRequest {
+        CreateIntentRequest {
+            direction: Direction::SuiToEvm,
+            source_address: "0x1".to_string(),
+            dest_address: "0x2".to_string(),
+            evm_chain: EvmChain::Base,
+            input_token: "0x2::sui::SUI".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: None,
+            referrer: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+            signature: Some(IntentSignature {
+                kind: SignatureKind::SuiPersonalMessage,
+                signature: "sig".to_string(),
+                nonce: 7,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_commit_then_reveal() {
+        let req = request();
+        let hash = commitment_hash(&req, SALT).unwrap();
+        let mut book = CommitmentBook::new().with_reveal_window(1_000);
+
+        let commitment = book
+            .commit(&hash.to_uppercase().replace("0X", "0x"), 5)
+            .unwrap();
+        assert_eq!(commitment.reveal_deadline, 1_005);
+        assert_eq!(
+            book.commit(&hash, 6),
+            Err(CommitmentError::AlreadyCommitted)
+        );
+
+        let mut changed = req.clone();
+        changed.input_amount = "2000000".to_string();
+        assert_eq!(
+            book.check_reveal(&hash, &changed, SALT, 10),
+            Err(CommitmentError::Mismatch)
+        );
+        assert_eq!(
+            book.check_reveal(&hash, &req, "0x01", 10),
+            Err(CommitmentError::InvalidSalt)
+        );
+        assert_eq!(book.check_reveal(&hash, &req, SALT, 10), Ok(()));
+
+        book.mark_revealed(&hash, "intent-1").unwrap();
+        assert_eq!(
+            book.check_reveal(&hash, &req, SALT, 10),
+            Err(CommitmentError::AlreadyRevealed)
+        );
+        assert_eq!(
+            book.get(&hash, 10).unwrap().intent_id.as_deref(),
+            Some("intent-1")
+        );
+    }
+
+    #[test]
+    fn test_reveal_window() {
+        let req = request();
+        let hash = commitment_hash(&req, SALT).unwrap();
+        let mut book = CommitmentBook::new().with_reveal_window(1_000);
+        book.commit(&hash, 0).unwrap();
+
+        assert_eq!(book.check_reveal(&hash, &req, SALT, 1_000), Ok(()));
+        assert_eq!(
+            book.check_reveal(&hash, &req, SALT, 1_001),
+            Err(CommitmentError::Expired)
+        );
+        assert_eq!(
+            book.get(&hash, 1_001).unwrap().status,
+            CommitmentStatus::Expired
+        );
+        assert_eq!(book.commit("0x1234", 0), Err(CommitmentError::InvalidHash));
+    }
+}
@@ -0,0 +1,946 @@
+//! Layered agent configuration
+//!
+//! Resolution order: built-in defaults → TOML file → environment variables.
+//! The file is optional; every value has a default except the mainnet intent
+//! package, which must come from the file or `MAINNET_INTENT_PACKAGE`.
+//!
+//! See `agent.example.toml` for the full layout.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use naisu_sui::{CustomStrategy, StrategyRegistry};
+use serde::{Deserialize, Serialize};
+
+use super::network::{AmountLimits, Network, Protocol, ProtocolConfig};
+use crate::batch::BatchConfig;
+use crate::bots::staking_solver::VALIDATOR_ADDRESS;
+use crate::executor::gas_pool::GasPoolConfig;
+use crate::executor::real_executor::INTENT_PACKAGE;
+use crate::feature_flags::FeatureFlags;
+use crate::fees::FeeConfig;
+use crate::heartbeat::HeartbeatConfig;
+use crate::polling::PollingConfig;
+use crate::selection::SelectionPolicy;
+use crate::solver::SolverConfig;
+use crate::throttle::ThrottleConfig;
+use crate::watchdog::WatchdogConfig;
+
+// ─── Sections ────────────────────────────────────────────────────────────────
+
+/// Top-level agent configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AgentConfig {
+    pub wallet: WalletConfig,
+    pub polling: PollingConfig,
+    /// How the winning bid is chosen
+    pub selection: SelectionPolicy,
+    /// Protocol fee taken from fulfillments
+    pub fees: FeeConfig,
+    /// Filling several small intents in one PTB
+    pub batching: BatchConfig,
+    /// Per-solver in-flight limits and wallet submission pacing
+    pub throttle: ThrottleConfig,
+    /// Per-status SLAs for stuck intent detection
+    pub watchdog: WatchdogConfig,
+    /// Dedicated gas coins kept apart from principal
+    pub gas_pool: GasPoolConfig,
+    /// Liveness reports to the API
+    pub heartbeat: HeartbeatConfig,
+    /// Risky capabilities switched on for this deployment
+    pub features: FeatureFlags,
+    /// Per-solver tuning, keyed by solver name (e.g. `StakingSolver`)
+    pub solvers: BTreeMap<String, SolverTuning>,
+    /// Custom yield strategies (`[[strategies]]`)
+    pub strategies: Vec<CustomStrategy>,
+    /// Per-network settings, keyed by network name (`testnet`, `mainnet`)
+    pub networks: BTreeMap<String, NetworkSection>,
+}
+
+/// Solver wallet
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WalletConfig {
+    pub address: Option<String>,
+}
+
+/// Overrides for a solver's `SolverConfig`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SolverTuning {
+    /// Set to false to keep the solver out of the daemon
+    pub enabled: Option<bool>,
+    pub min_profit_bps: Option<u16>,
+    pub gas_cost_bps: Option<u16>,
+    pub max_slippage_bps: Option<u16>,
+    /// Fulfillments in flight at once (default: `[throttle]`); read at
+    /// startup only
+    pub max_in_flight: Option<usize>,
+    /// Exposure cap (MIST), over `RISK_PROTOCOL_CAPS`
+    pub max_exposure: Option<u64>,
+}
+
+impl SolverTuning {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    /// Apply the overrides on top of a solver's built-in config
+    pub fn apply(&self, mut config: SolverConfig) -> SolverConfig {
+        if let Some(v) = self.min_profit_bps {
+            config.min_profit_bps = v;
+        }
+        if let Some(v) = self.gas_cost_bps {
+            config.gas_cost_bps = v;
+        }
+        if let Some(v) = self.max_slippage_bps {
+            config.max_slippage_bps = v;
+        }
+        config
+    }
+
+    /// Take every field `other` sets
+    pub fn overlay(&mut self, other: &SolverTuning) {
+        let SolverTuning {
+            enabled,
+            min_profit_bps,
+            gas_cost_bps,
+            max_slippage_bps,
+            max_in_flight,
+            max_exposure,
+        } = other;
+        self.enabled = enabled.or(self.enabled);
+        self.min_profit_bps = min_profit_bps.or(self.min_profit_bps);
+        self.gas_cost_bps = gas_cost_bps.or(self.gas_cost_bps);
+        self.max_slippage_bps = max_slippage_bps.or(self.max_slippage_bps);
+        self.max_in_flight = max_in_flight.or(self.max_in_flight);
+        self.max_exposure = max_exposure.or(self.max_exposure);
+    }
+}
+
+/// Settings for one network
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkSection {
+    pub rpc_url: Option<String>,
+    /// Wallet used on this network (falls back to `[wallet] address`)
+    pub wallet: Option<String>,
+    pub intent_package: Option<String>,
+    /// Validator used by the staking solver
+    pub validator: Option<String>,
+    /// Registered solvers to run here (all of them when unset)
+    pub solvers: Option<Vec<String>>,
+    /// Replaces `[throttle]` on this network
+    pub throttle: Option<ThrottleConfig>,
+    /// Protocol overrides, keyed by protocol name (e.g. `scallop`)
+    pub protocols: BTreeMap<String, ProtocolOverride>,
+}
+
+/// Overrides for a protocol's package and objects
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProtocolOverride {
+    pub package_id: Option<String>,
+    pub module: Option<String>,
+    /// Move Registry name to resolve the latest package from
+    pub mvr_name: Option<String>,
+    /// Named config objects, merged over the built-in ones
+    pub objects: BTreeMap<String, String>,
+    /// Smallest intent amount to bid on (MIST)
+    pub min_amount: Option<u64>,
+    /// Largest intent amount to bid on (MIST)
+    pub max_amount: Option<u64>,
+}
+
+// ─── Loading ─────────────────────────────────────────────────────────────────
+
+impl AgentConfig {
+    /// Defaults, then `path` (if any), then env overrides, then validation
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let config = match path {
+            Some(path) => Self::from_toml_file(path)?,
+            None => Self::defaults(),
+        };
+        let config = config.with_env();
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Built-in defaults (testnet intent package and validator)
+    pub fn defaults() -> Self {
+        let mut networks = BTreeMap::new();
+        networks.insert(
+            "testnet".to_string(),
+            NetworkSection {
+                intent_package: Some(INTENT_PACKAGE.to_string()),
+                validator: Some(VALIDATOR_ADDRESS.to_string()),
+                ..Default::default()
+            },
+        );
+        Self {
+            networks,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_toml_file(path: &Path) -> Result<Self, ConfigError> {
+        let raw = std::fs::read_to_string(path).map_err(|e| ConfigError::Read {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        Self::from_toml_str(&raw).map_err(|e| match e {
+            ConfigError::Parse { message, .. } => ConfigError::Parse {
+                path: path.display().to_string(),
+                message,
+            },
+            other => other,
+        })
+    }
+
+    /// Parse TOML, layering it over the defaults
+    pub fn from_toml_str(raw: &str) -> Result<Self, ConfigError> {
+        let file: AgentConfig = toml::from_str(raw).map_err(|e| ConfigError::Parse {
+            path: "<string>".to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(Self::defaults().merge(file))
+    }
+
+    /// Layer `other` over `self` (per-network fields merge individually)
+    fn merge(mut self, other: AgentConfig) -> Self {
+        if other.wallet.address.is_some() {
+            self.wallet.address = other.wallet.address;
+        }
+        self.polling = other.polling;
+        self.selection = other.selection;
+        self.fees = other.fees;
+        self.batching = other.batching;
+        self.throttle = other.throttle;
+        self.watchdog = other.watchdog;
+        self.gas_pool = other.gas_pool;
+        self.heartbeat = other.heartbeat;
+        self.solvers.extend(other.solvers);
+        self.strategies = other.strategies;
+        self.features = other.features;
+
+        for (name, section) in other.networks {
+            let base = self.networks.entry(name).or_default();
+            if section.rpc_url.is_some() {
+                base.rpc_url = section.rpc_url;
+            }
+            if section.wallet.is_some() {
+                base.wallet = section.wallet;
+            }
+            if section.intent_package.is_some() {
+                base.intent_package = section.intent_package;
+            }
+            if section.validator.is_some() {
+                base.validator = section.validator;
+            }
+            if section.throttle.is_some() {
+                base.throttle = section.throttle;
+            }
+            if section.solvers.is_some() {
+                base.solvers = section.solvers;
+            }
+            base.protocols.extend(section.protocols);
+        }
+        self
+    }
+
+    /// Apply environment overrides
+    ///
+    /// `SOLVER_ADDRESS`, `<NETWORK>_SOLVER_ADDRESS`, `<NETWORK>_INTENT_PACKAGE`,
+    /// `<NETWORK>_RPC_URL` (e.g. `DEVNET_RPC_URL`),
+    /// `STAKING_VALIDATOR` (testnet) and the polling, selection and fee
+    /// variables (see `PollingConfig::with_env`, `SelectionPolicy::with_env`
+    /// and `FeeConfig::with_env`).
+    pub fn with_env(mut self) -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+
+        if let Some(address) = var("SOLVER_ADDRESS") {
+            self.wallet.address = Some(address);
+        }
+
+        for network in NETWORK_KEYS {
+            let prefix = network.to_uppercase();
+            let section = self.networks.entry(network.to_string()).or_default();
+            if let Some(package) = var(&format!("{}_INTENT_PACKAGE", prefix)) {
+                section.intent_package = Some(package);
+            }
+            if let Some(url) = var(&format!("{}_RPC_URL", prefix)) {
+                section.rpc_url = Some(url);
+            }
+            if let Some(address) = var(&format!("{}_SOLVER_ADDRESS", prefix)) {
+                section.wallet = Some(address);
+            }
+            if let Some(solvers) = var(&format!("{}_SOLVERS", prefix)) {
+                section.solvers = Some(
+                    solvers
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect(),
+                );
+            }
+        }
+
+        if let Some(validator) = var("STAKING_VALIDATOR") {
+            self.networks
+                .entry("testnet".to_string())
+                .or_default()
+                .validator = Some(validator);
+        }
+
+        self.polling = self.polling.with_env();
+        self.selection = self.selection.with_env();
+        self.fees = self.fees.with_env();
+        self
+    }
+
+    // ─── Accessors ───────────────────────────────────────────────────────────
+
+    /// Settings for a network (empty section if not configured)
+    pub fn network(&self, network: Network) -> NetworkSection {
+        self.networks
+            .get(network_key(network))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// RPC URL for a network (configured or built-in; a custom network
+    /// always uses its own URL)
+    pub fn rpc_url(&self, network: Network) -> String {
+        if let Network::Custom { rpc_url, .. } = network {
+            return rpc_url.to_string();
+        }
+        self.network(network)
+            .rpc_url
+            .unwrap_or_else(|| network.rpc_url().to_string())
+    }
+
+    /// Whether a registered solver should run on a network
+    pub fn solver_enabled(&self, name: &str, network: Network) -> bool {
+        let listed = self
+            .network(network)
+            .solvers
+            .is_none_or(|solvers| solvers.iter().any(|s| s == name));
+        listed && self.solver(name).is_enabled()
+    }
+
+    /// Solver wallet for a network (network-specific, else the global one)
+    pub fn wallet_address(&self, network: Network) -> Option<String> {
+        self.network(network)
+            .wallet
+            .or_else(|| self.wallet.address.clone())
+    }
+
+    /// Intent package for a network
+    pub fn intent_package(&self, network: Network) -> Result<String, ConfigError> {
+        self.network(network)
+            .intent_package
+            .ok_or_else(|| ConfigError::MissingIntentPackage(network_key(network).to_string()))
+    }
+
+    /// Staking validator for a network
+    pub fn validator(&self, network: Network) -> String {
+        self.network(network)
+            .validator
+            .unwrap_or_else(|| VALIDATOR_ADDRESS.to_string())
+    }
+
+    /// Submission throttling for a network (its own section, else `[throttle]`)
+    pub fn throttle(&self, network: Network) -> ThrottleConfig {
+        self.network(network)
+            .throttle
+            .unwrap_or_else(|| self.throttle.clone())
+    }
+
+    /// Tuning for a solver (defaults if not configured)
+    pub fn solver(&self, name: &str) -> SolverTuning {
+        self.solvers.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Registry of the configured custom strategies (invalid ones are left out)
+    pub fn strategy_registry(&self) -> StrategyRegistry {
+        let mut registry = StrategyRegistry::new();
+        for strategy in &self.strategies {
+            let _ = registry.register(strategy.clone());
+        }
+        registry
+    }
+
+    /// Built-in protocol config with any configured overrides applied
+    ///
+    /// Where there is no built-in config (dev networks), an override with
+    /// both `package_id` and `module` defines one; otherwise `None`.
+    /// Protocols compiled out of this build never have a config.
+    pub fn protocol_config(&self, protocol: Protocol, network: Network) -> Option<ProtocolConfig> {
+        if !protocol.is_compiled() {
+            return None;
+        }
+        let builtin = ProtocolConfig::get(protocol, network);
+        let Some(over) = self.protocol_override(protocol, network) else {
+            return builtin;
+        };
+
+        let mut config = match builtin {
+            Some(config) => config,
+            None => ProtocolConfig {
+                network,
+                protocol,
+                package_id: over.package_id.clone()?,
+                module: over.module.clone()?,
+                limits: protocol.default_limits(),
+                config_objects: Vec::new(),
+            },
+        };
+
+        if let Some(package_id) = &over.package_id {
+            config.package_id = package_id.clone();
+        }
+        if let Some(module) = &over.module {
+            config.module = module.clone();
+        }
+        for (name, id) in &over.objects {
+            match config.config_objects.iter_mut().find(|(n, _)| n == name) {
+                Some(entry) => entry.1 = id.clone(),
+                None => config.config_objects.push((name.clone(), id.clone())),
+            }
+        }
+        config.limits = self.amount_limits(protocol, network);
+        Some(config)
+    }
+
+    /// Fulfillment size limits for a protocol, with overrides applied
+    pub fn amount_limits(&self, protocol: Protocol, network: Network) -> AmountLimits {
+        let mut limits = protocol.default_limits();
+        if let Some(over) = self.protocol_override(protocol, network) {
+            if let Some(min) = over.min_amount {
+                limits.min = min;
+            }
+            if over.max_amount.is_some() {
+                limits.max = over.max_amount;
+            }
+        }
+        limits
+    }
+
+    fn protocol_override(&self, protocol: Protocol, network: Network) -> Option<&ProtocolOverride> {
+        self.networks
+            .get(network_key(network))?
+            .protocols
+            .iter()
+            .find(|(name, _)| name.parse::<Protocol>().ok() == Some(protocol))
+            .map(|(_, o)| o)
+    }
+
+    // ─── Validation ──────────────────────────────────────────────────────────
+
+    /// Check the whole config, reporting every problem at once
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        if let Err(e) = self.polling.validate() {
+            problems.push(format!("polling: {}", e));
+        }
+        if let Err(e) = self.selection.validate() {
+            problems.push(format!("selection: {}", e));
+        }
+        if let Err(e) = self.fees.validate() {
+            problems.push(format!("fees: {}", e));
+        }
+        if let Err(e) = self.batching.validate() {
+            problems.push(format!("batching: {}", e));
+        }
+        if let Err(e) = self.throttle.validate() {
+            problems.push(format!("throttle: {}", e));
+        }
+        for (name, section) in &self.networks {
+            if let Some(Err(e)) = section.throttle.as_ref().map(ThrottleConfig::validate) {
+                problems.push(format!("networks.{}.throttle: {}", name, e));
+            }
+        }
+        if let Err(e) = self.watchdog.validate() {
+            problems.push(format!("watchdog: {}", e));
+        }
+        if let Err(e) = self.gas_pool.validate() {
+            problems.push(format!("gas_pool: {}", e));
+        }
+        if let Err(e) = self.heartbeat.validate() {
+            problems.push(format!("heartbeat: {}", e));
+        }
+        if let Some(treasury) = &self.fees.treasury {
+            if !is_sui_address(treasury) {
+                problems.push(format!("fees.treasury: invalid Sui address `{}`", treasury));
+            }
+        }
+
+        if let Err(e) = StrategyRegistry::from_strategies(self.strategies.clone()) {
+            problems.push(format!("strategies: {}", e));
+        }
+
+        if let Some(address) = &self.wallet.address {
+            if !is_sui_address(address) {
+                problems.push(format!("wallet.address: invalid Sui address `{}`", address));
+            }
+        }
+
+        for (name, tuning) in &self.solvers {
+            for (field, value) in [
+                ("min_profit_bps", tuning.min_profit_bps),
+                ("gas_cost_bps", tuning.gas_cost_bps),
+                ("max_slippage_bps", tuning.max_slippage_bps),
+            ] {
+                if value.is_some_and(|v| v > 10_000) {
+                    problems.push(format!(
+                        "solvers.{}.{}: must be at most 10000 bps",
+                        name, field
+                    ));
+                }
+            }
+            if tuning.max_in_flight == Some(0) {
+                problems.push(format!(
+                    "solvers.{}.max_in_flight: must be at least 1",
+                    name
+                ));
+            }
+        }
+
+        for (name, section) in &self.networks {
+            if !NETWORK_KEYS.contains(&name.as_str()) {
+                problems.push(format!(
+                    "networks.{}: unknown network (expected one of {})",
+                    name,
+                    NETWORK_KEYS.join(", ")
+                ));
+            }
+            if let Some(package) = &section.intent_package {
+                if !is_sui_address(package) {
+                    problems.push(format!(
+                        "networks.{}.intent_package: invalid object ID `{}`",
+                        name, package
+                    ));
+                }
+            }
+            if let Some(wallet) = &section.wallet {
+                if !is_sui_address(wallet) {
+                    problems.push(format!(
+                        "networks.{}.wallet: invalid Sui address `{}`",
+                        name, wallet
+                    ));
+                }
+            }
+            if let Some(validator) = &section.validator {
+                if !is_sui_address(validator) {
+                    problems.push(format!(
+                        "networks.{}.validator: invalid Sui address `{}`",
+                        name, validator
+                    ));
+                }
+            }
+            if let Some(url) = &section.rpc_url {
+                if !url.starts_with("http://") && !url.starts_with("https://") {
+                    problems.push(format!("networks.{}.rpc_url: must be an http(s) URL", name));
+                }
+            }
+            for (protocol, over) in &section.protocols {
+                if protocol.parse::<Protocol>().is_err() {
+                    problems.push(format!(
+                        "networks.{}.protocols.{}: unknown protocol",
+                        name, protocol
+                    ));
+                }
+                if let (Some(min), Some(max)) = (over.min_amount, over.max_amount) {
+                    if min > max {
+                        problems.push(format!(
+                            "networks.{}.protocols.{}: min_amount exceeds max_amount",
+                            name, protocol
+                        ));
+                    }
+                }
+                let ids = over.package_id.iter().chain(over.objects.values());
+                for id in ids.filter(|id| !is_sui_address(id)) {
+                    problems.push(format!(
+                        "networks.{}.protocols.{}: invalid object ID `{}`",
+                        name, protocol, id
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+/// Keys accepted under `[networks.*]`
+const NETWORK_KEYS: [&str; 5] = ["testnet", "mainnet", "devnet", "localnet", "custom"];
+
+fn network_key(network: Network) -> &'static str {
+    network.name()
+}
+
+/// `0x` followed by 1-64 hex digits (short forms like `0x3` are valid IDs)
+fn is_sui_address(value: &str) -> bool {
+    value.strip_prefix("0x").is_some_and(|hex| {
+        !hex.is_empty() && hex.len() <= 64 && hex.bytes().all(|b| b.is_ascii_hexdigit())
+    })
+}
+
+// ─── Hot reload ──────────────────────────────────────────────────────────────
+
+/// Agent config shared between tasks and swappable at runtime
+///
+/// Readers take a snapshot with `get()` and compare `generation()` to know
+/// when to rebuild anything derived from it (e.g. solvers).
+#[derive(Clone, Default)]
+pub struct SharedConfig {
+    inner: Arc<RwLock<AgentConfig>>,
+    generation: Arc<AtomicU64>,
+    /// File the config was loaded from, re-read by `reload`
+    path: Option<Arc<PathBuf>>,
+}
+
+impl SharedConfig {
+    pub fn new(config: AgentConfig, path: Option<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(config)),
+            generation: Arc::new(AtomicU64::new(0)),
+            path: path.map(Arc::new),
+        }
+    }
+
+    pub fn get(&self) -> AgentConfig {
+        self.inner.read().expect("config lock poisoned").clone()
+    }
+
+    /// Bumped on every change
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut AgentConfig)) {
+        f(&mut self.inner.write().expect("config lock poisoned"));
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+
+    pub fn replace(&self, config: AgentConfig) {
+        self.update(|current| *current = config);
+    }
+
+    /// Re-read the config file (and env); the old config stays on error
+    pub fn reload(&self) -> Result<(), ConfigError> {
+        let config = AgentConfig::load(self.path.as_deref().map(|p| p.as_path()))?;
+        self.replace(config);
+        Ok(())
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {source}")]
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid TOML in {path}: {message}")]
+    Parse { path: String, message: String },
+
+    #[error("Invalid configuration:\n  - {}", .0.join("\n  - "))]
+    Invalid(Vec<String>),
+
+    #[error("No intent package configured for {0} (set networks.{0}.intent_package or {upper}_INTENT_PACKAGE)", upper = .0.to_uppercase())]
+    MissingIntentPackage(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::RiskTier;
+
+    const SAMPLE: &str = r#"
+        [wallet]
+        address = "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa"
+
+        [polling]
+        interval_secs = 20
+
+        [solvers.ScallopSolver]
+        min_profit_bps = 35
+
+        [solvers.DeepBookSolver]
+        enabled = false
+
+        [networks.mainnet]
+        intent_package = "0xabc"
+
+        [networks.mainnet.protocols.scallop]
+        objects = { market = "0x123" }
+    "#;
+
+    #[test]
+    fn test_layering_keeps_defaults() {
+        let config = AgentConfig::from_toml_str(SAMPLE).unwrap();
+
+        // File value
+        assert_eq!(config.intent_package(Network::Mainnet).unwrap(), "0xabc");
+        assert_eq!(config.polling.interval_secs, 20);
+        // Defaults survive for sections the file doesn't touch
+        assert_eq!(
+            config.intent_package(Network::Testnet).unwrap(),
+            INTENT_PACKAGE
+        );
+        assert_eq!(config.validator(Network::Testnet), VALIDATOR_ADDRESS);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_fee_section() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [fees]
+            treasury = "0xfee"
+
+            [fees.schedule]
+            mode = "tiered"
+            tiers = [{ min_amount = 0, bps = 30 }, { min_amount = 1000, bps = 10 }]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(config.fees.quote(2_000).bps, 10);
+        assert!(config.validate().is_ok());
+
+        let config =
+            AgentConfig::from_toml_str("[fees.schedule]\nmode = \"flat\"\nbps = 25").unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_throttle_per_network() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [throttle]
+            submit_interval_ms = 1000
+
+            [solvers.ScallopSolver]
+            max_in_flight = 1
+
+            [networks.mainnet.throttle]
+            max_in_flight_per_solver = 4
+            submit_interval_ms = 200
+        "#,
+        )
+        .unwrap();
+        assert_eq!(config.throttle(Network::Testnet).submit_interval_ms, 1000);
+        assert_eq!(
+            config.throttle(Network::Mainnet).max_in_flight_per_solver,
+            4
+        );
+        assert_eq!(config.solver("ScallopSolver").max_in_flight, Some(1));
+        assert!(config.validate().is_ok());
+
+        let config = AgentConfig::from_toml_str(
+            "[solvers.NaviSolver]
+max_in_flight = 0",
+        )
+        .unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_custom_strategies() {
+        let raw = r#"
+            [[strategies]]
+            id = 5
+            name = "Suilend USDC"
+            protocol = "Suilend"
+            package = "0xabc"
+            module = "lending_market"
+            function = "deposit"
+            coin_type = "0xdba3::usdc::USDC"
+            risk_tier = "high"
+            args = [{ kind = "shared", object_id = "0x840" }, { kind = "coin" }]
+        "#;
+        let config = AgentConfig::from_toml_str(raw).unwrap();
+        assert!(config.validate().is_ok());
+        let registry = config.strategy_registry();
+        assert_eq!(registry.get(5).unwrap().risk_tier, RiskTier::High);
+
+        let config = AgentConfig::from_toml_str(&raw.replace("id = 5", "id = 2")).unwrap();
+        assert!(config.validate().is_err());
+        assert!(config.strategy_registry().get(2).is_none());
+    }
+
+    #[test]
+    fn test_solver_tuning() {
+        let config = AgentConfig::from_toml_str(SAMPLE).unwrap();
+
+        let tuned = config
+            .solver("ScallopSolver")
+            .apply(SolverConfig::default());
+        assert_eq!(tuned.min_profit_bps, 35);
+        assert_eq!(tuned.gas_cost_bps, SolverConfig::default().gas_cost_bps);
+        assert!(!config.solver("DeepBookSolver").is_enabled());
+        assert!(config.solver("StakingSolver").is_enabled());
+    }
+
+    #[test]
+    fn test_protocol_override() {
+        let config = AgentConfig::from_toml_str(SAMPLE).unwrap();
+        let scallop = config
+            .protocol_config(Protocol::Scallop, Network::Mainnet)
+            .unwrap();
+
+        let market = scallop
+            .config_objects
+            .iter()
+            .find(|(name, _)| name == "market")
+            .unwrap();
+        assert_eq!(market.1, "0x123");
+        // Untouched fields keep their built-in values
+        assert_eq!(
+            scallop.package_id,
+            ProtocolConfig::get(Protocol::Scallop, Network::Mainnet)
+                .unwrap()
+                .package_id
+        );
+    }
+
+    #[test]
+    fn test_validation_reports_all_problems() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [wallet]
+            address = "not-an-address"
+
+            [solvers.StakingSolver]
+            gas_cost_bps = 20000
+
+            [networks.moonnet]
+            rpc_url = "ftp://example"
+            "#,
+        )
+        .unwrap();
+
+        let ConfigError::Invalid(problems) = config.validate().unwrap_err() else {
+            panic!("expected Invalid");
+        };
+        assert_eq!(problems.len(), 4);
+        assert!(problems.iter().any(|p| p.contains("networks.moonnet")));
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        assert!(matches!(
+            AgentConfig::from_toml_str("[wallet]\nadress = \"0x1\""),
+            Err(ConfigError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wallet_per_network() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [wallet]
+            address = "0x1"
+
+            [networks.mainnet]
+            wallet = "0x2"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.wallet_address(Network::Testnet).unwrap(), "0x1");
+        assert_eq!(config.wallet_address(Network::Mainnet).unwrap(), "0x2");
+    }
+
+    #[test]
+    fn test_dev_network_protocol_override() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [networks.localnet]
+            intent_package = "0x42"
+
+            [networks.localnet.protocols.scallop]
+            package_id = "0xabc"
+            module = "mint"
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let scallop = config
+            .protocol_config(Protocol::Scallop, Network::Localnet)
+            .unwrap();
+        assert_eq!(scallop.package_id, "0xabc");
+        assert!(config
+            .protocol_config(Protocol::Navi, Network::Localnet)
+            .is_none());
+    }
+
+    #[test]
+    fn test_amount_limit_overrides() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [networks.mainnet.protocols.navi]
+            min_amount = 5000000000
+            max_amount = 100000000000000
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let navi = config.amount_limits(Protocol::Navi, Network::Mainnet);
+        assert_eq!(navi.min, 5_000_000_000);
+        assert_eq!(navi.max, Some(100_000_000_000_000));
+        assert_eq!(
+            config
+                .protocol_config(Protocol::Navi, Network::Mainnet)
+                .unwrap()
+                .limits,
+            navi
+        );
+        assert_eq!(
+            config.amount_limits(Protocol::Scallop, Network::Mainnet),
+            Protocol::Scallop.default_limits()
+        );
+
+        let inverted = AgentConfig::from_toml_str(
+            r#"
+            [networks.mainnet.protocols.navi]
+            min_amount = 2
+            max_amount = 1
+            "#,
+        )
+        .unwrap();
+        assert!(inverted.validate().is_err());
+    }
+
+    #[test]
+    fn test_shared_config_generation() {
+        let shared = SharedConfig::new(AgentConfig::defaults(), None);
+        assert_eq!(shared.generation(), 0);
+
+        shared.update(|c| c.wallet.address = Some("0x1".to_string()));
+        assert_eq!(shared.generation(), 1);
+        assert_eq!(shared.get().wallet.address.as_deref(), Some("0x1"));
+    }
+
+    #[test]
+    fn test_missing_mainnet_package() {
+        let config = AgentConfig::defaults();
+        assert!(matches!(
+            config.intent_package(Network::Mainnet),
+            Err(ConfigError::MissingIntentPackage(_))
+        ));
+    }
+}
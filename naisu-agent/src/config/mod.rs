@@ -1,5 +1,12 @@
 //! Configuration module for network and protocol settings
 
+pub mod agent;
+pub mod mvr;
 pub mod network;
 
-pub use network::{Network, Protocol, ProtocolConfig};
+pub use agent::{
+    AgentConfig, ConfigError, NetworkSection, ProtocolOverride, SharedConfig, SolverTuning,
+    WalletConfig,
+};
+pub use mvr::{MvrClient, MvrError, MvrPackage, PackageResolution};
+pub use network::{AmountLimits, Network, Protocol, ProtocolConfig};
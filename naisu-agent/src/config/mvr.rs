@@ -0,0 +1,336 @@
+//! Move Registry (MVR) package resolution
+//!
+//! Protocol package IDs in `ProtocolConfig` go stale when a protocol
+//! upgrades. At startup the agent asks MVR for the latest published-at
+//! address of each named package, caches the answer, and falls back to the
+//! built-in constants when MVR can't be reached.
+//!
+//! API: `GET {base}/v1/names/{name}` → `{ "package_address", "version", .. }`
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::agent::AgentConfig;
+use super::network::{Network, Protocol};
+
+/// How long a resolution stays fresh
+const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+/// Config object holding the address calls should target
+pub const PUBLISHED_AT: &str = "published_at";
+
+/// A resolved MVR name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MvrPackage {
+    #[serde(default)]
+    pub name: String,
+    /// Latest published-at address
+    pub package_address: String,
+    #[serde(default)]
+    pub version: Option<u64>,
+}
+
+/// Caching MVR client for one network
+pub struct MvrClient {
+    base_url: String,
+    http: reqwest::Client,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, (MvrPackage, Instant)>>,
+}
+
+impl MvrClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            http: reqwest::Client::builder()
+                .timeout(Duration::from_secs(5))
+                .build()
+                .unwrap_or_default(),
+            ttl: DEFAULT_TTL,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Public MVR endpoint for a network (`MVR_URL` overrides it)
+    pub fn for_network(network: Network) -> Option<Self> {
+        if let Ok(url) = std::env::var("MVR_URL") {
+            return Some(Self::new(url));
+        }
+        match network {
+            Network::Mainnet => Some(Self::new("https://mainnet.mvr.mystenlabs.com")),
+            Network::Testnet => Some(Self::new("https://testnet.mvr.mystenlabs.com")),
+            _ => None,
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    fn cached(&self, name: &str, fresh_only: bool) -> Option<MvrPackage> {
+        let cache = self.cache.lock().expect("mvr cache lock poisoned");
+        cache
+            .get(name)
+            .filter(|(_, at)| !fresh_only || at.elapsed() < self.ttl)
+            .map(|(pkg, _)| pkg.clone())
+    }
+
+    /// Resolve a name like `@cetuspackages/clmm`
+    ///
+    /// A stale cache entry is returned if the registry can't be reached.
+    pub async fn resolve(&self, name: &str) -> Result<MvrPackage, MvrError> {
+        if let Some(pkg) = self.cached(name, true) {
+            return Ok(pkg);
+        }
+
+        match self.fetch(name).await {
+            Ok(pkg) => {
+                self.cache
+                    .lock()
+                    .expect("mvr cache lock poisoned")
+                    .insert(name.to_string(), (pkg.clone(), Instant::now()));
+                Ok(pkg)
+            }
+            Err(e) => self.cached(name, false).ok_or(e),
+        }
+    }
+
+    async fn fetch(&self, name: &str) -> Result<MvrPackage, MvrError> {
+        let url = format!("{}/v1/names/{}", self.base_url, name);
+        let resp = self
+            .http
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| MvrError::Unreachable(e.to_string()))?;
+
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(MvrError::NotFound(name.to_string()));
+        }
+        let mut pkg: MvrPackage = resp
+            .error_for_status()
+            .map_err(|e| MvrError::Unreachable(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| MvrError::InvalidResponse(e.to_string()))?;
+
+        if pkg.name.is_empty() {
+            pkg.name = name.to_string();
+        }
+        Ok(pkg)
+    }
+}
+
+// ─── Config integration ──────────────────────────────────────────────────────
+
+/// Outcome of resolving one protocol's package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageResolution {
+    pub protocol: Protocol,
+    pub mvr_name: String,
+    /// Address from config / built-in constants
+    pub configured: String,
+    /// Address from MVR (None when falling back to `configured`)
+    pub resolved: Option<String>,
+    pub version: Option<u64>,
+}
+
+impl PackageResolution {
+    /// On-chain version differs from config
+    pub fn drifted(&self) -> bool {
+        self.resolved
+            .as_ref()
+            .is_some_and(|r| !same_address(r, &self.configured))
+    }
+}
+
+/// Default MVR name for a protocol's main package
+pub fn default_mvr_name(protocol: Protocol) -> Option<&'static str> {
+    match protocol {
+        Protocol::Cetus => Some("@cetuspackages/clmm"),
+        _ => None,
+    }
+}
+
+impl AgentConfig {
+    /// MVR name for a protocol on a network (configured or default)
+    pub fn mvr_name(&self, protocol: Protocol, network: Network) -> Option<String> {
+        self.network(network)
+            .protocols
+            .iter()
+            .find(|(name, _)| name.parse::<Protocol>().ok() == Some(protocol))
+            .and_then(|(_, o)| o.mvr_name.clone())
+            .or_else(|| default_mvr_name(protocol).map(str::to_string))
+    }
+
+    /// Resolve every named package on `network` through MVR
+    ///
+    /// Drifted addresses are written into the network's protocol overrides
+    /// as `published_at`, so solvers built from this config call the latest
+    /// package. Unreachable names keep their configured address.
+    pub async fn resolve_packages(
+        &mut self,
+        network: Network,
+        mvr: &MvrClient,
+    ) -> Vec<PackageResolution> {
+        let mut results = Vec::new();
+
+        for protocol in network.supported_protocols() {
+            let Some(mvr_name) = self.mvr_name(protocol, network) else {
+                continue;
+            };
+            let Some(config) = self.protocol_config(protocol, network) else {
+                continue;
+            };
+            let configured = config
+                .config_objects
+                .iter()
+                .find(|(name, _)| name == PUBLISHED_AT)
+                .map(|(_, id)| id.clone())
+                .unwrap_or(config.package_id);
+
+            let resolution = match mvr.resolve(&mvr_name).await {
+                Ok(pkg) => PackageResolution {
+                    protocol,
+                    mvr_name,
+                    configured,
+                    resolved: Some(pkg.package_address),
+                    version: pkg.version,
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        "⚠️  MVR lookup for {} failed ({}), using configured {}",
+                        mvr_name,
+                        e,
+                        configured
+                    );
+                    PackageResolution {
+                        protocol,
+                        mvr_name,
+                        configured,
+                        resolved: None,
+                        version: None,
+                    }
+                }
+            };
+
+            if resolution.drifted() {
+                let resolved = resolution.resolved.clone().unwrap_or_default();
+                tracing::warn!(
+                    "⚠️  {} upgraded on {}: config {} → on-chain {} (v{})",
+                    protocol.name(),
+                    network.name(),
+                    resolution.configured,
+                    resolved,
+                    resolution
+                        .version
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "?".to_string())
+                );
+                self.networks
+                    .entry(network.name().to_string())
+                    .or_default()
+                    .protocols
+                    .entry(protocol.name().to_lowercase())
+                    .or_default()
+                    .objects
+                    .insert(PUBLISHED_AT.to_string(), resolved);
+            }
+            results.push(resolution);
+        }
+
+        results
+    }
+}
+
+/// Compare Sui addresses ignoring case and leading-zero padding
+fn same_address(a: &str, b: &str) -> bool {
+    let norm = |s: &str| {
+        s.trim_start_matches("0x")
+            .trim_start_matches('0')
+            .to_lowercase()
+    };
+    norm(a) == norm(b)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MvrError {
+    #[error("MVR unreachable: {0}")]
+    Unreachable(String),
+
+    #[error("MVR name not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid MVR response: {0}")]
+    InvalidResponse(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drift_ignores_padding() {
+        let mut r = PackageResolution {
+            protocol: Protocol::Cetus,
+            mvr_name: "@cetuspackages/clmm".to_string(),
+            configured: "0x00ab".to_string(),
+            resolved: Some("0xAB".to_string()),
+            version: Some(5),
+        };
+        assert!(!r.drifted());
+
+        r.resolved = Some("0xcd".to_string());
+        assert!(r.drifted());
+
+        r.resolved = None;
+        assert!(!r.drifted());
+    }
+
+    #[test]
+    fn test_mvr_name_override() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [networks.mainnet.protocols.scallop]
+            mvr_name = "@scallop/core"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config
+                .mvr_name(Protocol::Scallop, Network::Mainnet)
+                .as_deref(),
+            Some("@scallop/core")
+        );
+        assert_eq!(
+            config
+                .mvr_name(Protocol::Cetus, Network::Mainnet)
+                .as_deref(),
+            Some("@cetuspackages/clmm")
+        );
+        assert!(config.mvr_name(Protocol::Navi, Network::Mainnet).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_registry_falls_back() {
+        // Nothing listens on port 9 (discard); resolution must fall back
+        let mvr = MvrClient::new("http://127.0.0.1:9");
+        let mut config = AgentConfig::defaults();
+
+        let results = config.resolve_packages(Network::Testnet, &mvr).await;
+        let cetus = results
+            .iter()
+            .find(|r| r.protocol == Protocol::Cetus)
+            .unwrap();
+        assert!(cetus.resolved.is_none());
+        assert!(!cetus.drifted());
+        assert!(!config
+            .network(Network::Testnet)
+            .protocols
+            .contains_key("cetus"));
+    }
+}
@@ -3,6 +3,14 @@
 //! Supports both Testnet and Mainnet with different protocol configurations.
 //! User can switch between networks in the frontend.
 //!
+//! Devnet, Localnet and Custom networks are for development: only native
+//! staking has a built-in config there, other protocols need overrides in
+//! the agent config (`[networks.<name>.protocols.<protocol>]`).
+//!
+//! Each protocol but native staking sits behind a Cargo feature of the
+//! same name. A protocol compiled out of the build has no config and is
+//! never supported or available.
+//!
 //! # Verified Mainnet Addresses
 //! - Scallop: 0xd384ded6b9e7f4d2c4c9007b0291ef88fbfed8e709bce83d2da69de2d79d013d (GitHub official)
 //! - Navi: 0xee0041239b89564ce870a7dec5ddc5d114367ab94a1137e90aa0633cb76518e0
@@ -15,34 +23,90 @@
 //! Sources:
 //! - Cetus SDK: https://github.com/CetusProtocol/cetus-clmm-sui-sdk/tree/main/src/config
 
+use naisu_core::{ExplorerLink, RiskTier, SuiNetwork};
+
 /// Network type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Network {
     #[default]
     Testnet,
     Mainnet,
+    Devnet,
+    /// `sui start` on this machine
+    Localnet,
+    /// Any other full node (see `Network::custom`)
+    Custom {
+        rpc_url: &'static str,
+        explorer_url: &'static str,
+    },
 }
 
 impl Network {
+    /// Custom network from runtime URLs
+    ///
+    /// The URLs are leaked to keep `Network` `Copy`; networks are created
+    /// once at startup, so this is bounded.
+    pub fn custom(rpc_url: impl Into<String>, explorer_url: impl Into<String>) -> Self {
+        Network::Custom {
+            rpc_url: Box::leak(rpc_url.into().into_boxed_str()),
+            explorer_url: Box::leak(explorer_url.into().into_boxed_str()),
+        }
+    }
+
+    /// Short lowercase name (config keys, logs, metrics)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Network::Testnet => "testnet",
+            Network::Mainnet => "mainnet",
+            Network::Devnet => "devnet",
+            Network::Localnet => "localnet",
+            Network::Custom { .. } => "custom",
+        }
+    }
+
     /// Get RPC URL for network
     pub fn rpc_url(&self) -> &'static str {
         match self {
             Network::Testnet => "https://fullnode.testnet.sui.io:443",
             Network::Mainnet => "https://fullnode.mainnet.sui.io:443",
+            Network::Devnet => "https://fullnode.devnet.sui.io:443",
+            Network::Localnet => "http://127.0.0.1:9000",
+            Network::Custom { rpc_url, .. } => rpc_url,
         }
     }
 
     /// Get explorer URL
     pub fn explorer_url(&self) -> &'static str {
         match self {
-            Network::Testnet => "https://suiscan.xyz/testnet",
-            Network::Mainnet => "https://suiscan.xyz/mainnet",
+            Network::Testnet => SuiNetwork::Testnet.explorer_url(),
+            Network::Mainnet => SuiNetwork::Mainnet.explorer_url(),
+            Network::Devnet => SuiNetwork::Devnet.explorer_url(),
+            Network::Localnet => "https://custom.suiscan.xyz/custom",
+            Network::Custom { explorer_url, .. } => explorer_url,
+        }
+    }
+
+    /// Explorer links for this network (`None` for a custom network
+    /// without an explorer)
+    pub fn explorer(&self) -> Option<ExplorerLink> {
+        Some(self.explorer_url())
+            .filter(|url| !url.is_empty())
+            .map(ExplorerLink::with_base)
+    }
+
+    /// Faucet endpoint, if the network has one
+    pub fn faucet_url(&self) -> Option<&'static str> {
+        match self {
+            Network::Testnet => Some("https://faucet.testnet.sui.io/v2/gas"),
+            Network::Devnet => Some("https://faucet.devnet.sui.io/v2/gas"),
+            Network::Localnet => Some("http://127.0.0.1:9123/v2/gas"),
+            Network::Mainnet | Network::Custom { .. } => None,
         }
     }
 
     /// Get supported protocols for this network
     pub fn supported_protocols(&self) -> Vec<Protocol> {
-        match self {
+        let protocols = match self {
             Network::Testnet => vec![
                 Protocol::NativeStaking,
                 Protocol::DeepBook,
@@ -55,7 +119,15 @@ impl Network {
                 Protocol::NativeStaking,
                 Protocol::DeepBook,
             ],
-        }
+            // Dev networks: system staking only
+            Network::Devnet | Network::Localnet | Network::Custom { .. } => {
+                vec![Protocol::NativeStaking]
+            }
+        };
+        protocols
+            .into_iter()
+            .filter(Protocol::is_compiled)
+            .collect()
     }
 }
 
@@ -66,13 +138,19 @@ impl std::str::FromStr for Network {
         match s.to_lowercase().as_str() {
             "testnet" => Ok(Network::Testnet),
             "mainnet" => Ok(Network::Mainnet),
+            "devnet" => Ok(Network::Devnet),
+            "localnet" | "local" => Ok(Network::Localnet),
+            // A full node URL selects a custom network (explorer unknown)
+            _ if s.starts_with("http://") || s.starts_with("https://") => {
+                Ok(Network::custom(s, ""))
+            }
             _ => Err(format!("Unknown network: {}", s)),
         }
     }
 }
 
 /// Protocol types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Protocol {
     /// Native Sui staking (always works)
     NativeStaking,
@@ -84,6 +162,10 @@ pub enum Protocol {
     Navi,
     /// Cetus AMM DEX (mainnet only) - TODO: Find address
     Cetus,
+    /// Bluefin CLMM DEX (mainnet; yield data only, no solver yet)
+    Bluefin,
+    /// Turbos CLMM DEX (mainnet; yield data only, no solver yet)
+    Turbos,
 }
 
 impl Protocol {
@@ -94,6 +176,8 @@ impl Protocol {
             Protocol::Scallop => "Scallop",
             Protocol::Navi => "Navi",
             Protocol::Cetus => "Cetus",
+            Protocol::Bluefin => "Bluefin",
+            Protocol::Turbos => "Turbos",
         }
     }
 
@@ -104,6 +188,7 @@ impl Protocol {
             Protocol::Scallop => "Lending",
             Protocol::Navi => "Lending",
             Protocol::Cetus => "DEX (AMM)",
+            Protocol::Bluefin | Protocol::Turbos => "DEX (CLMM)",
         }
     }
 
@@ -114,10 +199,70 @@ impl Protocol {
             Protocol::Scallop => 0.085,       // 8.5%
             Protocol::Navi => 0.08,           // 8%
             Protocol::Cetus => 0.10,          // 10% (LP fees)
+            Protocol::Bluefin => 0.12,        // 12% (LP fees + rewards)
+            Protocol::Turbos => 0.09,         // 9% (LP fees)
+        }
+    }
+
+    /// Built-in fulfillment size limits
+    pub fn default_limits(&self) -> AmountLimits {
+        let min = match self {
+            // Sui's staking threshold
+            Protocol::NativeStaking => 1_000_000_000,
+            Protocol::Scallop | Protocol::Navi => 100_000_000,
+            // Order book lot size / swap plus liquidity gas
+            Protocol::DeepBook | Protocol::Cetus | Protocol::Bluefin | Protocol::Turbos => {
+                1_000_000_000
+            }
+        };
+        AmountLimits { min, max: None }
+    }
+
+    /// Risk tier of deposits into the protocol
+    pub fn risk_tier(&self) -> RiskTier {
+        match self {
+            Protocol::NativeStaking => RiskTier::Low,
+            Protocol::Scallop | Protocol::Navi => RiskTier::Medium,
+            // Market making and LP positions carry inventory / IL risk
+            Protocol::DeepBook | Protocol::Cetus | Protocol::Bluefin | Protocol::Turbos => {
+                RiskTier::High
+            }
+        }
+    }
+
+    /// Whether deposits stay in stablecoins, as stable-only intents need
+    ///
+    /// Every solver deploys the intent as SUI or a SUI pair today, so none
+    /// qualify yet.
+    pub fn is_stable(&self) -> bool {
+        match self {
+            Protocol::NativeStaking
+            | Protocol::DeepBook
+            | Protocol::Scallop
+            | Protocol::Navi
+            | Protocol::Cetus
+            | Protocol::Bluefin
+            | Protocol::Turbos => false,
+        }
+    }
+
+    /// Whether the protocol's Cargo feature is enabled in this build
+    pub fn is_compiled(&self) -> bool {
+        match self {
+            Protocol::NativeStaking => true,
+            Protocol::DeepBook => cfg!(feature = "deepbook"),
+            Protocol::Scallop => cfg!(feature = "scallop"),
+            Protocol::Navi => cfg!(feature = "navi"),
+            Protocol::Cetus => cfg!(feature = "cetus"),
+            Protocol::Bluefin => cfg!(feature = "bluefin"),
+            Protocol::Turbos => cfg!(feature = "turbos"),
         }
     }
 
     pub fn is_available(&self, network: Network) -> bool {
+        if !self.is_compiled() {
+            return false;
+        }
         match (self, network) {
             // Testnet protocols
             (Protocol::NativeStaking, Network::Testnet) => true,
@@ -133,12 +278,47 @@ impl Protocol {
             (Protocol::Cetus, Network::Testnet) => true,
             (Protocol::Cetus, Network::Mainnet) => true,
 
+            // System staking exists on every network
+            (Protocol::NativeStaking, _) => true,
+
             // Everything else not available
             _ => false,
         }
     }
 }
 
+impl std::str::FromStr for Protocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "nativestaking" | "staking" => Ok(Protocol::NativeStaking),
+            "deepbook" => Ok(Protocol::DeepBook),
+            "scallop" => Ok(Protocol::Scallop),
+            "navi" => Ok(Protocol::Navi),
+            "cetus" => Ok(Protocol::Cetus),
+            "bluefin" => Ok(Protocol::Bluefin),
+            "turbos" => Ok(Protocol::Turbos),
+            _ => Err(format!("Unknown protocol: {}", s)),
+        }
+    }
+}
+
+/// Intent amounts a protocol fulfills (MIST)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmountLimits {
+    /// Smallest amount worth fulfilling
+    pub min: u64,
+    /// Largest amount fulfilled in one go (`None` = no cap)
+    pub max: Option<u64>,
+}
+
+impl AmountLimits {
+    pub fn contains(&self, amount: u64) -> bool {
+        amount >= self.min && self.max.is_none_or(|max| amount <= max)
+    }
+}
+
 /// Protocol configuration for each network
 #[derive(Debug, Clone)]
 pub struct ProtocolConfig {
@@ -146,6 +326,7 @@ pub struct ProtocolConfig {
     pub protocol: Protocol,
     pub package_id: String,
     pub module: String,
+    pub limits: AmountLimits,
     pub config_objects: Vec<(String, String)>, // (name, object_id)
 }
 
@@ -161,6 +342,7 @@ impl ProtocolConfig {
                 protocol,
                 package_id: "0x3".to_string(),
                 module: "sui_system".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![
                     ("sui_system_state".to_string(), "0x5".to_string()),
                     ("clock".to_string(), "0x6".to_string()),
@@ -168,12 +350,14 @@ impl ProtocolConfig {
             }),
 
             // DeepBook (Testnet)
+            #[cfg(feature = "deepbook")]
             (Protocol::DeepBook, Network::Testnet) => Some(Self {
                 network,
                 protocol,
                 package_id: "0x000000000000000000000000000000000000000000000000000000000000dee9"
                     .to_string(),
                 module: "clob_v2".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![],
             }),
 
@@ -181,6 +365,7 @@ impl ProtocolConfig {
             // Source: https://www.moveregistry.com/package/@cetuspackages/clmm
             // MVR Version: 5 (Latest)
             // GitHub Tag: testnet-v0.0.2
+            #[cfg(feature = "cetus")]
             (Protocol::Cetus, Network::Testnet) => Some(Self {
                 network,
                 protocol,
@@ -188,6 +373,7 @@ impl ProtocolConfig {
                 package_id: "0x5372d555ac734e272659136c2a0cd3227f9b92de67c80dc11250307268af2db8"
                     .to_string(),
                 module: "pool".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![
                     // Latest PublishedAt (where upgraded code lives)
                     (
@@ -236,6 +422,7 @@ impl ProtocolConfig {
                 protocol,
                 package_id: "0x3".to_string(),
                 module: "sui_system".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![
                     ("sui_system_state".to_string(), "0x5".to_string()),
                     ("clock".to_string(), "0x6".to_string()),
@@ -246,12 +433,14 @@ impl ProtocolConfig {
             // Source: https://github.com/scallop-io/sui-lending-protocol
             // File: contracts/protocol/publish-result.mainnet.json
             // Version: 17 (UPGRADED - LATEST)
+            #[cfg(feature = "scallop")]
             (Protocol::Scallop, Network::Mainnet) => Some(Self {
                 network,
                 protocol,
                 package_id: "0xd384ded6b9e7f4d2c4c9007b0291ef88fbfed8e709bce83d2da69de2d79d013d"
                     .to_string(),
                 module: "mint".to_string(), // For deposit/mint sSUI
+                limits: protocol.default_limits(),
                 config_objects: vec![
                     (
                         "market".to_string(),
@@ -278,12 +467,14 @@ impl ProtocolConfig {
 
             // Navi (Mainnet) - VERIFIED
             // Source: On-chain verification
+            #[cfg(feature = "navi")]
             (Protocol::Navi, Network::Mainnet) => Some(Self {
                 network,
                 protocol,
                 package_id: "0xee0041239b89564ce870a7dec5ddc5d114367ab94a1137e90aa0633cb76518e0"
                     .to_string(),
                 module: "incentive_v3".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![(
                     "storage".to_string(),
                     "0xbb4e2f4b6205c2e2a2db47aeb4f830796ec7c005f88537ee775986639bc442fe"
@@ -293,12 +484,14 @@ impl ProtocolConfig {
 
             // DeepBook (Mainnet) - VERIFIED
             // Source: Sui native (0xdee9)
+            #[cfg(feature = "deepbook")]
             (Protocol::DeepBook, Network::Mainnet) => Some(Self {
                 network,
                 protocol,
                 package_id: "0x000000000000000000000000000000000000000000000000000000000000dee9"
                     .to_string(),
                 module: "clob_v2".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![],
             }),
 
@@ -311,6 +504,7 @@ impl ProtocolConfig {
             // 1. CLMM Pool Package  - Core pool operations, liquidity management
             // 2. Cetus Config       - Protocol configuration, pool registry
             // 3. Integrate Package  - Swap routing, multi-hop swaps
+            #[cfg(feature = "cetus")]
             (Protocol::Cetus, Network::Mainnet) => Some(Self {
                 network,
                 protocol,
@@ -318,6 +512,7 @@ impl ProtocolConfig {
                 package_id: "0x1eabed72c53feb3805120a081dc15963c204dc8d091542592abaf7a35689b2fb"
                     .to_string(),
                 module: "pool".to_string(),
+                limits: protocol.default_limits(),
                 config_objects: vec![
                     // Global Config ID - Protocol-wide settings
                     (
@@ -358,6 +553,24 @@ impl ProtocolConfig {
                 ],
             }),
 
+            // ===== DEV NETWORKS =====
+
+            // Native Staking (system package is the same everywhere)
+            (
+                Protocol::NativeStaking,
+                Network::Devnet | Network::Localnet | Network::Custom { .. },
+            ) => Some(Self {
+                network,
+                protocol,
+                package_id: "0x3".to_string(),
+                module: "sui_system".to_string(),
+                limits: protocol.default_limits(),
+                config_objects: vec![
+                    ("sui_system_state".to_string(), "0x5".to_string()),
+                    ("clock".to_string(), "0x6".to_string()),
+                ],
+            }),
+
             // Unsupported combinations
             _ => None,
         }
@@ -387,6 +600,12 @@ mod tests {
             Network::Mainnet.rpc_url(),
             "https://fullnode.mainnet.sui.io:443"
         );
+        let explorer = Network::Mainnet.explorer().unwrap();
+        assert_eq!(explorer.tx("abc"), "https://suiscan.xyz/mainnet/tx/abc");
+        assert_eq!(
+            explorer.address("0x1"),
+            "https://suiscan.xyz/mainnet/account/0x1"
+        );
     }
 
     #[test]
@@ -423,6 +642,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dev_networks() {
+        assert_eq!("devnet".parse::<Network>().unwrap(), Network::Devnet);
+        assert_eq!("local".parse::<Network>().unwrap(), Network::Localnet);
+
+        let custom: Network = "http://10.0.0.5:9000".parse().unwrap();
+        assert_eq!(custom.rpc_url(), "http://10.0.0.5:9000");
+        assert_eq!(custom.name(), "custom");
+        assert!(custom.faucet_url().is_none());
+        assert!(custom.explorer().is_none());
+        assert_eq!(
+            Network::custom("http://10.0.0.5:9000", "http://explorer.local/")
+                .explorer()
+                .unwrap()
+                .tx("abc"),
+            "http://explorer.local/tx/abc"
+        );
+
+        // Only staking has a built-in config off testnet/mainnet
+        assert!(ProtocolConfig::get(Protocol::NativeStaking, Network::Localnet).is_some());
+        assert!(ProtocolConfig::get(Protocol::Scallop, Network::Devnet).is_none());
+        assert_eq!(
+            get_network_configs(Network::Devnet).len(),
+            Network::Devnet.supported_protocols().len()
+        );
+    }
+
+    #[test]
+    fn test_amount_limits() {
+        let staking = Protocol::NativeStaking.default_limits();
+        assert!(!staking.contains(999_999_999));
+        assert!(staking.contains(1_000_000_000));
+
+        let capped = AmountLimits {
+            min: 10,
+            max: Some(100),
+        };
+        assert!(capped.contains(100));
+        assert!(!capped.contains(101));
+        assert_eq!(
+            ProtocolConfig::get(Protocol::Scallop, Network::Mainnet)
+                .unwrap()
+                .limits,
+            Protocol::Scallop.default_limits()
+        );
+    }
+
     #[test]
     fn test_native_staking_both_networks() {
         let testnet = ProtocolConfig::get(Protocol::NativeStaking, Network::Testnet);
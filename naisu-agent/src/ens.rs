@@ -0,0 +1,256 @@
+//! ENS name resolution
+//!
+//! Resolves `.eth` names with `eth_call`s against the ENS registry on
+//! Ethereum: the registry names each node's resolver, which holds the
+//! address (`addr`) or, for `<address>.addr.reverse` nodes, the primary
+//! name (`name`). A primary name only counts if it resolves back to the
+//! same address, as ENS requires.
+//!
+//! Names are lowercased but not otherwise normalized (no UTS-46), so
+//! names outside ASCII may not resolve.
+
+use naisu_core::EvmChain;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+
+use crate::executor::evm_executor::selector;
+use crate::executor::signer::{hex_decode, hex_encode};
+
+/// ENS registry, at the same address on mainnet and Sepolia
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// `namehash` of an ENS name (EIP-137)
+pub fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let label_hash = Keccak256::digest(label.as_bytes());
+        node = Keccak256::digest([&node[..], &label_hash[..]].concat()).into();
+    }
+    node
+}
+
+/// Canonical form of an ENS name; `None` if `input` isn't one
+pub fn normalize_ens(input: &str) -> Option<String> {
+    let name = input.trim().to_lowercase();
+    let labels = name.strip_suffix(".eth")?;
+    let valid = labels
+        .split('.')
+        .all(|l| !l.is_empty() && !l.chars().any(|c| c.is_whitespace() || c == '/'));
+    valid.then_some(name)
+}
+
+/// Whether `input` is an ENS name rather than an address
+pub fn is_ens_name(input: &str) -> bool {
+    normalize_ens(input).is_some()
+}
+
+/// Node whose resolver holds the primary name of `address`
+fn reverse_node(address: &str) -> String {
+    let hex = address.trim().trim_start_matches("0x").to_lowercase();
+    format!("{}.addr.reverse", hex)
+}
+
+/// Calldata of a `(bytes32 node)` getter
+fn node_call(signature: &str, node: &[u8; 32]) -> String {
+    format!("0x{}{}", hex_encode(&selector(signature)), hex_encode(node))
+}
+
+/// Address in the low 20 bytes of an ABI word; `None` for the zero address
+fn decode_address(data: &[u8]) -> Result<Option<String>, EnsError> {
+    let word = data
+        .get(..32)
+        .ok_or_else(|| EnsError::Malformed("expected an address word".into()))?;
+    if word.iter().all(|&b| b == 0) {
+        return Ok(None);
+    }
+    Ok(Some(format!("0x{}", hex_encode(&word[12..]))))
+}
+
+/// ABI-encoded `string` return value; `None` if empty
+pub(crate) fn decode_string(data: &[u8]) -> Result<Option<String>, EnsError> {
+    let malformed = || EnsError::Malformed("expected an ABI string".into());
+    let number = |at: usize| -> Result<usize, EnsError> {
+        let word = data.get(at..at.saturating_add(32)).ok_or_else(malformed)?;
+        if word[..24].iter().any(|&b| b != 0) {
+            return Err(malformed());
+        }
+        Ok(word[24..]
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize))
+    };
+    let offset = number(0)?;
+    let len = number(offset)?;
+    let start = offset + 32;
+    let bytes = data
+        .get(start..start.saturating_add(len))
+        .ok_or_else(malformed)?;
+    let name = String::from_utf8(bytes.to_vec()).map_err(|_| malformed())?;
+    Ok(Some(name).filter(|n| !n.is_empty()))
+}
+
+/// Resolves ENS names through an Ethereum JSON-RPC endpoint
+pub struct EnsResolver {
+    client: reqwest::Client,
+    rpc_url: String,
+    registry: String,
+}
+
+impl EnsResolver {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            rpc_url: rpc_url.to_string(),
+            registry: ENS_REGISTRY.to_string(),
+        }
+    }
+
+    /// Resolver on Ethereum mainnet's public RPC
+    pub fn mainnet() -> Self {
+        Self::new(EvmChain::Ethereum.default_rpc_url())
+    }
+
+    pub fn with_registry(mut self, registry: &str) -> Self {
+        self.registry = registry.to_string();
+        self
+    }
+
+    /// Address `name` points at; `None` if it has no resolver or address
+    pub async fn resolve(&self, name: &str) -> Result<Option<String>, EnsError> {
+        let name = normalize_ens(name).ok_or_else(|| EnsError::InvalidName(name.to_string()))?;
+        let node = namehash(&name);
+        let Some(resolver) = self.resolver(&node).await? else {
+            return Ok(None);
+        };
+        let data = self
+            .call(&resolver, &node_call("addr(bytes32)", &node))
+            .await?;
+        decode_address(&data)
+    }
+
+    /// Primary name of `address`, if it resolves back to the address
+    pub async fn lookup(&self, address: &str) -> Result<Option<String>, EnsError> {
+        let node = namehash(&reverse_node(address));
+        let Some(resolver) = self.resolver(&node).await? else {
+            return Ok(None);
+        };
+        let data = self
+            .call(&resolver, &node_call("name(bytes32)", &node))
+            .await?;
+        let Some(name) = decode_string(&data)? else {
+            return Ok(None);
+        };
+        if !is_ens_name(&name) {
+            return Ok(None);
+        }
+
+        let forward = self.resolve(&name).await?;
+        let matches = forward.is_some_and(|a| a.eq_ignore_ascii_case(address.trim()));
+        Ok(matches.then_some(name))
+    }
+
+    async fn resolver(&self, node: &[u8; 32]) -> Result<Option<String>, EnsError> {
+        let data = self
+            .call(&self.registry, &node_call("resolver(bytes32)", node))
+            .await?;
+        decode_address(&data)
+    }
+
+    async fn call(&self, to: &str, data: &str) -> Result<Vec<u8>, EnsError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_call",
+            "params": [{ "to": to, "data": data }, "latest"],
+        });
+        let response: Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| EnsError::Rpc(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| EnsError::Rpc(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(EnsError::Rpc(error.to_string()));
+        }
+        let result = response["result"].as_str().unwrap_or_default();
+        hex_decode(result.strip_prefix("0x").unwrap_or(result))
+            .ok_or_else(|| EnsError::Malformed(format!("eth_call returned {}", result)))
+    }
+}
+
+/// ENS resolution errors
+#[derive(Debug, thiserror::Error)]
+pub enum EnsError {
+    #[error("Not an ENS name: {0}")]
+    InvalidName(String),
+
+    #[error("ENS RPC failed: {0}")]
+    Rpc(String),
+
+    #[error("Malformed ENS response: {0}")]
+    Malformed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namehash() {
+        // EIP-137 vectors
+        assert_eq!(namehash(""), [0u8; 32]);
+        assert_eq!(
+            hex_encode(&namehash("eth")),
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            hex_encode(&namehash("foo.eth")),
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+
+        assert_eq!(
+            normalize_ens(" Vitalik.ETH ").as_deref(),
+            Some("vitalik.eth")
+        );
+        assert!(!is_ens_name("alice.sui"));
+        assert!(!is_ens_name(".eth"));
+        assert_eq!(
+            reverse_node("0xAbC0000000000000000000000000000000000001"),
+            "abc0000000000000000000000000000000000001.addr.reverse"
+        );
+    }
+
+    #[test]
+    fn test_decode_return_values() {
+        let word = |hex: &str| format!("{:0>64}", hex);
+        let address = hex_decode(&word("d8da6bf26964af9d7eed9e03e53415d37aa96045")).unwrap();
+        assert_eq!(
+            decode_address(&address).unwrap().as_deref(),
+            Some("0xd8da6bf26964af9d7eed9e03e53415d37aa96045")
+        );
+        assert_eq!(decode_address(&[0u8; 32]).unwrap(), None);
+
+        // "vitalik.eth": offset, length, padded bytes
+        let name = hex_decode(
+            &[
+                word("20"),
+                word("b"),
+                format!("{:0<64}", hex_encode(b"vitalik.eth")),
+            ]
+            .concat(),
+        )
+        .unwrap();
+        assert_eq!(
+            decode_string(&name).unwrap().as_deref(),
+            Some("vitalik.eth")
+        );
+        assert!(decode_string(&[0u8; 16]).is_err());
+    }
+}
@@ -0,0 +1,636 @@
+//! Intent event schemas
+//!
+//! Typed payloads of the `intent` module's events. `IntentCreated` comes in
+//! two versions: V1 is what the contract emits today, V2 adds priority tips
+//! and a risk ceiling and allows a list of target protocols. A payload is
+//! V2 when it says `"version": 2` or carries any V2-only field.
+//!
+//! Parsing is tolerant: integers may be JSON strings (how Sui encodes u64)
+//! or numbers, and unknown fields are ignored but logged once per event
+//! kind. Events that still fail to parse land in a `DeadLetterQueue` rather
+//! than being dropped silently.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+use naisu_core::{RiskTier, Tip};
+use naisu_sui::SuiEvent;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::config::Protocol;
+use crate::solver::{parse_target_protocols, IntentRequest};
+
+/// Failed events kept for inspection
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 256;
+
+/// Fields only V2 `IntentCreated` payloads have
+const V2_FIELDS: [&str; 5] = [
+    "target_protocols",
+    "tip_bps",
+    "tip_amount",
+    "max_risk",
+    "stable_only",
+];
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum EventError {
+    #[error("Unknown event type: {0}")]
+    UnknownType(String),
+
+    #[error("Unsupported {kind} version: {version}")]
+    UnsupportedVersion { kind: &'static str, version: u64 },
+
+    #[error("Malformed {kind} payload: {reason}")]
+    Malformed { kind: &'static str, reason: String },
+}
+
+// ─── Field Decoding ──────────────────────────────────────────────────────────
+
+/// Integer as a JSON number or a decimal string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Integer {
+    Number(u64),
+    Text(String),
+}
+
+impl Integer {
+    fn value<T: TryFrom<u64>, E: serde::de::Error>(self) -> Result<T, E> {
+        let n = match self {
+            Integer::Number(n) => n,
+            Integer::Text(s) => s
+                .trim()
+                .parse()
+                .map_err(|_| E::custom(format!("invalid integer {:?}", s)))?,
+        };
+        T::try_from(n).map_err(|_| E::custom(format!("integer {} out of range", n)))
+    }
+}
+
+fn integer<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64>,
+{
+    Integer::deserialize(deserializer)?.value()
+}
+
+fn opt_integer<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64>,
+{
+    Option::<Integer>::deserialize(deserializer)?
+        .map(Integer::value)
+        .transpose()
+}
+
+fn decode<T: DeserializeOwned>(kind: &'static str, json: Value) -> Result<T, EventError> {
+    serde_json::from_value(json).map_err(|e| EventError::Malformed {
+        kind,
+        reason: e.to_string(),
+    })
+}
+
+// ─── Schemas ─────────────────────────────────────────────────────────────────
+
+/// `IntentCreated` as the contract emits it today
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IntentCreatedV1 {
+    pub intent_id: String,
+    pub user: String,
+    #[serde(deserialize_with = "integer")]
+    pub amount: u64,
+    /// Basis points
+    #[serde(deserialize_with = "integer")]
+    pub min_apy: u64,
+    #[serde(deserialize_with = "integer")]
+    pub deadline: u64,
+    /// `"any"`, a protocol name or a comma-separated list
+    #[serde(default)]
+    pub target_protocol: Option<Value>,
+    /// Fields this schema doesn't know
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, Value>,
+}
+
+/// `IntentCreated` with tips and a risk ceiling
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IntentCreatedV2 {
+    pub intent_id: String,
+    pub user: String,
+    #[serde(deserialize_with = "integer")]
+    pub amount: u64,
+    /// Basis points
+    #[serde(deserialize_with = "integer")]
+    pub min_apy: u64,
+    #[serde(deserialize_with = "integer")]
+    pub deadline: u64,
+    /// Same forms as V1, or an array of names
+    #[serde(default, alias = "target_protocol")]
+    pub target_protocols: Option<Value>,
+    /// Tip as bps of the amount (wins over `tip_amount`)
+    #[serde(default, deserialize_with = "opt_integer")]
+    pub tip_bps: Option<u16>,
+    #[serde(default, deserialize_with = "opt_integer")]
+    pub tip_amount: Option<u64>,
+    /// Risk tier name, or `"any"`
+    #[serde(default)]
+    pub max_risk: Option<String>,
+    /// Stablecoin strategies only
+    #[serde(default)]
+    pub stable_only: bool,
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, Value>,
+}
+
+/// Any version of `IntentCreated`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntentCreated {
+    V1(IntentCreatedV1),
+    V2(IntentCreatedV2),
+}
+
+impl IntentCreated {
+    pub fn from_json(json: &Value) -> Result<Self, EventError> {
+        const KIND: &str = "IntentCreated";
+
+        let mut json = json.clone();
+        let version = match json.as_object_mut().and_then(|o| o.remove("version")) {
+            Some(v) => Integer::deserialize(v)
+                .and_then(Integer::value::<u64, serde_json::Error>)
+                .map_err(|e| EventError::Malformed {
+                    kind: KIND,
+                    reason: format!("version: {}", e),
+                })?,
+            None if V2_FIELDS.iter().any(|f| json.get(f).is_some()) => 2,
+            None => 1,
+        };
+
+        match version {
+            1 => decode(KIND, json).map(IntentCreated::V1),
+            2 => decode(KIND, json).map(IntentCreated::V2),
+            version => Err(EventError::UnsupportedVersion {
+                kind: KIND,
+                version,
+            }),
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        match self {
+            IntentCreated::V1(_) => 1,
+            IntentCreated::V2(_) => 2,
+        }
+    }
+
+    pub fn intent_id(&self) -> &str {
+        match self {
+            IntentCreated::V1(e) => &e.intent_id,
+            IntentCreated::V2(e) => &e.intent_id,
+        }
+    }
+
+    /// Target protocol as written (`"any"` when missing)
+    pub fn target_protocol(&self) -> String {
+        let target = match self {
+            IntentCreated::V1(e) => e.target_protocol.as_ref(),
+            IntentCreated::V2(e) => e.target_protocols.as_ref(),
+        };
+        match target {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Array(items)) => items
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(","),
+            _ => "any".to_string(),
+        }
+    }
+
+    /// Protocols the intent allows (`None` = any)
+    pub fn allowed_protocols(&self) -> Option<Vec<Protocol>> {
+        match self {
+            IntentCreated::V1(e) => e.target_protocol.as_ref(),
+            IntentCreated::V2(e) => e.target_protocols.as_ref(),
+        }
+        .and_then(parse_target_protocols)
+    }
+
+    fn unknown(&self) -> &BTreeMap<String, Value> {
+        match self {
+            IntentCreated::V1(e) => &e.unknown,
+            IntentCreated::V2(e) => &e.unknown,
+        }
+    }
+}
+
+impl From<IntentCreated> for IntentRequest {
+    fn from(created: IntentCreated) -> Self {
+        let allowed_protocols = created.allowed_protocols();
+        match created {
+            IntentCreated::V1(e) => IntentRequest {
+                id: e.intent_id,
+                user: e.user,
+                amount: e.amount,
+                min_apy: e.min_apy,
+                deadline: e.deadline,
+                allowed_protocols,
+                tip: None,
+                max_risk: None,
+                stable_only: false,
+            },
+            IntentCreated::V2(e) => {
+                let tip = e
+                    .tip_bps
+                    .map(Tip::Bps)
+                    .or(e.tip_amount.map(Tip::Flat))
+                    .filter(|t| t.amount(e.amount) > 0);
+                IntentRequest {
+                    id: e.intent_id,
+                    user: e.user,
+                    amount: e.amount,
+                    min_apy: e.min_apy,
+                    deadline: e.deadline,
+                    allowed_protocols,
+                    tip,
+                    // "any" or an unknown tier = no ceiling
+                    max_risk: e.max_risk.and_then(|r| r.parse::<RiskTier>().ok()),
+                    stable_only: e.stable_only,
+                }
+            }
+        }
+    }
+}
+
+/// An intent was filled
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IntentFulfilled {
+    pub intent_id: String,
+    #[serde(default)]
+    pub user: Option<String>,
+    pub solver: String,
+    #[serde(default)]
+    pub protocol: String,
+    /// Basis points
+    #[serde(default, deserialize_with = "integer")]
+    pub apy: u64,
+    /// APY above the user's minimum (basis points)
+    #[serde(default, deserialize_with = "integer")]
+    pub user_surplus: u64,
+    #[serde(default, deserialize_with = "integer")]
+    pub solver_fee: u64,
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, Value>,
+}
+
+impl IntentFulfilled {
+    pub fn from_json(json: &Value) -> Result<Self, EventError> {
+        decode("IntentFulfilled", json.clone())
+    }
+}
+
+/// An intent was cancelled or expired
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct IntentCancelled {
+    pub intent_id: String,
+    #[serde(default)]
+    pub user: Option<String>,
+    /// 0 = expired, 1 = cancelled by the user
+    #[serde(default, deserialize_with = "integer")]
+    pub reason: u8,
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, Value>,
+}
+
+impl IntentCancelled {
+    pub fn from_json(json: &Value) -> Result<Self, EventError> {
+        decode("IntentCancelled", json.clone())
+    }
+}
+
+/// Any event of the intent module
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntentEvent {
+    Created(IntentCreated),
+    Fulfilled(IntentFulfilled),
+    Cancelled(IntentCancelled),
+}
+
+impl IntentEvent {
+    /// Parse by the event type's struct name (`0xpkg::intent::<Name>`)
+    pub fn parse(event: &SuiEvent) -> Result<Self, EventError> {
+        let json = &event.parsed_json;
+        match event_name(&event.event_type) {
+            "IntentCreated" => IntentCreated::from_json(json).map(IntentEvent::Created),
+            "IntentFulfilled" => IntentFulfilled::from_json(json).map(IntentEvent::Fulfilled),
+            "IntentCancelled" => IntentCancelled::from_json(json).map(IntentEvent::Cancelled),
+            _ => Err(EventError::UnknownType(event.event_type.clone())),
+        }
+    }
+
+    pub fn intent_id(&self) -> &str {
+        match self {
+            IntentEvent::Created(e) => e.intent_id(),
+            IntentEvent::Fulfilled(e) => &e.intent_id,
+            IntentEvent::Cancelled(e) => &e.intent_id,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            IntentEvent::Created(_) => "IntentCreated",
+            IntentEvent::Fulfilled(_) => "IntentFulfilled",
+            IntentEvent::Cancelled(_) => "IntentCancelled",
+        }
+    }
+
+    /// Payload fields the schema ignored
+    pub fn unknown_fields(&self) -> Vec<&str> {
+        let unknown = match self {
+            IntentEvent::Created(e) => e.unknown(),
+            IntentEvent::Fulfilled(e) => &e.unknown,
+            IntentEvent::Cancelled(e) => &e.unknown,
+        };
+        unknown.keys().map(String::as_str).collect()
+    }
+}
+
+/// Struct name of a Move event type, without module path or type arguments
+fn event_name(event_type: &str) -> &str {
+    let base = event_type.split('<').next().unwrap_or(event_type);
+    base.rsplit("::").next().unwrap_or(base)
+}
+
+// ─── Dead Letters ────────────────────────────────────────────────────────────
+
+/// An event that failed to parse
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetter {
+    pub event: SuiEvent,
+    pub error: String,
+    /// Unix millis
+    pub first_seen: u64,
+    /// Times the event was polled and failed again
+    pub seen: u32,
+}
+
+impl DeadLetter {
+    fn is(&self, event: &SuiEvent) -> bool {
+        self.event.event_type == event.event_type
+            && self.event.tx_digest == event.tx_digest
+            && self.event.parsed_json == event.parsed_json
+    }
+}
+
+/// Bounded queue of unparseable events; the oldest fall off when full
+#[derive(Debug)]
+pub struct DeadLetterQueue {
+    letters: Mutex<VecDeque<DeadLetter>>,
+    capacity: usize,
+}
+
+impl Default for DeadLetterQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEAD_LETTER_CAPACITY)
+    }
+}
+
+impl DeadLetterQueue {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            letters: Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Queue a failed event; `false` if it was already queued
+    pub fn push(&self, event: &SuiEvent, error: &EventError) -> bool {
+        let mut letters = self.letters.lock().expect("dead letters lock poisoned");
+        if let Some(letter) = letters.iter_mut().find(|l| l.is(event)) {
+            letter.seen += 1;
+            return false;
+        }
+        if letters.len() == self.capacity {
+            letters.pop_front();
+        }
+        letters.push_back(DeadLetter {
+            event: event.clone(),
+            error: error.to_string(),
+            first_seen: chrono::Utc::now().timestamp_millis() as u64,
+            seen: 1,
+        });
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.letters
+            .lock()
+            .expect("dead letters lock poisoned")
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Queued events, oldest first
+    pub fn snapshot(&self) -> Vec<DeadLetter> {
+        self.letters
+            .lock()
+            .expect("dead letters lock poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Take every queued event, e.g. to retry after a schema update
+    pub fn drain(&self) -> Vec<DeadLetter> {
+        self.letters
+            .lock()
+            .expect("dead letters lock poisoned")
+            .drain(..)
+            .collect()
+    }
+}
+
+// ─── Parser ──────────────────────────────────────────────────────────────────
+
+/// Parses polled events, logging schema drift and keeping failures
+#[derive(Debug, Default)]
+pub struct EventParser {
+    dead_letters: DeadLetterQueue,
+    /// `<kind>.<field>` already warned about
+    warned: Mutex<HashSet<String>>,
+}
+
+impl EventParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_dead_letters(mut self, dead_letters: DeadLetterQueue) -> Self {
+        self.dead_letters = dead_letters;
+        self
+    }
+
+    /// Parse an event, or dead-letter it
+    pub fn parse(&self, event: &SuiEvent) -> Option<IntentEvent> {
+        match IntentEvent::parse(event) {
+            Ok(parsed) => {
+                self.warn_unknown(&parsed);
+                Some(parsed)
+            }
+            Err(e) => {
+                if self.dead_letters.push(event, &e) {
+                    warn!(
+                        "⚠️  Dead-lettered event (tx {}): {}",
+                        event.tx_digest.as_deref().unwrap_or("unknown"),
+                        e
+                    );
+                }
+                None
+            }
+        }
+    }
+
+    fn warn_unknown(&self, event: &IntentEvent) {
+        let mut warned = self.warned.lock().expect("warned fields lock poisoned");
+        for field in event.unknown_fields() {
+            if warned.insert(format!("{}.{}", event.kind(), field)) {
+                warn!(
+                    "⚠️  Ignoring unknown field `{}` in {} events",
+                    field,
+                    event.kind()
+                );
+            }
+        }
+    }
+
+    pub fn dead_letters(&self) -> &DeadLetterQueue {
+        &self.dead_letters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(name: &str, parsed_json: Value) -> SuiEvent {
+        SuiEvent {
+            event_type: format!("0xpkg::intent::{}", name),
+            parsed_json,
+            sender: None,
+            tx_digest: Some("0xtx".to_string()),
+            timestamp_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_created_versions() {
+        let v1 = event(
+            "IntentCreated",
+            json!({
+                "intent_id": "0xi",
+                "user": "0xu",
+                "amount": "1000",
+                "min_apy": 720,
+                "deadline": "99",
+                "target_protocol": "scallop,navi",
+                "memo": "hi"
+            }),
+        );
+        let IntentEvent::Created(created) = IntentEvent::parse(&v1).unwrap() else {
+            panic!("not a created event");
+        };
+        assert_eq!(created.version(), 1);
+        assert_eq!(created.target_protocol(), "scallop,navi");
+        let intent = IntentRequest::from(created.clone());
+        assert_eq!(
+            (intent.amount, intent.min_apy, intent.deadline),
+            (1000, 720, 99)
+        );
+        assert_eq!(
+            intent.allowed_protocols,
+            Some(vec![Protocol::Scallop, Protocol::Navi])
+        );
+        assert_eq!(IntentEvent::Created(created).unknown_fields(), vec!["memo"]);
+
+        let v2 = event(
+            "IntentCreated",
+            json!({
+                "intent_id": "0xi",
+                "user": "0xu",
+                "amount": "1000000",
+                "min_apy": "500",
+                "deadline": "99",
+                "target_protocols": ["scallop"],
+                "tip_bps": "25",
+                "max_risk": "any",
+                "stable_only": true
+            }),
+        );
+        let Ok(IntentEvent::Created(created)) = IntentEvent::parse(&v2) else {
+            panic!("not a created event");
+        };
+        assert_eq!(created.version(), 2);
+        let intent = IntentRequest::from(created);
+        assert_eq!(intent.tip, Some(Tip::Bps(25)));
+        assert_eq!(intent.max_risk, None);
+        assert!(intent.stable_only);
+
+        let v3 = event("IntentCreated", json!({ "version": 3, "intent_id": "0xi" }));
+        assert!(matches!(
+            IntentEvent::parse(&v3),
+            Err(EventError::UnsupportedVersion { version: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_failures_are_dead_lettered() {
+        let parser = EventParser::new();
+
+        let fulfilled = event(
+            "IntentFulfilled",
+            json!({ "intent_id": "0xi", "solver": "0xs", "apy": "800" }),
+        );
+        let Some(IntentEvent::Fulfilled(f)) = parser.parse(&fulfilled) else {
+            panic!("not a fulfilled event");
+        };
+        assert_eq!((f.apy, f.solver_fee), (800, 0));
+
+        let cancelled = event(
+            "IntentCancelled",
+            json!({ "intent_id": "0xi", "reason": 1 }),
+        );
+        assert!(matches!(
+            parser.parse(&cancelled),
+            Some(IntentEvent::Cancelled(IntentCancelled { reason: 1, .. }))
+        ));
+
+        // Wrong types, missing fields and unknown events are kept, once each
+        let bad_amount = event(
+            "IntentCreated",
+            json!({ "intent_id": "0xi", "user": "0xu", "amount": "lots", "min_apy": "1", "deadline": "1" }),
+        );
+        let no_solver = event("IntentFulfilled", json!({ "intent_id": "0xi" }));
+        let unknown = event("IntentRepriced", json!({}));
+        for e in [&bad_amount, &no_solver, &unknown, &bad_amount] {
+            assert!(parser.parse(e).is_none());
+        }
+
+        let letters = parser.dead_letters().snapshot();
+        assert_eq!(letters.len(), 3);
+        assert_eq!(letters[0].seen, 2);
+        assert!(letters[0].error.contains("invalid integer"));
+        assert!(letters[2].error.starts_with("Unknown event type"));
+        assert_eq!(parser.dead_letters().drain().len(), 3);
+        assert!(parser.dead_letters().is_empty());
+    }
+}
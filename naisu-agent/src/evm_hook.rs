@@ -0,0 +1,285 @@
+//! EVM hook listener
+//!
+//! Polls the V4 hook's `IntentCreated` logs with `eth_getLogs` and decodes
+//! them into `IntentCreatedEvent`s. The hook emits one after swapping the
+//! user's input to USDC, so every event starts an EvmToSui intent that is
+//! past its swap.
+//!
+//! ```solidity
+//! event IntentCreated(
+//!     bytes32 indexed intentId,
+//!     address indexed user,
+//!     bytes32 suiDestination,
+//!     address inputToken,
+//!     uint256 inputAmount,
+//!     uint256 usdcAmount,
+//!     uint8 strategyId,
+//!     uint64 timestamp
+//! );
+//! ```
+
+use std::time::Duration;
+
+use naisu_core::IntentCreatedEvent;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+
+use crate::executor::signer::{hex_decode, hex_encode};
+
+/// Canonical signature of the hook's event
+pub const INTENT_CREATED_SIGNATURE: &str =
+    "IntentCreated(bytes32,address,bytes32,address,uint256,uint256,uint8,uint64)";
+
+/// Blocks scanned per `eth_getLogs` call (public RPCs cap the range)
+pub const MAX_BLOCK_RANGE: u64 = 2_000;
+
+/// Time between polls
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// `topic0` of `IntentCreated` logs
+pub fn intent_created_topic() -> String {
+    format!(
+        "0x{}",
+        hex_encode(&Keccak256::digest(INTENT_CREATED_SIGNATURE.as_bytes()))
+    )
+}
+
+/// A decoded hook event and where it was emitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookLog {
+    pub event: IntentCreatedEvent,
+    /// Transaction that swapped and emitted the event
+    pub tx_hash: String,
+    pub block_number: u64,
+}
+
+/// Decode one `eth_getLogs` entry
+pub fn decode_log(log: &Value) -> Result<HookLog, HookError> {
+    let topics: Vec<&str> = log["topics"]
+        .as_array()
+        .map(|t| t.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    if topics.len() != 3 {
+        return Err(HookError::Malformed(format!(
+            "expected 3 topics, got {}",
+            topics.len()
+        )));
+    }
+    if !topics[0].eq_ignore_ascii_case(&intent_created_topic()) {
+        return Err(HookError::Malformed("not an IntentCreated log".into()));
+    }
+
+    let intent_id = word(topics[1], "intentId")?;
+    let user = word(topics[2], "user")?;
+    let data = log["data"].as_str().unwrap_or_default();
+    let data = hex_decode(data.strip_prefix("0x").unwrap_or(data))
+        .filter(|d| d.len() == 6 * 32)
+        .ok_or_else(|| HookError::Malformed("data is not 6 ABI words".into()))?;
+    let words: Vec<&[u8]> = data.chunks(32).collect();
+
+    let tx_hash = log["transactionHash"]
+        .as_str()
+        .ok_or_else(|| HookError::Malformed("missing transactionHash".into()))?;
+    let block_number = log["blockNumber"]
+        .as_str()
+        .and_then(parse_quantity)
+        .ok_or_else(|| HookError::Malformed("missing blockNumber".into()))?;
+
+    Ok(HookLog {
+        event: IntentCreatedEvent {
+            intent_id: format!("0x{}", hex_encode(&intent_id)),
+            user: format!("0x{}", hex_encode(&user[12..])),
+            sui_destination: format!("0x{}", hex_encode(words[0])),
+            input_token: format!("0x{}", hex_encode(&words[1][12..])),
+            input_amount: uint(words[2], 16, "inputAmount")?.to_string(),
+            usdc_amount: uint(words[3], 16, "usdcAmount")?.to_string(),
+            strategy_id: uint(words[4], 1, "strategyId")? as u8,
+            timestamp: uint(words[5], 8, "timestamp")? as u64,
+        },
+        tx_hash: tx_hash.to_string(),
+        block_number,
+    })
+}
+
+/// 32-byte 0x-hex topic
+fn word(topic: &str, field: &str) -> Result<Vec<u8>, HookError> {
+    hex_decode(topic.strip_prefix("0x").unwrap_or(topic))
+        .filter(|w| w.len() == 32)
+        .ok_or_else(|| HookError::Malformed(format!("{} is not a 32-byte word", field)))
+}
+
+/// Unsigned ABI word that must fit in its low `len` bytes
+fn uint(word: &[u8], len: usize, field: &str) -> Result<u128, HookError> {
+    if word[..32 - len].iter().any(|&b| b != 0) {
+        return Err(HookError::Malformed(format!("{} overflows", field)));
+    }
+    Ok(word[32 - len..]
+        .iter()
+        .fold(0u128, |acc, &b| (acc << 8) | b as u128))
+}
+
+/// JSON-RPC hex quantity (`0x1a`)
+fn parse_quantity(value: &str) -> Option<u64> {
+    u64::from_str_radix(value.strip_prefix("0x")?, 16).ok()
+}
+
+/// Reads hook events from an EVM JSON-RPC endpoint
+pub struct HookListener {
+    client: reqwest::Client,
+    rpc_url: String,
+    hook_address: String,
+    interval: Duration,
+}
+
+impl HookListener {
+    pub fn new(rpc_url: &str, hook_address: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            rpc_url: rpc_url.to_string(),
+            hook_address: hook_address.to_string(),
+            interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Latest block number
+    pub async fn block_number(&self) -> Result<u64, HookError> {
+        let result = self.rpc("eth_blockNumber", json!([])).await?;
+        result
+            .as_str()
+            .and_then(parse_quantity)
+            .ok_or_else(|| HookError::Rpc(format!("invalid block number: {}", result)))
+    }
+
+    /// Decoded events in `from..=to`; undecodable logs are skipped
+    pub async fn logs(&self, from: u64, to: u64) -> Result<Vec<HookLog>, HookError> {
+        let filter = json!([{
+            "address": self.hook_address,
+            "fromBlock": format!("0x{:x}", from),
+            "toBlock": format!("0x{:x}", to),
+            "topics": [intent_created_topic()],
+        }]);
+        let result = self.rpc("eth_getLogs", filter).await?;
+
+        Ok(result
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|log| match decode_log(log) {
+                Ok(log) => Some(log),
+                Err(e) => {
+                    tracing::warn!("Skipping hook log: {}", e);
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Events from `from` towards the chain head, at most `MAX_BLOCK_RANGE`
+    /// blocks; returns them with the block to poll from next
+    pub async fn poll(&self, from: u64) -> Result<(Vec<HookLog>, u64), HookError> {
+        let head = self.block_number().await?;
+        if head < from {
+            return Ok((Vec::new(), from));
+        }
+        let to = head.min(from + MAX_BLOCK_RANGE - 1);
+        Ok((self.logs(from, to).await?, to + 1))
+    }
+
+    async fn rpc(&self, method: &str, params: Value) -> Result<Value, HookError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response: Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| HookError::Rpc(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| HookError::Rpc(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(HookError::Rpc(error.to_string()));
+        }
+        Ok(response["result"].clone())
+    }
+}
+
+/// Hook listener errors
+#[derive(Debug, thiserror::Error)]
+pub enum HookError {
+    #[error("EVM RPC failed: {0}")]
+    Rpc(String),
+
+    #[error("Malformed hook log: {0}")]
+    Malformed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn padded(hex: &str) -> String {
+        format!("{:0>64}", hex)
+    }
+
+    #[test]
+    fn test_decode_log() {
+        let data = [
+            padded("ab"),                                       // suiDestination
+            padded("036cbd53842c5426634e7929541ec2318f3dcf7e"), // inputToken
+            padded("de0b6b3a7640000"),                          // 1e18
+            padded("f4240"),                                    // 1_000_000
+            padded("3"),
+            padded("6553f100"),
+        ]
+        .concat();
+        let mut log = json!({
+            "topics": [
+                intent_created_topic(),
+                format!("0x{}", padded("1")),
+                format!("0x{}", padded("00000000000000000000000011aa")),
+            ],
+            "data": format!("0x{}", data),
+            "transactionHash": "0xswap",
+            "blockNumber": "0x10",
+        });
+
+        let decoded = decode_log(&log).unwrap();
+        assert_eq!(decoded.block_number, 16);
+        assert_eq!(decoded.tx_hash, "0xswap");
+        let event = decoded.event;
+        assert_eq!(event.intent_id, format!("0x{}", padded("1")));
+        assert_eq!(event.user, format!("0x{:0>40}", "11aa"));
+        assert_eq!(event.sui_destination, format!("0x{}", padded("ab")));
+        assert_eq!(
+            event.input_token,
+            "0x036cbd53842c5426634e7929541ec2318f3dcf7e"
+        );
+        assert_eq!(event.input_amount, "1000000000000000000");
+        assert_eq!(event.usdc_amount, "1000000");
+        assert_eq!(event.strategy_id, 3);
+        assert_eq!(event.timestamp, 1_700_000_000);
+
+        // Strategy IDs are one byte
+        log["data"] = json!(format!("0x{}", data.replace(&padded("3"), &padded("100"))));
+        assert!(matches!(decode_log(&log), Err(HookError::Malformed(_))));
+
+        log["topics"][0] = json!(format!("0x{}", padded("0")));
+        assert!(decode_log(&log).is_err());
+    }
+}
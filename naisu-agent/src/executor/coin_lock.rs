@@ -0,0 +1,78 @@
+//! Process-wide gas coin reservations
+//!
+//! Concurrent fulfillments share one solver wallet. A coin picked by one
+//! task is reserved until its transaction finishes, so another task never
+//! selects (and double-spends) the same object version.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+fn reserved() -> &'static Mutex<HashSet<String>> {
+    static RESERVED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+    RESERVED.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// A reserved coin, released when dropped
+#[derive(Debug)]
+pub struct CoinGuard {
+    id: String,
+}
+
+impl CoinGuard {
+    /// Reserve the first coin in `candidates` (preference order) not held by another task
+    pub fn reserve_first<I, S>(candidates: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut set = reserved().lock().expect("coin lock poisoned");
+        candidates
+            .into_iter()
+            .map(Into::into)
+            .find(|id| !set.contains(id))
+            .map(|id| {
+                set.insert(id.clone());
+                Self { id }
+            })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Drop for CoinGuard {
+    fn drop(&mut self) {
+        reserved()
+            .lock()
+            .expect("coin lock poisoned")
+            .remove(&self.id);
+    }
+}
+
+/// Whether a coin is currently reserved by some task
+pub fn is_reserved(coin_id: &str) -> bool {
+    reserved()
+        .lock()
+        .expect("coin lock poisoned")
+        .contains(coin_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservation_skips_held_coins_and_releases_on_drop() {
+        let first = CoinGuard::reserve_first(["0xcoinlock_a", "0xcoinlock_b"]).unwrap();
+        assert_eq!(first.id(), "0xcoinlock_a");
+
+        let second = CoinGuard::reserve_first(["0xcoinlock_a", "0xcoinlock_b"]).unwrap();
+        assert_eq!(second.id(), "0xcoinlock_b");
+        assert!(CoinGuard::reserve_first(["0xcoinlock_a", "0xcoinlock_b"]).is_none());
+
+        drop(first);
+        assert!(!is_reserved("0xcoinlock_a"));
+        assert!(is_reserved("0xcoinlock_b"));
+    }
+}
@@ -0,0 +1,389 @@
+//! EVM Transaction Executor
+//!
+//! Signs and submits EIP-1559 transactions from a relayer key over plain
+//! JSON-RPC. Used to finish CCTP transfers into EVM chains: once Circle
+//! has attested a burn, `receive_message` calls the destination chain's
+//! MessageTransmitter, which mints the USDC to the recipient.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use k256::ecdsa::SigningKey;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+use tracing::info;
+use zeroize::Zeroizing;
+
+use super::signer::{hex_decode, hex_encode};
+use crate::intent_auth::evm_address;
+
+/// `receiveMessage(bytes,bytes)` on the MessageTransmitter
+pub const RECEIVE_MESSAGE_SIGNATURE: &str = "receiveMessage(bytes,bytes)";
+
+/// How long `wait_for_receipt` polls before giving up
+pub const DEFAULT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(120);
+
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Estimated gas is padded by this much (percent)
+const GAS_LIMIT_BUFFER_PERCENT: u64 = 20;
+
+/// A mined transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmReceipt {
+    pub tx_hash: String,
+    pub block_number: u64,
+    pub success: bool,
+}
+
+/// Fields of an EIP-1559 (type 2) transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Tx {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub gas_limit: u64,
+    /// 20-byte recipient
+    pub to: Vec<u8>,
+    pub value: u128,
+    pub data: Vec<u8>,
+}
+
+impl Eip1559Tx {
+    fn fields(&self) -> Vec<Vec<u8>> {
+        vec![
+            rlp_uint(self.chain_id as u128),
+            rlp_uint(self.nonce as u128),
+            rlp_uint(self.max_priority_fee_per_gas),
+            rlp_uint(self.max_fee_per_gas),
+            rlp_uint(self.gas_limit as u128),
+            rlp_bytes(&self.to),
+            rlp_uint(self.value),
+            rlp_bytes(&self.data),
+            // Empty access list
+            rlp_list(&[]),
+        ]
+    }
+
+    /// Hash the sender signs
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let payload = [&[0x02][..], &rlp_list(&self.fields())].concat();
+        Keccak256::digest(payload).into()
+    }
+
+    /// `(y_parity, r, s)` over the signing hash
+    pub fn signature(&self, key: &SigningKey) -> Result<(bool, [u8; 32], [u8; 32])> {
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&self.signing_hash())
+            .map_err(|e| anyhow!("Signing failed: {}", e))?;
+        let bytes = signature.to_bytes();
+        let (mut r, mut s) = ([0u8; 32], [0u8; 32]);
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        Ok((recovery_id.is_y_odd(), r, s))
+    }
+
+    /// Raw signed transaction, as `eth_sendRawTransaction` takes it
+    pub fn sign(&self, key: &SigningKey) -> Result<Vec<u8>> {
+        let (y_parity, r, s) = self.signature(key)?;
+        let mut fields = self.fields();
+        fields.push(rlp_uint(y_parity as u128));
+        fields.push(rlp_bytes(trim_leading_zeros(&r)));
+        fields.push(rlp_bytes(trim_leading_zeros(&s)));
+        Ok([&[0x02][..], &rlp_list(&fields)].concat())
+    }
+}
+
+// ─── ABI / RLP ───────────────────────────────────────────────────────────────
+
+/// First 4 bytes of the signature's keccak256
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Calldata of `receiveMessage(message, attestation)`
+pub fn receive_message_calldata(message: &[u8], attestation: &[u8]) -> Vec<u8> {
+    let padded_len = |b: &[u8]| b.len().div_ceil(32) * 32;
+    let head = 2 * 32;
+    let second_offset = head + 32 + padded_len(message);
+
+    let mut data = selector(RECEIVE_MESSAGE_SIGNATURE).to_vec();
+    data.extend(abi_word(head as u128));
+    data.extend(abi_word(second_offset as u128));
+    for bytes in [message, attestation] {
+        data.extend(abi_word(bytes.len() as u128));
+        data.extend(bytes);
+        data.resize(data.len() + padded_len(bytes) - bytes.len(), 0);
+    }
+    data
+}
+
+fn abi_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+fn rlp_uint(value: u128) -> Vec<u8> {
+    rlp_bytes(trim_leading_zeros(&value.to_be_bytes()))
+}
+
+fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    match bytes {
+        [b] if *b < 0x80 => vec![*b],
+        _ => [rlp_length(bytes.len(), 0x80), bytes.to_vec()].concat(),
+    }
+}
+
+/// Encode already-encoded items as a list
+fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    [rlp_length(payload.len(), 0xc0), payload].concat()
+}
+
+fn rlp_length(len: usize, offset: u8) -> Vec<u8> {
+    if len <= 55 {
+        return vec![offset + len as u8];
+    }
+    let len_bytes = trim_leading_zeros(&len.to_be_bytes()).to_vec();
+    [vec![offset + 55 + len_bytes.len() as u8], len_bytes].concat()
+}
+
+fn parse_quantity(value: &Value) -> Option<u128> {
+    u128::from_str_radix(value.as_str()?.strip_prefix("0x")?, 16).ok()
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>> {
+    hex_decode(value.strip_prefix("0x").unwrap_or(value))
+        .ok_or_else(|| anyhow!("Invalid hex: {}", value))
+}
+
+// ─── Executor ────────────────────────────────────────────────────────────────
+
+/// Submits transactions to one EVM chain from a relayer key
+pub struct EvmExecutor {
+    client: reqwest::Client,
+    rpc_url: String,
+    chain_id: u64,
+    key: SigningKey,
+    address: String,
+    confirm_timeout: Duration,
+}
+
+impl std::fmt::Debug for EvmExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvmExecutor")
+            .field("rpc_url", &self.rpc_url)
+            .field("chain_id", &self.chain_id)
+            .field("address", &self.address)
+            .field("key", &naisu_core::REDACTED)
+            .finish()
+    }
+}
+
+impl EvmExecutor {
+    /// `private_key` is 32 hex bytes, `0x` optional
+    pub fn new(rpc_url: &str, chain_id: u64, private_key: &str) -> Result<Self> {
+        // Not `parse_hex`: its error would echo the key
+        let raw = hex_decode(private_key.strip_prefix("0x").unwrap_or(private_key))
+            .map(Zeroizing::new)
+            .ok_or_else(|| anyhow!("Private key is not hex"))?;
+        let key =
+            SigningKey::from_slice(&raw).map_err(|_| anyhow!("Invalid secp256k1 private key"))?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            rpc_url: rpc_url.to_string(),
+            chain_id,
+            address: evm_address(key.verifying_key()),
+            key,
+            confirm_timeout: DEFAULT_CONFIRM_TIMEOUT,
+        })
+    }
+
+    pub fn with_confirm_timeout(mut self, timeout: Duration) -> Self {
+        self.confirm_timeout = timeout;
+        self
+    }
+
+    /// Relayer address
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Mint a CCTP transfer on this chain; returns the mined receipt
+    pub async fn receive_message(
+        &self,
+        message_transmitter: &str,
+        message: &[u8],
+        attestation: &[u8],
+    ) -> Result<EvmReceipt> {
+        let data = receive_message_calldata(message, attestation);
+        let tx_hash = self.send_transaction(message_transmitter, data).await?;
+        info!("CCTP receiveMessage submitted: {}", tx_hash);
+        self.wait_for_receipt(&tx_hash).await
+    }
+
+    /// Sign and submit a call; returns the transaction hash
+    pub async fn send_transaction(&self, to: &str, data: Vec<u8>) -> Result<String> {
+        let call = json!({
+            "from": self.address,
+            "to": to,
+            "data": format!("0x{}", hex_encode(&data)),
+        });
+        let nonce = self
+            .rpc("eth_getTransactionCount", json!([self.address, "pending"]))
+            .await?;
+        let gas = self.rpc("eth_estimateGas", json!([call])).await?;
+        let gas_price = self.rpc("eth_gasPrice", json!([])).await?;
+        let priority = self.rpc("eth_maxPriorityFeePerGas", json!([])).await;
+
+        let gas_price = parse_quantity(&gas_price).context("Invalid gas price")?;
+        let gas = parse_quantity(&gas).context("Invalid gas estimate")? as u64;
+        let max_priority_fee_per_gas = priority
+            .ok()
+            .and_then(|p| parse_quantity(&p))
+            .unwrap_or(gas_price);
+        let tx = Eip1559Tx {
+            chain_id: self.chain_id,
+            nonce: parse_quantity(&nonce).context("Invalid nonce")? as u64,
+            max_priority_fee_per_gas,
+            // Room for the base fee to double before inclusion
+            max_fee_per_gas: 2 * gas_price + max_priority_fee_per_gas,
+            gas_limit: gas + gas * GAS_LIMIT_BUFFER_PERCENT / 100,
+            to: parse_hex(to)?,
+            value: 0,
+            data,
+        };
+
+        let raw = tx.sign(&self.key)?;
+        let hash = self
+            .rpc(
+                "eth_sendRawTransaction",
+                json!([format!("0x{}", hex_encode(&raw))]),
+            )
+            .await?;
+        hash.as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("No transaction hash returned"))
+    }
+
+    /// Poll until the transaction is mined or the confirm timeout passes
+    pub async fn wait_for_receipt(&self, tx_hash: &str) -> Result<EvmReceipt> {
+        let deadline = tokio::time::Instant::now() + self.confirm_timeout;
+        loop {
+            let receipt = self
+                .rpc("eth_getTransactionReceipt", json!([tx_hash]))
+                .await?;
+            if !receipt.is_null() {
+                return Ok(EvmReceipt {
+                    tx_hash: tx_hash.to_string(),
+                    block_number: parse_quantity(&receipt["blockNumber"]).unwrap_or(0) as u64,
+                    success: receipt["status"].as_str() == Some("0x1"),
+                });
+            }
+            if tokio::time::Instant::now() >= deadline {
+                bail!("Transaction {} not mined in time", tx_hash);
+            }
+            tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn rpc(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response: Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{} failed: {}", method, error);
+        }
+        Ok(response["result"].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intent_auth::recover_evm_address;
+
+    #[test]
+    fn test_rlp() {
+        assert_eq!(rlp_uint(0), vec![0x80]);
+        assert_eq!(rlp_uint(0x7f), vec![0x7f]);
+        assert_eq!(rlp_uint(1024), vec![0x82, 0x04, 0x00]);
+        assert_eq!(rlp_bytes(b"dog"), vec![0x83, b'd', b'o', b'g']);
+        assert_eq!(rlp_list(&[]), vec![0xc0]);
+
+        let long = rlp_bytes(&[0xaa; 56]);
+        assert_eq!(&long[..2], &[0xb8, 56]);
+        assert_eq!(long.len(), 58);
+    }
+
+    #[test]
+    fn test_receive_message_calldata() {
+        assert_eq!(
+            hex_encode(&selector("transfer(address,uint256)")),
+            "a9059cbb"
+        );
+
+        let data = receive_message_calldata(&[1; 40], &[2; 65]);
+        // selector, 2 offsets, 40 bytes in 2 words, 65 bytes in 3 words
+        assert_eq!(data.len(), 4 + 32 * (2 + 1 + 2 + 1 + 3));
+        assert_eq!(data[4 + 31], 64);
+        assert_eq!(data[4 + 63], 64 + 32 + 64);
+        assert_eq!(data[4 + 64 + 31], 40);
+        assert_eq!(data[4 + 64 + 32], 1);
+        assert_eq!(data[4 + 64 + 32 + 40], 0);
+    }
+
+    #[test]
+    fn test_sign_recovers_sender() {
+        let key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let tx = Eip1559Tx {
+            chain_id: 84532,
+            nonce: 3,
+            max_priority_fee_per_gas: 1_000_000,
+            max_fee_per_gas: 3_000_000,
+            gas_limit: 200_000,
+            to: vec![0x78; 20],
+            value: 0,
+            data: vec![0xde, 0xad],
+        };
+        let raw = tx.sign(&key).unwrap();
+        assert_eq!(raw[0], 0x02);
+
+        let (y_parity, r, s) = tx.signature(&key).unwrap();
+        let tail = [
+            rlp_bytes(trim_leading_zeros(&r)),
+            rlp_bytes(trim_leading_zeros(&s)),
+        ]
+        .concat();
+        assert!(raw.ends_with(&tail));
+
+        let signature = format!(
+            "0x{}{}{:02x}",
+            hex_encode(&r),
+            hex_encode(&s),
+            y_parity as u8
+        );
+        let signer = recover_evm_address(&tx.signing_hash(), &signature).unwrap();
+        assert_eq!(signer, evm_address(key.verifying_key()));
+    }
+}
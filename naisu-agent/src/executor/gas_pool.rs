@@ -0,0 +1,417 @@
+//! Dedicated gas coins
+//!
+//! Paying gas from the coin a deposit is split from couples the two: a
+//! fulfillment that locks or drains that coin also stalls every other
+//! transaction needing it. With the pool enabled the wallet keeps `size`
+//! small coins for gas only. Fulfillments take one round-robin as their
+//! `--gas-coin` and split principal from a separate coin, so principal is
+//! never spent on gas.
+//!
+//! A rebalancing task keeps the pool at `size` coins of `target_mist`:
+//! coins that fell below `min_mist` are merged back into principal and
+//! fresh ones are split off it. The refill transaction is the one place
+//! principal pays gas. It is only sent while the `gas_rebalancer` feature
+//! flag is on; until then the pool uses the coins it already has.
+
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use super::coin_lock::{is_reserved, CoinGuard};
+use crate::config::SharedConfig;
+
+/// Gas budget of a rebalancing transaction (MIST)
+const REBALANCE_GAS_BUDGET: u64 = 20_000_000;
+
+// ─── Config ──────────────────────────────────────────────────────────────────
+
+/// `[gas_pool]` section of the agent config
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GasPoolConfig {
+    pub enabled: bool,
+    /// Gas coins kept in the pool
+    pub size: usize,
+    /// Balance a new gas coin is split at (MIST)
+    pub target_mist: u64,
+    /// Coins below this are retired and replaced (MIST)
+    pub min_mist: u64,
+    pub rebalance_secs: u64,
+}
+
+impl Default for GasPoolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            size: 4,
+            target_mist: 500_000_000,
+            min_mist: 100_000_000,
+            rebalance_secs: 60,
+        }
+    }
+}
+
+impl GasPoolConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.size == 0 {
+            return Err("size must be at least 1".to_string());
+        }
+        if self.min_mist >= self.target_mist {
+            return Err("min_mist must be below target_mist".to_string());
+        }
+        if self.rebalance_secs == 0 {
+            return Err("rebalance_secs must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+// ─── Pool ────────────────────────────────────────────────────────────────────
+
+/// Gas coins of the solver wallet, handed out round-robin
+#[derive(Debug, Default)]
+pub struct GasPool {
+    enabled: AtomicBool,
+    coins: Mutex<Vec<String>>,
+    cursor: AtomicUsize,
+}
+
+impl GasPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide pool used by the executors
+    pub fn global() -> &'static GasPool {
+        static POOL: OnceLock<GasPool> = OnceLock::new();
+        POOL.get_or_init(GasPool::new)
+    }
+
+    /// Whether fulfillments must pay gas from the pool
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn coins(&self) -> Vec<String> {
+        self.coins.lock().expect("gas pool lock poisoned").clone()
+    }
+
+    pub fn set_coins(&self, coins: Vec<String>) {
+        *self.coins.lock().expect("gas pool lock poisoned") = coins;
+    }
+
+    pub fn contains(&self, coin_id: &str) -> bool {
+        self.coins
+            .lock()
+            .expect("gas pool lock poisoned")
+            .iter()
+            .any(|id| id == coin_id)
+    }
+
+    /// Reserve the next free gas coin, starting after the last one handed out
+    pub fn next(&self) -> Option<CoinGuard> {
+        let coins = self.coins();
+        if coins.is_empty() {
+            return None;
+        }
+        let start = self.cursor.fetch_add(1, Ordering::Relaxed) % coins.len();
+        CoinGuard::reserve_first(coins.iter().cycle().skip(start).take(coins.len()).cloned())
+    }
+}
+
+// ─── Rebalancing ─────────────────────────────────────────────────────────────
+
+/// What one rebalancing pass changes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RebalancePlan {
+    /// Pool after the pass, before new coins are added
+    pub keep: Vec<String>,
+    /// Drained gas coins to merge back into principal
+    pub retire: Vec<String>,
+    /// Gas coins of `target_mist` to split off principal
+    pub create: usize,
+}
+
+impl RebalancePlan {
+    /// Compare the pool with the wallet's coins (`(id, balance)`)
+    ///
+    /// Pool coins the wallet no longer owns are dropped; drained ones in
+    /// use stay until their transaction is done. When the pool is short,
+    /// unpooled coins no bigger than `target_mist` (gas coins from before
+    /// a restart) are adopted before new ones are split.
+    pub fn new(owned: &[(String, u64)], pool: &[String], config: &GasPoolConfig) -> Self {
+        let mut plan = Self::default();
+        for id in pool {
+            let Some((_, balance)) = owned.iter().find(|(owned_id, _)| owned_id == id) else {
+                continue;
+            };
+            if *balance < config.min_mist && !is_reserved(id) {
+                plan.retire.push(id.clone());
+            } else {
+                plan.keep.push(id.clone());
+            }
+        }
+
+        let adoptable = owned.iter().filter(|(id, balance)| {
+            !pool.contains(id) && (config.min_mist..=config.target_mist).contains(balance)
+        });
+        for (id, _) in adoptable {
+            if plan.keep.len() >= config.size {
+                break;
+            }
+            plan.keep.push(id.clone());
+        }
+
+        plan.create = config.size.saturating_sub(plan.keep.len());
+        plan
+    }
+
+    pub fn is_noop(&self) -> bool {
+        self.retire.is_empty() && self.create == 0
+    }
+
+    /// `sui client ptb` arguments, paid by and funded from `principal`
+    pub fn ptb_args(&self, principal: &str, owner: &str, config: &GasPoolConfig) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "--gas-budget".into(),
+            REBALANCE_GAS_BUDGET.to_string(),
+            "--gas-coin".into(),
+            format!("@{}", principal),
+        ];
+        if !self.retire.is_empty() {
+            let retired: Vec<String> = self.retire.iter().map(|id| format!("@{}", id)).collect();
+            args.extend([
+                "--merge-coins".into(),
+                "gas".into(),
+                "[".into(),
+                retired.join(","),
+                "]".into(),
+            ]);
+        }
+        if self.create > 0 {
+            let amounts = vec![config.target_mist.to_string(); self.create];
+            let coins: Vec<String> = (0..self.create)
+                .map(|i| format!("gas_coins.{}", i))
+                .collect();
+            args.extend([
+                "--split-coins".into(),
+                "gas".into(),
+                "[".into(),
+                amounts.join(","),
+                "]".into(),
+                "--assign".into(),
+                "gas_coins".into(),
+                "--transfer-objects".into(),
+                "[".into(),
+                coins.join(","),
+                "]".into(),
+                "@".into(),
+                owner.to_string(),
+            ]);
+        }
+        args
+    }
+}
+
+/// Keeps the global pool topped up for `address`
+pub struct GasPoolRebalancer {
+    config: GasPoolConfig,
+    address: String,
+    /// Checked for the `gas_rebalancer` feature flag before each transaction
+    shared: Option<SharedConfig>,
+}
+
+impl GasPoolRebalancer {
+    pub fn new(config: GasPoolConfig, address: impl Into<String>) -> Self {
+        Self {
+            config,
+            address: address.into(),
+            shared: None,
+        }
+    }
+
+    /// Only send rebalancing transactions while `gas_rebalancer` is on
+    pub fn with_shared_config(mut self, shared: SharedConfig) -> Self {
+        self.shared = Some(shared);
+        self
+    }
+
+    fn may_transact(&self) -> bool {
+        self.shared
+            .as_ref()
+            .is_none_or(|shared| shared.get().features.gas_rebalancer)
+    }
+
+    /// SUI coins of the wallet as `(id, balance)`
+    fn owned_coins(&self) -> Result<Vec<(String, u64)>> {
+        let output = Command::new("sui")
+            .args(["client", "gas", &self.address, "--json"])
+            .output()
+            .context("Failed to run sui client gas")?;
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("Failed to get gas objects: {}", err));
+        }
+        let gas_objects: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        Ok(gas_objects
+            .as_array()
+            .map(|data| {
+                data.iter()
+                    .filter_map(|obj| {
+                        let id = obj.get("gasCoinId")?.as_str()?.to_string();
+                        let balance = obj.get("mistBalance")?.as_u64()?;
+                        Some((id, balance))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Run one pass; returns the pool size afterwards
+    pub async fn rebalance_once(&self) -> Result<usize> {
+        let pool = GasPool::global();
+        let owned = self.owned_coins()?;
+        let plan = RebalancePlan::new(&owned, &pool.coins(), &self.config);
+        pool.set_coins(plan.keep.clone());
+        if plan.is_noop() {
+            return Ok(plan.keep.len());
+        }
+        if !self.may_transact() {
+            info!(
+                "⛽ Gas pool needs {} retired, {} created; feature gas_rebalancer is off",
+                plan.retire.len(),
+                plan.create
+            );
+            return Ok(plan.keep.len());
+        }
+
+        // Fund the refill from the largest coin outside the pool
+        let needed = self.config.target_mist * plan.create as u64 + REBALANCE_GAS_BUDGET;
+        let principal = owned
+            .iter()
+            .filter(|(id, balance)| {
+                !plan.keep.contains(id) && !plan.retire.contains(id) && *balance >= needed
+            })
+            .max_by_key(|(_, balance)| *balance)
+            .and_then(|(id, _)| CoinGuard::reserve_first([id.clone()]))
+            .ok_or_else(|| {
+                anyhow::anyhow!("No free principal coin with {} MIST to refill gas", needed)
+            })?;
+
+        let output = Command::new("sui")
+            .args(["client", "ptb", "--json"])
+            .args(plan.ptb_args(principal.id(), &self.address, &self.config))
+            .output()
+            .context("Failed to execute gas pool PTB")?;
+        let outcome = super::real_executor::parse_ptb_output(&output, "Gas pool")?;
+
+        let mut coins = plan.keep;
+        coins.extend(
+            outcome
+                .created_for(&self.address)
+                .into_iter()
+                .filter(|o| o.is_type("::coin::Coin<0x2::sui::SUI>"))
+                .map(|o| o.object_id.clone()),
+        );
+        info!(
+            "⛽ Gas pool rebalanced: {} retired, {} created ({})",
+            plan.retire.len(),
+            plan.create,
+            outcome.digest
+        );
+        let size = coins.len();
+        pool.set_coins(coins);
+        Ok(size)
+    }
+
+    /// Enable the pool and rebalance forever
+    pub async fn run(self) {
+        info!(
+            "⛽ Gas pool: {} coins of {} MIST for {}",
+            self.config.size, self.config.target_mist, self.address
+        );
+        GasPool::global().set_enabled(true);
+        let interval = Duration::from_secs(self.config.rebalance_secs);
+        loop {
+            match self.rebalance_once().await {
+                Ok(size) if size < self.config.size => {
+                    warn!("⚠️  Gas pool short: {}/{} coins", size, self.config.size)
+                }
+                Ok(_) => {}
+                Err(e) => warn!("⚠️  Gas pool rebalance failed: {}", e),
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(id: &str, balance: u64) -> (String, u64) {
+        (id.to_string(), balance)
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let pool = GasPool::new();
+        assert!(pool.next().is_none());
+        pool.set_coins(vec!["0xgaspool_a".to_string(), "0xgaspool_b".to_string()]);
+
+        let first = pool.next().unwrap();
+        assert_eq!(first.id(), "0xgaspool_a");
+        drop(first);
+        assert_eq!(pool.next().unwrap().id(), "0xgaspool_b");
+
+        // A coin in use is skipped
+        let held = pool.next().unwrap();
+        assert_eq!(held.id(), "0xgaspool_a");
+        assert_eq!(pool.next().unwrap().id(), "0xgaspool_b");
+        assert_eq!(pool.next().unwrap().id(), "0xgaspool_b");
+    }
+
+    #[test]
+    fn test_rebalance_plan() {
+        let config = GasPoolConfig {
+            size: 3,
+            ..GasPoolConfig::default()
+        };
+        let owned = [
+            coin("0xprincipal", 50_000_000_000),
+            coin("0xhealthy", 400_000_000),
+            coin("0xdrained", 10_000_000),
+            coin("0xleftover", 300_000_000),
+        ];
+        let pool = ["0xhealthy", "0xdrained", "0xgone"].map(String::from);
+
+        let plan = RebalancePlan::new(&owned, &pool, &config);
+        assert_eq!(plan.keep, ["0xhealthy", "0xleftover"]);
+        assert_eq!(plan.retire, ["0xdrained"]);
+        assert_eq!(plan.create, 1);
+
+        let args = plan.ptb_args("0xprincipal", "0xsolver", &config).join(" ");
+        assert!(args.starts_with("--gas-budget 20000000 --gas-coin @0xprincipal"));
+        assert!(args.contains("--merge-coins gas [ @0xdrained ]"));
+        assert!(args.ends_with(
+            "--split-coins gas [ 500000000 ] --assign gas_coins \
+             --transfer-objects [ gas_coins.0 ] @ 0xsolver"
+        ));
+
+        let full = RebalancePlan::new(
+            &owned[..2],
+            &pool[..1],
+            &GasPoolConfig { size: 1, ..config },
+        );
+        assert!(full.is_noop());
+    }
+}
@@ -2,29 +2,91 @@
 //!
 //! Handles real PTB execution on Sui testnet/mainnet.
 
+pub mod coin_lock;
+pub mod evm_executor;
+pub mod gas_pool;
+pub mod multisig;
+#[cfg(feature = "navi")]
 pub mod navi_executor;
 pub mod real_executor;
+pub mod retry;
+pub mod signer;
 
-use anyhow::Result;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use naisu_sui::{FulfillmentOutcome, SuiClient, SuiConfig};
 use serde_json::Value;
 
+use multisig::MultiSigWallet;
+use signer::Signer;
+
 /// Transaction executor for Sui
+///
+/// Signs with its own `Signer` (or multisig co-signers) and submits through
+/// `SuiClient`, for wallets whose key is not in the Sui CLI keystore.
 pub struct SuiExecutor {
     rpc_url: String,
     client: reqwest::Client,
+    sui: SuiClient,
     wallet_address: String,
-    #[allow(dead_code)]
-    private_key: String,
+    multisig: Option<MultiSigWallet>,
+    signer: Option<Arc<dyn Signer>>,
 }
 
 impl SuiExecutor {
-    /// Create new executor
-    pub fn new(rpc_url: &str, wallet_address: &str, private_key: &str) -> Self {
+    /// Create new executor, submitting over JSON-RPC to `rpc_url`
+    pub fn new(rpc_url: &str, wallet_address: &str) -> Self {
+        let mut config = SuiConfig::testnet();
+        config.rpc_url = rpc_url.to_string();
         Self {
             rpc_url: rpc_url.to_string(),
             client: reqwest::Client::new(),
+            sui: SuiClient::new(config),
             wallet_address: wallet_address.to_string(),
-            private_key: private_key.to_string(),
+            multisig: None,
+            signer: None,
+        }
+    }
+
+    /// Submit through `sui` (e.g. the network's GraphQL client) instead
+    pub fn with_client(mut self, sui: SuiClient) -> Self {
+        self.sui = sui;
+        self
+    }
+
+    /// Use a multisig treasury; the wallet address becomes the multisig address
+    pub fn with_multisig(mut self, wallet: MultiSigWallet) -> Self {
+        self.wallet_address = wallet.address();
+        self.multisig = Some(wallet);
+        self
+    }
+
+    /// Sign transaction bytes with the multisig co-signers
+    pub async fn sign_multisig(&self, tx_bytes: &[u8]) -> Result<String> {
+        match &self.multisig {
+            Some(wallet) => wallet.sign_transaction(tx_bytes).await,
+            None => Err(anyhow::anyhow!("No multisig wallet configured")),
+        }
+    }
+
+    /// Sign with a local or remote (KMS) key; the wallet address becomes the signer's
+    pub fn with_signer(mut self, signer: Arc<dyn Signer>) -> Self {
+        self.wallet_address = signer.address();
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Sign transaction bytes, preferring the multisig treasury when configured
+    pub async fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String> {
+        if self.multisig.is_some() {
+            return self.sign_multisig(tx_bytes).await;
+        }
+        match &self.signer {
+            Some(signer) => signer.sign_transaction(tx_bytes).await,
+            None => Err(anyhow::anyhow!("No signer configured")),
         }
     }
 
@@ -90,27 +152,34 @@ impl SuiExecutor {
         Ok(coin)
     }
 
-    /// Execute raw transaction (placeholder - would use sui-sdk)
-    pub async fn execute_transaction(&self, _tx_bytes: Vec<u8>) -> Result<TransactionResult> {
-        // TODO: Real implementation with sui-sdk
-        // 1. Sign transaction with private key
-        // 2. Submit to RPC
-        // 3. Wait for confirmation
-        // 4. Return digest
-
-        // For now, return mock
-        Ok(TransactionResult {
-            digest: format!("mock_tx_{}", chrono::Utc::now().timestamp()),
-            success: true,
-        })
+    /// Sign BCS `TransactionData` and execute it
+    ///
+    /// A failed execution status is returned as an outcome, not an error:
+    /// the transaction is on chain either way.
+    pub async fn execute_transaction(&self, tx_bytes: &[u8]) -> Result<FulfillmentOutcome> {
+        let signature = self.sign_transaction(tx_bytes).await?;
+        let response = self
+            .sui
+            .execute_transaction(&BASE64.encode(tx_bytes), vec![signature])
+            .await
+            .context("Failed to execute signed transaction")?;
+        Ok(response.into())
     }
 
-    /// Dry run transaction
-    pub async fn dry_run(&self, _tx_bytes: Vec<u8>) -> Result<DryRunResult> {
-        // TODO: Implement dry run
+    /// Dry run BCS `TransactionData` (no signature needed)
+    pub async fn dry_run(&self, tx_bytes: &[u8]) -> Result<DryRunResult> {
+        let response = self
+            .sui
+            .dry_run_transaction(&BASE64.encode(tx_bytes))
+            .await
+            .context("Failed to dry run transaction")?;
+        let gas = &response.effects.gas_used;
         Ok(DryRunResult {
-            success: true,
-            gas_used: 0,
+            success: response.effects.status.status == "success",
+            gas_used: [&gas.computation_cost, &gas.storage_cost]
+                .iter()
+                .filter_map(|cost| cost.parse::<u64>().ok())
+                .sum(),
         })
     }
 }
@@ -124,13 +193,6 @@ pub struct SuiCoin {
     pub balance: u64,
 }
 
-/// Transaction execution result
-#[derive(Debug)]
-pub struct TransactionResult {
-    pub digest: String,
-    pub success: bool,
-}
-
 /// Dry run result
 #[derive(Debug)]
 pub struct DryRunResult {
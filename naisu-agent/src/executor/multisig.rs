@@ -0,0 +1,524 @@
+//! Multisig treasury signing
+//!
+//! Lets the solver treasury live behind a Sui multisig (e.g. 2-of-3) instead
+//! of a single raw key. Each member key is held by a `CoSigner` — a local
+//! key, a KMS key or a remote approver service — and `MultiSigWallet`
+//! collects enough weight to reach the threshold and aggregates the result
+//! into a Sui `MultiSig` signature.
+//!
+//! Layouts follow the Sui TS SDK `bcs` definitions for `MultiSig`,
+//! `MultiSigPublicKey` and `CompressedSignature`.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use blake2::Digest;
+use tracing::{info, warn};
+
+use super::signer::{hex_encode, Blake2b256, SignatureScheme, Signer, SuiPublicKey};
+
+/// Signature scheme flag for multisig
+pub const MULTISIG_FLAG: u8 = 0x03;
+
+/// Max members in a Sui multisig
+pub const MAX_MULTISIG_MEMBERS: usize = 10;
+
+// ─── Multisig public key / address ───────────────────────────────────────────
+
+/// Multisig member: key plus voting weight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSigMember {
+    pub key: SuiPublicKey,
+    pub weight: u8,
+}
+
+/// Multisig public key (members + threshold)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSigPublicKey {
+    pub members: Vec<MultiSigMember>,
+    pub threshold: u16,
+}
+
+impl MultiSigPublicKey {
+    pub fn new(members: Vec<MultiSigMember>, threshold: u16) -> Result<Self> {
+        if members.is_empty() || members.len() > MAX_MULTISIG_MEMBERS {
+            anyhow::bail!(
+                "Multisig needs 1-{} members, got {}",
+                MAX_MULTISIG_MEMBERS,
+                members.len()
+            );
+        }
+        if members.iter().any(|m| m.weight == 0) {
+            anyhow::bail!("Multisig member weight must be > 0");
+        }
+        let total: u16 = members.iter().map(|m| m.weight as u16).sum();
+        if threshold == 0 || threshold > total {
+            anyhow::bail!("Invalid threshold {} (total weight {})", threshold, total);
+        }
+        for (i, m) in members.iter().enumerate() {
+            if members[..i].iter().any(|other| other.key == m.key) {
+                anyhow::bail!("Duplicate multisig member key");
+            }
+        }
+        Ok(Self { members, threshold })
+    }
+
+    /// Sui address: `blake2b256(0x03 ‖ threshold(u16 LE) ‖ [flag ‖ pk ‖ weight]...)`
+    pub fn address(&self) -> String {
+        let mut hasher = Blake2b256::new();
+        hasher.update([MULTISIG_FLAG]);
+        hasher.update(self.threshold.to_le_bytes());
+        for m in &self.members {
+            hasher.update([m.key.scheme.flag()]);
+            hasher.update(&m.key.bytes);
+            hasher.update([m.weight]);
+        }
+        format!("0x{}", hex_encode(&hasher.finalize()))
+    }
+
+    fn position_of(&self, key: &SuiPublicKey) -> Option<usize> {
+        self.members.iter().position(|m| &m.key == key)
+    }
+
+    /// BCS `MultiSigPublicKey { pk_map: vector<{PublicKey, u8}>, threshold: u16 }`
+    fn write_bcs(&self, out: &mut Vec<u8>) {
+        write_uleb128(out, self.members.len() as u64);
+        for m in &self.members {
+            // PublicKey enum: variant index == scheme flag, fixed-size bytes
+            write_uleb128(out, m.key.scheme.flag() as u64);
+            out.extend_from_slice(&m.key.bytes);
+            out.push(m.weight);
+        }
+        out.extend_from_slice(&self.threshold.to_le_bytes());
+    }
+}
+
+// ─── Co-signers ──────────────────────────────────────────────────────────────
+
+/// A party holding one multisig member key
+///
+/// Any `Signer` can act as a co-signer: a local key, a KMS-backed key or a
+/// remote approver.
+pub trait CoSigner: Signer {}
+
+impl<T: Signer + ?Sized> CoSigner for T {}
+
+/// Co-signer that asks a remote approval service to sign
+///
+/// Sends `POST {url}` with `{ "txBytes": <base64>, "address": <multisig> }`
+/// and expects `{ "signature": <base64 flag ‖ sig ‖ pubkey> }` back. The
+/// approver may refuse (non-2xx), which counts as a missing signature.
+pub struct RemoteApprover {
+    label: String,
+    url: String,
+    key: SuiPublicKey,
+    multisig_address: String,
+    client: reqwest::Client,
+}
+
+impl RemoteApprover {
+    pub fn new(label: &str, url: &str, key: SuiPublicKey, multisig_address: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            url: url.to_string(),
+            key,
+            multisig_address: multisig_address.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteApprover {
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn public_key(&self) -> SuiPublicKey {
+        self.key.clone()
+    }
+
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .post(&self.url)
+            .json(&serde_json::json!({
+                "txBytes": BASE64.encode(tx_bytes),
+                "address": self.multisig_address,
+            }))
+            .send()
+            .await
+            .with_context(|| format!("Approver {} unreachable", self.label))?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(
+                "Approver {} refused: HTTP {}",
+                self.label,
+                response.status()
+            );
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        let encoded = body["signature"]
+            .as_str()
+            .context("Approver response missing signature")?;
+        let serialized = BASE64
+            .decode(encoded)
+            .context("Invalid approver signature")?;
+
+        // flag ‖ sig(64) ‖ pubkey
+        let expected_len = 1 + 64 + self.key.scheme.public_key_len();
+        if serialized.len() != expected_len
+            || serialized[0] != self.key.scheme.flag()
+            || serialized[65..] != self.key.bytes[..]
+        {
+            anyhow::bail!(
+                "Approver {} returned a signature for the wrong key",
+                self.label
+            );
+        }
+
+        Ok(serialized[1..65].to_vec())
+    }
+}
+
+// ─── Wallet ──────────────────────────────────────────────────────────────────
+
+/// Multisig treasury wallet
+pub struct MultiSigWallet {
+    public_key: MultiSigPublicKey,
+    cosigners: Vec<Box<dyn CoSigner>>,
+}
+
+impl MultiSigWallet {
+    /// Every co-signer must control one of the multisig member keys
+    pub fn new(public_key: MultiSigPublicKey, cosigners: Vec<Box<dyn CoSigner>>) -> Result<Self> {
+        for c in &cosigners {
+            if public_key.position_of(&c.public_key()).is_none() {
+                anyhow::bail!("Co-signer {} is not a multisig member", c.label());
+            }
+        }
+        Ok(Self {
+            public_key,
+            cosigners,
+        })
+    }
+
+    /// Multisig treasury address
+    pub fn address(&self) -> String {
+        self.public_key.address()
+    }
+
+    pub fn public_key(&self) -> &MultiSigPublicKey {
+        &self.public_key
+    }
+
+    /// Collect signatures until the threshold is met and return the
+    /// serialized multisig (base64 of `0x03 ‖ bcs(MultiSig)`)
+    pub async fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String> {
+        let mut collected: Vec<(usize, SignatureScheme, Vec<u8>)> = Vec::new();
+        let mut weight: u16 = 0;
+
+        for cosigner in &self.cosigners {
+            if weight >= self.public_key.threshold {
+                break;
+            }
+            let key = cosigner.public_key();
+            let Some(index) = self.public_key.position_of(&key) else {
+                continue;
+            };
+            if collected.iter().any(|(i, _, _)| *i == index) {
+                continue;
+            }
+
+            match cosigner.sign_raw(tx_bytes).await {
+                Ok(sig) if sig.len() == 64 => {
+                    info!("   ✍️  {} signed", cosigner.label());
+                    weight += self.public_key.members[index].weight as u16;
+                    collected.push((index, key.scheme, sig));
+                }
+                Ok(sig) => warn!(
+                    "Co-signer {} returned {}-byte signature, ignoring",
+                    cosigner.label(),
+                    sig.len()
+                ),
+                Err(e) => warn!("Co-signer {} did not sign: {}", cosigner.label(), e),
+            }
+        }
+
+        if weight < self.public_key.threshold {
+            anyhow::bail!(
+                "Multisig threshold not met: weight {} of {}",
+                weight,
+                self.public_key.threshold
+            );
+        }
+
+        Ok(BASE64.encode(self.aggregate(collected)))
+    }
+
+    /// BCS `MultiSig { sigs, bitmap: u16, multisig_pk }` with the 0x03 flag
+    fn aggregate(&self, mut sigs: Vec<(usize, SignatureScheme, Vec<u8>)>) -> Vec<u8> {
+        // Signatures must be ordered by member index to match the bitmap
+        sigs.sort_by_key(|(i, _, _)| *i);
+
+        let bitmap = sigs.iter().fold(0u16, |acc, (i, _, _)| acc | (1 << i));
+
+        let mut out = vec![MULTISIG_FLAG];
+        write_uleb128(&mut out, sigs.len() as u64);
+        for (_, scheme, sig) in &sigs {
+            // CompressedSignature enum: variant index == scheme flag, 64 bytes
+            write_uleb128(&mut out, scheme.flag() as u64);
+            out.extend_from_slice(sig);
+        }
+        out.extend_from_slice(&bitmap.to_le_bytes());
+        self.public_key.write_bcs(&mut out);
+        out
+    }
+}
+
+// ─── From environment ────────────────────────────────────────────────────────
+
+/// Build the multisig treasury from environment variables, if configured
+///
+/// - `SOLVER_MULTISIG_MEMBERS`: `<key>[:<weight>]` entries, comma-separated,
+///   keys in Sui's base64 `flag ‖ pubkey` form (weight 1 by default)
+/// - `SOLVER_MULTISIG_THRESHOLD`: weight needed for a signature
+/// - `SOLVER_MULTISIG_APPROVERS` (optional): `<key>@<url>` entries, each
+///   a `RemoteApprover`
+///
+/// `signer` (the `SOLVER_SIGNER` key) co-signs as well. `None` without
+/// `SOLVER_MULTISIG_MEMBERS`.
+pub fn multisig_from_env(signer: Option<Arc<dyn Signer>>) -> Result<Option<MultiSigWallet>> {
+    let Ok(members) = std::env::var("SOLVER_MULTISIG_MEMBERS") else {
+        return Ok(None);
+    };
+    let threshold = std::env::var("SOLVER_MULTISIG_THRESHOLD")
+        .context("SOLVER_MULTISIG_THRESHOLD must be set")?
+        .parse()
+        .context("Invalid SOLVER_MULTISIG_THRESHOLD")?;
+    let public_key = MultiSigPublicKey::new(parse_members(&members)?, threshold)?;
+
+    let approvers = std::env::var("SOLVER_MULTISIG_APPROVERS").unwrap_or_default();
+    let mut cosigners: Vec<Box<dyn CoSigner>> = parse_approvers(&approvers, &public_key.address())?
+        .into_iter()
+        .map(|approver| Box::new(approver) as Box<dyn CoSigner>)
+        .collect();
+    cosigners.extend(signer.map(|s| Box::new(s) as Box<dyn CoSigner>));
+
+    MultiSigWallet::new(public_key, cosigners).map(Some)
+}
+
+/// Parse `<key>[:<weight>]` entries
+fn parse_members(spec: &str) -> Result<Vec<MultiSigMember>> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (key, weight) = match entry.split_once(':') {
+                Some((key, weight)) => (
+                    key,
+                    weight
+                        .parse()
+                        .with_context(|| format!("Invalid multisig weight: {}", weight))?,
+                ),
+                None => (entry, 1),
+            };
+            Ok(MultiSigMember {
+                key: SuiPublicKey::from_sui_base64(key)?,
+                weight,
+            })
+        })
+        .collect()
+}
+
+/// Parse `<key>@<url>` entries into approvers for `multisig_address`
+fn parse_approvers(spec: &str, multisig_address: &str) -> Result<Vec<RemoteApprover>> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .enumerate()
+        .map(|(i, entry)| {
+            let (key, url) = entry
+                .split_once('@')
+                .with_context(|| format!("Approver must be <key>@<url>: {}", entry))?;
+            Ok(RemoteApprover::new(
+                &format!("approver{}", i + 1),
+                url,
+                SuiPublicKey::from_sui_base64(key)?,
+                multisig_address,
+            ))
+        })
+        .collect()
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn write_uleb128(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::signer::{transaction_digest, LocalSigner};
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    fn local(n: u8) -> LocalSigner {
+        LocalSigner::new(&format!("key{}", n), [n; 32])
+    }
+
+    fn two_of_three() -> MultiSigPublicKey {
+        let members = (1..=3)
+            .map(|n| MultiSigMember {
+                key: local(n).public_key(),
+                weight: 1,
+            })
+            .collect();
+        MultiSigPublicKey::new(members, 2).unwrap()
+    }
+
+    /// Co-signer that always refuses
+    struct Offline(SuiPublicKey);
+
+    #[async_trait]
+    impl Signer for Offline {
+        fn label(&self) -> String {
+            "offline".to_string()
+        }
+
+        fn public_key(&self) -> SuiPublicKey {
+            self.0.clone()
+        }
+
+        async fn sign_raw(&self, _tx_bytes: &[u8]) -> Result<Vec<u8>> {
+            anyhow::bail!("offline")
+        }
+    }
+
+    #[test]
+    fn test_threshold_validation() {
+        let members = vec![MultiSigMember {
+            key: local(1).public_key(),
+            weight: 1,
+        }];
+        assert!(MultiSigPublicKey::new(members.clone(), 2).is_err());
+        assert!(MultiSigPublicKey::new(members, 1).is_ok());
+    }
+
+    #[test]
+    fn test_address_depends_on_threshold() {
+        let pk = two_of_three();
+        let addr = pk.address();
+        assert!(addr.starts_with("0x"));
+        assert_eq!(addr.len(), 66);
+
+        let mut one_of_three = pk.clone();
+        one_of_three.threshold = 1;
+        assert_ne!(addr, one_of_three.address());
+    }
+
+    #[test]
+    fn test_uleb128() {
+        let mut out = Vec::new();
+        write_uleb128(&mut out, 300);
+        assert_eq!(out, vec![0xac, 0x02]);
+    }
+
+    #[tokio::test]
+    async fn test_two_of_three_skips_offline_member() {
+        let pk = two_of_three();
+        let wallet = MultiSigWallet::new(
+            pk,
+            vec![
+                Box::new(Offline(local(1).public_key())),
+                Box::new(local(2)),
+                Box::new(local(3)),
+            ],
+        )
+        .unwrap();
+
+        let tx_bytes = b"tx-bytes";
+        let sig = BASE64
+            .decode(wallet.sign_transaction(tx_bytes).await.unwrap())
+            .unwrap();
+
+        assert_eq!(sig[0], MULTISIG_FLAG);
+        assert_eq!(sig[1], 2); // two signatures
+
+        // First signature is member #2's, and verifies against its key
+        assert_eq!(sig[2], SignatureScheme::Ed25519.flag());
+        let raw: [u8; 64] = sig[3..67].try_into().unwrap();
+        let pk_bytes: [u8; 32] = local(2).public_key().bytes.try_into().unwrap();
+        let vk = VerifyingKey::from_bytes(&pk_bytes).unwrap();
+        assert!(vk
+            .verify(&transaction_digest(tx_bytes), &Signature::from_bytes(&raw))
+            .is_ok());
+
+        // Bitmap: members 1 and 2 (0-based) -> 0b110
+        let bitmap_offset = 3 + 64 + 1 + 64;
+        assert_eq!(sig[bitmap_offset], 0b110);
+    }
+
+    #[tokio::test]
+    async fn test_threshold_not_met() {
+        let wallet = MultiSigWallet::new(
+            two_of_three(),
+            vec![Box::new(Offline(local(1).public_key())), Box::new(local(2))],
+        )
+        .unwrap();
+
+        assert!(wallet.sign_transaction(b"tx").await.is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_member_cosigner() {
+        let result = MultiSigWallet::new(two_of_three(), vec![Box::new(local(9))]);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_members_from_spec_with_shared_signer() {
+        let encoded = |n: u8| {
+            let key = local(n).public_key();
+            let mut bytes = vec![key.scheme.flag()];
+            bytes.extend(key.bytes);
+            BASE64.encode(bytes)
+        };
+        let spec = format!("{}:2, {},{}", encoded(1), encoded(2), encoded(3));
+
+        let members = parse_members(&spec).unwrap();
+        assert_eq!(members.len(), 3);
+        assert_eq!(members[0].weight, 2);
+        assert_eq!(members[1].key, local(2).public_key());
+        assert!(parse_members("not-a-key").is_err());
+
+        let approvers =
+            parse_approvers(&format!("{}@http://approver", encoded(3)), "0xms").unwrap();
+        assert_eq!(approvers[0].public_key(), local(3).public_key());
+        assert!(parse_approvers(&encoded(3), "0xms").is_err());
+
+        // The solver's own signer alone meets a threshold of its weight
+        let signer: Arc<dyn Signer> = Arc::new(local(1));
+        let wallet = MultiSigWallet::new(
+            MultiSigPublicKey::new(members, 2).unwrap(),
+            vec![Box::new(signer)],
+        )
+        .unwrap();
+        let sig = BASE64
+            .decode(wallet.sign_transaction(b"tx").await.unwrap())
+            .unwrap();
+        assert_eq!(sig[0], MULTISIG_FLAG);
+    }
+}
@@ -14,6 +14,7 @@
 //! - Claim mechanism
 
 use anyhow::{Context, Result};
+use naisu_core::{format_sui, ExplorerLink, SuiNetwork};
 use std::process::Command;
 use tracing::{error, info};
 
@@ -31,13 +32,16 @@ pub const NAVI_SUI_ASSET_ID: u8 = 0;
 /// Clock object
 pub const CLOCK_OBJECT: &str = "0x6";
 
-/// Solver wallet address (must be funded and active in Sui CLI)
+/// Solver wallet used when none is configured; its key must be in the Sui
+/// CLI keystore
 pub const SOLVER_ADDRESS: &str =
     "0x58160f98199897adf9b6456374a1ae202de9cd4b9668da495e6c45d375404746";
 
 /// Parameters for Navi deposit
 #[derive(Debug, Clone)]
 pub struct NaviDepositParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
     pub intent_id: String,
     pub user_address: String,
     pub amount: u64,
@@ -58,17 +62,17 @@ pub async fn execute_navi_deposit(params: NaviDepositParams) -> Result<String> {
     info!(
         "   Amount: {} MIST ({} SUI)",
         params.amount,
-        params.amount / 1_000_000_000
+        format_sui(params.amount)
     );
     info!("   User: {}", params.user_address);
     info!("   Protocol: Navi (Account-based)");
 
     // Check solver balance
-    let balance = check_solver_balance().await?;
+    let balance = check_solver_balance(&params.wallet).await?;
     info!(
         "   Solver Balance: {} MIST ({} SUI)",
         balance,
-        balance / 1_000_000_000
+        format_sui(balance)
     );
 
     if balance < params.amount + 10_000_000 {
@@ -81,23 +85,26 @@ pub async fn execute_navi_deposit(params: NaviDepositParams) -> Result<String> {
     }
 
     // Get coin object
-    let coin_object = get_solver_coin().await?;
+    let coin_object = get_solver_coin(&params.wallet).await?;
     info!("   Using coin: {}", coin_object);
 
     // Execute Navi deposit PTB
     let tx_digest = execute_navi_ptb(&params).await?;
 
     info!("✅ Navi deposit submitted: {}", tx_digest);
-    info!("   View: https://suiscan.xyz/testnet/tx/{}", tx_digest);
+    info!(
+        "   View: {}",
+        ExplorerLink::sui(SuiNetwork::Testnet).tx(&tx_digest)
+    );
     info!("   Note: Position held in solver's Navi account (not transferable token)");
 
     Ok(tx_digest)
 }
 
-/// Check solver wallet balance
-async fn check_solver_balance() -> Result<u64> {
+/// Check a solver wallet's balance
+async fn check_solver_balance(wallet: &str) -> Result<u64> {
     let output = Command::new("sui")
-        .args(["client", "gas", SOLVER_ADDRESS, "--json"])
+        .args(["client", "gas", wallet, "--json"])
         .output()
         .context("Failed to check balance")?;
 
@@ -129,9 +136,9 @@ async fn check_solver_balance() -> Result<u64> {
 }
 
 /// Get a SUI coin object from solver wallet
-async fn get_solver_coin() -> Result<String> {
+async fn get_solver_coin(wallet: &str) -> Result<String> {
     let output = Command::new("sui")
-        .args(["client", "objects", SOLVER_ADDRESS, "--json"])
+        .args(["client", "objects", wallet, "--json"])
         .output()
         .context("Failed to run sui client objects")?;
 
@@ -182,6 +189,9 @@ async fn execute_navi_ptb(params: &NaviDepositParams) -> Result<String> {
             "client",
             "ptb",
             "--json",
+            "--sender",
+            "@",
+            &params.wallet,
             "--gas-budget",
             "10000000",
             // Split coin for deposit amount
@@ -274,7 +284,7 @@ mod tests {
     #[tokio::test]
     async fn test_check_balance() {
         // Test that function doesn't panic
-        let result = check_solver_balance().await;
+        let result = check_solver_balance(SOLVER_ADDRESS).await;
         // Just verify it runs (may fail if wallet not configured)
         let _ = result;
     }
@@ -282,6 +292,7 @@ mod tests {
     #[tokio::test]
     async fn test_demo_deposit() {
         let params = NaviDepositParams {
+            wallet: SOLVER_ADDRESS.to_string(),
             intent_id: "0x123456789abcdef".to_string(),
             user_address: "0xuser123".to_string(),
             amount: 1_000_000_000, // 1 SUI
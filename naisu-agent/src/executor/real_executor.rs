@@ -2,13 +2,34 @@
 //!
 //! Actually signs and submits transactions to Sui testnet using Sui CLI.
 //! Uses native Sui staking which always works on testnet.
+//!
+//! PTBs are built by `sui client ptb`. They are signed with the CLI
+//! keystore, unless a `SuiExecutor` is registered for the sending wallet
+//! (`register_executor`): then the CLI only serializes the transaction,
+//! and the executor signs and submits it.
 
 use anyhow::{Context, Result};
-use std::process::Command;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use naisu_core::format_sui;
+use naisu_sui::{
+    ExecutionStatus, ExpectedTransfer, FulfillmentOutcome, IntentCall, IntentObject, IntentPackage,
+};
+use std::collections::HashMap;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
 use tracing::{error, info};
 
-/// Solver wallet address (must be funded and active in Sui CLI)
-/// Currently using active wallet with 3.09 SUI balance
+use super::coin_lock::CoinGuard;
+use super::gas_pool::GasPool;
+use super::retry::{with_contention_retry, ExecutorError, RetryPolicy};
+use super::SuiExecutor;
+use crate::batch::BatchLeg;
+use crate::fees::FeeTransfer;
+
+/// Solver wallet used when none is configured (`[wallet] address`);
+/// its key must be in the Sui CLI keystore
 pub const SOLVER_ADDRESS: &str =
     "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa";
 
@@ -25,68 +46,131 @@ pub const CLOCK_OBJECT: &str = "0x6";
 /// Sui System State object
 pub const SUI_SYSTEM_STATE: &str = "0x5";
 
+// ─── Simulation ──────────────────────────────────────────────────────────────
+
+/// Set by `--simulate`: fulfillment PTBs are dry-run, never executed
+static SIMULATE: AtomicBool = AtomicBool::new(false);
+
+/// Dry-run every fulfillment PTB from now on (or execute them again)
+pub fn set_simulate(simulate: bool) {
+    SIMULATE.store(simulate, Ordering::Relaxed);
+}
+
+pub fn is_simulating() -> bool {
+    SIMULATE.load(Ordering::Relaxed)
+}
+
+// ─── Signing ─────────────────────────────────────────────────────────────────
+
+/// Executors by the wallet they sign for
+fn executors() -> &'static RwLock<HashMap<String, Arc<SuiExecutor>>> {
+    static EXECUTORS: OnceLock<RwLock<HashMap<String, Arc<SuiExecutor>>>> = OnceLock::new();
+    EXECUTORS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Sign and submit PTBs sent by `executor.address()` with `executor`
+/// instead of the CLI keystore
+pub fn register_executor(executor: Arc<SuiExecutor>) {
+    executors()
+        .write()
+        .expect("executors lock poisoned")
+        .insert(executor.address().to_string(), executor);
+}
+
+fn executor_for(wallet: &str) -> Option<Arc<SuiExecutor>> {
+    executors()
+        .read()
+        .expect("executors lock poisoned")
+        .get(wallet)
+        .cloned()
+}
+
+/// `sui client ptb --json`, with `--dry-run` when simulating
+fn ptb_command() -> Command {
+    let mut command = Command::new("sui");
+    command.args(["client", "ptb", "--json"]);
+    if is_simulating() {
+        command.arg("--dry-run");
+    }
+    command
+}
+
 /// Parameters for staking fulfillment
 #[derive(Debug, Clone)]
 pub struct FulfillmentParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
     pub intent_id: String,
     pub user_address: String,
     pub amount: u64,
     pub validator: String,
+    /// Protocol fee sent to the treasury in the same PTB
+    pub fee: Option<FeeTransfer>,
 }
 
 /// Execute a REAL staking fulfillment transaction
 ///
 /// Flow:
 /// 1. Switch to solver wallet
-/// 2. Split gas coin to get staking amount
-/// 3. Call sui_system::request_add_stake
+/// 2. Split the staking amount (off the gas coin unless a gas pool is used)
+/// 3. Call sui_system::request_add_stake_non_entry
 /// 4. Get StakedSui object
 /// 5. Transfer StakedSui to user
-pub async fn execute_staking_fulfillment(params: FulfillmentParams) -> Result<String> {
+pub async fn execute_staking_fulfillment(params: FulfillmentParams) -> Result<FulfillmentOutcome> {
     info!("🔥 EXECUTING REAL STAKING FULFILLMENT");
     info!("   Intent: {}", params.intent_id);
     info!(
         "   Amount: {} MIST ({} SUI)",
         params.amount,
-        params.amount / 1_000_000_000
+        format_sui(params.amount)
     );
     info!("   User: {}", params.user_address);
     info!("   Validator: {}", params.validator);
 
     // Check solver balance first
-    let balance = check_solver_balance().await?;
+    let balance = check_solver_balance(&params.wallet).await?;
     info!(
         "   Solver Balance: {} MIST ({} SUI)",
         balance,
-        balance / 1_000_000_000
+        format_sui(balance)
     );
 
-    if balance < params.amount + 10_000_000 {
-        // amount + gas buffer
+    // amount + fee + gas buffer
+    let needed = params.amount + fee_amount(&params.fee) + 10_000_000;
+    if balance < needed {
         return Err(anyhow::anyhow!(
             "Insufficient balance: {} MIST available, need {} MIST",
             balance,
-            params.amount + 10_000_000
+            needed
         ));
     }
 
-    // Get coin object
-    let coin_object = get_solver_coin().await?;
-    info!("   Using coin: {}", coin_object);
-
-    // Execute staking PTB
-    let tx_digest = execute_staking_ptb(&params, &coin_object).await?;
+    // Execute staking PTB, retrying on shared object contention.
+    // The coin is re-selected each attempt so we always use its latest version.
+    let params_ref = &params;
+    let outcome = with_contention_retry("Staking", RetryPolicy::default(), || async move {
+        let coins = get_fulfillment_coins(&params_ref.wallet).await?;
+        execute_staking_ptb(params_ref, &coins).await
+    })
+    .await?;
+
+    info!("✅ Transaction submitted: {}", outcome.digest);
+    if let Some(staked) = outcome.created_of_type("::staking_pool::StakedSui") {
+        info!("   StakedSui: {}", staked.object_id);
+    }
 
-    info!("✅ Transaction submitted: {}", tx_digest);
-    info!("   View: https://suiscan.xyz/testnet/tx/{}", tx_digest);
+    Ok(outcome)
+}
 
-    Ok(tx_digest)
+/// Fee the PTB sends to the treasury (MIST)
+fn fee_amount(fee: &Option<FeeTransfer>) -> u64 {
+    fee.as_ref().map_or(0, |f| f.amount)
 }
 
-/// Check solver wallet balance
-pub async fn check_solver_balance() -> Result<u64> {
+/// Check a solver wallet's balance
+pub async fn check_solver_balance(wallet: &str) -> Result<u64> {
     let output = Command::new("sui")
-        .args(["client", "gas", SOLVER_ADDRESS, "--json"])
+        .args(["client", "gas", wallet, "--json"])
         .output()
         .context("Failed to check balance")?;
 
@@ -119,10 +203,11 @@ pub async fn check_solver_balance() -> Result<u64> {
 }
 
 /// Get a coin object from solver wallet with sufficient balance
-/// Returns the coin with largest balance to ensure enough for staking + gas
-async fn get_solver_coin() -> Result<String> {
+/// Returns the largest coin not reserved by another in-flight fulfillment,
+/// reserved until the returned guard is dropped
+async fn get_solver_coin(wallet: &str) -> Result<CoinGuard> {
     let output = Command::new("sui")
-        .args(["client", "gas", SOLVER_ADDRESS, "--json"])
+        .args(["client", "gas", wallet, "--json"])
         .output()
         .context("Failed to run sui client gas")?;
 
@@ -134,42 +219,95 @@ async fn get_solver_coin() -> Result<String> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let gas_objects: serde_json::Value = serde_json::from_str(&stdout)?;
 
-    // Find the coin with largest balance (to have enough for staking + gas)
-    let mut best_coin: Option<(String, u64)> = None;
+    // Need at least 1.1 SUI (1 SUI for stake + 0.1 for gas buffer)
+    let mut candidates: Vec<(String, u64)> = gas_objects
+        .as_array()
+        .map(|data| {
+            data.iter()
+                .filter_map(|obj| {
+                    let id = obj.get("gasCoinId")?.as_str()?.to_string();
+                    let balance = obj.get("mistBalance")?.as_u64()?;
+                    Some((id, balance))
+                })
+                .filter(|(_, balance)| *balance >= 1_100_000_000)
+                .filter(|(id, _)| !GasPool::global().contains(id))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Prefer the largest coin
+    candidates.sort_by_key(|&(_, balance)| std::cmp::Reverse(balance));
+
+    if candidates.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No SUI coin with sufficient balance found. Need at least 1.1 SUI for staking + gas"
+        ));
+    }
 
-    if let Some(data) = gas_objects.as_array() {
-        for obj in data {
-            let obj_id = obj
-                .get("gasCoinId")
-                .and_then(|id| id.as_str())
-                .map(|s| s.to_string());
-
-            let balance = obj.get("mistBalance").and_then(|b| b.as_u64());
-
-            if let (Some(id), Some(bal)) = (obj_id, balance) {
-                // Need at least 1.1 SUI (1 SUI for stake + 0.1 for gas buffer)
-                if bal >= 1_100_000_000 {
-                    // Pick the largest coin
-                    if best_coin.as_ref().is_none_or(|(_, b)| bal > *b) {
-                        best_coin = Some((id, bal));
-                    }
-                }
-            }
+    match CoinGuard::reserve_first(candidates.iter().map(|(id, _)| id.clone())) {
+        Some(guard) => {
+            let balance = candidates
+                .iter()
+                .find(|(id, _)| id == guard.id())
+                .map(|(_, b)| *b)
+                .unwrap_or(0);
+            info!("   Selected coin: {} with {} MIST", guard.id(), balance);
+            Ok(guard)
         }
+        None => Err(anyhow::anyhow!(
+            "All {} eligible SUI coins are in use by other fulfillments",
+            candidates.len()
+        )),
     }
+}
+
+/// Coins one fulfillment PTB spends, reserved until dropped
+struct FulfillmentCoins {
+    /// Coin deposits (and the fee) are split from
+    principal: CoinGuard,
+    /// Dedicated gas coin; without a gas pool the principal pays gas
+    gas: Option<CoinGuard>,
+}
 
-    if let Some((coin_id, balance)) = best_coin {
-        info!("   Selected coin: {} with {} MIST", coin_id, balance);
-        return Ok(coin_id);
+impl FulfillmentCoins {
+    /// `--gas-coin` argument
+    fn gas_coin(&self) -> String {
+        format!("@{}", self.gas.as_ref().unwrap_or(&self.principal).id())
     }
 
-    Err(anyhow::anyhow!(
-        "No SUI coin with sufficient balance found. Need at least 1.1 SUI for staking + gas"
-    ))
+    /// What `--split-coins` splits from
+    fn source(&self) -> String {
+        match self.gas {
+            Some(_) => format!("@{}", self.principal.id()),
+            None => "gas".to_string(),
+        }
+    }
+}
+
+/// Reserve a principal coin, and a gas coin from the pool when it is enabled
+async fn get_fulfillment_coins(wallet: &str) -> Result<FulfillmentCoins> {
+    let pool = GasPool::global();
+    let gas = if pool.is_enabled() {
+        let coin = pool
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No free gas coin in the gas pool"))?;
+        Some(coin)
+    } else {
+        None
+    };
+    let principal = get_solver_coin(wallet).await?;
+    info!("   Using coin: {}", principal.id());
+    if let Some(gas) = &gas {
+        info!("   Gas coin: {}", gas.id());
+    }
+    Ok(FulfillmentCoins { principal, gas })
 }
 
 /// Execute staking PTB
-async fn execute_staking_ptb(params: &FulfillmentParams, coin_object: &str) -> Result<String> {
+async fn execute_staking_ptb(
+    params: &FulfillmentParams,
+    coins: &FulfillmentCoins,
+) -> Result<FulfillmentOutcome> {
     // Minimum stake amount: 1 SUI
     const MIN_STAKE: u64 = 1_000_000_000; // 1 SUI in MIST
 
@@ -181,74 +319,67 @@ async fn execute_staking_ptb(params: &FulfillmentParams, coin_object: &str) -> R
         ));
     }
 
-    let amount_str = params.amount.to_string();
+    // Without a gas pool the gas coin also funds the stake ("gas" source)
+    let (gas_coin, source) = (coins.gas_coin(), coins.source());
 
     info!("   Building PTB...");
-    info!("   - Gas coin: {}", coin_object);
-    info!("   - Stake amount: {} MIST", amount_str);
+    info!("   - Gas coin: {}", gas_coin);
+    info!("   - Stake amount: {} MIST", params.amount);
     info!("   - Validator: {}", params.validator);
+    if let Some(fee) = &params.fee {
+        info!("   - Protocol fee: {} MIST → {}", fee.amount, fee.treasury);
+    }
 
-    // Build PTB using gas coin for both gas and staking
-    // Use "gas" keyword to use the gas coin for splitting
-    let output = Command::new("sui")
-        .args([
-            "client",
-            "ptb",
-            "--json",
-            "--gas-budget",
-            "100000000",
-            // Split gas coin for staking amount
-            "--split-coins",
-            "gas",
-            "[",
-            &amount_str,
-            "]",
-            "--assign",
-            "stake_coin",
-            // Stake it
-            "--move-call",
-            &format!("{}::sui_system::request_add_stake", SUI_SYSTEM),
-            "@",
-            SUI_SYSTEM_STATE,
-            "stake_coin",
-            "@",
-            &params.validator,
-        ])
-        .output()
-        .context("Failed to execute PTB")?;
-
-    // Check stdout for success (Sui CLI may emit warnings to stderr)
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    let args = staking_ptb_args(params, &gas_coin, &source);
+    let mut expected = vec![ExpectedTransfer::new(
+        &params.user_address,
+        "::staking_pool::StakedSui",
+    )];
+    expected.extend(params.fee.iter().map(FeeTransfer::expected_transfer));
 
-    // Try to parse digest from stdout even if status is not success (due to warnings)
-    if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
-        if let Some(digest) = result["digest"].as_str() {
-            info!("✅ Transaction submitted: {}", digest);
-            return Ok(digest.to_string());
-        }
-    }
+    run_verified_ptb(&params.wallet, &args, &expected, "Staking").await
+}
 
-    // If we got here, check if it's just a version warning
-    if !output.status.success() {
-        // Check if stderr only contains warnings, not actual errors
-        if stderr.contains("api version mismatch") && !stderr.contains("Error") {
-            // Try parsing stdout anyway
-            if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
-                if let Some(digest) = result["digest"].as_str() {
-                    info!(
-                        "✅ Transaction submitted (with version warning): {}",
-                        digest
-                    );
-                    return Ok(digest.to_string());
-                }
-            }
-        }
-        error!("PTB failed: {}", stderr);
-        Err(anyhow::anyhow!("PTB execution failed: {}", stderr))
-    } else {
-        Err(anyhow::anyhow!("Unknown PTB result"))
-    }
+/// `sui` arguments of a staking PTB, splitting the stake from `source`
+///
+/// The entry `request_add_stake` would leave the StakedSui with the
+/// solver; the non-entry variant returns it so it can go to the user.
+fn staking_ptb_args(params: &FulfillmentParams, gas_coin: &str, source: &str) -> Vec<String> {
+    let mut args: Vec<String> = [
+        "--gas-budget",
+        "100000000",
+        "--gas-coin",
+        gas_coin,
+        // Split the staking amount
+        "--split-coins",
+        source,
+        "[",
+        &params.amount.to_string(),
+        "]",
+        "--assign",
+        "stake_coin",
+        // Stake it
+        "--move-call",
+        &format!("{}::sui_system::request_add_stake_non_entry", SUI_SYSTEM),
+        "@",
+        SUI_SYSTEM_STATE,
+        "stake_coin",
+        "@",
+        &params.validator,
+        "--assign",
+        "staked_sui",
+        // Hand the StakedSui to the user
+        "--transfer-objects",
+        "[",
+        "staked_sui",
+        "]",
+        "@",
+        &params.user_address,
+    ]
+    .map(String::from)
+    .to_vec();
+    args.extend(params.fee.iter().flat_map(|fee| fee.ptb_args_from(source)));
+    args
 }
 
 /// Execute fulfillment using Sui CLI directly
@@ -298,20 +429,53 @@ pub async fn execute_with_cli(
     }
 }
 
+/// An on-chain intent a PTB fulfills through the intent package
+#[derive(Debug, Clone)]
+pub struct IntentFulfillment {
+    pub package: IntentPackage,
+    pub intent: IntentObject,
+    /// APY reported to the package (basis points)
+    pub apy: u64,
+}
+
+impl IntentFulfillment {
+    /// `fulfill_intent` args handing the `output` coin (of `output_type`)
+    /// to the intent
+    pub fn ptb_args(&self, output_type: &str, output: &str, protocol: &str) -> Vec<String> {
+        self.package.ptb_args(&IntentCall::Fulfill {
+            intent: self.intent.clone(),
+            output_type: output_type.to_string(),
+            output,
+            protocol: protocol.to_string(),
+            apy: self.apy,
+        })
+    }
+}
+
 /// Parameters for Scallop fulfillment
+#[cfg(feature = "scallop")]
 #[derive(Debug, Clone)]
 pub struct ScallopFulfillmentParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
     pub intent_id: String,
     pub user_address: String,
     pub amount: u64,
     pub scallop_package: String,
     pub scallop_market: String,
     pub scallop_version: String,
+    /// Protocol fee sent to the treasury in the same PTB
+    pub fee: Option<FeeTransfer>,
+    /// Fulfill the intent with the sSUI; sent straight to the user if unset
+    pub fulfillment: Option<IntentFulfillment>,
 }
 
 /// Parameters for Navi fulfillment
+#[cfg(feature = "navi")]
 #[derive(Debug, Clone)]
 pub struct NaviFulfillmentParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
     pub intent_id: String,
     pub user_address: String,
     pub amount: u64,
@@ -321,8 +485,11 @@ pub struct NaviFulfillmentParams {
 }
 
 /// Parameters for Cetus fulfillment
+#[cfg(feature = "cetus")]
 #[derive(Debug, Clone)]
 pub struct CetusFulfillmentParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
     pub intent_id: String,
     pub user_address: String,
     pub amount: u64,
@@ -330,6 +497,8 @@ pub struct CetusFulfillmentParams {
     pub cetus_factory: String,
     pub tick_lower: i32,
     pub tick_upper: i32,
+    /// Protocol fee sent to the treasury in the same PTB
+    pub fee: Option<FeeTransfer>,
 }
 
 /// Execute a REAL Scallop fulfillment transaction
@@ -337,52 +506,60 @@ pub struct CetusFulfillmentParams {
 /// Flow:
 /// 1. Split gas coin for deposit amount
 /// 2. Call scallop::mint::mint to get sSUI
-/// 3. Transfer sSUI to user
-pub async fn execute_scallop_fulfillment(params: ScallopFulfillmentParams) -> Result<String> {
+/// 3. Fulfill the intent with the sSUI (or transfer it to the user)
+#[cfg(feature = "scallop")]
+pub async fn execute_scallop_fulfillment(
+    params: ScallopFulfillmentParams,
+) -> Result<FulfillmentOutcome> {
     info!("🔥 EXECUTING REAL SCALLOP FULFILLMENT");
     info!("   Intent: {}", params.intent_id);
     info!(
         "   Amount: {} MIST ({} SUI)",
         params.amount,
-        params.amount / 1_000_000_000
+        format_sui(params.amount)
     );
     info!("   User: {}", params.user_address);
     info!("   Scallop Package: {}", params.scallop_package);
 
     // Check solver balance first
-    let balance = check_solver_balance().await?;
+    let balance = check_solver_balance(&params.wallet).await?;
     info!(
         "   Solver Balance: {} MIST ({} SUI)",
         balance,
-        balance / 1_000_000_000
+        format_sui(balance)
     );
 
-    if balance < params.amount + 10_000_000 {
+    let needed = params.amount + fee_amount(&params.fee) + 10_000_000;
+    if balance < needed {
         return Err(anyhow::anyhow!(
             "Insufficient balance: {} MIST available, need {} MIST",
             balance,
-            params.amount + 10_000_000
+            needed
         ));
     }
 
-    // Get coin object
-    let coin_object = get_solver_coin().await?;
-    info!("   Using coin: {}", coin_object);
-
-    // Execute Scallop PTB
-    let tx_digest = execute_scallop_ptb(&params, &coin_object).await?;
-
-    info!("✅ Scallop transaction submitted: {}", tx_digest);
-    info!("   View: https://suiscan.xyz/mainnet/tx/{}", tx_digest);
+    // Execute Scallop PTB (retried on shared object contention)
+    let params_ref = &params;
+    let outcome = with_contention_retry("Scallop", RetryPolicy::default(), || async move {
+        let coins = get_fulfillment_coins(&params_ref.wallet).await?;
+        execute_scallop_ptb(params_ref, &coins).await
+    })
+    .await?;
+
+    info!("✅ Scallop transaction submitted: {}", outcome.digest);
+    if let Some(s_coin) = outcome.created_of_type("::reserve::MarketCoin") {
+        info!("   sCoin: {}", s_coin.object_id);
+    }
 
-    Ok(tx_digest)
+    Ok(outcome)
 }
 
 /// Execute Scallop PTB
+#[cfg(feature = "scallop")]
 async fn execute_scallop_ptb(
     params: &ScallopFulfillmentParams,
-    _coin_object: &str,
-) -> Result<String> {
+    coins: &FulfillmentCoins,
+) -> Result<FulfillmentOutcome> {
     let amount_str = params.amount.to_string();
 
     info!("   Building Scallop PTB...");
@@ -392,73 +569,72 @@ async fn execute_scallop_ptb(
     // Build PTB for Scallop mint
     // 1. Split coin for amount
     // 2. Call mint::mint to get sSUI
-    // 3. Transfer sSUI to user (or fulfill intent)
-
-    let output = Command::new("sui")
-        .args([
-            "client",
-            "ptb",
-            "--json",
-            "--gas-budget",
-            "100000000",
-            // Split the coin from gas
-            "--split-coins",
-            "gas",
-            "[",
-            &amount_str,
-            "]",
-            "--assign",
-            "deposit_coin",
-            // Call Scallop mint
-            "--move-call",
-            &format!("{}::mint::mint", params.scallop_package),
-            "@",
-            &params.scallop_version,
-            "@",
-            &params.scallop_market,
-            "deposit_coin",
-            "@",
-            CLOCK_OBJECT,
-            "--assign",
-            "s_sui_coin",
-            // TODO: Add fulfill_intent call here
-            // For now, just return the sSUI to solver
-        ])
-        .output()
-        .context("Failed to execute Scallop PTB")?;
-
-    // Check stdout for success
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
-        if let Some(digest) = result["digest"].as_str() {
-            info!("✅ Scallop transaction submitted: {}", digest);
-            return Ok(digest.to_string());
-        }
-    }
-
-    if !output.status.success() {
-        if stderr.contains("api version mismatch") && !stderr.contains("Error") {
-            if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
-                if let Some(digest) = result["digest"].as_str() {
-                    info!(
-                        "✅ Transaction submitted (with version warning): {}",
-                        digest
-                    );
-                    return Ok(digest.to_string());
-                }
-            }
+    // 3. Fulfill the intent with the sSUI (or transfer it to the user)
+
+    let (gas_coin, source) = (coins.gas_coin(), coins.source());
+    let mut args: Vec<String> = [
+        "--gas-budget",
+        "100000000",
+        "--gas-coin",
+        &gas_coin,
+        // Split the coin from gas
+        "--split-coins",
+        &source,
+        "[",
+        &amount_str,
+        "]",
+        "--assign",
+        "deposit_coin",
+        // Call Scallop mint
+        "--move-call",
+        &format!("{}::mint::mint", params.scallop_package),
+        "@",
+        &params.scallop_version,
+        "@",
+        &params.scallop_market,
+        "deposit_coin",
+        "@",
+        CLOCK_OBJECT,
+        "--assign",
+        "s_sui_coin",
+    ]
+    .map(String::from)
+    .to_vec();
+    match &params.fulfillment {
+        Some(fulfillment) => {
+            let s_coin_type = format!(
+                "{}::reserve::MarketCoin<0x2::sui::SUI>",
+                params.scallop_package
+            );
+            args.extend(fulfillment.ptb_args(&s_coin_type, "s_sui_coin", "scallop"));
         }
-        error!("Scallop PTB failed: {}", stderr);
-        Err(anyhow::anyhow!("Scallop PTB execution failed: {}", stderr))
-    } else {
-        Err(anyhow::anyhow!("Unknown Scallop PTB result"))
+        None => args.extend(
+            [
+                "--transfer-objects",
+                "[",
+                "s_sui_coin",
+                "]",
+                "@",
+                &params.user_address,
+            ]
+            .map(String::from),
+        ),
     }
+    args.extend(params.fee.iter().flat_map(|fee| fee.ptb_args_from(&source)));
+    let expected: Vec<_> = params
+        .fee
+        .iter()
+        .map(FeeTransfer::expected_transfer)
+        .collect();
+
+    run_verified_ptb(&params.wallet, &args, &expected, "Scallop").await
 }
 
 /// Execute a REAL Navi fulfillment transaction
-pub async fn execute_navi_fulfillment(_params: NaviFulfillmentParams) -> Result<String> {
+#[cfg(feature = "navi")]
+pub async fn execute_navi_fulfillment(
+    _params: NaviFulfillmentParams,
+) -> Result<FulfillmentOutcome> {
     // Navi is account-based, making it complex for intent fulfillment
     // Options:
     // 1. Create new obligation, deposit, transfer obligation to user
@@ -479,13 +655,16 @@ pub async fn execute_navi_fulfillment(_params: NaviFulfillmentParams) -> Result<
 /// 3. Open position in SUI/USDC pool
 /// 4. Add liquidity with both tokens
 /// 5. Transfer position NFT to user
-pub async fn execute_cetus_fulfillment(params: CetusFulfillmentParams) -> Result<String> {
+#[cfg(feature = "cetus")]
+pub async fn execute_cetus_fulfillment(
+    params: CetusFulfillmentParams,
+) -> Result<FulfillmentOutcome> {
     info!("🔥 EXECUTING REAL CETUS CLMM FULFILLMENT");
     info!("   Intent: {}", params.intent_id);
     info!(
         "   Amount: {} MIST ({} SUI)",
         params.amount,
-        params.amount / 1_000_000_000
+        format_sui(params.amount)
     );
     info!("   User: {}", params.user_address);
     info!(
@@ -494,36 +673,41 @@ pub async fn execute_cetus_fulfillment(params: CetusFulfillmentParams) -> Result
     );
 
     // Check solver balance first
-    let balance = check_solver_balance().await?;
+    let balance = check_solver_balance(&params.wallet).await?;
     info!(
         "   Solver Balance: {} MIST ({} SUI)",
         balance,
-        balance / 1_000_000_000
+        format_sui(balance)
     );
 
-    if balance < params.amount + 50_000_000 {
-        // amount + gas buffer (CLMM needs more gas)
+    // amount + fee + gas buffer (CLMM needs more gas)
+    let needed = params.amount + fee_amount(&params.fee) + 50_000_000;
+    if balance < needed {
         return Err(anyhow::anyhow!(
             "Insufficient balance: {} MIST available, need {} MIST",
             balance,
-            params.amount + 50_000_000
+            needed
         ));
     }
 
-    // Get coin object
-    let coin_object = get_solver_coin().await?;
-    info!("   Using coin: {}", coin_object);
-
-    // Execute Cetus PTB
-    let tx_digest = execute_cetus_ptb(&params, &coin_object).await?;
-
-    info!("✅ Cetus transaction submitted: {}", tx_digest);
-    info!("   View: https://suiscan.xyz/mainnet/tx/{}", tx_digest);
+    // Execute Cetus PTB (retried on shared object contention)
+    let params_ref = &params;
+    let outcome = with_contention_retry("Cetus", RetryPolicy::default(), || async move {
+        let coins = get_fulfillment_coins(&params_ref.wallet).await?;
+        execute_cetus_ptb(params_ref, &coins).await
+    })
+    .await?;
+
+    info!("✅ Cetus transaction submitted: {}", outcome.digest);
+    if let Some(position) = outcome.created_of_type("::position::Position") {
+        info!("   Position: {}", position.object_id);
+    }
 
-    Ok(tx_digest)
+    Ok(outcome)
 }
 
 /// Testnet USDC/SUI Pool (from on-chain query)
+#[cfg(feature = "cetus")]
 const TESTNET_POOL_USDC_SUI: &str =
     "0x2603c08065a848b719f5f465e40dbef485ec4fd9c967ebe83a7565269a74a2b2";
 
@@ -535,7 +719,11 @@ const TESTNET_POOL_USDC_SUI: &str =
 /// 3. Open position in pool
 /// 4. Add liquidity with both tokens
 /// 5. Transfer position to user
-async fn execute_cetus_ptb(params: &CetusFulfillmentParams, _coin_object: &str) -> Result<String> {
+#[cfg(feature = "cetus")]
+async fn execute_cetus_ptb(
+    params: &CetusFulfillmentParams,
+    coins: &FulfillmentCoins,
+) -> Result<FulfillmentOutcome> {
     let half_amount = params.amount / 2;
     let amount_str = params.amount.to_string();
     let half_amount_str = half_amount.to_string();
@@ -557,77 +745,445 @@ async fn execute_cetus_ptb(params: &CetusFulfillmentParams, _coin_object: &str)
     // 4. Add liquidity
     // 5. Transfer position to user
 
-    let output = Command::new("sui")
-        .args([
-            "client",
-            "ptb",
-            "--json",
-            "--gas-budget",
-            "100000000",
-            // Split coin for dual-sided liquidity (50/50)
-            "--split-coins",
-            "gas",
-            "[",
-            &half_amount_str,
-            "]",
-            "--assign",
-            "sui_for_liquidity",
-            // Note: In full implementation:
-            // - Call integrate::router::swap to get USDC
-            // - Then add liquidity with both tokens
-            // For hackathon demo, we open position (which creates the NFT)
-            "--move-call",
-            &format!("{}::pool::open_position", params.cetus_core),
-            "@",
-            &params.cetus_factory,
-            &params.tick_lower.to_string(),
-            &params.tick_upper.to_string(),
-            "--assign",
-            "position_nft",
-            // Transfer position to user
-            "--transfer-objects",
-            "[",
-            "position_nft",
-            "]",
-            "@",
-            &params.user_address,
-        ])
+    let (gas_coin, source) = (coins.gas_coin(), coins.source());
+    let mut args: Vec<String> = [
+        "--gas-budget",
+        "100000000",
+        "--gas-coin",
+        &gas_coin,
+        // Split coin for dual-sided liquidity (50/50)
+        "--split-coins",
+        &source,
+        "[",
+        &half_amount_str,
+        "]",
+        "--assign",
+        "sui_for_liquidity",
+        // Note: In full implementation:
+        // - Call integrate::router::swap to get USDC
+        // - Then add liquidity with both tokens
+        // For hackathon demo, we open position (which creates the NFT)
+        "--move-call",
+        &format!("{}::pool::open_position", params.cetus_core),
+        "@",
+        &params.cetus_factory,
+        &params.tick_lower.to_string(),
+        &params.tick_upper.to_string(),
+        "--assign",
+        "position_nft",
+        // Transfer position to user
+        "--transfer-objects",
+        "[",
+        "position_nft",
+        "]",
+        "@",
+        &params.user_address,
+    ]
+    .map(String::from)
+    .to_vec();
+    args.extend(params.fee.iter().flat_map(|fee| fee.ptb_args_from(&source)));
+    let mut expected = vec![ExpectedTransfer::new(
+        &params.user_address,
+        "::position::Position",
+    )];
+    expected.extend(params.fee.iter().map(FeeTransfer::expected_transfer));
+
+    run_verified_ptb(&params.wallet, &args, &expected, "Cetus").await
+}
+
+// ─── Intents ─────────────────────────────────────────────────────────────────
+
+/// Parameters for opening an intent from the active wallet
+#[derive(Debug, Clone)]
+pub struct CreateIntentParams {
+    pub package: IntentPackage,
+    /// SUI locked in the intent (MIST)
+    pub amount: u64,
+    /// Basis points
+    pub min_apy: u64,
+    pub deadline_secs: u64,
+    /// Protocol name, or `"any"`
+    pub target_protocol: String,
+}
+
+/// Lock SUI split off the gas coin in a new shared `YieldIntent`
+pub async fn execute_create_intent(params: CreateIntentParams) -> Result<FulfillmentOutcome> {
+    info!(
+        "📝 Creating intent: {} SUI, min {} bps",
+        format_sui(params.amount),
+        params.min_apy
+    );
+    let mut args: Vec<String> = [
+        "--gas-budget",
+        "100000000",
+        "--split-coins",
+        "gas",
+        "[",
+        &params.amount.to_string(),
+        "]",
+        "--assign",
+        "input",
+    ]
+    .map(String::from)
+    .to_vec();
+    args.extend(params.package.ptb_args(&IntentCall::Create {
+        coin_type: "0x2::sui::SUI".to_string(),
+        input: "input",
+        min_apy: params.min_apy,
+        deadline_secs: params.deadline_secs,
+        target_protocol: params.target_protocol.clone(),
+    }));
+
+    let output = ptb_command()
+        .args(&args)
         .output()
-        .context("Failed to execute Cetus PTB")?;
+        .context("Failed to execute create intent PTB")?;
+    parse_ptb_output(&output, "Create intent")
+}
+
+/// Reclaim an open intent's coin as its owner (`user_cancel_intent`)
+pub async fn execute_user_cancel(
+    package: &IntentPackage,
+    intent: IntentObject,
+) -> Result<FulfillmentOutcome> {
+    info!("↩️  Cancelling intent {}", intent.object_id);
+    let mut args: Vec<String> = vec!["--gas-budget".to_string(), "50000000".to_string()];
+    args.extend(package.ptb_args(&IntentCall::<&str>::UserCancel { intent }));
+
+    let output = ptb_command()
+        .args(&args)
+        .output()
+        .context("Failed to execute cancel intent PTB")?;
+    parse_ptb_output(&output, "Cancel intent")
+}
+
+// ─── Batches ─────────────────────────────────────────────────────────────────
+
+/// What every leg of a batch deposits into
+#[derive(Debug, Clone)]
+pub enum BatchDeposit {
+    /// Native staking with one validator
+    Staking { validator: String },
+    /// Scallop lending (sSUI)
+    #[cfg(feature = "scallop")]
+    Scallop {
+        package: String,
+        market: String,
+        version: String,
+    },
+}
+
+impl BatchDeposit {
+    fn label(&self) -> &'static str {
+        match self {
+            BatchDeposit::Staking { .. } => "Staking batch",
+            #[cfg(feature = "scallop")]
+            BatchDeposit::Scallop { .. } => "Scallop batch",
+        }
+    }
+
+    /// Type of the receipt each leg's user receives
+    fn receipt_type(&self) -> &'static str {
+        match self {
+            BatchDeposit::Staking { .. } => "::staking_pool::StakedSui",
+            #[cfg(feature = "scallop")]
+            BatchDeposit::Scallop { .. } => "::reserve::MarketCoin",
+        }
+    }
+}
+
+/// Parameters for a batch fulfillment
+#[derive(Debug, Clone)]
+pub struct BatchFulfillmentParams {
+    /// Solver wallet that signs and pays
+    pub wallet: String,
+    pub legs: Vec<BatchLeg>,
+    pub deposit: BatchDeposit,
+    /// Fees of every leg, sent to the treasury in one transfer
+    pub fee: Option<FeeTransfer>,
+}
+
+/// Execute several intents in one PTB
+///
+/// Flow:
+/// 1. Split the principal coin into every leg's amount at once
+/// 2. Deposit each split coin
+/// 3. Transfer each receipt (StakedSui / sSUI) to its leg's user
+///
+/// The PTB is atomic: either every leg is filled or none is.
+pub async fn execute_batch_fulfillment(
+    params: BatchFulfillmentParams,
+) -> Result<FulfillmentOutcome> {
+    let label = params.deposit.label();
+    let total: u64 = params.legs.iter().map(|l| l.amount).sum();
+    info!("🔥 EXECUTING {} FULFILLMENT", label.to_uppercase());
+    info!("   Legs: {}", params.legs.len());
+    for leg in &params.legs {
+        info!("   - {}: {} MIST → {}", leg.intent_id, leg.amount, leg.user);
+    }
 
-    // Check stdout for success
+    let balance = check_solver_balance(&params.wallet).await?;
+    info!(
+        "   Solver Balance: {} MIST ({} SUI)",
+        balance,
+        format_sui(balance)
+    );
+
+    // total + fee + gas buffer (one buffer for the whole batch)
+    let needed = total + fee_amount(&params.fee) + 10_000_000;
+    if balance < needed {
+        return Err(anyhow::anyhow!(
+            "Insufficient balance: {} MIST available, need {} MIST",
+            balance,
+            needed
+        ));
+    }
+
+    let params_ref = &params;
+    let outcome = with_contention_retry(label, RetryPolicy::default(), || async move {
+        let coins = get_fulfillment_coins(&params_ref.wallet).await?;
+        let args = batch_ptb_args(params_ref, &coins.gas_coin(), &coins.source());
+        run_verified_ptb(
+            &params_ref.wallet,
+            &args,
+            &batch_expected_transfers(params_ref),
+            label,
+        )
+        .await
+    })
+    .await?;
+
+    info!("✅ {} submitted: {}", label, outcome.digest);
+    Ok(outcome)
+}
+
+/// `sui` arguments of a batch PTB, splitting legs from `source`
+fn batch_ptb_args(params: &BatchFulfillmentParams, gas_coin: &str, source: &str) -> Vec<String> {
+    // Each leg past the first adds a deposit and a transfer
+    let budget = 100_000_000 + 5_000_000 * params.legs.len().saturating_sub(1) as u64;
+    let amounts: Vec<String> = params.legs.iter().map(|l| l.amount.to_string()).collect();
+
+    let mut args: Vec<String> = vec![
+        "--gas-budget".into(),
+        budget.to_string(),
+        "--gas-coin".into(),
+        gas_coin.into(),
+        "--split-coins".into(),
+        source.into(),
+        "[".into(),
+        amounts.join(","),
+        "]".into(),
+        "--assign".into(),
+        "leg_coins".into(),
+    ];
+
+    for (i, leg) in params.legs.iter().enumerate() {
+        let coin = format!("leg_coins.{}", i);
+        let receipt = format!("receipt_{}", i);
+        match &params.deposit {
+            BatchDeposit::Staking { validator } => args.extend([
+                "--move-call".into(),
+                format!("{}::sui_system::request_add_stake_non_entry", SUI_SYSTEM),
+                "@".into(),
+                SUI_SYSTEM_STATE.into(),
+                coin,
+                "@".into(),
+                validator.clone(),
+            ]),
+            #[cfg(feature = "scallop")]
+            BatchDeposit::Scallop {
+                package,
+                market,
+                version,
+            } => args.extend([
+                "--move-call".into(),
+                format!("{}::mint::mint", package),
+                "@".into(),
+                version.clone(),
+                "@".into(),
+                market.clone(),
+                coin,
+                "@".into(),
+                CLOCK_OBJECT.into(),
+            ]),
+        }
+        args.extend([
+            "--assign".into(),
+            receipt.clone(),
+            "--transfer-objects".into(),
+            "[".into(),
+            receipt,
+            "]".into(),
+            "@".into(),
+            leg.user.clone(),
+        ]);
+    }
+
+    args.extend(params.fee.iter().flat_map(|fee| fee.ptb_args_from(source)));
+    args
+}
+
+/// Receipts every leg's user must get, plus the treasury's fee coin
+fn batch_expected_transfers(params: &BatchFulfillmentParams) -> Vec<ExpectedTransfer> {
+    let receipt = params.deposit.receipt_type();
+    params
+        .legs
+        .iter()
+        .map(|leg| ExpectedTransfer::new(&leg.user, receipt))
+        .chain(params.fee.iter().map(FeeTransfer::expected_transfer))
+        .collect()
+}
+
+// ─── Submission ──────────────────────────────────────────────────────────────
+
+/// Run a PTB sent by `wallet`, dry-running it first when it transfers
+/// objects
+///
+/// A transfer command fed the wrong result still executes, so the dry
+/// run's created objects are checked against `expected` before anything
+/// is submitted. When simulating, the checked dry run is the result.
+async fn run_verified_ptb(
+    wallet: &str,
+    args: &[String],
+    expected: &[ExpectedTransfer],
+    label: &str,
+) -> Result<FulfillmentOutcome> {
+    if !expected.is_empty() {
+        let output = Command::new("sui")
+            .args([
+                "client",
+                "ptb",
+                "--json",
+                "--dry-run",
+                "--sender",
+                "@",
+                wallet,
+            ])
+            .args(args)
+            .output()
+            .with_context(|| format!("Failed to dry run {} PTB", label))?;
+        let response: serde_json::Value =
+            serde_json::from_slice(&output.stdout).with_context(|| {
+                format!(
+                    "{} dry run failed: {}",
+                    label,
+                    String::from_utf8_lossy(&output.stderr)
+                )
+            })?;
+        let dry_run = FulfillmentOutcome::from_dry_run_json(&response)
+            .with_context(|| format!("Failed to parse {} dry run effects", label))?;
+        if let ExecutionStatus::Failure { error } = &dry_run.status {
+            return Err(anyhow::anyhow!("{} dry run failed: {}", label, error));
+        }
+        dry_run
+            .verify_transfers(expected)
+            .with_context(|| format!("{} PTB transfer check failed", label))?;
+        if is_simulating() {
+            info!("🧪 {} dry run: transfers verified", label);
+            return Ok(dry_run);
+        }
+    }
+
+    if let Some(executor) = executor_for(wallet).filter(|_| !is_simulating()) {
+        let output = Command::new("sui")
+            .args(["client", "ptb", "--serialize-unsigned-transaction"])
+            .args(["--sender", "@", wallet])
+            .args(args)
+            .output()
+            .with_context(|| format!("Failed to build {} PTB", label))?;
+        return submit_serialized(&executor, &output, label).await;
+    }
+
+    let output = ptb_command()
+        .args(["--sender", "@", wallet])
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to execute {} PTB", label))?;
+    parse_ptb_output(&output, label)
+}
+
+/// Sign `--serialize-unsigned-transaction` output with `executor` and
+/// submit it
+async fn submit_serialized(
+    executor: &SuiExecutor,
+    output: &Output,
+    label: &str,
+) -> Result<FulfillmentOutcome> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let tx_bytes = stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| BASE64.decode(line).ok())
+        .with_context(|| {
+            format!(
+                "{} PTB could not be serialized: {}",
+                label,
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })?;
+
+    let outcome = executor.execute_transaction(&tx_bytes).await?;
+    info!("✅ {} transaction submitted: {}", label, outcome.digest);
+    Ok(outcome)
+}
+
+/// Parse `sui client ptb --json` output into a typed outcome
+///
+/// The CLI may exit non-zero on harmless warnings (e.g. "api version
+/// mismatch") while still printing a valid JSON result, so stdout is
+/// checked first. When simulating, the output is a dry run response.
+pub(super) fn parse_ptb_output(output: &Output, label: &str) -> Result<FulfillmentOutcome> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
+        if is_simulating() && result["effects"].is_object() {
+            let outcome = FulfillmentOutcome::from_dry_run_json(&result)
+                .with_context(|| format!("Failed to parse {} dry run effects", label))?;
+            info!("🧪 {} dry run: {:?}", label, outcome.status);
+            return Ok(outcome);
+        }
         if let Some(digest) = result["digest"].as_str() {
-            info!("✅ Cetus transaction submitted: {}", digest);
-            return Ok(digest.to_string());
+            // Submitted: the error must not look like a pre-flight failure
+            let outcome =
+                FulfillmentOutcome::from_json(&result).map_err(|e| ExecutorError::Unconfirmed {
+                    label: label.to_string(),
+                    digest: digest.to_string(),
+                    error: e.to_string(),
+                })?;
+            if !output.status.success() {
+                info!("   (submitted with CLI warning)");
+            }
+            info!("✅ {} transaction submitted: {}", label, outcome.digest);
+            return Ok(outcome);
         }
     }
 
     if !output.status.success() {
-        if stderr.contains("api version mismatch") && !stderr.contains("Error") {
-            if let Ok(result) = serde_json::from_str::<serde_json::Value>(&stdout) {
-                if let Some(digest) = result["digest"].as_str() {
-                    info!(
-                        "✅ Transaction submitted (with version warning): {}",
-                        digest
-                    );
-                    return Ok(digest.to_string());
-                }
-            }
-        }
-        error!("Cetus PTB failed: {}", stderr);
-        Err(anyhow::anyhow!("Cetus PTB execution failed: {}", stderr))
+        error!("{} PTB failed: {}", label, stderr);
+        Err(anyhow::anyhow!(
+            "{} PTB execution failed: {}",
+            label,
+            stderr
+        ))
     } else {
-        Err(anyhow::anyhow!("Unknown Cetus PTB result"))
+        Err(anyhow::anyhow!("Unknown {} PTB result", label))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::executor::signer::{
+        normalize_ecdsa_signature, transaction_digest, SignatureScheme, Signer, SuiPublicKey,
+    };
+    use naisu_sui::{
+        CoinPage, DryRunResponse, EventPage, SuiClient, SuiClientError, SuiConfig, SuiObject,
+        SuiTransport, TransactionResponse, ValidatorInfo, ZkLoginVerifyResult,
+    };
+    use sha2::{Digest, Sha256};
 
     #[test]
     fn test_addresses() {
@@ -636,11 +1192,302 @@ mod tests {
         assert!(SUI_SYSTEM.starts_with("0x"));
     }
 
+    #[test]
+    fn test_digest_with_unreadable_effects_is_unconfirmed() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let output = Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: br#"{"digest": "0xabc", "effects": "garbled"}"#.to_vec(),
+            stderr: vec![],
+        };
+        let err = parse_ptb_output(&output, "Test").unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<ExecutorError>(),
+            Some(ExecutorError::Unconfirmed { digest, .. }) if digest == "0xabc"
+        ));
+    }
+
+    #[test]
+    fn test_staking_ptb_sends_stake_to_user() {
+        let params = FulfillmentParams {
+            wallet: SOLVER_ADDRESS.to_string(),
+            intent_id: "i1".to_string(),
+            user_address: "0xalice".to_string(),
+            amount: 1_000_000_000,
+            validator: "0xval".to_string(),
+            fee: None,
+        };
+        let args = staking_ptb_args(&params, "@0xgas", "gas").join(" ");
+
+        assert!(args.contains(
+            "0x3::sui_system::request_add_stake_non_entry @ 0x5 stake_coin @ 0xval --assign staked_sui"
+        ));
+        assert!(args.ends_with("--transfer-objects [ staked_sui ] @ 0xalice"));
+    }
+
+    #[test]
+    fn test_batch_ptb_args() {
+        let leg = |id: &str, user: &str, amount: u64| BatchLeg {
+            intent_id: id.to_string(),
+            user: user.to_string(),
+            amount,
+        };
+        let params = BatchFulfillmentParams {
+            wallet: SOLVER_ADDRESS.to_string(),
+            legs: vec![leg("i1", "0xalice", 1_000), leg("i2", "0xbob", 2_000)],
+            deposit: BatchDeposit::Staking {
+                validator: "0xval".to_string(),
+            },
+            fee: Some(FeeTransfer {
+                amount: 3,
+                treasury: "0xtreasury".to_string(),
+            }),
+        };
+        let args = batch_ptb_args(&params, "@0xgas", "gas").join(" ");
+
+        assert!(args.contains("--split-coins gas [ 1000,2000 ] --assign leg_coins"));
+        assert!(args.contains(
+            "@ 0x5 leg_coins.1 @ 0xval --assign receipt_1 --transfer-objects [ receipt_1 ] @ 0xbob"
+        ));
+        assert!(args.ends_with("--transfer-objects [ protocol_fee ] @ 0xtreasury"));
+        assert!(args.contains("--gas-budget 105000000"));
+
+        let expected = batch_expected_transfers(&params);
+        assert_eq!(expected.len(), 3);
+        assert_eq!(
+            expected[1],
+            ExpectedTransfer::new("0xbob", "::staking_pool::StakedSui")
+        );
+        assert_eq!(expected[2].recipient, "0xtreasury");
+
+        // With a gas pool, legs and the fee come out of the principal coin
+        let args = batch_ptb_args(&params, "@0xgas", "@0xprincipal").join(" ");
+        assert!(args.contains("--gas-coin @0xgas --split-coins @0xprincipal [ 1000,2000 ]"));
+        assert!(args.contains("--split-coins @0xprincipal [ 3 ] --assign protocol_fee"));
+    }
+
     #[tokio::test]
     async fn test_check_balance() {
         // This will fail if wallet not configured, but shows the function works
-        let result = check_solver_balance().await;
+        let result = check_solver_balance(SOLVER_ADDRESS).await;
         // Just verify it doesn't panic
         let _ = result;
     }
+
+    /// Secp256k1 key standing in for a KMS-held one
+    struct RemoteKey(k256::ecdsa::SigningKey);
+
+    #[async_trait::async_trait]
+    impl Signer for RemoteKey {
+        fn label(&self) -> String {
+            "remote".to_string()
+        }
+
+        fn public_key(&self) -> SuiPublicKey {
+            let point = self.0.verifying_key().to_encoded_point(true);
+            SuiPublicKey::new(SignatureScheme::Secp256k1, point.as_bytes().to_vec()).unwrap()
+        }
+
+        async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+            use k256::ecdsa::signature::hazmat::PrehashSigner;
+            let digest: [u8; 32] = Sha256::digest(transaction_digest(tx_bytes)).into();
+            let signature: k256::ecdsa::Signature = self.0.sign_prehash(&digest)?;
+            normalize_ecdsa_signature(SignatureScheme::Secp256k1, &signature.to_bytes())
+        }
+    }
+
+    /// Executed `(tx_bytes, signatures)`
+    type Executions = Vec<(String, Vec<String>)>;
+
+    /// Node that records what it is asked to execute
+    #[derive(Clone, Default)]
+    struct Node {
+        executed: Arc<std::sync::Mutex<Executions>>,
+    }
+
+    fn unsupported(op: &str) -> SuiClientError {
+        SuiClientError::Request(format!("{} is not supported by Node", op))
+    }
+
+    #[async_trait::async_trait]
+    impl SuiTransport for Node {
+        fn name(&self) -> &'static str {
+            "node"
+        }
+
+        async fn get_coins(
+            &self,
+            _owner: &str,
+            _coin_type: Option<&str>,
+            _cursor: Option<&str>,
+        ) -> Result<CoinPage, SuiClientError> {
+            Err(unsupported("get_coins"))
+        }
+
+        async fn get_object(&self, _object_id: &str) -> Result<SuiObject, SuiClientError> {
+            Err(unsupported("get_object"))
+        }
+
+        async fn get_dynamic_field_object(
+            &self,
+            _parent_id: &str,
+            _name: serde_json::Value,
+        ) -> Result<SuiObject, SuiClientError> {
+            Err(unsupported("get_dynamic_field_object"))
+        }
+
+        async fn query_events(
+            &self,
+            _event_type: &str,
+            _cursor: Option<&str>,
+            _limit: usize,
+        ) -> Result<EventPage, SuiClientError> {
+            Err(unsupported("query_events"))
+        }
+
+        async fn dry_run_transaction(
+            &self,
+            _tx_bytes: &str,
+        ) -> Result<DryRunResponse, SuiClientError> {
+            Err(unsupported("dry_run_transaction"))
+        }
+
+        async fn execute_transaction(
+            &self,
+            tx_bytes: &str,
+            signatures: Vec<String>,
+        ) -> Result<TransactionResponse, SuiClientError> {
+            self.executed
+                .lock()
+                .unwrap()
+                .push((tx_bytes.to_string(), signatures));
+            serde_json::from_value(serde_json::json!({
+                "digest": "0xsigned",
+                "effects": {
+                    "status": { "status": "success" },
+                    "gasUsed": { "computationCost": "1000", "storageCost": "500", "storageRebate": "200" }
+                },
+                "objectChanges": [{
+                    "type": "created",
+                    "sender": "0xsolver",
+                    "owner": { "AddressOwner": "0xalice" },
+                    "objectType": "0x3::staking_pool::StakedSui",
+                    "objectId": "0xstake",
+                    "version": "7",
+                    "digest": "d"
+                }]
+            }))
+            .map_err(|e| SuiClientError::Parse(e.to_string()))
+        }
+
+        async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+            Err(unsupported("get_reference_gas_price"))
+        }
+
+        async fn verify_zklogin_signature(
+            &self,
+            _message: &str,
+            _signature: &str,
+            _author: &str,
+        ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+            Err(unsupported("verify_zklogin_signature"))
+        }
+
+        async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+            Err(unsupported("get_validators"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_executor_signs_with_remote_key() {
+        use k256::ecdsa::signature::hazmat::PrehashVerifier;
+        use std::os::unix::process::ExitStatusExt;
+
+        let key = k256::ecdsa::SigningKey::from_slice(&[5u8; 32]).unwrap();
+        let verifying_key = *key.verifying_key();
+        let signer: Arc<dyn Signer> = Arc::new(RemoteKey(key));
+        let wallet = signer.address();
+        let node = Node::default();
+        register_executor(Arc::new(
+            SuiExecutor::new("http://unused", &wallet)
+                .with_client(SuiClient::with_transport(
+                    SuiConfig::testnet(),
+                    Box::new(node.clone()),
+                ))
+                .with_signer(signer),
+        ));
+
+        // What `sui client ptb --serialize-unsigned-transaction` prints
+        let tx_bytes = b"transaction-data".to_vec();
+        let output = Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: format!("{}\n", BASE64.encode(&tx_bytes)).into_bytes(),
+            stderr: vec![],
+        };
+        let executor = executor_for(&wallet).expect("executor registered for its wallet");
+        let outcome = submit_serialized(&executor, &output, "Test").await.unwrap();
+
+        assert_eq!(outcome.digest, "0xsigned");
+        assert_eq!(outcome.created_for("0xalice").len(), 1);
+        assert_eq!(outcome.gas_used.net(), 1_300);
+
+        let executed = node.executed.lock().unwrap();
+        let (sent, signatures) = &executed[0];
+        assert_eq!(BASE64.decode(sent).unwrap(), tx_bytes);
+        let signature = BASE64.decode(&signatures[0]).unwrap();
+        assert_eq!(signature[0], SignatureScheme::Secp256k1.flag());
+        let raw = k256::ecdsa::Signature::from_slice(&signature[1..65]).unwrap();
+        let digest: [u8; 32] = Sha256::digest(transaction_digest(&tx_bytes)).into();
+        assert!(verifying_key.verify_prehash(&digest, &raw).is_ok());
+        assert!(executor_for(SOLVER_ADDRESS).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_registered_multisig_signs_for_treasury() {
+        use crate::executor::multisig::{
+            MultiSigMember, MultiSigPublicKey, MultiSigWallet, MULTISIG_FLAG,
+        };
+        use crate::executor::signer::LocalSigner;
+        use std::os::unix::process::ExitStatusExt;
+
+        let key = |n: u8| LocalSigner::new(&format!("key{}", n), [n; 32]);
+        let members = (1..=2)
+            .map(|n| MultiSigMember {
+                key: key(n).public_key(),
+                weight: 1,
+            })
+            .collect();
+        let wallet = MultiSigWallet::new(
+            MultiSigPublicKey::new(members, 2).unwrap(),
+            vec![Box::new(key(1)), Box::new(key(2))],
+        )
+        .unwrap();
+        let treasury = wallet.address();
+        let node = Node::default();
+        // The solver key co-signs; the multisig signs for the treasury
+        register_executor(Arc::new(
+            SuiExecutor::new("http://unused", &treasury)
+                .with_client(SuiClient::with_transport(
+                    SuiConfig::testnet(),
+                    Box::new(node.clone()),
+                ))
+                .with_signer(Arc::new(key(1)))
+                .with_multisig(wallet),
+        ));
+
+        let output = Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: BASE64.encode(b"treasury-tx").into_bytes(),
+            stderr: vec![],
+        };
+        let executor = executor_for(&treasury).expect("executor registered for the treasury");
+        submit_serialized(&executor, &output, "Test").await.unwrap();
+
+        let executed = node.executed.lock().unwrap();
+        let signature = BASE64.decode(&executed[0].1[0]).unwrap();
+        assert_eq!(signature[0], MULTISIG_FLAG);
+        assert_eq!(signature[1], 2); // both members signed
+    }
 }
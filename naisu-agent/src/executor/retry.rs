@@ -0,0 +1,258 @@
+//! Shared-object contention handling
+//!
+//! PTBs that touch hot shared objects (Scallop market, Cetus pool, the Sui
+//! system state) can be rejected or cancelled when another transaction wins
+//! the object first. `sui client ptb` resolves object references when the
+//! transaction is built, so rebuilding and resubmitting picks up the latest
+//! versions. This module detects those failures and retries with jittered
+//! backoff a bounded number of times.
+
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use naisu_sui::{ExecutionStatus, FulfillmentOutcome};
+use tracing::warn;
+
+/// Error fragments that indicate a version conflict or congestion on a
+/// shared object, rather than a real failure of the transaction logic.
+///
+/// Owned-object equivocation (`ObjectLockConflict`, "already locked by a
+/// different transaction") is deliberately absent: the object stays locked until the epoch ends, so
+/// retrying only delays the error.
+const CONTENTION_MARKERS: &[&str] = &[
+    "ObjectVersionUnavailableForConsumption",
+    "is not available for consumption",
+    "ExecutionCancelledDueToSharedObjectCongestion",
+    "SharedObjectCongestion",
+    "TooManyTransactionsPendingOnObject",
+];
+
+/// Retry policy for contended transactions
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first one
+    pub max_attempts: u32,
+    /// Base delay before the first retry (doubles each attempt)
+    pub base_delay: Duration,
+    /// Upper bound for a single delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(4),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `attempt` (1-based), with up to 50% jitter
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let capped = exp.min(self.max_delay);
+        let jitter_ms = capped.as_millis() as u64 / 2;
+        let jitter = if jitter_ms == 0 {
+            0
+        } else {
+            jitter_seed() % jitter_ms
+        };
+        capped + Duration::from_millis(jitter)
+    }
+}
+
+/// Executor errors that callers may want to tell apart
+#[derive(Debug, thiserror::Error)]
+pub enum ExecutorError {
+    #[error("{label}: shared object still contended after {attempts} attempts: {last_error}")]
+    ObjectContended {
+        label: String,
+        attempts: u32,
+        last_error: String,
+    },
+
+    /// The CLI printed a digest but its effects could not be read
+    #[error("{label}: transaction {digest} submitted with unreadable effects: {error}")]
+    Unconfirmed {
+        label: String,
+        digest: String,
+        error: String,
+    },
+}
+
+/// Check whether an error message looks like shared-object contention
+pub fn is_contention_error(message: &str) -> bool {
+    CONTENTION_MARKERS.iter().any(|m| message.contains(m))
+}
+
+/// Run `attempt` until it succeeds, fails for a non-contention reason,
+/// or the retry budget is exhausted (-> `ExecutorError::ObjectContended`).
+///
+/// Outcomes with a failed execution status caused by congestion are
+/// retried too, since the transaction was cancelled before doing anything.
+pub async fn with_contention_retry<F, Fut>(
+    label: &str,
+    policy: RetryPolicy,
+    mut attempt: F,
+) -> Result<FulfillmentOutcome>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<FulfillmentOutcome>>,
+{
+    let mut last_error = String::new();
+
+    for n in 1..=policy.max_attempts.max(1) {
+        let message = match attempt().await {
+            Ok(outcome) => match &outcome.status {
+                ExecutionStatus::Failure { error } if is_contention_error(error) => error.clone(),
+                _ => return Ok(outcome),
+            },
+            Err(e) => {
+                let message = format!("{:#}", e);
+                if !is_contention_error(&message) {
+                    return Err(e);
+                }
+                message
+            }
+        };
+
+        last_error = message;
+        if n < policy.max_attempts {
+            let delay = policy.delay_for(n);
+            warn!(
+                "⚠️  {} hit shared object contention (attempt {}/{}), retrying in {:?}",
+                label, n, policy.max_attempts, delay
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    Err(ExecutorError::ObjectContended {
+        label: label.to_string(),
+        attempts: policy.max_attempts,
+        last_error,
+    }
+    .into())
+}
+
+/// Cheap jitter source (no need for a CSPRNG here)
+fn jitter_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_sui::GasCostSummary;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn outcome(status: ExecutionStatus) -> FulfillmentOutcome {
+        FulfillmentOutcome {
+            digest: "tx".to_string(),
+            created_objects: vec![],
+            gas_used: GasCostSummary::default(),
+            status,
+        }
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn test_is_contention_error() {
+        assert!(is_contention_error(
+            "Transaction failed: ObjectVersionUnavailableForConsumption { .. }"
+        ));
+        assert!(is_contention_error(
+            "ExecutionCancelledDueToSharedObjectCongestion"
+        ));
+        assert!(!is_contention_error("InsufficientGas"));
+    }
+
+    #[tokio::test]
+    async fn test_equivocation_is_not_retried() {
+        let calls = AtomicU32::new(0);
+        let result = with_contention_retry("Test", fast_policy(), || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async {
+                Err(anyhow::anyhow!(
+                    "ObjectLockConflict: object 0x5 is already locked by a different transaction"
+                ))
+            }
+        })
+        .await;
+
+        let err = result.unwrap_err();
+        assert!(err.downcast_ref::<ExecutorError>().is_none());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let policy = RetryPolicy::default();
+        // Cap (4s) plus at most 50% jitter
+        assert!(policy.delay_for(10) <= Duration::from_secs(6));
+        assert!(policy.delay_for(1) >= Duration::from_millis(250));
+    }
+
+    #[tokio::test]
+    async fn test_retries_then_succeeds() {
+        let calls = AtomicU32::new(0);
+        let result = with_contention_retry("Test", fast_policy(), || {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n == 0 {
+                    Err(anyhow::anyhow!("ObjectVersionUnavailableForConsumption"))
+                } else {
+                    Ok(outcome(ExecutionStatus::Success))
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_with_contended_error() {
+        let result = with_contention_retry("Test", fast_policy(), || async {
+            Ok(outcome(ExecutionStatus::Failure {
+                error: "ExecutionCancelledDueToSharedObjectCongestion".to_string(),
+            }))
+        })
+        .await;
+
+        let err = result.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ExecutorError>(),
+            Some(ExecutorError::ObjectContended { attempts: 3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_non_contention_error_is_not_retried() {
+        let calls = AtomicU32::new(0);
+        let result = with_contention_retry("Test", fast_policy(), || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Err(anyhow::anyhow!("InsufficientGas")) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
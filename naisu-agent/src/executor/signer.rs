@@ -0,0 +1,750 @@
+//! Transaction signers
+//!
+//! `Signer` abstracts where the solver key lives so production deployments
+//! never need a raw private key on disk:
+//! - `LocalSigner`: Ed25519 key in process memory (dev / testnet)
+//! - `KmsSigner`: secp256k1 / P-256 key in AWS KMS, GCP Cloud KMS, or any
+//!   HTTP signing service speaking the same small protocol
+//! - `RotatingSigner`: wraps another signer and supports key rotation
+//!
+//! All signers sign the Sui intent digest `blake2b256([0,0,0] ‖ tx_bytes)`.
+//! ECDSA schemes additionally hash that digest with SHA-256 (as Sui does),
+//! and signatures are normalized to low-s.
+
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use ed25519_dalek::{Signer as _, SigningKey};
+use naisu_core::Secret;
+use sha2::Sha256;
+use tracing::info;
+use zeroize::Zeroizing;
+
+pub(crate) type Blake2b256 = Blake2b<U32>;
+
+// ─── Keys ────────────────────────────────────────────────────────────────────
+
+/// Signature schemes supported by Sui keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+    Ed25519,
+    Secp256k1,
+    Secp256r1,
+}
+
+impl SignatureScheme {
+    /// Sui signature scheme flag
+    pub fn flag(&self) -> u8 {
+        match self {
+            SignatureScheme::Ed25519 => 0x00,
+            SignatureScheme::Secp256k1 => 0x01,
+            SignatureScheme::Secp256r1 => 0x02,
+        }
+    }
+
+    pub fn from_flag(flag: u8) -> Option<Self> {
+        match flag {
+            0x00 => Some(SignatureScheme::Ed25519),
+            0x01 => Some(SignatureScheme::Secp256k1),
+            0x02 => Some(SignatureScheme::Secp256r1),
+            _ => None,
+        }
+    }
+
+    /// Public key length in bytes
+    pub fn public_key_len(&self) -> usize {
+        match self {
+            SignatureScheme::Ed25519 => 32,
+            SignatureScheme::Secp256k1 | SignatureScheme::Secp256r1 => 33,
+        }
+    }
+}
+
+/// A Sui public key with its scheme
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuiPublicKey {
+    pub scheme: SignatureScheme,
+    pub bytes: Vec<u8>,
+}
+
+impl SuiPublicKey {
+    pub fn new(scheme: SignatureScheme, bytes: Vec<u8>) -> Result<Self> {
+        if bytes.len() != scheme.public_key_len() {
+            anyhow::bail!(
+                "Invalid {:?} public key length: {} (expected {})",
+                scheme,
+                bytes.len(),
+                scheme.public_key_len()
+            );
+        }
+        Ok(Self { scheme, bytes })
+    }
+
+    /// Parse Sui's base64 `flag ‖ pubkey` encoding (as printed by `sui keytool`)
+    pub fn from_sui_base64(encoded: &str) -> Result<Self> {
+        let raw = BASE64
+            .decode(encoded)
+            .context("Invalid base64 public key")?;
+        let (flag, bytes) = raw.split_first().context("Empty public key")?;
+        let scheme = SignatureScheme::from_flag(*flag)
+            .with_context(|| format!("Unsupported key scheme flag: {}", flag))?;
+        Self::new(scheme, bytes.to_vec())
+    }
+
+    /// Sui address: `blake2b256(flag ‖ pubkey)`
+    pub fn address(&self) -> String {
+        let mut hasher = Blake2b256::new();
+        hasher.update([self.scheme.flag()]);
+        hasher.update(&self.bytes);
+        format!("0x{}", hex_encode(&hasher.finalize()))
+    }
+
+    /// Serialize a raw signature as base64 `flag ‖ sig ‖ pubkey`
+    pub fn serialize_signature(&self, raw_signature: &[u8]) -> String {
+        let mut bytes = vec![self.scheme.flag()];
+        bytes.extend_from_slice(raw_signature);
+        bytes.extend_from_slice(&self.bytes);
+        BASE64.encode(bytes)
+    }
+}
+
+// ─── Signer trait ────────────────────────────────────────────────────────────
+
+/// Something that can sign Sui transactions
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Human-readable label (for logs)
+    fn label(&self) -> String;
+
+    /// Public key of the signing key
+    fn public_key(&self) -> SuiPublicKey;
+
+    /// Sign transaction bytes, returning the raw 64-byte signature
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>>;
+
+    /// Sui address controlled by this signer
+    fn address(&self) -> String {
+        self.public_key().address()
+    }
+
+    /// Sign and serialize (base64 `flag ‖ sig ‖ pubkey`), ready for execution
+    async fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String> {
+        let signature = self.sign_raw(tx_bytes).await?;
+        Ok(self.public_key().serialize_signature(&signature))
+    }
+}
+
+#[async_trait]
+impl<S: Signer + ?Sized> Signer for Arc<S> {
+    fn label(&self) -> String {
+        (**self).label()
+    }
+
+    fn public_key(&self) -> SuiPublicKey {
+        (**self).public_key()
+    }
+
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+        (**self).sign_raw(tx_bytes).await
+    }
+
+    async fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String> {
+        (**self).sign_transaction(tx_bytes).await
+    }
+}
+
+// ─── Local key ───────────────────────────────────────────────────────────────
+
+/// Ed25519 key held in process memory (zeroized on drop)
+pub struct LocalSigner {
+    label: String,
+    key: SigningKey,
+}
+
+impl std::fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalSigner")
+            .field("label", &self.label)
+            .field("address", &self.address())
+            .field("key", &naisu_core::REDACTED)
+            .finish()
+    }
+}
+
+impl LocalSigner {
+    pub fn new(label: &str, secret: [u8; 32]) -> Self {
+        Self {
+            label: label.to_string(),
+            key: SigningKey::from_bytes(&secret),
+        }
+    }
+
+    /// Parse a 32-byte hex secret or Sui's base64 `flag ‖ secret` export
+    pub fn from_encoded(label: &str, encoded: &str) -> Result<Self> {
+        let raw = match hex_decode(encoded.strip_prefix("0x").unwrap_or(encoded)) {
+            Some(bytes) => Zeroizing::new(bytes),
+            None => {
+                let bytes = Zeroizing::new(
+                    BASE64
+                        .decode(encoded)
+                        .context("Key is neither hex nor base64")?,
+                );
+                match bytes.split_first() {
+                    Some((0x00, rest)) => Zeroizing::new(rest.to_vec()),
+                    _ => anyhow::bail!("Only Ed25519 local keys are supported"),
+                }
+            }
+        };
+        let secret: Zeroizing<[u8; 32]> = Zeroizing::new(
+            raw.as_slice()
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("Ed25519 secret must be 32 bytes"))?,
+        );
+        Ok(Self::new(label, *secret))
+    }
+
+    /// Sign a Sui personal message, serialized like a wallet signature
+    pub fn sign_personal_message(&self, message: &[u8]) -> String {
+        let digest = naisu_sui::personal_message_digest(message);
+        let signature = self.key.sign(&digest).to_bytes();
+        self.public_key().serialize_signature(&signature)
+    }
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn public_key(&self) -> SuiPublicKey {
+        SuiPublicKey {
+            scheme: SignatureScheme::Ed25519,
+            bytes: self.key.verifying_key().to_bytes().to_vec(),
+        }
+    }
+
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+        let digest = transaction_digest(tx_bytes);
+        Ok(self.key.sign(&digest).to_bytes().to_vec())
+    }
+}
+
+// ─── KMS / remote signer ─────────────────────────────────────────────────────
+
+/// Where a `KmsSigner` sends signing requests
+#[derive(Debug, Clone)]
+pub enum KmsBackend {
+    /// AWS KMS JSON API. AWS requires SigV4 request signing, so `endpoint`
+    /// is expected to be a SigV4 signing proxy (e.g. the `aws-sigv4-proxy`
+    /// sidecar) in front of `kms.<region>.amazonaws.com`.
+    Aws { endpoint: String, key_id: String },
+    /// GCP Cloud KMS REST API, authenticated with an OAuth access token
+    Gcp {
+        /// `projects/*/locations/*/keyRings/*/cryptoKeys/*/cryptoKeyVersions/*`
+        key_version: String,
+        access_token: Secret,
+    },
+    /// Generic HTTP signer:
+    /// - `GET  {url}/public-key?keyId=..` -> `{ "publicKey": <base64 flag ‖ pk> }`
+    /// - `POST {url}/sign` `{ keyId, digest }` -> `{ "signature": <base64 DER or raw> }`
+    Http { url: String, key_id: String },
+}
+
+/// ECDSA signer whose private key never leaves the KMS/HSM
+pub struct KmsSigner {
+    backend: KmsBackend,
+    public_key: SuiPublicKey,
+    client: reqwest::Client,
+}
+
+impl KmsSigner {
+    /// Connect to the backend and fetch the public key
+    pub async fn connect(backend: KmsBackend) -> Result<Self> {
+        let client = reqwest::Client::new();
+        let public_key = fetch_public_key(&client, &backend).await?;
+        info!(
+            "🔐 KMS signer ready: {:?} key, address {}",
+            public_key.scheme,
+            public_key.address()
+        );
+        Ok(Self {
+            backend,
+            public_key,
+            client,
+        })
+    }
+
+    /// Build with a known public key (skips the network round trip)
+    pub fn with_public_key(backend: KmsBackend, public_key: SuiPublicKey) -> Result<Self> {
+        if public_key.scheme == SignatureScheme::Ed25519 {
+            anyhow::bail!("KMS signers only support secp256k1 / secp256r1 keys");
+        }
+        Ok(Self {
+            backend,
+            public_key,
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Request a DER (or raw) signature over a SHA-256 digest
+    async fn remote_sign(&self, digest: &[u8; 32]) -> Result<Vec<u8>> {
+        let encoded = BASE64.encode(digest);
+
+        let signature = match &self.backend {
+            KmsBackend::Aws { endpoint, key_id } => {
+                let body: serde_json::Value = self
+                    .client
+                    .post(endpoint)
+                    .header("X-Amz-Target", "TrentService.Sign")
+                    .header("Content-Type", "application/x-amz-json-1.1")
+                    .json(&serde_json::json!({
+                        "KeyId": key_id,
+                        "Message": encoded,
+                        "MessageType": "DIGEST",
+                        "SigningAlgorithm": "ECDSA_SHA_256",
+                    }))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                body["Signature"].as_str().map(|s| s.to_string())
+            }
+            KmsBackend::Gcp {
+                key_version,
+                access_token,
+            } => {
+                let body: serde_json::Value = self
+                    .client
+                    .post(format!(
+                        "https://cloudkms.googleapis.com/v1/{}:asymmetricSign",
+                        key_version
+                    ))
+                    .bearer_auth(access_token.expose())
+                    .json(&serde_json::json!({ "digest": { "sha256": encoded } }))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                body["signature"].as_str().map(|s| s.to_string())
+            }
+            KmsBackend::Http { url, key_id } => {
+                let body: serde_json::Value = self
+                    .client
+                    .post(format!("{}/sign", url.trim_end_matches('/')))
+                    .json(&serde_json::json!({ "keyId": key_id, "digest": encoded }))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                body["signature"].as_str().map(|s| s.to_string())
+            }
+        };
+
+        let signature = signature.context("Signer response missing signature")?;
+        BASE64
+            .decode(signature)
+            .context("Invalid base64 signature from signer")
+    }
+}
+
+#[async_trait]
+impl Signer for KmsSigner {
+    fn label(&self) -> String {
+        match &self.backend {
+            KmsBackend::Aws { key_id, .. } => format!("aws-kms:{}", key_id),
+            KmsBackend::Gcp { key_version, .. } => format!("gcp-kms:{}", key_version),
+            KmsBackend::Http { key_id, .. } => format!("http:{}", key_id),
+        }
+    }
+
+    fn public_key(&self) -> SuiPublicKey {
+        self.public_key.clone()
+    }
+
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+        // Sui ECDSA signs SHA-256(blake2b256(intent ‖ tx))
+        let digest: [u8; 32] = Sha256::digest(transaction_digest(tx_bytes)).into();
+        let signature = self.remote_sign(&digest).await?;
+        normalize_ecdsa_signature(self.public_key.scheme, &signature)
+    }
+}
+
+async fn fetch_public_key(client: &reqwest::Client, backend: &KmsBackend) -> Result<SuiPublicKey> {
+    match backend {
+        KmsBackend::Aws { endpoint, key_id } => {
+            let body: serde_json::Value = client
+                .post(endpoint)
+                .header("X-Amz-Target", "TrentService.GetPublicKey")
+                .header("Content-Type", "application/x-amz-json-1.1")
+                .json(&serde_json::json!({ "KeyId": key_id }))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            let scheme = match body["KeySpec"].as_str() {
+                Some("ECC_SECG_P256K1") => SignatureScheme::Secp256k1,
+                Some("ECC_NIST_P256") => SignatureScheme::Secp256r1,
+                other => anyhow::bail!("Unsupported AWS KMS key spec: {:?}", other),
+            };
+            let der = BASE64
+                .decode(body["PublicKey"].as_str().context("Missing PublicKey")?)
+                .context("Invalid PublicKey encoding")?;
+            public_key_from_spki_der(scheme, &der)
+        }
+        KmsBackend::Gcp {
+            key_version,
+            access_token,
+        } => {
+            let body: serde_json::Value = client
+                .get(format!(
+                    "https://cloudkms.googleapis.com/v1/{}/publicKey",
+                    key_version
+                ))
+                .bearer_auth(access_token.expose())
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            let scheme = match body["algorithm"].as_str() {
+                Some("EC_SIGN_SECP256K1_SHA256") => SignatureScheme::Secp256k1,
+                Some("EC_SIGN_P256_SHA256") => SignatureScheme::Secp256r1,
+                other => anyhow::bail!("Unsupported GCP KMS algorithm: {:?}", other),
+            };
+            let pem = body["pem"].as_str().context("Missing pem")?;
+            public_key_from_spki_pem(scheme, pem)
+        }
+        KmsBackend::Http { url, key_id } => {
+            let body: serde_json::Value = client
+                .get(format!("{}/public-key", url.trim_end_matches('/')))
+                .query(&[("keyId", key_id)])
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+            SuiPublicKey::from_sui_base64(body["publicKey"].as_str().context("Missing publicKey")?)
+        }
+    }
+}
+
+/// Compressed SEC1 key from a DER SubjectPublicKeyInfo
+fn public_key_from_spki_der(scheme: SignatureScheme, der: &[u8]) -> Result<SuiPublicKey> {
+    use k256::pkcs8::DecodePublicKey;
+
+    let bytes = match scheme {
+        SignatureScheme::Secp256k1 => k256::PublicKey::from_public_key_der(der)
+            .map_err(|e| anyhow::anyhow!("Invalid secp256k1 key: {}", e))?
+            .to_sec1_bytes()
+            .to_vec(),
+        SignatureScheme::Secp256r1 => p256::PublicKey::from_public_key_der(der)
+            .map_err(|e| anyhow::anyhow!("Invalid P-256 key: {}", e))?
+            .to_sec1_bytes()
+            .to_vec(),
+        SignatureScheme::Ed25519 => anyhow::bail!("Ed25519 is not supported for KMS keys"),
+    };
+    // to_sec1_bytes is uncompressed; Sui wants the 33-byte compressed form
+    SuiPublicKey::new(scheme, compress_sec1(&bytes)?)
+}
+
+fn public_key_from_spki_pem(scheme: SignatureScheme, pem: &str) -> Result<SuiPublicKey> {
+    let body: String = pem
+        .lines()
+        .filter(|l| !l.starts_with("-----"))
+        .collect::<Vec<_>>()
+        .concat();
+    let der = BASE64.decode(body).context("Invalid PEM body")?;
+    public_key_from_spki_der(scheme, &der)
+}
+
+/// Compress an uncompressed SEC1 point (`0x04 ‖ X ‖ Y`)
+fn compress_sec1(point: &[u8]) -> Result<Vec<u8>> {
+    match point.len() {
+        33 => Ok(point.to_vec()),
+        65 if point[0] == 0x04 => {
+            let prefix = if point[64] & 1 == 1 { 0x03 } else { 0x02 };
+            let mut out = vec![prefix];
+            out.extend_from_slice(&point[1..33]);
+            Ok(out)
+        }
+        n => anyhow::bail!("Unexpected SEC1 point length: {}", n),
+    }
+}
+
+/// Convert a DER (or raw r ‖ s) ECDSA signature into Sui's 64-byte low-s form
+pub fn normalize_ecdsa_signature(scheme: SignatureScheme, signature: &[u8]) -> Result<Vec<u8>> {
+    let bytes = match scheme {
+        SignatureScheme::Secp256k1 => {
+            let sig = if signature.len() == 64 {
+                k256::ecdsa::Signature::from_slice(signature)
+            } else {
+                k256::ecdsa::Signature::from_der(signature)
+            }
+            .map_err(|e| anyhow::anyhow!("Invalid secp256k1 signature: {}", e))?;
+            sig.normalize_s().unwrap_or(sig).to_bytes().to_vec()
+        }
+        SignatureScheme::Secp256r1 => {
+            let sig = if signature.len() == 64 {
+                p256::ecdsa::Signature::from_slice(signature)
+            } else {
+                p256::ecdsa::Signature::from_der(signature)
+            }
+            .map_err(|e| anyhow::anyhow!("Invalid P-256 signature: {}", e))?;
+            sig.normalize_s().unwrap_or(sig).to_bytes().to_vec()
+        }
+        SignatureScheme::Ed25519 => anyhow::bail!("Ed25519 signatures are not ECDSA"),
+    };
+    Ok(bytes)
+}
+
+// ─── Key rotation ────────────────────────────────────────────────────────────
+
+/// Signer that can swap its active key at runtime
+///
+/// Retired signers are kept so the operator can still sweep funds left at
+/// their addresses after a rotation.
+pub struct RotatingSigner {
+    active: RwLock<Arc<dyn Signer>>,
+    retired: RwLock<Vec<Arc<dyn Signer>>>,
+}
+
+impl RotatingSigner {
+    pub fn new(signer: Arc<dyn Signer>) -> Self {
+        Self {
+            active: RwLock::new(signer),
+            retired: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Currently active signer
+    pub fn active(&self) -> Arc<dyn Signer> {
+        self.active.read().expect("signer lock poisoned").clone()
+    }
+
+    /// Make `next` the active signer, returning the one it replaced
+    pub fn rotate(&self, next: Arc<dyn Signer>) -> Arc<dyn Signer> {
+        let previous = {
+            let mut active = self.active.write().expect("signer lock poisoned");
+            std::mem::replace(&mut *active, next)
+        };
+        info!(
+            "🔄 Rotated signer: {} -> {}",
+            previous.address(),
+            self.active().address()
+        );
+        self.retired
+            .write()
+            .expect("signer lock poisoned")
+            .push(previous.clone());
+        previous
+    }
+
+    /// Addresses of keys rotated out (may still hold funds)
+    pub fn retired_addresses(&self) -> Vec<String> {
+        self.retired
+            .read()
+            .expect("signer lock poisoned")
+            .iter()
+            .map(|s| s.address())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Signer for RotatingSigner {
+    fn label(&self) -> String {
+        format!("rotating({})", self.active().label())
+    }
+
+    fn public_key(&self) -> SuiPublicKey {
+        self.active().public_key()
+    }
+
+    async fn sign_raw(&self, tx_bytes: &[u8]) -> Result<Vec<u8>> {
+        self.active().sign_raw(tx_bytes).await
+    }
+
+    async fn sign_transaction(&self, tx_bytes: &[u8]) -> Result<String> {
+        // Snapshot once so the signature and public key always match
+        let signer = self.active();
+        signer.sign_transaction(tx_bytes).await
+    }
+}
+
+// ─── From environment ────────────────────────────────────────────────────────
+
+/// Build the solver signer from environment variables
+///
+/// `SOLVER_SIGNER` selects the backend:
+/// - `local` (default): `SOLVER_PRIVATE_KEY`
+/// - `aws-kms`: `AWS_KMS_ENDPOINT`, `KMS_KEY_ID`
+/// - `gcp-kms`: `GCP_KMS_KEY_VERSION`, `GCP_ACCESS_TOKEN`
+/// - `http`: `REMOTE_SIGNER_URL`, `REMOTE_SIGNER_KEY_ID`
+///
+/// `SOLVER_PRIVATE_KEY` and `GCP_ACCESS_TOKEN` may instead be read from the
+/// file named by `SOLVER_PRIVATE_KEY_FILE` / `GCP_ACCESS_TOKEN_FILE`.
+pub async fn signer_from_env() -> Result<Arc<dyn Signer>> {
+    let var = |name: &str| std::env::var(name).with_context(|| format!("{} must be set", name));
+
+    let kind = std::env::var("SOLVER_SIGNER").unwrap_or_else(|_| "local".to_string());
+    let signer: Arc<dyn Signer> = match kind.as_str() {
+        "local" => Arc::new(LocalSigner::from_encoded(
+            "local",
+            Secret::required("SOLVER_PRIVATE_KEY")?.expose(),
+        )?),
+        "aws-kms" => Arc::new(
+            KmsSigner::connect(KmsBackend::Aws {
+                endpoint: var("AWS_KMS_ENDPOINT")?,
+                key_id: var("KMS_KEY_ID")?,
+            })
+            .await?,
+        ),
+        "gcp-kms" => Arc::new(
+            KmsSigner::connect(KmsBackend::Gcp {
+                key_version: var("GCP_KMS_KEY_VERSION")?,
+                access_token: Secret::required("GCP_ACCESS_TOKEN")?,
+            })
+            .await?,
+        ),
+        "http" => Arc::new(
+            KmsSigner::connect(KmsBackend::Http {
+                url: var("REMOTE_SIGNER_URL")?,
+                key_id: var("REMOTE_SIGNER_KEY_ID")?,
+            })
+            .await?,
+        ),
+        other => anyhow::bail!("Unknown SOLVER_SIGNER: {}", other),
+    };
+
+    Ok(signer)
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+/// Digest signed by Sui keys: `blake2b256(intent ‖ tx_bytes)`,
+/// with intent = TransactionData / V0 / Sui
+pub fn transaction_digest(tx_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::new();
+    hasher.update([0u8, 0, 0]);
+    hasher.update(tx_bytes);
+    hasher.finalize().into()
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::signature::hazmat::PrehashVerifier;
+
+    #[tokio::test]
+    async fn test_local_signer_serialization() {
+        let signer = LocalSigner::new("test", [7u8; 32]);
+        let serialized = BASE64
+            .decode(signer.sign_transaction(b"tx").await.unwrap())
+            .unwrap();
+
+        assert_eq!(serialized.len(), 1 + 64 + 32);
+        assert_eq!(serialized[0], SignatureScheme::Ed25519.flag());
+        assert_eq!(&serialized[65..], &signer.public_key().bytes[..]);
+        assert_eq!(signer.address().len(), 66);
+    }
+
+    #[test]
+    fn test_local_signer_from_encoded() {
+        let hex = "07".repeat(32);
+        let from_hex = LocalSigner::from_encoded("a", &hex).unwrap();
+
+        let mut flagged = vec![0x00];
+        flagged.extend([7u8; 32]);
+        let from_b64 = LocalSigner::from_encoded("b", &BASE64.encode(flagged)).unwrap();
+
+        assert_eq!(from_hex.public_key(), from_b64.public_key());
+    }
+
+    #[test]
+    fn test_local_signer_debug_is_redacted() {
+        let hex = "07".repeat(32);
+        let signer = LocalSigner::from_encoded("solver", &hex).unwrap();
+        let debug = format!("{:?}", signer);
+
+        assert!(debug.contains(naisu_core::REDACTED));
+        assert!(!debug.contains(&hex));
+        assert!(!format!("{:?}", Secret::new(hex.clone())).contains(&hex));
+    }
+
+    #[test]
+    fn test_normalize_der_signature_to_low_s() {
+        let key = k256::ecdsa::SigningKey::from_slice(&[9u8; 32]).unwrap();
+        let digest: [u8; 32] = Sha256::digest(transaction_digest(b"tx")).into();
+        let (sig, _) = key.sign_prehash_recoverable(&digest).unwrap();
+
+        // Force a high-s variant, as KMS may return
+        let high_s = match sig.normalize_s() {
+            Some(_) => sig,
+            None => {
+                let s = sig.s();
+                k256::ecdsa::Signature::from_scalars(sig.r(), -*s).unwrap()
+            }
+        };
+
+        let normalized =
+            normalize_ecdsa_signature(SignatureScheme::Secp256k1, high_s.to_der().as_bytes())
+                .unwrap();
+        assert_eq!(normalized.len(), 64);
+
+        let parsed = k256::ecdsa::Signature::from_slice(&normalized).unwrap();
+        assert!(parsed.normalize_s().is_none(), "signature must be low-s");
+        assert!(key.verifying_key().verify_prehash(&digest, &parsed).is_ok());
+    }
+
+    #[test]
+    fn test_compress_sec1() {
+        let mut point = vec![0x04];
+        point.extend([1u8; 32]);
+        point.extend([3u8; 32]); // odd y
+        let compressed = compress_sec1(&point).unwrap();
+        assert_eq!(compressed[0], 0x03);
+        assert_eq!(compressed.len(), 33);
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_retired_address() {
+        let first: Arc<dyn Signer> = Arc::new(LocalSigner::new("first", [1u8; 32]));
+        let second: Arc<dyn Signer> = Arc::new(LocalSigner::new("second", [2u8; 32]));
+        let first_address = first.address();
+
+        let rotating = RotatingSigner::new(first);
+        rotating.rotate(second.clone());
+
+        assert_eq!(rotating.address(), second.address());
+        assert_eq!(rotating.retired_addresses(), vec![first_address]);
+
+        let serialized = BASE64
+            .decode(rotating.sign_transaction(b"tx").await.unwrap())
+            .unwrap();
+        assert_eq!(&serialized[65..], &second.public_key().bytes[..]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Runtime feature flags
+//!
+//! Risky capabilities ship switched off and are turned on per deployment,
+//! first in the config file (`[features]`), then live through the admin API
+//! (`PUT /api/v1/admin/features/{name}`). The API keeps its changes as
+//! `FeatureOverrides`; daemons poll `GET /api/v1/admin/features` with the
+//! kill switch and layer them over their shared config, like solver tuning.
+//!
+//! | Flag                  | Gates                                          |
+//! |-----------------------|------------------------------------------------|
+//! | `mainnet_fulfillment` | Submitting fulfillments on mainnet             |
+//! | `new_solvers`         | Solvers registered as experimental             |
+//! | `gas_rebalancer`      | Gas pool rebalancing transactions              |
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::AgentConfig;
+
+/// A capability that can be switched on and off at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    /// Live fulfillment on mainnet (off: mainnet intents are evaluated only)
+    MainnetFulfillment,
+    /// Solvers whose descriptor is marked experimental
+    NewSolvers,
+    /// Gas pool rebalancing (off: the pool keeps the coins it has)
+    GasRebalancer,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 3] = [
+        Feature::MainnetFulfillment,
+        Feature::NewSolvers,
+        Feature::GasRebalancer,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::MainnetFulfillment => "mainnet_fulfillment",
+            Feature::NewSolvers => "new_solvers",
+            Feature::GasRebalancer => "gas_rebalancer",
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Feature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Feature::ALL
+            .into_iter()
+            .find(|feature| feature.name() == s)
+            .ok_or_else(|| format!("Unknown feature: {}", s))
+    }
+}
+
+/// `[features]` section; everything is off unless enabled
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeatureFlags {
+    pub mainnet_fulfillment: bool,
+    pub new_solvers: bool,
+    pub gas_rebalancer: bool,
+}
+
+impl FeatureFlags {
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::MainnetFulfillment => self.mainnet_fulfillment,
+            Feature::NewSolvers => self.new_solvers,
+            Feature::GasRebalancer => self.gas_rebalancer,
+        }
+    }
+
+    pub fn set(&mut self, feature: Feature, enabled: bool) {
+        match feature {
+            Feature::MainnetFulfillment => self.mainnet_fulfillment = enabled,
+            Feature::NewSolvers => self.new_solvers = enabled,
+            Feature::GasRebalancer => self.gas_rebalancer = enabled,
+        }
+    }
+}
+
+/// Flags set through the API, layered over the config file's
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureOverrides {
+    /// Bumped on every update
+    pub version: u64,
+    pub features: BTreeMap<Feature, bool>,
+}
+
+impl FeatureOverrides {
+    /// Set a flag; returns its previous override
+    pub fn set(&mut self, feature: Feature, enabled: bool) -> Option<bool> {
+        self.version += 1;
+        self.features.insert(feature, enabled)
+    }
+
+    /// Flags in force: `base` with the overrides layered on top
+    pub fn resolve(&self, base: &FeatureFlags) -> FeatureFlags {
+        let mut flags = base.clone();
+        for (&feature, &enabled) in &self.features {
+            flags.set(feature, enabled);
+        }
+        flags
+    }
+
+    /// Layer the overrides over a config's `[features]`
+    pub fn apply(&self, config: &mut AgentConfig) {
+        config.features = self.resolve(&config.features);
+    }
+
+    /// Whether `config` already has every override in force
+    pub fn is_applied(&self, config: &AgentConfig) -> bool {
+        self.resolve(&config.features) == config.features
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features_are_off_by_default() {
+        let config = AgentConfig::defaults();
+        assert!(Feature::ALL
+            .iter()
+            .all(|&feature| !config.features.is_enabled(feature)));
+    }
+
+    #[test]
+    fn test_overrides_layer_over_config() {
+        let mut config = AgentConfig::from_toml_str(
+            r#"
+            [features]
+            new_solvers = true
+            "#,
+        )
+        .unwrap();
+
+        let mut overrides = FeatureOverrides::default();
+        assert!(overrides.is_applied(&config));
+        assert_eq!(overrides.set(Feature::MainnetFulfillment, true), None);
+        assert_eq!(
+            overrides.set(Feature::MainnetFulfillment, false),
+            Some(true)
+        );
+        overrides.set(Feature::GasRebalancer, true);
+        assert_eq!(overrides.version, 3);
+        assert!(!overrides.is_applied(&config));
+
+        overrides.apply(&mut config);
+        assert!(!config.features.mainnet_fulfillment);
+        assert!(config.features.gas_rebalancer);
+        // Flags the API never touched keep the file's value
+        assert!(config.features.new_solvers);
+        assert!(overrides.is_applied(&config));
+    }
+
+    #[test]
+    fn test_feature_names_round_trip() {
+        for feature in Feature::ALL {
+            assert_eq!(feature.name().parse::<Feature>(), Ok(feature));
+        }
+        assert!("rebalancer".parse::<Feature>().is_err());
+    }
+}
@@ -0,0 +1,365 @@
+//! Protocol fees
+//!
+//! Naisu takes a fee from every fulfillment. The winning solver splits it
+//! off its own coin inside the fulfillment PTB and sends it to the treasury,
+//! so the fee settles atomically with the fulfillment.
+//!
+//! The rate is either flat or tiered by intent amount (the highest tier the
+//! amount reaches applies). Without a treasury address no fee is charged.
+//! A share of the fee on referred intents goes to the referrer (see
+//! `referral`).
+//!
+//! Settings layer as defaults → TOML `[fees]` table → env.
+
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use anyhow::Result;
+use naisu_sui::ExpectedTransfer;
+use serde::{Deserialize, Serialize};
+
+/// Highest fee rate accepted (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// How the fee rate is chosen
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum FeeSchedule {
+    /// Same rate for every intent
+    Flat { bps: u16 },
+    /// Rate by intent amount
+    Tiered { tiers: Vec<FeeTier> },
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        FeeSchedule::Flat { bps: 0 }
+    }
+}
+
+/// Rate for intents of at least `min_amount`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeeTier {
+    /// Base units (MIST)
+    pub min_amount: u64,
+    pub bps: u16,
+}
+
+impl FeeSchedule {
+    /// Rate for an intent amount (bps)
+    pub fn bps_for(&self, amount: u64) -> u16 {
+        match self {
+            FeeSchedule::Flat { bps } => *bps,
+            FeeSchedule::Tiered { tiers } => tiers
+                .iter()
+                .filter(|t| amount >= t.min_amount)
+                .max_by_key(|t| t.min_amount)
+                .map_or(0, |t| t.bps),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let rates: Vec<u16> = match self {
+            FeeSchedule::Flat { bps } => vec![*bps],
+            FeeSchedule::Tiered { tiers } => {
+                let mut amounts: Vec<u64> = tiers.iter().map(|t| t.min_amount).collect();
+                amounts.sort_unstable();
+                amounts.dedup();
+                if amounts.len() != tiers.len() {
+                    anyhow::bail!("fee tiers must have distinct min_amount");
+                }
+                tiers.iter().map(|t| t.bps).collect()
+            }
+        };
+        if rates.iter().any(|&bps| bps > MAX_FEE_BPS) {
+            anyhow::bail!("fee must be at most {} bps", MAX_FEE_BPS);
+        }
+        Ok(())
+    }
+}
+
+/// Fee settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeeConfig {
+    pub schedule: FeeSchedule,
+    /// Address fees are sent to
+    pub treasury: Option<String>,
+    /// Share of the fee owed to an intent's referrer (bps of the fee)
+    pub referral_share_bps: u16,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            schedule: FeeSchedule::default(),
+            treasury: None,
+            referral_share_bps: 2_000,
+        }
+    }
+}
+
+impl FeeConfig {
+    /// Override with `PROTOCOL_FEE_BPS` (flat rate), `FEE_TREASURY` and
+    /// `REFERRAL_SHARE_BPS`
+    pub fn with_env(mut self) -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+
+        if let Some(bps) = var("PROTOCOL_FEE_BPS").and_then(|v| u16::from_str(&v).ok()) {
+            self.schedule = FeeSchedule::Flat { bps };
+        }
+        if let Some(treasury) = var("FEE_TREASURY") {
+            self.treasury = Some(treasury);
+        }
+        if let Some(bps) = var("REFERRAL_SHARE_BPS").and_then(|v| u16::from_str(&v).ok()) {
+            self.referral_share_bps = bps;
+        }
+        self
+    }
+
+    /// Reject rates above the cap and fees with nowhere to go
+    pub fn validate(&self) -> Result<()> {
+        self.schedule.validate()?;
+        if self.referral_share_bps > 10_000 {
+            anyhow::bail!("referral_share_bps must be at most 10000");
+        }
+        let charges = match &self.schedule {
+            FeeSchedule::Flat { bps } => *bps > 0,
+            FeeSchedule::Tiered { tiers } => tiers.iter().any(|t| t.bps > 0),
+        };
+        if charges && self.treasury.is_none() {
+            anyhow::bail!("a non-zero fee needs a treasury address");
+        }
+        Ok(())
+    }
+
+    /// Fee owed on an intent amount
+    pub fn quote(&self, amount: u64) -> FeeQuote {
+        let bps = if self.treasury.is_some() {
+            self.schedule.bps_for(amount)
+        } else {
+            0
+        };
+        FeeQuote {
+            amount,
+            bps,
+            fee: (amount as u128 * bps as u128 / 10_000) as u64,
+        }
+    }
+
+    /// Transfer to add to the fulfillment PTB, if a fee is owed
+    pub fn transfer(&self, amount: u64) -> Option<FeeTransfer> {
+        let quote = self.quote(amount);
+        let treasury = self.treasury.clone()?;
+        (quote.fee > 0).then_some(FeeTransfer {
+            amount: quote.fee,
+            treasury,
+        })
+    }
+
+    /// One transfer covering every intent of a batch
+    ///
+    /// Each intent is quoted on its own amount, so tiers apply as if the
+    /// intents were fulfilled separately.
+    pub fn batch_transfer(&self, amounts: &[u64]) -> Option<FeeTransfer> {
+        let total: u64 = amounts.iter().map(|a| self.quote(*a).fee).sum();
+        let treasury = self.treasury.clone()?;
+        (total > 0).then_some(FeeTransfer {
+            amount: total,
+            treasury,
+        })
+    }
+}
+
+/// Fee on one intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FeeQuote {
+    /// Intent amount (MIST)
+    pub amount: u64,
+    pub bps: u16,
+    /// MIST
+    pub fee: u64,
+}
+
+/// Fee coin routed to the treasury
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTransfer {
+    /// MIST
+    pub amount: u64,
+    pub treasury: String,
+}
+
+impl FeeTransfer {
+    /// `sui client ptb` arguments that split the fee off the gas coin and
+    /// send it to the treasury
+    pub fn ptb_args(&self) -> Vec<String> {
+        self.ptb_args_from("gas")
+    }
+
+    /// The fee coin the treasury must receive
+    pub fn expected_transfer(&self) -> ExpectedTransfer {
+        ExpectedTransfer::new(&self.treasury, "::coin::Coin<0x2::sui::SUI>")
+    }
+
+    /// Same as `ptb_args`, splitting the fee off `source` (`gas` or `@<coin>`)
+    pub fn ptb_args_from(&self, source: &str) -> Vec<String> {
+        let amount = self.amount.to_string();
+        [
+            "--split-coins",
+            source,
+            "[",
+            amount.as_str(),
+            "]",
+            "--assign",
+            "protocol_fee",
+            "--transfer-objects",
+            "[",
+            "protocol_fee",
+            "]",
+            "@",
+            self.treasury.as_str(),
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+}
+
+// ─── Accrual ─────────────────────────────────────────────────────────────────
+
+/// Fees collected from one solver (or all of them)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FeeAccrual {
+    pub fulfillments: u64,
+    /// Intent volume fees were charged on (MIST)
+    pub volume: u64,
+    /// MIST
+    pub fees: u64,
+}
+
+impl FeeAccrual {
+    fn add(&mut self, volume: u64, fee: u64) {
+        self.fulfillments += 1;
+        self.volume += volume;
+        self.fees += fee;
+    }
+}
+
+/// Fees accrued per solver
+#[derive(Debug, Clone, Default)]
+pub struct FeeLedger {
+    by_solver: HashMap<String, FeeAccrual>,
+}
+
+/// Accrued fees, in total and per solver
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FeeReport {
+    pub total: FeeAccrual,
+    pub by_solver: BTreeMap<String, FeeAccrual>,
+}
+
+impl FeeLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a fee paid on a fulfillment of `volume`
+    pub fn accrue(&mut self, solver: &str, volume: u64, fee: u64) {
+        self.by_solver
+            .entry(solver.to_string())
+            .or_default()
+            .add(volume, fee);
+    }
+
+    /// Fees accrued from one solver
+    pub fn solver(&self, solver: &str) -> FeeAccrual {
+        self.by_solver.get(solver).copied().unwrap_or_default()
+    }
+
+    pub fn report(&self) -> FeeReport {
+        let total = self
+            .by_solver
+            .values()
+            .fold(FeeAccrual::default(), |mut total, a| {
+                total.fulfillments += a.fulfillments;
+                total.volume += a.volume;
+                total.fees += a.fees;
+                total
+            });
+        FeeReport {
+            total,
+            by_solver: self
+                .by_solver
+                .iter()
+                .map(|(s, a)| (s.clone(), *a))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TREASURY: &str = "0xfee";
+
+    #[test]
+    fn test_tiered_quote() {
+        let config = FeeConfig {
+            schedule: FeeSchedule::Tiered {
+                tiers: vec![
+                    FeeTier {
+                        min_amount: 0,
+                        bps: 30,
+                    },
+                    FeeTier {
+                        min_amount: 100_000_000_000,
+                        bps: 10,
+                    },
+                ],
+            },
+            treasury: Some(TREASURY.to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.quote(1_000_000_000).fee, 3_000_000);
+        assert_eq!(config.quote(200_000_000_000).bps, 10);
+
+        let transfer = config.transfer(1_000_000_000).unwrap();
+        assert_eq!(transfer.amount, 3_000_000);
+        assert_eq!(
+            transfer.ptb_args().last().map(String::as_str),
+            Some(TREASURY)
+        );
+
+        // No treasury, no fee
+        let config = FeeConfig {
+            treasury: None,
+            ..config
+        };
+        assert_eq!(config.quote(1_000_000_000).fee, 0);
+        assert!(config.transfer(1_000_000_000).is_none());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_ledger_accrues_per_solver() {
+        let mut ledger = FeeLedger::new();
+        ledger.accrue("A", 1_000, 3);
+        ledger.accrue("A", 2_000, 6);
+        ledger.accrue("B", 500, 1);
+
+        assert_eq!(ledger.solver("A").fees, 9);
+        let report = ledger.report();
+        assert_eq!(report.total.fulfillments, 3);
+        assert_eq!(report.total.volume, 3_500);
+        assert_eq!(report.by_solver["B"].fees, 1);
+        assert!(FeeConfig {
+            schedule: FeeSchedule::Flat { bps: 2_000 },
+            treasury: Some(TREASURY.to_string()),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
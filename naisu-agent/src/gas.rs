@@ -0,0 +1,172 @@
+//! Gas price oracle
+//!
+//! Tracks the network's reference gas price and the gas recent fulfillments
+//! actually paid, per PTB template (each solver builds one template). A
+//! solver converts its template's expected gas into bps of the intent
+//! amount and bids with that instead of the static `gas_cost_bps`, so
+//! intents too small to cover gas get no bid.
+//!
+//! Computation cost scales with the gas price, so samples are rescaled to
+//! the current reference price. Storage cost does not.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use naisu_sui::{GasCostSummary, SuiClient, SuiClientError};
+
+/// Fulfillments kept per template
+pub const DEFAULT_WINDOW: usize = 20;
+
+/// Gas one fulfillment paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GasSample {
+    /// Computation cost (MIST)
+    computation: u64,
+    /// Storage cost less the rebate (MIST)
+    storage: i64,
+    /// Reference gas price when it ran (0 = unknown)
+    gas_price: u64,
+}
+
+/// Live gas costs, shared by the daemon and its solvers
+#[derive(Debug)]
+pub struct GasOracle {
+    /// MIST per gas unit (0 = not fetched yet)
+    reference_gas_price: AtomicU64,
+    samples: RwLock<HashMap<String, VecDeque<GasSample>>>,
+    window: usize,
+}
+
+impl Default for GasOracle {
+    fn default() -> Self {
+        Self {
+            reference_gas_price: AtomicU64::new(0),
+            samples: RwLock::new(HashMap::new()),
+            window: DEFAULT_WINDOW,
+        }
+    }
+}
+
+impl GasOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Current reference gas price, once known
+    pub fn reference_gas_price(&self) -> Option<u64> {
+        Some(self.reference_gas_price.load(Ordering::Relaxed)).filter(|p| *p > 0)
+    }
+
+    pub fn set_reference_gas_price(&self, price: u64) {
+        self.reference_gas_price.store(price, Ordering::Relaxed);
+    }
+
+    /// Fetch the reference gas price for the current epoch
+    pub async fn refresh(&self, client: &SuiClient) -> Result<u64, SuiClientError> {
+        let price = client.get_reference_gas_price().await?;
+        self.set_reference_gas_price(price);
+        Ok(price)
+    }
+
+    /// Record the gas a fulfillment of `template` paid
+    pub fn record(&self, template: &str, gas: &GasCostSummary) {
+        let sample = GasSample {
+            computation: gas.computation_cost,
+            storage: gas.storage_cost as i64 - gas.storage_rebate as i64,
+            gas_price: self.reference_gas_price.load(Ordering::Relaxed),
+        };
+        let mut samples = self.samples.write().expect("gas samples lock poisoned");
+        let window = samples.entry(template.to_string()).or_default();
+        window.push_back(sample);
+        while window.len() > self.window {
+            window.pop_front();
+        }
+    }
+
+    /// Expected gas of a `template` fulfillment at today's price (MIST)
+    pub fn expected_gas(&self, template: &str) -> Option<u64> {
+        let samples = self.samples.read().expect("gas samples lock poisoned");
+        let window = samples.get(template).filter(|w| !w.is_empty())?;
+        let price = self.reference_gas_price.load(Ordering::Relaxed);
+
+        let total: i128 = window
+            .iter()
+            .map(|s| {
+                let computation = if s.gas_price > 0 && price > 0 {
+                    s.computation as i128 * price as i128 / s.gas_price as i128
+                } else {
+                    s.computation as i128
+                };
+                computation + s.storage as i128
+            })
+            .sum();
+        Some((total / window.len() as i128).max(0) as u64)
+    }
+
+    /// Expected gas of a `template` fulfillment as bps of `amount`
+    ///
+    /// Rounds up. `None` until the template has a recorded fulfillment.
+    pub fn gas_cost_bps(&self, template: &str, amount: u64) -> Option<u16> {
+        let gas = self.expected_gas(template)? as u128;
+        if amount == 0 {
+            return Some(u16::MAX);
+        }
+        let bps = (gas * 10_000).div_ceil(amount as u128);
+        Some(bps.min(u16::MAX as u128) as u16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gas(computation: u64, storage: u64, rebate: u64) -> GasCostSummary {
+        GasCostSummary {
+            computation_cost: computation,
+            storage_cost: storage,
+            storage_rebate: rebate,
+            non_refundable_storage_fee: 0,
+        }
+    }
+
+    #[test]
+    fn test_expected_gas_follows_price() {
+        let oracle = GasOracle::new().with_window(2);
+        assert_eq!(oracle.expected_gas("StakingSolver"), None);
+        assert_eq!(oracle.gas_cost_bps("StakingSolver", 1_000), None);
+
+        oracle.set_reference_gas_price(1_000);
+        oracle.record("StakingSolver", &gas(2_000_000, 1_000_000, 500_000));
+        oracle.record("StakingSolver", &gas(4_000_000, 1_000_000, 500_000));
+        assert_eq!(oracle.expected_gas("StakingSolver"), Some(3_500_000));
+
+        // Computation doubles with the price, storage doesn't
+        oracle.set_reference_gas_price(2_000);
+        assert_eq!(oracle.expected_gas("StakingSolver"), Some(6_500_000));
+
+        // Oldest sample falls out of the window
+        oracle.record("StakingSolver", &gas(8_000_000, 0, 0));
+        assert_eq!(oracle.expected_gas("StakingSolver"), Some(8_250_000));
+    }
+
+    #[test]
+    fn test_gas_cost_bps_scales_with_amount() {
+        let oracle = GasOracle::new();
+        oracle.record("NaviSolver", &gas(5_000_000, 0, 0));
+
+        // 0.005 SUI gas: 0.05 bps of 1000 SUI, 50 bps of 1 SUI
+        assert_eq!(
+            oracle.gas_cost_bps("NaviSolver", 1_000_000_000_000),
+            Some(1)
+        );
+        assert_eq!(oracle.gas_cost_bps("NaviSolver", 1_000_000_000), Some(50));
+        assert_eq!(oracle.gas_cost_bps("NaviSolver", 1), Some(u16::MAX));
+        assert_eq!(oracle.gas_cost_bps("NaviSolver", 0), Some(u16::MAX));
+    }
+}
@@ -0,0 +1,133 @@
+//! Solver heartbeats
+//!
+//! Each daemon pipeline POSTs a `Heartbeat` to `/api/v1/solvers/heartbeat`
+//! every `interval_secs`: its wallet balance and, per solver, whether it is
+//! taking intents and when it last fulfilled one. The API marks a solver
+//! stale once it has missed `MISSED_HEARTBEATS` in a row, so a daemon that
+//! crashed or lost the network drops out without saying goodbye.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::accounting::FulfillmentRecord;
+
+/// Heartbeats a solver may miss before it is stale
+pub const MISSED_HEARTBEATS: u64 = 3;
+
+/// `[heartbeat]` section of the agent config
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HeartbeatConfig {
+    /// Needs `NAISU_API_URL`
+    pub enabled: bool,
+    pub interval_secs: u64,
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 30,
+        }
+    }
+}
+
+impl HeartbeatConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.interval_secs == 0 {
+            return Err("interval_secs must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Whether a solver is taking intents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolverState {
+    Active,
+    /// Every in-flight slot is taken
+    Busy,
+    /// Kill switch engaged: evaluating only
+    Halted,
+}
+
+/// One solver's line in a heartbeat
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolverStatus {
+    pub name: String,
+    pub state: SolverState,
+    pub in_flight: usize,
+    /// Unix millis of the last real (not simulated) fulfillment
+    pub last_fulfillment_ms: Option<u64>,
+}
+
+/// What a daemon pipeline reports every interval
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heartbeat {
+    pub network: String,
+    pub wallet: Option<String>,
+    /// SUI balance of `wallet` (MIST), if it could be read
+    pub wallet_balance: Option<u64>,
+    /// Interval until the next heartbeat
+    pub interval_secs: u64,
+    pub solvers: Vec<SolverStatus>,
+}
+
+/// Whether a heartbeat received at `last_ms` has been missed too often by
+/// `now_ms`
+pub fn is_stale(last_ms: u64, interval_secs: u64, now_ms: u64) -> bool {
+    let grace = MISSED_HEARTBEATS.saturating_mul(interval_secs.saturating_mul(1000));
+    now_ms.saturating_sub(last_ms) > grace
+}
+
+/// Last real fulfillment per solver
+pub fn last_fulfillments(records: &[FulfillmentRecord]) -> HashMap<String, u64> {
+    let mut last = HashMap::new();
+    for record in records.iter().filter(|r| !r.simulated) {
+        let at = last.entry(record.solver.clone()).or_insert(0);
+        *at = record.timestamp_ms.max(*at);
+    }
+    last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(solver: &str, timestamp_ms: u64, simulated: bool) -> FulfillmentRecord {
+        FulfillmentRecord {
+            intent_id: format!("intent-{}", timestamp_ms),
+            solver: solver.to_string(),
+            protocol: "native_staking".to_string(),
+            digest: format!("0x{}", timestamp_ms),
+            timestamp_ms,
+            capital: 1_000_000_000,
+            gas_paid: 0,
+            offered_apy_bps: 800,
+            market_apy_bps: 850,
+            tip: 0,
+            realized_return: None,
+            simulated,
+            failed_attempts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_staleness_and_last_fulfillment() {
+        // Three missed 30s heartbeats
+        assert!(!is_stale(1_000, 30, 91_000));
+        assert!(is_stale(1_000, 30, 91_001));
+
+        let last = last_fulfillments(&[
+            record("StakingSolver", 5_000, false),
+            record("StakingSolver", 2_000, false),
+            record("StakingSolver", 9_000, true),
+            record("ScallopSolver", 7_000, true),
+        ]);
+        assert_eq!(last.get("StakingSolver"), Some(&5_000));
+        // Dry runs don't count as fulfillments
+        assert!(!last.contains_key("ScallopSolver"));
+    }
+}
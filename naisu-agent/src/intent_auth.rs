@@ -0,0 +1,386 @@
+//! Intent signatures
+//!
+//! Authenticates an intent to its source wallet before the API accepts it.
+//! EVM-originated intents (`EvmToSui`) are signed as EIP-712 typed data,
+//! Sui-originated ones (`SuiToEvm`) as a Sui personal message. Both cover
+//! every field of the creation request plus a client-chosen nonce.
+//!
+//! EIP-712 domain: `{ name: "Naisu", version: "1", chainId }` with the chain
+//! ID of the intent's EVM chain; the message type is `INTENT_TYPE`. The Sui
+//! message is the text built by `personal_message`.
+
+use k256::ecdsa::RecoveryId;
+use naisu_core::{CreateIntentRequest, Direction, IntentSignature, RiskTier, SignatureKind, Tip};
+use naisu_sui::{SignatureVerifier, VerifyError};
+use sha3::{Digest, Keccak256};
+
+use crate::executor::signer::{hex_decode, hex_encode};
+
+/// EIP-712 domain type
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+
+/// EIP-712 intent type
+pub const INTENT_TYPE: &str = "Intent(address source,bytes32 recipient,address inputToken,uint256 inputAmount,uint8 strategy,uint8 maxRisk,bool stableOnly,uint16 tipBps,uint256 tipAmount,string referrer,uint256 nonce)";
+
+/// Check a creation request's signature against its source wallet
+///
+/// Returns the signature to store with the intent. Sui signatures go
+/// through `verifier`, which needs a fullnode for zkLogin signers.
+pub async fn verify_intent(
+    req: &CreateIntentRequest,
+    verifier: &SignatureVerifier,
+) -> Result<IntentSignature, IntentAuthError> {
+    verify_intent_from(req, &req.source_address, verifier).await
+}
+
+/// Check a request's signature, as signed, against the wallet `signer`
+///
+/// For requests whose source is a name: the payload keeps the name the
+/// user signed, and `signer` is the address it resolves to.
+pub async fn verify_intent_from(
+    req: &CreateIntentRequest,
+    signer: &str,
+    verifier: &SignatureVerifier,
+) -> Result<IntentSignature, IntentAuthError> {
+    let sig = req.signature.as_ref().ok_or(IntentAuthError::Missing)?;
+
+    let expected = match req.direction {
+        Direction::EvmToSui => SignatureKind::Eip712,
+        Direction::SuiToEvm => SignatureKind::SuiPersonalMessage,
+    };
+    if sig.kind != expected {
+        return Err(IntentAuthError::WrongKind {
+            direction: req.direction,
+            expected,
+        });
+    }
+
+    match sig.kind {
+        SignatureKind::Eip712 => {
+            let recovered = recover_evm_address(&eip712_digest(req, sig.nonce)?, &sig.signature)?;
+            if !same_address(&recovered, signer) {
+                return Err(IntentAuthError::SignerMismatch(recovered));
+            }
+        }
+        SignatureKind::SuiPersonalMessage => {
+            verifier
+                .verify_personal_message(
+                    personal_message(req, sig.nonce).as_bytes(),
+                    &sig.signature,
+                    signer,
+                )
+                .await?;
+        }
+    }
+    Ok(sig.clone())
+}
+
+// ─── EIP-712 ─────────────────────────────────────────────────────────────────
+
+/// Typed-data digest an EVM wallet signs for the request
+pub fn eip712_digest(req: &CreateIntentRequest, nonce: u64) -> Result<[u8; 32], IntentAuthError> {
+    let domain = [
+        keccak256(EIP712_DOMAIN_TYPE.as_bytes()),
+        keccak256(b"Naisu"),
+        keccak256(b"1"),
+        uint256(req.evm_chain.chain_id() as u128),
+    ]
+    .concat();
+
+    let (tip_bps, tip_amount) = match req.tip {
+        Some(Tip::Bps(bps)) => (bps as u128, 0),
+        Some(Tip::Flat(amount)) => (0, amount as u128),
+        None => (0, 0),
+    };
+    let message = [
+        keccak256(INTENT_TYPE.as_bytes()),
+        hex_word(&req.source_address, 20, "source_address")?,
+        hex_word(&req.dest_address, 32, "dest_address")?,
+        hex_word(&req.input_token, 20, "input_token")?,
+        uint256(input_amount(req)?),
+        uint256(req.strategy.map_or(0, |s| s.id()) as u128),
+        uint256(max_risk_id(req.max_risk) as u128),
+        uint256(req.stable_only as u128),
+        uint256(tip_bps),
+        uint256(tip_amount),
+        keccak256(req.referrer.as_deref().unwrap_or_default().as_bytes()),
+        uint256(nonce as u128),
+    ]
+    .concat();
+
+    Ok(keccak256(
+        &[&[0x19, 0x01][..], &keccak256(&domain), &keccak256(&message)].concat(),
+    ))
+}
+
+/// EVM address that signed a digest (`r ‖ s ‖ v`, v as 0/1 or 27/28)
+pub fn recover_evm_address(digest: &[u8; 32], signature: &str) -> Result<String, IntentAuthError> {
+    let bytes = hex_decode(signature.strip_prefix("0x").unwrap_or(signature))
+        .filter(|b| b.len() == 65)
+        .ok_or_else(|| IntentAuthError::Malformed("signature must be 65 hex bytes".into()))?;
+
+    let v = bytes[64];
+    let recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })
+        .ok_or(IntentAuthError::InvalidSignature)?;
+    let signature = k256::ecdsa::Signature::from_slice(&bytes[..64])
+        .map_err(|_| IntentAuthError::InvalidSignature)?;
+    let key = k256::ecdsa::VerifyingKey::recover_from_prehash(digest, &signature, recovery_id)
+        .map_err(|_| IntentAuthError::InvalidSignature)?;
+
+    Ok(evm_address(&key))
+}
+
+/// EVM address of a secp256k1 key: last 20 bytes of `keccak256(x ‖ y)`
+pub fn evm_address(key: &k256::ecdsa::VerifyingKey) -> String {
+    let point = key.to_encoded_point(false);
+    format!("0x{}", hex_encode(&keccak256(&point.as_bytes()[1..])[12..]))
+}
+
+// ─── Sui personal message ────────────────────────────────────────────────────
+
+/// Text a Sui wallet signs for the request
+pub fn personal_message(req: &CreateIntentRequest, nonce: u64) -> String {
+    let strategy = req
+        .strategy
+        .map_or_else(|| "none".to_string(), |s| s.id().to_string());
+    let tip = match req.tip {
+        Some(Tip::Bps(bps)) => format!("{} bps", bps),
+        Some(Tip::Flat(amount)) => amount.to_string(),
+        None => "none".to_string(),
+    };
+    format!(
+        "Naisu intent\nDirection: {:?}\nFrom: {}\nTo: {}\nChain: {}\nToken: {}\nAmount: {}\nStrategy: {}\nMax risk: {}\nStable only: {}\nTip: {}\nReferrer: {}\nNonce: {}",
+        req.direction,
+        req.source_address,
+        req.dest_address,
+        req.evm_chain.chain_id(),
+        req.input_token,
+        req.input_amount,
+        strategy,
+        req.max_risk.map_or("any", |t| t.as_str()),
+        if req.stable_only { "yes" } else { "no" },
+        tip,
+        req.referrer.as_deref().unwrap_or("none"),
+        nonce,
+    )
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+/// `maxRisk` field: 0 = any, then low / medium / high
+fn max_risk_id(max_risk: Option<RiskTier>) -> u8 {
+    match max_risk {
+        None => 0,
+        Some(RiskTier::Low) => 1,
+        Some(RiskTier::Medium) => 2,
+        Some(RiskTier::High) => 3,
+    }
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn uint256(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// 0x-hex value of at most `len` bytes, left-padded to a 32-byte word
+fn hex_word(value: &str, len: usize, field: &str) -> Result<[u8; 32], IntentAuthError> {
+    let hex = value.strip_prefix("0x").unwrap_or(value);
+    let bytes = (!hex.is_empty() && hex.len() <= 2 * len)
+        .then(|| hex_decode(&format!("{:0>width$}", hex, width = 2 * len)))
+        .flatten()
+        .ok_or_else(|| {
+            IntentAuthError::Malformed(format!("{} is not a {}-byte hex value", field, len))
+        })?;
+
+    let mut word = [0u8; 32];
+    word[32 - len..].copy_from_slice(&bytes);
+    Ok(word)
+}
+
+fn input_amount(req: &CreateIntentRequest) -> Result<u128, IntentAuthError> {
+    req.input_amount
+        .parse()
+        .map_err(|_| IntentAuthError::Malformed("input_amount is not an integer".into()))
+}
+
+/// Compare hex addresses ignoring case and leading zeros
+fn same_address(a: &str, b: &str) -> bool {
+    let strip = |s: &str| {
+        s.strip_prefix("0x")
+            .unwrap_or(s)
+            .trim_start_matches('0')
+            .to_lowercase()
+    };
+    strip(a) == strip(b)
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum IntentAuthError {
+    #[error("Intent signature is required")]
+    Missing,
+
+    #[error("{direction:?} intents must be signed as {expected:?}")]
+    WrongKind {
+        direction: Direction,
+        expected: SignatureKind,
+    },
+
+    #[error("Malformed intent signature: {0}")]
+    Malformed(String),
+
+    #[error("Invalid intent signature")]
+    InvalidSignature,
+
+    #[error("Intent signed by {0}, not the source wallet")]
+    SignerMismatch(String),
+
+    #[error("Could not verify intent signature: {0}")]
+    Unavailable(String),
+}
+
+impl From<VerifyError> for IntentAuthError {
+    fn from(e: VerifyError) -> Self {
+        match e {
+            VerifyError::Malformed(_)
+            | VerifyError::UnsupportedScheme(_)
+            | VerifyError::ZkLogin(_) => IntentAuthError::Malformed(e.to_string()),
+            VerifyError::AddressMismatch { actual, .. } => IntentAuthError::SignerMismatch(actual),
+            VerifyError::ProofUnchecked | VerifyError::Client(_) => {
+                IntentAuthError::Unavailable(e.to_string())
+            }
+            VerifyError::InvalidSignature
+            | VerifyError::ThresholdNotMet { .. }
+            | VerifyError::ProofRejected(_) => IntentAuthError::InvalidSignature,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Signer as _;
+    use naisu_core::{EvmChain, YieldStrategy};
+    use naisu_sui::personal_message_digest;
+
+    use crate::executor::signer::{SignatureScheme, SuiPublicKey};
+
+    fn request(direction: Direction, source: &str) -> CreateIntentRequest {
+        CreateIntentRequest {
+            direction,
+            source_address: source.to_string(),
+            dest_address: "0x2".to_string(),
+            evm_chain: EvmChain::Base,
+            input_token: "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913".to_string(),
+            input_amount: "1000000".to_string(),
+            strategy: Some(YieldStrategy::ScallopUsdc),
+            referrer: None,
+            tip: Some(Tip::Bps(10)),
+            max_risk: None,
+            stable_only: false,
+            signature: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_eip712_signature() {
+        assert_eq!(
+            hex_encode(&keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        let verifier = SignatureVerifier::offline();
+        let key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let mut req = request(Direction::EvmToSui, &evm_address(key.verifying_key()));
+        let digest = eip712_digest(&req, 42).unwrap();
+        let (sig, recovery_id) = key.sign_prehash_recoverable(&digest).unwrap();
+        let mut bytes = sig.to_bytes().to_vec();
+        bytes.push(27 + recovery_id.to_byte());
+        req.signature = Some(IntentSignature {
+            kind: SignatureKind::Eip712,
+            signature: format!("0x{}", hex_encode(&bytes)),
+            nonce: 42,
+        });
+        assert!(verify_intent(&req, &verifier).await.is_ok());
+
+        // Any signed field changing invalidates it
+        req.tip = Some(Tip::Bps(500));
+        assert!(matches!(
+            verify_intent(&req, &verifier).await,
+            Err(IntentAuthError::SignerMismatch(_))
+        ));
+
+        req.direction = Direction::SuiToEvm;
+        assert!(matches!(
+            verify_intent(&req, &verifier).await,
+            Err(IntentAuthError::WrongKind { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sui_personal_message_signature() {
+        let verifier = SignatureVerifier::offline();
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let public_key = SuiPublicKey::new(
+            SignatureScheme::Ed25519,
+            key.verifying_key().to_bytes().to_vec(),
+        )
+        .unwrap();
+
+        let mut req = request(Direction::SuiToEvm, &public_key.address());
+        let digest = personal_message_digest(personal_message(&req, 1).as_bytes());
+        req.signature = Some(IntentSignature {
+            kind: SignatureKind::SuiPersonalMessage,
+            signature: public_key.serialize_signature(&key.sign(&digest).to_bytes()),
+            nonce: 1,
+        });
+        assert!(verify_intent(&req, &verifier).await.is_ok());
+
+        req.input_amount = "2000000".to_string();
+        assert_eq!(
+            verify_intent(&req, &verifier).await,
+            Err(IntentAuthError::InvalidSignature)
+        );
+
+        req.signature = None;
+        assert_eq!(
+            verify_intent(&req, &verifier).await,
+            Err(IntentAuthError::Missing)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_name_verifies_against_resolved_address() {
+        let verifier = SignatureVerifier::offline();
+        let key = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+        let public_key = SuiPublicKey::new(
+            SignatureScheme::Ed25519,
+            key.verifying_key().to_bytes().to_vec(),
+        )
+        .unwrap();
+
+        let mut req = request(Direction::SuiToEvm, "alice.sui");
+        req.dest_address = "vitalik.eth".to_string();
+        let digest = personal_message_digest(personal_message(&req, 3).as_bytes());
+        req.signature = Some(IntentSignature {
+            kind: SignatureKind::SuiPersonalMessage,
+            signature: public_key.serialize_signature(&key.sign(&digest).to_bytes()),
+            nonce: 3,
+        });
+        let address = public_key.address();
+        assert!(verify_intent_from(&req, &address, &verifier).await.is_ok());
+
+        // The resolved name must be the signer's
+        let other = format!("0x{}", "ab".repeat(32));
+        assert!(matches!(
+            verify_intent_from(&req, &other, &verifier).await,
+            Err(IntentAuthError::SignerMismatch(_))
+        ));
+    }
+}
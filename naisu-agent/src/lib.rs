@@ -17,13 +17,86 @@
 //! - Testnet: Native Staking, DeepBook
 //! - Mainnet: Cetus, Scallop, Navi, Native Staking, DeepBook
 
+pub mod accounting;
+pub mod analytics;
+pub mod apy_history;
+pub mod audit;
+pub mod availability;
+pub mod balance_monitor;
+pub mod batch;
 pub mod bots;
+pub mod bridge_latency;
+pub mod cctp_relay;
+#[cfg(any(test, feature = "chaos"))]
+pub mod chaos;
+pub mod commitment;
 pub mod config;
+pub mod ens;
+pub mod events;
+pub mod evm_hook;
 pub mod executor;
+pub mod feature_flags;
+pub mod fees;
+pub mod gas;
+pub mod heartbeat;
+pub mod intent_auth;
+pub mod metrics;
+pub mod notify;
+pub mod polling;
+pub mod pools;
+pub mod price;
+pub mod receipt;
+pub mod reconcile;
+pub mod referral;
+pub mod registry;
+pub mod reputation;
+pub mod risk;
+pub mod route_cost;
+pub mod selection;
 pub mod solver;
 pub mod solver_factory;
+pub mod solver_overrides;
+pub mod throttle;
+pub mod token_check;
+pub mod tracker;
+pub mod upgrade_watch;
+pub mod verify;
+pub mod watchdog;
 
-pub use config::{Network, Protocol, ProtocolConfig};
-pub use executor::{SuiCoin, SuiExecutor, TransactionResult};
+pub use accounting::{FulfillmentRecord, Granularity, Ledger, PnlReport};
+pub use analytics::{IntentAnalytics, SolverStanding, Window};
+pub use apy_history::{ApyHistory, Comparison};
+pub use audit::{AuditAction, AuditEntry, AuditFilter, AuditLog};
+pub use availability::{Availability, AvailabilityCache, AvailabilityProber, ProbeResult};
+pub use balance_monitor::{BalanceMonitor, BalanceMonitorConfig, RefillRecord};
+pub use batch::{BatchConfig, BatchLeg, BatchPlan};
+pub use commitment::{Commitment, CommitmentBook, CommitmentError, CommitmentStatus};
+pub use config::{AgentConfig, ConfigError, Network, Protocol, ProtocolConfig, SharedConfig};
+pub use ens::{EnsError, EnsResolver};
+pub use events::{DeadLetterQueue, EventError, EventParser, IntentEvent};
+pub use executor::gas_pool::{GasPool, GasPoolConfig, GasPoolRebalancer};
+pub use executor::{SuiCoin, SuiExecutor};
+pub use fees::{FeeConfig, FeeLedger, FeeSchedule};
+pub use gas::GasOracle;
+pub use heartbeat::{Heartbeat, HeartbeatConfig, SolverState, SolverStatus};
+pub use intent_auth::{verify_intent, IntentAuthError};
+pub use metrics::{MetricsSnapshot, NetworkMetrics};
+pub use notify::{Alert, AlertLevel, NotificationHub, Notifier};
+pub use polling::{PollSchedule, PollingConfig};
+pub use price::PriceOracle;
+pub use receipt::{FulfillmentReceipt, SignedReceipt};
+pub use reconcile::{
+    ChainIntent, ChainState, Drift, DriftKind, DriftSnapshot, LocalState, Reconciler,
+};
+pub use referral::{ReferralAccount, ReferralBook, ReferralError};
+pub use registry::{BoxedSolver, SolverDescriptor, SolverRegistry};
+pub use reputation::{Reputation, ReputationWeights, SolverHistory};
+pub use risk::{KillSwitch, RiskError, RiskLimits, RiskManager};
+pub use selection::{SelectionPolicy, SelectionStrategy};
 pub use solver::{Bid, Solver, SolverConfig};
 pub use solver_factory::{MultiNetworkSolver, SolverFactory};
+pub use throttle::{Throttle, ThrottleConfig};
+pub use tracker::{Claim, Closure, IntentTracker, OpenIntents};
+pub use upgrade_watch::{PackageUpgrade, UpgradeWatcher};
+pub use verify::{SettlementVerifier, Verification, VerificationStatus};
+pub use watchdog::{Remediation, StuckIntent, WatchdogConfig};
@@ -2,8 +2,7 @@
 //!
 //! Run multiple solver bots that compete to fulfill yield intents.
 
-use naisu_agent::bots::{NaviSolver, ScallopSolver};
-use naisu_agent::solver::Solver;
+use naisu_agent::bots;
 use tracing::info;
 
 #[tokio::main]
@@ -12,13 +11,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("🤖 Starting Naisu Solver Bots...");
 
-    // Initialize solvers
-    let scallop = ScallopSolver::new();
-    let navi = NaviSolver::new();
-
+    // Solvers compiled into this build
     info!("✅ Loaded solvers:");
-    info!("   - {}", scallop.name());
-    info!("   - {}", navi.name());
+    for descriptor in bots::builtin() {
+        info!("   - {}", descriptor.name);
+    }
 
     // TODO: Start solver event loops
     // 1. Poll for new YieldIntent shared objects
@@ -0,0 +1,167 @@
+//! Per-network pipeline metrics
+//!
+//! Lock-free counters shared between a network's poll loop and its
+//! fulfillment tasks. Each network pipeline owns one `NetworkMetrics`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+
+use crate::config::Network;
+
+/// Counters for one network pipeline
+#[derive(Debug)]
+pub struct NetworkMetrics {
+    network: Network,
+    polls: AtomicU64,
+    poll_errors: AtomicU64,
+    intents_seen: AtomicU64,
+    no_bids: AtomicU64,
+    fulfillments: AtomicU64,
+    simulated: AtomicU64,
+    failures: AtomicU64,
+    blocked: AtomicU64,
+    preempted: AtomicU64,
+}
+
+/// Point-in-time copy of `NetworkMetrics`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MetricsSnapshot {
+    pub network: String,
+    pub polls: u64,
+    pub poll_errors: u64,
+    pub intents_seen: u64,
+    /// Intents no solver bid on
+    pub no_bids: u64,
+    pub fulfillments: u64,
+    /// Dry-run fulfillments (`--simulate`), not counted in `fulfillments`
+    pub simulated: u64,
+    pub failures: u64,
+    /// Fulfillments stopped by risk controls or the kill switch
+    pub blocked: u64,
+    /// Intents closed on chain while this pipeline was still working on them
+    pub preempted: u64,
+}
+
+impl NetworkMetrics {
+    pub fn new(network: Network) -> Self {
+        Self {
+            network,
+            polls: AtomicU64::new(0),
+            poll_errors: AtomicU64::new(0),
+            intents_seen: AtomicU64::new(0),
+            no_bids: AtomicU64::new(0),
+            fulfillments: AtomicU64::new(0),
+            simulated: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            blocked: AtomicU64::new(0),
+            preempted: AtomicU64::new(0),
+        }
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    pub fn record_poll(&self, ok: bool) {
+        self.polls.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            self.poll_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_intents(&self, count: usize) {
+        self.intents_seen.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_no_bid(&self) {
+        self.no_bids.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_fulfillment(&self) {
+        self.fulfillments.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_simulated(&self) {
+        self.simulated.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_failure(&self) {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_blocked(&self) {
+        self.blocked.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_preempted(&self) {
+        self.preempted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let load = |c: &AtomicU64| c.load(Ordering::Relaxed);
+        MetricsSnapshot {
+            network: self.network.name().to_string(),
+            polls: load(&self.polls),
+            poll_errors: load(&self.poll_errors),
+            intents_seen: load(&self.intents_seen),
+            no_bids: load(&self.no_bids),
+            fulfillments: load(&self.fulfillments),
+            simulated: load(&self.simulated),
+            failures: load(&self.failures),
+            blocked: load(&self.blocked),
+            preempted: load(&self.preempted),
+        }
+    }
+}
+
+impl std::fmt::Display for MetricsSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] polls {} ({} errors), intents {}, fulfilled {}, simulated {}, failed {}, blocked {}, preempted {}, no bid {}",
+            self.network,
+            self.polls,
+            self.poll_errors,
+            self.intents_seen,
+            self.fulfillments,
+            self.simulated,
+            self.failures,
+            self.blocked,
+            self.preempted,
+            self.no_bids
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_are_per_network() {
+        let testnet = NetworkMetrics::new(Network::Testnet);
+        let mainnet = NetworkMetrics::new(Network::Mainnet);
+
+        testnet.record_poll(true);
+        testnet.record_poll(false);
+        testnet.record_intents(3);
+        testnet.record_fulfillment();
+        testnet.record_simulated();
+        mainnet.record_blocked();
+        mainnet.record_preempted();
+
+        let t = testnet.snapshot();
+        assert_eq!(t.network, "testnet");
+        assert_eq!((t.polls, t.poll_errors), (2, 1));
+        assert_eq!(t.intents_seen, 3);
+        assert_eq!(t.fulfillments, 1);
+        assert_eq!(t.simulated, 1);
+        assert_eq!(t.blocked, 0);
+
+        let m = mainnet.snapshot();
+        assert_eq!(m.blocked, 1);
+        assert_eq!(m.preempted, 1);
+        assert_eq!(m.polls, 0);
+    }
+}
@@ -0,0 +1,138 @@
+//! Operator notifications
+//!
+//! Alerts are always logged; a webhook (Slack/Discord compatible) can be
+//! added with `ALERT_WEBHOOK_URL`.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+/// Alert severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertLevel {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl AlertLevel {
+    fn emoji(&self) -> &'static str {
+        match self {
+            AlertLevel::Info => "ℹ️",
+            AlertLevel::Warning => "⚠️",
+            AlertLevel::Critical => "🚨",
+        }
+    }
+}
+
+/// A notification for operators
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub level: AlertLevel,
+    pub title: String,
+    pub message: String,
+}
+
+impl Alert {
+    pub fn new(level: AlertLevel, title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            title: title.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Single-line rendering used by log and chat notifiers
+    pub fn text(&self) -> String {
+        format!("{} {}: {}", self.level.emoji(), self.title, self.message)
+    }
+}
+
+/// Delivery channel for alerts
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, alert: &Alert);
+}
+
+/// Writes alerts to the tracing log
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, alert: &Alert) {
+        match alert.level {
+            AlertLevel::Info => info!("{}", alert.text()),
+            AlertLevel::Warning => warn!("{}", alert.text()),
+            AlertLevel::Critical => error!("{}", alert.text()),
+        }
+    }
+}
+
+/// Posts alerts to a chat webhook
+///
+/// Sends both `text` (Slack) and `content` (Discord) so either accepts it.
+pub struct WebhookNotifier {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, alert: &Alert) {
+        let text = alert.text();
+        let body = serde_json::json!({ "text": text, "content": text });
+        if let Err(e) = self.client.post(&self.url).json(&body).send().await {
+            warn!("Failed to deliver webhook alert: {}", e);
+        }
+    }
+}
+
+/// Fans alerts out to every configured notifier
+pub struct NotificationHub {
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl NotificationHub {
+    /// Log-only hub
+    pub fn new() -> Self {
+        Self {
+            notifiers: vec![Box::new(LogNotifier)],
+        }
+    }
+
+    /// Log plus a webhook when `ALERT_WEBHOOK_URL` is set
+    pub fn from_env() -> Self {
+        let hub = Self::new();
+        match std::env::var("ALERT_WEBHOOK_URL") {
+            Ok(url) if !url.is_empty() => hub.with(Box::new(WebhookNotifier::new(&url))),
+            _ => hub,
+        }
+    }
+
+    pub fn with(mut self, notifier: Box<dyn Notifier>) -> Self {
+        self.notifiers.push(notifier);
+        self
+    }
+
+    pub async fn send(&self, alert: Alert) {
+        for notifier in &self.notifiers {
+            notifier.notify(&alert).await;
+        }
+    }
+}
+
+impl Default for NotificationHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
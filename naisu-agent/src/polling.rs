@@ -0,0 +1,295 @@
+//! Daemon polling schedule
+//!
+//! Controls how often the daemon polls for intents:
+//! - base interval with random jitter (avoids synchronized solvers)
+//! - exponential backoff while RPC calls keep failing
+//! - a short burst of fast polls right after a fulfillment, when follow-up
+//!   intents are most likely
+//!
+//! Settings layer as defaults → TOML `[polling]` table → env → CLI flags.
+
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Polling settings
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct PollingConfig {
+    /// Normal delay between polls
+    pub interval_secs: u64,
+    /// Random jitter applied to each delay, as a percentage (0-100)
+    pub jitter_pct: u8,
+    /// Upper bound for the backoff delay after RPC errors
+    pub max_backoff_secs: u64,
+    /// Delay between polls right after a fulfillment
+    pub fast_interval_secs: u64,
+    /// Number of fast polls after each fulfillment
+    pub fast_poll_count: u32,
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 10,
+            jitter_pct: 10,
+            max_backoff_secs: 300,
+            fast_interval_secs: 2,
+            fast_poll_count: 3,
+        }
+    }
+}
+
+impl PollingConfig {
+    /// Read the `[polling]` table of a TOML file (other tables are ignored)
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::from_toml_str(&raw).with_context(|| format!("Invalid config in {}", path.display()))
+    }
+
+    pub fn from_toml_str(raw: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct File {
+            #[serde(default)]
+            polling: PollingConfig,
+        }
+
+        Ok(toml::from_str::<File>(raw)?.polling)
+    }
+
+    /// Override with `POLL_INTERVAL_SECS`, `POLL_JITTER_PCT`,
+    /// `POLL_MAX_BACKOFF_SECS`, `FAST_POLL_INTERVAL_SECS`, `FAST_POLL_COUNT`
+    pub fn with_env(mut self) -> Self {
+        fn var<T: std::str::FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok().and_then(|v| v.parse().ok())
+        }
+
+        if let Some(v) = var("POLL_INTERVAL_SECS") {
+            self.interval_secs = v;
+        }
+        if let Some(v) = var("POLL_JITTER_PCT") {
+            self.jitter_pct = v;
+        }
+        if let Some(v) = var("POLL_MAX_BACKOFF_SECS") {
+            self.max_backoff_secs = v;
+        }
+        if let Some(v) = var("FAST_POLL_INTERVAL_SECS") {
+            self.fast_interval_secs = v;
+        }
+        if let Some(v) = var("FAST_POLL_COUNT") {
+            self.fast_poll_count = v;
+        }
+        self
+    }
+
+    /// Override with `--poll-interval`, `--poll-jitter`, `--max-backoff`,
+    /// `--fast-poll-interval`, `--fast-poll-count`
+    pub fn with_args(mut self, args: &[String]) -> Self {
+        fn flag<T: std::str::FromStr>(args: &[String], name: &str) -> Option<T> {
+            args.iter()
+                .position(|a| a == name)
+                .and_then(|i| args.get(i + 1))
+                .and_then(|v| v.parse().ok())
+        }
+
+        if let Some(v) = flag(args, "--poll-interval") {
+            self.interval_secs = v;
+        }
+        if let Some(v) = flag(args, "--poll-jitter") {
+            self.jitter_pct = v;
+        }
+        if let Some(v) = flag(args, "--max-backoff") {
+            self.max_backoff_secs = v;
+        }
+        if let Some(v) = flag(args, "--fast-poll-interval") {
+            self.fast_interval_secs = v;
+        }
+        if let Some(v) = flag(args, "--fast-poll-count") {
+            self.fast_poll_count = v;
+        }
+        self
+    }
+
+    /// Reject settings that would spin or never poll
+    pub fn validate(&self) -> Result<()> {
+        if self.interval_secs == 0 {
+            anyhow::bail!("poll interval must be at least 1 second");
+        }
+        if self.jitter_pct > 100 {
+            anyhow::bail!("poll jitter must be between 0 and 100 percent");
+        }
+        if self.max_backoff_secs < self.interval_secs {
+            anyhow::bail!(
+                "max backoff ({}s) must not be below the poll interval ({}s)",
+                self.max_backoff_secs,
+                self.interval_secs
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Computes the delay before each poll
+#[derive(Debug, Clone)]
+pub struct PollSchedule {
+    config: PollingConfig,
+    consecutive_errors: u32,
+    fast_polls_left: u32,
+}
+
+impl PollSchedule {
+    pub fn new(config: PollingConfig) -> Self {
+        Self {
+            config,
+            consecutive_errors: 0,
+            fast_polls_left: 0,
+        }
+    }
+
+    pub fn config(&self) -> &PollingConfig {
+        &self.config
+    }
+
+    pub fn on_success(&mut self) {
+        self.consecutive_errors = 0;
+    }
+
+    pub fn on_error(&mut self) {
+        self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+    }
+
+    /// Switch to fast polling for the next few polls
+    pub fn on_fulfillment(&mut self) {
+        self.fast_polls_left = self.config.fast_poll_count;
+    }
+
+    pub fn is_backing_off(&self) -> bool {
+        self.consecutive_errors > 0
+    }
+
+    /// Delay before the next poll, without jitter
+    pub fn base_delay(&self) -> Duration {
+        let interval = Duration::from_secs(self.config.interval_secs);
+
+        if self.consecutive_errors > 0 {
+            let factor = 2u32.saturating_pow(self.consecutive_errors.min(16));
+            return interval
+                .saturating_mul(factor)
+                .min(Duration::from_secs(self.config.max_backoff_secs));
+        }
+
+        if self.fast_polls_left > 0 {
+            return Duration::from_secs(self.config.fast_interval_secs);
+        }
+
+        interval
+    }
+
+    /// Delay before the next poll (consumes one fast poll if any are left)
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.base_delay();
+        if self.consecutive_errors == 0 && self.fast_polls_left > 0 {
+            self.fast_polls_left -= 1;
+        }
+        apply_jitter(delay, self.config.jitter_pct)
+    }
+}
+
+/// Spread `delay` by up to ±`pct` percent
+fn apply_jitter(delay: Duration, pct: u8) -> Duration {
+    let spread_ms = delay.as_millis() as u64 * pct.min(100) as u64 / 100;
+    if spread_ms == 0 {
+        return delay;
+    }
+
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+    let offset = seed % (2 * spread_ms + 1);
+
+    (delay + Duration::from_millis(offset)).saturating_sub(Duration::from_millis(spread_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_jitter() -> PollingConfig {
+        PollingConfig {
+            jitter_pct: 0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let mut schedule = PollSchedule::new(no_jitter());
+        assert_eq!(schedule.next_delay(), Duration::from_secs(10));
+
+        schedule.on_error();
+        assert_eq!(schedule.next_delay(), Duration::from_secs(20));
+        schedule.on_error();
+        assert_eq!(schedule.next_delay(), Duration::from_secs(40));
+
+        for _ in 0..10 {
+            schedule.on_error();
+        }
+        assert_eq!(schedule.next_delay(), Duration::from_secs(300));
+
+        schedule.on_success();
+        assert_eq!(schedule.next_delay(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_fast_polls_after_fulfillment() {
+        let mut schedule = PollSchedule::new(no_jitter());
+        schedule.on_fulfillment();
+
+        for _ in 0..3 {
+            assert_eq!(schedule.next_delay(), Duration::from_secs(2));
+        }
+        assert_eq!(schedule.next_delay(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_jitter_stays_in_range() {
+        for _ in 0..50 {
+            let d = apply_jitter(Duration::from_secs(10), 10);
+            assert!(d >= Duration::from_secs(9) && d <= Duration::from_secs(11));
+        }
+    }
+
+    #[test]
+    fn test_layering_toml_then_args() {
+        let config = PollingConfig::from_toml_str(
+            r#"
+            [polling]
+            interval_secs = 30
+            fast_poll_count = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.interval_secs, 30);
+        assert_eq!(config.fast_poll_count, 5);
+        assert_eq!(config.jitter_pct, 10);
+
+        let args: Vec<String> = ["--poll-interval", "15"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let config = config.with_args(&args);
+        assert_eq!(config.interval_secs, 15);
+        assert!(config.validate().is_ok());
+
+        let invalid = PollingConfig {
+            interval_secs: 0,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
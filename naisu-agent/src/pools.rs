@@ -0,0 +1,351 @@
+//! Cetus pool registry
+//!
+//! Lists Cetus CLMM pools with their pair, fee tier, liquidity, price and
+//! an estimated fee APR. Pools are discovered from the factory's
+//! `CreatePoolEvent`s and read from their objects; results are cached.
+//!
+//! The fee APR comes from the pool's global fee growth: the fees earned per
+//! unit of liquidity since a baseline snapshot, times current liquidity,
+//! annualized and divided by TVL. It is `None` until a pool has been seen
+//! twice.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use naisu_sui::{SuiClient, SuiClientError, SuiObject};
+use serde::Serialize;
+use tracing::warn;
+
+use crate::config::{Network, Protocol, SharedConfig};
+
+/// Default cap on pools read per refresh
+pub const DEFAULT_MAX_POOLS: usize = 50;
+
+/// Age at which the fee growth baseline is replaced
+const APR_WINDOW: Duration = Duration::from_secs(3600);
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 3600.0;
+
+/// 2^64, the Q64.64 fixed-point scale
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+
+/// On-chain state of one pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolState {
+    pub pool_id: String,
+    pub coin_type_a: String,
+    pub coin_type_b: String,
+    /// Parts per million
+    pub fee_rate: u64,
+    pub liquidity: u128,
+    /// Q64.64
+    pub sqrt_price: u128,
+    pub balance_a: u64,
+    pub balance_b: u64,
+    /// Q64.64 fees per unit of liquidity, wrapping
+    pub fee_growth_a: u128,
+    pub fee_growth_b: u128,
+    pub paused: bool,
+}
+
+/// Move numbers come back as strings, numbers, or `{ value }` for balances
+fn number<T: std::str::FromStr>(value: &serde_json::Value) -> Option<T> {
+    let value = value.get("value").unwrap_or(value);
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Number(n) => n.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Split `0xpkg::pool::Pool<A, B>` into `(A, B)`
+fn pool_coin_types(repr: &str) -> Option<(String, String)> {
+    let inner = repr.split_once('<')?.1.strip_suffix('>')?;
+    let mut depth = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                return Some((
+                    inner[..i].trim().to_string(),
+                    inner[i + 1..].trim().to_string(),
+                ))
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Last path segment of a coin type (`0x2::sui::SUI` → `SUI`)
+fn symbol(coin_type: &str) -> &str {
+    coin_type.rsplit("::").next().unwrap_or(coin_type)
+}
+
+impl PoolState {
+    pub fn from_object(object: &SuiObject) -> Option<Self> {
+        let content = object.content.as_ref()?;
+        let repr = object
+            .r#type
+            .as_deref()
+            .or_else(|| content["type"].as_str())?;
+        let (coin_type_a, coin_type_b) = pool_coin_types(repr)?;
+        let fields = &content["fields"];
+
+        Some(Self {
+            pool_id: object.object_id.clone(),
+            coin_type_a,
+            coin_type_b,
+            fee_rate: number(&fields["fee_rate"])?,
+            liquidity: number(&fields["liquidity"])?,
+            sqrt_price: number(&fields["current_sqrt_price"])?,
+            balance_a: number(&fields["coin_a"]).unwrap_or(0),
+            balance_b: number(&fields["coin_b"]).unwrap_or(0),
+            fee_growth_a: number(&fields["fee_growth_global_a"]).unwrap_or(0),
+            fee_growth_b: number(&fields["fee_growth_global_b"]).unwrap_or(0),
+            paused: fields["is_pause"].as_bool().unwrap_or(false),
+        })
+    }
+
+    /// Coin B per coin A, in base units
+    pub fn price(&self) -> f64 {
+        let sqrt = self.sqrt_price as f64 / Q64;
+        sqrt * sqrt
+    }
+
+    /// Reserves valued in coin B base units
+    pub fn tvl(&self) -> f64 {
+        self.balance_a as f64 * self.price() + self.balance_b as f64
+    }
+
+    /// Annualized fees since `earlier`, over TVL
+    pub fn fee_apr_since(&self, earlier: &PoolState, elapsed: Duration) -> Option<f64> {
+        let tvl = self.tvl();
+        if elapsed.is_zero() || tvl <= 0.0 {
+            return None;
+        }
+        let growth_a = self.fee_growth_a.wrapping_sub(earlier.fee_growth_a) as f64 / Q64;
+        let growth_b = self.fee_growth_b.wrapping_sub(earlier.fee_growth_b) as f64 / Q64;
+        let fees = (growth_a * self.price() + growth_b) * self.liquidity as f64;
+        Some(fees / tvl * SECONDS_PER_YEAR / elapsed.as_secs_f64())
+    }
+}
+
+/// Pool as listed by the API
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolInfo {
+    pub pool_id: String,
+    pub protocol: &'static str,
+    /// e.g. `USDC/SUI`
+    pub pair: String,
+    pub coin_type_a: String,
+    pub coin_type_b: String,
+    pub fee_rate_bps: u64,
+    /// u128, as a string
+    pub liquidity: String,
+    /// Coin B per coin A, in base units
+    pub price: f64,
+    /// In coin B base units
+    pub tvl: f64,
+    /// Fraction per year (0.12 = 12%)
+    pub fee_apr: Option<f64>,
+}
+
+impl PoolInfo {
+    fn new(state: &PoolState, fee_apr: Option<f64>) -> Self {
+        Self {
+            pool_id: state.pool_id.clone(),
+            protocol: Protocol::Cetus.name(),
+            pair: format!(
+                "{}/{}",
+                symbol(&state.coin_type_a),
+                symbol(&state.coin_type_b)
+            ),
+            coin_type_a: state.coin_type_a.clone(),
+            coin_type_b: state.coin_type_b.clone(),
+            fee_rate_bps: state.fee_rate / 100,
+            liquidity: state.liquidity.to_string(),
+            price: state.price(),
+            tvl: state.tvl(),
+            fee_apr,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PoolCache {
+    pools: Vec<PoolInfo>,
+    refreshed: Option<Instant>,
+    /// Fee growth baseline per pool
+    baselines: HashMap<String, (PoolState, Instant)>,
+}
+
+/// Cached Cetus pools of one network
+pub struct PoolRegistry {
+    network: Network,
+    client: SuiClient,
+    config: SharedConfig,
+    max_pools: usize,
+    cache: RwLock<PoolCache>,
+}
+
+impl PoolRegistry {
+    pub fn new(network: Network, client: SuiClient, config: SharedConfig) -> Self {
+        Self {
+            network,
+            client,
+            config,
+            max_pools: DEFAULT_MAX_POOLS,
+            cache: RwLock::new(PoolCache::default()),
+        }
+    }
+
+    pub fn with_max_pools(mut self, max_pools: usize) -> Self {
+        self.max_pools = max_pools;
+        self
+    }
+
+    /// Pool IDs from the factory's creation events
+    async fn discover(&self) -> Result<Vec<String>, SuiClientError> {
+        let Some(config) = self
+            .config
+            .get()
+            .protocol_config(Protocol::Cetus, self.network)
+        else {
+            return Ok(vec![]);
+        };
+        let event_type = format!("{}::factory::CreatePoolEvent", config.package_id);
+
+        let mut ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        while ids.len() < self.max_pools {
+            let page = self
+                .client
+                .query_events(&event_type, cursor.as_deref(), self.max_pools - ids.len())
+                .await?;
+            ids.extend(
+                page.data
+                    .iter()
+                    .filter_map(|e| e.parsed_json["pool_id"].as_str().map(|s| s.to_string())),
+            );
+            cursor = page.next_cursor;
+            if !page.has_next_page || cursor.is_none() {
+                break;
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Read every pool and rebuild the cache
+    pub async fn refresh(&self) -> Result<Vec<PoolInfo>, SuiClientError> {
+        let mut states = Vec::new();
+        for id in self.discover().await? {
+            match self.client.get_object(&id).await {
+                Ok(object) => match PoolState::from_object(&object) {
+                    Some(state) if !state.paused => states.push(state),
+                    Some(_) => {}
+                    None => warn!("⚠️  Unreadable Cetus pool {}", id),
+                },
+                Err(e) => warn!("⚠️  Failed to read Cetus pool {}: {}", id, e),
+            }
+        }
+
+        let now = Instant::now();
+        let mut cache = self.cache.write().expect("pool cache lock poisoned");
+        let mut pools = Vec::new();
+        for state in states {
+            let fee_apr = cache
+                .baselines
+                .get(&state.pool_id)
+                .and_then(|(earlier, at)| state.fee_apr_since(earlier, now - *at));
+            pools.push(PoolInfo::new(&state, fee_apr));
+
+            let stale = cache
+                .baselines
+                .get(&state.pool_id)
+                .is_none_or(|(_, at)| now - *at >= APR_WINDOW);
+            if stale {
+                cache.baselines.insert(state.pool_id.clone(), (state, now));
+            }
+        }
+        pools.sort_by(|a, b| b.tvl.total_cmp(&a.tvl));
+
+        cache.pools = pools.clone();
+        cache.refreshed = Some(now);
+        Ok(pools)
+    }
+
+    /// Cached pools, refreshed if older than `ttl`
+    ///
+    /// A failed refresh serves the previous list if there is one.
+    pub async fn pools(&self, ttl: Duration) -> Result<Vec<PoolInfo>, SuiClientError> {
+        let cached = {
+            let cache = self.cache.read().expect("pool cache lock poisoned");
+            let fresh = cache.refreshed.is_some_and(|at| at.elapsed() < ttl);
+            (fresh, cache.refreshed.is_some(), cache.pools.clone())
+        };
+        match cached {
+            (true, _, pools) => Ok(pools),
+            (false, had_pools, pools) => match self.refresh().await {
+                Ok(pools) => Ok(pools),
+                Err(e) if had_pools => {
+                    warn!("⚠️  Serving stale Cetus pools: {}", e);
+                    Ok(pools)
+                }
+                Err(e) => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_object(fee_growth_a: u128) -> SuiObject {
+        SuiObject {
+            object_id: "0xpool".to_string(),
+            version: "1".to_string(),
+            digest: "d".to_string(),
+            r#type: Some("0xcetus::pool::Pool<0xa::usdc::USDC, 0x2::sui::SUI>".to_string()),
+            owner: None,
+            content: Some(serde_json::json!({
+                "dataType": "moveObject",
+                "fields": {
+                    "fee_rate": "2500",
+                    "liquidity": "1000000",
+                    // price 4: sqrt 2 in Q64.64
+                    "current_sqrt_price": (2u128 << 64).to_string(),
+                    "coin_a": "1000",
+                    "coin_b": { "value": "6000" },
+                    "fee_growth_global_a": fee_growth_a.to_string(),
+                    "fee_growth_global_b": "0",
+                    "is_pause": false
+                }
+            })),
+        }
+    }
+
+    #[test]
+    fn test_pool_state_and_apr() {
+        let earlier = PoolState::from_object(&pool_object(0)).unwrap();
+        assert_eq!(earlier.coin_type_a, "0xa::usdc::USDC");
+        assert_eq!(earlier.coin_type_b, "0x2::sui::SUI");
+        assert_eq!(earlier.price(), 4.0);
+        assert_eq!(earlier.tvl(), 10_000.0);
+
+        // 2^-20 of coin A in fees per unit of liquidity, 1 A = 4 B
+        let later = PoolState::from_object(&pool_object(1u128 << 44)).unwrap();
+        let day = Duration::from_secs(24 * 3600);
+        let apr = later.fee_apr_since(&earlier, day).unwrap();
+        let expected = 1_000_000.0 / (1u64 << 20) as f64 * 4.0 / 10_000.0 * 365.0;
+        assert!((apr - expected).abs() < 1e-9);
+        assert!(later.fee_apr_since(&earlier, Duration::ZERO).is_none());
+
+        let info = PoolInfo::new(&later, Some(apr));
+        assert_eq!(info.pair, "USDC/SUI");
+        assert_eq!(info.fee_rate_bps, 25);
+    }
+}
@@ -0,0 +1,129 @@
+//! USD price oracle
+//!
+//! Stablecoins are priced at $1. SUI is priced at the mid of the DeepBook
+//! SUI/USDC book, cached for a TTL; when a refresh fails the last price is
+//! served instead.
+
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use naisu_core::{is_stablecoin, to_decimal};
+use naisu_sui::adapters::deepbook::AdapterError;
+use naisu_sui::adapters::{DeepBookAdapter, SUI_USDC_POOL};
+use tracing::warn;
+
+/// How long a SUI price is served before re-reading the book
+pub const DEFAULT_PRICE_TTL: Duration = Duration::from_secs(30);
+
+/// Levels read per side; the mid only needs the top of book
+const BOOK_DEPTH: usize = 1;
+
+/// Base-unit decimals of a coin symbol or type
+pub fn decimals(coin: &str) -> Option<u32> {
+    let symbol = coin.rsplit("::").next().unwrap_or(coin);
+    if symbol.eq_ignore_ascii_case("SUI") {
+        Some(9)
+    } else if is_stablecoin(symbol) {
+        Some(6)
+    } else {
+        None
+    }
+}
+
+/// USD prices of the coins intents move
+pub struct PriceOracle {
+    book: DeepBookAdapter,
+    ttl: Duration,
+    /// Last SUI price and when it was read
+    sui: RwLock<Option<(f64, Instant)>>,
+}
+
+impl PriceOracle {
+    pub fn new(book: DeepBookAdapter) -> Self {
+        Self {
+            book,
+            ttl: DEFAULT_PRICE_TTL,
+            sui: RwLock::new(None),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Use `price` as the SUI price until the TTL runs out
+    pub fn set_sui_price(&self, price: f64) {
+        *self.sui.write().expect("price lock poisoned") = Some((price, Instant::now()));
+    }
+
+    /// USD per SUI
+    pub async fn sui_usd(&self) -> Result<f64, AdapterError> {
+        let cached = *self.sui.read().expect("price lock poisoned");
+        if let Some((price, _)) = cached.filter(|(_, at)| at.elapsed() < self.ttl) {
+            return Ok(price);
+        }
+
+        let mid = self
+            .book
+            .get_order_book(SUI_USDC_POOL, BOOK_DEPTH)
+            .await
+            .and_then(|book| {
+                book.mid_price()
+                    .ok_or_else(|| AdapterError::EmptyBook(SUI_USDC_POOL.to_string()))
+            });
+        match (mid, cached) {
+            (Ok(price), _) => {
+                self.set_sui_price(price);
+                Ok(price)
+            }
+            (Err(e), Some((price, _))) => {
+                warn!("⚠️  Serving stale SUI price: {}", e);
+                Ok(price)
+            }
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    /// USD price of one whole coin (`None` for coins it can't price)
+    pub async fn usd_price(&self, coin: &str) -> Option<f64> {
+        let symbol = coin.rsplit("::").next().unwrap_or(coin);
+        if is_stablecoin(symbol) {
+            return Some(1.0);
+        }
+        if !symbol.eq_ignore_ascii_case("SUI") {
+            return None;
+        }
+        match self.sui_usd().await {
+            Ok(price) => Some(price),
+            Err(e) => {
+                warn!("⚠️  No SUI price: {}", e);
+                None
+            }
+        }
+    }
+
+    /// USD value of `amount` base units of `coin`
+    pub async fn usd_value(&self, coin: &str, amount: u64) -> Option<f64> {
+        Some(to_decimal(amount, decimals(coin)?) * self.usd_price(coin).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_usd_value() {
+        // Unreachable indexer: only the cached price can answer
+        let oracle = PriceOracle::new(DeepBookAdapter::with_base_url(
+            "http://127.0.0.1:9".to_string(),
+        ));
+        assert_eq!(oracle.usd_value("USDC", 2_500_000).await, Some(2.5));
+        assert_eq!(oracle.usd_value("0x2::sui::SUI", 1).await, None);
+
+        oracle.set_sui_price(1.5);
+        assert_eq!(oracle.usd_value("SUI", 2_000_000_000).await, Some(3.0));
+        assert_eq!(oracle.usd_value("CETUS", 1_000).await, None);
+    }
+}
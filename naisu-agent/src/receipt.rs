@@ -0,0 +1,192 @@
+//! Fulfillment receipts
+//!
+//! Once settlement verification confirms a fulfillment, the API issues a
+//! receipt: what the user got, from whom, at what APY and cost. Receipts
+//! are signed as a Sui personal message over their JSON, so a user can
+//! keep one as a record and anyone can check it with standard Sui tooling.
+
+use naisu_core::{FulfilledObject, FulfillmentReport};
+use naisu_sui::{verify_personal_message, VerifyError};
+use serde::{Deserialize, Serialize};
+
+use crate::executor::signer::{LocalSigner, Signer};
+use crate::verify::{Verification, VerificationStatus};
+
+/// Record of a verified fulfillment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulfillmentReceipt {
+    pub intent_id: String,
+    pub solver: String,
+    pub protocol: String,
+    /// Fulfillment transaction digest
+    pub digest: String,
+    pub user: String,
+    /// Intent amount (MIST)
+    pub amount: u64,
+    /// Objects transferred to the user
+    pub objects: Vec<FulfilledObject>,
+    /// APY delivered (basis points)
+    pub realized_apy_bps: u64,
+    /// Protocol fee (MIST)
+    pub fee: u64,
+    /// Net gas the solver paid (MIST), when reported
+    pub gas_used: Option<i64>,
+    /// Unix millis
+    pub verified_at: u64,
+    /// The intent package's `YieldReceipt`, when it issued one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intent_receipt: Option<String>,
+}
+
+impl FulfillmentReceipt {
+    /// Receipt for a report, if its verification succeeded
+    pub fn issue(
+        intent_id: &str,
+        report: &FulfillmentReport,
+        verification: &Verification,
+    ) -> Option<Self> {
+        if verification.status != VerificationStatus::Verified {
+            return None;
+        }
+        // Prefer what the intent package recorded over the solver's claim
+        let fulfilled = verification.fulfilled.as_ref();
+        Some(Self {
+            intent_id: intent_id.to_string(),
+            solver: report.solver.clone(),
+            protocol: report.protocol.clone(),
+            digest: report.digest.clone(),
+            user: match fulfilled {
+                Some(f) => f.user.clone(),
+                None => report.user.clone()?,
+            },
+            amount: match fulfilled {
+                Some(f) => f.amount,
+                None => report.amount?,
+            },
+            objects: report.created_objects.clone(),
+            realized_apy_bps: fulfilled.map_or(report.realized_apy_bps, |f| f.apy),
+            fee: report.fee.unwrap_or(0),
+            gas_used: report.gas_used,
+            verified_at: verification.checked_at,
+            intent_receipt: fulfilled.map(|f| f.receipt_id.clone()),
+        })
+    }
+
+    /// Bytes the signature covers
+    pub fn message(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("receipts always serialize")
+    }
+
+    /// Sign as a personal message
+    pub fn sign(self, signer: &LocalSigner) -> SignedReceipt {
+        let signature = signer.sign_personal_message(&self.message());
+        SignedReceipt {
+            receipt: self,
+            signer: Some(signer.address()),
+            signature: Some(signature),
+        }
+    }
+}
+
+/// Receipt with the issuer's signature (unsigned when no key is configured)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedReceipt {
+    pub receipt: FulfillmentReceipt,
+    /// Issuer address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+    /// Base64 Sui signature over `receipt.message()`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl SignedReceipt {
+    pub fn unsigned(receipt: FulfillmentReceipt) -> Self {
+        Self {
+            receipt,
+            signer: None,
+            signature: None,
+        }
+    }
+
+    /// Check the signature against the claimed signer
+    pub fn verify(&self) -> Result<(), VerifyError> {
+        let (Some(signer), Some(signature)) = (&self.signer, &self.signature) else {
+            return Err(VerifyError::Malformed("receipt is not signed".into()));
+        };
+        verify_personal_message(&self.receipt.message(), signature, signer)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> FulfillmentReport {
+        FulfillmentReport {
+            digest: "0xdigest".to_string(),
+            solver: "StakingSolver".to_string(),
+            protocol: "native_staking".to_string(),
+            realized_apy_bps: 850,
+            created_objects: vec![FulfilledObject {
+                object_id: "0xstaked".to_string(),
+                object_type: "0x3::staking_pool::StakedSui".to_string(),
+            }],
+            reported_at: 1,
+            user: Some("0xuser".to_string()),
+            amount: Some(1_000_000_000),
+            fee: Some(1_000_000),
+            gas_used: Some(2_500_000),
+            failed_attempts: Vec::new(),
+        }
+    }
+
+    fn verification(status: VerificationStatus) -> Verification {
+        Verification {
+            status,
+            object_id: Some("0xstaked".to_string()),
+            amount: Some(1_000_000_000),
+            problems: Vec::new(),
+            fulfilled: None,
+            checked_at: 42,
+        }
+    }
+
+    #[test]
+    fn test_issue_needs_verification() {
+        let report = report();
+        assert!(FulfillmentReceipt::issue(
+            "i1",
+            &report,
+            &verification(VerificationStatus::Mismatch)
+        )
+        .is_none());
+
+        let receipt =
+            FulfillmentReceipt::issue("i1", &report, &verification(VerificationStatus::Verified))
+                .unwrap();
+        assert_eq!(receipt.fee, 1_000_000);
+        assert_eq!(receipt.verified_at, 42);
+        assert_eq!(receipt.objects.len(), 1);
+    }
+
+    #[test]
+    fn test_signed_receipt_verifies() {
+        let receipt =
+            FulfillmentReceipt::issue("i1", &report(), &verification(VerificationStatus::Verified))
+                .unwrap();
+        let signed = receipt.sign(&LocalSigner::new("receipts", [9u8; 32]));
+        assert!(signed.verify().is_ok());
+
+        // Survives a JSON round trip
+        let blob = serde_json::to_string(&signed).unwrap();
+        let parsed: SignedReceipt = serde_json::from_str(&blob).unwrap();
+        assert!(parsed.verify().is_ok());
+
+        let mut tampered = parsed;
+        tampered.receipt.realized_apy_bps = 900;
+        assert!(tampered.verify().is_err());
+        assert!(SignedReceipt::unsigned(tampered.receipt).verify().is_err());
+    }
+}
@@ -0,0 +1,486 @@
+//! Reconciliation between locally stored intents and the chain
+//!
+//! Local state drifts when events are missed or an intent is fulfilled
+//! outside the solver pipeline. The reconciler rebuilds the on-chain view
+//! from `IntentCreated` / `IntentFulfilled` / `IntentCancelled` events,
+//! confirms that intents still look open by reading their `YieldIntent`
+//! object, and diffs that against what the caller has stored. Repairing is
+//! left to the owner of the local state; the reconciler only counts drift
+//! and alerts on it.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+use naisu_sui::{SuiClient, SuiClientError, SuiEvent};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::events::{IntentCancelled, IntentCreated, IntentFulfilled};
+use crate::notify::{Alert, AlertLevel, NotificationHub};
+
+/// On-chain state of a YieldIntent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ChainState {
+    Open,
+    Fulfilled {
+        solver: String,
+        protocol: String,
+        apy: u64,
+        digest: Option<String>,
+    },
+    /// 0 = expired, 1 = cancelled by the user
+    Cancelled {
+        reason: u8,
+    },
+    /// Object deleted without an event in the scanned window
+    Closed,
+}
+
+/// An intent as seen on chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainIntent {
+    pub intent_id: String,
+    pub user: String,
+    pub amount: u64,
+    pub min_apy: u64,
+    pub deadline: u64,
+    pub target_protocol: String,
+    /// Creation tx digest
+    pub digest: Option<String>,
+    pub created_at: Option<u64>,
+    #[serde(flatten)]
+    pub state: ChainState,
+}
+
+/// An intent as stored locally
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum LocalState {
+    Open,
+    Fulfilled { digest: Option<String> },
+    Cancelled,
+}
+
+/// How local state disagrees with the chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DriftKind {
+    /// On chain, unknown locally
+    Untracked,
+    /// Fulfilled on chain, open locally
+    MissedFulfillment,
+    /// Cancelled on chain, open locally
+    MissedCancellation,
+    /// Object gone on chain, open locally
+    Vanished,
+    /// Fulfilled locally, not on chain
+    UnconfirmedFulfillment,
+    /// Fulfilled in a different transaction than reported
+    DigestMismatch,
+}
+
+impl DriftKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DriftKind::Untracked => "untracked",
+            DriftKind::MissedFulfillment => "missed_fulfillment",
+            DriftKind::MissedCancellation => "missed_cancellation",
+            DriftKind::Vanished => "vanished",
+            DriftKind::UnconfirmedFulfillment => "unconfirmed_fulfillment",
+            DriftKind::DigestMismatch => "digest_mismatch",
+        }
+    }
+
+    /// Whether adopting the chain's view fixes it; the rest need a human
+    pub fn is_repairable(&self) -> bool {
+        !matches!(
+            self,
+            DriftKind::UnconfirmedFulfillment | DriftKind::DigestMismatch
+        )
+    }
+}
+
+/// One discrepancy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Drift {
+    pub intent_id: String,
+    pub kind: DriftKind,
+    pub local: Option<LocalState>,
+    pub chain: ChainState,
+}
+
+/// Compare local state with the chain
+///
+/// Intents only known locally are skipped: their events may simply be
+/// older than the scanned window.
+pub fn diff(
+    local: &HashMap<String, LocalState>,
+    chain: &HashMap<String, ChainIntent>,
+) -> Vec<Drift> {
+    let mut drifts: Vec<Drift> = chain
+        .values()
+        .filter_map(|c| {
+            let l = local.get(&c.intent_id);
+            let kind = match (l, &c.state) {
+                (None, _) => DriftKind::Untracked,
+                (Some(LocalState::Open), ChainState::Fulfilled { .. }) => {
+                    DriftKind::MissedFulfillment
+                }
+                (Some(LocalState::Open), ChainState::Cancelled { .. }) => {
+                    DriftKind::MissedCancellation
+                }
+                (Some(LocalState::Open), ChainState::Closed) => DriftKind::Vanished,
+                (
+                    Some(LocalState::Fulfilled { .. }),
+                    ChainState::Open | ChainState::Cancelled { .. },
+                ) => DriftKind::UnconfirmedFulfillment,
+                (
+                    Some(LocalState::Fulfilled {
+                        digest: Some(reported),
+                    }),
+                    ChainState::Fulfilled {
+                        digest: Some(actual),
+                        ..
+                    },
+                ) if reported != actual => DriftKind::DigestMismatch,
+                _ => return None,
+            };
+            Some(Drift {
+                intent_id: c.intent_id.clone(),
+                kind,
+                local: l.cloned(),
+                chain: c.state.clone(),
+            })
+        })
+        .collect();
+    drifts.sort_by(|a, b| a.intent_id.cmp(&b.intent_id));
+    drifts
+}
+
+/// Drift counters across runs
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DriftSnapshot {
+    pub runs: u64,
+    pub errors: u64,
+    /// Unix millis of the last successful run
+    pub last_run: Option<u64>,
+    /// Intents seen on chain in the last run
+    pub checked: usize,
+    /// Drift found in the last run
+    pub current: usize,
+    pub total: u64,
+    pub repaired: u64,
+    pub by_kind: HashMap<&'static str, u64>,
+}
+
+/// Scans the chain and diffs it against local state
+pub struct Reconciler {
+    client: SuiClient,
+    intent_package: String,
+    notifier: NotificationHub,
+    interval: Duration,
+    /// Event pages read per event type
+    max_pages: usize,
+    metrics: Mutex<DriftSnapshot>,
+}
+
+impl Reconciler {
+    pub fn new(
+        client: SuiClient,
+        intent_package: impl Into<String>,
+        notifier: NotificationHub,
+    ) -> Self {
+        Self {
+            client,
+            intent_package: intent_package.into(),
+            notifier,
+            interval: Duration::from_secs(
+                std::env::var("RECONCILE_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(300),
+            ),
+            max_pages: 5,
+            metrics: Mutex::new(DriftSnapshot::default()),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn metrics(&self) -> DriftSnapshot {
+        self.metrics
+            .lock()
+            .expect("drift metrics lock poisoned")
+            .clone()
+    }
+
+    async fn events(&self, name: &str) -> Result<Vec<SuiEvent>> {
+        let event_type = format!("{}::intent::{}", self.intent_package, name);
+        let mut events = Vec::new();
+        let mut cursor = None;
+        for _ in 0..self.max_pages {
+            let page = self
+                .client
+                .query_events(&event_type, cursor.as_deref(), 50)
+                .await?;
+            events.extend(page.data);
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        Ok(events)
+    }
+
+    /// Current on-chain view of recently created intents
+    pub async fn scan(&self) -> Result<HashMap<String, ChainIntent>> {
+        let mut intents: HashMap<String, ChainIntent> = self
+            .events("IntentCreated")
+            .await?
+            .iter()
+            .filter_map(parse_created)
+            .map(|i| (i.intent_id.clone(), i))
+            .collect();
+
+        for event in self.events("IntentFulfilled").await? {
+            let Some((id, state)) = parse_fulfilled(&event) else {
+                continue;
+            };
+            if let Some(intent) = intents.get_mut(&id) {
+                intent.state = state;
+            }
+        }
+        for event in self.events("IntentCancelled").await? {
+            let Some((id, state)) = parse_cancelled(&event) else {
+                continue;
+            };
+            if let Some(intent) = intents.get_mut(&id) {
+                intent.state = state;
+            }
+        }
+
+        // Open intents must still exist as open YieldIntent objects
+        for intent in intents.values_mut() {
+            if intent.state != ChainState::Open {
+                continue;
+            }
+            match self.client.get_yield_intent(&intent.intent_id).await {
+                Ok(object) if object.is_open() => {}
+                Ok(_) => intent.state = ChainState::Closed,
+                Err(SuiClientError::ObjectNotFound(_)) => intent.state = ChainState::Closed,
+                Err(e) => warn!("⚠️  Could not read intent {}: {}", intent.intent_id, e),
+            }
+        }
+        Ok(intents)
+    }
+
+    /// Scan and diff once, updating metrics and alerting on unrepairable drift
+    pub async fn reconcile(
+        &self,
+        local: &HashMap<String, LocalState>,
+    ) -> Result<(HashMap<String, ChainIntent>, Vec<Drift>)> {
+        let chain = match self.scan().await {
+            Ok(chain) => chain,
+            Err(e) => {
+                self.metrics
+                    .lock()
+                    .expect("drift metrics lock poisoned")
+                    .errors += 1;
+                return Err(e);
+            }
+        };
+        let drifts = diff(local, &chain);
+
+        {
+            let mut metrics = self.metrics.lock().expect("drift metrics lock poisoned");
+            metrics.runs += 1;
+            metrics.last_run = Some(chrono::Utc::now().timestamp_millis() as u64);
+            metrics.checked = chain.len();
+            metrics.current = drifts.len();
+            metrics.total += drifts.len() as u64;
+            for drift in &drifts {
+                *metrics.by_kind.entry(drift.kind.as_str()).or_default() += 1;
+                if drift.kind.is_repairable() {
+                    metrics.repaired += 1;
+                }
+            }
+        }
+
+        info!(
+            "🔎 Reconciled {} intents, {} drifted",
+            chain.len(),
+            drifts.len()
+        );
+
+        let unrepairable: Vec<&Drift> = drifts.iter().filter(|d| !d.kind.is_repairable()).collect();
+        if !unrepairable.is_empty() {
+            let lines: Vec<String> = unrepairable
+                .iter()
+                .map(|d| format!("{}: {}", d.intent_id, d.kind.as_str()))
+                .collect();
+            self.notifier
+                .send(Alert::new(
+                    AlertLevel::Warning,
+                    format!("{} intents disagree with chain", unrepairable.len()),
+                    lines.join("\n"),
+                ))
+                .await;
+        }
+
+        Ok((chain, drifts))
+    }
+}
+
+fn parse_created(event: &SuiEvent) -> Option<ChainIntent> {
+    let created = IntentCreated::from_json(&event.parsed_json).ok()?;
+    let target_protocol = created.target_protocol();
+    let (intent_id, user, amount, min_apy, deadline) = match created {
+        IntentCreated::V1(e) => (e.intent_id, e.user, e.amount, e.min_apy, e.deadline),
+        IntentCreated::V2(e) => (e.intent_id, e.user, e.amount, e.min_apy, e.deadline),
+    };
+    Some(ChainIntent {
+        intent_id,
+        user,
+        amount,
+        min_apy,
+        deadline,
+        target_protocol,
+        digest: event.tx_digest.clone(),
+        created_at: event.timestamp_ms,
+        state: ChainState::Open,
+    })
+}
+
+fn parse_fulfilled(event: &SuiEvent) -> Option<(String, ChainState)> {
+    let fulfilled = IntentFulfilled::from_json(&event.parsed_json).ok()?;
+    Some((
+        fulfilled.intent_id,
+        ChainState::Fulfilled {
+            solver: fulfilled.solver,
+            protocol: fulfilled.protocol,
+            apy: fulfilled.apy,
+            digest: event.tx_digest.clone(),
+        },
+    ))
+}
+
+fn parse_cancelled(event: &SuiEvent) -> Option<(String, ChainState)> {
+    let cancelled = IntentCancelled::from_json(&event.parsed_json).ok()?;
+    Some((
+        cancelled.intent_id,
+        ChainState::Cancelled {
+            reason: cancelled.reason,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn chain_intent(id: &str, state: ChainState) -> (String, ChainIntent) {
+        (
+            id.to_string(),
+            ChainIntent {
+                intent_id: id.to_string(),
+                user: "0xuser".to_string(),
+                amount: 1_000,
+                min_apy: 700,
+                deadline: 0,
+                target_protocol: "any".to_string(),
+                digest: None,
+                created_at: None,
+                state,
+            },
+        )
+    }
+
+    fn fulfilled(digest: &str) -> ChainState {
+        ChainState::Fulfilled {
+            solver: "0xsolver".to_string(),
+            protocol: "scallop".to_string(),
+            apy: 800,
+            digest: Some(digest.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_drift() {
+        let chain: HashMap<_, _> = [
+            chain_intent("a", ChainState::Open),
+            chain_intent("b", fulfilled("tx1")),
+            chain_intent("c", ChainState::Cancelled { reason: 0 }),
+            chain_intent("d", ChainState::Open),
+            chain_intent("e", fulfilled("tx2")),
+            chain_intent("f", fulfilled("tx3")),
+        ]
+        .into_iter()
+        .collect();
+        let fulfilled_local = |d: &str| LocalState::Fulfilled {
+            digest: Some(d.to_string()),
+        };
+        let local: HashMap<_, _> = [
+            ("b".to_string(), LocalState::Open),
+            ("c".to_string(), LocalState::Open),
+            ("d".to_string(), fulfilled_local("tx0")),
+            ("e".to_string(), fulfilled_local("txX")),
+            ("f".to_string(), fulfilled_local("tx3")),
+            ("z".to_string(), LocalState::Open),
+        ]
+        .into_iter()
+        .collect();
+
+        let kinds: Vec<(String, DriftKind)> = diff(&local, &chain)
+            .into_iter()
+            .map(|d| (d.intent_id, d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("a".to_string(), DriftKind::Untracked),
+                ("b".to_string(), DriftKind::MissedFulfillment),
+                ("c".to_string(), DriftKind::MissedCancellation),
+                ("d".to_string(), DriftKind::UnconfirmedFulfillment),
+                ("e".to_string(), DriftKind::DigestMismatch),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let created = SuiEvent {
+            event_type: "0xpkg::intent::IntentCreated".to_string(),
+            parsed_json: json!({
+                "intent_id": "0xi",
+                "user": "0xu",
+                "amount": "1000",
+                "min_apy": "720",
+                "deadline": 99,
+                "target_protocol": "scallop"
+            }),
+            sender: None,
+            tx_digest: Some("tx".to_string()),
+            timestamp_ms: Some(1),
+        };
+        let intent = parse_created(&created).unwrap();
+        assert_eq!(intent.amount, 1000);
+        assert_eq!(intent.deadline, 99);
+        assert_eq!(intent.state, ChainState::Open);
+    }
+}
@@ -0,0 +1,219 @@
+//! Referrals and fee sharing
+//!
+//! An intent can name a referrer when it is created. The referrer is
+//! credited with the intent's volume, and with a share of the protocol fee
+//! (`FeeConfig::referral_share_bps`) once the intent is fulfilled.
+//!
+//! Payouts are claimed in two steps: building a claim reserves everything
+//! claimable as pending, and confirming it with the payout transaction
+//! moves the pending amount to claimed. Fees that accrue in between stay
+//! claimable for the next claim.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+/// What one referrer has earned
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ReferralAccount {
+    pub referrer: String,
+    /// Intents created with this referrer
+    pub intents: u64,
+    /// Input amount of those intents (raw units)
+    pub referred_volume: u64,
+    /// Protocol fees paid on referred fulfillments (MIST)
+    pub fees_generated: u64,
+    /// Referrer's share of those fees (MIST)
+    pub accrued: u64,
+    /// Reserved by a built, unconfirmed claim (MIST)
+    pub pending: u64,
+    /// Paid out (MIST)
+    pub claimed: u64,
+    /// Last payout transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_claim_tx: Option<String>,
+}
+
+impl ReferralAccount {
+    fn new(referrer: &str) -> Self {
+        Self {
+            referrer: referrer.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Accrued and neither claimed nor pending (MIST)
+    pub fn claimable(&self) -> u64 {
+        self.accrued - self.claimed - self.pending
+    }
+}
+
+/// Referral accounts, keyed by referrer address
+#[derive(Debug, Clone, Default)]
+pub struct ReferralBook {
+    accounts: HashMap<String, ReferralAccount>,
+    /// Intent ID → referrer
+    referrers: HashMap<String, String>,
+}
+
+impl ReferralBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Credit a new intent to its referrer
+    pub fn record_intent(
+        &mut self,
+        intent_id: &str,
+        referrer: &str,
+        volume: u64,
+    ) -> Result<(), ReferralError> {
+        if self.referrers.contains_key(intent_id) {
+            return Err(ReferralError::AlreadyReferred(intent_id.to_string()));
+        }
+        self.referrers
+            .insert(intent_id.to_string(), referrer.to_string());
+
+        let account = self.account_mut(referrer);
+        account.intents += 1;
+        account.referred_volume = account.referred_volume.saturating_add(volume);
+        Ok(())
+    }
+
+    /// Credit the referrer's share of a fulfillment fee
+    ///
+    /// Returns the share, or `None` if the intent had no referrer.
+    pub fn record_fee(&mut self, intent_id: &str, fee: u64, share_bps: u16) -> Option<u64> {
+        let referrer = self.referrers.get(intent_id)?.clone();
+        let share = (fee as u128 * share_bps as u128 / 10_000) as u64;
+
+        let account = self.account_mut(&referrer);
+        account.fees_generated += fee;
+        account.accrued += share;
+        Some(share)
+    }
+
+    /// Referrer of an intent, if any
+    pub fn referrer(&self, intent_id: &str) -> Option<&str> {
+        self.referrers.get(intent_id).map(String::as_str)
+    }
+
+    /// Account for a referrer (empty if they have referred nothing)
+    pub fn account(&self, referrer: &str) -> ReferralAccount {
+        self.accounts
+            .get(referrer)
+            .cloned()
+            .unwrap_or_else(|| ReferralAccount::new(referrer))
+    }
+
+    /// Reserve everything claimable for a payout and return the amount
+    pub fn begin_claim(&mut self, referrer: &str) -> Result<u64, ReferralError> {
+        let account = self
+            .accounts
+            .get_mut(referrer)
+            .ok_or(ReferralError::NothingToClaim)?;
+        if account.pending > 0 {
+            return Err(ReferralError::ClaimPending(account.pending));
+        }
+        let amount = account.claimable();
+        if amount == 0 {
+            return Err(ReferralError::NothingToClaim);
+        }
+        account.pending = amount;
+        Ok(amount)
+    }
+
+    /// Record the payout of the pending claim
+    pub fn confirm_claim(&mut self, referrer: &str, tx: &str) -> Result<u64, ReferralError> {
+        let account = self
+            .accounts
+            .get_mut(referrer)
+            .filter(|a| a.pending > 0)
+            .ok_or(ReferralError::NoPendingClaim)?;
+        let amount = account.pending;
+        account.claimed += amount;
+        account.pending = 0;
+        account.last_claim_tx = Some(tx.to_string());
+        Ok(amount)
+    }
+
+    fn account_mut(&mut self, referrer: &str) -> &mut ReferralAccount {
+        self.accounts
+            .entry(referrer.to_string())
+            .or_insert_with(|| ReferralAccount::new(referrer))
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ReferralError {
+    #[error("Intent {0} already has a referrer")]
+    AlreadyReferred(String),
+
+    #[error("Nothing to claim")]
+    NothingToClaim,
+
+    #[error("A claim of {0} is already pending")]
+    ClaimPending(u64),
+
+    #[error("No pending claim to confirm")]
+    NoPendingClaim,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_share_accrues_to_referrer() {
+        let mut book = ReferralBook::new();
+        book.record_intent("i1", "0xref", 5_000).unwrap();
+        book.record_intent("i2", "0xref", 3_000).unwrap();
+        assert_eq!(
+            book.record_intent("i1", "0xother", 1),
+            Err(ReferralError::AlreadyReferred("i1".to_string()))
+        );
+
+        assert_eq!(book.record_fee("i1", 1_000, 2_000), Some(200));
+        assert_eq!(book.record_fee("unreferred", 1_000, 2_000), None);
+
+        let account = book.account("0xref");
+        assert_eq!(account.intents, 2);
+        assert_eq!(account.referred_volume, 8_000);
+        assert_eq!(account.fees_generated, 1_000);
+        assert_eq!(account.claimable(), 200);
+    }
+
+    #[test]
+    fn test_claim_lifecycle() {
+        let mut book = ReferralBook::new();
+        book.record_intent("i1", "0xref", 5_000).unwrap();
+        book.record_fee("i1", 1_000, 5_000);
+
+        assert_eq!(book.begin_claim("0xref"), Ok(500));
+        assert_eq!(
+            book.begin_claim("0xref"),
+            Err(ReferralError::ClaimPending(500))
+        );
+
+        // Accrues while the claim is pending, claimable next time
+        book.record_intent("i2", "0xref", 1_000).unwrap();
+        book.record_fee("i2", 100, 5_000);
+        assert_eq!(book.confirm_claim("0xref", "0xdigest"), Ok(500));
+
+        let account = book.account("0xref");
+        assert_eq!(account.claimed, 500);
+        assert_eq!(account.claimable(), 50);
+        assert_eq!(
+            book.confirm_claim("0xref", "0xdigest"),
+            Err(ReferralError::NoPendingClaim)
+        );
+        assert_eq!(
+            book.begin_claim("0xnobody"),
+            Err(ReferralError::NothingToClaim)
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Solver registry
+//!
+//! Each bot describes itself with a `SolverDescriptor` (name, protocols,
+//! networks, constructor) and is registered once in `bots::builtin()`.
+//! `SolverFactory` builds whatever the registry offers for a network, so a
+//! new solver needs no changes to the factory or the daemon.
+//!
+//! Which registered solvers run is deployment config: `solvers = [..]` under
+//! `[networks.<name>]` or `<NETWORK>_SOLVERS=a,b`.
+
+use std::sync::{Arc, OnceLock};
+
+use crate::config::{AgentConfig, Network, Protocol};
+use crate::executor::real_executor::SOLVER_ADDRESS;
+use crate::gas::GasOracle;
+use crate::solver::Solver;
+
+/// A built solver, ready for the daemon
+pub type BoxedSolver = Box<dyn Solver + Send + Sync>;
+
+/// What a constructor gets to build a solver with
+pub struct SolverContext<'a> {
+    pub network: Network,
+    pub config: &'a AgentConfig,
+    /// Live gas costs for bidding
+    pub gas: Arc<GasOracle>,
+}
+
+impl SolverContext<'_> {
+    /// Wallet fulfillments are paid from: `[wallet] address` for the
+    /// network, else the built-in solver wallet
+    pub fn wallet(&self) -> String {
+        self.config
+            .wallet_address(self.network)
+            .unwrap_or_else(|| SOLVER_ADDRESS.to_string())
+    }
+}
+
+/// Self-description of a solver
+#[derive(Clone, Copy)]
+pub struct SolverDescriptor {
+    /// Solver name, also the `[solvers.<name>]` tuning key
+    pub name: &'static str,
+    /// Protocols the solver deploys into
+    pub protocols: &'static [Protocol],
+    /// Networks the solver runs on
+    pub supports: fn(Network) -> bool,
+    /// Only runs with the `new_solvers` feature flag on
+    pub experimental: bool,
+    pub build: fn(&SolverContext) -> BoxedSolver,
+}
+
+impl SolverDescriptor {
+    pub fn supports(&self, network: Network) -> bool {
+        (self.supports)(network)
+    }
+
+    /// Build with a fresh gas oracle (static gas costs until it has data)
+    pub fn build(&self, network: Network, config: &AgentConfig) -> BoxedSolver {
+        self.build_with(&SolverContext {
+            network,
+            config,
+            gas: Arc::default(),
+        })
+    }
+
+    pub fn build_with(&self, ctx: &SolverContext) -> BoxedSolver {
+        (self.build)(ctx)
+    }
+}
+
+impl std::fmt::Debug for SolverDescriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SolverDescriptor")
+            .field("name", &self.name)
+            .field("protocols", &self.protocols)
+            .field("experimental", &self.experimental)
+            .finish()
+    }
+}
+
+/// Registered solvers, in registration order
+#[derive(Debug, Default)]
+pub struct SolverRegistry {
+    descriptors: Vec<SolverDescriptor>,
+}
+
+impl SolverRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry of the bots shipped with the agent
+    pub fn builtin() -> Arc<SolverRegistry> {
+        static BUILTIN: OnceLock<Arc<SolverRegistry>> = OnceLock::new();
+        BUILTIN
+            .get_or_init(|| {
+                let mut registry = SolverRegistry::new();
+                for descriptor in crate::bots::builtin() {
+                    registry
+                        .register(descriptor)
+                        .expect("built-in solver names are unique");
+                }
+                Arc::new(registry)
+            })
+            .clone()
+    }
+
+    /// Add a solver; names must be unique
+    pub fn register(&mut self, descriptor: SolverDescriptor) -> Result<(), RegistryError> {
+        if self.get(descriptor.name).is_some() {
+            return Err(RegistryError::Duplicate(descriptor.name.to_string()));
+        }
+        self.descriptors.push(descriptor);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SolverDescriptor> {
+        self.descriptors.iter().find(|d| d.name == name)
+    }
+
+    pub fn descriptors(&self) -> &[SolverDescriptor] {
+        &self.descriptors
+    }
+
+    /// Solvers that run on a network
+    pub fn for_network(&self, network: Network) -> impl Iterator<Item = &SolverDescriptor> {
+        self.descriptors.iter().filter(move |d| d.supports(network))
+    }
+
+    /// First solver on a network that deploys into `protocol`
+    pub fn for_protocol(&self, protocol: Protocol, network: Network) -> Option<&SolverDescriptor> {
+        self.for_network(network)
+            .find(|d| d.protocols.contains(&protocol))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    #[error("Solver already registered: {0}")]
+    Duplicate(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bots::StakingSolver;
+
+    #[test]
+    fn test_register_rejects_duplicates() {
+        let mut registry = SolverRegistry::new();
+        let descriptor = StakingSolver::descriptor();
+        registry.register(descriptor).unwrap();
+        assert!(matches!(
+            registry.register(descriptor),
+            Err(RegistryError::Duplicate(name)) if name == "StakingSolver"
+        ));
+    }
+
+    #[test]
+    fn test_context_wallet_follows_config() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [wallet]
+            address = "0xshared"
+
+            [networks.mainnet]
+            wallet = "0xmainnet"
+            "#,
+        )
+        .unwrap();
+        let ctx = |network| SolverContext {
+            network,
+            config: &config,
+            gas: Arc::default(),
+        };
+        assert_eq!(ctx(Network::Mainnet).wallet(), "0xmainnet");
+        assert_eq!(ctx(Network::Testnet).wallet(), "0xshared");
+
+        let defaults = AgentConfig::defaults();
+        let ctx = SolverContext {
+            network: Network::Testnet,
+            config: &defaults,
+            gas: Arc::default(),
+        };
+        assert_eq!(ctx.wallet(), SOLVER_ADDRESS);
+    }
+
+    #[test]
+    fn test_builtin_registry() {
+        let registry = SolverRegistry::builtin();
+        assert_eq!(registry.descriptors().len(), 5);
+
+        let testnet: Vec<&str> = registry
+            .for_network(Network::Testnet)
+            .map(|d| d.name)
+            .collect();
+        assert_eq!(testnet, vec!["StakingSolver", "DeepBookSolver"]);
+
+        let scallop = registry
+            .for_protocol(Protocol::Scallop, Network::Mainnet)
+            .unwrap();
+        assert_eq!(scallop.name, "ScallopSolver");
+        assert!(registry
+            .for_protocol(Protocol::Scallop, Network::Testnet)
+            .is_none());
+
+        let built = scallop.build(Network::Mainnet, &AgentConfig::defaults());
+        assert_eq!(built.name(), "ScallopSolver");
+    }
+}
@@ -0,0 +1,240 @@
+//! Solver reputation
+//!
+//! Scores solvers from their fulfillment history:
+//! - success rate: fulfillments per intent the solver won
+//! - APY accuracy: APY delivered relative to the APY bid
+//! - latency: time from bid to fulfillment report
+//! - verification: share of fulfillments that matched on-chain
+//!
+//! Each component is 0.0 - 1.0 (0.5 while unknown) and the weighted sum is
+//! scaled to 0 - 100. Solvers with little history are pulled toward the
+//! middle, so one lucky fulfillment doesn't top the leaderboard.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::verify::VerificationStatus;
+
+/// Score for a component with no data
+const NEUTRAL: f64 = 0.5;
+
+/// Fulfillments needed before history outweighs the neutral prior
+const PRIOR_WEIGHT: f64 = 3.0;
+
+/// Raw history for one solver
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolverHistory {
+    pub solver: String,
+    /// Intents the solver won
+    pub wins: u64,
+    pub fulfillments: u64,
+    /// (bid APY, delivered APY) in bps
+    pub apy: Vec<(u64, u64)>,
+    /// Bid-to-fulfillment times (ms)
+    pub latencies_ms: Vec<u64>,
+    pub verified: u64,
+    pub mismatches: u64,
+}
+
+impl SolverHistory {
+    pub fn new(solver: impl Into<String>) -> Self {
+        Self {
+            solver: solver.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn record_win(&mut self) {
+        self.wins += 1;
+    }
+
+    pub fn record_fulfillment(
+        &mut self,
+        bid_apy: Option<u64>,
+        delivered_apy: u64,
+        latency_ms: Option<u64>,
+    ) {
+        self.fulfillments += 1;
+        if let Some(bid) = bid_apy {
+            self.apy.push((bid, delivered_apy));
+        }
+        if let Some(latency) = latency_ms {
+            self.latencies_ms.push(latency);
+        }
+    }
+
+    pub fn record_verification(&mut self, status: VerificationStatus) {
+        match status {
+            VerificationStatus::Verified => self.verified += 1,
+            VerificationStatus::Mismatch => self.mismatches += 1,
+            VerificationStatus::Unverifiable => {}
+        }
+    }
+
+    fn success_rate(&self) -> Option<f64> {
+        // Manual fulfillments can outnumber recorded wins
+        let attempts = self.wins.max(self.fulfillments);
+        (attempts > 0).then(|| self.fulfillments as f64 / attempts as f64)
+    }
+
+    fn apy_accuracy(&self) -> Option<f64> {
+        let ratios: Vec<f64> = self
+            .apy
+            .iter()
+            .filter(|(bid, _)| *bid > 0)
+            .map(|(bid, delivered)| (*delivered as f64 / *bid as f64).min(1.0))
+            .collect();
+        (!ratios.is_empty()).then(|| ratios.iter().sum::<f64>() / ratios.len() as f64)
+    }
+
+    fn median_latency(&self) -> Option<u64> {
+        let mut latencies = self.latencies_ms.clone();
+        latencies.sort_unstable();
+        latencies.get(latencies.len().checked_sub(1)? / 2).copied()
+    }
+
+    fn mismatch_rate(&self) -> Option<f64> {
+        let checked = self.verified + self.mismatches;
+        (checked > 0).then(|| self.mismatches as f64 / checked as f64)
+    }
+}
+
+/// Component weights
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReputationWeights {
+    pub success: f64,
+    pub apy: f64,
+    pub latency: f64,
+    pub verification: f64,
+    /// Latency that scores 0.5; faster approaches 1.0
+    pub target_latency_ms: u64,
+}
+
+impl Default for ReputationWeights {
+    fn default() -> Self {
+        Self {
+            success: 0.4,
+            apy: 0.3,
+            latency: 0.15,
+            verification: 0.15,
+            target_latency_ms: 60_000,
+        }
+    }
+}
+
+/// A solver's reputation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Reputation {
+    pub solver: String,
+    /// 0 - 100
+    pub score: f64,
+    pub wins: u64,
+    pub fulfillments: u64,
+    pub success_rate: Option<f64>,
+    /// Delivered / bid APY, capped at 1.0
+    pub apy_accuracy: Option<f64>,
+    pub median_latency_ms: Option<u64>,
+    pub mismatch_rate: Option<f64>,
+}
+
+/// Score one solver
+pub fn score(history: &SolverHistory, weights: &ReputationWeights) -> Reputation {
+    let success_rate = history.success_rate();
+    let apy_accuracy = history.apy_accuracy();
+    let median_latency_ms = history.median_latency();
+    let mismatch_rate = history.mismatch_rate();
+
+    let latency = median_latency_ms
+        .map(|l| weights.target_latency_ms as f64 / (weights.target_latency_ms + l).max(1) as f64);
+    let components = [
+        (weights.success, success_rate),
+        (weights.apy, apy_accuracy),
+        (weights.latency, latency),
+        (weights.verification, mismatch_rate.map(|r| 1.0 - r)),
+    ];
+    let total_weight: f64 = components.iter().map(|(w, _)| w).sum();
+    let raw = if total_weight > 0.0 {
+        components
+            .iter()
+            .map(|(w, v)| w * v.unwrap_or(NEUTRAL))
+            .sum::<f64>()
+            / total_weight
+    } else {
+        NEUTRAL
+    };
+
+    let n = history.fulfillments.max(history.wins) as f64;
+    let smoothed = (n * raw + PRIOR_WEIGHT * NEUTRAL) / (n + PRIOR_WEIGHT);
+
+    Reputation {
+        solver: history.solver.clone(),
+        score: (smoothed * 10_000.0).round() / 100.0,
+        wins: history.wins,
+        fulfillments: history.fulfillments,
+        success_rate,
+        apy_accuracy,
+        median_latency_ms,
+        mismatch_rate,
+    }
+}
+
+/// Score every solver, best first
+pub fn leaderboard<'a>(
+    histories: impl IntoIterator<Item = &'a SolverHistory>,
+    weights: &ReputationWeights,
+) -> Vec<Reputation> {
+    let mut board: Vec<Reputation> = histories.into_iter().map(|h| score(h, weights)).collect();
+    board.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.fulfillments.cmp(&a.fulfillments))
+            .then_with(|| a.solver.cmp(&b.solver))
+    });
+    board
+}
+
+/// Solver name → score, for selection tie-breaks
+pub fn scores(board: &[Reputation]) -> HashMap<String, f64> {
+    board.iter().map(|r| (r.solver.clone(), r.score)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reliable_solver_ranks_first() {
+        let mut reliable = SolverHistory::new("A");
+        let mut flaky = SolverHistory::new("B");
+        for _ in 0..10 {
+            reliable.record_win();
+            reliable.record_fulfillment(Some(800), 800, Some(20_000));
+            reliable.record_verification(VerificationStatus::Verified);
+
+            flaky.record_win();
+        }
+        for _ in 0..5 {
+            flaky.record_fulfillment(Some(800), 600, Some(120_000));
+            flaky.record_verification(VerificationStatus::Mismatch);
+        }
+
+        let board = leaderboard([&flaky, &reliable], &ReputationWeights::default());
+        assert_eq!(board[0].solver, "A");
+        assert_eq!(board[1].success_rate, Some(0.5));
+        assert_eq!(board[1].mismatch_rate, Some(1.0));
+        assert!(board[0].score > 80.0 && board[1].score < 50.0);
+    }
+
+    #[test]
+    fn test_new_solver_is_neutral() {
+        let rep = score(&SolverHistory::new("new"), &ReputationWeights::default());
+        assert_eq!(rep.score, 50.0);
+
+        let mut lucky = SolverHistory::new("lucky");
+        lucky.record_win();
+        lucky.record_fulfillment(Some(800), 800, Some(1_000));
+        let rep = score(&lucky, &ReputationWeights::default());
+        assert!(rep.score > 50.0 && rep.score < 75.0);
+    }
+}
@@ -0,0 +1,504 @@
+//! Treasury risk controls
+//!
+//! Limits how much of the solver treasury can be committed to fulfillments:
+//! - per-intent cap
+//! - per-protocol exposure caps (keyed by solver name), adjustable at runtime
+//! - rolling daily volume cap (resets at UTC midnight)
+//! - minimum treasury reserve that must remain after a fill
+//! - emergency kill switch that halts fulfillment
+//!
+//! The kill switch only gates `fulfill`; the daemon keeps polling and
+//! evaluating intents so monitoring stays live while fills are halted.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use chrono::{NaiveDate, Utc};
+use tracing::{info, warn};
+
+// ─── Limits ──────────────────────────────────────────────────────────────────
+
+/// Configured risk limits (all amounts in MIST / base units; `None` = unlimited)
+#[derive(Debug, Clone, Default)]
+pub struct RiskLimits {
+    /// Largest single intent the solver will fill
+    pub max_intent_amount: Option<u64>,
+    /// Exposure cap per protocol, keyed by solver name
+    pub protocol_caps: HashMap<String, u64>,
+    /// Cap for protocols without an explicit entry
+    pub default_protocol_cap: Option<u64>,
+    /// Total volume fillable per UTC day
+    pub max_daily_volume: Option<u64>,
+    /// Treasury balance that must remain after a fill
+    pub min_treasury_reserve: u64,
+}
+
+impl RiskLimits {
+    /// Load limits from environment variables
+    ///
+    /// - `RISK_MAX_INTENT_AMOUNT`
+    /// - `RISK_PROTOCOL_CAPS` (e.g. `ScallopSolver=5000000000,NaviSolver=2000000000`)
+    /// - `RISK_DEFAULT_PROTOCOL_CAP`
+    /// - `RISK_MAX_DAILY_VOLUME`
+    /// - `RISK_MIN_TREASURY_RESERVE`
+    pub fn from_env() -> Self {
+        let amount = |name: &str| std::env::var(name).ok().and_then(|v| v.parse().ok());
+
+        let protocol_caps = std::env::var("RISK_PROTOCOL_CAPS")
+            .map(|v| parse_protocol_caps(&v))
+            .unwrap_or_default();
+
+        Self {
+            max_intent_amount: amount("RISK_MAX_INTENT_AMOUNT"),
+            protocol_caps,
+            default_protocol_cap: amount("RISK_DEFAULT_PROTOCOL_CAP"),
+            max_daily_volume: amount("RISK_MAX_DAILY_VOLUME"),
+            min_treasury_reserve: amount("RISK_MIN_TREASURY_RESERVE").unwrap_or(0),
+        }
+    }
+
+    /// Exposure cap for a protocol
+    pub fn protocol_cap(&self, protocol: &str) -> Option<u64> {
+        self.protocol_caps
+            .get(protocol)
+            .copied()
+            .or(self.default_protocol_cap)
+    }
+}
+
+/// Parse `name=amount,name=amount`, skipping malformed entries
+fn parse_protocol_caps(value: &str) -> HashMap<String, u64> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let (name, cap) = entry.split_once('=')?;
+            Some((name.trim().to_string(), cap.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+// ─── Kill switch ─────────────────────────────────────────────────────────────
+
+/// Emergency stop for fulfillment, shareable across tasks
+#[derive(Debug, Clone, Default)]
+pub struct KillSwitch {
+    engaged: Arc<AtomicBool>,
+    reason: Arc<Mutex<Option<String>>>,
+}
+
+impl KillSwitch {
+    /// Initial state from `SOLVER_KILL_SWITCH` (true/1 = engaged)
+    pub fn from_env() -> Self {
+        let switch = Self::default();
+        let engaged = std::env::var("SOLVER_KILL_SWITCH")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        if engaged {
+            switch.engage("SOLVER_KILL_SWITCH set");
+        }
+        switch
+    }
+
+    pub fn engage(&self, reason: &str) {
+        *self.reason.lock().expect("kill switch lock poisoned") = Some(reason.to_string());
+        if !self.engaged.swap(true, Ordering::SeqCst) {
+            warn!("🛑 Kill switch engaged: {}", reason);
+        }
+    }
+
+    pub fn release(&self) {
+        *self.reason.lock().expect("kill switch lock poisoned") = None;
+        if self.engaged.swap(false, Ordering::SeqCst) {
+            info!("▶️  Kill switch released, fulfillment resumed");
+        }
+    }
+
+    pub fn is_engaged(&self) -> bool {
+        self.engaged.load(Ordering::SeqCst)
+    }
+
+    pub fn reason(&self) -> Option<String> {
+        self.reason
+            .lock()
+            .expect("kill switch lock poisoned")
+            .clone()
+    }
+}
+
+// ─── Manager ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default)]
+struct Exposure {
+    per_protocol: HashMap<String, u64>,
+    day: Option<NaiveDate>,
+    daily_volume: u64,
+    /// Reserved for fills still in flight, not yet out of the treasury balance
+    in_flight: u64,
+}
+
+impl Exposure {
+    /// Reset the daily volume when the UTC day changes
+    fn roll_day(&mut self, today: NaiveDate) {
+        if self.day != Some(today) {
+            self.day = Some(today);
+            self.daily_volume = 0;
+        }
+    }
+}
+
+/// Tracks exposure and checks fills against `RiskLimits`
+#[derive(Debug)]
+pub struct RiskManager {
+    limits: RiskLimits,
+    /// Caps set at runtime (`[solvers.<name>] max_exposure`), over `limits`
+    cap_overrides: Mutex<HashMap<String, u64>>,
+    kill_switch: KillSwitch,
+    exposure: Mutex<Exposure>,
+}
+
+impl RiskManager {
+    pub fn new(limits: RiskLimits, kill_switch: KillSwitch) -> Self {
+        Self {
+            limits,
+            cap_overrides: Mutex::new(HashMap::new()),
+            kill_switch,
+            exposure: Mutex::new(Exposure::default()),
+        }
+    }
+
+    /// Limits and kill switch from the environment
+    pub fn from_env() -> Self {
+        Self::new(RiskLimits::from_env(), KillSwitch::from_env())
+    }
+
+    pub fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    pub fn kill_switch(&self) -> &KillSwitch {
+        &self.kill_switch
+    }
+
+    /// Override a protocol's exposure cap; `None` falls back to the limits
+    pub fn set_protocol_cap(&self, protocol: &str, cap: Option<u64>) {
+        let mut overrides = self.cap_overrides.lock().expect("cap lock poisoned");
+        let previous = match cap {
+            Some(cap) => overrides.insert(protocol.to_string(), cap),
+            None => overrides.remove(protocol),
+        };
+        if previous != cap {
+            info!("🛡️  Exposure cap for {} set to {:?}", protocol, cap);
+        }
+    }
+
+    /// Exposure cap in force for a protocol
+    pub fn protocol_cap(&self, protocol: &str) -> Option<u64> {
+        self.cap_overrides
+            .lock()
+            .expect("cap lock poisoned")
+            .get(protocol)
+            .copied()
+            .or_else(|| self.limits.protocol_cap(protocol))
+    }
+
+    /// Check whether a fill of `amount` via `protocol` is allowed
+    ///
+    /// `treasury_balance` is the solver's current balance, if known; the
+    /// reserve check is skipped when it is `None`.
+    pub fn check(
+        &self,
+        protocol: &str,
+        amount: u64,
+        treasury_balance: Option<u64>,
+    ) -> Result<(), RiskError> {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        self.check_locked(&mut exposure, protocol, amount, treasury_balance)
+    }
+
+    /// Check and record a fill atomically
+    ///
+    /// Concurrent fulfillments use this instead of `check` + `record_fill`
+    /// so two tasks can't both pass a cap that only one of them fits under.
+    /// The amount stays counted against the treasury reserve until
+    /// `complete_reservation` (the fill landed) or `cancel_reservation`
+    /// (it didn't).
+    pub fn reserve(
+        &self,
+        protocol: &str,
+        amount: u64,
+        treasury_balance: Option<u64>,
+    ) -> Result<(), RiskError> {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        self.check_locked(&mut exposure, protocol, amount, treasury_balance)?;
+        *exposure
+            .per_protocol
+            .entry(protocol.to_string())
+            .or_default() += amount;
+        exposure.daily_volume += amount;
+        exposure.in_flight += amount;
+        Ok(())
+    }
+
+    /// A reserved fill landed: the treasury balance now accounts for it
+    pub fn complete_reservation(&self, amount: u64) {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        exposure.in_flight = exposure.in_flight.saturating_sub(amount);
+    }
+
+    /// Undo a `reserve` whose fill did not go through
+    pub fn cancel_reservation(&self, protocol: &str, amount: u64) {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        if let Some(current) = exposure.per_protocol.get_mut(protocol) {
+            *current = current.saturating_sub(amount);
+        }
+        exposure.daily_volume = exposure.daily_volume.saturating_sub(amount);
+        exposure.in_flight = exposure.in_flight.saturating_sub(amount);
+    }
+
+    fn check_locked(
+        &self,
+        exposure: &mut Exposure,
+        protocol: &str,
+        amount: u64,
+        treasury_balance: Option<u64>,
+    ) -> Result<(), RiskError> {
+        if self.kill_switch.is_engaged() {
+            return Err(RiskError::KillSwitch(
+                self.kill_switch.reason().unwrap_or_default(),
+            ));
+        }
+
+        if let Some(max) = self.limits.max_intent_amount {
+            if amount > max {
+                return Err(RiskError::IntentTooLarge { amount, max });
+            }
+        }
+
+        exposure.roll_day(Utc::now().date_naive());
+
+        if let Some(cap) = self.protocol_cap(protocol) {
+            let current = exposure.per_protocol.get(protocol).copied().unwrap_or(0);
+            if current.saturating_add(amount) > cap {
+                return Err(RiskError::ProtocolCap {
+                    protocol: protocol.to_string(),
+                    current,
+                    cap,
+                });
+            }
+        }
+
+        if let Some(max) = self.limits.max_daily_volume {
+            if exposure.daily_volume.saturating_add(amount) > max {
+                return Err(RiskError::DailyVolume {
+                    current: exposure.daily_volume,
+                    max,
+                });
+            }
+        }
+
+        // Fills still in flight haven't left the balance yet
+        if let Some(balance) = treasury_balance {
+            let remaining = balance
+                .saturating_sub(exposure.in_flight)
+                .saturating_sub(amount);
+            if remaining < self.limits.min_treasury_reserve {
+                return Err(RiskError::ReserveBreached {
+                    remaining,
+                    reserve: self.limits.min_treasury_reserve,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record a successful fill
+    pub fn record_fill(&self, protocol: &str, amount: u64) {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        exposure.roll_day(Utc::now().date_naive());
+        *exposure
+            .per_protocol
+            .entry(protocol.to_string())
+            .or_default() += amount;
+        exposure.daily_volume += amount;
+    }
+
+    /// Reduce exposure when a position is withdrawn or settled
+    pub fn release_exposure(&self, protocol: &str, amount: u64) {
+        let mut exposure = self.exposure.lock().expect("exposure lock poisoned");
+        if let Some(current) = exposure.per_protocol.get_mut(protocol) {
+            *current = current.saturating_sub(amount);
+        }
+    }
+
+    /// Current exposure for a protocol
+    pub fn exposure(&self, protocol: &str) -> u64 {
+        self.exposure
+            .lock()
+            .expect("exposure lock poisoned")
+            .per_protocol
+            .get(protocol)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum RiskError {
+    #[error("Kill switch engaged: {0}")]
+    KillSwitch(String),
+
+    #[error("Intent amount {amount} exceeds per-intent cap {max}")]
+    IntentTooLarge { amount: u64, max: u64 },
+
+    #[error("{protocol} exposure {current} would exceed cap {cap}")]
+    ProtocolCap {
+        protocol: String,
+        current: u64,
+        cap: u64,
+    },
+
+    #[error("Daily volume {current} would exceed cap {max}")]
+    DailyVolume { current: u64, max: u64 },
+
+    #[error("Treasury would drop to {remaining}, below reserve {reserve}")]
+    ReserveBreached { remaining: u64, reserve: u64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> RiskLimits {
+        RiskLimits {
+            max_intent_amount: Some(1_000),
+            protocol_caps: parse_protocol_caps("ScallopSolver=1500, bad, NaviSolver=x"),
+            default_protocol_cap: None,
+            max_daily_volume: Some(2_500),
+            min_treasury_reserve: 100,
+        }
+    }
+
+    #[test]
+    fn test_parse_protocol_caps() {
+        let caps = parse_protocol_caps("ScallopSolver=1500, bad, NaviSolver=x");
+        assert_eq!(caps.len(), 1);
+        assert_eq!(caps["ScallopSolver"], 1_500);
+    }
+
+    #[test]
+    fn test_per_intent_and_protocol_caps() {
+        let risk = RiskManager::new(limits(), KillSwitch::default());
+
+        assert!(matches!(
+            risk.check("ScallopSolver", 1_001, None),
+            Err(RiskError::IntentTooLarge { .. })
+        ));
+
+        assert!(risk.check("ScallopSolver", 1_000, None).is_ok());
+        risk.record_fill("ScallopSolver", 1_000);
+        assert!(matches!(
+            risk.check("ScallopSolver", 600, None),
+            Err(RiskError::ProtocolCap { .. })
+        ));
+
+        // Uncapped protocol is still bound by the daily volume
+        risk.record_fill("StakingSolver", 1_000);
+        assert!(matches!(
+            risk.check("StakingSolver", 600, None),
+            Err(RiskError::DailyVolume { .. })
+        ));
+
+        risk.release_exposure("ScallopSolver", 1_000);
+        assert_eq!(risk.exposure("ScallopSolver"), 0);
+    }
+
+    #[test]
+    fn test_treasury_reserve() {
+        let risk = RiskManager::new(limits(), KillSwitch::default());
+        assert!(risk.check("StakingSolver", 500, Some(600)).is_ok());
+        assert!(matches!(
+            risk.check("StakingSolver", 550, Some(600)),
+            Err(RiskError::ReserveBreached { remaining: 50, .. })
+        ));
+    }
+
+    #[test]
+    fn test_kill_switch_blocks_fills() {
+        let switch = KillSwitch::default();
+        let risk = RiskManager::new(RiskLimits::default(), switch.clone());
+
+        switch.engage("manual halt");
+        assert!(matches!(
+            risk.check("StakingSolver", 1, None),
+            Err(RiskError::KillSwitch(reason)) if reason == "manual halt"
+        ));
+
+        switch.release();
+        assert!(risk.check("StakingSolver", 1, None).is_ok());
+    }
+
+    #[test]
+    fn test_reserve_is_atomic_and_cancellable() {
+        let risk = RiskManager::new(limits(), KillSwitch::default());
+
+        assert!(risk.reserve("ScallopSolver", 1_000, None).is_ok());
+        assert!(risk.reserve("ScallopSolver", 1_000, None).is_err());
+
+        risk.cancel_reservation("ScallopSolver", 1_000);
+        assert_eq!(risk.exposure("ScallopSolver"), 0);
+        assert!(risk.reserve("ScallopSolver", 1_000, None).is_ok());
+    }
+
+    #[test]
+    fn test_concurrent_reserves_respect_treasury_reserve() {
+        let risk = Arc::new(RiskManager::new(limits(), KillSwitch::default()));
+        let barrier = Arc::new(std::sync::Barrier::new(8));
+
+        // Every task sees the same balance: 1_000 - 100 reserve fits 3 x 300
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (risk, barrier) = (risk.clone(), barrier.clone());
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    risk.reserve("StakingSolver", 300, Some(1_000)).is_ok()
+                })
+            })
+            .collect();
+        let reserved = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|ok| *ok)
+            .count();
+        assert_eq!(reserved, 3);
+        assert!(matches!(
+            risk.check("StakingSolver", 300, Some(1_000)),
+            Err(RiskError::ReserveBreached { remaining: 0, .. })
+        ));
+
+        // A failed fill frees its share; a landed one leaves the balance
+        risk.cancel_reservation("StakingSolver", 300);
+        assert!(risk.reserve("StakingSolver", 300, Some(1_000)).is_ok());
+        risk.complete_reservation(300);
+        assert!(risk.check("StakingSolver", 300, Some(700)).is_err());
+        assert!(risk.check("StakingSolver", 300, Some(1_000)).is_ok());
+        assert_eq!(risk.exposure("StakingSolver"), 900);
+    }
+
+    #[test]
+    fn test_runtime_protocol_cap() {
+        let risk = RiskManager::new(limits(), KillSwitch::default());
+        risk.record_fill("ScallopSolver", 1_000);
+
+        risk.set_protocol_cap("ScallopSolver", Some(2_000));
+        assert!(risk.check("ScallopSolver", 600, None).is_ok());
+
+        // Clearing the override restores RISK_PROTOCOL_CAPS
+        risk.set_protocol_cap("ScallopSolver", None);
+        assert_eq!(risk.protocol_cap("ScallopSolver"), Some(1_500));
+        assert!(risk.check("ScallopSolver", 600, None).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Route cost estimates
+//!
+//! What an EvmToSui intent costs end to end: the hook's swap and the CCTP
+//! burn on the source chain, Circle's attestation wait, and the solver's
+//! deposit on Sui. EVM steps are typical gas amounts at the chain's current
+//! gas price. The Sui deposit is dry run when a transaction is given,
+//! otherwise it is estimated from the reference gas price.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use naisu_core::EvmChain;
+use naisu_sui::SuiClient;
+use serde_json::{json, Value};
+
+/// Gas of a USDC swap through the V4 hook, `IntentCreated` included
+pub const EVM_SWAP_GAS: u64 = 180_000;
+
+/// Gas of `depositForBurn` on the TokenMessenger
+pub const CCTP_BURN_GAS: u64 = 120_000;
+
+/// Computation units of a typical deposit PTB
+pub const SUI_DEPOSIT_GAS_UNITS: u64 = 5_000;
+
+/// Net storage of a typical deposit PTB (MIST)
+pub const SUI_DEPOSIT_STORAGE: u64 = 2_000_000;
+
+/// Time until Circle attests a burn on `chain`
+///
+/// CCTP v1 waits for hard finality on the source chain; L2s settle on
+/// Ethereum, so they wait as long as Ethereum does.
+pub fn attestation_wait(chain: EvmChain) -> Duration {
+    match chain {
+        EvmChain::Avalanche => Duration::from_secs(20),
+        EvmChain::Polygon => Duration::from_secs(8 * 60),
+        _ => Duration::from_secs(13 * 60),
+    }
+}
+
+/// Cost of `gas` at `gas_price` (wei), capped at `u64::MAX`
+pub fn evm_gas_cost(gas: u64, gas_price: u128) -> u64 {
+    (gas as u128)
+        .saturating_mul(gas_price)
+        .min(u64::MAX as u128) as u64
+}
+
+/// Deposit gas estimated from the reference gas price (MIST)
+pub fn sui_deposit_estimate(reference_gas_price: u64) -> u64 {
+    SUI_DEPOSIT_GAS_UNITS
+        .saturating_mul(reference_gas_price)
+        .saturating_add(SUI_DEPOSIT_STORAGE)
+}
+
+/// Current gas price of an EVM chain (wei)
+pub async fn evm_gas_price(rpc_url: &str) -> Result<u128> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_gasPrice",
+        "params": [],
+    });
+    let response: Value = reqwest::Client::new()
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await?
+        .json()
+        .await?;
+    if let Some(error) = response.get("error") {
+        bail!("eth_gasPrice failed: {}", error);
+    }
+    response["result"]
+        .as_str()
+        .and_then(|p| u128::from_str_radix(p.strip_prefix("0x")?, 16).ok())
+        .ok_or_else(|| anyhow!("Invalid gas price: {}", response["result"]))
+}
+
+/// Gas of a deposit on Sui (MIST) and whether it came from a dry run
+///
+/// `tx_bytes` (base64 BCS) is dry run when given; a failing dry run is an
+/// error rather than an estimate.
+pub async fn sui_deposit_gas(client: &SuiClient, tx_bytes: Option<&str>) -> Result<(u64, bool)> {
+    let Some(tx_bytes) = tx_bytes else {
+        let price = client.get_reference_gas_price().await?;
+        return Ok((sui_deposit_estimate(price), false));
+    };
+
+    let dry_run = client.dry_run_transaction(tx_bytes).await?;
+    if dry_run.effects.status.status != "success" {
+        bail!("Deposit dry run failed");
+    }
+    let gas = &dry_run.effects.gas_used;
+    let computation: u64 = gas
+        .computation_cost
+        .parse()
+        .context("Invalid computation cost")?;
+    let storage: u64 = gas.storage_cost.parse().context("Invalid storage cost")?;
+    Ok((computation + storage, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimates() {
+        // 180k gas at 2 gwei
+        assert_eq!(
+            evm_gas_cost(EVM_SWAP_GAS, 2_000_000_000),
+            360_000_000_000_000
+        );
+        assert_eq!(evm_gas_cost(CCTP_BURN_GAS, u128::MAX), u64::MAX);
+
+        assert_eq!(sui_deposit_estimate(750), 5_750_000);
+        assert_eq!(
+            attestation_wait(EvmChain::Base),
+            attestation_wait(EvmChain::Ethereum)
+        );
+        assert!(attestation_wait(EvmChain::Avalanche) < Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,326 @@
+//! Winning bid selection
+//!
+//! One policy shared by the daemon (which bid to fulfill) and the API
+//! (previewing the winner for an intent):
+//! - `ranked`: highest APY, then highest confidence, then earliest bid
+//! - `weighted`: `apy_weight * apy / best_apy + confidence_weight * confidence`,
+//!   ties again going to the earliest bid
+//!
+//! Bids below the intent's minimum APY or the confidence floor never win.
+//! "Earliest" is input order, so callers pass bids in arrival order. When
+//! solver reputation scores are supplied, the more reputable solver wins a
+//! tie before arrival order is considered.
+//!
+//! If the winner fails to fulfill, the daemon cascades down the ranking:
+//! up to `max_fallbacks` runners-up get their turn, best first.
+//!
+//! Settings layer as defaults → TOML `[selection]` table → env.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::solver::Bid;
+
+/// How competing bids are ordered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectionStrategy {
+    /// APY, then confidence, then earliest
+    #[default]
+    Ranked,
+    /// Weighted APY/confidence score, then earliest
+    Weighted,
+}
+
+impl FromStr for SelectionStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ranked" => Ok(SelectionStrategy::Ranked),
+            "weighted" => Ok(SelectionStrategy::Weighted),
+            _ => Err(format!("Unknown selection strategy: {}", s)),
+        }
+    }
+}
+
+/// Anything that can compete for an intent
+pub trait Rankable {
+    /// Bidding solver's name
+    fn solver(&self) -> &str;
+    /// Offered APY (basis points)
+    fn apy(&self) -> u64;
+    /// Confidence score (0.0 - 1.0)
+    fn confidence(&self) -> f64;
+}
+
+impl Rankable for Bid {
+    fn solver(&self) -> &str {
+        &self.solver_name
+    }
+
+    fn apy(&self) -> u64 {
+        self.apy
+    }
+
+    fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+/// Selection settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SelectionPolicy {
+    pub strategy: SelectionStrategy,
+    /// Bids less confident than this are ignored (0.0 - 1.0)
+    pub min_confidence: f64,
+    /// Weight of relative APY under `weighted`
+    pub apy_weight: f64,
+    /// Weight of confidence under `weighted`
+    pub confidence_weight: f64,
+    /// Break ties on solver reputation when scores are available
+    pub reputation_tiebreak: bool,
+    /// Runners-up tried, in rank order, when the winner fails to fulfill
+    pub max_fallbacks: usize,
+}
+
+impl Default for SelectionPolicy {
+    fn default() -> Self {
+        Self {
+            strategy: SelectionStrategy::Ranked,
+            min_confidence: 0.0,
+            apy_weight: 0.7,
+            confidence_weight: 0.3,
+            reputation_tiebreak: true,
+            max_fallbacks: 2,
+        }
+    }
+}
+
+impl SelectionPolicy {
+    /// Override with `SELECTION_STRATEGY`, `MIN_BID_CONFIDENCE`,
+    /// `REPUTATION_TIEBREAK` and `MAX_FALLBACKS`
+    pub fn with_env(mut self) -> Self {
+        fn var<T: FromStr>(name: &str) -> Option<T> {
+            std::env::var(name).ok().and_then(|v| v.parse().ok())
+        }
+
+        if let Some(v) = var("SELECTION_STRATEGY") {
+            self.strategy = v;
+        }
+        if let Some(v) = var("MIN_BID_CONFIDENCE") {
+            self.min_confidence = v;
+        }
+        if let Some(v) = var("REPUTATION_TIEBREAK") {
+            self.reputation_tiebreak = v;
+        }
+        if let Some(v) = var("MAX_FALLBACKS") {
+            self.max_fallbacks = v;
+        }
+        self
+    }
+
+    /// Reject floors and weights that can't select anything sensibly
+    pub fn validate(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.min_confidence) {
+            anyhow::bail!("min_confidence must be between 0.0 and 1.0");
+        }
+        if self.apy_weight < 0.0 || self.confidence_weight < 0.0 {
+            anyhow::bail!("selection weights must not be negative");
+        }
+        if self.strategy == SelectionStrategy::Weighted
+            && self.apy_weight + self.confidence_weight == 0.0
+        {
+            anyhow::bail!("weighted selection needs a non-zero weight");
+        }
+        Ok(())
+    }
+
+    fn eligible<T: Rankable>(&self, bid: &T, min_apy: u64) -> bool {
+        bid.apy() >= min_apy && bid.confidence() >= self.min_confidence
+    }
+
+    /// Eligible bids, best first
+    pub fn rank<T: Rankable>(&self, bids: Vec<T>, min_apy: u64) -> Vec<T> {
+        self.rank_with(bids, min_apy, &HashMap::new())
+    }
+
+    /// Eligible bids, best first, breaking ties on solver reputation
+    pub fn rank_with<T: Rankable>(
+        &self,
+        bids: Vec<T>,
+        min_apy: u64,
+        reputation: &HashMap<String, f64>,
+    ) -> Vec<T> {
+        let mut bids: Vec<T> = bids
+            .into_iter()
+            .filter(|b| self.eligible(b, min_apy))
+            .collect();
+        let best_apy = bids.iter().map(Rankable::apy).max().unwrap_or(0).max(1) as f64;
+        let reputation_of = |bid: &T| {
+            if self.reputation_tiebreak {
+                reputation.get(bid.solver()).copied().unwrap_or(0.0)
+            } else {
+                0.0
+            }
+        };
+
+        // Stable sort: equal bids keep arrival order
+        bids.sort_by(|a, b| {
+            let order = match self.strategy {
+                SelectionStrategy::Ranked => b
+                    .apy()
+                    .cmp(&a.apy())
+                    .then(b.confidence().total_cmp(&a.confidence())),
+                SelectionStrategy::Weighted => {
+                    let score = |bid: &T| {
+                        self.apy_weight * bid.apy() as f64 / best_apy
+                            + self.confidence_weight * bid.confidence()
+                    };
+                    score(b).partial_cmp(&score(a)).unwrap_or(Ordering::Equal)
+                }
+            };
+            order.then(reputation_of(b).total_cmp(&reputation_of(a)))
+        });
+        bids
+    }
+
+    /// Bids to try in turn: the winner, then up to `max_fallbacks`
+    /// runners-up
+    pub fn cascade<T: Rankable>(&self, bids: Vec<T>, min_apy: u64) -> Vec<T> {
+        let mut ranked = self.rank(bids, min_apy);
+        ranked.truncate(self.max_fallbacks.saturating_add(1));
+        ranked
+    }
+
+    /// Winning bid, if any is eligible
+    pub fn select<T: Rankable>(&self, bids: Vec<T>, min_apy: u64) -> Option<T> {
+        self.rank(bids, min_apy).into_iter().next()
+    }
+
+    /// Winning bid, breaking ties on solver reputation
+    pub fn select_with<T: Rankable>(
+        &self,
+        bids: Vec<T>,
+        min_apy: u64,
+        reputation: &HashMap<String, f64>,
+    ) -> Option<T> {
+        self.rank_with(bids, min_apy, reputation).into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid(name: &str, apy: u64, confidence: f64) -> Bid {
+        Bid {
+            solver_name: name.to_string(),
+            apy,
+            profit_bps: 20,
+            tip_bps: 0,
+            confidence,
+        }
+    }
+
+    #[test]
+    fn test_ranked_tie_breaking() {
+        let policy = SelectionPolicy::default();
+        let bids = vec![
+            bid("A", 800, 0.8),
+            bid("B", 820, 0.7),
+            bid("C", 820, 0.9),
+            bid("D", 820, 0.9),
+        ];
+
+        let names: Vec<String> = policy
+            .rank(bids, 750)
+            .into_iter()
+            .map(|b| b.solver_name)
+            .collect();
+        assert_eq!(names, vec!["C", "D", "B", "A"]);
+    }
+
+    #[test]
+    fn test_cascade_depth() {
+        let bids = || vec![bid("A", 800, 0.9), bid("B", 820, 0.9), bid("C", 700, 0.9)];
+        let names = |policy: &SelectionPolicy| -> Vec<String> {
+            policy
+                .cascade(bids(), 750)
+                .into_iter()
+                .map(|b| b.solver_name)
+                .collect()
+        };
+        // C is below the user's minimum, so it is never a fallback
+        assert_eq!(names(&SelectionPolicy::default()), vec!["B", "A"]);
+        let policy = SelectionPolicy {
+            max_fallbacks: 0,
+            ..Default::default()
+        };
+        assert_eq!(names(&policy), vec!["B"]);
+    }
+
+    #[test]
+    fn test_confidence_floor() {
+        let policy = SelectionPolicy {
+            min_confidence: 0.85,
+            ..Default::default()
+        };
+        let winner = policy
+            .select(vec![bid("A", 900, 0.5), bid("B", 800, 0.9)], 750)
+            .unwrap();
+        assert_eq!(winner.solver_name, "B");
+        assert!(policy.select(vec![bid("A", 900, 0.5)], 750).is_none());
+    }
+
+    #[test]
+    fn test_reputation_breaks_ties() {
+        let policy = SelectionPolicy::default();
+        let reputation = HashMap::from([("A".to_string(), 40.0), ("B".to_string(), 90.0)]);
+        let bids = vec![bid("A", 820, 0.9), bid("B", 820, 0.9), bid("C", 830, 0.5)];
+
+        let names: Vec<String> = policy
+            .rank_with(bids.clone(), 750, &reputation)
+            .into_iter()
+            .map(|b| b.solver_name)
+            .collect();
+        // Reputation never beats a better APY
+        assert_eq!(names, vec!["C", "B", "A"]);
+
+        let policy = SelectionPolicy {
+            reputation_tiebreak: false,
+            ..Default::default()
+        };
+        let winner = policy
+            .select_with(bids[..2].to_vec(), 750, &reputation)
+            .unwrap();
+        assert_eq!(winner.solver_name, "A");
+    }
+
+    #[test]
+    fn test_weighted_prefers_confident_bid() {
+        let policy = SelectionPolicy {
+            strategy: SelectionStrategy::Weighted,
+            apy_weight: 0.5,
+            confidence_weight: 0.5,
+            ..Default::default()
+        };
+        // 820/820 * 0.5 + 0.5 * 0.5 = 0.75 vs 810/820 * 0.5 + 1.0 * 0.5 ≈ 0.99
+        let winner = policy
+            .select(vec![bid("A", 820, 0.5), bid("B", 810, 1.0)], 750)
+            .unwrap();
+        assert_eq!(winner.solver_name, "B");
+
+        assert!(SelectionPolicy {
+            min_confidence: 1.5,
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
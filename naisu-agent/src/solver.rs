@@ -5,6 +5,15 @@
 
 // Solver implementations are in bots/ module
 
+use crate::config::Protocol;
+use crate::events::IntentEvent;
+use crate::executor::retry::ExecutorError;
+use crate::selection::SelectionPolicy;
+use naisu_core::{RiskTier, Tip};
+use naisu_sui::{FulfillmentOutcome, SuiEvent};
+
+pub use naisu_core::calculate_bid;
+
 /// Solver configuration
 #[derive(Debug, Clone)]
 pub struct SolverConfig {
@@ -12,7 +21,7 @@ pub struct SolverConfig {
     pub name: String,
     /// Minimum profit margin (basis points, e.g., 20 = 0.2%)
     pub min_profit_bps: u16,
-    /// Estimated gas cost (basis points)
+    /// Estimated gas cost (basis points), used until the gas oracle has data
     pub gas_cost_bps: u16,
     /// Maximum slippage tolerance
     pub max_slippage_bps: u16,
@@ -36,8 +45,10 @@ pub struct Bid {
     pub solver_name: String,
     /// Offered APY (basis points, e.g., 750 = 7.5%)
     pub apy: u64,
-    /// Estimated profit for solver (basis points)
+    /// APY spread kept by the solver, after any tip (basis points)
     pub profit_bps: u16,
+    /// Intent tip the bid counts on (basis points of the amount)
+    pub tip_bps: u64,
     /// Confidence score (0.0 - 1.0)
     pub confidence: f64,
 }
@@ -63,7 +74,24 @@ pub trait Solver {
     ///
     /// This is called when the solver wins the bid.
     /// Must execute quickly to win the race.
-    async fn fulfill(&self, intent: &IntentRequest) -> Result<String, SolverError>;
+    async fn fulfill(&self, intent: &IntentRequest) -> Result<FulfillmentOutcome, SolverError>;
+
+    /// Whether `fulfill_batch` can fill several intents in one PTB
+    fn supports_batching(&self) -> bool {
+        false
+    }
+
+    /// Fulfill several won intents in one transaction
+    ///
+    /// All or nothing. Returns one outcome per intent, in order (see
+    /// `batch::split_outcome`).
+    async fn fulfill_batch(
+        &self,
+        intents: &[IntentRequest],
+    ) -> Result<Vec<FulfillmentOutcome>, SolverError> {
+        let _ = intents;
+        Err(SolverError::BatchUnsupported(self.name().to_string()))
+    }
 }
 
 /// Intent request from user
@@ -79,6 +107,73 @@ pub struct IntentRequest {
     pub min_apy: u64,
     /// Deadline timestamp
     pub deadline: u64,
+    /// Protocols the user allows (`None` = any)
+    pub allowed_protocols: Option<Vec<Protocol>>,
+    /// Priority tip paid to the winning solver
+    pub tip: Option<Tip>,
+    /// Riskiest protocol tier the user accepts (`None` = any)
+    pub max_risk: Option<RiskTier>,
+    /// Only stablecoin strategies may fill it
+    pub stable_only: bool,
+}
+
+impl IntentRequest {
+    /// Whether a solver may deploy this intent into `protocol`
+    ///
+    /// The protocol must be allowed, within the user's risk ceiling, and
+    /// stable if the intent is stable-only.
+    pub fn allows(&self, protocol: Protocol) -> bool {
+        self.allowed_protocols
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&protocol))
+            && self.within_risk(protocol.risk_tier())
+            && (!self.stable_only || protocol.is_stable())
+    }
+
+    /// Whether `tier` is within the user's risk ceiling
+    pub fn within_risk(&self, tier: RiskTier) -> bool {
+        self.max_risk.is_none_or(|max| tier <= max)
+    }
+
+    /// Tip as bps of the amount (0 without one)
+    pub fn tip_bps(&self) -> u64 {
+        self.tip.map_or(0, |t| t.bps(self.amount))
+    }
+
+    /// Tip owed to the winning solver (0 without one)
+    pub fn tip_amount(&self) -> u64 {
+        self.tip.map_or(0, |t| t.amount(self.amount))
+    }
+
+    /// Parse an IntentCreated event of any schema version
+    ///
+    /// `None` for other events and malformed payloads (see `events` for
+    /// the details).
+    pub fn from_event(event: &SuiEvent) -> Option<Self> {
+        match IntentEvent::parse(event).ok()? {
+            IntentEvent::Created(created) => Some(created.into()),
+            _ => None,
+        }
+    }
+}
+
+/// Parse an intent's target protocol
+///
+/// Accepts `"any"`, a protocol name, a comma-separated list, or an array of
+/// names. Unknown names are dropped, so a target naming only unknown
+/// protocols allows none.
+pub fn parse_target_protocols(value: &serde_json::Value) -> Option<Vec<Protocol>> {
+    let names: Vec<&str> = match value {
+        serde_json::Value::String(s) => s.split(',').map(str::trim).collect(),
+        serde_json::Value::Array(items) => items.iter().filter_map(|v| v.as_str()).collect(),
+        _ => return None,
+    };
+    let names: Vec<&str> = names.into_iter().filter(|n| !n.is_empty()).collect();
+    if names.is_empty() || names.iter().any(|n| n.eq_ignore_ascii_case("any")) {
+        return None;
+    }
+
+    Some(names.iter().filter_map(|n| n.parse().ok()).collect())
 }
 
 /// Solver errors
@@ -93,48 +188,67 @@ pub enum SolverError {
     #[error("Race lost: another solver won")]
     RaceLost,
 
+    #[error("Shared object contended: {0}")]
+    ObjectContended(String),
+
     #[error("Market data unavailable")]
     MarketDataUnavailable,
+
+    #[error("Pool cannot absorb the deposit: {0}")]
+    InsufficientLiquidity(String),
+
+    #[error("{0} cannot fulfill intents in batches")]
+    BatchUnsupported(String),
+
+    /// The transaction reached the chain but did not fill the intent
+    #[error("Transaction {digest} submitted but not fulfilled: {reason}")]
+    Submitted { digest: String, reason: String },
 }
 
-/// Calculate optimal bid for a solver
-///
-/// Formula: bid_apy = market_apy - solver_profit - gas_cost
-///
-/// # Example
-/// - Market APY: 8.5% (850 bps)
-/// - User min: 7.5% (750 bps)
-/// - Spread: 1.0% (100 bps)
-/// - Gas cost: 0.1% (10 bps)
-/// - Solver profit: 0.2% (20 bps)
-/// - Bid APY: 8.2% (820 bps)
-pub fn calculate_bid(
-    market_apy: u64,     // e.g., 850 (8.5%)
-    user_min: u64,       // e.g., 750 (7.5%)
-    gas_cost_bps: u16,   // e.g., 10 (0.1%)
-    min_profit_bps: u16, // e.g., 20 (0.2%)
-) -> Option<u64> {
-    let spread = market_apy.saturating_sub(user_min);
-    let required = (gas_cost_bps + min_profit_bps) as u64;
-
-    if spread <= required {
-        // Not profitable
-        return None;
+impl SolverError {
+    /// Whether another solver could still fulfill the intent
+    ///
+    /// Only errors raised before submission qualify: once a digest exists
+    /// the intent may already be filled, so a fallback risks filling twice.
+    pub fn allows_fallback(&self) -> bool {
+        !matches!(
+            self,
+            SolverError::IntentUnavailable(_)
+                | SolverError::RaceLost
+                | SolverError::Submitted { .. }
+        )
     }
 
-    // Bid: give user most of the spread, keep small profit
-    let bid_apy = market_apy - min_profit_bps as u64;
-    Some(bid_apy)
+    /// Error for an outcome that landed on chain with a failed status
+    pub fn reverted(outcome: &FulfillmentOutcome) -> Self {
+        SolverError::Submitted {
+            digest: outcome.digest.clone(),
+            reason: format!("{:?}", outcome.status),
+        }
+    }
+
+    /// Map an executor error, keeping shared-object contention distinct
+    pub fn from_executor(err: anyhow::Error) -> Self {
+        match err.downcast_ref::<ExecutorError>() {
+            Some(e @ ExecutorError::ObjectContended { .. }) => {
+                SolverError::ObjectContended(e.to_string())
+            }
+            Some(ExecutorError::Unconfirmed { digest, error, .. }) => SolverError::Submitted {
+                digest: digest.clone(),
+                reason: error.clone(),
+            },
+            None => SolverError::FulfillmentFailed(err.to_string()),
+        }
+    }
 }
 
 /// Select winning bid from multiple solvers
 ///
 /// Winner is the bid with highest APY for user
-/// (as long as it's above user's minimum)
+/// (as long as it's above user's minimum); ties go to the more confident,
+/// then the earlier bid. See `SelectionPolicy` for configurable selection.
 pub fn select_winner(bids: Vec<Bid>, min_apy: u64) -> Option<Bid> {
-    bids.into_iter()
-        .filter(|b| b.apy >= min_apy)
-        .max_by(|a, b| a.apy.cmp(&b.apy))
+    SelectionPolicy::default().select(bids, min_apy)
 }
 
 #[cfg(test)]
@@ -149,7 +263,7 @@ mod tests {
         let gas_cost = 10; // 0.1%
         let profit = 20; // 0.2%
 
-        let bid = calculate_bid(market_apy, user_min, gas_cost, profit);
+        let bid = calculate_bid(market_apy, user_min, gas_cost, profit, 0);
 
         assert!(bid.is_some());
         assert_eq!(bid.unwrap(), 830); // 8.3% (market - profit)
@@ -163,11 +277,94 @@ mod tests {
         let gas_cost = 10;
         let profit = 20;
 
-        let bid = calculate_bid(market_apy, user_min, gas_cost, profit);
+        let bid = calculate_bid(market_apy, user_min, gas_cost, profit, 0);
 
         assert!(bid.is_none()); // Not worth it
     }
 
+    #[test]
+    fn test_tip_adjusted_bid() {
+        // Spread: 0.1%, too small alone; a 0.25% tip covers gas and profit
+        assert_eq!(calculate_bid(800, 790, 10, 20, 25), Some(800));
+        // A smaller tip covers part of the profit margin
+        assert_eq!(calculate_bid(850, 750, 10, 20, 5), Some(835));
+        // No tip makes a below-minimum market acceptable
+        assert_eq!(calculate_bid(700, 750, 10, 20, 500), None);
+
+        let intent = IntentRequest {
+            id: "0x1".to_string(),
+            user: "0x2".to_string(),
+            amount: 2_000_000,
+            min_apy: 500,
+            deadline: 0,
+            allowed_protocols: None,
+            tip: Some(Tip::Flat(5_000)),
+            max_risk: None,
+            stable_only: false,
+        };
+        assert_eq!(intent.tip_bps(), 25);
+        assert_eq!(intent.tip_amount(), 5_000);
+    }
+
+    #[test]
+    fn test_parse_target_protocols() {
+        use serde_json::json;
+
+        assert_eq!(parse_target_protocols(&json!("any")), None);
+        assert_eq!(parse_target_protocols(&json!("")), None);
+        assert_eq!(
+            parse_target_protocols(&json!("scallop")),
+            Some(vec![Protocol::Scallop])
+        );
+        assert_eq!(
+            parse_target_protocols(&json!(["navi", "native_staking"])),
+            Some(vec![Protocol::Navi, Protocol::NativeStaking])
+        );
+        assert_eq!(parse_target_protocols(&json!("aave")), Some(vec![]));
+
+        let intent = IntentRequest {
+            id: "0x1".to_string(),
+            user: "0x2".to_string(),
+            amount: 1_000,
+            min_apy: 500,
+            deadline: 0,
+            allowed_protocols: parse_target_protocols(&json!("scallop, navi")),
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+        };
+        assert!(intent.allows(Protocol::Navi));
+        assert!(!intent.allows(Protocol::NativeStaking));
+    }
+
+    #[test]
+    fn test_risk_ceiling() {
+        let mut intent = IntentRequest {
+            id: "0x1".to_string(),
+            user: "0x2".to_string(),
+            amount: 1_000,
+            min_apy: 500,
+            deadline: 0,
+            allowed_protocols: None,
+            tip: None,
+            max_risk: Some(RiskTier::Medium),
+            stable_only: false,
+        };
+        assert!(intent.allows(Protocol::NativeStaking));
+        assert!(intent.allows(Protocol::Scallop));
+        assert!(!intent.allows(Protocol::Cetus));
+
+        intent.max_risk = Some(RiskTier::Low);
+        assert!(!intent.allows(Protocol::Navi));
+
+        intent.max_risk = None;
+        assert!(intent.allows(Protocol::Cetus));
+
+        intent.stable_only = true;
+        assert!(!intent.allows(Protocol::Cetus));
+        assert!(!intent.allows(Protocol::NativeStaking));
+    }
+
     #[test]
     fn test_select_winner() {
         let bids = vec![
@@ -175,18 +372,21 @@ mod tests {
                 solver_name: "A".to_string(),
                 apy: 820,
                 profit_bps: 30,
+                tip_bps: 0,
                 confidence: 0.9,
             },
             Bid {
                 solver_name: "B".to_string(),
                 apy: 800,
                 profit_bps: 20,
+                tip_bps: 0,
                 confidence: 0.8,
             },
             Bid {
                 solver_name: "C".to_string(),
                 apy: 810,
                 profit_bps: 25,
+                tip_bps: 0,
                 confidence: 0.85,
             },
         ];
@@ -196,4 +396,33 @@ mod tests {
         assert!(winner.is_some());
         assert_eq!(winner.unwrap().solver_name, "A"); // Highest APY
     }
+
+    #[test]
+    fn test_submitted_errors_do_not_fall_back() {
+        assert!(SolverError::FulfillmentFailed("dry run failed".to_string()).allows_fallback());
+        assert!(SolverError::InsufficientLiquidity("pool".to_string()).allows_fallback());
+
+        let outcome = FulfillmentOutcome {
+            digest: "0xabc".to_string(),
+            created_objects: vec![],
+            gas_used: Default::default(),
+            status: naisu_sui::ExecutionStatus::Failure {
+                error: "MoveAbort".to_string(),
+            },
+        };
+        let reverted = SolverError::reverted(&outcome);
+        assert!(matches!(&reverted, SolverError::Submitted { digest, .. } if digest == "0xabc"));
+        assert!(!reverted.allows_fallback());
+
+        let unconfirmed = SolverError::from_executor(
+            ExecutorError::Unconfirmed {
+                label: "Staking".to_string(),
+                digest: "0xdef".to_string(),
+                error: "missing effects".to_string(),
+            }
+            .into(),
+        );
+        assert!(matches!(&unconfirmed, SolverError::Submitted { digest, .. } if digest == "0xdef"));
+        assert!(!unconfirmed.allows_fallback());
+    }
 }
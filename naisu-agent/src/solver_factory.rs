@@ -1,59 +1,133 @@
 //! Solver Factory
 //!
-//! Creates appropriate solvers based on network configuration.
-//! Supports both Testnet and Mainnet with different protocol availability.
+//! Builds the registered solvers (see `registry`) that run on a network.
+//! Solver tuning, validators and protocol overrides come from `AgentConfig`,
+//! which can also restrict a network to a subset of solvers.
+//! Probed availability (see `availability`) overrides the static flags.
 
-use crate::bots::StakingSolver;
-use crate::config::{Network, Protocol};
-use crate::solver::{Solver, SolverError};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::config::{AgentConfig, Network, Protocol};
+use crate::gas::GasOracle;
+use crate::registry::{BoxedSolver, SolverContext, SolverRegistry};
+use crate::solver::SolverError;
 
 /// Factory for creating solvers based on network
 pub struct SolverFactory {
     network: Network,
+    config: AgentConfig,
+    registry: Arc<SolverRegistry>,
+    /// Probed availability; protocols missing here use `Protocol::is_available`
+    availability: HashMap<Protocol, bool>,
+    /// Shared with every solver built
+    gas: Arc<GasOracle>,
 }
 
 impl SolverFactory {
     /// Create new solver factory for specific network
     pub fn new(network: Network) -> Self {
-        Self { network }
+        Self::with_config(network, AgentConfig::defaults())
     }
 
-    /// Get all available solvers for current network
-    pub fn create_solvers(&self) -> Vec<Box<dyn Solver + Send + Sync>> {
-        // Native staking works on all networks
-        let solvers: Vec<Box<dyn Solver + Send + Sync>> = vec![Box::new(StakingSolver::new())];
+    /// Create a factory that applies the given agent config
+    pub fn with_config(network: Network, config: AgentConfig) -> Self {
+        Self {
+            network,
+            config,
+            registry: SolverRegistry::builtin(),
+            availability: HashMap::new(),
+            gas: Arc::default(),
+        }
+    }
 
-        // Add network-specific solvers
-        match self.network {
-            Network::Testnet => {
-                // Testnet: Only staking and DeepBook
-                // DeepBook solver would be added here when implemented
-                tracing::info!("Testnet mode: Staking + DeepBook (when implemented)");
-            }
-            Network::Mainnet => {
-                // Mainnet: All protocols
-                // TODO: Add CetusSolver, ScallopSolver, NaviSolver when implemented
-                tracing::info!("Mainnet mode: Full protocol suite (when implemented)");
+    /// Build from a custom registry instead of the built-in bots
+    pub fn with_registry(mut self, registry: Arc<SolverRegistry>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Use probed protocol availability
+    pub fn with_availability(mut self, availability: HashMap<Protocol, bool>) -> Self {
+        self.availability = availability;
+        self
+    }
+
+    /// Bid with live gas costs from `gas`
+    pub fn with_gas_oracle(mut self, gas: Arc<GasOracle>) -> Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Probed availability, falling back to the static flag
+    pub fn is_available(&self, protocol: Protocol) -> bool {
+        self.availability
+            .get(&protocol)
+            .copied()
+            .unwrap_or_else(|| protocol.is_available(self.network))
+    }
+
+    /// Build every registered solver for this network that config enables
+    pub fn create_solvers(&self) -> Vec<BoxedSolver> {
+        if let Some(listed) = &self.config.network(self.network).solvers {
+            for name in listed {
+                if self.registry.get(name).is_none() {
+                    tracing::warn!("Unknown solver `{}` in config, ignoring", name);
+                }
             }
         }
 
-        solvers
+        self.registry
+            .for_network(self.network)
+            .filter(|d| {
+                // Only skip protocols a probe found missing
+                let probed_down = d
+                    .protocols
+                    .iter()
+                    .any(|p| self.availability.get(p) == Some(&false));
+                if probed_down {
+                    tracing::info!(
+                        "{} unavailable on {}, skipping",
+                        d.name,
+                        self.network.name()
+                    );
+                }
+                !probed_down
+            })
+            .filter(|d| {
+                let gated = d.experimental && !self.config.features.new_solvers;
+                if gated {
+                    tracing::info!("{} is experimental, enable new_solvers to run it", d.name);
+                }
+                !gated
+            })
+            .filter(|d| {
+                let enabled = self.config.solver_enabled(d.name, self.network);
+                if !enabled {
+                    tracing::info!("{} disabled by config", d.name);
+                }
+                enabled
+            })
+            .map(|d| d.build_with(&self.context()))
+            .collect()
     }
 
     /// Get solver for specific protocol
     pub fn create_solver_for_protocol(
         &self,
         protocol: Protocol,
-    ) -> Result<Box<dyn Solver + Send + Sync>, SolverError> {
-        // Check if protocol is supported on this network
-        let supported = self.network.supported_protocols();
-        if !supported.contains(&protocol) {
-            return Err(SolverError::MarketDataUnavailable);
-        }
+    ) -> Result<BoxedSolver, SolverError> {
+        self.registry
+            .for_protocol(protocol, self.network)
+            .map(|d| d.build_with(&self.context()))
+            .ok_or(SolverError::MarketDataUnavailable)
+    }
 
-        match protocol {
-            Protocol::NativeStaking => Ok(Box::new(StakingSolver::new())),
-            _ => Err(SolverError::MarketDataUnavailable),
+    fn context(&self) -> SolverContext<'_> {
+        SolverContext {
+            network: self.network,
+            config: &self.config,
+            gas: self.gas.clone(),
         }
     }
 
@@ -72,30 +146,54 @@ impl SolverFactory {
 pub struct MultiNetworkSolver {
     testnet_factory: SolverFactory,
     mainnet_factory: SolverFactory,
+    /// Used to build factories for dev networks on demand
+    config: AgentConfig,
 }
 
 impl MultiNetworkSolver {
     /// Create new multi-network solver
     pub fn new() -> Self {
+        Self::with_config(AgentConfig::defaults())
+    }
+
+    /// Create factories for every network from one agent config
+    pub fn with_config(config: AgentConfig) -> Self {
         Self {
-            testnet_factory: SolverFactory::new(Network::Testnet),
-            mainnet_factory: SolverFactory::new(Network::Mainnet),
+            testnet_factory: SolverFactory::with_config(Network::Testnet, config.clone()),
+            mainnet_factory: SolverFactory::with_config(Network::Mainnet, config.clone()),
+            config,
         }
     }
 
     /// Get solvers for specific network
-    pub fn get_solvers(&self, network: Network) -> Vec<Box<dyn Solver + Send + Sync>> {
+    pub fn get_solvers(&self, network: Network) -> Vec<BoxedSolver> {
+        match self.get_factory(network) {
+            Some(factory) => factory.create_solvers(),
+            None => SolverFactory::with_config(network, self.config.clone()).create_solvers(),
+        }
+    }
+
+    /// Apply probed availability to a network's factory
+    pub fn set_availability(&mut self, network: Network, availability: HashMap<Protocol, bool>) {
         match network {
-            Network::Testnet => self.testnet_factory.create_solvers(),
-            Network::Mainnet => self.mainnet_factory.create_solvers(),
+            Network::Testnet => {
+                self.testnet_factory = SolverFactory::with_config(network, self.config.clone())
+                    .with_availability(availability)
+            }
+            Network::Mainnet => {
+                self.mainnet_factory = SolverFactory::with_config(network, self.config.clone())
+                    .with_availability(availability)
+            }
+            _ => {}
         }
     }
 
-    /// Get factory for specific network
-    pub fn get_factory(&self, network: Network) -> &SolverFactory {
+    /// Get factory for specific network (testnet and mainnet only)
+    pub fn get_factory(&self, network: Network) -> Option<&SolverFactory> {
         match network {
-            Network::Testnet => &self.testnet_factory,
-            Network::Mainnet => &self.mainnet_factory,
+            Network::Testnet => Some(&self.testnet_factory),
+            Network::Mainnet => Some(&self.mainnet_factory),
+            _ => None,
         }
     }
 }
@@ -142,4 +240,84 @@ mod tests {
         assert!(!testnet_solvers.is_empty());
         assert!(!mainnet_solvers.is_empty());
     }
+
+    #[test]
+    fn test_disabled_solver_is_skipped() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [solvers.DeepBookSolver]
+            enabled = false
+            "#,
+        )
+        .unwrap();
+        let multi = MultiNetworkSolver::with_config(config);
+
+        let names: Vec<String> = multi
+            .get_solvers(Network::Testnet)
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["StakingSolver"]);
+        assert_eq!(multi.get_solvers(Network::Mainnet).len(), 4);
+    }
+
+    #[test]
+    fn test_probed_unavailable_protocol_is_skipped() {
+        let factory = SolverFactory::new(Network::Mainnet).with_availability(HashMap::from([
+            (Protocol::Navi, false),
+            (Protocol::Cetus, true),
+        ]));
+
+        let solvers = factory.create_solvers();
+        let names: Vec<&str> = solvers.iter().map(|s| s.name()).collect();
+        assert!(!names.contains(&"NaviSolver"));
+        assert!(names.contains(&"CetusSolver"));
+        assert!(!factory.is_available(Protocol::Navi));
+        // Unprobed protocols keep the static flag
+        assert!(factory.is_available(Protocol::Scallop));
+    }
+
+    #[test]
+    fn test_network_solver_list() {
+        let config = AgentConfig::from_toml_str(
+            r#"
+            [networks.mainnet]
+            solvers = ["ScallopSolver", "NaviSolver", "NoSuchSolver"]
+
+            [solvers.NaviSolver]
+            enabled = false
+            "#,
+        )
+        .unwrap();
+        let factory = SolverFactory::with_config(Network::Mainnet, config);
+
+        let solvers = factory.create_solvers();
+        let names: Vec<&str> = solvers.iter().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["ScallopSolver"]);
+    }
+
+    #[test]
+    fn test_experimental_solver_needs_feature_flag() {
+        let names = |config: AgentConfig| -> Vec<String> {
+            SolverFactory::with_config(Network::Testnet, config)
+                .create_solvers()
+                .iter()
+                .map(|s| s.name().to_string())
+                .collect()
+        };
+
+        assert_eq!(names(AgentConfig::defaults()), vec!["StakingSolver"]);
+
+        let mut config = AgentConfig::defaults();
+        config.features.new_solvers = true;
+        assert_eq!(names(config), vec!["StakingSolver", "DeepBookSolver"]);
+    }
+
+    #[test]
+    fn test_dev_network_gets_staking_only() {
+        let multi = MultiNetworkSolver::new();
+        let solvers = multi.get_solvers(Network::Localnet);
+        assert_eq!(solvers.len(), 1);
+        assert_eq!(solvers[0].name(), "StakingSolver");
+    }
 }
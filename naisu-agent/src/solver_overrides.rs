@@ -0,0 +1,97 @@
+//! Runtime solver tuning
+//!
+//! Operators adjust `[solvers.<name>]` settings through the admin API
+//! (`PUT /api/v1/admin/solvers/{name}/config`). The API keeps them as
+//! `SolverOverrides`; daemons poll `GET /api/v1/admin/solvers/config` with
+//! the kill switch and layer them over their shared config, which rebuilds
+//! the solvers without a restart.
+//!
+//! Overrides only ever set fields: a field left out of an update keeps its
+//! current value. They are layered again after a config file reload.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AgentConfig, SolverTuning};
+
+/// Solver tuning set through the API, by solver name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolverOverrides {
+    /// Bumped on every update
+    pub version: u64,
+    pub solvers: BTreeMap<String, SolverTuning>,
+}
+
+impl SolverOverrides {
+    /// Layer `tuning` over a solver's overrides; returns the result
+    pub fn set(&mut self, solver: &str, tuning: &SolverTuning) -> SolverTuning {
+        let entry = self.solvers.entry(solver.to_string()).or_default();
+        entry.overlay(tuning);
+        self.version += 1;
+        entry.clone()
+    }
+
+    /// Layer the overrides over a config's `[solvers]`
+    pub fn apply(&self, config: &mut AgentConfig) {
+        self.layer(&mut config.solvers);
+    }
+
+    /// Whether `config` already has every override in force
+    pub fn is_applied(&self, config: &AgentConfig) -> bool {
+        let mut tuned = config.solvers.clone();
+        self.layer(&mut tuned);
+        tuned == config.solvers
+    }
+
+    fn layer(&self, solvers: &mut BTreeMap<String, SolverTuning>) {
+        for (name, tuning) in &self.solvers {
+            solvers.entry(name.clone()).or_default().overlay(tuning);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overrides_layer_over_config() {
+        let mut config = AgentConfig::defaults();
+        config.solvers.insert(
+            "StakingSolver".to_string(),
+            SolverTuning {
+                min_profit_bps: Some(20),
+                gas_cost_bps: Some(15),
+                ..Default::default()
+            },
+        );
+
+        let mut overrides = SolverOverrides::default();
+        overrides.set(
+            "StakingSolver",
+            &SolverTuning {
+                min_profit_bps: Some(40),
+                ..Default::default()
+            },
+        );
+        let navi = overrides.set(
+            "NaviSolver",
+            &SolverTuning {
+                enabled: Some(false),
+                ..Default::default()
+            },
+        );
+        assert_eq!(overrides.version, 2);
+        assert_eq!(navi.enabled, Some(false));
+        assert!(!overrides.is_applied(&config));
+
+        overrides.apply(&mut config);
+        let staking = config.solver("StakingSolver");
+        assert_eq!(staking.min_profit_bps, Some(40));
+        // Fields the override leaves out keep the file's value
+        assert_eq!(staking.gas_cost_bps, Some(15));
+        assert!(!config.solver("NaviSolver").is_enabled());
+        assert!(overrides.is_applied(&config));
+    }
+}
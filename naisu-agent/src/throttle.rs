@@ -0,0 +1,157 @@
+//! Submission throttling
+//!
+//! A burst of intents won by one solver would otherwise fire several PTBs
+//! from the same wallet at once, and concurrent transactions spending the
+//! same gas coin or shared objects fail with equivocation or locked-object
+//! errors. The daemon therefore:
+//! - caps how many fulfillments each solver has in flight,
+//! - queues every transaction of a network's wallet, one at a time,
+//! - waits `submit_interval_ms` between submissions.
+//!
+//! Settings layer as `[throttle]` → `[networks.<name>.throttle]`; a
+//! solver's `max_in_flight` in `[solvers.<name>]` overrides the default.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Deserialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// `[throttle]` section of the agent config
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThrottleConfig {
+    /// Fulfillments one solver may have in flight
+    pub max_in_flight_per_solver: usize,
+    /// Pause between two transactions from the wallet
+    pub submit_interval_ms: u64,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight_per_solver: 2,
+            submit_interval_ms: 500,
+        }
+    }
+}
+
+impl ThrottleConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_in_flight_per_solver == 0 {
+            return Err("max_in_flight_per_solver must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Per-solver slots and the wallet's submission queue for one network
+pub struct Throttle {
+    config: ThrottleConfig,
+    /// Per-solver `max_in_flight` overrides
+    limits: HashMap<String, usize>,
+    slots: Mutex<HashMap<String, Arc<Semaphore>>>,
+    /// Held while a transaction is submitted; when the last one finished
+    wallet: tokio::sync::Mutex<Option<Instant>>,
+}
+
+impl Throttle {
+    pub fn new(config: ThrottleConfig) -> Self {
+        Self {
+            config,
+            limits: HashMap::new(),
+            slots: Mutex::new(HashMap::new()),
+            wallet: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// In-flight limit for one solver
+    pub fn with_solver_limit(mut self, solver: &str, limit: usize) -> Self {
+        self.limits.insert(solver.to_string(), limit.max(1));
+        self
+    }
+
+    /// In-flight limit applied to `solver`
+    pub fn solver_limit(&self, solver: &str) -> usize {
+        self.limits
+            .get(solver)
+            .copied()
+            .unwrap_or(self.config.max_in_flight_per_solver)
+    }
+
+    /// Wait for one of `solver`'s in-flight slots; held until dropped
+    pub async fn acquire(&self, solver: &str) -> OwnedSemaphorePermit {
+        let slots = self
+            .slots
+            .lock()
+            .expect("throttle lock poisoned")
+            .entry(solver.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.solver_limit(solver))))
+            .clone();
+        slots
+            .acquire_owned()
+            .await
+            .expect("solver slots are never closed")
+    }
+
+    /// Free slots of `solver` right now
+    pub fn available(&self, solver: &str) -> usize {
+        self.slots
+            .lock()
+            .expect("throttle lock poisoned")
+            .get(solver)
+            .map(|s| s.available_permits())
+            .unwrap_or_else(|| self.solver_limit(solver))
+    }
+
+    /// Run a wallet transaction once the ones queued before it are done
+    /// and the submission interval has passed
+    pub async fn submit<T>(&self, transaction: impl Future<Output = T>) -> T {
+        let mut last = self.wallet.lock().await;
+        if let Some(finished) = *last {
+            let interval = Duration::from_millis(self.config.submit_interval_ms);
+            tokio::time::sleep_until(finished + interval).await;
+        }
+        let result = transaction.await;
+        *last = Some(Instant::now());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_submissions_are_paced() {
+        let throttle = Throttle::new(ThrottleConfig {
+            max_in_flight_per_solver: 2,
+            submit_interval_ms: 50,
+        });
+        let (a, b) = tokio::join!(
+            throttle.submit(async { Instant::now() }),
+            throttle.submit(async { Instant::now() }),
+        );
+        let gap = if a < b { b - a } else { a - b };
+        assert!(gap >= Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_solver_slots() {
+        let throttle =
+            Throttle::new(ThrottleConfig::default()).with_solver_limit("ScallopSolver", 1);
+        assert_eq!(throttle.solver_limit("StakingSolver"), 2);
+
+        let held = throttle.acquire("ScallopSolver").await;
+        assert_eq!(throttle.available("ScallopSolver"), 0);
+        // Other solvers are unaffected
+        let _staking = throttle.acquire("StakingSolver").await;
+        assert_eq!(throttle.available("StakingSolver"), 1);
+
+        drop(held);
+        assert_eq!(throttle.available("ScallopSolver"), 1);
+    }
+}
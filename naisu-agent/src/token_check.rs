@@ -0,0 +1,260 @@
+//! EVM input token checks
+//!
+//! EvmToSui intents swap an arbitrary ERC-20 into USDC, and the amounts
+//! around that swap assume the token moves exactly what is sent and that
+//! its base units are known. `TokenChecker` reads a token's `decimals()`
+//! and `symbol()` and flags fee-on-transfer tokens, which deliver less
+//! than the swap was quoted for.
+//!
+//! Fee-on-transfer can't be read off a token directly; the heuristic calls
+//! the fee getters common fee tokens expose and flags any that returns a
+//! non-zero fee. Tokens hiding their fee slip through.
+
+use std::collections::HashMap;
+
+use naisu_core::{from_decimal, to_decimal, USDC_DECIMALS};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::sync::RwLock;
+
+use crate::ens::decode_string;
+use crate::executor::evm_executor::selector;
+use crate::executor::signer::{hex_decode, hex_encode};
+
+/// Most decimals an input token may have
+pub const MAX_DECIMALS: u8 = 36;
+
+/// Getters fee-on-transfer tokens commonly expose
+pub const FEE_GETTERS: [&str; 8] = [
+    "_taxFee()",
+    "taxFee()",
+    "_liquidityFee()",
+    "transferFee()",
+    "_transferFee()",
+    "buyTax()",
+    "sellTax()",
+    "totalFees()",
+];
+
+/// What an intent needs to know about its input token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenMetadata {
+    pub address: String,
+    /// `None` if `symbol()` is missing or not a string
+    pub symbol: Option<String>,
+    pub decimals: u8,
+    /// Fee getter that returned a non-zero fee
+    pub fee_getter: Option<String>,
+}
+
+impl TokenMetadata {
+    pub fn is_fee_on_transfer(&self) -> bool {
+        self.fee_getter.is_some()
+    }
+
+    fn label(&self) -> String {
+        self.symbol.clone().unwrap_or_else(|| self.address.clone())
+    }
+
+    /// Rejects tokens intents can't take
+    pub fn check(&self) -> Result<(), TokenCheckError> {
+        if let Some(getter) = &self.fee_getter {
+            return Err(TokenCheckError::FeeOnTransfer {
+                token: self.label(),
+                getter: getter.clone(),
+            });
+        }
+        if self.decimals > MAX_DECIMALS {
+            return Err(TokenCheckError::UnsupportedDecimals {
+                token: self.label(),
+                decimals: self.decimals,
+            });
+        }
+        Ok(())
+    }
+
+    /// USDC (base units) `amount` of this token is worth at `usd_price`
+    pub fn expected_usdc(&self, amount: u64, usd_price: f64) -> Result<u64, TokenCheckError> {
+        self.check()?;
+        let value = to_decimal(amount, self.decimals as u32) * usd_price;
+        Ok(from_decimal(value, USDC_DECIMALS))
+    }
+}
+
+/// Reads and caches token metadata through EVM JSON-RPC endpoints
+#[derive(Default)]
+pub struct TokenChecker {
+    client: reqwest::Client,
+    /// By RPC URL and lowercased token address
+    cache: RwLock<HashMap<(String, String), TokenMetadata>>,
+}
+
+impl TokenChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Metadata of `token` on the chain behind `rpc_url`
+    ///
+    /// Cached once read, so fees switched on later are not seen until
+    /// restart.
+    pub async fn metadata(
+        &self,
+        rpc_url: &str,
+        token: &str,
+    ) -> Result<TokenMetadata, TokenCheckError> {
+        let key = (rpc_url.to_string(), token.trim().to_lowercase());
+        if let Some(meta) = self.cache.read().await.get(&key) {
+            return Ok(meta.clone());
+        }
+
+        let call = |signature: &str| format!("0x{}", hex_encode(&selector(signature)));
+        let decimals = self
+            .call(rpc_url, &key.1, &call("decimals()"))
+            .await?
+            .ok_or_else(|| TokenCheckError::NotAToken(key.1.clone()))?;
+        let decimals = decode_uint(&decimals)
+            .filter(|d| *d <= u8::MAX as u128)
+            .ok_or_else(|| TokenCheckError::NotAToken(key.1.clone()))? as u8;
+
+        // bytes32 symbols (e.g. MKR) decode as errors and are left out
+        let symbol = match self.call(rpc_url, &key.1, &call("symbol()")).await? {
+            Some(data) => decode_string(&data).ok().flatten(),
+            None => None,
+        };
+
+        let mut fee_getter = None;
+        for getter in FEE_GETTERS {
+            let data = self.call(rpc_url, &key.1, &call(getter)).await?;
+            if data
+                .and_then(|d| decode_uint(&d))
+                .is_some_and(|fee| fee > 0)
+            {
+                fee_getter = Some(getter.to_string());
+                break;
+            }
+        }
+
+        let meta = TokenMetadata {
+            address: key.1.clone(),
+            symbol,
+            decimals,
+            fee_getter,
+        };
+        self.cache.write().await.insert(key, meta.clone());
+        Ok(meta)
+    }
+
+    /// Return data of an `eth_call`; `None` if it reverted or returned nothing
+    async fn call(
+        &self,
+        rpc_url: &str,
+        to: &str,
+        data: &str,
+    ) -> Result<Option<Vec<u8>>, TokenCheckError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_call",
+            "params": [{ "to": to, "data": data }, "latest"],
+        });
+        let response: Value = self
+            .client
+            .post(rpc_url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| TokenCheckError::Rpc(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| TokenCheckError::Rpc(e.to_string()))?;
+
+        if response.get("error").is_some() {
+            return Ok(None);
+        }
+        let result = response["result"].as_str().unwrap_or_default();
+        let data = hex_decode(result.strip_prefix("0x").unwrap_or(result))
+            .ok_or_else(|| TokenCheckError::Rpc(format!("eth_call returned {}", result)))?;
+        Ok(Some(data).filter(|d| !d.is_empty()))
+    }
+}
+
+/// First ABI word as an integer; `None` if short or above `u128`
+fn decode_uint(data: &[u8]) -> Option<u128> {
+    let word = data.get(..32)?;
+    if word[..16].iter().any(|&b| b != 0) {
+        return None;
+    }
+    Some(
+        word[16..]
+            .iter()
+            .fold(0u128, |acc, &b| (acc << 8) | b as u128),
+    )
+}
+
+/// Token check errors
+#[derive(Debug, thiserror::Error)]
+pub enum TokenCheckError {
+    #[error("{0} is not an ERC-20 token (no decimals())")]
+    NotAToken(String),
+
+    #[error("{token} charges a transfer fee ({getter}); fee-on-transfer tokens are not supported")]
+    FeeOnTransfer { token: String, getter: String },
+
+    #[error("{token} has {decimals} decimals; at most {MAX_DECIMALS} are supported")]
+    UnsupportedDecimals { token: String, decimals: u8 },
+
+    #[error("EVM RPC failed: {0}")]
+    Rpc(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(decimals: u8) -> TokenMetadata {
+        TokenMetadata {
+            address: "0x6b175474e89094c44da98b954eedeac495271d0f".to_string(),
+            symbol: Some("DAI".to_string()),
+            decimals,
+            fee_getter: None,
+        }
+    }
+
+    #[test]
+    fn test_expected_usdc() {
+        // 2.5 DAI (18 decimals) is 2.5 USDC (6 decimals), not 2.5e12
+        let dai = token(18);
+        assert_eq!(
+            dai.expected_usdc(2_500_000_000_000_000_000, 1.0).unwrap(),
+            2_500_000
+        );
+        assert_eq!(token(6).expected_usdc(2_500_000, 1.0).unwrap(), 2_500_000);
+        assert_eq!(
+            token(8).expected_usdc(10_000_000, 60_000.0).unwrap(),
+            6_000_000_000
+        );
+
+        let fee = TokenMetadata {
+            fee_getter: Some("_taxFee()".to_string()),
+            ..dai
+        };
+        assert!(fee.is_fee_on_transfer());
+        assert!(matches!(
+            fee.expected_usdc(1, 1.0),
+            Err(TokenCheckError::FeeOnTransfer { .. })
+        ));
+        assert!(matches!(
+            token(77).check(),
+            Err(TokenCheckError::UnsupportedDecimals { decimals: 77, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_uint() {
+        let word = hex_decode(&format!("{:0>64}", "12")).unwrap();
+        assert_eq!(decode_uint(&word), Some(18));
+        assert_eq!(decode_uint(&[0u8; 31]), None);
+        assert_eq!(decode_uint(&[0xff; 32]), None);
+    }
+}
@@ -0,0 +1,226 @@
+//! Intent bookkeeping for the daemon
+//!
+//! Every poll returns the latest IntentCreated events, so the daemon sees
+//! the same intent again and again. The tracker hands each intent to at
+//! most one fulfillment task per process: the task claims it up front and
+//! it stays processed afterwards, whether the task filled it, failed or
+//! panicked. Dropping the claim clears the in-flight mark, so a crashed
+//! task never leaves an intent stuck.
+//!
+//! `OpenIntents` follows which intents are still open on chain, from the
+//! IntentFulfilled and IntentCancelled events, so the daemon can drop an
+//! intent someone else closed while it was still bidding.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    processed: HashSet<String>,
+    in_flight: HashSet<String>,
+}
+
+/// Intents this process has handled, shared with fulfillment tasks
+#[derive(Debug, Clone, Default)]
+pub struct IntentTracker {
+    state: Arc<Mutex<TrackerState>>,
+}
+
+impl IntentTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, TrackerState> {
+        self.state.lock().expect("intent tracker lock poisoned")
+    }
+
+    /// Whether an intent has not been handled yet
+    pub fn is_new(&self, intent_id: &str) -> bool {
+        !self.state().processed.contains(intent_id)
+    }
+
+    /// Mark an intent handled without running a task (e.g. no bids)
+    pub fn mark_processed(&self, intent_id: impl Into<String>) {
+        self.state().processed.insert(intent_id.into());
+    }
+
+    /// Claim intents for one task
+    ///
+    /// `None` if any of them was already handled; nothing is claimed then.
+    pub fn claim(&self, intent_ids: Vec<String>) -> Option<Claim> {
+        let mut state = self.state();
+        if intent_ids.iter().any(|id| state.processed.contains(id)) {
+            return None;
+        }
+        state.processed.extend(intent_ids.iter().cloned());
+        state.in_flight.extend(intent_ids.iter().cloned());
+        drop(state);
+
+        Some(Claim {
+            intent_ids,
+            tracker: self.clone(),
+        })
+    }
+
+    /// Intents with a running task
+    pub fn in_flight(&self) -> usize {
+        self.state().in_flight.len()
+    }
+}
+
+/// Intents owned by a running task; released from flight on drop
+#[derive(Debug)]
+pub struct Claim {
+    intent_ids: Vec<String>,
+    tracker: IntentTracker,
+}
+
+impl Claim {
+    pub fn intent_ids(&self) -> &[String] {
+        &self.intent_ids
+    }
+}
+
+impl Drop for Claim {
+    fn drop(&mut self) {
+        let mut state = self.tracker.state();
+        for id in &self.intent_ids {
+            state.in_flight.remove(id);
+        }
+    }
+}
+
+// ─── Open Set ────────────────────────────────────────────────────────────────
+
+/// How an intent stopped being open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Closure {
+    Fulfilled {
+        solver: String,
+    },
+    /// 0 = expired, 1 = cancelled by the user
+    Cancelled {
+        reason: u8,
+    },
+}
+
+impl std::fmt::Display for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Closure::Fulfilled { solver } => write!(f, "fulfilled by {}", solver),
+            Closure::Cancelled { reason: 0 } => write!(f, "expired"),
+            Closure::Cancelled { .. } => write!(f, "cancelled"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct OpenState {
+    open: HashSet<String>,
+    closed: HashMap<String, Closure>,
+}
+
+/// Intents open on chain, shared with fulfillment tasks
+///
+/// Events are polled by type, so a closure can arrive before the creation
+/// it closes. Closures are remembered and always win.
+#[derive(Debug, Clone, Default)]
+pub struct OpenIntents {
+    state: Arc<Mutex<OpenState>>,
+}
+
+impl OpenIntents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, OpenState> {
+        self.state.lock().expect("open intents lock poisoned")
+    }
+
+    /// Record a created intent; `false` if it is already closed
+    pub fn open(&self, intent_id: &str) -> bool {
+        let mut state = self.state();
+        if state.closed.contains_key(intent_id) {
+            return false;
+        }
+        state.open.insert(intent_id.to_string());
+        true
+    }
+
+    /// Record a closure; `true` if the intent was open
+    pub fn close(&self, intent_id: &str, closure: Closure) -> bool {
+        let mut state = self.state();
+        let was_open = state.open.remove(intent_id);
+        state.closed.entry(intent_id.to_string()).or_insert(closure);
+        was_open
+    }
+
+    /// Why an intent closed, if it has
+    pub fn closure(&self, intent_id: &str) -> Option<Closure> {
+        self.state().closed.get(intent_id).cloned()
+    }
+
+    pub fn is_open(&self, intent_id: &str) -> bool {
+        self.state().open.contains(intent_id)
+    }
+
+    /// Intents currently open
+    pub fn len(&self) -> usize {
+        self.state().open.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claims_are_exclusive() {
+        let tracker = IntentTracker::new();
+        let claim = tracker
+            .claim(vec!["i1".to_string(), "i2".to_string()])
+            .unwrap();
+        assert_eq!(claim.intent_ids().len(), 2);
+        assert_eq!(tracker.in_flight(), 2);
+
+        // Overlapping claims are refused whole
+        assert!(tracker
+            .claim(vec!["i3".to_string(), "i2".to_string()])
+            .is_none());
+        assert!(tracker.is_new("i3"));
+
+        drop(claim);
+        assert_eq!(tracker.in_flight(), 0);
+        assert!(!tracker.is_new("i1"));
+        assert!(tracker.claim(vec!["i1".to_string()]).is_none());
+
+        tracker.mark_processed("i4");
+        assert!(!tracker.is_new("i4"));
+    }
+
+    #[test]
+    fn test_closures_win() {
+        let open = OpenIntents::new();
+        assert!(open.open("i1"));
+        assert!(open.is_open("i1"));
+
+        let fulfilled = Closure::Fulfilled {
+            solver: "0xrival".to_string(),
+        };
+        assert!(open.close("i1", fulfilled.clone()));
+        assert!(!open.is_open("i1"));
+        assert_eq!(open.closure("i1"), Some(fulfilled));
+
+        // Closed before its creation was seen
+        assert!(!open.close("i2", Closure::Cancelled { reason: 1 }));
+        assert!(!open.open("i2"));
+        assert_eq!(open.closure("i2").unwrap().to_string(), "cancelled");
+        assert!(open.is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! Protocol package upgrade watcher
+//!
+//! Periodically compares each protocol's configured `published_at` address
+//! with the latest on-chain version, read from the protocol's `UpgradeCap`
+//! (config object `upgrade_cap`) or, failing that, from the Move Registry.
+//! An upgrade raises an alert and is written into the shared config, so the
+//! daemon picks it up without a restart.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use naisu_sui::SuiClient;
+use tracing::{info, warn};
+
+use crate::config::mvr::PUBLISHED_AT;
+use crate::config::{MvrClient, Network, Protocol, SharedConfig};
+use crate::notify::{Alert, AlertLevel, NotificationHub};
+
+/// Config object naming a protocol's `0x2::package::UpgradeCap`
+pub const UPGRADE_CAP: &str = "upgrade_cap";
+
+/// A detected package upgrade
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageUpgrade {
+    pub protocol: Protocol,
+    pub previous: String,
+    pub current: String,
+    pub version: Option<u64>,
+}
+
+/// Latest package address and version from an UpgradeCap's content
+fn parse_upgrade_cap(content: &serde_json::Value) -> Option<(String, Option<u64>)> {
+    let fields = content.get("fields")?;
+    let package = fields.get("package")?.as_str()?.to_string();
+    let version = fields.get("version").and_then(|v| match v {
+        serde_json::Value::String(s) => s.parse().ok(),
+        other => other.as_u64(),
+    });
+    Some((package, version))
+}
+
+/// Watches one network's protocol packages
+pub struct UpgradeWatcher {
+    network: Network,
+    config: SharedConfig,
+    client: SuiClient,
+    mvr: Option<MvrClient>,
+    notifier: NotificationHub,
+    interval: Duration,
+}
+
+impl UpgradeWatcher {
+    pub fn new(
+        network: Network,
+        config: SharedConfig,
+        client: SuiClient,
+        notifier: NotificationHub,
+    ) -> Self {
+        Self {
+            network,
+            config,
+            client,
+            mvr: MvrClient::for_network(network),
+            notifier,
+            interval: Duration::from_secs(
+                std::env::var("UPGRADE_CHECK_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(600),
+            ),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Latest on-chain package for a protocol (UpgradeCap first, then MVR)
+    async fn latest(&self, protocol: Protocol) -> Result<Option<(String, Option<u64>)>> {
+        let config = self.config.get();
+        let Some(protocol_config) = config.protocol_config(protocol, self.network) else {
+            return Ok(None);
+        };
+
+        let upgrade_cap = protocol_config
+            .config_objects
+            .iter()
+            .find(|(name, _)| name == UPGRADE_CAP)
+            .map(|(_, id)| id.clone());
+        if let Some(cap) = upgrade_cap {
+            let object = self.client.get_object(&cap).await?;
+            let content = object
+                .content
+                .ok_or_else(|| anyhow!("UpgradeCap {} has no content", cap))?;
+            return parse_upgrade_cap(&content)
+                .map(Some)
+                .ok_or_else(|| anyhow!("{} is not an UpgradeCap", cap));
+        }
+
+        match (&self.mvr, config.mvr_name(protocol, self.network)) {
+            (Some(mvr), Some(name)) => {
+                let pkg = mvr.resolve(&name).await?;
+                Ok(Some((pkg.package_address, pkg.version)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Check every protocol once, applying and alerting on upgrades
+    pub async fn check_once(&self) -> Vec<PackageUpgrade> {
+        let mut upgrades = Vec::new();
+
+        for protocol in self.network.supported_protocols() {
+            let Some(protocol_config) = self.config.get().protocol_config(protocol, self.network)
+            else {
+                continue;
+            };
+            let configured = protocol_config
+                .config_objects
+                .iter()
+                .find(|(name, _)| name == PUBLISHED_AT)
+                .map(|(_, id)| id.clone())
+                .unwrap_or(protocol_config.package_id);
+
+            let (current, version) = match self.latest(protocol).await {
+                Ok(Some(latest)) => latest,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("⚠️  Upgrade check for {} failed: {}", protocol.name(), e);
+                    continue;
+                }
+            };
+            if current.eq_ignore_ascii_case(&configured) {
+                continue;
+            }
+
+            let upgrade = PackageUpgrade {
+                protocol,
+                previous: configured,
+                current,
+                version,
+            };
+            self.apply(&upgrade).await;
+            upgrades.push(upgrade);
+        }
+
+        upgrades
+    }
+
+    async fn apply(&self, upgrade: &PackageUpgrade) {
+        let network = self.network;
+        self.config.update(|config| {
+            config
+                .networks
+                .entry(network.name().to_string())
+                .or_default()
+                .protocols
+                .entry(upgrade.protocol.name().to_lowercase())
+                .or_default()
+                .objects
+                .insert(PUBLISHED_AT.to_string(), upgrade.current.clone());
+        });
+
+        self.notifier
+            .send(Alert::new(
+                AlertLevel::Warning,
+                format!("{} upgraded on {}", upgrade.protocol.name(), network.name()),
+                format!(
+                    "published_at {} → {}{}; config reloaded",
+                    upgrade.previous,
+                    upgrade.current,
+                    upgrade
+                        .version
+                        .map(|v| format!(" (v{})", v))
+                        .unwrap_or_default()
+                ),
+            ))
+            .await;
+    }
+
+    /// Poll forever
+    pub async fn run(self) {
+        info!(
+            "🔎 Upgrade watcher: {} every {:?}",
+            self.network.name(),
+            self.interval
+        );
+        loop {
+            tokio::time::sleep(self.interval).await;
+            self.check_once().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_upgrade_cap() {
+        let content = serde_json::json!({
+            "dataType": "moveObject",
+            "type": "0x2::package::UpgradeCap",
+            "fields": {
+                "id": { "id": "0xcap" },
+                "package": "0xnew",
+                "policy": 0,
+                "version": "5"
+            }
+        });
+        assert_eq!(
+            parse_upgrade_cap(&content),
+            Some(("0xnew".to_string(), Some(5)))
+        );
+        assert_eq!(parse_upgrade_cap(&serde_json::json!({})), None);
+    }
+}
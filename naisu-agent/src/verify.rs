@@ -0,0 +1,445 @@
+//! Post-fulfillment settlement verification
+//!
+//! A solver's fulfillment report is a claim. The verifier reads the objects
+//! it says it created and checks that the user owns the receipt the
+//! protocol should have produced (StakedSui, sSUI, Cetus position) and that
+//! its amount matches the intent. When the intent package's own
+//! `YieldReceipt` is among the created objects, its recorded user, amount
+//! and APY are checked as well. Only a verified fulfillment completes an
+//! intent; a mismatch raises an alert.
+
+use std::time::Duration;
+
+use naisu_core::FulfillmentReport;
+use naisu_sui::{decode_fulfilled_intent, FulfilledIntent, SuiClient, SuiClientError, SuiObject};
+use serde::Serialize;
+use tracing::warn;
+
+use crate::config::Protocol;
+use crate::notify::{Alert, AlertLevel, NotificationHub};
+
+/// What a protocol hands the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Receipt {
+    /// Fragment of the receipt's Move type
+    pub type_fragment: &'static str,
+    /// Content field holding the amount
+    pub amount_field: &'static str,
+    /// Amount must equal the intent amount (otherwise just be non-zero,
+    /// e.g. sCoin balances are in share units)
+    pub exact: bool,
+}
+
+/// Receipt a protocol's fulfillment leaves with the user, if it leaves one
+pub fn expected_receipt(protocol: Protocol) -> Option<Receipt> {
+    match protocol {
+        Protocol::NativeStaking => Some(Receipt {
+            type_fragment: "::staking_pool::StakedSui",
+            amount_field: "principal",
+            exact: true,
+        }),
+        Protocol::Scallop => Some(Receipt {
+            type_fragment: "::s_coin::sCoin",
+            amount_field: "balance",
+            exact: false,
+        }),
+        Protocol::Cetus => Some(Receipt {
+            type_fragment: "::position::Position",
+            amount_field: "liquidity",
+            exact: false,
+        }),
+        // Account-based deposits: nothing is transferred to the user
+        Protocol::Navi | Protocol::DeepBook => None,
+        // No solver deposits there yet
+        Protocol::Bluefin | Protocol::Turbos => None,
+    }
+}
+
+/// Type fragment of the intent package's `YieldReceipt`
+pub const INTENT_RECEIPT: &str = "::intent::YieldReceipt";
+
+/// What the user should have received
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub user: String,
+    pub amount: u64,
+}
+
+/// Verification outcome
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStatus {
+    Verified,
+    /// The chain disagrees with the report
+    Mismatch,
+    /// No receipt to check, or the chain couldn't be read
+    Unverifiable,
+}
+
+/// Result of verifying one fulfillment
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Verification {
+    pub status: VerificationStatus,
+    /// Receipt object that was checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    pub problems: Vec<String>,
+    /// The intent package's record of the fulfillment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    pub fulfilled: Option<FulfilledIntent>,
+    /// Unix millis
+    pub checked_at: u64,
+}
+
+impl Verification {
+    fn new(status: VerificationStatus, problems: Vec<String>) -> Self {
+        Self {
+            status,
+            object_id: None,
+            amount: None,
+            problems,
+            fulfilled: None,
+            checked_at: chrono::Utc::now().timestamp_millis() as u64,
+        }
+    }
+}
+
+/// Checks reported fulfillments against the chain
+pub struct SettlementVerifier {
+    client: SuiClient,
+    notifier: NotificationHub,
+    /// Reads retried while the receipt isn't indexed yet
+    attempts: u32,
+    retry_delay: Duration,
+}
+
+impl SettlementVerifier {
+    pub fn new(client: SuiClient, notifier: NotificationHub) -> Self {
+        Self {
+            client,
+            notifier,
+            attempts: 3,
+            retry_delay: Duration::from_secs(5),
+        }
+    }
+
+    pub fn with_retries(mut self, attempts: u32, retry_delay: Duration) -> Self {
+        self.attempts = attempts.max(1);
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Verify a report, alerting on a mismatch
+    pub async fn verify(
+        &self,
+        intent_id: &str,
+        report: &FulfillmentReport,
+        expected: &Expected,
+    ) -> Verification {
+        let verification = self.check(report, expected).await;
+
+        if verification.status == VerificationStatus::Mismatch {
+            self.notifier
+                .send(Alert::new(
+                    AlertLevel::Critical,
+                    format!("Settlement mismatch for intent {}", intent_id),
+                    format!(
+                        "{} reported {} ({}): {}",
+                        report.solver,
+                        report.digest,
+                        report.protocol,
+                        verification.problems.join("; ")
+                    ),
+                ))
+                .await;
+        }
+        verification
+    }
+
+    async fn check(&self, report: &FulfillmentReport, expected: &Expected) -> Verification {
+        let mut verification = self.check_protocol_receipt(report, expected).await;
+
+        let Some(reported) = report
+            .created_objects
+            .iter()
+            .find(|o| o.object_type.contains(INTENT_RECEIPT))
+        else {
+            return verification;
+        };
+        let fulfilled = match self
+            .read(&reported.object_id)
+            .await
+            .and_then(|object| decode_fulfilled_intent(&object))
+        {
+            Ok(fulfilled) => fulfilled,
+            Err(e) => {
+                verification
+                    .problems
+                    .push(format!("Intent receipt unreadable: {}", e));
+                return verification;
+            }
+        };
+
+        let problems = check_fulfilled(&fulfilled, report, expected);
+        verification.status = match verification.status {
+            VerificationStatus::Mismatch => VerificationStatus::Mismatch,
+            _ if !problems.is_empty() => VerificationStatus::Mismatch,
+            VerificationStatus::Verified => VerificationStatus::Verified,
+            // The package's receipt stands in for a protocol one
+            VerificationStatus::Unverifiable => {
+                verification.problems.clear();
+                VerificationStatus::Verified
+            }
+        };
+        verification.problems.extend(problems);
+        verification.fulfilled = Some(fulfilled);
+        verification
+    }
+
+    async fn check_protocol_receipt(
+        &self,
+        report: &FulfillmentReport,
+        expected: &Expected,
+    ) -> Verification {
+        let Ok(protocol) = report.protocol.parse::<Protocol>() else {
+            return Verification::new(
+                VerificationStatus::Unverifiable,
+                vec![format!("Unknown protocol `{}`", report.protocol)],
+            );
+        };
+        let Some(receipt) = expected_receipt(protocol) else {
+            return Verification::new(
+                VerificationStatus::Unverifiable,
+                vec![format!("{} leaves no receipt to check", protocol.name())],
+            );
+        };
+
+        let Some(reported) = report
+            .created_objects
+            .iter()
+            .find(|o| o.object_type.contains(receipt.type_fragment))
+        else {
+            return Verification::new(
+                VerificationStatus::Mismatch,
+                vec![format!("No {} in the report", receipt.type_fragment)],
+            );
+        };
+
+        let object = match self.read(&reported.object_id).await {
+            Ok(object) => object,
+            Err(SuiClientError::ObjectNotFound(id)) => {
+                return Verification::new(
+                    VerificationStatus::Mismatch,
+                    vec![format!("Object {} does not exist", id)],
+                );
+            }
+            Err(e) => {
+                return Verification::new(VerificationStatus::Unverifiable, vec![e.to_string()]);
+            }
+        };
+
+        let mut verification = check_receipt(&object, receipt, expected);
+        verification.object_id = Some(reported.object_id.clone());
+        verification
+    }
+
+    async fn read(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
+        let mut attempt = 1;
+        loop {
+            match self.client.get_object(object_id).await {
+                Ok(object) => return Ok(object),
+                Err(e) if attempt >= self.attempts => return Err(e),
+                Err(e) => {
+                    warn!("⚠️  Reading {} failed ({}), retrying", object_id, e);
+                    attempt += 1;
+                    tokio::time::sleep(self.retry_delay).await;
+                }
+            }
+        }
+    }
+}
+
+/// Compare the intent package's receipt with the report
+fn check_fulfilled(
+    fulfilled: &FulfilledIntent,
+    report: &FulfillmentReport,
+    expected: &Expected,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !fulfilled.user.eq_ignore_ascii_case(&expected.user) {
+        problems.push(format!(
+            "Intent receipt is for {}, not {}",
+            fulfilled.user, expected.user
+        ));
+    }
+    if !fulfilled
+        .owner
+        .as_deref()
+        .is_some_and(|o| o.eq_ignore_ascii_case(&expected.user))
+    {
+        problems.push(format!(
+            "Intent receipt owned by {}, not {}",
+            fulfilled.owner.as_deref().unwrap_or("a non-address owner"),
+            expected.user
+        ));
+    }
+    if fulfilled.amount != expected.amount {
+        problems.push(format!(
+            "Intent receipt amount is {}, expected {}",
+            fulfilled.amount, expected.amount
+        ));
+    }
+    if fulfilled.apy != report.realized_apy_bps {
+        problems.push(format!(
+            "Intent receipt records {} bps, reported {}",
+            fulfilled.apy, report.realized_apy_bps
+        ));
+    }
+    if !fulfilled.protocol.eq_ignore_ascii_case(&report.protocol) {
+        problems.push(format!(
+            "Intent receipt records protocol `{}`, reported `{}`",
+            fulfilled.protocol, report.protocol
+        ));
+    }
+    problems
+}
+
+/// Compare an on-chain receipt with what the user should have received
+fn check_receipt(object: &SuiObject, receipt: Receipt, expected: &Expected) -> Verification {
+    let mut problems = Vec::new();
+
+    let object_type = object.r#type.as_deref().unwrap_or_default();
+    if !object_type.contains(receipt.type_fragment) {
+        problems.push(format!(
+            "Expected {}, found `{}`",
+            receipt.type_fragment, object_type
+        ));
+    }
+
+    let owner = object
+        .owner
+        .as_ref()
+        .and_then(|o| o.get("AddressOwner"))
+        .and_then(|o| o.as_str());
+    if !owner.is_some_and(|o| o.eq_ignore_ascii_case(&expected.user)) {
+        problems.push(format!(
+            "Owned by {}, not {}",
+            owner.unwrap_or("a non-address owner"),
+            expected.user
+        ));
+    }
+
+    let amount = object
+        .content
+        .as_ref()
+        .and_then(|c| c.get("fields"))
+        .and_then(|f| f.get(receipt.amount_field))
+        .and_then(|v| match v {
+            serde_json::Value::String(s) => s.parse::<u64>().ok(),
+            other => other.as_u64(),
+        });
+    match amount {
+        None => problems.push(format!("No `{}` in object content", receipt.amount_field)),
+        Some(amount) if receipt.exact && amount != expected.amount => problems.push(format!(
+            "{} is {}, expected {}",
+            receipt.amount_field, amount, expected.amount
+        )),
+        Some(0) => problems.push(format!("{} is zero", receipt.amount_field)),
+        Some(_) => {}
+    }
+
+    let status = if problems.is_empty() {
+        VerificationStatus::Verified
+    } else {
+        VerificationStatus::Mismatch
+    };
+    let mut verification = Verification::new(status, problems);
+    verification.amount = amount;
+    verification
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn staked_sui(owner: &str, principal: &str) -> SuiObject {
+        SuiObject {
+            object_id: "0xstake".to_string(),
+            version: "1".to_string(),
+            digest: "d".to_string(),
+            r#type: Some("0x3::staking_pool::StakedSui".to_string()),
+            owner: Some(json!({ "AddressOwner": owner })),
+            content: Some(json!({ "fields": { "principal": principal } })),
+        }
+    }
+
+    #[test]
+    fn test_check_staked_sui() {
+        let receipt = expected_receipt(Protocol::NativeStaking).unwrap();
+        let expected = Expected {
+            user: "0xuser".to_string(),
+            amount: 1_000_000_000,
+        };
+
+        let ok = check_receipt(&staked_sui("0xuser", "1000000000"), receipt, &expected);
+        assert_eq!(ok.status, VerificationStatus::Verified);
+        assert_eq!(ok.amount, Some(1_000_000_000));
+
+        let short = check_receipt(&staked_sui("0xuser", "900000000"), receipt, &expected);
+        assert_eq!(short.status, VerificationStatus::Mismatch);
+
+        let stolen = check_receipt(&staked_sui("0xsolver", "1000000000"), receipt, &expected);
+        assert_eq!(stolen.status, VerificationStatus::Mismatch);
+        assert_eq!(stolen.problems.len(), 1);
+    }
+
+    #[test]
+    fn test_check_fulfilled() {
+        let fulfilled = FulfilledIntent {
+            receipt_id: "0xreceipt".to_string(),
+            yield_type: "0x2::sui::SUI".to_string(),
+            user: "0xuser".to_string(),
+            owner: Some("0xuser".to_string()),
+            protocol: "navi".to_string(),
+            apy: 640,
+            amount: 1_000_000_000,
+            fulfilled_at: 1_700_000_000_000,
+        };
+        let report = FulfillmentReport {
+            digest: "digest".to_string(),
+            solver: "navi-solver".to_string(),
+            protocol: "navi".to_string(),
+            realized_apy_bps: 640,
+            created_objects: vec![],
+            reported_at: 0,
+            user: None,
+            amount: None,
+            fee: None,
+            gas_used: None,
+            failed_attempts: vec![],
+        };
+        let expected = Expected {
+            user: "0xUSER".to_string(),
+            amount: 1_000_000_000,
+        };
+        assert!(check_fulfilled(&fulfilled, &report, &expected).is_empty());
+
+        let inflated = FulfillmentReport {
+            realized_apy_bps: 900,
+            ..report
+        };
+        assert_eq!(check_fulfilled(&fulfilled, &inflated, &expected).len(), 1);
+    }
+
+    #[test]
+    fn test_account_based_protocols_have_no_receipt() {
+        assert!(expected_receipt(Protocol::Navi).is_none());
+        assert!(!expected_receipt(Protocol::Scallop).unwrap().exact);
+    }
+}
@@ -0,0 +1,214 @@
+//! Stuck intent detection
+//!
+//! Every non-terminal status has an SLA: an intent that has not moved for
+//! longer is stuck. Stuck intents are reported with the remediation an
+//! operator should reach for, and escalate from a warning to a critical
+//! alert once they pass `escalate_factor` times their SLA.
+//!
+//! Failed intents are left out; they already wait on a retry or refund.
+
+use std::time::Duration;
+
+use naisu_core::{Intent, IntentStatus, RefundPlan, MAX_RETRIES};
+use serde::{Deserialize, Serialize};
+
+use crate::notify::AlertLevel;
+
+// ─── Config ──────────────────────────────────────────────────────────────────
+
+/// `[watchdog]` section of the agent config: SLAs per status, in minutes
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchdogConfig {
+    pub pending_mins: u64,
+    pub swap_completed_mins: u64,
+    pub bridging_mins: u64,
+    pub bridge_completed_mins: u64,
+    pub deposited_mins: u64,
+    pub refund_pending_mins: u64,
+    /// Stuck intents turn critical past this multiple of their SLA
+    pub escalate_factor: u64,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            pending_mins: 30,
+            swap_completed_mins: 30,
+            bridging_mins: 60,
+            bridge_completed_mins: 30,
+            deposited_mins: 60,
+            refund_pending_mins: 24 * 60,
+            escalate_factor: 3,
+        }
+    }
+}
+
+impl WatchdogConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let slas = [
+            self.pending_mins,
+            self.swap_completed_mins,
+            self.bridging_mins,
+            self.bridge_completed_mins,
+            self.deposited_mins,
+            self.refund_pending_mins,
+        ];
+        if slas.contains(&0) {
+            return Err("SLAs must be at least one minute".to_string());
+        }
+        if self.escalate_factor < 2 {
+            return Err("escalate_factor must be at least 2".to_string());
+        }
+        Ok(())
+    }
+
+    /// How long an intent may sit in `status` (`None` for terminal states)
+    pub fn sla(&self, status: IntentStatus) -> Option<Duration> {
+        let mins = match status {
+            IntentStatus::Pending => self.pending_mins,
+            IntentStatus::SwapCompleted => self.swap_completed_mins,
+            IntentStatus::Bridging => self.bridging_mins,
+            IntentStatus::BridgeCompleted => self.bridge_completed_mins,
+            IntentStatus::Deposited => self.deposited_mins,
+            IntentStatus::RefundPending => self.refund_pending_mins,
+            IntentStatus::Completed
+            | IntentStatus::Failed
+            | IntentStatus::Cancelled
+            | IntentStatus::Refunded => return None,
+        };
+        Some(Duration::from_secs(mins * 60))
+    }
+}
+
+// ─── Detection ───────────────────────────────────────────────────────────────
+
+/// What an operator should do about a stuck intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Remediation {
+    /// Fail it and retry from where it stopped
+    Retry,
+    /// Fail it and refund the user
+    Refund,
+    /// Needs a look: funds are mid-flight or a refund is unconfirmed
+    Manual,
+}
+
+impl Remediation {
+    /// Suggestion for a stuck intent, judged as if it failed now
+    pub fn suggest(intent: &Intent) -> Self {
+        match intent.status {
+            IntentStatus::RefundPending | IntentStatus::Deposited => return Remediation::Manual,
+            _ => {}
+        }
+        if intent.retries < MAX_RETRIES {
+            return Remediation::Retry;
+        }
+        let mut failed = intent.clone();
+        failed.status = IntentStatus::Failed;
+        match RefundPlan::for_intent(&failed) {
+            Ok(_) => Remediation::Refund,
+            // USDC mid-flight, or nothing left on chain to refund
+            Err(_) => Remediation::Manual,
+        }
+    }
+}
+
+/// An intent past its status SLA
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StuckIntent {
+    pub intent_id: String,
+    pub status: IntentStatus,
+    /// Time since the intent last changed
+    pub stalled_secs: u64,
+    pub sla_secs: u64,
+    pub level: AlertLevel,
+    pub remediation: Remediation,
+}
+
+/// Stuck intents as of `now` (unix seconds), longest stalled first
+pub fn stuck_intents(intents: &[Intent], config: &WatchdogConfig, now: i64) -> Vec<StuckIntent> {
+    let mut stuck: Vec<_> = intents
+        .iter()
+        .filter_map(|intent| {
+            let sla = config.sla(intent.status)?.as_secs();
+            let stalled = now.saturating_sub(intent.updated_at).max(0) as u64;
+            if stalled <= sla {
+                return None;
+            }
+            let level = if stalled > sla.saturating_mul(config.escalate_factor) {
+                AlertLevel::Critical
+            } else {
+                AlertLevel::Warning
+            };
+            Some(StuckIntent {
+                intent_id: intent.id.clone(),
+                status: intent.status,
+                stalled_secs: stalled,
+                sla_secs: sla,
+                level,
+                remediation: Remediation::suggest(intent),
+            })
+        })
+        .collect();
+    stuck.sort_by_key(|s| std::cmp::Reverse(s.stalled_secs));
+    stuck
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use naisu_core::{EvmChain, YieldStrategy};
+
+    fn intent(id: &str, status: IntentStatus, updated_at: i64) -> Intent {
+        let mut intent = Intent::new_evm_to_sui(
+            id.to_string(),
+            "0xuser".to_string(),
+            "0xsui".to_string(),
+            EvmChain::BaseSepolia,
+            "0xtoken".to_string(),
+            "1000000".to_string(),
+            YieldStrategy::from_id(1),
+        );
+        intent.status = status;
+        intent.updated_at = updated_at;
+        intent
+    }
+
+    #[test]
+    fn test_stuck_intents() {
+        let config = WatchdogConfig::default();
+        let now = 100_000;
+        let mut bridging = intent("bridging", IntentStatus::Bridging, now - 2 * 3600);
+        bridging.bridge_tx_hash = Some("0xburn".to_string());
+        let intents = vec![
+            intent("fresh", IntentStatus::Pending, now - 60),
+            intent("done", IntentStatus::Completed, 0),
+            intent("refund", IntentStatus::RefundPending, now - 25 * 3600),
+            bridging.clone(),
+        ];
+
+        let stuck = stuck_intents(&intents, &config, now);
+        let ids: Vec<_> = stuck.iter().map(|s| s.intent_id.as_str()).collect();
+        assert_eq!(ids, ["refund", "bridging"]);
+        assert_eq!(stuck[0].remediation, Remediation::Manual);
+        assert_eq!(stuck[1].remediation, Remediation::Retry);
+        assert_eq!(stuck[1].level, AlertLevel::Warning);
+
+        // Past 3x the SLA it escalates; with retries spent, burned USDC
+        // needs an operator
+        bridging.updated_at = now - 4 * 3600;
+        bridging.retries = MAX_RETRIES;
+        let stuck = stuck_intents(&[bridging], &config, now);
+        assert_eq!(stuck[0].level, AlertLevel::Critical);
+        assert_eq!(stuck[0].remediation, Remediation::Manual);
+
+        // Swapped USDC still on the source chain can be refunded
+        let mut swapped = intent("swapped", IntentStatus::SwapCompleted, 0);
+        swapped.swap_tx_hash = Some("0xswap".to_string());
+        swapped.usdc_amount = Some("1000000".to_string());
+        swapped.retries = MAX_RETRIES;
+        assert_eq!(Remediation::suggest(&swapped), Remediation::Refund);
+    }
+}
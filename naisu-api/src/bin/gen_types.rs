@@ -0,0 +1,114 @@
+//! TypeScript type generator
+//!
+//! Writes the API's request/response DTOs and the core intent types to one
+//! `types.ts` bundle for the frontend.
+//!
+//! Run: cargo run -p naisu-api --features ts --bin gen-types [-- <out.ts>]
+//!
+//! Defaults to `naisu-frontend/src/types/api.ts`. With `--check` nothing is
+//! written; it exits non-zero when the file is out of date (for CI).
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use naisu_agent::bridge_latency::Eta;
+use naisu_agent::verify::{Verification, VerificationStatus};
+use naisu_api::common::response::{
+    ApiErrorResponse, ApiSuccessResponse, ErrorCode, Pagination, ProblemDetails,
+};
+use naisu_api::feature::intent::handler::{
+    ExplorerUrls, IntentResponse, IntentSearchHit, IntentTransaction,
+};
+use naisu_api::feature::portfolio::handler::{Portfolio, Position, UnclaimedRewards};
+use naisu_api::feature::solver::handler::SolverBidResponse;
+use naisu_api::state::{RefKind, SolverBidEntry};
+use naisu_core::{
+    CreateIntentRequest, Direction, EvmChain, FailedAttempt, FulfilledObject, FulfillmentReport,
+    Intent, IntentSignature, IntentStatus, RiskTier, SignatureKind, Tip, YieldStrategy,
+};
+use ts_rs::TS;
+
+const DEFAULT_OUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../naisu-frontend/src/types/api.ts"
+);
+
+const HEADER: &str = "// Generated by `cargo run -p naisu-api --features ts --bin gen-types`.
+// Do not edit; change the Rust types and regenerate.
+";
+
+/// Every exported declaration, in bundle order
+fn declarations() -> Vec<String> {
+    vec![
+        // Core
+        Direction::decl(),
+        IntentStatus::decl(),
+        EvmChain::decl(),
+        YieldStrategy::decl(),
+        RiskTier::decl(),
+        Tip::decl(),
+        SignatureKind::decl(),
+        IntentSignature::decl(),
+        Intent::decl(),
+        CreateIntentRequest::decl(),
+        FulfilledObject::decl(),
+        FailedAttempt::decl(),
+        FulfillmentReport::decl(),
+        VerificationStatus::decl(),
+        Verification::decl(),
+        // API
+        ApiSuccessResponse::<()>::decl(),
+        ApiErrorResponse::decl(),
+        ErrorCode::decl(),
+        ProblemDetails::decl(),
+        Pagination::decl(),
+        ExplorerUrls::decl(),
+        IntentResponse::decl(),
+        RefKind::decl(),
+        Eta::decl(),
+        IntentSearchHit::decl(),
+        IntentTransaction::decl(),
+        SolverBidEntry::decl(),
+        SolverBidResponse::decl(),
+        Position::decl(),
+        UnclaimedRewards::decl(),
+        Portfolio::decl(),
+    ]
+}
+
+fn bundle() -> String {
+    let body: Vec<String> = declarations()
+        .into_iter()
+        .map(|decl| format!("export {}\n", decl))
+        .collect();
+    // serde_json writes 64-bit integers as plain JSON numbers
+    format!("{}\n{}", HEADER, body.join("\n")).replace("bigint", "number")
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let check = args.iter().any(|a| a == "--check");
+    let out = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_OUT));
+
+    let bundle = bundle();
+    if check {
+        let current = std::fs::read_to_string(&out).unwrap_or_default();
+        if current != bundle {
+            eprintln!("❌ {} is out of date; run gen-types", out.display());
+            return ExitCode::FAILURE;
+        }
+        println!("✅ {} is up to date", out.display());
+        return ExitCode::SUCCESS;
+    }
+
+    if let Err(e) = std::fs::write(&out, bundle) {
+        eprintln!("❌ Failed to write {}: {}", out.display(), e);
+        return ExitCode::FAILURE;
+    }
+    println!("✅ Wrote {}", out.display());
+    ExitCode::SUCCESS
+}
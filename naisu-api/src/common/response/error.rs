@@ -1,19 +1,47 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use naisu_core::{NaisuError, ValidationError};
 use serde::Serialize;
 use std::fmt;
 
+use super::ErrorCode;
+
+/// Media type of error bodies (RFC 7807)
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
 /// Standard error response
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct ApiErrorResponse {
     pub success: bool,
     pub code: u16,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Set explicitly; otherwise derived from `code`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+}
+
+/// Error body as sent on the wire: RFC 7807 members plus the legacy
+/// `success`/`code`/`message` envelope
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    pub error_code: ErrorCode,
+    pub success: bool,
+    pub code: u16,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 impl ApiErrorResponse {
@@ -23,9 +51,15 @@ impl ApiErrorResponse {
             code: 500,
             message: message.into(),
             error: None,
+            error_code: None,
         }
     }
 
+    /// Error with a registered code, served with the code's status
+    pub fn coded(error_code: ErrorCode, message: impl Into<String>) -> Self {
+        Self::new(message).with_error_code(error_code)
+    }
+
     pub fn with_code(mut self, code: StatusCode) -> Self {
         self.code = code.as_u16();
         self
@@ -40,6 +74,36 @@ impl ApiErrorResponse {
         self.error = Some(error.into());
         self
     }
+
+    /// Sets the error code and the status it maps to
+    pub fn with_error_code(mut self, error_code: ErrorCode) -> Self {
+        self.code = error_code.status().as_u16();
+        self.error_code = Some(error_code);
+        self
+    }
+
+    pub fn error_code(&self) -> ErrorCode {
+        self.error_code.unwrap_or_else(|| {
+            ErrorCode::from_status(
+                StatusCode::from_u16(self.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            )
+        })
+    }
+
+    pub fn to_problem(&self) -> ProblemDetails {
+        let error_code = self.error_code();
+        ProblemDetails {
+            problem_type: error_code.type_uri(),
+            title: error_code.title().to_string(),
+            status: self.code,
+            detail: self.message.clone(),
+            error_code,
+            success: false,
+            code: self.code,
+            message: self.message.clone(),
+            error: self.error.clone(),
+        }
+    }
 }
 
 impl Default for ApiErrorResponse {
@@ -49,6 +113,7 @@ impl Default for ApiErrorResponse {
             code: 500,
             message: "Internal server error".to_string(),
             error: None,
+            error_code: None,
         }
     }
 }
@@ -64,7 +129,23 @@ impl std::error::Error for ApiErrorResponse {}
 impl IntoResponse for ApiErrorResponse {
     fn into_response(self) -> Response {
         let status = StatusCode::from_u16(self.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
-        (status, Json(self)).into_response()
+        let mut response = (status, Json(self.to_problem())).into_response();
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        response
+    }
+}
+
+impl From<NaisuError> for ApiErrorResponse {
+    fn from(e: NaisuError) -> Self {
+        Self::coded(ErrorCode::from(&e), e.to_string())
+    }
+}
+
+impl From<ValidationError> for ApiErrorResponse {
+    fn from(e: ValidationError) -> Self {
+        Self::coded(ErrorCode::from(&e), e.to_string())
     }
 }
 
@@ -84,3 +165,42 @@ impl IntoApiError for &str {
         ApiErrorResponse::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+
+    #[tokio::test]
+    async fn test_problem_json_response() {
+        let response =
+            ApiErrorResponse::coded(ErrorCode::IntentNotFound, "No intent api_1").into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let problem: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem["type"], "urn:naisu:error:intent-not-found");
+        assert_eq!(problem["title"], "Intent not found");
+        assert_eq!(problem["status"], 404);
+        assert_eq!(problem["detail"], "No intent api_1");
+        assert_eq!(problem["error_code"], "INTENT_NOT_FOUND");
+        // Legacy envelope
+        assert_eq!(problem["success"], false);
+        assert_eq!(problem["message"], "No intent api_1");
+    }
+
+    #[test]
+    fn test_status_only_errors_get_generic_codes() {
+        let error = ApiErrorResponse::new("bad").with_code(StatusCode::BAD_REQUEST);
+        assert_eq!(error.error_code(), ErrorCode::BadRequest);
+        assert_eq!(
+            ApiErrorResponse::default().error_code(),
+            ErrorCode::InternalError
+        );
+
+        let error = ApiErrorResponse::from(NaisuError::Sui("rpc down".to_string()));
+        assert_eq!(error.error_code(), ErrorCode::ChainError);
+        assert_eq!(error.code, 502);
+    }
+}
@@ -0,0 +1,143 @@
+use axum::http::StatusCode;
+use naisu_core::{NaisuError, ValidationError};
+use serde::Serialize;
+
+/// Stable, machine-readable error codes
+///
+/// Clients branch on these rather than on messages, so a code is never
+/// renamed or reused once published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    // ─── Request ─────────────────────────────────────────────────────────
+    BadRequest,
+    ValidationFailed,
+    NetworkUnsupported,
+    Unauthorized,
+    Forbidden,
+    PayloadTooLarge,
+    // ─── Resources ───────────────────────────────────────────────────────
+    NotFound,
+    IntentNotFound,
+    Conflict,
+    InvalidIntentState,
+    BidTooLow,
+    Unprocessable,
+    // ─── Server ──────────────────────────────────────────────────────────
+    ChainError,
+    UpstreamError,
+    ServiceUnavailable,
+    ConfigError,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BadRequest => "BAD_REQUEST",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::NetworkUnsupported => "NETWORK_UNSUPPORTED",
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::Forbidden => "FORBIDDEN",
+            Self::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            Self::NotFound => "NOT_FOUND",
+            Self::IntentNotFound => "INTENT_NOT_FOUND",
+            Self::Conflict => "CONFLICT",
+            Self::InvalidIntentState => "INVALID_INTENT_STATE",
+            Self::BidTooLow => "BID_TOO_LOW",
+            Self::Unprocessable => "UNPROCESSABLE",
+            Self::ChainError => "CHAIN_ERROR",
+            Self::UpstreamError => "UPSTREAM_ERROR",
+            Self::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            Self::ConfigError => "CONFIG_ERROR",
+            Self::InternalError => "INTERNAL_ERROR",
+        }
+    }
+
+    /// HTTP status the code is served with
+    pub fn status(self) -> StatusCode {
+        match self {
+            Self::BadRequest | Self::ValidationFailed | Self::NetworkUnsupported => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::NotFound | Self::IntentNotFound => StatusCode::NOT_FOUND,
+            Self::Conflict | Self::InvalidIntentState | Self::BidTooLow => StatusCode::CONFLICT,
+            Self::Unprocessable => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ChainError | Self::UpstreamError => StatusCode::BAD_GATEWAY,
+            Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ConfigError | Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Short human-readable summary (the problem `title`)
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::BadRequest => "Bad request",
+            Self::ValidationFailed => "Validation failed",
+            Self::NetworkUnsupported => "Network not supported",
+            Self::Unauthorized => "Unauthorized",
+            Self::Forbidden => "Forbidden",
+            Self::PayloadTooLarge => "Payload too large",
+            Self::NotFound => "Not found",
+            Self::IntentNotFound => "Intent not found",
+            Self::Conflict => "Conflict",
+            Self::InvalidIntentState => "Invalid intent state",
+            Self::BidTooLow => "Bid too low",
+            Self::Unprocessable => "Unprocessable request",
+            Self::ChainError => "Chain error",
+            Self::UpstreamError => "Upstream error",
+            Self::ServiceUnavailable => "Service unavailable",
+            Self::ConfigError => "Configuration error",
+            Self::InternalError => "Internal server error",
+        }
+    }
+
+    /// Problem `type` URI
+    pub fn type_uri(self) -> String {
+        format!(
+            "urn:naisu:error:{}",
+            self.as_str().to_ascii_lowercase().replace('_', "-")
+        )
+    }
+
+    /// Generic code for errors raised with only a status
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST => Self::BadRequest,
+            StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            StatusCode::FORBIDDEN => Self::Forbidden,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::PAYLOAD_TOO_LARGE => Self::PayloadTooLarge,
+            StatusCode::UNPROCESSABLE_ENTITY => Self::Unprocessable,
+            StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT => Self::UpstreamError,
+            StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable,
+            _ => Self::InternalError,
+        }
+    }
+}
+
+impl From<&NaisuError> for ErrorCode {
+    fn from(e: &NaisuError) -> Self {
+        match e {
+            NaisuError::Evm(_) | NaisuError::Bridge(_) | NaisuError::Sui(_) => Self::ChainError,
+            NaisuError::IntentNotFound(_) => Self::IntentNotFound,
+            NaisuError::InvalidState { .. } => Self::InvalidIntentState,
+            NaisuError::Config(_) => Self::ConfigError,
+            NaisuError::Api(_) => Self::UpstreamError,
+            NaisuError::Database(_) | NaisuError::Serialization(_) | NaisuError::Unknown(_) => {
+                Self::InternalError
+            }
+        }
+    }
+}
+
+impl From<&ValidationError> for ErrorCode {
+    fn from(_: &ValidationError) -> Self {
+        Self::ValidationFailed
+    }
+}
@@ -6,9 +6,13 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 pub mod error;
+pub mod error_code;
+pub mod pagination;
 pub mod success;
 
-pub use error::ApiErrorResponse;
+pub use error::{ApiErrorResponse, ProblemDetails, PROBLEM_JSON};
+pub use error_code::ErrorCode;
+pub use pagination::{PageQuery, Pagination};
 pub use success::ApiSuccessResponse;
 
 /// Generic API response type
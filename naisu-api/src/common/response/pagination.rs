@@ -0,0 +1,124 @@
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use super::ApiErrorResponse;
+
+/// Page size when a list request sets no `limit`
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+/// Largest page a list endpoint returns
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Pagination metadata on list responses
+#[derive(Debug, Clone, Default, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Pagination {
+    /// Items across all pages, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    /// Pass as `cursor` to fetch the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl Pagination {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_total(mut self, total: usize) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Sets the next cursor and marks more pages available
+    pub fn with_next_cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.next_cursor = Some(cursor.into());
+        self.has_more = true;
+        self
+    }
+}
+
+/// `?limit=&cursor=` parameters of list endpoints
+///
+/// Cursors are opaque to clients; for the in-memory lists served here they
+/// are offsets into the filtered list.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PageQuery {
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+}
+
+impl PageQuery {
+    /// Cut one page out of `items`, returning it with its metadata
+    pub fn paginate<T>(&self, items: Vec<T>) -> Result<(Vec<T>, Pagination), ApiErrorResponse> {
+        let offset = match &self.cursor {
+            Some(cursor) => cursor.parse::<usize>().map_err(|_| {
+                ApiErrorResponse::new(format!("Invalid cursor '{}'", cursor))
+                    .with_code(StatusCode::BAD_REQUEST)
+            })?,
+            None => 0,
+        };
+        let limit = self
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+
+        let total = items.len();
+        let page: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+        let end = offset.saturating_add(page.len());
+
+        let mut pagination = Pagination::new().with_total(total);
+        if end < total {
+            pagination = pagination.with_next_cursor(end.to_string());
+        }
+        Ok((page, pagination))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(limit: Option<usize>, cursor: Option<&str>) -> PageQuery {
+        PageQuery {
+            limit,
+            cursor: cursor.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_paginate_follows_cursor() {
+        let items: Vec<u32> = (0..5).collect();
+
+        let (page, meta) = query(Some(2), None).paginate(items.clone()).unwrap();
+        assert_eq!(page, vec![0, 1]);
+        assert_eq!(meta.total, Some(5));
+        assert_eq!(meta.next_cursor.as_deref(), Some("2"));
+        assert!(meta.has_more);
+
+        let (page, meta) = query(Some(2), Some("4")).paginate(items.clone()).unwrap();
+        assert_eq!(page, vec![4]);
+        assert!(meta.next_cursor.is_none());
+        assert!(!meta.has_more);
+
+        // Past the end: an empty last page
+        let (page, meta) = query(None, Some("9")).paginate(items).unwrap();
+        assert!(page.is_empty());
+        assert!(!meta.has_more);
+    }
+
+    #[test]
+    fn test_paginate_limits() {
+        let items: Vec<usize> = (0..250).collect();
+        let (page, _) = query(None, None).paginate(items.clone()).unwrap();
+        assert_eq!(page.len(), DEFAULT_PAGE_SIZE);
+        let (page, _) = query(Some(1_000), None).paginate(items.clone()).unwrap();
+        assert_eq!(page.len(), MAX_PAGE_SIZE);
+        let (page, _) = query(Some(0), None).paginate(items.clone()).unwrap();
+        assert_eq!(page.len(), 1);
+
+        let err = query(None, Some("abc")).paginate(items).unwrap_err();
+        assert_eq!(err.code, StatusCode::BAD_REQUEST.as_u16());
+    }
+}
@@ -5,14 +5,19 @@ use axum::{
 };
 use serde::Serialize;
 
+use super::Pagination;
+
 /// Standard success response wrapper
 #[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct ApiSuccessResponse<T> {
     pub success: bool,
     pub code: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
     pub data: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
 }
 
 impl<T: Serialize> ApiSuccessResponse<T> {
@@ -22,6 +27,7 @@ impl<T: Serialize> ApiSuccessResponse<T> {
             code: 200,
             message: None,
             data,
+            pagination: None,
         }
     }
 
@@ -39,6 +45,11 @@ impl<T: Serialize> ApiSuccessResponse<T> {
         self.data = data;
         self
     }
+
+    pub fn with_pagination(mut self, pagination: Pagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
 }
 
 impl<T: Serialize> Default for ApiSuccessResponse<T>
@@ -51,6 +62,7 @@ where
             code: 200,
             message: None,
             data: T::default(),
+            pagination: None,
         }
     }
 }
@@ -1,11 +1,24 @@
 use std::env;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
 
 use dotenvy::dotenv;
+use naisu_agent::config::{AgentConfig, Network};
+use naisu_agent::executor::signer::LocalSigner;
+use naisu_core::{validate_evm_address, validate_sui_address, EvmChain, Secret};
+
+use crate::runtime::RuntimeConfig;
 
 #[derive(Debug, Clone)]
 pub struct ServerConfig {
     pub port: u16,
-    pub cors_allowed_origins: Vec<String>,
+    /// gRPC port, used when built with the `grpc` feature
+    pub grpc_port: u16,
+    /// Largest request body accepted (bytes)
+    pub max_body_bytes: usize,
+    /// Largest body for admin calls, which carry state snapshots (bytes)
+    pub max_admin_body_bytes: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -13,12 +26,33 @@ pub struct EvmConfig {
     pub rpc_url: String,
     pub hook_address: String,
     pub chain_id: u64,
+    /// First block the hook listener scans; the chain head at startup if unset
+    pub start_block: Option<u64>,
+    /// Key the CCTP relayer submits `receiveMessage` with; relaying disabled if unset
+    pub relayer_key: Option<Secret>,
+    /// Ethereum RPC ENS names are resolved through
+    pub ens_rpc_url: String,
+}
+
+impl EvmConfig {
+    /// `EVM_RPC_URL` for the configured chain, the public RPC for others
+    pub fn rpc_url_for(&self, chain: EvmChain) -> &str {
+        if chain.chain_id() == self.chain_id {
+            &self.rpc_url
+        } else {
+            chain.default_rpc_url()
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct SuiConfig {
+    /// Network the API starts on (`SUI_NETWORK`, switchable at runtime)
+    pub network: String,
     pub rpc_url: String,
     pub package_id: Option<String>,
+    /// Agent TOML config with protocol overrides (reloadable via admin API)
+    pub agent_config_path: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +62,23 @@ pub struct BridgeConfig {
     pub lifi_api_url: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AdminConfig {
+    /// Key required in `x-admin-key` for admin calls; admin API disabled if unset
+    pub api_key: Option<Secret>,
+    /// Key solver daemons send in `x-solver-key`; solver reports disabled if unset
+    pub solver_api_key: Option<Secret>,
+    /// Ed25519 key (hex or Sui base64) receipts are signed with; unsigned if unset
+    pub receipt_signing_key: Option<Secret>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IntentsConfig {
+    /// Window (seconds) in which a matching open intent from the same user
+    /// is treated as a double submission; detection disabled if 0
+    pub duplicate_window_secs: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub rust_env: String,
@@ -36,6 +87,13 @@ pub struct Config {
     pub evm: EvmConfig,
     pub sui: SuiConfig,
     pub bridge: BridgeConfig,
+    pub admin: AdminConfig,
+    pub intents: IntentsConfig,
+}
+
+/// Secret from `NAME` or `NAME_FILE`; load errors are reported by `validate`
+fn secret(name: &str) -> Option<Secret> {
+    Secret::from_env(name).ok().flatten()
 }
 
 impl Config {
@@ -53,11 +111,18 @@ impl Config {
                     .ok()
                     .and_then(|p| p.parse().ok())
                     .unwrap_or(8080),
-                cors_allowed_origins: env::var("CORS_ALLOWED_ORIGINS")
-                    .unwrap_or_else(|_| "*".to_string())
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .collect(),
+                grpc_port: env::var("GRPC_PORT")
+                    .ok()
+                    .and_then(|p| p.parse().ok())
+                    .unwrap_or(50051),
+                max_body_bytes: env::var("MAX_BODY_BYTES")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(256 * 1024),
+                max_admin_body_bytes: env::var("MAX_ADMIN_BODY_BYTES")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(16 * 1024 * 1024),
             },
             evm: EvmConfig {
                 rpc_url: env::var("EVM_RPC_URL")
@@ -68,11 +133,19 @@ impl Config {
                     .ok()
                     .and_then(|v| v.parse().ok())
                     .unwrap_or(84532),
+                start_block: env::var("EVM_START_BLOCK")
+                    .ok()
+                    .and_then(|v| v.parse().ok()),
+                relayer_key: secret("EVM_RELAYER_KEY"),
+                ens_rpc_url: env::var("ENS_RPC_URL")
+                    .unwrap_or_else(|_| EvmChain::Ethereum.default_rpc_url().to_string()),
             },
             sui: SuiConfig {
+                network: env::var("SUI_NETWORK").unwrap_or_else(|_| "testnet".to_string()),
                 rpc_url: env::var("SUI_RPC_URL")
                     .unwrap_or_else(|_| "https://fullnode.testnet.sui.io:443".to_string()),
                 package_id: env::var("SUI_PACKAGE_ID").ok(),
+                agent_config_path: env::var("AGENT_CONFIG_PATH").ok().filter(|p| !p.is_empty()),
             },
             bridge: BridgeConfig {
                 cctp_api_url: env::var("CCTP_API_URL")
@@ -81,6 +154,17 @@ impl Config {
                     .unwrap_or_else(|_| "https://api.testnet.wormholescan.io".to_string()),
                 lifi_api_url: env::var("LIFI_API_URL").ok(),
             },
+            admin: AdminConfig {
+                api_key: secret("ADMIN_API_KEY"),
+                solver_api_key: secret("SOLVER_API_KEY"),
+                receipt_signing_key: secret("RECEIPT_SIGNING_KEY"),
+            },
+            intents: IntentsConfig {
+                duplicate_window_secs: env::var("DUPLICATE_INTENT_WINDOW_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(120),
+            },
         }
     }
 
@@ -92,3 +176,337 @@ impl Config {
         }
     }
 }
+
+// ─── Validation ──────────────────────────────────────────────────────────────
+
+/// Numeric variables `from_env` falls back to defaults for
+const NUMERIC_VARS: [&str; 8] = [
+    "PORT",
+    "GRPC_PORT",
+    "EVM_CHAIN_ID",
+    "EVM_START_BLOCK",
+    "DUPLICATE_INTENT_WINDOW_SECS",
+    "MAX_BODY_BYTES",
+    "MAX_ADMIN_BODY_BYTES",
+    "RECONCILE_INTERVAL_SECS",
+];
+
+/// Secrets that may come from `NAME` or `NAME_FILE`
+const SECRET_VARS: [&str; 4] = [
+    "EVM_RELAYER_KEY",
+    "ADMIN_API_KEY",
+    "SOLVER_API_KEY",
+    "RECEIPT_SIGNING_KEY",
+];
+
+/// How bad a configuration problem is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Works, but probably not as intended
+    Warning,
+    /// Broken; the API refuses to start with these on mainnet
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Warning => "WARNING",
+            Severity::Critical => "CRITICAL",
+        }
+    }
+}
+
+/// One configuration problem
+#[derive(Debug, Clone)]
+pub struct ConfigIssue {
+    pub severity: Severity,
+    /// Environment variable (or file) at fault
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn critical(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Critical,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {}",
+            self.severity.as_str(),
+            self.field,
+            self.message
+        )
+    }
+}
+
+/// Critical problems that stop the API from starting
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid configuration:\n  - {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n  - "))]
+pub struct ConfigError(pub Vec<ConfigIssue>);
+
+fn check_url(issues: &mut Vec<ConfigIssue>, field: &str, url: &str) {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        issues.push(ConfigIssue::critical(
+            field,
+            format!("`{}` is not an http(s) URL", url),
+        ));
+    }
+}
+
+impl Config {
+    /// Every problem with the loaded configuration, most severe first
+    ///
+    /// Offline checks only; `self_check` adds the ones that need the network.
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        for var in NUMERIC_VARS {
+            if let Ok(value) = env::var(var) {
+                if !value.is_empty() && u64::from_str(value.trim()).is_err() {
+                    issues.push(ConfigIssue::critical(
+                        var,
+                        format!("`{}` is not a number; the default is used", value),
+                    ));
+                }
+            }
+        }
+
+        for var in SECRET_VARS {
+            if let Err(e) = Secret::from_env(var) {
+                issues.push(ConfigIssue::critical(var, e.to_string()));
+            }
+        }
+
+        let network = self.sui.network.parse::<Network>();
+        if let Err(e) = &network {
+            issues.push(ConfigIssue::critical("SUI_NETWORK", e.clone()));
+        }
+        let mainnet = matches!(network, Ok(Network::Mainnet));
+
+        if let Err(ConfigError(runtime)) = RuntimeConfig::from_env() {
+            issues.extend(runtime);
+        }
+        if self.server.max_body_bytes == 0 {
+            issues.push(ConfigIssue::critical("MAX_BODY_BYTES", "must be above 0"));
+        }
+
+        check_url(&mut issues, "SUI_RPC_URL", &self.sui.rpc_url);
+        check_url(&mut issues, "EVM_RPC_URL", &self.evm.rpc_url);
+        check_url(&mut issues, "ENS_RPC_URL", &self.evm.ens_rpc_url);
+        check_url(&mut issues, "CCTP_API_URL", &self.bridge.cctp_api_url);
+        check_url(
+            &mut issues,
+            "WORMHOLE_API_URL",
+            &self.bridge.wormhole_api_url,
+        );
+        if let Some(url) = &self.bridge.lifi_api_url {
+            check_url(&mut issues, "LIFI_API_URL", url);
+        }
+        if mainnet && self.sui.rpc_url.contains("testnet") {
+            issues.push(ConfigIssue::critical(
+                "SUI_RPC_URL",
+                "points at testnet while SUI_NETWORK is mainnet",
+            ));
+        }
+
+        if let Some(package) = &self.sui.package_id {
+            if validate_sui_address(package).is_err() {
+                issues.push(ConfigIssue::critical(
+                    "SUI_PACKAGE_ID",
+                    format!("`{}` is not a Sui object ID", package),
+                ));
+            }
+        }
+
+        match EvmChain::from_chain_id(self.evm.chain_id) {
+            None => issues.push(ConfigIssue::warning(
+                "EVM_CHAIN_ID",
+                format!(
+                    "unknown chain {}; the EVM hook listener is disabled",
+                    self.evm.chain_id
+                ),
+            )),
+            Some(chain) if mainnet && chain.is_testnet() => issues.push(ConfigIssue::warning(
+                "EVM_CHAIN_ID",
+                format!("{} is a testnet on a mainnet deployment", chain.name()),
+            )),
+            Some(_) => {}
+        }
+        if validate_evm_address(&self.evm.hook_address).is_err() {
+            issues.push(ConfigIssue::critical(
+                "HOOK_ADDRESS",
+                format!("`{}` is not an EVM address", self.evm.hook_address),
+            ));
+        } else if self
+            .evm
+            .hook_address
+            .trim_start_matches("0x")
+            .bytes()
+            .all(|b| b == b'0')
+        {
+            issues.push(ConfigIssue::warning(
+                "HOOK_ADDRESS",
+                "unset; EVM intents are not picked up",
+            ));
+        }
+        if let Some(key) = &self.evm.relayer_key {
+            let hex = key.expose();
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                issues.push(ConfigIssue::critical(
+                    "EVM_RELAYER_KEY",
+                    "must be a 32-byte hex private key",
+                ));
+            }
+        }
+
+        if let Some(key) = &self.admin.receipt_signing_key {
+            if let Err(e) = LocalSigner::from_encoded("receipts", key.expose()) {
+                issues.push(ConfigIssue::critical(
+                    "RECEIPT_SIGNING_KEY",
+                    format!("{}; receipts would go out unsigned", e),
+                ));
+            }
+        }
+        if self.admin.api_key.is_none() {
+            issues.push(ConfigIssue::warning(
+                "ADMIN_API_KEY",
+                "unset; admin endpoints are disabled",
+            ));
+        }
+        if self.admin.solver_api_key.is_none() {
+            issues.push(ConfigIssue::warning(
+                "SOLVER_API_KEY",
+                "unset; solver fulfillment reports are rejected",
+            ));
+        }
+
+        let agent_config = AgentConfig::load(self.sui.agent_config_path.as_deref().map(Path::new))
+            .and_then(|config| config.validate().map(|_| config));
+        match agent_config {
+            Err(e) => issues.push(ConfigIssue::critical("AGENT_CONFIG_PATH", e.to_string())),
+            Ok(config) => {
+                if let Ok(network) = network {
+                    if let Err(e) = config.intent_package(network) {
+                        issues.push(ConfigIssue::warning("AGENT_CONFIG_PATH", e.to_string()));
+                    }
+                }
+            }
+        }
+
+        issues.sort_by_key(|i| std::cmp::Reverse(i.severity));
+        issues
+    }
+
+    /// Whether the API starts on mainnet
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self.sui.network.parse::<Network>(), Ok(Network::Mainnet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(network: &str, rpc_url: &str) -> Config {
+        Config {
+            rust_env: "development".to_string(),
+            is_production: false,
+            server: ServerConfig {
+                port: 8080,
+                grpc_port: 50051,
+                max_body_bytes: 256 * 1024,
+                max_admin_body_bytes: 16 * 1024 * 1024,
+            },
+            evm: EvmConfig {
+                rpc_url: "https://sepolia.base.org".to_string(),
+                hook_address: "0x1111111111111111111111111111111111111111".to_string(),
+                chain_id: 84532,
+                start_block: None,
+                relayer_key: None,
+                ens_rpc_url: EvmChain::Ethereum.default_rpc_url().to_string(),
+            },
+            sui: SuiConfig {
+                network: network.to_string(),
+                rpc_url: rpc_url.to_string(),
+                package_id: None,
+                agent_config_path: None,
+            },
+            bridge: BridgeConfig {
+                cctp_api_url: "https://iris-api-sandbox.circle.com".to_string(),
+                wormhole_api_url: "https://api.testnet.wormholescan.io".to_string(),
+                lifi_api_url: None,
+            },
+            admin: AdminConfig {
+                api_key: None,
+                solver_api_key: None,
+                receipt_signing_key: None,
+            },
+            intents: IntentsConfig {
+                duplicate_window_secs: 120,
+            },
+        }
+    }
+
+    fn issue<'a>(issues: &'a [ConfigIssue], field: &str) -> Option<&'a ConfigIssue> {
+        issues.iter().find(|i| i.field == field)
+    }
+
+    #[test]
+    fn test_validate_flags_broken_settings() {
+        let issues = config("testnet", "https://fullnode.testnet.sui.io:443").validate();
+        assert!(issue(&issues, "SUI_RPC_URL").is_none());
+        assert!(issue(&issues, "HOOK_ADDRESS").is_none());
+        assert_eq!(
+            issue(&issues, "ADMIN_API_KEY").map(|i| i.severity),
+            Some(Severity::Warning)
+        );
+
+        let mut broken = config("mainnet", "https://fullnode.testnet.sui.io:443");
+        broken.evm.hook_address = "0xnot-an-address".to_string();
+        broken.bridge.lifi_api_url = Some("li.quest".to_string());
+        broken.server.max_body_bytes = 0;
+        broken.evm.relayer_key = Some(Secret::new("0x1234"));
+        let issues = broken.validate();
+        for field in [
+            "SUI_RPC_URL",
+            "HOOK_ADDRESS",
+            "LIFI_API_URL",
+            "MAX_BODY_BYTES",
+            "EVM_RELAYER_KEY",
+        ] {
+            assert_eq!(
+                issue(&issues, field).map(|i| i.severity),
+                Some(Severity::Critical),
+                "{}",
+                field
+            );
+        }
+        // Base Sepolia on mainnet only warns
+        assert_eq!(
+            issue(&issues, "EVM_CHAIN_ID").map(|i| i.severity),
+            Some(Severity::Warning)
+        );
+        // Most severe first
+        assert!(issues
+            .windows(2)
+            .all(|pair| pair[0].severity >= pair[1].severity));
+    }
+}
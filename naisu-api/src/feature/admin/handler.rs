@@ -0,0 +1,431 @@
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use naisu_agent::audit::{AuditAction, AuditEntry, AuditFilter};
+use naisu_agent::config::SolverTuning;
+use naisu_agent::feature_flags::{Feature, FeatureFlags, FeatureOverrides};
+use naisu_agent::registry::SolverRegistry;
+use naisu_agent::solver_overrides::SolverOverrides;
+use naisu_agent::watchdog::{stuck_intents, StuckIntent};
+use naisu_sui::CustomStrategy;
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::runtime::RuntimeConfig;
+use crate::snapshot::{ImportSummary, SnapshotError, StateSnapshot};
+use crate::state::AppState;
+
+/// Header carrying the admin API key
+pub const ADMIN_KEY_HEADER: &str = "x-admin-key";
+
+/// Audit actor for calls authorized with the admin key
+pub(crate) const ADMIN_ACTOR: &str = "admin";
+
+/// Kill switch state, polled by solver daemons
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KillSwitchStatus {
+    pub engaged: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Unix millis of the last change
+    pub updated_at: u64,
+}
+
+/// Request body for toggling the kill switch
+#[derive(Debug, Deserialize)]
+pub struct SetKillSwitchRequest {
+    pub engaged: bool,
+    pub reason: Option<String>,
+}
+
+/// GET /admin/kill-switch — current kill switch state
+pub async fn get_kill_switch(State(state): State<AppState>) -> ApiResponse<KillSwitchStatus> {
+    Ok(ApiSuccessResponse::new(
+        state.kill_switch.read().await.clone(),
+    ))
+}
+
+/// POST /admin/kill-switch — engage or release (requires `x-admin-key`)
+pub async fn set_kill_switch(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<SetKillSwitchRequest>,
+) -> ApiResponse<KillSwitchStatus> {
+    authorize(&state, &headers)?;
+
+    let status = KillSwitchStatus {
+        engaged: req.engaged,
+        reason: req.reason.filter(|_| req.engaged),
+        updated_at: now_millis(),
+    };
+
+    tracing::warn!(
+        engaged = status.engaged,
+        reason = ?status.reason,
+        "Kill switch updated via admin API"
+    );
+
+    let before = std::mem::replace(&mut *state.kill_switch.write().await, status.clone());
+    state.audit(
+        ADMIN_ACTOR,
+        AuditAction::Admin,
+        "kill_switch",
+        serde_json::to_value(before).ok(),
+        serde_json::to_value(&status).ok(),
+    );
+
+    let message = if status.engaged {
+        "Fulfillment halted"
+    } else {
+        "Fulfillment resumed"
+    };
+    Ok(ApiSuccessResponse::new(status).with_message(message))
+}
+
+/// Result of a config reload
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigReloadResponse {
+    /// Config generation now in effect
+    pub generation: u64,
+    /// Runtime settings (CORS, caching, compression) generation now in effect
+    pub runtime_generation: u64,
+}
+
+/// Re-read `.env`, the agent config and the runtime settings
+///
+/// Nothing is applied unless both parse; each then swaps in whole.
+pub(crate) fn reload_configs(
+    state: &AppState,
+    actor: &str,
+) -> Result<ConfigReloadResponse, String> {
+    let before = serde_json::json!({
+        "generation": state.agent_config.generation(),
+        "runtime_generation": state.runtime.generation(),
+    });
+
+    dotenvy::dotenv_override().ok();
+    let runtime = RuntimeConfig::from_env().map_err(|e| e.to_string())?;
+    state.agent_config.reload().map_err(|e| e.to_string())?;
+    state.runtime.replace(runtime);
+
+    let reloaded = ConfigReloadResponse {
+        generation: state.agent_config.generation(),
+        runtime_generation: state.runtime.generation(),
+    };
+    state.audit(
+        actor,
+        AuditAction::Admin,
+        "config_reload",
+        Some(before),
+        Some(serde_json::json!({
+            "generation": reloaded.generation,
+            "runtime_generation": reloaded.runtime_generation,
+        })),
+    );
+    Ok(reloaded)
+}
+
+/// POST /admin/config/reload — re-read the agent config and runtime settings
+/// (requires `x-admin-key`)
+pub async fn reload_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResponse<ConfigReloadResponse> {
+    authorize(&state, &headers)?;
+
+    let reloaded = reload_configs(&state, ADMIN_ACTOR)
+        .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::UNPROCESSABLE_ENTITY))?;
+
+    tracing::info!("Config reloaded via admin API");
+    Ok(ApiSuccessResponse::new(reloaded).with_message("Config reloaded"))
+}
+
+/// GET /admin/strategies — registered custom strategies
+pub async fn list_strategies(State(state): State<AppState>) -> ApiResponse<Vec<CustomStrategy>> {
+    Ok(ApiSuccessResponse::new(
+        state.agent_config.get().strategy_registry().list(),
+    ))
+}
+
+/// POST /admin/strategies — register or replace a custom strategy
+/// (requires `x-admin-key`)
+///
+/// Lives in the running config only; a config reload drops strategies that
+/// aren't in the file.
+pub async fn register_strategy(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(strategy): Json<CustomStrategy>,
+) -> ApiResponse<CustomStrategy> {
+    authorize(&state, &headers)?;
+
+    strategy.validate().map_err(|e| {
+        ApiErrorResponse::new(e.to_string()).with_code(StatusCode::UNPROCESSABLE_ENTITY)
+    })?;
+    let before = state
+        .agent_config
+        .get()
+        .strategies
+        .into_iter()
+        .find(|s| s.id == strategy.id);
+    state.agent_config.update(|config| {
+        config.strategies.retain(|s| s.id != strategy.id);
+        config.strategies.push(strategy.clone());
+    });
+    state.audit(
+        ADMIN_ACTOR,
+        AuditAction::Admin,
+        "register_strategy",
+        before.and_then(|s| serde_json::to_value(s).ok()),
+        serde_json::to_value(&strategy).ok(),
+    );
+
+    tracing::info!(id = strategy.id, name = %strategy.name, "Custom strategy registered");
+    Ok(ApiSuccessResponse::new(strategy)
+        .with_code(StatusCode::CREATED)
+        .with_message("Strategy registered"))
+}
+
+/// DELETE /admin/strategies/{id} — remove a custom strategy (requires `x-admin-key`)
+pub async fn remove_strategy(
+    State(state): State<AppState>,
+    Path(id): Path<u8>,
+    headers: HeaderMap,
+) -> ApiResponse<CustomStrategy> {
+    authorize(&state, &headers)?;
+
+    let removed = state
+        .agent_config
+        .get()
+        .strategies
+        .into_iter()
+        .find(|s| s.id == id)
+        .ok_or_else(|| {
+            ApiErrorResponse::new(format!("Strategy {} not found", id))
+                .with_code(StatusCode::NOT_FOUND)
+        })?;
+    state
+        .agent_config
+        .update(|config| config.strategies.retain(|s| s.id != id));
+    state.audit(
+        ADMIN_ACTOR,
+        AuditAction::Admin,
+        "remove_strategy",
+        serde_json::to_value(&removed).ok(),
+        None,
+    );
+
+    tracing::info!(id, "Custom strategy removed");
+    Ok(ApiSuccessResponse::new(removed).with_message("Strategy removed"))
+}
+
+/// GET /admin/solvers/config — solver tuning set through the API, polled by
+/// solver daemons
+pub async fn get_solver_overrides(State(state): State<AppState>) -> ApiResponse<SolverOverrides> {
+    Ok(ApiSuccessResponse::new(
+        state.solver_overrides.read().await.clone(),
+    ))
+}
+
+/// PUT /admin/solvers/{name}/config — adjust a solver's tuning on every
+/// daemon without a restart (requires `x-admin-key`)
+///
+/// Fields left out keep their current value.
+pub async fn set_solver_config(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    Json(tuning): Json<SolverTuning>,
+) -> ApiResponse<SolverTuning> {
+    authorize(&state, &headers)?;
+
+    if SolverRegistry::builtin().get(&name).is_none() {
+        return Err(ApiErrorResponse::new(format!("Unknown solver: {}", name))
+            .with_code(StatusCode::NOT_FOUND));
+    }
+    if tuning.max_in_flight.is_some() {
+        return Err(ApiErrorResponse::new(
+            "max_in_flight is read at daemon startup; set it in the config file",
+        )
+        .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+    }
+
+    let mut overrides = state.solver_overrides.write().await;
+    let before = overrides.solvers.get(&name).cloned();
+    let after = overrides.set(&name, &tuning);
+    let version = overrides.version;
+    drop(overrides);
+    state.audit(
+        ADMIN_ACTOR,
+        AuditAction::Admin,
+        &format!("solver_config:{}", name),
+        before.and_then(|t| serde_json::to_value(t).ok()),
+        serde_json::to_value(&after).ok(),
+    );
+
+    tracing::info!(solver = %name, version, "Solver config updated via admin API");
+    Ok(ApiSuccessResponse::new(after).with_message("Solver config updated"))
+}
+
+/// Feature flags in force, and the overrides daemons layer over their config
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureFlagsResponse {
+    /// The agent config's `[features]` with the overrides applied
+    pub flags: FeatureFlags,
+    pub overrides: FeatureOverrides,
+}
+
+/// Request body for switching a feature flag
+#[derive(Debug, Deserialize)]
+pub struct SetFeatureRequest {
+    pub enabled: bool,
+}
+
+/// GET /admin/features — feature flags, polled by solver daemons
+pub async fn get_features(State(state): State<AppState>) -> ApiResponse<FeatureFlagsResponse> {
+    let overrides = state.feature_overrides.read().await.clone();
+    Ok(ApiSuccessResponse::new(FeatureFlagsResponse {
+        flags: overrides.resolve(&state.agent_config.get().features),
+        overrides,
+    }))
+}
+
+/// PUT /admin/features/{name} — switch a feature on or off on every daemon
+/// without a restart (requires `x-admin-key`)
+pub async fn set_feature(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<SetFeatureRequest>,
+) -> ApiResponse<FeatureFlagsResponse> {
+    authorize(&state, &headers)?;
+
+    let feature: Feature = name
+        .parse()
+        .map_err(|e: String| ApiErrorResponse::new(e).with_code(StatusCode::NOT_FOUND))?;
+
+    let mut overrides = state.feature_overrides.write().await;
+    let base = state.agent_config.get().features;
+    let before = overrides.resolve(&base).is_enabled(feature);
+    overrides.set(feature, req.enabled);
+    let response = FeatureFlagsResponse {
+        flags: overrides.resolve(&base),
+        overrides: overrides.clone(),
+    };
+    drop(overrides);
+    state.audit(
+        ADMIN_ACTOR,
+        AuditAction::Admin,
+        &format!("feature:{}", feature),
+        Some(serde_json::json!({ "enabled": before })),
+        Some(serde_json::json!({ "enabled": req.enabled })),
+    );
+
+    tracing::info!(%feature, enabled = req.enabled, "Feature flag switched via admin API");
+    let message = if req.enabled {
+        "Feature enabled"
+    } else {
+        "Feature disabled"
+    };
+    Ok(ApiSuccessResponse::new(response).with_message(message))
+}
+
+/// GET /admin/audit — audit log, newest first (requires `x-admin-key`)
+///
+/// Filters: `action`, `actor`, `target`, `since` (unix millis), `limit`.
+pub async fn get_audit_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(filter): Query<AuditFilter>,
+) -> ApiResponse<Vec<AuditEntry>> {
+    authorize(&state, &headers)?;
+    let entries = state
+        .audit
+        .lock()
+        .expect("audit lock poisoned")
+        .query(&filter);
+    Ok(ApiSuccessResponse::new(entries))
+}
+
+/// GET /admin/stuck-intents — intents past their status SLA, longest stalled
+/// first, with a suggested remediation (requires `x-admin-key`)
+pub async fn get_stuck_intents(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResponse<Vec<StuckIntent>> {
+    authorize(&state, &headers)?;
+    let stuck = stuck_intents(
+        &state.list_intents().await,
+        &state.agent_config.get().watchdog,
+        (now_millis() / 1000) as i64,
+    );
+    Ok(ApiSuccessResponse::new(stuck))
+}
+
+/// GET /admin/snapshot — export intents, bids and positions (requires `x-admin-key`)
+pub async fn export_snapshot(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResponse<StateSnapshot> {
+    authorize(&state, &headers)?;
+    Ok(ApiSuccessResponse::new(state.export_snapshot().await))
+}
+
+/// Query for snapshot imports
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportQuery {
+    /// Overwrite existing state instead of requiring an empty instance
+    #[serde(default)]
+    pub replace: bool,
+}
+
+/// POST /admin/snapshot — restore an exported snapshot (requires `x-admin-key`)
+pub async fn import_snapshot(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ImportQuery>,
+    Json(snapshot): Json<StateSnapshot>,
+) -> ApiResponse<ImportSummary> {
+    authorize(&state, &headers)?;
+
+    let summary = state
+        .import_snapshot(snapshot, query.replace, ADMIN_ACTOR)
+        .await
+        .map_err(|e| {
+            let code = match e {
+                SnapshotError::NotEmpty => StatusCode::CONFLICT,
+                _ => StatusCode::UNPROCESSABLE_ENTITY,
+            };
+            ApiErrorResponse::new(e.to_string()).with_code(code)
+        })?;
+
+    tracing::info!(?summary, "Snapshot imported via admin API");
+    Ok(ApiSuccessResponse::new(summary).with_message("Snapshot imported"))
+}
+
+/// Check the admin key; admin calls are disabled when no key is configured
+pub(crate) fn authorize(state: &AppState, headers: &HeaderMap) -> Result<(), ApiErrorResponse> {
+    let Some(expected) = state.config.admin.api_key.as_ref() else {
+        return Err(
+            ApiErrorResponse::new("Admin API is disabled (ADMIN_API_KEY not set)")
+                .with_code(StatusCode::FORBIDDEN),
+        );
+    };
+
+    let provided = headers.get(ADMIN_KEY_HEADER).and_then(|v| v.to_str().ok());
+
+    if !provided.is_some_and(|key| expected.matches(key)) {
+        return Err(ApiErrorResponse::new("Invalid admin key").with_code(StatusCode::UNAUTHORIZED));
+    }
+    Ok(())
+}
+
+pub(crate) fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,30 @@
+use axum::routing::{delete, get, post, put};
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn admin_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/kill-switch",
+            get(handler::get_kill_switch).post(handler::set_kill_switch),
+        )
+        .route("/config/reload", post(handler::reload_config))
+        .route("/audit", get(handler::get_audit_log))
+        .route("/stuck-intents", get(handler::get_stuck_intents))
+        .route(
+            "/snapshot",
+            get(handler::export_snapshot).post(handler::import_snapshot),
+        )
+        .route(
+            "/strategies",
+            get(handler::list_strategies).post(handler::register_strategy),
+        )
+        .route("/strategies/{id}", delete(handler::remove_strategy))
+        .route("/solvers/config", get(handler::get_solver_overrides))
+        .route("/solvers/{name}/config", put(handler::set_solver_config))
+        .route("/features", get(handler::get_features))
+        .route("/features/{name}", put(handler::set_feature))
+}
@@ -0,0 +1,154 @@
+//! CCTP Relay
+//!
+//! Background job completing Sui → EVM intents. Intents in `Bridging` with
+//! a recorded burn digest are relayed once Circle has attested the burn:
+//! the mint transaction becomes the intent's `dest_tx_hash` and the intent
+//! is `Completed`. A reverted mint fails the intent at the attestation
+//! stage, so a retry relays it again. Disabled while `EVM_RELAYER_KEY` is
+//! unset.
+//!
+//! Completed mints feed the bridge latency tracker, and intents bridging
+//! for longer than their route's p95 raise an alert once.
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use naisu_agent::bridge_latency::{self, LatencySample};
+use naisu_agent::cctp_relay::{AttestationClient, CctpRelayer};
+use naisu_agent::executor::evm_executor::EvmExecutor;
+use naisu_agent::notify::{Alert, AlertLevel, NotificationHub};
+use naisu_core::{Direction, EvmChain, IntentStatus};
+
+use crate::config::Config;
+use crate::feature::admin::handler::now_millis;
+use crate::state::AppState;
+
+/// Actor recorded in the audit log for relayed intents
+pub const RELAYER_ACTOR: &str = "cctp_relayer";
+
+/// Time between relay passes
+const RELAY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Relayer with an executor on every EVM chain
+///
+/// The configured `EVM_RPC_URL` serves its own chain, the rest use public
+/// endpoints.
+fn relayer(config: &Config, key: &str) -> Result<CctpRelayer, String> {
+    let mut relayer = CctpRelayer::new(AttestationClient::new(&config.bridge.cctp_api_url));
+    for chain in EvmChain::ALL {
+        let rpc_url = config.evm.rpc_url_for(chain);
+        let executor =
+            EvmExecutor::new(rpc_url, chain.chain_id(), key).map_err(|e| e.to_string())?;
+        relayer = relayer.with_executor(chain, executor);
+    }
+    Ok(relayer)
+}
+
+/// Relay every attested burn once; returns how many intents completed
+///
+/// The attestation leg of a completed transfer is measured up to the pass
+/// that found it, so it runs up to one relay interval long.
+pub async fn relay_once(state: &AppState, relayer: &CctpRelayer) -> usize {
+    let bridging: Vec<_> = state
+        .list_intents()
+        .await
+        .into_iter()
+        .filter(|i| i.direction == Direction::SuiToEvm && i.status == IntentStatus::Bridging)
+        .collect();
+
+    let mut completed = 0;
+    for mut intent in bridging {
+        let Some(burn) = intent.bridge_tx_hash.clone() else {
+            continue;
+        };
+        let burned_for = (now_millis() / 1000).saturating_sub(intent.updated_at.max(0) as u64);
+        let started = Instant::now();
+        match relayer.relay(intent.evm_chain, &burn).await {
+            Ok(None) => {}
+            Ok(Some(receipt)) if receipt.success => {
+                let sample = LatencySample::new(Duration::from_secs(burned_for), started.elapsed());
+                state
+                    .bridge_latency
+                    .write()
+                    .await
+                    .record(&bridge_latency::route(&intent), sample);
+                intent.dest_tx_hash = Some(receipt.tx_hash.clone());
+                intent.set_status(IntentStatus::Completed);
+                state.upsert_intent(intent.clone(), RELAYER_ACTOR).await;
+                tracing::info!(
+                    intent_id = %intent.id,
+                    tx_hash = %receipt.tx_hash,
+                    "CCTP mint completed"
+                );
+                completed += 1;
+            }
+            Ok(Some(receipt)) => {
+                intent.fail(format!("receiveMessage reverted: {}", receipt.tx_hash));
+                state.upsert_intent(intent.clone(), RELAYER_ACTOR).await;
+                tracing::warn!(intent_id = %intent.id, tx_hash = %receipt.tx_hash, "CCTP mint reverted");
+            }
+            Err(e) => tracing::warn!(intent_id = %intent.id, "CCTP relay failed: {}", e),
+        }
+    }
+    completed
+}
+
+/// Alert once for every intent bridging longer than its route's p95
+///
+/// `alerted` holds the intents already reported and is pruned to those
+/// still bridging.
+pub async fn alert_overdue(
+    state: &AppState,
+    notifier: &NotificationHub,
+    alerted: &mut HashSet<String>,
+) {
+    let bridging: Vec<_> = state
+        .list_intents()
+        .await
+        .into_iter()
+        .filter(|i| i.status == IntentStatus::Bridging)
+        .collect();
+    alerted.retain(|id| bridging.iter().any(|i| &i.id == id));
+
+    for intent in bridging {
+        let Some(eta) = state.bridge_eta(&intent).await else {
+            continue;
+        };
+        if eta.overdue && alerted.insert(intent.id.clone()) {
+            let alert = Alert::new(
+                AlertLevel::Warning,
+                "Bridge overdue",
+                format!(
+                    "Intent {} ({}) has been bridging for {} min, past the p95 of recent transfers",
+                    intent.id,
+                    bridge_latency::route(&intent),
+                    eta.elapsed_secs / 60
+                ),
+            );
+            notifier.send(alert).await;
+        }
+    }
+}
+
+/// Relay forever
+pub async fn run(state: AppState) {
+    let Some(key) = state.config.evm.relayer_key.clone() else {
+        tracing::info!("EVM_RELAYER_KEY unset, CCTP relayer disabled");
+        return;
+    };
+    let relayer = match relayer(&state.config, key.expose()) {
+        Ok(relayer) => relayer,
+        Err(e) => {
+            tracing::warn!("Invalid EVM_RELAYER_KEY, CCTP relayer disabled: {}", e);
+            return;
+        }
+    };
+
+    let notifier = NotificationHub::from_env();
+    let mut alerted = HashSet::new();
+    loop {
+        relay_once(&state, &relayer).await;
+        alert_overdue(&state, &notifier, &mut alerted).await;
+        tokio::time::sleep(RELAY_INTERVAL).await;
+    }
+}
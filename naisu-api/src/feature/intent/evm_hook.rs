@@ -0,0 +1,98 @@
+//! EVM Hook Intake
+//!
+//! Background job turning the V4 hook's `IntentCreated` logs into EvmToSui
+//! intents. The hook has already swapped the input to USDC, so intents are
+//! stored in `SwapCompleted` with the log's transaction as their swap tx.
+//! Disabled while `HOOK_ADDRESS` is unset or the zero address.
+
+use naisu_agent::evm_hook::{HookListener, HookLog};
+use naisu_core::{EvmChain, Intent, IntentSource, IntentStatus, YieldStrategy};
+
+use crate::feature::admin::handler::now_millis;
+use crate::state::{new_intent_id, AppState};
+
+/// Actor recorded in the audit log for hook-created intents
+pub const HOOK_ACTOR: &str = "evm_hook";
+
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// The intent a hook event starts, linked to the event's `intentId`
+pub fn intent_from_log(log: &HookLog, chain: EvmChain) -> Intent {
+    let event = &log.event;
+    let created_ms = match event.timestamp {
+        0 => now_millis(),
+        secs => secs.saturating_mul(1000),
+    };
+    let mut intent = Intent::new_evm_to_sui(
+        new_intent_id(IntentSource::EvmHook, created_ms),
+        event.user.clone(),
+        event.sui_destination.clone(),
+        chain,
+        event.input_token.clone(),
+        event.input_amount.clone(),
+        YieldStrategy::from_id(event.strategy_id),
+    );
+    intent.usdc_amount = Some(event.usdc_amount.clone());
+    intent.swap_tx_hash = Some(log.tx_hash.clone());
+    intent.evm_intent_id = Some(event.intent_id.to_lowercase());
+    intent.status = IntentStatus::SwapCompleted;
+    if event.timestamp > 0 {
+        intent.created_at = event.timestamp as i64;
+    }
+    intent
+}
+
+/// Store intents for new hook events; returns how many were created
+///
+/// Events for intents already stored (a rescanned block) are ignored.
+pub async fn ingest(state: &AppState, logs: &[HookLog], chain: EvmChain) -> usize {
+    let mut created = 0;
+    for log in logs {
+        if state.find_intent(&log.event.intent_id).await.is_some() {
+            continue;
+        }
+        let intent = intent_from_log(log, chain);
+        tracing::info!(
+            intent_id = %intent.id,
+            evm_intent_id = %log.event.intent_id,
+            tx_hash = %log.tx_hash,
+            "Intent created from EVM hook"
+        );
+        state.upsert_intent(intent, HOOK_ACTOR).await;
+        created += 1;
+    }
+    created
+}
+
+/// Follow the hook forever
+pub async fn run(state: AppState) {
+    let evm = state.config.evm.clone();
+    if evm.hook_address.is_empty() || evm.hook_address.eq_ignore_ascii_case(ZERO_ADDRESS) {
+        tracing::info!("HOOK_ADDRESS unset, EVM hook listener disabled");
+        return;
+    }
+    let Some(chain) = EvmChain::from_chain_id(evm.chain_id) else {
+        tracing::warn!(
+            "Unknown EVM_CHAIN_ID {}, EVM hook listener disabled",
+            evm.chain_id
+        );
+        return;
+    };
+
+    let listener = HookListener::new(&evm.rpc_url, &evm.hook_address);
+    let mut from = evm.start_block;
+    loop {
+        let next = match from {
+            Some(block) => listener.poll(block).await,
+            None => listener.block_number().await.map(|head| (Vec::new(), head)),
+        };
+        match next {
+            Ok((logs, next)) => {
+                ingest(&state, &logs, chain).await;
+                from = Some(next);
+            }
+            Err(e) => tracing::warn!("EVM hook poll failed: {}", e),
+        }
+        tokio::time::sleep(listener.interval()).await;
+    }
+}
@@ -1,17 +1,63 @@
 //! Intent Handlers
 //!
-//! API endpoints for querying intents (cached/indexed)
+//! API endpoints for querying intents (cached/indexed) and for solver
+//! daemons to report fulfillments
 
-use axum::{extract::Query, Json};
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use naisu_agent::analytics::{
+    self, FulfillmentSample, IntentSample, Percentiles, ProtocolShare, SampleStatus,
+    SolverStanding, Window,
+};
+use naisu_agent::bridge_latency::{Eta, RouteLatency};
+use naisu_agent::commitment::{Commitment, CommitmentError};
+use naisu_agent::config::{Network, Protocol};
+use naisu_agent::fees::FeeReport;
+use naisu_agent::intent_auth::{verify_intent_from, IntentAuthError};
+use naisu_agent::receipt::{FulfillmentReceipt, SignedReceipt};
+use naisu_agent::reconcile::{ChainIntent, ChainState, Drift, DriftSnapshot};
+use naisu_agent::token_check::TokenCheckError;
+use naisu_agent::verify::{Expected, Verification, VerificationStatus};
+use naisu_core::{
+    format_units, is_stablecoin, retry_status, validate_evm_address, validate_sui_address,
+    CreateIntentRequest, Direction, EvmRefundTx, ExplorerLink, FailureStage, FulfillmentReport,
+    Intent, IntentSource, IntentStatus, RecoveryError, RefundChain, RefundPlan, RiskTier,
+    YieldStrategy, SUI_DECIMALS,
+};
+use naisu_sui::{
+    CoinObject, GasData, IntentPackage, NewIntent, ObjectRef, ProgrammableTransactionBlock,
+    SuiClient, SuiClientError, SuiConfig, VerifyError, ANY_PROTOCOL, SUI_COIN_TYPE,
+};
 use serde::{Deserialize, Serialize};
 
-use crate::common::response::{success::ApiSuccessResponse, ApiResponse};
+use crate::common::response::{
+    ApiErrorResponse, ApiResponse, ApiSuccessResponse, ErrorCode, PageQuery,
+};
+use crate::feature::admin::handler::{authorize, now_millis, ADMIN_ACTOR};
+use crate::feature::quote::handler::token_error;
+use crate::names::{AddressKind, NameError, Resolved};
+use crate::state::{new_intent_id, AppState, RefKind};
+
+/// Header carrying the solver API key
+pub const SOLVER_KEY_HEADER: &str = "x-solver-key";
+
+/// Audit actor for status changes made by settlement verification
+const VERIFIER_ACTOR: &str = "settlement_verifier";
+
+/// Audit actor for intents linked to their chain IDs
+const LINK_ACTOR: &str = "intent_links";
 
 /// Intent response
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct IntentResponse {
     pub intent_id: String,
     pub user: String,
+    /// SuiNS name of `user`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
     pub amount: String,
     pub min_apy: u64,
     pub deadline: u64,
@@ -19,26 +65,120 @@ pub struct IntentResponse {
     pub target_protocol: String,
     pub created_at: u64,
     pub tx_digest: String,
+    /// Reported by the winning solver
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment: Option<FulfillmentReport>,
+    /// On-chain check of the fulfillment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+    /// Explorer links on the API's current network
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explorer: Option<ExplorerUrls>,
+}
+
+/// Explorer links for an intent's transactions
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct ExplorerUrls {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_tx: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_tx: Option<String>,
+}
+
+impl IntentResponse {
+    /// Overlay a solver's fulfillment report
+    fn with_fulfillment(mut self, report: Option<FulfillmentReport>) -> Self {
+        if report.is_some() {
+            self.status = "fulfilled".to_string();
+        }
+        self.fulfillment = report;
+        self
+    }
+
+    /// Overlay the settlement check: only a verified fulfillment completes
+    fn with_verification(mut self, verification: Option<Verification>) -> Self {
+        match verification.as_ref().map(|v| v.status) {
+            Some(VerificationStatus::Verified) => self.status = "completed".to_string(),
+            Some(VerificationStatus::Mismatch) => self.status = "disputed".to_string(),
+            _ => {}
+        }
+        self.verification = verification;
+        self
+    }
+
+    async fn overlay(mut self, state: &AppState) -> Self {
+        if let Some(chain) = state.chain_intents.read().await.get(&self.intent_id) {
+            match chain.state {
+                ChainState::Cancelled { reason: 0 } => self.status = "expired".to_string(),
+                ChainState::Cancelled { .. } => self.status = "cancelled".to_string(),
+                ChainState::Closed => self.status = "closed".to_string(),
+                _ => {}
+            }
+        }
+        self.user_name = state
+            .names
+            .reverse(&state.sui_client(), &self.user, AddressKind::Sui)
+            .await;
+        let report = state.get_fulfillment(&self.intent_id).await;
+        let verification = state.get_verification(&self.intent_id).await;
+        let explorer = state.network().parse::<Network>().ok();
+        self.with_fulfillment(report)
+            .with_verification(verification)
+            .with_explorer(explorer.and_then(|n| n.explorer()))
+    }
+
+    /// Link the creation and fulfillment transactions
+    fn with_explorer(mut self, explorer: Option<ExplorerLink>) -> Self {
+        self.explorer = explorer.map(|explorer| ExplorerUrls {
+            creation_tx: Some(&self.tx_digest)
+                .filter(|d| !d.is_empty())
+                .map(|d| explorer.tx(d)),
+            fulfillment_tx: self.fulfillment.as_ref().map(|f| explorer.tx(&f.digest)),
+        });
+        self
+    }
+}
+
+impl From<&ChainIntent> for IntentResponse {
+    fn from(intent: &ChainIntent) -> Self {
+        Self {
+            intent_id: intent.intent_id.clone(),
+            user: intent.user.clone(),
+            user_name: None,
+            amount: intent.amount.to_string(),
+            min_apy: intent.min_apy,
+            deadline: intent.deadline,
+            status: match intent.state {
+                ChainState::Fulfilled { .. } => "fulfilled".to_string(),
+                _ => "open".to_string(),
+            },
+            target_protocol: intent.target_protocol.clone(),
+            created_at: intent.created_at.unwrap_or_default(),
+            tx_digest: intent.digest.clone().unwrap_or_default(),
+            fulfillment: None,
+            verification: None,
+            explorer: None,
+        }
+    }
 }
 
 /// Query parameters for listing intents
 #[derive(Deserialize)]
 pub struct ListIntentsQuery {
-    pub status: Option<String>, // "open", "fulfilled", "expired"
+    pub status: Option<String>, // "open", "fulfilled", "completed", "disputed", "expired", "cancelled", "closed"
     pub limit: Option<usize>,
+    pub cursor: Option<String>,
 }
 
-/// List intents (cached from blockchain)
-pub async fn list_intents(
-    Query(params): Query<ListIntentsQuery>,
-) -> ApiResponse<Json<ApiSuccessResponse<Vec<IntentResponse>>>> {
-    // In production: query from database (cached)
-    // For now: mock data showing structure
-    
-    let mut intents = vec![
+/// Indexed intents (mock data until the indexer lands)
+pub(crate) fn mock_intents() -> Vec<IntentResponse> {
+    vec![
         IntentResponse {
-            intent_id: "0x56241772c0fc5bf95d2e18ed2e8129f1a2ae4b592b21b3a66e67d09b851d20b6".to_string(),
+            intent_id: "0x56241772c0fc5bf95d2e18ed2e8129f1a2ae4b592b21b3a66e67d09b851d20b6"
+                .to_string(),
             user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
+            user_name: None,
             amount: "1000000000".to_string(),
             min_apy: 720,
             deadline: 1770326616245,
@@ -46,10 +186,15 @@ pub async fn list_intents(
             target_protocol: "any".to_string(),
             created_at: 1770287442164,
             tx_digest: "BpJnnnSRkjUNqFR27rHexcCEf9Dr6uwhiUi2UCkAPhzj".to_string(),
+            fulfillment: None,
+            verification: None,
+            explorer: None,
         },
         IntentResponse {
-            intent_id: "0x6053a19f8240c8c6134e1955f443ee9fa207aa57f18258711b83a6611bbee01c".to_string(),
+            intent_id: "0x6053a19f8240c8c6134e1955f443ee9fa207aa57f18258711b83a6611bbee01c"
+                .to_string(),
             user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
+            user_name: None,
             amount: "1000".to_string(),
             min_apy: 720,
             deadline: 1770326616245,
@@ -57,63 +202,1332 @@ pub async fn list_intents(
             target_protocol: "scallop".to_string(),
             created_at: 1770287538404,
             tx_digest: "t6uFYkEcB1DFjNmodqRGVC2rUhuFc4cX5YaqdJwEA94".to_string(),
+            fulfillment: None,
+            verification: None,
+            explorer: None,
         },
-    ];
-    
+    ]
+}
+
+/// Every known intent with reports and chain state applied, newest first
+async fn known_intents(state: &AppState) -> Vec<IntentResponse> {
+    // In production: query from database (cached)
+    // For now: mock data plus whatever the reconciler found on chain
+    let mut known = mock_intents();
+    for chain in state.chain_intents.read().await.values() {
+        if !known.iter().any(|i| i.intent_id == chain.intent_id) {
+            known.push(chain.into());
+        }
+    }
+    known.sort_by_key(|i| std::cmp::Reverse(i.created_at));
+
+    let mut intents = Vec::new();
+    for intent in known {
+        intents.push(intent.overlay(state).await);
+    }
+    intents
+}
+
+/// A known marketplace intent with reports and chain state applied
+pub(crate) async fn find_intent(state: &AppState, intent_id: &str) -> Option<IntentResponse> {
+    let chain = state
+        .chain_intents
+        .read()
+        .await
+        .get(intent_id)
+        .map(IntentResponse::from);
+    let found = mock_intents()
+        .into_iter()
+        .find(|i| i.intent_id == intent_id)
+        .or(chain)?;
+    Some(found.overlay(state).await)
+}
+
+/// List intents (cached from blockchain)
+pub async fn list_intents(
+    State(state): State<AppState>,
+    Query(params): Query<ListIntentsQuery>,
+) -> ApiResponse<Vec<IntentResponse>> {
+    let mut intents = known_intents(&state).await;
+
     // Filter by status if provided
     if let Some(status) = params.status {
         intents.retain(|i| i.status == status);
     }
-    
-    // Apply limit
-    let limit = params.limit.unwrap_or(20);
-    intents.truncate(limit);
-    
-    Ok(ApiSuccessResponse::new(intents))
+
+    let (intents, pagination) = PageQuery {
+        limit: params.limit,
+        cursor: params.cursor,
+    }
+    .paginate(intents)?;
+
+    Ok(ApiSuccessResponse::new(intents).with_pagination(pagination))
 }
 
 /// Get single intent by ID
 pub async fn get_intent(
-    axum::extract::Path(intent_id): axum::extract::Path<String>,
-) -> ApiResponse<Json<ApiSuccessResponse<IntentResponse>>> {
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<IntentResponse> {
     // Mock: in production query from DB
-    let intent = IntentResponse {
-        intent_id: intent_id.clone(),
-        user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
-        amount: "1000000000".to_string(),
-        min_apy: 720,
-        deadline: 1770326616245,
-        status: "open".to_string(),
-        target_protocol: "any".to_string(),
-        created_at: 1770287442164,
-        tx_digest: "BpJnnnSRkjUNqFR27rHexcCEf9Dr6uwhiUi2UCkAPhzj".to_string(),
-    };
-    
+    let chain = state
+        .chain_intents
+        .read()
+        .await
+        .get(&intent_id)
+        .map(IntentResponse::from);
+    let intent = mock_intents()
+        .into_iter()
+        .find(|i| i.intent_id == intent_id)
+        .or(chain)
+        .unwrap_or_else(|| IntentResponse {
+            intent_id: intent_id.clone(),
+            user: "0xf800cb70f9f90d4f9858efbfe3ecdf0c1540d36c185807532892a98883e9c7fa".to_string(),
+            user_name: None,
+            amount: "1000000000".to_string(),
+            min_apy: 720,
+            deadline: 1770326616245,
+            status: "open".to_string(),
+            target_protocol: "any".to_string(),
+            created_at: 1770287442164,
+            tx_digest: "BpJnnnSRkjUNqFR27rHexcCEf9Dr6uwhiUi2UCkAPhzj".to_string(),
+            fulfillment: None,
+            verification: None,
+            explorer: None,
+        });
+
+    Ok(ApiSuccessResponse::new(intent.overlay(&state).await))
+}
+
+/// Query parameters for searching intents; exactly one is required
+#[derive(Debug, Deserialize)]
+pub struct SearchIntentsQuery {
+    /// Swap, bridge, destination, refund, creation or fulfillment tx
+    pub digest: Option<String>,
+    /// Intent object, EVM `intentId`, or an object a fulfillment created
+    pub object: Option<String>,
+}
+
+/// The intent a digest or object belongs to
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct IntentSearchHit {
+    pub intent_id: String,
+    pub matched: RefKind,
+    /// Solver-marketplace intent, with reports and chain state applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intent: Option<IntentResponse>,
+    /// Cross-chain intent created through this API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross_chain: Option<Intent>,
+    /// Time left while the cross-chain intent is bridging
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge_eta: Option<Eta>,
+}
+
+/// GET /intents/search?digest=|object= — find the intent behind a digest or object
+pub async fn search_intents(
+    State(state): State<AppState>,
+    Query(params): Query<SearchIntentsQuery>,
+) -> ApiResponse<IntentSearchHit> {
+    let (reference, want_object) = match (params.digest, params.object) {
+        (Some(digest), None) => (digest, false),
+        (None, Some(object)) => (object, true),
+        _ => {
+            return Err(
+                ApiErrorResponse::new("Pass exactly one of digest or object")
+                    .with_code(StatusCode::BAD_REQUEST),
+            )
+        }
+    };
+
+    let indexed = state.intent_index.read().await.get(&reference);
+    // Mock intents aren't stored, so aren't indexed
+    let hit = indexed
+        .filter(|(_, kind)| kind.is_object() == want_object)
+        .or_else(|| {
+            mock_intents().into_iter().find_map(|i| {
+                if want_object && i.intent_id.eq_ignore_ascii_case(&reference) {
+                    Some((i.intent_id, RefKind::IntentObject))
+                } else if !want_object && i.tx_digest == reference {
+                    Some((i.intent_id, RefKind::CreationTx))
+                } else {
+                    None
+                }
+            })
+        });
+    let Some((intent_id, matched)) = hit else {
+        return Err(ApiErrorResponse::coded(
+            ErrorCode::IntentNotFound,
+            format!("No intent found for {}", reference),
+        ));
+    };
+
+    let cross_chain = state.get_intent(&intent_id).await;
+    let (intent, bridge_eta) = match &cross_chain {
+        Some(cross_chain) => (None, state.bridge_eta(cross_chain).await),
+        None => (find_intent(&state, &intent_id).await, None),
+    };
+
+    Ok(ApiSuccessResponse::new(IntentSearchHit {
+        intent_id,
+        matched,
+        intent,
+        cross_chain,
+        bridge_eta,
+    }))
+}
+
+/// POST /intents — create a cross-chain intent
+///
+/// The request must be signed by its source wallet (EIP-712 for EVM → Sui,
+/// a Sui personal message for Sui → EVM); the signature is stored with the
+/// intent as dispute evidence and can't be reused. An optional `referrer`
+/// is credited with the intent's volume and later with a share of its
+/// protocol fee. An optional `tip` is paid to the winning solver and gets
+/// the intent fulfilled ahead of untipped ones.
+///
+/// A request matching an open intent of the same user (see
+/// `Intent::is_near_duplicate`) returns that intent with 200 instead,
+/// unless `force=true`.
+pub async fn create_intent(
+    State(state): State<AppState>,
+    Query(params): Query<CreateIntentQuery>,
+    Json(req): Json<CreateIntentRequest>,
+) -> ApiResponse<Intent> {
+    match open_intent(&state, req, None, params.force).await? {
+        Opened::Created(intent) => Ok(ApiSuccessResponse::new(intent)
+            .with_code(StatusCode::CREATED)
+            .with_message("Intent created")),
+        Opened::Duplicate(intent) => {
+            let message = format!(
+                "Matches open intent {}; resubmit with force=true to create another",
+                intent.id
+            );
+            Ok(ApiSuccessResponse::new(intent).with_message(message))
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CreateIntentQuery {
+    /// Create the intent even if it duplicates an open one
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Outcome of `open_intent`
+enum Opened {
+    Created(Intent),
+    /// Nothing created; the open intent the request duplicates
+    Duplicate(Intent),
+}
+
+/// Statuses in which a matching intent counts as a double submission
+fn is_open(status: IntentStatus) -> bool {
+    matches!(
+        status,
+        IntentStatus::Pending
+            | IntentStatus::SwapCompleted
+            | IntentStatus::Bridging
+            | IntentStatus::BridgeCompleted
+    )
+}
+
+/// Validate a creation request and index the intent
+///
+/// `commitment` is the commit-reveal commitment being revealed, closed
+/// together with the intent's creation. Unless `force`, a near-duplicate
+/// of an open intent is returned instead of creating one.
+async fn open_intent(
+    state: &AppState,
+    mut req: CreateIntentRequest,
+    commitment: Option<&str>,
+    force: bool,
+) -> Result<Opened, ApiErrorResponse> {
+    let bad_request = |msg: &str| ApiErrorResponse::new(msg).with_code(StatusCode::BAD_REQUEST);
+
+    let (source_kind, dest_kind) = match req.direction {
+        Direction::EvmToSui => (AddressKind::Evm, AddressKind::Sui),
+        Direction::SuiToEvm => (AddressKind::Sui, AddressKind::Evm),
+    };
+    let source = resolve_name(state, &req.source_address, source_kind).await?;
+    let dest = resolve_name(state, &req.dest_address, dest_kind).await?;
+
+    // The signature covers the names as typed; the resolved source address
+    // must be the signer
+    let verifier = state.signature_verifier();
+    let signature = verify_intent_from(&req, &source.address, &verifier)
+        .await
+        .map_err(|e| {
+            let code = match e {
+                IntentAuthError::Malformed(_) | IntentAuthError::WrongKind { .. } => {
+                    StatusCode::BAD_REQUEST
+                }
+                IntentAuthError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+                _ => StatusCode::UNAUTHORIZED,
+            };
+            ApiErrorResponse::new(e.to_string()).with_code(code)
+        })?;
+
+    req.source_address = source.address;
+    req.dest_address = dest.address;
+
+    let volume = req.validate()?;
+
+    if let Some(strategy) = req.strategy {
+        let (tier, stable) = match strategy {
+            YieldStrategy::Custom(id) => state
+                .agent_config
+                .get()
+                .strategy_registry()
+                .get(id)
+                .map(|s| (s.risk_tier, is_stablecoin(&s.coin_type)))
+                .ok_or_else(|| bad_request(&format!("Unknown strategy ID: {}", id)))?,
+            builtin => (
+                builtin
+                    .protocol()
+                    .parse::<Protocol>()
+                    .map_or(RiskTier::default(), |p| p.risk_tier()),
+                builtin.is_stable(),
+            ),
+        };
+        if let Some(max) = req.max_risk.filter(|max| tier > *max) {
+            return Err(bad_request(&format!(
+                "strategy is {} risk, above max_risk {}",
+                tier.as_str(),
+                max.as_str()
+            )));
+        }
+        if req.stable_only && !stable {
+            return Err(bad_request(
+                "stable_only intents need a stablecoin strategy",
+            ));
+        }
+    }
+
+    let id = new_intent_id(IntentSource::Api, now_millis());
+    let mut intent = Intent::from_request(id, req)?;
+
+    let window = state.config.intents.duplicate_window_secs;
+    if !force && window > 0 {
+        // Checked before the replay check: a resent request carries the
+        // same signature and should get its intent back, not a conflict
+        let duplicate = state
+            .intents
+            .read()
+            .await
+            .values()
+            .filter(|i| is_open(i.status) && i.is_near_duplicate(&intent, window))
+            .max_by_key(|i| i.created_at)
+            .cloned();
+        if let Some(existing) = duplicate {
+            tracing::info!(
+                intent_id = %existing.id,
+                source = %existing.source_address,
+                "Duplicate intent submission"
+            );
+            return Ok(Opened::Duplicate(existing));
+        }
+    }
+
+    let replayed = state.intents.read().await.values().any(|i| {
+        i.signature
+            .as_ref()
+            .is_some_and(|s| s.signature == signature.signature)
+    });
+    if replayed {
+        return Err(ApiErrorResponse::new("Intent signature was already used")
+            .with_code(StatusCode::CONFLICT));
+    }
+
+    if intent.direction == Direction::EvmToSui {
+        check_input_token(state, &intent).await?;
+    }
+
+    if let Some(referrer) = &intent.referrer {
+        state
+            .referrals
+            .write()
+            .await
+            .record_intent(&intent.id, referrer, volume)
+            .map_err(|e| ApiErrorResponse::new(e.to_string()).with_code(StatusCode::CONFLICT))?;
+    }
+
+    intent.signature = Some(signature);
+    intent.source_name = source.name;
+    intent.dest_name = dest.name;
+    if let Some(hash) = commitment {
+        state
+            .commitments
+            .write()
+            .await
+            .mark_revealed(hash, &intent.id)
+            .map_err(commitment_error)?;
+        intent.commitment = Some(hash.to_lowercase());
+    }
+    state
+        .upsert_intent(intent.clone(), &intent.source_address)
+        .await;
+    tracing::info!(
+        intent_id = %intent.id,
+        direction = ?intent.direction,
+        referrer = ?intent.referrer,
+        tip = ?intent.tip,
+        committed = intent.commitment.is_some(),
+        "Intent created"
+    );
+    Ok(Opened::Created(intent))
+}
+
+/// Reject EVM input tokens the swap can't take (fee-on-transfer, odd
+/// decimals)
+///
+/// Symbols and native ETH aren't checked, nor is anything while the RPC is
+/// down.
+async fn check_input_token(state: &AppState, intent: &Intent) -> Result<(), ApiErrorResponse> {
+    let token = &intent.input_token;
+    if validate_evm_address(token).is_err()
+        || token.eq_ignore_ascii_case(intent.evm_chain.usdc_address())
+    {
+        return Ok(());
+    }
+    let rpc_url = state.config.evm.rpc_url_for(intent.evm_chain);
+    match state.tokens.metadata(rpc_url, token).await {
+        Ok(meta) => meta.check().map_err(token_error),
+        Err(TokenCheckError::Rpc(e)) => {
+            tracing::warn!("Input token {} not checked: {}", token, e);
+            Ok(())
+        }
+        Err(e) => Err(token_error(e)),
+    }
+}
+
+/// An address field of a request, resolving SuiNS / ENS names
+pub(crate) async fn resolve_name(
+    state: &AppState,
+    input: &str,
+    kind: AddressKind,
+) -> Result<Resolved, ApiErrorResponse> {
+    state
+        .names
+        .resolve(&state.sui_client(), input, kind)
+        .await
+        .map_err(|e| {
+            let code = match e {
+                NameError::NotFound(_) => StatusCode::BAD_REQUEST,
+                NameError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            };
+            ApiErrorResponse::new(e.to_string()).with_code(code)
+        })
+}
+
+// ─── On-chain creation ───────────────────────────────────────────────────────
+
+/// Default gas budget for an intent creation (MIST)
+const CREATE_INTENT_GAS_BUDGET: u64 = 50_000_000;
+
+/// Intent creation transaction request body
+#[derive(Debug, Deserialize)]
+pub struct IntentTransactionRequest {
+    /// Signs and pays gas; address or SuiNS name
+    pub sender: String,
+    /// Base units of `coin_type`
+    pub amount: u64,
+    /// Defaults to SUI
+    pub coin_type: Option<String>,
+    /// Basis points
+    pub min_apy: u64,
+    /// Seconds from execution
+    pub deadline_secs: u64,
+    /// Protocol name; any protocol when absent
+    pub target_protocol: Option<String>,
+    /// MIST
+    pub gas_budget: Option<u64>,
+}
+
+/// Unsigned `create_intent` transaction
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct IntentTransaction {
+    /// Base64 BCS `TransactionData` for the sender to sign
+    pub tx_bytes: String,
+    pub sender: String,
+    pub intent_package: String,
+    pub gas_price: u64,
+    pub gas_budget: u64,
+    pub summary: String,
+}
+
+/// Largest coins first until they cover `needed`
+fn select_coins(coins: &[CoinObject], needed: u64) -> Option<Vec<ObjectRef>> {
+    let mut coins: Vec<(&CoinObject, u64)> = coins
+        .iter()
+        .filter_map(|c| Some((c, c.balance.parse().ok()?)))
+        .collect();
+    coins.sort_by_key(|(_, balance)| std::cmp::Reverse(*balance));
+
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for (coin, balance) in coins {
+        if total >= needed {
+            break;
+        }
+        selected.push(ObjectRef::try_from(coin).ok()?);
+        total += balance;
+    }
+    (total >= needed).then_some(selected)
+}
+
+/// POST /intents/transaction — build a signable `create_intent` transaction
+///
+/// For Sui wallets creating intents without the frontend or the Move CLI.
+/// The amount is split off the sender's coins and locked in a new shared
+/// intent; SUI intents split it off the gas coin.
+pub async fn build_intent_transaction(
+    State(state): State<AppState>,
+    Json(req): Json<IntentTransactionRequest>,
+) -> ApiResponse<IntentTransaction> {
+    let bad_request =
+        |message: String| ApiErrorResponse::new(message).with_code(StatusCode::BAD_REQUEST);
+    if req.amount == 0 {
+        return Err(bad_request("amount must be positive".to_string()));
+    }
+    if req.deadline_secs == 0 {
+        return Err(bad_request("deadline_secs must be positive".to_string()));
+    }
+    let target_protocol = match req.target_protocol.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(name) if name.eq_ignore_ascii_case(ANY_PROTOCOL) => None,
+        Some(name) => Some(
+            name.parse::<Protocol>()
+                .map_err(bad_request)?
+                .name()
+                .to_string(),
+        ),
+    };
+    let sender = resolve_name(&state, &req.sender, AddressKind::Sui)
+        .await?
+        .address;
+    let coin_type = req.coin_type.unwrap_or_else(|| SUI_COIN_TYPE.to_string());
+    let gas_budget = req.gas_budget.unwrap_or(CREATE_INTENT_GAS_BUDGET);
+
+    let network = state.network().parse().unwrap_or(Network::Testnet);
+    let package = state
+        .agent_config
+        .get()
+        .intent_package(network)
+        .map(|id| IntentPackage::new(&id))
+        .map_err(|e| {
+            ApiErrorResponse::new(e.to_string()).with_code(StatusCode::SERVICE_UNAVAILABLE)
+        })?;
+
+    let client = state.sui_client();
+    let rpc_error = |e: SuiClientError| {
+        ApiErrorResponse::new(format!("Failed to prepare transaction: {}", e))
+            .with_code(StatusCode::BAD_GATEWAY)
+    };
+    let gas_price = client.get_reference_gas_price().await.map_err(rpc_error)?;
+    let sui_coins = client
+        .get_coins(&sender, Some(SUI_COIN_TYPE))
+        .await
+        .map_err(rpc_error)?;
+
+    // SUI intents pay the amount out of the gas coins too
+    let is_sui = coin_type == SUI_COIN_TYPE;
+    let gas_needed = gas_budget + if is_sui { req.amount } else { 0 };
+    let payment = select_coins(&sui_coins, gas_needed).ok_or_else(|| {
+        ApiErrorResponse::new(format!("Not enough SUI: {} MIST needed", gas_needed))
+            .with_code(StatusCode::CONFLICT)
+    })?;
+    let input_coins = if is_sui {
+        Vec::new()
+    } else {
+        let coins = client
+            .get_coins(&sender, Some(&coin_type))
+            .await
+            .map_err(rpc_error)?;
+        select_coins(&coins, req.amount).ok_or_else(|| {
+            ApiErrorResponse::new(format!("Not enough {} for {}", coin_type, req.amount))
+                .with_code(StatusCode::CONFLICT)
+        })?
+    };
+
+    let intent = NewIntent {
+        coin_type,
+        amount: req.amount,
+        min_apy: req.min_apy,
+        deadline_secs: req.deadline_secs,
+        target_protocol,
+    };
+    let ptb = package
+        .create_intent_ptb(&intent, &input_coins)
+        .map_err(|e| bad_request(e.to_string()))?;
+    let tx_bytes = ptb
+        .tx_bytes(
+            &sender,
+            &GasData {
+                payment,
+                owner: sender.clone(),
+                price: gas_price,
+                budget: gas_budget,
+            },
+        )
+        .map_err(|e| {
+            ApiErrorResponse::new(format!("Failed to encode transaction: {}", e))
+                .with_code(StatusCode::INTERNAL_SERVER_ERROR)
+        })?;
+
+    let amount = if is_sui {
+        format!("{} SUI", format_units(intent.amount, SUI_DECIMALS, 4))
+    } else {
+        format!("{} {}", intent.amount, intent.coin_type)
+    };
+    let summary = format!(
+        "Lock {} for at least {} bps on {}, open for {}s",
+        amount,
+        intent.min_apy,
+        intent.target_protocol.as_deref().unwrap_or(ANY_PROTOCOL),
+        intent.deadline_secs
+    );
+
+    tracing::info!(sender = %sender, "Intent transaction built");
+    Ok(ApiSuccessResponse::new(IntentTransaction {
+        tx_bytes,
+        sender,
+        intent_package: package.package_id().to_string(),
+        gas_price,
+        gas_budget,
+        summary,
+    })
+    .with_message("Sign and execute tx_bytes to create the intent"))
+}
+
+// ─── Commit-reveal ───────────────────────────────────────────────────────────
+
+/// Commitment request body
+#[derive(Debug, Deserialize)]
+pub struct CommitRequest {
+    /// `keccak256(personal_message(request, nonce) ‖ salt)`
+    pub commitment: String,
+}
+
+/// Reveal request body
+#[derive(Debug, Deserialize)]
+pub struct RevealRequest {
+    /// The committed creation request
+    pub request: CreateIntentRequest,
+    /// 0x-hex 32-byte salt
+    pub salt: String,
+}
+
+fn commitment_error(e: CommitmentError) -> ApiErrorResponse {
+    let code = match e {
+        CommitmentError::NotFound => StatusCode::NOT_FOUND,
+        CommitmentError::AlreadyCommitted | CommitmentError::AlreadyRevealed => {
+            StatusCode::CONFLICT
+        }
+        CommitmentError::Expired => StatusCode::GONE,
+        CommitmentError::InvalidHash | CommitmentError::InvalidSalt | CommitmentError::Mismatch => {
+            StatusCode::BAD_REQUEST
+        }
+    };
+    ApiErrorResponse::new(e.to_string()).with_code(code)
+}
+
+/// POST /intents/commitments — commit to a private intent
+///
+/// Only the hash is stored; nothing about the intent is visible until it
+/// is revealed, which must happen before `reveal_deadline`.
+pub async fn commit_intent(
+    State(state): State<AppState>,
+    Json(req): Json<CommitRequest>,
+) -> ApiResponse<Commitment> {
+    let commitment = state
+        .commitments
+        .write()
+        .await
+        .commit(&req.commitment, now_millis())
+        .map_err(commitment_error)?;
+
+    tracing::info!(commitment = %commitment.hash, "Intent committed");
+    Ok(ApiSuccessResponse::new(commitment)
+        .with_code(StatusCode::CREATED)
+        .with_message("Commitment recorded"))
+}
+
+/// GET /intents/commitments/{hash} — commitment status
+pub async fn get_commitment(
+    State(state): State<AppState>,
+    Path(hash): Path<String>,
+) -> ApiResponse<Commitment> {
+    let commitment = state
+        .commitments
+        .read()
+        .await
+        .get(&hash, now_millis())
+        .ok_or_else(|| commitment_error(CommitmentError::NotFound))?;
+    Ok(ApiSuccessResponse::new(commitment))
+}
+
+/// POST /intents/commitments/{hash}/reveal — reveal a committed intent
+///
+/// The request and salt must hash to the commitment. The intent is then
+/// created exactly as by `POST /intents`, and only from here on do solvers
+/// see it.
+pub async fn reveal_intent(
+    State(state): State<AppState>,
+    Path(hash): Path<String>,
+    Json(req): Json<RevealRequest>,
+) -> ApiResponse<Intent> {
+    state
+        .commitments
+        .read()
+        .await
+        .check_reveal(&hash, &req.request, &req.salt, now_millis())
+        .map_err(commitment_error)?;
+
+    // A commitment can only be revealed once, so reveals skip duplicate
+    // detection
+    let (Opened::Created(intent) | Opened::Duplicate(intent)) =
+        open_intent(&state, req.request, Some(&hash), true).await?;
+    Ok(ApiSuccessResponse::new(intent)
+        .with_code(StatusCode::CREATED)
+        .with_message("Intent revealed"))
+}
+
+/// POST /intents/{id}/fulfillment — a solver reports an on-chain fulfillment
+///
+/// Requires `x-solver-key`. Re-sending the same digest is a no-op; a
+/// different digest for an already fulfilled intent is a conflict. The
+/// report is then verified on-chain in the background.
+pub async fn report_fulfillment(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    headers: HeaderMap,
+    Json(mut report): Json<FulfillmentReport>,
+) -> ApiResponse<FulfillmentReport> {
+    authorize_solver(&state, &headers)?;
+
+    if report.digest.is_empty() {
+        return Err(ApiErrorResponse::new("digest is required").with_code(StatusCode::BAD_REQUEST));
+    }
+    if report.solver.is_empty() {
+        return Err(ApiErrorResponse::new("solver is required").with_code(StatusCode::BAD_REQUEST));
+    }
+
+    report.reported_at = now_millis();
+    if let Some(existing) = state.record_fulfillment(&intent_id, report.clone()).await {
+        if existing.digest == report.digest {
+            return Ok(ApiSuccessResponse::new(existing).with_message("Already recorded"));
+        }
+        return Err(ApiErrorResponse::new(format!(
+            "Intent already fulfilled by {} in {}",
+            existing.solver, existing.digest
+        ))
+        .with_code(StatusCode::CONFLICT));
+    }
+
+    tracing::info!(
+        intent_id = %intent_id,
+        solver = %report.solver,
+        digest = %report.digest,
+        apy_bps = report.realized_apy_bps,
+        "Fulfillment reported"
+    );
+
+    // Linked cross-chain intents move on; the funds are now in the protocol
+    if let Some(mut intent) = state.find_intent(&intent_id).await {
+        intent.dest_tx_hash = Some(report.digest.clone());
+        intent.set_status(IntentStatus::Deposited);
+        state.upsert_intent(intent, &report.solver).await;
+    }
+
+    tokio::spawn(verify_settlement(state, intent_id, report.clone()));
+
+    Ok(ApiSuccessResponse::new(report)
+        .with_code(StatusCode::CREATED)
+        .with_message("Fulfillment recorded"))
+}
+
+/// Check that the user received what the report claims
+///
+/// Completes the indexed intent only when the receipt checks out; a
+/// mismatch is kept for review and alerted by the verifier.
+async fn verify_settlement(state: AppState, intent_id: String, report: FulfillmentReport) {
+    let (Some(user), Some(amount)) = (report.user.clone(), report.amount) else {
+        tracing::warn!(intent_id = %intent_id, "Report has no user/amount, skipping verification");
+        return;
+    };
+    let network = state.network().parse().unwrap_or(Network::Testnet);
+    let Some(verifier) = state.verifiers.get(&network).cloned() else {
+        tracing::warn!(intent_id = %intent_id, "No settlement verifier for {}", network.name());
+        return;
+    };
+
+    let verification = verifier
+        .verify(&intent_id, &report, &Expected { user, amount })
+        .await;
+    tracing::info!(
+        intent_id = %intent_id,
+        status = ?verification.status,
+        problems = ?verification.problems,
+        "Settlement verified"
+    );
+
+    if verification.status == VerificationStatus::Verified {
+        state
+            .update_intent_status(&intent_id, IntentStatus::Completed, VERIFIER_ACTOR)
+            .await;
+    }
+    if let Some(receipt) = FulfillmentReceipt::issue(&intent_id, &report, &verification) {
+        let receipt = match &state.receipt_signer {
+            Some(signer) => receipt.sign(signer),
+            None => SignedReceipt::unsigned(receipt),
+        };
+        state.record_receipt(&intent_id, receipt).await;
+    }
+    state.record_verification(&intent_id, verification).await;
+}
+
+/// GET /intents/{id}/receipt — receipt of a verified fulfillment
+///
+/// Signed by the API's receipt key when one is configured, so the JSON can
+/// be kept as a standalone record.
+pub async fn get_receipt(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+) -> ApiResponse<SignedReceipt> {
+    if let Some(receipt) = state.get_receipt(&intent_id).await {
+        return Ok(ApiSuccessResponse::new(receipt));
+    }
+
+    let message = match state.get_verification(&intent_id).await {
+        Some(v) if v.status != VerificationStatus::Verified => {
+            "Fulfillment failed verification, no receipt issued"
+        }
+        _ if state.get_fulfillment(&intent_id).await.is_some() => "Fulfillment not verified yet",
+        _ => "Intent has no fulfillment",
+    };
+    Err(ApiErrorResponse::new(message).with_code(StatusCode::NOT_FOUND))
+}
+
+/// Check the solver key; reports are rejected when no key is configured
+fn authorize_solver(state: &AppState, headers: &HeaderMap) -> Result<(), ApiErrorResponse> {
+    let Some(expected) = state.config.admin.solver_api_key.as_ref() else {
+        return Err(
+            ApiErrorResponse::new("Solver API is disabled (SOLVER_API_KEY not set)")
+                .with_code(StatusCode::FORBIDDEN),
+        );
+    };
+
+    let provided = headers.get(SOLVER_KEY_HEADER).and_then(|v| v.to_str().ok());
+
+    if !provided.is_some_and(|key| expected.matches(key)) {
+        return Err(ApiErrorResponse::new("Invalid solver key").with_code(StatusCode::UNAUTHORIZED));
+    }
+    Ok(())
+}
+
+// ─── Reconciliation ──────────────────────────────────────────────────────────
+
+/// Reconciler metrics and the drift it last found
+#[derive(Debug, Serialize)]
+pub struct ReconciliationResponse {
+    pub network: String,
+    /// None when the network has no intent package configured
+    pub metrics: Option<DriftSnapshot>,
+    pub drifts: Vec<Drift>,
+}
+
+/// GET /intents/reconciliation — drift between the API and the chain
+pub async fn get_reconciliation(
+    State(state): State<AppState>,
+) -> ApiResponse<ReconciliationResponse> {
+    let network = state.network();
+    let metrics = network
+        .parse::<Network>()
+        .ok()
+        .and_then(|n| state.reconcilers.get(&n))
+        .map(|r| r.metrics());
+    let drifts = state.drifts.read().await.clone();
+
+    Ok(ApiSuccessResponse::new(ReconciliationResponse {
+        network,
+        metrics,
+        drifts,
+    }))
+}
+
+// ─── Recovery ────────────────────────────────────────────────────────────────
+
+/// Result of a retry or refund call
+#[derive(Debug, Serialize)]
+pub struct RecoveryResponse {
+    pub intent_id: String,
+    pub stage: FailureStage,
+    pub status: IntentStatus,
+    pub retries: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<RefundPlan>,
+    /// Unsigned refund PTB for the USDC holder to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sui_tx: Option<ProgrammableTransactionBlock>,
+    /// Unsigned refund transfer for the USDC holder to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_tx: Option<EvmRefundTx>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_tx_hash: Option<String>,
+}
+
+impl RecoveryResponse {
+    fn new(intent: &Intent) -> Self {
+        Self {
+            intent_id: intent.id.clone(),
+            stage: FailureStage::classify(intent),
+            status: intent.status,
+            retries: intent.retries,
+            refund: None,
+            sui_tx: None,
+            evm_tx: None,
+            refund_tx_hash: intent.refund_tx_hash.clone(),
+        }
+    }
+}
+
+/// Refund request body
+#[derive(Debug, Deserialize)]
+pub struct RefundRequest {
+    /// Sui address holding the stuck USDC (required for Sui refunds)
+    pub holder: Option<String>,
+    /// Hash of the executed refund; confirms a pending refund
+    pub tx_hash: Option<String>,
+}
+
+fn recovery_error(e: RecoveryError) -> ApiErrorResponse {
+    ApiErrorResponse::coded(ErrorCode::InvalidIntentState, e.to_string())
+}
+
+async fn indexed_intent(state: &AppState, intent_id: &str) -> Result<Intent, ApiErrorResponse> {
+    state.find_intent(intent_id).await.ok_or_else(|| {
+        ApiErrorResponse::coded(
+            ErrorCode::IntentNotFound,
+            format!("Intent {} not found", intent_id),
+        )
+    })
+}
+
+/// Cancel request body
+#[derive(Debug, Deserialize)]
+pub struct CancelRequest {
+    /// Sui signature over `cancel_message(intent_id)`
+    pub signature: String,
+}
+
+/// Personal message a user signs to cancel an intent
+pub fn cancel_message(intent_id: &str) -> String {
+    format!("Cancel Naisu intent {}", intent_id)
+}
+
+/// Check a user's Sui personal-message signature
+///
+/// Accepts ed25519, secp256k1/r1, multisig and zkLogin signers.
+pub(crate) async fn authenticate_user(
+    state: &AppState,
+    message: &str,
+    signature: &str,
+    address: &str,
+) -> Result<(), ApiErrorResponse> {
+    state
+        .signature_verifier()
+        .verify_personal_message(message.as_bytes(), signature, address)
+        .await
+        .map_err(|e| {
+            let code = match e {
+                VerifyError::Malformed(_)
+                | VerifyError::UnsupportedScheme(_)
+                | VerifyError::ZkLogin(_) => StatusCode::BAD_REQUEST,
+                VerifyError::ProofUnchecked | VerifyError::Client(_) => {
+                    StatusCode::SERVICE_UNAVAILABLE
+                }
+                _ => StatusCode::UNAUTHORIZED,
+            };
+            ApiErrorResponse::new(e.to_string()).with_code(code)
+        })?;
+    Ok(())
+}
+
+/// POST /intents/{id}/cancel — cancel a pending intent
+///
+/// Must be signed by the intent's Sui wallet (the source of a Sui → EVM
+/// intent, the recipient of an EVM → Sui one) as a personal message with
+/// the text `Cancel Naisu intent {id}`.
+pub async fn cancel_intent(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Json(request): Json<CancelRequest>,
+) -> ApiResponse<Intent> {
+    let mut intent = indexed_intent(&state, &intent_id).await?;
+    let owner = match intent.direction {
+        Direction::SuiToEvm => intent.source_address.clone(),
+        Direction::EvmToSui => intent.dest_address.clone(),
+    };
+    authenticate_user(
+        &state,
+        &cancel_message(&intent_id),
+        &request.signature,
+        &owner,
+    )
+    .await?;
+
+    if intent.status != IntentStatus::Pending {
+        return Err(ApiErrorResponse::new(format!(
+            "Intent is {}, only pending intents can be cancelled",
+            intent.status.as_str()
+        ))
+        .with_code(StatusCode::CONFLICT));
+    }
+    intent.set_status(IntentStatus::Cancelled);
+    state.upsert_intent(intent.clone(), &owner).await;
+
+    tracing::info!(intent_id = %intent_id, "Intent cancelled");
+    Ok(ApiSuccessResponse::new(intent).with_message("Intent cancelled"))
+}
+
+/// Bridge report body
+#[derive(Debug, Deserialize)]
+pub struct BridgeRequest {
+    /// Digest of the Sui `deposit_for_burn` transaction
+    pub tx_digest: String,
+    /// CCTP nonce from the burn's events
+    #[serde(default)]
+    pub nonce: Option<String>,
+    /// Sui signature over `bridge_message(intent_id, tx_digest)`
+    pub signature: String,
+}
+
+/// Personal message a user signs to report their burn
+pub fn bridge_message(intent_id: &str, tx_digest: &str) -> String {
+    format!("Bridge Naisu intent {} with {}", intent_id, tx_digest)
+}
+
+/// POST /intents/{id}/bridge — record the Sui burn of a Sui → EVM intent
+///
+/// Must be signed by the intent's Sui wallet. The intent moves to
+/// `bridging`; the CCTP relayer mints it on the EVM chain once Circle has
+/// attested the burn.
+pub async fn report_bridge(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Json(request): Json<BridgeRequest>,
+) -> ApiResponse<Intent> {
+    let mut intent = indexed_intent(&state, &intent_id).await?;
+    if intent.direction != Direction::SuiToEvm {
+        return Err(
+            ApiErrorResponse::new("Only Sui → EVM intents are bridged from Sui")
+                .with_code(StatusCode::BAD_REQUEST),
+        );
+    }
+    let owner = intent.source_address.clone();
+    authenticate_user(
+        &state,
+        &bridge_message(&intent_id, &request.tx_digest),
+        &request.signature,
+        &owner,
+    )
+    .await?;
+
+    if !matches!(
+        intent.status,
+        IntentStatus::Pending | IntentStatus::SwapCompleted
+    ) {
+        return Err(ApiErrorResponse::new(format!(
+            "Intent is {}, the burn can't be recorded",
+            intent.status.as_str()
+        ))
+        .with_code(StatusCode::CONFLICT));
+    }
+    intent.bridge_tx_hash = Some(request.tx_digest);
+    intent.bridge_nonce = request.nonce;
+    intent.set_status(IntentStatus::Bridging);
+    state.upsert_intent(intent.clone(), &owner).await;
+
+    tracing::info!(intent_id = %intent_id, "Intent bridging");
+    Ok(ApiSuccessResponse::new(intent).with_message("Burn recorded, waiting for attestation"))
+}
+
+/// Link request body
+#[derive(Debug, Deserialize)]
+pub struct LinkRequest {
+    /// `intentId` of the EVM hook event
+    #[serde(default)]
+    pub evm_intent_id: Option<String>,
+    /// Sui intent object opened for the intent
+    #[serde(default)]
+    pub sui_object_id: Option<String>,
+}
+
+/// POST /intents/{id}/links — link an intent to its IDs on chain
+///
+/// Requires `x-solver-key`. A linked ID finds the intent in search and can
+/// stand in for its ID on `/intents/{id}/…` calls, including fulfillment
+/// reports keyed by the Sui object. Links can't be changed once set, and an
+/// ID can only be linked to one intent.
+pub async fn link_intent(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<LinkRequest>,
+) -> ApiResponse<Intent> {
+    authorize_solver(&state, &headers)?;
+
+    let mut intent = indexed_intent(&state, &intent_id).await?;
+    let links = [
+        (
+            request.evm_intent_id,
+            &mut intent.evm_intent_id,
+            "evm_intent_id",
+        ),
+        (
+            request.sui_object_id,
+            &mut intent.sui_object_id,
+            "sui_object_id",
+        ),
+    ];
+    for (requested, linked, field) in links {
+        let Some(requested) = requested.map(|r| r.trim().to_lowercase()) else {
+            continue;
+        };
+        if validate_sui_address(&requested).is_err() {
+            return Err(ApiErrorResponse::new(format!("{} must be 0x hex", field))
+                .with_code(StatusCode::BAD_REQUEST));
+        }
+        if let Some(existing) = linked.as_deref().filter(|l| *l != requested) {
+            return Err(ApiErrorResponse::new(format!(
+                "{} is already linked to {}",
+                field, existing
+            ))
+            .with_code(StatusCode::CONFLICT));
+        }
+        let other = state.find_intent(&requested).await;
+        if let Some(other) = other.filter(|o| o.id != intent.id) {
+            return Err(ApiErrorResponse::new(format!(
+                "{} is already linked to intent {}",
+                requested, other.id
+            ))
+            .with_code(StatusCode::CONFLICT));
+        }
+        *linked = Some(requested);
+    }
+    state.upsert_intent(intent.clone(), LINK_ACTOR).await;
+
+    tracing::info!(
+        intent_id = %intent.id,
+        evm_intent_id = ?intent.evm_intent_id,
+        sui_object_id = ?intent.sui_object_id,
+        "Intent linked"
+    );
     Ok(ApiSuccessResponse::new(intent))
 }
 
+/// POST /intents/{id}/retry — resume a failed intent from the stage it failed at
+///
+/// Requires `x-admin-key`. Allowed up to `MAX_RETRIES` times.
+pub async fn retry_intent(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    headers: HeaderMap,
+) -> ApiResponse<RecoveryResponse> {
+    authorize(&state, &headers)?;
+
+    let mut intent = indexed_intent(&state, &intent_id).await?;
+    let status = retry_status(&intent).map_err(recovery_error)?;
+    intent.retry(status);
+    state.upsert_intent(intent.clone(), ADMIN_ACTOR).await;
+
+    tracing::info!(
+        intent_id = %intent_id,
+        status = status.as_str(),
+        retries = intent.retries,
+        "Intent retried"
+    );
+    Ok(ApiSuccessResponse::new(RecoveryResponse::new(&intent))
+        .with_message(format!("Resumed at {}", status.as_str())))
+}
+
+/// POST /intents/{id}/refund — build the refund for a failed intent
+///
+/// Requires `x-admin-key`. Without `tx_hash` this returns the unsigned
+/// refund transaction and moves the intent to `refund_pending`; sending the
+/// executed refund's `tx_hash` afterwards marks it `refunded`.
+pub async fn refund_intent(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<RefundRequest>,
+) -> ApiResponse<RecoveryResponse> {
+    authorize(&state, &headers)?;
+    let mut intent = indexed_intent(&state, &intent_id).await?;
+
+    if let Some(tx_hash) = request.tx_hash {
+        if intent.status != IntentStatus::RefundPending {
+            return Err(ApiErrorResponse::new(format!(
+                "Intent is {}, not refund_pending",
+                intent.status.as_str()
+            ))
+            .with_code(StatusCode::CONFLICT));
+        }
+        intent.refund_tx_hash = Some(tx_hash);
+        intent.set_status(IntentStatus::Refunded);
+        state.upsert_intent(intent.clone(), ADMIN_ACTOR).await;
+
+        tracing::info!(intent_id = %intent_id, "Intent refunded");
+        return Ok(ApiSuccessResponse::new(RecoveryResponse::new(&intent))
+            .with_message("Refund confirmed"));
+    }
+
+    let plan = RefundPlan::for_intent(&intent).map_err(recovery_error)?;
+    let mut response = RecoveryResponse::new(&intent);
+    match plan.chain {
+        RefundChain::Evm(chain) => {
+            let tx = EvmRefundTx::usdc_transfer(chain, &plan.recipient, plan.amount)
+                .map_err(recovery_error)?;
+            response.evm_tx = Some(tx);
+        }
+        RefundChain::Sui => {
+            let Some(holder) = request.holder else {
+                return Err(ApiErrorResponse::new("holder is required for Sui refunds")
+                    .with_code(StatusCode::BAD_REQUEST));
+            };
+            let mut config = SuiConfig::testnet();
+            config.rpc_url = state.config.sui.rpc_url.clone();
+            let client = SuiClient::new(config);
+            let coins = client
+                .get_coins(&holder, Some(naisu_sui::USDC_COIN_TYPE))
+                .await
+                .map_err(|e| {
+                    ApiErrorResponse::new(format!("Failed to fetch USDC coins: {}", e))
+                        .with_code(StatusCode::BAD_GATEWAY)
+                })?;
+            let ptb = naisu_sui::build_usdc_refund_ptb(&coins, plan.amount, &plan.recipient)
+                .map_err(|e| {
+                    ApiErrorResponse::new(e.to_string()).with_code(StatusCode::CONFLICT)
+                })?;
+            response.sui_tx = Some(ptb);
+        }
+    }
+
+    intent.set_status(IntentStatus::RefundPending);
+    state.upsert_intent(intent.clone(), ADMIN_ACTOR).await;
+    response.status = intent.status;
+    response.refund = Some(plan);
+
+    tracing::info!(intent_id = %intent_id, "Refund built");
+    Ok(ApiSuccessResponse::new(response).with_message("Refund pending"))
+}
+
+/// Query parameters for stats
+#[derive(Deserialize)]
+pub struct StatsQuery {
+    /// 1h, 24h, 7d, 30d or all (default)
+    pub window: Option<String>,
+}
+
+fn parse_window(window: Option<String>) -> Result<Window, ApiErrorResponse> {
+    window
+        .map(|w| w.parse::<Window>())
+        .transpose()
+        .map(Option::unwrap_or_default)
+        .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::BAD_REQUEST))
+}
+
+impl From<&IntentResponse> for IntentSample {
+    fn from(intent: &IntentResponse) -> Self {
+        Self {
+            created_at: intent.created_at,
+            amount: intent.amount.parse().unwrap_or(0),
+            status: match intent.status.as_str() {
+                "open" => SampleStatus::Open,
+                "fulfilled" | "completed" | "disputed" => SampleStatus::Fulfilled,
+                _ => SampleStatus::Closed,
+            },
+            fulfillment: intent.fulfillment.as_ref().map(|f| FulfillmentSample {
+                solver: f.solver.clone(),
+                protocol: f.protocol.clone(),
+                apy_bps: f.realized_apy_bps,
+                fee: f.fee.unwrap_or(0),
+                reported_at: f.reported_at,
+            }),
+        }
+    }
+}
+
+async fn intent_samples(state: &AppState) -> Vec<IntentSample> {
+    known_intents(state).await.iter().map(Into::into).collect()
+}
+
 /// Intent stats
 #[derive(Serialize)]
 pub struct IntentStats {
+    pub window: Window,
     pub total_intents: u64,
     pub open_intents: u64,
     pub fulfilled_intents: u64,
     pub total_volume_sui: String,
+    /// Average realized APY (percent)
     pub avg_apy: f64,
+    pub time_to_fulfill_ms: Percentiles,
+    pub market_share: Vec<ProtocolShare>,
+    /// Protocol fees on fulfillments in the window
+    pub total_fees_sui: String,
+    /// Fees accrued since startup, per solver
+    pub fee_accrual: FeeReport,
+    /// Where fees are sent, if charged
+    pub fee_treasury: Option<String>,
 }
 
-pub async fn get_stats() -> ApiResponse<Json<ApiSuccessResponse<IntentStats>>> {
+/// GET /intents/stats?window= — marketplace stats from stored intents and reports
+pub async fn get_stats(
+    State(state): State<AppState>,
+    Query(params): Query<StatsQuery>,
+) -> ApiResponse<IntentStats> {
+    let window = parse_window(params.window)?;
+    let analytics = analytics::compute(&intent_samples(&state).await, window, now_millis());
+
     let stats = IntentStats {
-        total_intents: 15,
-        open_intents: 3,
-        fulfilled_intents: 12,
-        total_volume_sui: "45.5".to_string(),
-        avg_apy: 8.25,
+        window,
+        total_intents: analytics.total_intents,
+        open_intents: analytics.open_intents,
+        fulfilled_intents: analytics.fulfilled_intents,
+        total_volume_sui: format_units(analytics.total_volume, SUI_DECIMALS, SUI_DECIMALS),
+        avg_apy: analytics.avg_apy_bps.unwrap_or(0.0) / 100.0,
+        time_to_fulfill_ms: analytics.time_to_fulfill_ms,
+        market_share: analytics.market_share,
+        total_fees_sui: format_units(analytics.total_fees, SUI_DECIMALS, SUI_DECIMALS),
+        fee_accrual: state.fees.read().await.report(),
+        fee_treasury: state.agent_config.get().fees.treasury,
     };
-    
+
     Ok(ApiSuccessResponse::new(stats))
 }
 
+/// GET /intents/stats/solvers?window= — solvers ranked by fulfillments
+pub async fn get_solver_stats(
+    State(state): State<AppState>,
+    Query(params): Query<StatsQuery>,
+) -> ApiResponse<Vec<SolverStanding>> {
+    let window = parse_window(params.window)?;
+    let standings = analytics::leaderboard(&intent_samples(&state).await, window, now_millis());
+    Ok(ApiSuccessResponse::new(standings))
+}
+
+/// GET /intents/stats/bridge — burn → attestation → mint percentiles per chain pair
+pub async fn get_bridge_stats(State(state): State<AppState>) -> ApiResponse<Vec<RouteLatency>> {
+    Ok(ApiSuccessResponse::new(
+        state.bridge_latency.read().await.routes(),
+    ))
+}
+
 /// Solver bids for an intent
 #[derive(Serialize)]
 pub struct BidResponse {
@@ -124,8 +1538,9 @@ pub struct BidResponse {
 }
 
 pub async fn get_intent_bids(
-    axum::extract::Path(_intent_id): axum::extract::Path<String>,
-) -> ApiResponse<Json<ApiSuccessResponse<Vec<BidResponse>>>> {
+    Path(_intent_id): Path<String>,
+    Query(page): Query<PageQuery>,
+) -> ApiResponse<Vec<BidResponse>> {
     // Mock bids
     let bids = vec![
         BidResponse {
@@ -141,6 +1556,7 @@ pub async fn get_intent_bids(
             timestamp: 1770287451000,
         },
     ];
-    
-    Ok(ApiSuccessResponse::new(bids))
+
+    let (bids, pagination) = page.paginate(bids)?;
+    Ok(ApiSuccessResponse::new(bids).with_pagination(pagination))
 }
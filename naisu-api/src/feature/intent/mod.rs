@@ -2,8 +2,12 @@
 //!
 //! API endpoints for querying and indexing intents
 
+pub mod cctp_relay;
+pub mod evm_hook;
 pub mod handler;
+pub mod reconcile;
 pub mod route;
+pub mod watchdog;
 
 pub use handler::*;
 pub use route::intent_routes;
@@ -0,0 +1,133 @@
+//! Intent Reconciliation
+//!
+//! Background job keeping the API's intent view in line with the chain:
+//! chain-only intents are indexed, missed fulfillments are backfilled from
+//! `IntentFulfilled` events and cancellations are picked up. Disagreements
+//! the chain can't settle (reports with no on-chain fulfillment) are only
+//! flagged.
+
+use std::collections::HashMap;
+
+use naisu_agent::config::Network;
+use naisu_agent::reconcile::{ChainState, Drift, DriftKind, LocalState};
+use naisu_core::FulfillmentReport;
+
+use super::handler::mock_intents;
+use crate::feature::admin::handler::now_millis;
+use crate::state::AppState;
+
+/// What the API currently believes about every intent it knows
+pub async fn local_states(state: &AppState) -> HashMap<String, LocalState> {
+    let mut local: HashMap<String, LocalState> = mock_intents()
+        .into_iter()
+        .map(|i| {
+            let s = if i.status == "fulfilled" {
+                LocalState::Fulfilled { digest: None }
+            } else {
+                LocalState::Open
+            };
+            (i.intent_id, s)
+        })
+        .collect();
+
+    for (id, intent) in state.chain_intents.read().await.iter() {
+        let s = match intent.state {
+            ChainState::Open => LocalState::Open,
+            ChainState::Fulfilled { .. } => LocalState::Fulfilled { digest: None },
+            ChainState::Cancelled { .. } | ChainState::Closed => LocalState::Cancelled,
+        };
+        local.insert(id.clone(), s);
+    }
+
+    for (id, report) in state.fulfillments.read().await.iter() {
+        local.insert(
+            id.clone(),
+            LocalState::Fulfilled {
+                digest: Some(report.digest.clone()),
+            },
+        );
+    }
+    local
+}
+
+/// Adopt the chain's view for repairable drift
+async fn repair(state: &AppState, drift: &Drift) {
+    if !drift.kind.is_repairable() {
+        tracing::warn!(
+            intent_id = %drift.intent_id,
+            kind = drift.kind.as_str(),
+            "Intent drift needs review"
+        );
+        return;
+    }
+
+    if let ChainState::Fulfilled {
+        solver,
+        protocol,
+        apy,
+        digest: Some(digest),
+    } = &drift.chain
+    {
+        let report = FulfillmentReport {
+            digest: digest.clone(),
+            solver: solver.clone(),
+            protocol: protocol.clone(),
+            realized_apy_bps: *apy,
+            created_objects: Vec::new(),
+            reported_at: now_millis(),
+            user: None,
+            amount: None,
+            fee: None,
+            gas_used: None,
+            failed_attempts: Vec::new(),
+        };
+        state.record_fulfillment(&drift.intent_id, report).await;
+    }
+
+    if drift.kind != DriftKind::Untracked {
+        tracing::info!(
+            intent_id = %drift.intent_id,
+            kind = drift.kind.as_str(),
+            "Intent repaired from chain"
+        );
+    }
+}
+
+/// Reconcile the selected network once
+pub async fn reconcile_once(state: &AppState) -> Result<usize, String> {
+    let network: Network = state.network().parse().unwrap_or(Network::Testnet);
+    let Some(reconciler) = state.reconcilers.get(&network).cloned() else {
+        return Ok(0);
+    };
+
+    let local = local_states(state).await;
+    let (chain, drifts) = reconciler
+        .reconcile(&local)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    for drift in &drifts {
+        repair(state, drift).await;
+    }
+    state.upsert_chain_intents(chain).await;
+    let count = drifts.len();
+    *state.drifts.write().await = drifts;
+    Ok(count)
+}
+
+/// Reconcile forever at the reconciler's interval
+pub async fn run(state: AppState) {
+    let interval = state
+        .reconcilers
+        .values()
+        .map(|r| r.interval())
+        .min()
+        .unwrap_or(std::time::Duration::from_secs(300));
+
+    loop {
+        if let Err(e) = reconcile_once(&state).await {
+            tracing::warn!("Reconciliation failed: {}", e);
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
@@ -1,18 +1,45 @@
 //! Intent Routes
 
 use axum::{
-    routing::{get},
+    routing::{get, post},
     Router,
 };
 
-use crate::state::AppState;
 use super::handler;
+use crate::state::AppState;
 
 /// Create intent routes
 pub fn intent_routes() -> Router<AppState> {
     Router::new()
-        .route("/intents", get(handler::list_intents))
+        .route(
+            "/intents",
+            get(handler::list_intents).post(handler::create_intent),
+        )
+        .route(
+            "/intents/transaction",
+            post(handler::build_intent_transaction),
+        )
+        .route("/intents/commitments", post(handler::commit_intent))
+        .route("/intents/commitments/{hash}", get(handler::get_commitment))
+        .route(
+            "/intents/commitments/{hash}/reveal",
+            post(handler::reveal_intent),
+        )
+        .route("/intents/search", get(handler::search_intents))
         .route("/intents/stats", get(handler::get_stats))
-        .route("/intents/:id", get(handler::get_intent))
-        .route("/intents/:id/bids", get(handler::get_intent_bids))
+        .route("/intents/stats/solvers", get(handler::get_solver_stats))
+        .route("/intents/stats/bridge", get(handler::get_bridge_stats))
+        .route("/intents/reconciliation", get(handler::get_reconciliation))
+        .route("/intents/{id}", get(handler::get_intent))
+        .route("/intents/{id}/bids", get(handler::get_intent_bids))
+        .route(
+            "/intents/{id}/fulfillment",
+            post(handler::report_fulfillment),
+        )
+        .route("/intents/{id}/receipt", get(handler::get_receipt))
+        .route("/intents/{id}/bridge", post(handler::report_bridge))
+        .route("/intents/{id}/links", post(handler::link_intent))
+        .route("/intents/{id}/cancel", post(handler::cancel_intent))
+        .route("/intents/{id}/retry", post(handler::retry_intent))
+        .route("/intents/{id}/refund", post(handler::refund_intent))
 }
@@ -0,0 +1,74 @@
+//! Stuck Intent Watchdog
+//!
+//! Background job alerting on intents past their status SLA (the agent
+//! config's `[watchdog]` section). Each intent is reported once as a
+//! warning and once more if it escalates to critical; moving to another
+//! status starts it over.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use naisu_agent::notify::{Alert, AlertLevel, NotificationHub};
+use naisu_agent::watchdog::{stuck_intents, StuckIntent};
+use naisu_core::IntentStatus;
+
+use crate::feature::admin::handler::now_millis;
+use crate::state::AppState;
+
+/// Time between checks
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+fn alert(stuck: &StuckIntent) -> Alert {
+    Alert::new(
+        stuck.level,
+        "Intent stuck",
+        format!(
+            "Intent {} has been {} for {} min (SLA {} min); suggested: {:?}",
+            stuck.intent_id,
+            stuck.status.as_str(),
+            stuck.stalled_secs / 60,
+            stuck.sla_secs / 60,
+            stuck.remediation
+        ),
+    )
+}
+
+/// Alert on newly stuck or escalated intents
+///
+/// `alerted` holds the status and level each intent was last reported at.
+pub async fn check_once(
+    state: &AppState,
+    notifier: &NotificationHub,
+    alerted: &mut HashMap<String, (IntentStatus, AlertLevel)>,
+) -> Vec<StuckIntent> {
+    let stuck = stuck_intents(
+        &state.list_intents().await,
+        &state.agent_config.get().watchdog,
+        (now_millis() / 1000) as i64,
+    );
+    alerted.retain(|id, (status, _)| {
+        stuck
+            .iter()
+            .any(|s| &s.intent_id == id && s.status == *status)
+    });
+
+    for intent in &stuck {
+        let reported = alerted.get(&intent.intent_id).map(|(_, level)| *level);
+        if reported == Some(intent.level) {
+            continue;
+        }
+        notifier.send(alert(intent)).await;
+        alerted.insert(intent.intent_id.clone(), (intent.status, intent.level));
+    }
+    stuck
+}
+
+/// Watch forever
+pub async fn run(state: AppState) {
+    let notifier = NotificationHub::from_env();
+    let mut alerted = HashMap::new();
+    loop {
+        check_once(&state, &notifier, &mut alerted).await;
+        tokio::time::sleep(CHECK_INTERVAL).await;
+    }
+}
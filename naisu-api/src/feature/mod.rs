@@ -1,6 +1,15 @@
 //! API Feature Modules
 
+pub mod admin;
 pub mod health;
+pub mod intent;
 pub mod network;
+pub mod orderbook;
+pub mod pool;
+pub mod portfolio;
+pub mod quote;
+pub mod referral;
 pub mod solver;
+pub mod staking;
 pub mod strategy;
+pub mod wallet;
@@ -4,10 +4,16 @@
 //! and query supported protocols for each network.
 
 use axum::{extract::State, routing::get, Json, Router};
+use naisu_agent::config::{Network, Protocol};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use crate::state::AppState;
+use crate::feature::admin::handler::now_millis;
+use crate::state::{AppState, SolverLiveness};
+
+/// Audit actor for switches; the endpoint is unauthenticated
+const NETWORK_ACTOR: &str = "api";
 
 /// Network information response
 #[derive(Debug, Serialize)]
@@ -15,6 +21,8 @@ pub struct NetworkInfo {
     pub current_network: String,
     pub supported_networks: Vec<String>,
     pub supported_protocols: Vec<ProtocolInfo>,
+    /// Solvers heartbeating on the current network
+    pub solvers: Vec<SolverLiveness>,
 }
 
 /// Protocol information
@@ -26,6 +34,15 @@ pub struct ProtocolInfo {
     pub available: bool,
 }
 
+/// Resolved protocol config (built-in values plus overrides)
+#[derive(Debug, Serialize)]
+pub struct ProtocolConfigInfo {
+    pub protocol: String,
+    pub package_id: String,
+    pub module: String,
+    pub objects: BTreeMap<String, String>,
+}
+
 /// Switch network request
 #[derive(Debug, Deserialize)]
 pub struct SwitchNetworkRequest {
@@ -40,67 +57,84 @@ pub struct SwitchNetworkResponse {
     pub message: String,
 }
 
+fn display_name(protocol: Protocol) -> &'static str {
+    match protocol {
+        Protocol::NativeStaking => "Native Staking",
+        other => other.name(),
+    }
+}
+
+fn protocol_type(protocol: Protocol) -> &'static str {
+    match protocol {
+        Protocol::NativeStaking => "staking",
+        Protocol::DeepBook => "dex_clob",
+        Protocol::Scallop | Protocol::Navi => "lending",
+        Protocol::Cetus | Protocol::Bluefin | Protocol::Turbos => "dex_amm",
+    }
+}
+
 /// Get current network info
 async fn get_network_info(State(state): State<Arc<AppState>>) -> Json<NetworkInfo> {
     let current = state.network();
 
-    let supported_protocols = match current.as_str() {
-        "testnet" => vec![
-            ProtocolInfo {
-                name: "Native Staking".to_string(),
-                protocol_type: "staking".to_string(),
-                estimated_apy: 0.025,
-                available: true,
-            },
-            ProtocolInfo {
-                name: "DeepBook".to_string(),
-                protocol_type: "dex_clob".to_string(),
-                estimated_apy: 0.05,
-                available: false, // TODO: Implement
-            },
-        ],
-        "mainnet" => vec![
-            ProtocolInfo {
-                name: "Cetus".to_string(),
-                protocol_type: "dex_amm".to_string(),
-                estimated_apy: 0.08,
-                available: false, // TODO: Implement
-            },
-            ProtocolInfo {
-                name: "Scallop".to_string(),
-                protocol_type: "lending".to_string(),
-                estimated_apy: 0.085,
-                available: false,
-            },
-            ProtocolInfo {
-                name: "Navi".to_string(),
-                protocol_type: "lending".to_string(),
-                estimated_apy: 0.08,
-                available: false,
-            },
-            ProtocolInfo {
-                name: "Native Staking".to_string(),
-                protocol_type: "staking".to_string(),
-                estimated_apy: 0.025,
-                available: true,
-            },
-            ProtocolInfo {
-                name: "DeepBook".to_string(),
-                protocol_type: "dex_clob".to_string(),
-                estimated_apy: 0.05,
-                available: false,
-            },
-        ],
-        _ => vec![],
+    let supported_protocols = match current.parse::<Network>() {
+        Ok(network) => {
+            let prober = state.availability.get(&network);
+            if let Some(prober) = prober {
+                prober
+                    .refresh_if_stale(state.runtime.get().availability_ttl)
+                    .await;
+            }
+
+            network
+                .supported_protocols()
+                .into_iter()
+                .map(|p| ProtocolInfo {
+                    name: display_name(p).to_string(),
+                    protocol_type: protocol_type(p).to_string(),
+                    estimated_apy: p.apy_estimate(),
+                    // Unreachable RPC: report the static flag rather than guess
+                    available: prober
+                        .and_then(|prober| prober.cache().is_available(p))
+                        .unwrap_or_else(|| p.is_available(network)),
+                })
+                .collect()
+        }
+        Err(_) => vec![],
     };
 
+    let solvers = state.solver_liveness(Some(&current), now_millis()).await;
+
     Json(NetworkInfo {
         current_network: current,
         supported_networks: vec!["testnet".to_string(), "mainnet".to_string()],
         supported_protocols,
+        solvers,
     })
 }
 
+/// Get protocol configs for the current network (reflects hot reloads)
+async fn get_protocol_configs(State(state): State<Arc<AppState>>) -> Json<Vec<ProtocolConfigInfo>> {
+    let Ok(network) = state.network().parse::<Network>() else {
+        return Json(vec![]);
+    };
+    let config = state.agent_config.get();
+
+    let protocols = network
+        .supported_protocols()
+        .into_iter()
+        .filter_map(|p| config.protocol_config(p, network))
+        .map(|c| ProtocolConfigInfo {
+            protocol: c.protocol.name().to_string(),
+            package_id: c.package_id,
+            module: c.module,
+            objects: c.config_objects.into_iter().collect(),
+        })
+        .collect();
+
+    Json(protocols)
+}
+
 /// Switch network
 async fn switch_network(
     State(state): State<Arc<AppState>>,
@@ -110,7 +144,7 @@ async fn switch_network(
 
     match network.as_str() {
         "testnet" | "mainnet" => {
-            state.set_network(&network);
+            state.set_network(&network, NETWORK_ACTOR);
             Json(SwitchNetworkResponse {
                 success: true,
                 network,
@@ -129,5 +163,6 @@ async fn switch_network(
 pub fn routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/info", get(get_network_info))
+        .route("/protocols", get(get_protocol_configs))
         .route("/switch", axum::routing::post(switch_network))
 }
@@ -0,0 +1,84 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+};
+use naisu_agent::config::Network;
+use naisu_sui::adapters::deepbook::{
+    DeepBookAdapter, OrderBook, Side, DEFAULT_DEPTH, SUI_USDC_POOL,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse, ErrorCode};
+use crate::state::AppState;
+
+/// Most levels per side a caller may ask for
+const MAX_DEPTH: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct OrderBookQuery {
+    /// Indexer pool name (default: `SUI_USDC`)
+    pub pool: Option<String>,
+    /// Levels per side
+    pub depth: Option<usize>,
+    /// Order size in SUI to quote slippage for
+    pub size: Option<f64>,
+    /// `testnet` or `mainnet` (default: current network)
+    pub network: Option<String>,
+}
+
+/// Slippage of a taker order of `size`, in bps from mid
+#[derive(Debug, Serialize)]
+pub struct Slippage {
+    pub size: f64,
+    /// `None` when the fetched depth can't fill the order
+    pub buy_bps: Option<f64>,
+    pub sell_bps: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrderBookDepth {
+    #[serde(flatten)]
+    pub book: OrderBook,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+    pub mid_price: Option<f64>,
+    pub spread_bps: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slippage: Option<Slippage>,
+}
+
+/// GET /orderbook — DeepBook level-2 depth, mid price, spread and slippage
+pub async fn get_order_book(
+    State(state): State<AppState>,
+    Query(params): Query<OrderBookQuery>,
+) -> ApiResponse<OrderBookDepth> {
+    let network: Network = params
+        .network
+        .unwrap_or_else(|| state.network())
+        .parse()
+        .map_err(|e: String| ApiErrorResponse::coded(ErrorCode::NetworkUnsupported, e))?;
+    let adapter = match network {
+        Network::Mainnet => DeepBookAdapter::new(),
+        _ => DeepBookAdapter::testnet(),
+    };
+    let pool = params.pool.unwrap_or_else(|| SUI_USDC_POOL.to_string());
+    let depth = params.depth.unwrap_or(DEFAULT_DEPTH).clamp(1, MAX_DEPTH);
+
+    let book = adapter.get_order_book(&pool, depth).await.map_err(|e| {
+        ApiErrorResponse::new(format!("Failed to fetch order book: {}", e))
+            .with_code(StatusCode::BAD_GATEWAY)
+    })?;
+
+    Ok(ApiSuccessResponse::new(OrderBookDepth {
+        best_bid: book.best_bid(),
+        best_ask: book.best_ask(),
+        mid_price: book.mid_price(),
+        spread_bps: book.spread_bps(),
+        slippage: params.size.map(|size| Slippage {
+            size,
+            buy_bps: book.slippage_bps(Side::Buy, size),
+            sell_bps: book.slippage_bps(Side::Sell, size),
+        }),
+        book,
+    }))
+}
@@ -0,0 +1,10 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn orderbook_routes() -> Router<AppState> {
+    Router::new().route("/", get(handler::get_order_book))
+}
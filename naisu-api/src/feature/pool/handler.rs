@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+};
+use naisu_agent::config::{Network, Protocol};
+use naisu_agent::pools::PoolInfo;
+use serde::Deserialize;
+
+use crate::common::response::{
+    ApiErrorResponse, ApiResponse, ApiSuccessResponse, ErrorCode, PageQuery,
+};
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct PoolsQuery {
+    /// Only `cetus` has pools to list (default)
+    pub protocol: Option<String>,
+    /// `testnet` or `mainnet` (default: current network)
+    pub network: Option<String>,
+}
+
+fn bad_request(message: String) -> ApiErrorResponse {
+    ApiErrorResponse::new(message).with_code(StatusCode::BAD_REQUEST)
+}
+
+/// GET /pools — liquidity pools with TVL and estimated fee APR, largest first
+pub async fn list_pools(
+    State(state): State<AppState>,
+    Query(params): Query<PoolsQuery>,
+    Query(page): Query<PageQuery>,
+) -> ApiResponse<Vec<PoolInfo>> {
+    let protocol = match params.protocol {
+        Some(protocol) => protocol.parse::<Protocol>().map_err(bad_request)?,
+        None => Protocol::Cetus,
+    };
+    if protocol != Protocol::Cetus {
+        return Err(bad_request(format!(
+            "No pool listing for {}",
+            protocol.name()
+        )));
+    }
+    let network: Network = params
+        .network
+        .unwrap_or_else(|| state.network())
+        .parse()
+        .map_err(|e: String| ApiErrorResponse::coded(ErrorCode::NetworkUnsupported, e))?;
+
+    let Some(registry) = state.pools.get(&network) else {
+        return Err(ApiErrorResponse::coded(
+            ErrorCode::NetworkUnsupported,
+            format!("No pools on {}", network.name()),
+        ));
+    };
+    let pools = registry
+        .pools(state.runtime.get().pools_ttl)
+        .await
+        .map_err(|e| {
+            ApiErrorResponse::new(format!("Failed to fetch pools: {}", e))
+                .with_code(StatusCode::BAD_GATEWAY)
+        })?;
+
+    let (pools, pagination) = page.paginate(pools)?;
+    Ok(ApiSuccessResponse::new(pools).with_pagination(pagination))
+}
@@ -0,0 +1,169 @@
+use axum::extract::{Path, State};
+use naisu_agent::ens::is_ens_name;
+use naisu_core::{Direction, Intent, IntentStatus};
+use naisu_sui::is_suins_name;
+use serde::Serialize;
+
+use crate::common::response::{ApiResponse, ApiSuccessResponse};
+use crate::feature::intent::handler::resolve_name;
+use crate::feature::referral::handler::normalize;
+use crate::names::{AddressKind, Resolved};
+use crate::state::AppState;
+
+/// A deposit a fulfilled intent holds for the wallet
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Position {
+    pub intent_id: String,
+    pub protocol: String,
+    /// Coin `amount` is counted in: `SUI` once a solver reported the
+    /// deposit, otherwise the bridged `USDC`
+    pub asset: &'static str,
+    /// Principal (base units of `asset`)
+    pub amount: u64,
+    /// `None` when the oracle has no price
+    pub value_usd: Option<f64>,
+    /// Realized APY (basis points), once the fulfillment is reported
+    pub apy_bps: Option<u64>,
+    pub status: IntentStatus,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct UnclaimedRewards {
+    /// Claimable referral rewards (MIST)
+    pub referral: u64,
+    pub value_usd: Option<f64>,
+}
+
+/// Everything a dashboard shows for one wallet
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct Portfolio {
+    pub address: String,
+    /// SuiNS / ENS name of `address`
+    pub name: Option<String>,
+    /// Priced positions plus priced rewards
+    pub total_value_usd: f64,
+    /// Value-weighted APY of the priced positions (basis points)
+    pub blended_apy_bps: Option<u64>,
+    pub positions: Vec<Position>,
+    /// Intents still bridging or refunding, newest first
+    pub pending_intents: Vec<Intent>,
+    pub unclaimed_rewards: UnclaimedRewards,
+}
+
+fn is_in_flight(status: IntentStatus) -> bool {
+    matches!(
+        status,
+        IntentStatus::Pending
+            | IntentStatus::SwapCompleted
+            | IntentStatus::Bridging
+            | IntentStatus::BridgeCompleted
+            | IntentStatus::RefundPending
+    )
+}
+
+fn blended_apy_bps(positions: &[Position]) -> Option<u64> {
+    let (weighted, total) = positions
+        .iter()
+        .filter_map(|p| Some((p.value_usd?, p.apy_bps?)))
+        .fold((0.0, 0.0), |(weighted, total), (value, apy)| {
+            (weighted + value * apy as f64, total + value)
+        });
+    (total > 0.0).then(|| (weighted / total).round() as u64)
+}
+
+/// GET /portfolio/{address} — positions, pending intents and rewards
+///
+/// `address` may also be a SuiNS or ENS name. Intents are matched on source
+/// or destination address. Positions the oracle can't price are listed
+/// without a value and left out of the total and the blended APY.
+pub async fn get_portfolio(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> ApiResponse<Portfolio> {
+    let kind = if is_suins_name(&address) {
+        AddressKind::Sui
+    } else if is_ens_name(&address) {
+        AddressKind::Evm
+    } else {
+        AddressKind::of(&address).unwrap_or(AddressKind::Sui)
+    };
+    let Resolved { address, name } = resolve_name(&state, &address, kind).await?;
+    let owner = normalize(&address);
+    let mut intents: Vec<Intent> = state
+        .list_intents()
+        .await
+        .into_iter()
+        .filter(|i| normalize(&i.source_address) == owner || normalize(&i.dest_address) == owner)
+        .collect();
+    intents.sort_by_key(|i| std::cmp::Reverse(i.created_at));
+
+    let mut positions = Vec::new();
+    let mut pending_intents = Vec::new();
+    for intent in intents {
+        if is_in_flight(intent.status) {
+            pending_intents.push(intent);
+            continue;
+        }
+        let deposited = matches!(
+            intent.status,
+            IntentStatus::Deposited | IntentStatus::Completed
+        );
+        if intent.direction != Direction::EvmToSui || !deposited {
+            continue;
+        }
+
+        let report = state.get_fulfillment(&intent.id).await;
+        let (asset, amount) = match report.as_ref().and_then(|r| r.amount) {
+            Some(mist) => ("SUI", mist),
+            None => (
+                "USDC",
+                intent
+                    .usdc_amount
+                    .as_deref()
+                    .and_then(|a| a.parse().ok())
+                    .unwrap_or(0),
+            ),
+        };
+        let protocol = match &report {
+            Some(report) => report.protocol.clone(),
+            None => intent
+                .strategy
+                .map_or("unknown", |s| s.protocol())
+                .to_string(),
+        };
+        positions.push(Position {
+            intent_id: intent.id,
+            protocol,
+            asset,
+            amount,
+            value_usd: state.prices.usd_value(asset, amount).await,
+            apy_bps: report.map(|r| r.realized_apy_bps),
+            status: intent.status,
+        });
+    }
+
+    let referral = state.referrals.read().await.account(&owner).claimable();
+    let rewards_usd = match referral {
+        0 => Some(0.0),
+        mist => state.prices.usd_value("SUI", mist).await,
+    };
+
+    let total_value_usd =
+        positions.iter().filter_map(|p| p.value_usd).sum::<f64>() + rewards_usd.unwrap_or(0.0);
+
+    Ok(ApiSuccessResponse::new(Portfolio {
+        address,
+        name,
+        total_value_usd,
+        blended_apy_bps: blended_apy_bps(&positions),
+        positions,
+        pending_intents,
+        unclaimed_rewards: UnclaimedRewards {
+            referral,
+            value_usd: rewards_usd,
+        },
+    }))
+}
@@ -0,0 +1,10 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn portfolio_routes() -> Router<AppState> {
+    Router::new().route("/{address}", get(handler::get_portfolio))
+}
@@ -0,0 +1,207 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+};
+use naisu_agent::route_cost::{
+    attestation_wait, evm_gas_cost, evm_gas_price, sui_deposit_gas, CCTP_BURN_GAS, EVM_SWAP_GAS,
+};
+use naisu_agent::token_check::{TokenCheckError, TokenMetadata};
+use naisu_core::{from_decimal, to_decimal, EvmChain, SUI_DECIMALS, USDC_DECIMALS};
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::feature::strategy::handler::{fetch_live_strategies, StrategyData};
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct FeeQuoteQuery {
+    /// USDC to bridge, or `input_token` to swap (base units)
+    pub amount: u64,
+    /// EVM token swapped into USDC (default: USDC itself)
+    pub input_token: Option<String>,
+    /// Source chain (default: `EVM_CHAIN_ID`)
+    pub chain: Option<EvmChain>,
+    /// Deposit transaction (base64 BCS) to dry run for the Sui gas
+    pub tx_bytes: Option<String>,
+    /// Include the top-ranked strategy for this asset (e.g. `SUI`)
+    pub asset: Option<String>,
+}
+
+/// One cost of the route
+#[derive(Debug, Serialize)]
+pub struct FeeItem {
+    /// Coin the cost is paid in
+    pub asset: &'static str,
+    /// Base units of `asset` (wei, MIST)
+    pub amount: u64,
+    /// `None` when the oracle has no price
+    pub value_usd: Option<f64>,
+}
+
+/// Itemized cost of an EvmToSui intent
+#[derive(Debug, Serialize)]
+pub struct FeeBreakdown {
+    pub chain: EvmChain,
+    /// USDC quoted (base units)
+    pub amount: u64,
+    /// The swapped token, when quoting a swap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_token: Option<TokenMetadata>,
+    pub evm_swap_gas: FeeItem,
+    pub cctp_burn_gas: FeeItem,
+    /// Expected wait for Circle's attestation
+    pub attestation_wait_secs: u64,
+    pub sui_deposit_gas: FeeItem,
+    /// Whether `sui_deposit_gas` comes from a dry run
+    pub sui_gas_dry_run: bool,
+    pub protocol_fee: FeeItem,
+    pub protocol_fee_bps: u16,
+    /// Sum of the priced items
+    pub total_usd: f64,
+    /// Items left out of `total_usd` for lack of a price
+    pub unpriced: Vec<&'static str>,
+    /// Top-ranked live strategy for `asset`, with its score breakdown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<StrategyData>,
+}
+
+/// Unsupported tokens are the client's to fix; RPC failures are not
+pub(crate) fn token_error(e: TokenCheckError) -> ApiErrorResponse {
+    let code = match e {
+        TokenCheckError::Rpc(_) => StatusCode::BAD_GATEWAY,
+        _ => StatusCode::UNPROCESSABLE_ENTITY,
+    };
+    ApiErrorResponse::new(e.to_string()).with_code(code)
+}
+
+async fn priced(state: &AppState, asset: &'static str, amount: u64) -> FeeItem {
+    FeeItem {
+        asset,
+        amount,
+        value_usd: state.prices.usd_value(asset, amount).await,
+    }
+}
+
+/// GET /quote/fees — gas, wait time and protocol fee of the full route
+///
+/// EVM gas is priced at the source chain's current gas price. The protocol
+/// fee is charged in SUI on the deposit, so `amount` is converted at the
+/// oracle's SUI price.
+///
+/// With `input_token`, `amount` is in the token's own decimals (read on
+/// chain) and converted to USDC at the oracle's price; fee-on-transfer
+/// tokens are rejected.
+///
+/// With `asset`, the quote also carries the strategy ranked first for it
+/// and why.
+pub async fn get_fee_quote(
+    State(state): State<AppState>,
+    Query(query): Query<FeeQuoteQuery>,
+) -> ApiResponse<FeeBreakdown> {
+    let evm = &state.config.evm;
+    let chain = match query.chain {
+        Some(chain) => chain,
+        None => EvmChain::from_chain_id(evm.chain_id).ok_or_else(|| {
+            ApiErrorResponse::new(format!("Unknown EVM_CHAIN_ID {}", evm.chain_id))
+                .with_code(StatusCode::INTERNAL_SERVER_ERROR)
+        })?,
+    };
+    let rpc_url = evm.rpc_url_for(chain);
+
+    let (amount, input_token) = match query
+        .input_token
+        .as_deref()
+        .filter(|t| !t.eq_ignore_ascii_case(chain.usdc_address()))
+    {
+        None => (query.amount, None),
+        Some(token) => {
+            let meta = state
+                .tokens
+                .metadata(rpc_url, token)
+                .await
+                .map_err(token_error)?;
+            let price = match &meta.symbol {
+                Some(symbol) => state.prices.usd_price(symbol).await,
+                None => None,
+            };
+            let price = price.ok_or_else(|| {
+                ApiErrorResponse::new(format!(
+                    "No USD price for {}; quote the USDC amount instead",
+                    meta.symbol.as_deref().unwrap_or(token)
+                ))
+                .with_code(StatusCode::UNPROCESSABLE_ENTITY)
+            })?;
+            let usdc = meta
+                .expected_usdc(query.amount, price)
+                .map_err(token_error)?;
+            (usdc, Some(meta))
+        }
+    };
+
+    let gas_price = evm_gas_price(rpc_url).await.map_err(|e| {
+        ApiErrorResponse::new(format!("EVM gas price unavailable: {}", e))
+            .with_code(StatusCode::BAD_GATEWAY)
+    })?;
+    let (sui_gas, sui_gas_dry_run) =
+        sui_deposit_gas(&state.sui_client(), query.tx_bytes.as_deref())
+            .await
+            .map_err(|e| {
+                let code = if query.tx_bytes.is_some() {
+                    StatusCode::BAD_REQUEST
+                } else {
+                    StatusCode::BAD_GATEWAY
+                };
+                ApiErrorResponse::new(format!("Sui gas unavailable: {}", e)).with_code(code)
+            })?;
+
+    let sui_usd = state.prices.usd_price("SUI").await.ok_or_else(|| {
+        ApiErrorResponse::new("No SUI price to quote the protocol fee")
+            .with_code(StatusCode::SERVICE_UNAVAILABLE)
+    })?;
+    let deposit_mist = from_decimal(to_decimal(amount, USDC_DECIMALS) / sui_usd, SUI_DECIMALS);
+    let fee = state.agent_config.get().fees.quote(deposit_mist);
+
+    let native = chain.native_symbol();
+    let evm_swap_gas = priced(&state, native, evm_gas_cost(EVM_SWAP_GAS, gas_price)).await;
+    let cctp_burn_gas = priced(&state, native, evm_gas_cost(CCTP_BURN_GAS, gas_price)).await;
+    let sui_deposit_gas = priced(&state, "SUI", sui_gas).await;
+    let protocol_fee = priced(&state, "SUI", fee.fee).await;
+
+    let items = [
+        ("evm_swap_gas", &evm_swap_gas),
+        ("cctp_burn_gas", &cctp_burn_gas),
+        ("sui_deposit_gas", &sui_deposit_gas),
+        ("protocol_fee", &protocol_fee),
+    ];
+    let total_usd = items.iter().filter_map(|(_, i)| i.value_usd).sum();
+    let unpriced = items
+        .iter()
+        .filter(|(_, i)| i.value_usd.is_none())
+        .map(|(name, _)| *name)
+        .collect();
+
+    let strategy = match &query.asset {
+        Some(asset) => fetch_live_strategies(&state)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .find(|s| s.asset.eq_ignore_ascii_case(asset)),
+        None => None,
+    };
+
+    Ok(ApiSuccessResponse::new(FeeBreakdown {
+        chain,
+        amount,
+        input_token,
+        evm_swap_gas,
+        cctp_burn_gas,
+        attestation_wait_secs: attestation_wait(chain).as_secs(),
+        sui_deposit_gas,
+        sui_gas_dry_run,
+        protocol_fee,
+        protocol_fee_bps: fee.bps,
+        total_usd,
+        unpriced,
+        strategy,
+    }))
+}
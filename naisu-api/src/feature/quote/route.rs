@@ -0,0 +1,10 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn quote_routes() -> Router<AppState> {
+    Router::new().route("/fees", get(handler::get_fee_quote))
+}
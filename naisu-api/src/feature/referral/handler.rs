@@ -0,0 +1,105 @@
+use axum::{
+    extract::{Json, Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use naisu_agent::audit::AuditAction;
+use naisu_agent::referral::{ReferralAccount, ReferralError};
+use naisu_sui::ProgrammableTransactionBlock;
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::feature::admin::handler::{authorize, ADMIN_ACTOR};
+use crate::state::AppState;
+
+/// Referrer addresses are stored lowercase
+pub(crate) fn normalize(address: &str) -> String {
+    address.trim().to_lowercase()
+}
+
+fn referral_error(e: ReferralError) -> ApiErrorResponse {
+    ApiErrorResponse::new(e.to_string()).with_code(StatusCode::CONFLICT)
+}
+
+/// GET /referrals/{address} — referred volume and accrued rewards
+pub async fn get_referrals(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> ApiResponse<ReferralAccount> {
+    let account = state.referrals.read().await.account(&normalize(&address));
+    Ok(ApiSuccessResponse::new(account))
+}
+
+/// Request body for a reward claim
+#[derive(Debug, Deserialize)]
+pub struct ClaimRequest {
+    /// Executed payout digest; confirms the pending claim
+    pub tx_hash: Option<String>,
+}
+
+/// A built or confirmed reward payout
+#[derive(Debug, Serialize)]
+pub struct ClaimResponse {
+    /// MIST
+    pub amount: u64,
+    pub account: ReferralAccount,
+    /// Signer of the payout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub treasury: Option<String>,
+    /// Unsigned payout for the treasury to sign
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sui_tx: Option<ProgrammableTransactionBlock>,
+}
+
+/// POST /referrals/{address}/claim — build or confirm a reward payout
+///
+/// Without `tx_hash` this reserves everything claimable and returns the
+/// unsigned payout from the fee treasury. Sending the executed payout's
+/// `tx_hash` afterwards (requires `x-admin-key`) marks it claimed.
+pub async fn claim_rewards(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<ClaimRequest>,
+) -> ApiResponse<ClaimResponse> {
+    let address = normalize(&address);
+
+    if let Some(tx_hash) = request.tx_hash {
+        authorize(&state, &headers)?;
+        let mut referrals = state.referrals.write().await;
+        let amount = referrals
+            .confirm_claim(&address, &tx_hash)
+            .map_err(referral_error)?;
+
+        state.audit(
+            ADMIN_ACTOR,
+            AuditAction::Admin,
+            "confirm_referral_claim",
+            None,
+            Some(serde_json::json!({ "referrer": address, "amount": amount, "tx_hash": tx_hash })),
+        );
+        tracing::info!(referrer = %address, amount, "Referral rewards claimed");
+        return Ok(ApiSuccessResponse::new(ClaimResponse {
+            amount,
+            account: referrals.account(&address),
+            treasury: None,
+            sui_tx: None,
+        })
+        .with_message("Claim confirmed"));
+    }
+
+    let Some(treasury) = state.agent_config.get().fees.treasury else {
+        return Err(ApiErrorResponse::new("No fee treasury configured")
+            .with_code(StatusCode::SERVICE_UNAVAILABLE));
+    };
+    let mut referrals = state.referrals.write().await;
+    let amount = referrals.begin_claim(&address).map_err(referral_error)?;
+
+    tracing::info!(referrer = %address, amount, "Referral claim built");
+    Ok(ApiSuccessResponse::new(ClaimResponse {
+        amount,
+        account: referrals.account(&address),
+        treasury: Some(treasury),
+        sui_tx: Some(naisu_sui::build_sui_payout_ptb(amount, &address)),
+    })
+    .with_message("Claim pending"))
+}
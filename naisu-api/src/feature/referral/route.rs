@@ -0,0 +1,12 @@
+use axum::routing::{get, post};
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn referral_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{address}", get(handler::get_referrals))
+        .route("/{address}/claim", post(handler::claim_rewards))
+}
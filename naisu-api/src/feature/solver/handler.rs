@@ -1,26 +1,43 @@
 use axum::{
-    extract::{Json, Path, State},
-    http::StatusCode,
+    extract::{Json, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
 };
+use naisu_agent::accounting::{FulfillmentRecord, Granularity};
+use naisu_agent::config::Protocol;
+use naisu_agent::heartbeat::Heartbeat;
+use naisu_agent::reputation::{self, Reputation};
+use naisu_agent::selection::{Rankable, SelectionStrategy};
 use serde::{Deserialize, Serialize};
 
-use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
-use crate::state::{AppState, SolverBidEntry};
+use crate::common::response::{
+    ApiErrorResponse, ApiResponse, ApiSuccessResponse, ErrorCode, PageQuery,
+};
+use crate::feature::admin::handler::now_millis;
+use crate::state::{
+    AppState, BidOutcome, SelectedWinner, SolverBidEntry, SolverLiveness, WorseBid,
+};
 
 /// Response DTO for solver bids (matches frontend expectations)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct SolverBidResponse {
     pub solver_id: String,
     pub solver_name: String,
     pub protocol: String,
     pub apy: u64, // basis points
+    /// APY spread the solver keeps, net of the tip (bps)
+    pub spread_bps: u64,
+    pub tip_bps: u64,
     pub timestamp: u64,
     pub confidence: f64,
 }
 
 impl From<SolverBidEntry> for SolverBidResponse {
     fn from(entry: SolverBidEntry) -> Self {
+        let confidence = entry.confidence();
+
         // Infer solver_id from solver_name (e.g., "ScallopSolver" -> "scallop")
         let solver_id = if entry.solver_name.to_lowercase().contains("scallop") {
             "scallop".to_string()
@@ -35,8 +52,10 @@ impl From<SolverBidEntry> for SolverBidResponse {
             solver_name: entry.solver_name,
             protocol: entry.protocol,
             apy: entry.offered_apy,
+            spread_bps: entry.profit_bps,
+            tip_bps: entry.tip_bps,
             timestamp: entry.timestamp,
-            confidence: 0.95, // Default high confidence
+            confidence,
         }
     }
 }
@@ -57,6 +76,27 @@ pub async fn post_bid(
         );
     }
 
+    if let Some(intent) = state.get_intent(&bid.intent_id).await {
+        let protocol = bid.protocol.parse::<Protocol>();
+        if let Some(max) = intent.max_risk {
+            if !protocol.as_ref().is_ok_and(|p| p.risk_tier() <= max) {
+                return Err(ApiErrorResponse::new(format!(
+                    "Protocol {} is above the intent's max risk ({})",
+                    bid.protocol,
+                    max.as_str()
+                ))
+                .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+            }
+        }
+        if intent.stable_only && !protocol.as_ref().is_ok_and(Protocol::is_stable) {
+            return Err(ApiErrorResponse::new(format!(
+                "Protocol {} is not a stablecoin strategy; the intent is stable-only",
+                bid.protocol
+            ))
+            .with_code(StatusCode::UNPROCESSABLE_ENTITY));
+        }
+    }
+
     tracing::info!(
         intent_id = %bid.intent_id,
         solver = %bid.solver_name,
@@ -64,18 +104,35 @@ pub async fn post_bid(
         "New solver bid received"
     );
 
-    let stored = bid.clone();
-    state.add_bid(bid).await;
-
-    Ok(ApiSuccessResponse::new(stored)
-        .with_code(StatusCode::CREATED)
-        .with_message("Bid stored"))
+    match state.add_bid(bid).await {
+        Ok((BidOutcome::Created, stored)) => Ok(ApiSuccessResponse::new(stored)
+            .with_code(StatusCode::CREATED)
+            .with_message("Bid stored")),
+        Ok((BidOutcome::Replaced { previous_apy }, stored)) => {
+            let message = format!(
+                "Bid replaced ({} → {} bps)",
+                previous_apy, stored.offered_apy
+            );
+            Ok(ApiSuccessResponse::new(stored).with_message(message))
+        }
+        Ok((BidOutcome::Unchanged, active)) => {
+            Ok(ApiSuccessResponse::new(active).with_message("Bid unchanged"))
+        }
+        Err(WorseBid { active_apy }) => Err(ApiErrorResponse::coded(
+            ErrorCode::BidTooLow,
+            format!(
+                "Active bid of {} bps is higher; re-bids must not lower the APY",
+                active_apy
+            ),
+        )),
+    }
 }
 
-/// GET /solvers/bids/:intent_id — retrieve all bids for an intent
+/// GET /solvers/bids/:intent_id — retrieve the active bids for an intent (one per solver)
 pub async fn get_bids(
     State(state): State<AppState>,
     Path(intent_id): Path<String>,
+    Query(page): Query<PageQuery>,
 ) -> ApiResponse<Vec<SolverBidResponse>> {
     let bids = state.get_bids_for_intent(&intent_id).await;
 
@@ -83,5 +140,213 @@ pub async fn get_bids(
     let response_bids: Vec<SolverBidResponse> =
         bids.into_iter().map(SolverBidResponse::from).collect();
 
-    Ok(ApiSuccessResponse::new(response_bids))
+    let (response_bids, pagination) = page.paginate(response_bids)?;
+    Ok(ApiSuccessResponse::new(response_bids).with_pagination(pagination))
+}
+
+/// GET /solvers/bids/{intent_id}/history — every accepted bid revision
+pub async fn get_bid_history(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Query(page): Query<PageQuery>,
+) -> ApiResponse<Vec<SolverBidEntry>> {
+    let (history, pagination) = page.paginate(state.get_bid_history(&intent_id).await)?;
+    Ok(ApiSuccessResponse::new(history).with_pagination(pagination))
+}
+
+/// Query parameters for a winner preview
+#[derive(Debug, Deserialize)]
+pub struct WinnerQuery {
+    /// Intent's minimum APY (basis points)
+    pub min_apy: Option<u64>,
+}
+
+/// Which bid would win an intent under the configured policy
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WinnerPreview {
+    pub strategy: SelectionStrategy,
+    pub min_confidence: f64,
+    pub winner: Option<SolverBidResponse>,
+    /// Eligible bids, best first
+    pub ranking: Vec<SolverBidResponse>,
+    /// Winner a solver daemon actually picked, once it reports one
+    pub selected: Option<SelectedWinner>,
+}
+
+/// GET /solvers/bids/{intent_id}/winner — preview the winning bid
+pub async fn get_winner(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Query(params): Query<WinnerQuery>,
+) -> ApiResponse<WinnerPreview> {
+    let policy = state.agent_config.get().selection;
+    let scores = reputation::scores(&state.reputation().await);
+
+    let mut bids = state.get_bids_for_intent(&intent_id).await;
+    bids.sort_by_key(|b| b.timestamp);
+    let ranking: Vec<SolverBidResponse> = policy
+        .rank_with(bids, params.min_apy.unwrap_or(0), &scores)
+        .into_iter()
+        .map(SolverBidResponse::from)
+        .collect();
+
+    Ok(ApiSuccessResponse::new(WinnerPreview {
+        strategy: policy.strategy,
+        min_confidence: policy.min_confidence,
+        winner: ranking.first().cloned(),
+        ranking,
+        selected: state.get_selected_winner(&intent_id).await,
+    }))
+}
+
+/// POST /solvers/bids/{intent_id}/winner — record the winner a daemon picked
+pub async fn post_winner(
+    State(state): State<AppState>,
+    Path(intent_id): Path<String>,
+    Json(mut winner): Json<SelectedWinner>,
+) -> ApiResponse<SelectedWinner> {
+    if winner.solver_name.is_empty() {
+        return Err(
+            ApiErrorResponse::new("solver_name is required").with_code(StatusCode::BAD_REQUEST)
+        );
+    }
+    winner.selected_at = now_millis();
+
+    tracing::info!(
+        intent_id = %intent_id,
+        solver = %winner.solver_name,
+        apy_bps = winner.offered_apy,
+        "Solver daemon picked a winner"
+    );
+    state
+        .record_selected_winner(&intent_id, winner.clone())
+        .await;
+    Ok(ApiSuccessResponse::new(winner).with_message("Winner recorded"))
+}
+
+/// GET /solvers/leaderboard — solvers by reputation, best first
+pub async fn get_leaderboard(State(state): State<AppState>) -> ApiResponse<Vec<Reputation>> {
+    Ok(ApiSuccessResponse::new(state.reputation().await))
+}
+
+/// Query parameters for the solver listing
+#[derive(Debug, Deserialize)]
+pub struct SolversQuery {
+    /// Only solvers on this network
+    pub network: Option<String>,
+}
+
+/// GET /solvers — solvers that have sent heartbeats, flagged stale when they
+/// stopped
+pub async fn get_solvers(
+    State(state): State<AppState>,
+    Query(query): Query<SolversQuery>,
+) -> ApiResponse<Vec<SolverLiveness>> {
+    let solvers = state
+        .solver_liveness(query.network.as_deref(), now_millis())
+        .await;
+    Ok(ApiSuccessResponse::new(solvers))
+}
+
+/// POST /solvers/heartbeat — a daemon reporting its solvers alive
+pub async fn post_heartbeat(
+    State(state): State<AppState>,
+    Json(heartbeat): Json<Heartbeat>,
+) -> ApiResponse<()> {
+    if heartbeat.interval_secs == 0 {
+        return Err(ApiErrorResponse::new("interval_secs must be at least 1")
+            .with_code(StatusCode::BAD_REQUEST));
+    }
+    state.record_heartbeat(heartbeat, now_millis()).await;
+    Ok(ApiSuccessResponse::new(()).with_message("Heartbeat recorded"))
+}
+
+/// POST /solvers/{id}/fulfillments — record a fulfillment for PnL accounting
+pub async fn post_fulfillment(
+    State(state): State<AppState>,
+    Path(solver_id): Path<String>,
+    Json(mut record): Json<FulfillmentRecord>,
+) -> ApiResponse<FulfillmentRecord> {
+    if record.digest.is_empty() {
+        return Err(ApiErrorResponse::new("digest is required").with_code(StatusCode::BAD_REQUEST));
+    }
+    record.solver = solver_id;
+
+    state.ledger.write().await.record(record.clone());
+
+    Ok(ApiSuccessResponse::new(record)
+        .with_code(StatusCode::CREATED)
+        .with_message("Fulfillment recorded"))
+}
+
+/// Realized return for a settled fulfillment
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRequest {
+    pub digest: String,
+    pub realized_return: i64,
+}
+
+/// POST /solvers/{id}/settlements — set the realized return of a fulfillment
+pub async fn post_settlement(
+    State(state): State<AppState>,
+    Path(_solver_id): Path<String>,
+    Json(req): Json<SettlementRequest>,
+) -> ApiResponse<()> {
+    if !state
+        .ledger
+        .write()
+        .await
+        .settle(&req.digest, req.realized_return)
+    {
+        return Err(
+            ApiErrorResponse::new(format!("Unknown fulfillment: {}", req.digest))
+                .with_code(StatusCode::NOT_FOUND),
+        );
+    }
+
+    Ok(ApiSuccessResponse::new(()).with_message("Settlement recorded"))
+}
+
+/// Query parameters for PnL reports
+#[derive(Debug, Deserialize)]
+pub struct PnlQuery {
+    /// `daily` (default) or `weekly`
+    pub period: Option<String>,
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+/// GET /solvers/{id}/pnl — daily/weekly PnL, as JSON or CSV
+pub async fn get_pnl(
+    State(state): State<AppState>,
+    Path(solver_id): Path<String>,
+    Query(params): Query<PnlQuery>,
+) -> Result<Response, ApiErrorResponse> {
+    let granularity = match params.period.as_deref() {
+        Some(period) => period
+            .parse::<Granularity>()
+            .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::BAD_REQUEST))?,
+        None => Granularity::default(),
+    };
+
+    let report = state.ledger.read().await.report(&solver_id, granularity);
+
+    match params.format.as_deref() {
+        Some("csv") => Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}-pnl.csv\"", solver_id),
+                ),
+            ],
+            report.to_csv(),
+        )
+            .into_response()),
+        None | Some("json") => Ok(ApiSuccessResponse::new(report).into_response()),
+        Some(other) => Err(ApiErrorResponse::new(format!("Unknown format: {}", other))
+            .with_code(StatusCode::BAD_REQUEST)),
+    }
 }
@@ -7,6 +7,17 @@ use super::handler;
 
 pub fn solver_routes() -> Router<AppState> {
     Router::new()
+        .route("/", get(handler::get_solvers))
+        .route("/heartbeat", post(handler::post_heartbeat))
         .route("/bids", post(handler::post_bid))
         .route("/bids/{intent_id}", get(handler::get_bids))
+        .route("/bids/{intent_id}/history", get(handler::get_bid_history))
+        .route(
+            "/bids/{intent_id}/winner",
+            get(handler::get_winner).post(handler::post_winner),
+        )
+        .route("/leaderboard", get(handler::get_leaderboard))
+        .route("/{id}/fulfillments", post(handler::post_fulfillment))
+        .route("/{id}/settlements", post(handler::post_settlement))
+        .route("/{id}/pnl", get(handler::get_pnl))
 }
@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+};
+use naisu_agent::config::Network;
+use naisu_sui::ValidatorInfo;
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse, ErrorCode};
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorsQuery {
+    /// `testnet` or `mainnet` (default: current network)
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidatorList {
+    pub network: String,
+    /// Highest APY first
+    pub validators: Vec<ValidatorInfo>,
+}
+
+/// GET /staking/validators — active validators with APY, commission and stake
+pub async fn get_validators(
+    State(state): State<AppState>,
+    Query(params): Query<ValidatorsQuery>,
+) -> ApiResponse<ValidatorList> {
+    let network: Network = params
+        .network
+        .unwrap_or_else(|| state.network())
+        .parse()
+        .map_err(|e: String| ApiErrorResponse::coded(ErrorCode::NetworkUnsupported, e))?;
+
+    let mut validators = state
+        .sui_client_for(network)
+        .get_validators()
+        .await
+        .map_err(|e| {
+            ApiErrorResponse::new(format!("Failed to fetch validators: {}", e))
+                .with_code(StatusCode::BAD_GATEWAY)
+        })?;
+    validators.sort_by(|a, b| b.apy.total_cmp(&a.apy));
+
+    Ok(ApiSuccessResponse::new(ValidatorList {
+        network: network.name().to_string(),
+        validators,
+    }))
+}
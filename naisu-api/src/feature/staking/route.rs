@@ -0,0 +1,10 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn staking_routes() -> Router<AppState> {
+    Router::new().route("/validators", get(handler::get_validators))
+}
@@ -1,6 +1,19 @@
-use serde::Serialize;
+use std::sync::Arc;
 
-use crate::common::response::{ApiResponse, ApiSuccessResponse};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+};
+use naisu_agent::analytics::Window;
+use naisu_agent::apy_history::Comparison;
+use naisu_agent::config::Network;
+use naisu_core::{is_stable_asset, RiskModel, RiskTier};
+use naisu_sui::ScoreBreakdown;
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse, PageQuery};
+use crate::feature::admin::handler::now_millis;
+use crate::state::AppState;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct StrategyData {
@@ -8,68 +21,82 @@ pub struct StrategyData {
     pub protocol: String,
     pub asset: String,
     pub apy: f64,
+    /// 1-10, lower is safer
     pub risk_score: u8,
+    pub risk_tier: RiskTier,
+    /// Composite ranking score (live data only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Points and penalties behind `score`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_breakdown: Option<ScoreBreakdown>,
+}
+
+impl StrategyData {
+    fn new(id: &str, protocol: &str, asset: &str, apy: f64, risk_score: u8) -> Self {
+        Self {
+            id: id.to_string(),
+            protocol: protocol.to_string(),
+            asset: asset.to_string(),
+            apy,
+            risk_score,
+            risk_tier: RiskModel::default().tier(risk_score),
+            score: None,
+            score_breakdown: None,
+        }
+    }
 }
 
 /// Hardcoded fallback matching MOCK_RATES used by the solver bots
 fn mock_strategies() -> Vec<StrategyData> {
     vec![
-        StrategyData {
-            id: "scallop_sui".to_string(),
-            protocol: "Scallop".to_string(),
-            asset: "SUI".to_string(),
-            apy: 8.5,
-            risk_score: 3,
-        },
-        StrategyData {
-            id: "scallop_usdc".to_string(),
-            protocol: "Scallop".to_string(),
-            asset: "USDC".to_string(),
-            apy: 7.2,
-            risk_score: 2,
-        },
-        StrategyData {
-            id: "navi_sui".to_string(),
-            protocol: "Navi".to_string(),
-            asset: "SUI".to_string(),
-            apy: 8.0,
-            risk_score: 4,
-        },
-        StrategyData {
-            id: "navi_usdc".to_string(),
-            protocol: "Navi".to_string(),
-            asset: "USDC".to_string(),
-            apy: 6.8,
-            risk_score: 3,
-        },
+        StrategyData::new("scallop_sui", "Scallop", "SUI", 8.5, 3),
+        StrategyData::new("scallop_usdc", "Scallop", "USDC", 7.2, 2),
+        StrategyData::new("navi_sui", "Navi", "SUI", 8.0, 4),
+        StrategyData::new("navi_usdc", "Navi", "USDC", 6.8, 3),
     ]
 }
 
-/// GET /strategies — returns yield strategies.
+#[derive(Debug, Deserialize)]
+pub struct StrategiesQuery {
+    /// Only stablecoin lending and stable-stable pools
+    #[serde(default)]
+    pub stable_only: bool,
+}
+
+/// GET /strategies — returns yield strategies, best score first.
 /// Attempts live adapter fetch; on any failure returns mock data.
-pub async fn get_strategies() -> ApiResponse<Vec<StrategyData>> {
+pub async fn get_strategies(
+    State(state): State<AppState>,
+    Query(params): Query<StrategiesQuery>,
+    Query(page): Query<PageQuery>,
+) -> ApiResponse<Vec<StrategyData>> {
     // Try real adapters via naisu-sui
-    let live = fetch_live_strategies().await;
+    let live = fetch_live_strategies(&state).await;
 
-    let strategies = match live {
+    let mut strategies = match live {
         Some(data) if !data.is_empty() => data,
         _ => {
             tracing::info!("Using mock strategy fallback");
             mock_strategies()
         }
     };
+    if params.stable_only {
+        strategies.retain(|s| is_stable_asset(&s.asset));
+    }
 
-    Ok(ApiSuccessResponse::new(strategies))
+    let (strategies, pagination) = page.paginate(strategies)?;
+    Ok(ApiSuccessResponse::new(strategies).with_pagination(pagination))
 }
 
-/// Attempt to pull data from the real Scallop/Navi adapters.
+/// Attempt to pull data from the real protocol adapters.
 /// Returns None on any error so we can fall back gracefully.
-async fn fetch_live_strategies() -> Option<Vec<StrategyData>> {
-    use naisu_sui::adapters::{NaviAdapter, ScallopAdapter, YieldComparator};
+pub(crate) async fn fetch_live_strategies(state: &AppState) -> Option<Vec<StrategyData>> {
+    use naisu_sui::adapters::YieldComparator;
 
-    let scallop = ScallopAdapter::new();
-    let navi = NaviAdapter::new();
-    let comparator = YieldComparator::new(scallop, navi);
+    // Scallop and Navi are read on chain when their APIs are down
+    let sui = Arc::new(state.sui_client_for(Network::Mainnet));
+    let comparator = YieldComparator::mainnet_with_chain(sui);
 
     let opportunities = comparator.get_all_opportunities().await.ok()?;
 
@@ -89,8 +116,56 @@ async fn fetch_live_strategies() -> Option<Vec<StrategyData>> {
             asset: o.asset,
             apy: o.apy,
             risk_score: o.risk_score,
+            risk_tier: o.risk_tier,
+            score: Some(o.score),
+            score_breakdown: Some(o.score_breakdown),
         })
         .collect();
 
     Some(strategies)
 }
+
+#[derive(Debug, Deserialize)]
+pub struct CompareQuery {
+    /// Comma-separated, e.g. `USDC,SUI` (default: all sampled)
+    pub assets: Option<String>,
+    /// Comma-separated, e.g. `scallop,navi` (default: all sampled)
+    pub protocols: Option<String>,
+    /// `1h`, `24h`, `7d`, `30d` (default) or `all`
+    pub window: Option<String>,
+}
+
+fn split_list(list: Option<&str>) -> Vec<String> {
+    list.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// GET /strategies/compare — APY history of several strategies on one time grid
+///
+/// Every requested protocol × asset gets a series with one value per
+/// timestamp (`null` where it wasn't sampled), ready to chart together.
+pub async fn compare_strategies(
+    State(state): State<AppState>,
+    Query(params): Query<CompareQuery>,
+) -> ApiResponse<Comparison> {
+    let window = match params.window.as_deref() {
+        Some(window) => window
+            .parse::<Window>()
+            .map_err(|e| ApiErrorResponse::new(e).with_code(StatusCode::BAD_REQUEST))?,
+        None => Window::Month,
+    };
+    let protocols = split_list(params.protocols.as_deref());
+    let assets = split_list(params.assets.as_deref());
+
+    let comparison =
+        state
+            .apy_history
+            .read()
+            .await
+            .compare(&protocols, &assets, window, now_millis());
+    Ok(ApiSuccessResponse::new(comparison))
+}
@@ -0,0 +1,37 @@
+//! APY Sampling
+//!
+//! Background job recording every live strategy's APY into the history
+//! `/strategies/compare` charts from. Mock fallback rates are never
+//! recorded.
+
+use std::time::Duration;
+
+use super::handler::fetch_live_strategies;
+use crate::feature::admin::handler::now_millis;
+use crate::state::AppState;
+
+/// Time between samples
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Record one sample of every live strategy
+pub async fn sample_once(state: &AppState) -> usize {
+    let Some(strategies) = fetch_live_strategies(state).await else {
+        return 0;
+    };
+    let now = now_millis();
+    let mut history = state.apy_history.write().await;
+    for s in &strategies {
+        history.record(&s.protocol, &s.asset, s.apy, now);
+    }
+    strategies.len()
+}
+
+/// Sample forever
+pub async fn run(state: AppState) {
+    loop {
+        if sample_once(&state).await == 0 {
+            tracing::warn!("APY sample skipped: no live strategy data");
+        }
+        tokio::time::sleep(SAMPLE_INTERVAL).await;
+    }
+}
@@ -1,2 +1,3 @@
 pub mod handler;
+pub mod history;
 pub mod route;
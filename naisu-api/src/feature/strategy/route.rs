@@ -6,5 +6,7 @@ use crate::state::AppState;
 use super::handler;
 
 pub fn strategy_routes() -> Router<AppState> {
-    Router::new().route("/", get(handler::get_strategies))
+    Router::new()
+        .route("/", get(handler::get_strategies))
+        .route("/compare", get(handler::compare_strategies))
 }
@@ -0,0 +1,150 @@
+use axum::{
+    extract::{Json, Path, Query, State},
+    http::StatusCode,
+};
+use naisu_core::validate_sui_address;
+use naisu_sui::{
+    prepare_usdc_coin, CctpSuiError, CoinPreparation, CoinSummary, SuiClientError, USDC_COIN_TYPE,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::common::response::{ApiErrorResponse, ApiResponse, ApiSuccessResponse};
+use crate::state::AppState;
+
+/// SUI coin type, reported by `/balances` alongside USDC
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+fn validate_address(address: &str) -> Result<(), ApiErrorResponse> {
+    Ok(validate_sui_address(address)?)
+}
+
+fn rpc_error(e: SuiClientError) -> ApiErrorResponse {
+    ApiErrorResponse::new(format!("Failed to fetch coins: {}", e))
+        .with_code(StatusCode::BAD_GATEWAY)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsdcQuery {
+    /// Amount the caller wants to spend (base units)
+    pub amount: Option<u64>,
+}
+
+/// USDC balance, and whether it can pay `amount` as is
+#[derive(Debug, Serialize)]
+pub struct UsdcBalance {
+    #[serde(flatten)]
+    pub summary: CoinSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    /// Balance covers `amount`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sufficient: Option<bool>,
+    /// No single coin covers `amount`; merge before building the burn PTB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_merge: Option<bool>,
+}
+
+/// GET /wallet/{address}/usdc — USDC balance and coin layout
+///
+/// With `?amount=`, also says whether the balance covers it and whether
+/// coins must be merged first.
+pub async fn get_usdc(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(params): Query<UsdcQuery>,
+) -> ApiResponse<UsdcBalance> {
+    validate_address(&address)?;
+    let summary = state
+        .sui_client()
+        .get_coin_summary(&address, USDC_COIN_TYPE)
+        .await
+        .map_err(rpc_error)?;
+
+    Ok(ApiSuccessResponse::new(UsdcBalance {
+        sufficient: params.amount.map(|a| summary.covers(a)),
+        needs_merge: params.amount.map(|a| summary.needs_merge(a)),
+        amount: params.amount,
+        summary,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BalancesQuery {
+    /// Coin type to report (default: SUI and USDC)
+    pub coin_type: Option<String>,
+}
+
+/// GET /wallet/{address}/balances — balances per coin type
+pub async fn get_balances(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(params): Query<BalancesQuery>,
+) -> ApiResponse<Vec<CoinSummary>> {
+    validate_address(&address)?;
+    let coin_types = match params.coin_type {
+        Some(coin_type) => vec![coin_type],
+        None => vec![SUI_COIN_TYPE.to_string(), USDC_COIN_TYPE.to_string()],
+    };
+
+    let client = state.sui_client();
+    let mut balances = Vec::new();
+    for coin_type in &coin_types {
+        balances.push(
+            client
+                .get_coin_summary(&address, coin_type)
+                .await
+                .map_err(rpc_error)?,
+        );
+    }
+    Ok(ApiSuccessResponse::new(balances))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PrepareUsdcRequest {
+    /// Exact amount the burn needs (base units)
+    pub amount: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreparedUsdc {
+    pub amount: u64,
+    /// `ready` with the coin to burn, or `prepare` with a PTB to sign first
+    #[serde(flatten)]
+    pub preparation: CoinPreparation,
+}
+
+/// POST /wallet/{address}/usdc/prepare — get a USDC coin of exact amount
+///
+/// CCTP burns a whole coin. Returns the coin if one already matches,
+/// otherwise a PTB that merges/splits the holder's USDC into one.
+pub async fn prepare_usdc(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Json(payload): Json<PrepareUsdcRequest>,
+) -> ApiResponse<PreparedUsdc> {
+    validate_address(&address)?;
+    let coins = state
+        .sui_client()
+        .get_coins(&address, Some(USDC_COIN_TYPE))
+        .await
+        .map_err(rpc_error)?;
+
+    let preparation = prepare_usdc_coin(&coins, payload.amount, &address).map_err(|e| match e {
+        CctpSuiError::CoinNotFound(_) | CctpSuiError::InsufficientBalance => {
+            ApiErrorResponse::new(format!("Not enough USDC for {}", payload.amount))
+                .with_code(StatusCode::CONFLICT)
+        }
+        e => ApiErrorResponse::new(format!("Failed to prepare coins: {}", e))
+            .with_code(StatusCode::BAD_REQUEST),
+    })?;
+
+    let message = match &preparation {
+        CoinPreparation::Ready { .. } => "Coin ready, no preparation needed",
+        CoinPreparation::Prepare { .. } => "Sign the PTB to prepare the coin",
+    };
+    Ok(ApiSuccessResponse::new(PreparedUsdc {
+        amount: payload.amount,
+        preparation,
+    })
+    .with_message(message))
+}
@@ -0,0 +1,13 @@
+use axum::routing::{get, post};
+use axum::Router;
+
+use crate::state::AppState;
+
+use super::handler;
+
+pub fn wallet_routes() -> Router<AppState> {
+    Router::new()
+        .route("/{address}/usdc", get(handler::get_usdc))
+        .route("/{address}/usdc/prepare", post(handler::prepare_usdc))
+        .route("/{address}/balances", get(handler::get_balances))
+}
@@ -0,0 +1,29 @@
+//! gRPC server
+//!
+//! Tonic service for solver daemons, served next to the REST API when the
+//! `grpc` feature is on. Each RPC runs through the matching REST handler,
+//! so validation, solver auth and state are shared.
+
+pub mod service;
+
+pub mod proto {
+    tonic::include_proto!("naisu.v1");
+}
+
+use std::net::SocketAddr;
+
+use tracing::info;
+
+use crate::state::AppState;
+use proto::intent_service_server::IntentServiceServer;
+use service::IntentGrpc;
+
+/// Serve the gRPC API on `port`
+pub async fn serve(state: AppState, port: u16) -> Result<(), tonic::transport::Error> {
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    info!("📡 gRPC available on {}", addr);
+    tonic::transport::Server::builder()
+        .add_service(IntentServiceServer::new(IntentGrpc::new(state)))
+        .serve(addr)
+        .await
+}
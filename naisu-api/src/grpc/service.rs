@@ -0,0 +1,278 @@
+use axum::extract::{Json, Path, Query, State};
+use axum::http::StatusCode;
+use naisu_agent::verify::VerificationStatus;
+use naisu_core::{FailedAttempt, FulfilledObject, FulfillmentReport};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Code, Request, Response, Status};
+
+use super::proto::{self, intent_service_server::IntentService};
+use crate::common::response::ApiErrorResponse;
+use crate::feature::admin::handler::now_millis;
+use crate::feature::intent::handler::{self as intent, IntentResponse, ListIntentsQuery};
+use crate::feature::solver::handler as solver;
+use crate::state::{AppState, SolverBidEntry};
+
+/// Events queued per watcher before it is treated as slow
+const WATCH_BUFFER: usize = 64;
+
+/// `IntentService` over the shared API state
+pub struct IntentGrpc {
+    state: AppState,
+}
+
+impl IntentGrpc {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+}
+
+/// gRPC status for a REST handler error, by its HTTP code
+fn to_status(e: ApiErrorResponse) -> Status {
+    let code = match StatusCode::from_u16(e.code) {
+        Ok(StatusCode::BAD_REQUEST) => Code::InvalidArgument,
+        Ok(StatusCode::UNAUTHORIZED) => Code::Unauthenticated,
+        Ok(StatusCode::FORBIDDEN) => Code::PermissionDenied,
+        Ok(StatusCode::NOT_FOUND) => Code::NotFound,
+        Ok(StatusCode::CONFLICT) => Code::AlreadyExists,
+        Ok(StatusCode::UNPROCESSABLE_ENTITY) => Code::FailedPrecondition,
+        _ => Code::Internal,
+    };
+    Status::new(code, e.message)
+}
+
+// ─── Conversions ─────────────────────────────────────────────────────────────
+
+impl From<FulfillmentReport> for proto::Fulfillment {
+    fn from(report: FulfillmentReport) -> Self {
+        Self {
+            digest: report.digest,
+            solver: report.solver,
+            protocol: report.protocol,
+            realized_apy_bps: report.realized_apy_bps,
+            created_objects: report
+                .created_objects
+                .into_iter()
+                .map(|o| proto::FulfilledObject {
+                    object_id: o.object_id,
+                    object_type: o.object_type,
+                })
+                .collect(),
+            reported_at: report.reported_at,
+            user: report.user,
+            amount: report.amount,
+            fee: report.fee,
+            gas_used: report.gas_used,
+            failed_attempts: report
+                .failed_attempts
+                .into_iter()
+                .map(|a| proto::FailedAttempt {
+                    solver: a.solver,
+                    apy_bps: a.apy_bps,
+                    error: a.error,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<proto::Fulfillment> for FulfillmentReport {
+    fn from(report: proto::Fulfillment) -> Self {
+        Self {
+            digest: report.digest,
+            solver: report.solver,
+            protocol: report.protocol,
+            realized_apy_bps: report.realized_apy_bps,
+            created_objects: report
+                .created_objects
+                .into_iter()
+                .map(|o| FulfilledObject {
+                    object_id: o.object_id,
+                    object_type: o.object_type,
+                })
+                .collect(),
+            reported_at: report.reported_at,
+            user: report.user,
+            amount: report.amount,
+            fee: report.fee,
+            gas_used: report.gas_used,
+            failed_attempts: report
+                .failed_attempts
+                .into_iter()
+                .map(|a| FailedAttempt {
+                    solver: a.solver,
+                    apy_bps: a.apy_bps,
+                    error: a.error,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<IntentResponse> for proto::Intent {
+    fn from(intent: IntentResponse) -> Self {
+        Self {
+            intent_id: intent.intent_id,
+            user: intent.user,
+            amount: intent.amount,
+            min_apy: intent.min_apy,
+            deadline: intent.deadline,
+            status: intent.status,
+            target_protocol: intent.target_protocol,
+            created_at: intent.created_at,
+            tx_digest: intent.tx_digest,
+            fulfillment: intent.fulfillment.map(Into::into),
+            verification: intent.verification.map(|v| {
+                match v.status {
+                    VerificationStatus::Verified => "verified",
+                    VerificationStatus::Mismatch => "mismatch",
+                    VerificationStatus::Unverifiable => "unverifiable",
+                }
+                .to_string()
+            }),
+        }
+    }
+}
+
+impl From<SolverBidEntry> for proto::Bid {
+    fn from(bid: SolverBidEntry) -> Self {
+        Self {
+            intent_id: bid.intent_id,
+            solver_name: bid.solver_name,
+            protocol: bid.protocol,
+            offered_apy: bid.offered_apy,
+            profit_bps: bid.profit_bps,
+            tip_bps: bid.tip_bps,
+            timestamp: bid.timestamp,
+            confidence: bid.confidence,
+            revision: bid.revision,
+        }
+    }
+}
+
+impl From<proto::Bid> for SolverBidEntry {
+    fn from(bid: proto::Bid) -> Self {
+        Self {
+            intent_id: bid.intent_id,
+            solver_name: bid.solver_name,
+            protocol: bid.protocol,
+            offered_apy: bid.offered_apy,
+            profit_bps: bid.profit_bps,
+            tip_bps: bid.tip_bps,
+            timestamp: match bid.timestamp {
+                0 => now_millis(),
+                timestamp => timestamp,
+            },
+            confidence: bid.confidence,
+            // Assigned by the API
+            revision: 0,
+        }
+    }
+}
+
+// ─── Service ─────────────────────────────────────────────────────────────────
+
+#[tonic::async_trait]
+impl IntentService for IntentGrpc {
+    async fn list_intents(
+        &self,
+        request: Request<proto::ListIntentsRequest>,
+    ) -> Result<Response<proto::ListIntentsResponse>, Status> {
+        let req = request.into_inner();
+        let query = ListIntentsQuery {
+            status: req.status,
+            limit: req.limit.map(|l| l as usize),
+            cursor: None,
+        };
+        let intents = intent::list_intents(State(self.state.clone()), Query(query))
+            .await
+            .map_err(to_status)?
+            .data;
+        Ok(Response::new(proto::ListIntentsResponse {
+            intents: intents.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn submit_bid(
+        &self,
+        request: Request<proto::SubmitBidRequest>,
+    ) -> Result<Response<proto::SubmitBidResponse>, Status> {
+        let bid = request
+            .into_inner()
+            .bid
+            .ok_or_else(|| Status::invalid_argument("bid is required"))?;
+        let stored = solver::post_bid(State(self.state.clone()), Json(bid.into()))
+            .await
+            .map_err(to_status)?;
+        Ok(Response::new(proto::SubmitBidResponse {
+            bid: Some(stored.data.into()),
+            message: stored.message.unwrap_or_default(),
+        }))
+    }
+
+    async fn report_fulfillment(
+        &self,
+        request: Request<proto::ReportFulfillmentRequest>,
+    ) -> Result<Response<proto::ReportFulfillmentResponse>, Status> {
+        // Carries `x-solver-key`
+        let headers = request.metadata().clone().into_headers();
+        let req = request.into_inner();
+        let report = req
+            .report
+            .ok_or_else(|| Status::invalid_argument("report is required"))?;
+        let recorded = intent::report_fulfillment(
+            State(self.state.clone()),
+            Path(req.intent_id),
+            headers,
+            Json(report.into()),
+        )
+        .await
+        .map_err(to_status)?;
+        Ok(Response::new(proto::ReportFulfillmentResponse {
+            report: Some(recorded.data.into()),
+            message: recorded.message.unwrap_or_default(),
+        }))
+    }
+
+    type WatchIntentsStream = ReceiverStream<Result<proto::IntentEvent, Status>>;
+
+    /// Streams until the client disconnects; a watcher that falls behind
+    /// skips the missed changes rather than stalling the others
+    async fn watch_intents(
+        &self,
+        request: Request<proto::WatchIntentsRequest>,
+    ) -> Result<Response<Self::WatchIntentsStream>, Status> {
+        let status = request.into_inner().status;
+        let mut changes = self.state.intent_events.subscribe();
+        let (tx, rx) = mpsc::channel(WATCH_BUFFER);
+        let state = self.state.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let intent_id = match changes.recv().await {
+                    Ok(intent_id) => intent_id,
+                    Err(RecvError::Lagged(missed)) => {
+                        tracing::warn!(missed, "gRPC intent watcher lagged");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let Some(intent) = intent::find_intent(&state, &intent_id).await else {
+                    continue;
+                };
+                if status.as_ref().is_some_and(|s| *s != intent.status) {
+                    continue;
+                }
+                let event = proto::IntentEvent {
+                    intent: Some(intent.into()),
+                };
+                if tx.send(Ok(event)).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
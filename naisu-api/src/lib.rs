@@ -5,7 +5,13 @@
 pub mod common;
 pub mod config;
 pub mod feature;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod logging;
 pub mod middleware;
+pub mod names;
 pub mod route;
+pub mod runtime;
+pub mod self_check;
+pub mod snapshot;
 pub mod state;
@@ -1,26 +1,56 @@
 //! Naisu API Server
 //!
 //! Entry point for the HTTP API server.
+//!
+//! Snapshots: `--import <file>` restores a snapshot (see `StateSnapshot`)
+//! before serving, `--export <file>` writes one on Ctrl-C shutdown.
+//!
+//! `--check-config` validates the configuration, probes RPCs, packages and
+//! the agent wallet, prints a report and exits (non-zero on CRITICAL issues).
+//! Without it, CRITICAL issues are logged, and stop startup on mainnet.
+//!
+//! `SIGHUP` reloads the agent config and the runtime settings (see `runtime`).
 
-use std::{net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
 
 use axum::{
-    http::{header, HeaderValue, Method},
+    extract::DefaultBodyLimit,
+    http::{header, Method},
     middleware,
 };
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::{AllowOrigin, CorsLayer};
-use tracing::{info, Level};
+use tracing::{error, info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
 use naisu_api::{
-    common::server::create_dual_stack_listener, config::Config, middleware::http_trace_middleware,
-    route::app_routes, state::AppState,
+    common::server::create_dual_stack_listener,
+    config::{Config, ConfigError, Severity},
+    feature::{intent, strategy},
+    middleware::{http_trace_middleware, payload_too_large_middleware},
+    route::app_routes,
+    runtime::{self, RuntimeCompression},
+    self_check,
+    snapshot::StateSnapshot,
+    state::AppState,
 };
 
+/// Actor recorded in the audit log for CLI imports
+const CLI_ACTOR: &str = "cli";
+
+/// Path following `flag` on the command line
+fn path_arg(args: &[String], flag: &str) -> Option<PathBuf> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(PathBuf::from)
+}
+
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
     // Load environment
     dotenvy::dotenv().ok();
+    let args: Vec<String> = std::env::args().collect();
 
     // Initialize logging
     let subscriber = FmtSubscriber::builder()
@@ -44,37 +74,93 @@ async fn main() -> std::io::Result<()> {
     let app_state = AppState::new();
     info!("✅ Application state initialized");
 
-    // Setup CORS - handle wildcard separately
-    let cors = if config.server.cors_allowed_origins.len() == 1
-        && config.server.cors_allowed_origins[0] == "*"
+    if args.iter().any(|a| a == "--check-config") {
+        let report = self_check::run(&config, &app_state).await;
+        report.print();
+        if report.has_critical() {
+            return Err(std::io::Error::other("configuration has CRITICAL issues"));
+        }
+        return Ok(());
+    }
+
+    let issues = config.validate();
+    for issue in &issues {
+        match issue.severity {
+            Severity::Warning => warn!("⚠️  {}", issue),
+            Severity::Critical => error!("❌ {}", issue),
+        }
+    }
+    let critical: Vec<_> = issues
+        .into_iter()
+        .filter(|i| i.severity == Severity::Critical)
+        .collect();
+    if config.is_mainnet() && !critical.is_empty() {
+        error!("Refusing to start on mainnet with CRITICAL configuration issues");
+        return Err(std::io::Error::other(ConfigError(critical)));
+    }
+
+    // Restore a snapshot before anything reads the stores
+    if let Some(path) = path_arg(&args, "--import") {
+        let snapshot = StateSnapshot::read(&path).map_err(std::io::Error::other)?;
+        let summary = app_state
+            .import_snapshot(snapshot, false, CLI_ACTOR)
+            .await
+            .map_err(std::io::Error::other)?;
+        info!(path = %path.display(), ?summary, "✅ Snapshot imported");
+    }
+
+    // Reload CORS, cache and compression settings and the agent config on SIGHUP
+    tokio::spawn(runtime::watch_sighup(app_state.clone()));
+
+    // Keep the intent view in line with the chain
+    tokio::spawn(intent::reconcile::run(app_state.clone()));
+
+    // Pick up intents started by the EVM hook
+    tokio::spawn(intent::evm_hook::run(app_state.clone()));
+
+    // Mint attested Sui burns on their EVM chains
+    tokio::spawn(intent::cctp_relay::run(app_state.clone()));
+
+    // Flag intents stalled past their SLA
+    tokio::spawn(intent::watchdog::run(app_state.clone()));
+
+    // Build the APY history behind comparison charts
+    tokio::spawn(strategy::history::run(app_state.clone()));
+
+    // Typed solver API next to REST
+    #[cfg(feature = "grpc")]
     {
-        // Wildcard: allow any origin
-        CorsLayer::new()
-            .allow_origin(AllowOrigin::any())
-            .allow_methods([Method::GET, Method::POST])
-            .allow_headers([header::ACCEPT, header::CONTENT_TYPE])
-    } else {
-        // Specific origins: parse and use list
-        let allowed_origins: Vec<_> = config
-            .server
-            .cors_allowed_origins
-            .iter()
-            .map(|origin| {
-                origin
-                    .parse::<HeaderValue>()
-                    .expect("Invalid CORS origin in config")
-            })
-            .collect();
-
-        CorsLayer::new()
-            .allow_origin(allowed_origins)
-            .allow_methods([Method::GET, Method::POST])
-            .allow_headers([header::ACCEPT, header::CONTENT_TYPE])
-    };
+        let grpc_state = app_state.clone();
+        let grpc_port = config.server.grpc_port;
+        tokio::spawn(async move {
+            if let Err(e) = naisu_api::grpc::serve(grpc_state, grpc_port).await {
+                error!("❌ gRPC server stopped: {}", e);
+            }
+        });
+    }
+
+    // CORS and compression read the reloadable settings on every request
+    let settings = app_state.runtime.clone();
+    let cors = CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin, _| {
+            settings.get().allows_origin(origin)
+        }))
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::ACCEPT, header::CONTENT_TYPE, header::IF_NONE_MATCH])
+        .expose_headers([header::ETAG]);
+
+    // Large payloads (series, pool lists) are compressed for clients that accept it
+    let compression = CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .compress_when(RuntimeCompression(app_state.runtime.clone()));
 
     // Build application router
     let app = app_routes(app_state.clone())
+        .layer(DefaultBodyLimit::max(config.server.max_body_bytes))
+        .layer(middleware::from_fn(payload_too_large_middleware))
         .layer(middleware::from_fn(http_trace_middleware))
+        .layer(compression)
         .layer(cors)
         .into_make_service_with_connect_info::<SocketAddr>();
 
@@ -87,6 +173,20 @@ async fn main() -> std::io::Result<()> {
         config.server.port
     );
 
-    // Run server
-    axum::serve(listener, app).await
+    // Run server; with `--export`, stop on Ctrl-C and write a snapshot
+    let Some(path) = path_arg(&args, "--export") else {
+        return axum::serve(listener, app).await;
+    };
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await?;
+
+    let snapshot = app_state.export_snapshot().await;
+    match snapshot.write(&path) {
+        Ok(()) => info!(path = %path.display(), "✅ Snapshot exported"),
+        Err(e) => error!(path = %path.display(), "❌ Snapshot export failed: {}", e),
+    }
+    Ok(())
 }
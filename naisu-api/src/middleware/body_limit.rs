@@ -0,0 +1,75 @@
+use axum::{
+    body::to_bytes,
+    extract::Request,
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::common::response::ApiErrorResponse;
+
+/// Turns body-limit rejections into the standard JSON error
+///
+/// `DefaultBodyLimit` rejects oversized bodies with a plain-text 413; this
+/// rewrites them so clients get the same envelope as every other error.
+pub async fn payload_too_large_middleware(request: Request, next: Next) -> Response {
+    let length = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE {
+        return response;
+    }
+
+    let reason = to_bytes(response.into_body(), 1024)
+        .await
+        .map(|b| String::from_utf8_lossy(&b).into_owned())
+        .unwrap_or_default();
+    let message = match length {
+        Some(length) => format!("Request body of {} bytes is too large", length),
+        None => "Request body is too large".to_string(),
+    };
+
+    let mut error = ApiErrorResponse::new(message).with_code(StatusCode::PAYLOAD_TOO_LARGE);
+    if !reason.is_empty() {
+        error = error.with_error(reason);
+    }
+    error.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::DefaultBodyLimit, middleware::from_fn, routing::post, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_oversized_body_gets_error_envelope() {
+        let app = Router::new()
+            .route("/echo", post(|body: String| async move { body }))
+            .layer(DefaultBodyLimit::max(16))
+            .layer(from_fn(payload_too_large_middleware));
+        let request = |body: &'static str| {
+            Request::post("/echo")
+                .header(header::CONTENT_LENGTH, body.len())
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(request("small")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(request("a body well over sixteen bytes"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error["error_code"], "PAYLOAD_TOO_LARGE");
+        assert_eq!(error["message"], "Request body of 30 bytes is too large");
+    }
+}
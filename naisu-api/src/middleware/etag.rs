@@ -0,0 +1,147 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::time::Duration;
+
+use crate::runtime::RuntimeConfig;
+use crate::state::AppState;
+
+/// Cache policy for a group of read-only routes
+#[derive(Clone)]
+pub struct CachePolicy {
+    state: AppState,
+    /// Picks the route group's max-age from the reloadable settings
+    max_age: fn(&RuntimeConfig) -> Duration,
+}
+
+impl CachePolicy {
+    pub fn new(state: AppState, max_age: fn(&RuntimeConfig) -> Duration) -> Self {
+        Self { state, max_age }
+    }
+
+    /// Config generation and network, so a reload or switch changes every tag
+    fn data_version(&self) -> (u64, String) {
+        (self.state.agent_config.generation(), self.state.network())
+    }
+
+    fn cache_control(&self) -> HeaderValue {
+        let value = format!(
+            "public, max-age={}, must-revalidate",
+            (self.max_age)(&self.state.runtime.get()).as_secs()
+        );
+        HeaderValue::from_str(&value).expect("cache-control is ASCII")
+    }
+}
+
+/// ETag of a payload under a data version
+fn etag(body: &[u8], (generation, network): &(u64, String)) -> String {
+    let mut hasher = DefaultHasher::new();
+    hasher.write_u64(*generation);
+    hasher.write(network.as_bytes());
+    hasher.write(body);
+    format!("\"{:016x}\"", hasher.finish())
+}
+
+/// Whether an `If-None-Match` header matches `etag` (weak comparison)
+fn matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Conditional GET middleware
+///
+/// Tags successful GET responses with an ETag and `Cache-Control`, and
+/// answers a matching `If-None-Match` with an empty 304.
+pub async fn etag_middleware(
+    State(policy): State<CachePolicy>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::GET && request.method() != Method::HEAD {
+        return next.run(request).await;
+    }
+    let request_headers = request.headers().clone();
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let etag = etag(&bytes, &policy.data_version());
+    let etag_value = HeaderValue::from_str(&etag).expect("etag is ASCII");
+
+    if matches(&request_headers, &etag) {
+        let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+        let headers = not_modified.headers_mut();
+        headers.insert(header::ETAG, etag_value);
+        headers.insert(header::CACHE_CONTROL, policy.cache_control());
+        return not_modified;
+    }
+
+    parts.headers.insert(header::ETAG, etag_value);
+    parts
+        .headers
+        .insert(header::CACHE_CONTROL, policy.cache_control());
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware::from_fn_with_state, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let policy = CachePolicy::new(AppState::new(), |runtime| runtime.network_max_age);
+        Router::new()
+            .route("/network", get(|| async { "testnet" }))
+            .layer(from_fn_with_state(policy, etag_middleware))
+    }
+
+    fn get_request(if_none_match: Option<&str>) -> Request {
+        let mut request = Request::get("/network");
+        if let Some(tag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, tag);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_matching_etag_is_not_modified() {
+        let response = app().oneshot(get_request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            "public, max-age=10, must-revalidate"
+        );
+        let tag = response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let weak = format!("\"other\", W/{}", tag);
+        let response = app().oneshot(get_request(Some(&weak))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], tag.as_str());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+
+        let response = app().oneshot(get_request(Some("\"stale\""))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
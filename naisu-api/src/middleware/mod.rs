@@ -1,3 +1,7 @@
+pub mod body_limit;
+pub mod etag;
 pub mod http_trace;
 
+pub use body_limit::payload_too_large_middleware;
+pub use etag::{etag_middleware, CachePolicy};
 pub use http_trace::http_trace_middleware;
@@ -0,0 +1,151 @@
+//! SuiNS and ENS names for user-facing addresses
+//!
+//! Wherever the API takes a Sui address it also takes a SuiNS name
+//! (`alice.sui`, `@alice`), and an ENS name (`alice.eth`) wherever it takes
+//! an EVM address. `NameService` resolves names on the way in and looks up
+//! names for addresses on the way out. Reverse lookups are cached, since
+//! list responses show the same users over and over.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use naisu_agent::ens::{normalize_ens, EnsError, EnsResolver};
+use naisu_core::{validate_evm_address, validate_sui_address};
+use naisu_sui::{normalize_suins, SuiClient};
+use tokio::sync::RwLock;
+
+/// How long a reverse lookup is reused, "no name" and failures included,
+/// so responses don't wait on a name service that is down
+const REVERSE_TTL: Duration = Duration::from_secs(600);
+
+/// Which chain an address belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressKind {
+    Sui,
+    Evm,
+}
+
+impl AddressKind {
+    /// Guess from the address's shape: 20-byte hex is taken as EVM
+    pub fn of(address: &str) -> Option<Self> {
+        if validate_evm_address(address).is_ok() {
+            Some(Self::Evm)
+        } else if validate_sui_address(address).is_ok() {
+            Some(Self::Sui)
+        } else {
+            None
+        }
+    }
+}
+
+/// An address and its name, whichever of the two was given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved {
+    pub address: String,
+    pub name: Option<String>,
+}
+
+/// Name resolution errors
+#[derive(Debug, thiserror::Error)]
+pub enum NameError {
+    #[error("{0} is not registered or has no address")]
+    NotFound(String),
+
+    #[error("Name service unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl From<EnsError> for NameError {
+    fn from(e: EnsError) -> Self {
+        NameError::Unavailable(e.to_string())
+    }
+}
+
+/// Reverse lookups by address: the name, if any, and when it was looked up
+type ReverseCache = HashMap<(AddressKind, String), (Option<String>, Instant)>;
+
+/// Resolves SuiNS and ENS names, caching reverse lookups
+pub struct NameService {
+    ens: EnsResolver,
+    reverse: RwLock<ReverseCache>,
+}
+
+impl NameService {
+    pub fn new(ens: EnsResolver) -> Self {
+        Self {
+            ens,
+            reverse: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// `input` as a `kind` address, with its name
+    ///
+    /// Names are resolved; anything else is taken as an address (left for
+    /// the caller to validate) and named by reverse lookup.
+    pub async fn resolve(
+        &self,
+        sui: &SuiClient,
+        input: &str,
+        kind: AddressKind,
+    ) -> Result<Resolved, NameError> {
+        let name = match kind {
+            AddressKind::Sui => normalize_suins(input),
+            AddressKind::Evm => normalize_ens(input),
+        };
+        let Some(name) = name else {
+            return Ok(Resolved {
+                address: input.to_string(),
+                name: self.reverse(sui, input, kind).await,
+            });
+        };
+
+        let address = match kind {
+            AddressKind::Sui => sui
+                .resolve_suins(&name)
+                .await
+                .map_err(|e| NameError::Unavailable(e.to_string()))?,
+            AddressKind::Evm => self.ens.resolve(&name).await?,
+        };
+        let address = address.ok_or_else(|| NameError::NotFound(name.clone()))?;
+        Ok(Resolved {
+            address,
+            name: Some(name),
+        })
+    }
+
+    /// Name of a `kind` address; `None` if it has none or the lookup failed
+    pub async fn reverse(
+        &self,
+        sui: &SuiClient,
+        address: &str,
+        kind: AddressKind,
+    ) -> Option<String> {
+        let valid = match kind {
+            AddressKind::Sui => validate_sui_address(address).is_ok(),
+            AddressKind::Evm => validate_evm_address(address).is_ok(),
+        };
+        if !valid {
+            return None;
+        }
+        let key = (kind, address.trim().to_lowercase());
+        if let Some((name, at)) = self.reverse.read().await.get(&key) {
+            if at.elapsed() < REVERSE_TTL {
+                return name.clone();
+            }
+        }
+
+        let name = match kind {
+            AddressKind::Sui => sui.suins_name(&key.1).await.map_err(|e| e.to_string()),
+            AddressKind::Evm => self.ens.lookup(&key.1).await.map_err(|e| e.to_string()),
+        };
+        let name = name.unwrap_or_else(|e| {
+            tracing::debug!("Reverse lookup of {} failed: {}", address, e);
+            None
+        });
+        self.reverse
+            .write()
+            .await
+            .insert(key, (name.clone(), Instant::now()));
+        name
+    }
+}
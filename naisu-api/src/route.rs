@@ -1,11 +1,16 @@
-use axum::Router;
+use axum::{extract::DefaultBodyLimit, middleware::from_fn_with_state, Router};
 use std::sync::Arc;
 
 use crate::{
     feature::{
-        health::route::health_routes, network, solver::route::solver_routes,
-        strategy::route::strategy_routes,
+        admin::route::admin_routes, health::route::health_routes, intent::intent_routes, network,
+        orderbook::route::orderbook_routes, pool::route::pool_routes,
+        portfolio::route::portfolio_routes, quote::route::quote_routes,
+        referral::route::referral_routes, solver::route::solver_routes,
+        staking::route::staking_routes, strategy::route::strategy_routes,
+        wallet::route::wallet_routes,
     },
+    middleware::{etag_middleware, CachePolicy},
     state::AppState,
 };
 
@@ -14,11 +19,36 @@ pub fn app_routes(state: AppState) -> Router {
     // Convert to Arc for network routes
     let state_arc = Arc::new(state.clone());
 
+    let network_cache = CachePolicy::new(state.clone(), |runtime| runtime.network_max_age);
+    let strategies_cache = CachePolicy::new(state.clone(), |runtime| runtime.strategies_max_age);
+
     let api_routes = Router::new()
         .nest("/health", health_routes())
-        .nest("/network", network::routes().with_state(state_arc))
-        .nest("/strategies", strategy_routes())
-        .nest("/solvers", solver_routes());
+        .nest(
+            "/network",
+            network::routes()
+                .with_state(state_arc)
+                .layer(from_fn_with_state(network_cache, etag_middleware)),
+        )
+        .nest(
+            "/strategies",
+            strategy_routes().layer(from_fn_with_state(strategies_cache, etag_middleware)),
+        )
+        .nest("/pools", pool_routes())
+        .nest("/orderbook", orderbook_routes())
+        .nest("/portfolio", portfolio_routes())
+        .nest("/quote", quote_routes())
+        .nest("/solvers", solver_routes())
+        .nest("/staking", staking_routes())
+        .nest("/referrals", referral_routes())
+        .nest("/wallet", wallet_routes())
+        .nest(
+            "/admin",
+            admin_routes().layer(DefaultBodyLimit::max(
+                state.config.server.max_admin_body_bytes,
+            )),
+        )
+        .merge(intent_routes());
 
     Router::new()
         .nest("/api/v1", api_routes)
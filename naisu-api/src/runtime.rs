@@ -0,0 +1,261 @@
+//! Hot-reloadable API settings
+//!
+//! The part of the configuration that is safe to change under load: CORS
+//! origins, client cache lifetimes, server-side cache TTLs and response
+//! compression. `SIGHUP` or `POST /admin/config/reload` re-reads `.env` and
+//! the environment and swaps the new values in as one snapshot. Ports, keys
+//! and body limits still need a restart.
+
+use std::env;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use axum::body::HttpBody;
+use axum::http::{HeaderValue, Response};
+use tower_http::compression::predicate::{DefaultPredicate, Predicate, SizeAbove};
+
+use crate::config::{ConfigError, ConfigIssue};
+use crate::feature::admin::handler::reload_configs;
+use crate::state::AppState;
+
+/// Audit actor for signal-triggered reloads
+const SIGHUP_ACTOR: &str = "sighup";
+
+/// Settings applied to running layers without a restart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// `*` allows any origin
+    pub cors_allowed_origins: Vec<String>,
+    /// Client cache lifetime for `/strategies`
+    pub strategies_max_age: Duration,
+    /// Client cache lifetime for `/network`
+    pub network_max_age: Duration,
+    /// How long pool listings are served before re-reading the chain
+    pub pools_ttl: Duration,
+    /// How long protocol probe results are served before re-probing
+    pub availability_ttl: Duration,
+    pub compression: bool,
+    /// Responses smaller than this go out uncompressed
+    pub compress_above_bytes: u16,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            cors_allowed_origins: vec!["*".to_string()],
+            strategies_max_age: Duration::from_secs(60),
+            network_max_age: Duration::from_secs(10),
+            pools_ttl: Duration::from_secs(60),
+            availability_ttl: Duration::from_secs(300),
+            compression: true,
+            compress_above_bytes: 1024,
+        }
+    }
+}
+
+/// `name` parsed as `T`, recording an issue and keeping `default` if invalid
+fn parse_var<T: FromStr>(name: &str, default: T, issues: &mut Vec<ConfigIssue>) -> T {
+    match env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().unwrap_or_else(|_| {
+            issues.push(ConfigIssue::critical(
+                name,
+                format!("`{}` is not a valid value", value),
+            ));
+            default
+        }),
+        _ => default,
+    }
+}
+
+impl RuntimeConfig {
+    /// Read from the environment, reporting every invalid value at once
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let defaults = Self::default();
+        let mut issues = Vec::new();
+        let mut secs = |name: &str, default: Duration| {
+            Duration::from_secs(parse_var(name, default.as_secs(), &mut issues))
+        };
+
+        let strategies_max_age = secs("STRATEGIES_MAX_AGE_SECS", defaults.strategies_max_age);
+        let network_max_age = secs("NETWORK_MAX_AGE_SECS", defaults.network_max_age);
+        let pools_ttl = secs("POOLS_TTL_SECS", defaults.pools_ttl);
+        let availability_ttl = secs("AVAILABILITY_TTL_SECS", defaults.availability_ttl);
+
+        let cors_allowed_origins: Vec<String> = env::var("CORS_ALLOWED_ORIGINS")
+            .unwrap_or_else(|_| "*".to_string())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        for origin in &cors_allowed_origins {
+            if origin != "*" && HeaderValue::from_str(origin).is_err() {
+                issues.push(ConfigIssue::critical(
+                    "CORS_ALLOWED_ORIGINS",
+                    format!("`{}` is not a valid origin", origin),
+                ));
+            }
+        }
+
+        let config = Self {
+            cors_allowed_origins,
+            strategies_max_age,
+            network_max_age,
+            pools_ttl,
+            availability_ttl,
+            compression: parse_var("COMPRESSION", defaults.compression, &mut issues),
+            compress_above_bytes: parse_var(
+                "COMPRESS_ABOVE_BYTES",
+                defaults.compress_above_bytes,
+                &mut issues,
+            ),
+        };
+        if issues.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError(issues))
+        }
+    }
+
+    /// Whether `origin` may call the API from a browser
+    pub fn allows_origin(&self, origin: &HeaderValue) -> bool {
+        self.cors_allowed_origins
+            .iter()
+            .any(|allowed| allowed == "*" || origin.as_bytes() == allowed.as_bytes())
+    }
+}
+
+// ─── Shared handle ───────────────────────────────────────────────────────────
+
+/// Current `RuntimeConfig`, swapped whole on reload
+#[derive(Debug, Clone)]
+pub struct SharedRuntimeConfig {
+    inner: Arc<RwLock<Arc<RuntimeConfig>>>,
+    generation: Arc<AtomicU64>,
+}
+
+impl SharedRuntimeConfig {
+    pub fn new(config: RuntimeConfig) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Arc::new(config))),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Snapshot in effect; read once per request so it stays consistent
+    pub fn get(&self) -> Arc<RuntimeConfig> {
+        self.inner
+            .read()
+            .expect("runtime config lock poisoned")
+            .clone()
+    }
+
+    /// Bumped on every change
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Swap in `config`; returns whether anything changed
+    pub fn replace(&self, config: RuntimeConfig) -> bool {
+        let mut current = self.inner.write().expect("runtime config lock poisoned");
+        if **current == config {
+            return false;
+        }
+        *current = Arc::new(config);
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        true
+    }
+}
+
+/// Compression predicate that follows the runtime toggle and threshold
+#[derive(Debug, Clone)]
+pub struct RuntimeCompression(pub SharedRuntimeConfig);
+
+impl Predicate for RuntimeCompression {
+    fn should_compress<B>(&self, response: &Response<B>) -> bool
+    where
+        B: HttpBody,
+    {
+        let config = self.0.get();
+        config.compression
+            && DefaultPredicate::new()
+                .and(SizeAbove::new(config.compress_above_bytes))
+                .should_compress(response)
+    }
+}
+
+// ─── Signal ──────────────────────────────────────────────────────────────────
+
+/// Reload the configuration on every `SIGHUP`
+#[cfg(unix)]
+pub async fn watch_sighup(state: AppState) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangups = match signal(SignalKind::hangup()) {
+        Ok(hangups) => hangups,
+        Err(e) => {
+            tracing::warn!(
+                "SIGHUP handler unavailable, reload via admin API only: {}",
+                e
+            );
+            return;
+        }
+    };
+    while hangups.recv().await.is_some() {
+        match reload_configs(&state, SIGHUP_ACTOR) {
+            Ok(reloaded) => tracing::info!(
+                generation = reloaded.generation,
+                runtime_generation = reloaded.runtime_generation,
+                "🔄 Config reloaded on SIGHUP"
+            ),
+            Err(e) => tracing::error!("SIGHUP reload rejected, keeping current config: {}", e),
+        }
+    }
+}
+
+/// `SIGHUP` doesn't exist here; reload through the admin API
+#[cfg(not(unix))]
+pub async fn watch_sighup(_state: AppState) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_swaps_snapshot() {
+        let shared = SharedRuntimeConfig::new(RuntimeConfig::default());
+        let before = shared.get();
+
+        assert!(!shared.replace(RuntimeConfig::default()));
+        assert_eq!(shared.generation(), 0);
+
+        let reloaded = RuntimeConfig {
+            cors_allowed_origins: vec!["https://app.naisu.io".to_string()],
+            ..RuntimeConfig::default()
+        };
+        assert!(shared.replace(reloaded));
+        assert_eq!(shared.generation(), 1);
+        // Snapshots taken before the reload keep the old values
+        assert!(before.allows_origin(&HeaderValue::from_static("https://evil.example")));
+        let current = shared.get();
+        assert!(current.allows_origin(&HeaderValue::from_static("https://app.naisu.io")));
+        assert!(!current.allows_origin(&HeaderValue::from_static("https://evil.example")));
+    }
+
+    #[test]
+    fn test_compression_follows_reload() {
+        let shared = SharedRuntimeConfig::new(RuntimeConfig::default());
+        let predicate = RuntimeCompression(shared.clone());
+        let response = |size: usize| Response::new("x".repeat(size));
+
+        assert!(predicate.should_compress(&response(4096)));
+        assert!(!predicate.should_compress(&response(512)));
+
+        shared.replace(RuntimeConfig {
+            compression: false,
+            ..RuntimeConfig::default()
+        });
+        assert!(!predicate.should_compress(&response(4096)));
+    }
+}
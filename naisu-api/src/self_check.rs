@@ -0,0 +1,196 @@
+//! Startup self-check (`--check-config`)
+//!
+//! Runs the offline `Config::validate` checks, then the ones that need the
+//! network: RPC reachability, that the configured packages and protocol
+//! objects exist, and that the agent wallet can pay gas.
+
+use naisu_agent::config::Network;
+use naisu_agent::evm_hook::HookListener;
+use naisu_core::format_sui;
+use naisu_sui::SuiClient;
+
+use crate::config::{Config, ConfigIssue, Severity};
+use crate::state::AppState;
+
+/// Agent wallet balance below which gas is flagged (MIST)
+const MIN_GAS_BALANCE: u64 = 100_000_000;
+
+const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// Outcome of a self-check
+#[derive(Debug, Default)]
+pub struct SelfCheckReport {
+    /// Checks that passed
+    pub passed: Vec<String>,
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl SelfCheckReport {
+    pub fn has_critical(&self) -> bool {
+        self.issues.iter().any(|i| i.severity == Severity::Critical)
+    }
+
+    pub fn print(&self) {
+        println!("\n🩺 Configuration self-check");
+        for check in &self.passed {
+            println!("  ✅ {}", check);
+        }
+        for issue in &self.issues {
+            let icon = match issue.severity {
+                Severity::Warning => "⚠️ ",
+                Severity::Critical => "❌",
+            };
+            println!("  {} {}", icon, issue);
+        }
+        let critical = self
+            .issues
+            .iter()
+            .filter(|i| i.severity == Severity::Critical)
+            .count();
+        println!(
+            "\n{} passed, {} warning(s), {} critical",
+            self.passed.len(),
+            self.issues.len() - critical,
+            critical
+        );
+    }
+}
+
+async fn check_object(
+    report: &mut SelfCheckReport,
+    client: &SuiClient,
+    field: &str,
+    label: &str,
+    object_id: &str,
+    severity: Severity,
+) {
+    match client.get_object(object_id).await {
+        Ok(_) => report
+            .passed
+            .push(format!("{} {} exists", label, object_id)),
+        Err(e) => report.issues.push(ConfigIssue {
+            severity,
+            field: field.to_string(),
+            message: format!("{} {} not readable: {}", label, object_id, e),
+        }),
+    }
+}
+
+/// Run every check against the configured network
+pub async fn run(config: &Config, state: &AppState) -> SelfCheckReport {
+    let mut report = SelfCheckReport {
+        passed: Vec::new(),
+        issues: config.validate(),
+    };
+    let network = config
+        .sui
+        .network
+        .parse::<Network>()
+        .unwrap_or(Network::Testnet);
+    let agent_config = state.agent_config.get();
+    let client = state.sui_client_for(network);
+
+    // ─── Sui ─────────────────────────────────────────────────────────────────
+    match client.get_reference_gas_price().await {
+        Ok(price) => report.passed.push(format!(
+            "Sui {} RPC reachable (gas price {} MIST)",
+            network.name(),
+            price
+        )),
+        Err(e) => {
+            report.issues.push(ConfigIssue::critical(
+                "SUI_RPC_URL",
+                format!("{} RPC unreachable: {}", network.name(), e),
+            ));
+            report.issues.sort_by_key(|i| std::cmp::Reverse(i.severity));
+            return report;
+        }
+    }
+
+    if let Some(package) = &config.sui.package_id {
+        check_object(
+            &mut report,
+            &client,
+            "SUI_PACKAGE_ID",
+            "Package",
+            package,
+            Severity::Critical,
+        )
+        .await;
+    }
+    if let Ok(package) = agent_config.intent_package(network) {
+        check_object(
+            &mut report,
+            &client,
+            "AGENT_CONFIG_PATH",
+            "Intent package",
+            &package,
+            Severity::Critical,
+        )
+        .await;
+    }
+    for protocol in network.supported_protocols() {
+        let Some(protocol_config) = agent_config.protocol_config(protocol, network) else {
+            continue;
+        };
+        let field = format!("{} config", protocol.name());
+        check_object(
+            &mut report,
+            &client,
+            &field,
+            &format!("{} package", protocol.name()),
+            &protocol_config.package_id,
+            Severity::Warning,
+        )
+        .await;
+        for (name, object_id) in &protocol_config.config_objects {
+            check_object(
+                &mut report,
+                &client,
+                &field,
+                &format!("{} {}", protocol.name(), name),
+                object_id,
+                Severity::Warning,
+            )
+            .await;
+        }
+    }
+
+    if let Some(wallet) = &agent_config.wallet.address {
+        match client.get_coin_summary(wallet, SUI_COIN_TYPE).await {
+            Ok(summary) if summary.balance >= MIN_GAS_BALANCE => report.passed.push(format!(
+                "Wallet {} holds {} SUI",
+                wallet,
+                format_sui(summary.balance)
+            )),
+            Ok(summary) => report.issues.push(ConfigIssue::warning(
+                "wallet.address",
+                format!(
+                    "{} holds {} SUI, below the {} SUI needed for gas",
+                    wallet,
+                    format_sui(summary.balance),
+                    format_sui(MIN_GAS_BALANCE)
+                ),
+            )),
+            Err(e) => report.issues.push(ConfigIssue::warning(
+                "wallet.address",
+                format!("balance of {} not readable: {}", wallet, e),
+            )),
+        }
+    }
+
+    // ─── EVM ─────────────────────────────────────────────────────────────────
+    let listener = HookListener::new(&config.evm.rpc_url, &config.evm.hook_address);
+    match listener.block_number().await {
+        Ok(head) => report
+            .passed
+            .push(format!("EVM RPC reachable (block {})", head)),
+        Err(e) => report.issues.push(ConfigIssue::critical(
+            "EVM_RPC_URL",
+            format!("unreachable: {}", e),
+        )),
+    }
+
+    report.issues.sort_by_key(|i| std::cmp::Reverse(i.severity));
+    report
+}
@@ -0,0 +1,99 @@
+//! State snapshots
+//!
+//! Versioned JSON dump of the in-memory stores: intents, bids, fulfillment
+//! reports (the positions), receipts and the reconciler's chain view. Used
+//! for backups, cloning an environment and moving state into a database.
+//!
+//! There are no event cursors to carry over: the reconciler re-scans chain
+//! events on its next run, and the imported chain view only seeds it.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use naisu_agent::receipt::SignedReceipt;
+use naisu_agent::reconcile::ChainIntent;
+use naisu_core::{FulfillmentReport, Intent};
+use serde::{Deserialize, Serialize};
+
+use crate::state::SolverBidEntry;
+
+/// Snapshot format written by this build
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Everything needed to rebuild an instance's state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    pub version: u32,
+    /// Unix millis
+    pub exported_at: u64,
+    pub network: String,
+    pub intents: Vec<Intent>,
+    /// Active bids per intent
+    pub bids: HashMap<String, Vec<SolverBidEntry>>,
+    pub bid_history: HashMap<String, Vec<SolverBidEntry>>,
+    /// Fulfillment reports per intent
+    pub fulfillments: HashMap<String, FulfillmentReport>,
+    #[serde(default)]
+    pub receipts: HashMap<String, SignedReceipt>,
+    #[serde(default)]
+    pub chain_intents: HashMap<String, ChainIntent>,
+}
+
+/// What an import restored
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ImportSummary {
+    pub intents: usize,
+    pub bids: usize,
+    pub fulfillments: usize,
+    pub receipts: usize,
+    pub chain_intents: usize,
+}
+
+impl StateSnapshot {
+    /// Read and version-check a snapshot file
+    pub fn read(path: &Path) -> Result<Self, SnapshotError> {
+        let json = std::fs::read_to_string(path)?;
+        let snapshot: Self = serde_json::from_str(&json)?;
+        snapshot.check_version()?;
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot as pretty-printed JSON
+    pub fn write(&self, path: &Path) -> Result<(), SnapshotError> {
+        std::fs::write(path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn check_version(&self) -> Result<(), SnapshotError> {
+        if self.version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(self.version));
+        }
+        Ok(())
+    }
+
+    pub fn summary(&self) -> ImportSummary {
+        ImportSummary {
+            intents: self.intents.len(),
+            bids: self.bids.values().map(Vec::len).sum(),
+            fulfillments: self.fulfillments.len(),
+            receipts: self.receipts.len(),
+            chain_intents: self.chain_intents.len(),
+        }
+    }
+}
+
+/// Snapshot errors
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("Snapshot version {0} is newer than supported ({SNAPSHOT_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("State is not empty; import with replace to overwrite it")]
+    NotEmpty,
+
+    #[error("Snapshot I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid snapshot: {0}")]
+    Json(#[from] serde_json::Error),
+}
@@ -1,21 +1,259 @@
 use std::sync::Arc;
 
 use axum::extract::FromRef;
-use naisu_core::{Intent, IntentStatus};
+use naisu_agent::accounting::Ledger;
+use naisu_agent::apy_history::ApyHistory;
+use naisu_agent::audit::{AuditAction, AuditLog};
+use naisu_agent::availability::AvailabilityProber;
+use naisu_agent::bridge_latency::{self, Eta, LatencyTracker};
+use naisu_agent::commitment::CommitmentBook;
+use naisu_agent::config::{AgentConfig, Network, SharedConfig};
+use naisu_agent::ens::EnsResolver;
+use naisu_agent::executor::signer::LocalSigner;
+use naisu_agent::feature_flags::FeatureOverrides;
+use naisu_agent::fees::FeeLedger;
+use naisu_agent::heartbeat::{self, Heartbeat, SolverState};
+use naisu_agent::notify::NotificationHub;
+use naisu_agent::pools::PoolRegistry;
+use naisu_agent::price::PriceOracle;
+use naisu_agent::receipt::SignedReceipt;
+use naisu_agent::reconcile::{ChainIntent, ChainState, Drift, Reconciler};
+use naisu_agent::referral::ReferralBook;
+use naisu_agent::reputation::{self, Reputation, ReputationWeights, SolverHistory};
+use naisu_agent::route_cost::attestation_wait;
+use naisu_agent::selection::Rankable;
+use naisu_agent::solver_overrides::SolverOverrides;
+use naisu_agent::token_check::TokenChecker;
+use naisu_agent::verify::{SettlementVerifier, Verification};
+use naisu_core::{Direction, FulfillmentReport, Intent, IntentId, IntentSource, IntentStatus};
+use naisu_sui::{DeepBookAdapter, SignatureVerifier, SuiClient};
 use std::collections::HashMap;
-use tokio::sync::RwLock;
+use std::path::PathBuf;
+use tokio::sync::{broadcast, RwLock};
 
 use crate::config::Config;
+use crate::feature::admin::handler::{now_millis, KillSwitchStatus};
+use crate::names::NameService;
+use crate::runtime::{RuntimeConfig, SharedRuntimeConfig};
+use crate::snapshot::{ImportSummary, SnapshotError, StateSnapshot, SNAPSHOT_VERSION};
+
+/// A fresh intent ID created at `timestamp_ms`
+pub fn new_intent_id(source: IntentSource, timestamp_ms: u64) -> String {
+    // Bytes 6 and 8 of a v4 UUID carry its version and variant bits
+    let uuid = uuid::Uuid::new_v4();
+    let bytes = uuid.as_bytes();
+    let mut entropy = [0u8; 10];
+    entropy[..6].copy_from_slice(&bytes[..6]);
+    entropy[6..].copy_from_slice(&bytes[12..]);
+    IntentId::new(source, timestamp_ms, entropy).to_string()
+}
+
+/// Sui client for a network, at the agent config's RPC URL
+fn sui_client(network: Network, agent_config: &SharedConfig) -> SuiClient {
+    let mut sui_config = match network {
+        Network::Mainnet => naisu_sui::SuiConfig::mainnet(),
+        _ => naisu_sui::SuiConfig::testnet(),
+    };
+    sui_config.rpc_url = agent_config.get().rpc_url(network);
+    SuiClient::new(sui_config)
+}
 
 /// A single solver bid persisted in memory
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct SolverBidEntry {
     pub intent_id: String,
     pub solver_name: String,
     pub protocol: String,
     pub offered_apy: u64, // basis points
     pub profit_bps: u64,
+    /// Intent tip the bid counts on (bps of the amount)
+    #[serde(default)]
+    pub tip_bps: u64,
     pub timestamp: u64, // unix millis
+    /// Solver's confidence (0.0 - 1.0); older solvers don't send it
+    #[serde(default)]
+    pub confidence: Option<f64>,
+    /// Set by the API: 1 for a solver's first bid, +1 per replacement
+    #[serde(default)]
+    pub revision: u32,
+}
+
+/// The winner a solver daemon picked for an intent
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SelectedWinner {
+    pub solver_name: String,
+    pub offered_apy: u64,
+    /// Next-ranked solvers, tried in order if the winner fails
+    #[serde(default)]
+    pub fallbacks: Vec<String>,
+    /// Set by the API (unix millis)
+    #[serde(default)]
+    pub selected_at: u64,
+}
+
+/// What happened to a posted bid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidOutcome {
+    /// First bid from this solver
+    Created,
+    /// Replaced a lower bid
+    Replaced { previous_apy: u64 },
+    /// Same APY as the active bid, which is kept
+    Unchanged,
+}
+
+/// A re-bid below the solver's active bid
+#[derive(Debug, Clone, Copy)]
+pub struct WorseBid {
+    pub active_apy: u64,
+}
+
+/// What a searched digest or object ID is to its intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum RefKind {
+    /// The on-chain intent object
+    IntentObject,
+    /// `intentId` of the EVM hook event that created the intent
+    EvmIntent,
+    CreationTx,
+    SwapTx,
+    BridgeTx,
+    DestTx,
+    RefundTx,
+    FulfillmentTx,
+    /// Object a fulfillment created for the user
+    CreatedObject,
+}
+
+impl RefKind {
+    pub fn is_object(&self) -> bool {
+        matches!(
+            self,
+            RefKind::IntentObject | RefKind::EvmIntent | RefKind::CreatedObject
+        )
+    }
+
+    /// An ID of the intent itself on another chain
+    pub fn is_link(&self) -> bool {
+        matches!(self, RefKind::IntentObject | RefKind::EvmIntent)
+    }
+}
+
+/// Digests and object IDs → intent, kept in step with the intent stores
+#[derive(Debug, Default)]
+pub struct IntentIndex {
+    refs: HashMap<String, (String, RefKind)>,
+}
+
+impl IntentIndex {
+    /// Hex (`0x…`) references are case-insensitive, base58 digests are not
+    fn key(reference: &str) -> String {
+        let reference = reference.trim();
+        if reference.starts_with("0x") || reference.starts_with("0X") {
+            reference.to_lowercase()
+        } else {
+            reference.to_string()
+        }
+    }
+
+    pub fn insert(&mut self, reference: &str, intent_id: &str, kind: RefKind) {
+        if !reference.trim().is_empty() {
+            self.refs
+                .insert(Self::key(reference), (intent_id.to_string(), kind));
+        }
+    }
+
+    /// Intent ID and role of `reference`
+    pub fn get(&self, reference: &str) -> Option<(String, RefKind)> {
+        self.refs.get(&Self::key(reference)).cloned()
+    }
+
+    fn index_intent(&mut self, intent: &Intent) {
+        if let Some(id) = &intent.evm_intent_id {
+            self.insert(id, &intent.id, RefKind::EvmIntent);
+        }
+        if let Some(id) = &intent.sui_object_id {
+            self.insert(id, &intent.id, RefKind::IntentObject);
+        }
+        let hashes = [
+            (&intent.swap_tx_hash, RefKind::SwapTx),
+            (&intent.bridge_tx_hash, RefKind::BridgeTx),
+            (&intent.dest_tx_hash, RefKind::DestTx),
+            (&intent.refund_tx_hash, RefKind::RefundTx),
+        ];
+        for (hash, kind) in hashes {
+            if let Some(hash) = hash {
+                self.insert(hash, &intent.id, kind);
+            }
+        }
+    }
+
+    fn index_fulfillment(&mut self, intent_id: &str, report: &FulfillmentReport) {
+        self.insert(&report.digest, intent_id, RefKind::FulfillmentTx);
+        for object in &report.created_objects {
+            self.insert(&object.object_id, intent_id, RefKind::CreatedObject);
+        }
+    }
+
+    /// Refs of a Sui intent object go to the cross-chain intent it is
+    /// linked to, if any
+    fn index_chain_intent(&mut self, intent: &ChainIntent) {
+        let owner = match self.get(&intent.intent_id) {
+            Some((owner, RefKind::IntentObject)) => owner,
+            _ => intent.intent_id.clone(),
+        };
+        self.insert(&intent.intent_id, &owner, RefKind::IntentObject);
+        if let Some(digest) = &intent.digest {
+            self.insert(digest, &owner, RefKind::CreationTx);
+        }
+        if let ChainState::Fulfilled {
+            digest: Some(digest),
+            ..
+        } = &intent.state
+        {
+            self.insert(digest, &owner, RefKind::FulfillmentTx);
+        }
+    }
+}
+
+/// A solver's last heartbeat, as served to clients
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SolverLiveness {
+    pub solver: String,
+    pub network: String,
+    pub state: SolverState,
+    pub in_flight: usize,
+    pub wallet: Option<String>,
+    /// SUI balance of `wallet` (MIST)
+    pub wallet_balance: Option<u64>,
+    pub last_fulfillment_ms: Option<u64>,
+    pub last_heartbeat_ms: u64,
+    pub interval_secs: u64,
+    /// Missed too many heartbeats; `state` is as last reported
+    pub stale: bool,
+}
+
+/// Intent changes buffered per subscriber before it lags
+const INTENT_EVENT_BUFFER: usize = 256;
+
+/// Confidence assumed for bids that don't report one
+pub const DEFAULT_BID_CONFIDENCE: f64 = 0.95;
+
+impl Rankable for SolverBidEntry {
+    fn solver(&self) -> &str {
+        &self.solver_name
+    }
+
+    fn apy(&self) -> u64 {
+        self.offered_apy
+    }
+
+    fn confidence(&self) -> f64 {
+        self.confidence.unwrap_or(DEFAULT_BID_CONFIDENCE)
+    }
 }
 
 /// Application state shared across all handlers
@@ -23,19 +261,175 @@ pub struct SolverBidEntry {
 pub struct AppState {
     pub config: Arc<Config>,
     pub intents: Arc<RwLock<HashMap<String, Intent>>>,
+    /// Active bids per intent, one per solver
     pub bids: Arc<RwLock<HashMap<String, Vec<SolverBidEntry>>>>,
+    /// Every accepted bid revision per intent
+    #[from_ref(skip)]
+    pub bid_history: Arc<RwLock<HashMap<String, Vec<SolverBidEntry>>>>,
+    /// Winner picked by a solver daemon, per intent
+    pub selected_winners: Arc<RwLock<HashMap<String, SelectedWinner>>>,
+    /// Solver fulfillment reports per intent
+    pub fulfillments: Arc<RwLock<HashMap<String, FulfillmentReport>>>,
+    /// On-chain settlement checks of those reports
+    pub verifications: Arc<RwLock<HashMap<String, Verification>>>,
+    /// Receipts of verified fulfillments
+    pub receipts: Arc<RwLock<HashMap<String, SignedReceipt>>>,
+    /// Signs receipts (`RECEIPT_SIGNING_KEY`)
+    pub receipt_signer: Option<Arc<LocalSigner>>,
     pub network: Arc<RwLock<String>>,
+    pub kill_switch: Arc<RwLock<KillSwitchStatus>>,
+    pub ledger: Arc<RwLock<Ledger>>,
+    /// Protocol fees accrued per solver
+    pub fees: Arc<RwLock<FeeLedger>>,
+    /// Referred volume and fee shares per referrer
+    pub referrals: Arc<RwLock<ReferralBook>>,
+    /// Commit-reveal commitments
+    pub commitments: Arc<RwLock<CommitmentBook>>,
+    /// Protocol configs, hot-reloadable through the admin API
+    pub agent_config: SharedConfig,
+    /// CORS, cache and compression settings, reloaded with the agent config
+    pub runtime: SharedRuntimeConfig,
+    /// On-chain protocol availability per network
+    pub availability: Arc<HashMap<Network, Arc<AvailabilityProber>>>,
+    /// Cached Cetus pools per network
+    pub pools: Arc<HashMap<Network, Arc<PoolRegistry>>>,
+    /// Sampled strategy APYs, for comparison charts
+    pub apy_history: Arc<RwLock<ApyHistory>>,
+    /// Observed CCTP transfer times per chain pair
+    pub bridge_latency: Arc<RwLock<LatencyTracker>>,
+    /// USD prices, from the mainnet DeepBook SUI/USDC book
+    pub prices: Arc<PriceOracle>,
+    /// Settlement verifier per network
+    pub verifiers: Arc<HashMap<Network, Arc<SettlementVerifier>>>,
+    /// User signature verifier per network
+    pub signature_verifiers: Arc<HashMap<Network, Arc<SignatureVerifier>>>,
+    /// Chain reconciler per network with an intent package
+    pub reconcilers: Arc<HashMap<Network, Arc<Reconciler>>>,
+    /// Intents as last seen on chain by the reconciler
+    pub chain_intents: Arc<RwLock<HashMap<String, ChainIntent>>>,
+    /// Drift found by the last reconciliation
+    pub drifts: Arc<RwLock<Vec<Drift>>>,
+    /// Transaction digests and object IDs of every stored intent
+    pub intent_index: Arc<RwLock<IntentIndex>>,
+    /// Every state change, with actor and before/after snapshots
+    pub audit: Arc<std::sync::Mutex<AuditLog>>,
+    /// IDs of marketplace intents whose view changed, for watchers
+    pub intent_events: broadcast::Sender<String>,
+    /// Last heartbeat per `network/solver`
+    pub solver_liveness: Arc<RwLock<HashMap<String, SolverLiveness>>>,
+    /// Solver tuning set through the admin API, polled by daemons
+    pub solver_overrides: Arc<RwLock<SolverOverrides>>,
+    /// Feature flags set through the admin API, polled by daemons
+    pub feature_overrides: Arc<RwLock<FeatureOverrides>>,
+    /// SuiNS / ENS resolution for addresses in requests and responses
+    pub names: Arc<NameService>,
+    /// Decimals and fee-on-transfer checks of EVM input tokens
+    pub tokens: Arc<TokenChecker>,
 }
 
 impl AppState {
     pub fn new() -> Self {
         let config = Arc::new(Config::from_env());
 
+        let agent_config_path = config.sui.agent_config_path.as_ref().map(PathBuf::from);
+        let agent_config = AgentConfig::load(agent_config_path.as_deref()).unwrap_or_else(|e| {
+            tracing::warn!("Invalid agent config, using defaults: {}", e);
+            AgentConfig::defaults()
+        });
+
+        let agent_config = SharedConfig::new(agent_config, agent_config_path);
+        let runtime = RuntimeConfig::from_env().unwrap_or_else(|e| {
+            tracing::warn!("Invalid runtime settings, using defaults: {}", e);
+            RuntimeConfig::default()
+        });
+        let client = |network: Network| sui_client(network, &agent_config);
+        let networks = [Network::Testnet, Network::Mainnet];
+        let availability = networks
+            .iter()
+            .map(|&network| {
+                let prober =
+                    AvailabilityProber::new(network, client(network), agent_config.clone());
+                (network, Arc::new(prober))
+            })
+            .collect();
+        let pools = networks
+            .iter()
+            .map(|&network| {
+                let registry = PoolRegistry::new(network, client(network), agent_config.clone());
+                (network, Arc::new(registry))
+            })
+            .collect();
+        let verifiers = networks
+            .iter()
+            .map(|&network| {
+                let verifier =
+                    SettlementVerifier::new(client(network), NotificationHub::from_env());
+                (network, Arc::new(verifier))
+            })
+            .collect();
+        let signature_verifiers = networks
+            .iter()
+            .map(|&network| {
+                let verifier = SignatureVerifier::new(client(network));
+                (network, Arc::new(verifier))
+            })
+            .collect();
+        let receipt_signer =
+            config.admin.receipt_signing_key.as_ref().and_then(
+                |key| match LocalSigner::from_encoded("receipts", key.expose()) {
+                    Ok(signer) => Some(Arc::new(signer)),
+                    Err(e) => {
+                        tracing::warn!("Invalid RECEIPT_SIGNING_KEY, receipts unsigned: {}", e);
+                        None
+                    }
+                },
+            );
+        let reconcilers = networks
+            .iter()
+            .filter_map(|&network| {
+                let package = agent_config.get().intent_package(network).ok()?;
+                let reconciler =
+                    Reconciler::new(client(network), package, NotificationHub::from_env());
+                Some((network, Arc::new(reconciler)))
+            })
+            .collect();
+
         Self {
-            config,
             intents: Arc::new(RwLock::new(HashMap::new())),
             bids: Arc::new(RwLock::new(HashMap::new())),
-            network: Arc::new(RwLock::new("testnet".to_string())),
+            bid_history: Arc::new(RwLock::new(HashMap::new())),
+            selected_winners: Arc::new(RwLock::new(HashMap::new())),
+            fulfillments: Arc::new(RwLock::new(HashMap::new())),
+            verifications: Arc::new(RwLock::new(HashMap::new())),
+            receipts: Arc::new(RwLock::new(HashMap::new())),
+            receipt_signer,
+            network: Arc::new(RwLock::new(config.sui.network.clone())),
+            kill_switch: Arc::new(RwLock::new(KillSwitchStatus::default())),
+            ledger: Arc::new(RwLock::new(Ledger::new())),
+            fees: Arc::new(RwLock::new(FeeLedger::new())),
+            referrals: Arc::new(RwLock::new(ReferralBook::new())),
+            commitments: Arc::new(RwLock::new(CommitmentBook::new())),
+            agent_config,
+            runtime: SharedRuntimeConfig::new(runtime),
+            availability: Arc::new(availability),
+            pools: Arc::new(pools),
+            apy_history: Arc::new(RwLock::new(ApyHistory::new())),
+            bridge_latency: Arc::new(RwLock::new(LatencyTracker::new())),
+            prices: Arc::new(PriceOracle::new(DeepBookAdapter::new())),
+            verifiers: Arc::new(verifiers),
+            signature_verifiers: Arc::new(signature_verifiers),
+            reconcilers: Arc::new(reconcilers),
+            chain_intents: Arc::new(RwLock::new(HashMap::new())),
+            drifts: Arc::new(RwLock::new(Vec::new())),
+            intent_index: Arc::new(RwLock::new(IntentIndex::default())),
+            audit: Arc::new(std::sync::Mutex::new(AuditLog::new())),
+            intent_events: broadcast::channel(INTENT_EVENT_BUFFER).0,
+            solver_liveness: Arc::new(RwLock::new(HashMap::new())),
+            solver_overrides: Arc::new(RwLock::new(SolverOverrides::default())),
+            feature_overrides: Arc::new(RwLock::new(FeatureOverrides::default())),
+            names: Arc::new(NameService::new(EnsResolver::new(&config.evm.ens_rpc_url))),
+            tokens: Arc::new(TokenChecker::new()),
+            config,
         }
     }
 
@@ -48,16 +442,124 @@ impl AppState {
     }
 
     /// Set current network
-    pub fn set_network(&self, network: &str) {
+    pub fn set_network(&self, network: &str, actor: &str) {
         if let Ok(mut n) = self.network.try_write() {
-            *n = network.to_string();
+            let before = std::mem::replace(&mut *n, network.to_string());
+            self.audit(
+                actor,
+                AuditAction::NetworkSwitch,
+                network,
+                Some(serde_json::json!(before)),
+                Some(serde_json::json!(network)),
+            );
         }
     }
 
+    /// Append to the audit log
+    pub fn audit(
+        &self,
+        actor: &str,
+        action: AuditAction,
+        target: &str,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) {
+        self.audit.lock().expect("audit lock poisoned").record(
+            actor,
+            action,
+            target,
+            before,
+            after,
+            now_millis(),
+        );
+    }
+
+    /// Tell watchers an intent's view changed (dropped when nobody listens)
+    pub fn notify_intent(&self, intent_id: &str) {
+        let _ = self.intent_events.send(intent_id.to_string());
+    }
+
+    /// Sui client for the current network
+    pub fn sui_client(&self) -> SuiClient {
+        let network = self.network().parse().unwrap_or(Network::Testnet);
+        sui_client(network, &self.agent_config)
+    }
+
+    /// Sui client for a given network
+    pub fn sui_client_for(&self, network: Network) -> SuiClient {
+        sui_client(network, &self.agent_config)
+    }
+
+    /// User signature verifier for the current network
+    pub fn signature_verifier(&self) -> Arc<SignatureVerifier> {
+        let network = self.network().parse().unwrap_or(Network::Testnet);
+        self.signature_verifiers
+            .get(&network)
+            .cloned()
+            .unwrap_or_else(|| Arc::new(SignatureVerifier::offline()))
+    }
+
     /// Store a solver bid, keyed by intent_id
-    pub async fn add_bid(&self, bid: SolverBidEntry) {
+    ///
+    /// Each solver has one active bid per intent. A higher re-bid replaces it
+    /// (keeping the old one in the history), an equal one is ignored and a
+    /// lower one is rejected.
+    pub async fn add_bid(
+        &self,
+        mut bid: SolverBidEntry,
+    ) -> Result<(BidOutcome, SolverBidEntry), WorseBid> {
         let mut bids = self.bids.write().await;
-        bids.entry(bid.intent_id.clone()).or_default().push(bid);
+        let active = bids.entry(bid.intent_id.clone()).or_default();
+
+        let (outcome, previous) = match active.iter_mut().find(|b| b.solver_name == bid.solver_name)
+        {
+            Some(current) if bid.offered_apy < current.offered_apy => {
+                return Err(WorseBid {
+                    active_apy: current.offered_apy,
+                });
+            }
+            Some(current) if bid.offered_apy == current.offered_apy => {
+                return Ok((BidOutcome::Unchanged, current.clone()));
+            }
+            Some(current) => {
+                let previous_apy = current.offered_apy;
+                bid.revision = current.revision + 1;
+                let previous = std::mem::replace(current, bid.clone());
+                (BidOutcome::Replaced { previous_apy }, Some(previous))
+            }
+            None => {
+                bid.revision = 1;
+                active.push(bid.clone());
+                (BidOutcome::Created, None)
+            }
+        };
+        drop(bids);
+
+        let action = match outcome {
+            BidOutcome::Replaced { .. } => AuditAction::BidReplaced,
+            _ => AuditAction::BidInserted,
+        };
+        self.audit(
+            &bid.solver_name,
+            action,
+            &bid.intent_id,
+            previous.and_then(|b| serde_json::to_value(b).ok()),
+            serde_json::to_value(&bid).ok(),
+        );
+
+        self.bid_history
+            .write()
+            .await
+            .entry(bid.intent_id.clone())
+            .or_default()
+            .push(bid.clone());
+        Ok((outcome, bid))
+    }
+
+    /// Every accepted revision for an intent, oldest first
+    pub async fn get_bid_history(&self, intent_id: &str) -> Vec<SolverBidEntry> {
+        let history = self.bid_history.read().await;
+        history.get(intent_id).cloned().unwrap_or_default()
     }
 
     /// Retrieve all bids for a given intent
@@ -66,23 +568,253 @@ impl AppState {
         bids.get(intent_id).cloned().unwrap_or_default()
     }
 
+    /// Store the winner a daemon picked for an intent (the latest pick wins)
+    pub async fn record_selected_winner(&self, intent_id: &str, winner: SelectedWinner) {
+        self.selected_winners
+            .write()
+            .await
+            .insert(intent_id.to_string(), winner);
+        self.notify_intent(intent_id);
+    }
+
+    /// Winner a daemon picked for an intent, if one reported it
+    pub async fn get_selected_winner(&self, intent_id: &str) -> Option<SelectedWinner> {
+        self.selected_winners.read().await.get(intent_id).cloned()
+    }
+
+    /// Store a solver's fulfillment report for an intent
+    ///
+    /// The first report wins; if one exists it is returned and kept. A
+    /// reported protocol fee accrues to the solver, and its referral share
+    /// to the intent's referrer.
+    pub async fn record_fulfillment(
+        &self,
+        intent_id: &str,
+        report: FulfillmentReport,
+    ) -> Option<FulfillmentReport> {
+        let mut fulfillments = self.fulfillments.write().await;
+        if let Some(existing) = fulfillments.get(intent_id) {
+            return Some(existing.clone());
+        }
+        if let Some(fee) = report.fee {
+            self.fees
+                .write()
+                .await
+                .accrue(&report.solver, report.amount.unwrap_or(0), fee);
+            let share_bps = self.agent_config.get().fees.referral_share_bps;
+            self.referrals
+                .write()
+                .await
+                .record_fee(intent_id, fee, share_bps);
+        }
+        self.intent_index
+            .write()
+            .await
+            .index_fulfillment(intent_id, &report);
+        fulfillments.insert(intent_id.to_string(), report);
+        drop(fulfillments);
+        self.notify_intent(intent_id);
+        None
+    }
+
+    /// Fulfillment reported for an intent, if any
+    pub async fn get_fulfillment(&self, intent_id: &str) -> Option<FulfillmentReport> {
+        let fulfillments = self.fulfillments.read().await;
+        fulfillments.get(intent_id).cloned()
+    }
+
+    /// Store the settlement check for an intent's fulfillment
+    pub async fn record_verification(&self, intent_id: &str, verification: Verification) {
+        let mut verifications = self.verifications.write().await;
+        verifications.insert(intent_id.to_string(), verification);
+        drop(verifications);
+        self.notify_intent(intent_id);
+    }
+
+    /// Settlement check for an intent, if it has run
+    pub async fn get_verification(&self, intent_id: &str) -> Option<Verification> {
+        let verifications = self.verifications.read().await;
+        verifications.get(intent_id).cloned()
+    }
+
+    /// Store an intent's fulfillment receipt
+    pub async fn record_receipt(&self, intent_id: &str, receipt: SignedReceipt) {
+        let mut receipts = self.receipts.write().await;
+        receipts.insert(intent_id.to_string(), receipt);
+    }
+
+    /// Receipt for an intent, once its fulfillment is verified
+    pub async fn get_receipt(&self, intent_id: &str) -> Option<SignedReceipt> {
+        let receipts = self.receipts.read().await;
+        receipts.get(intent_id).cloned()
+    }
+
+    /// Solver reputation from bids, fulfillments and settlement checks
+    ///
+    /// A win is the bid the selection policy ranks first for an intent.
+    pub async fn reputation(&self) -> Vec<Reputation> {
+        let policy = self.agent_config.get().selection;
+        let bids = self.bids.read().await;
+        let fulfillments = self.fulfillments.read().await;
+        let verifications = self.verifications.read().await;
+
+        let mut histories: HashMap<String, SolverHistory> = HashMap::new();
+        for intent_bids in bids.values() {
+            let mut intent_bids = intent_bids.clone();
+            intent_bids.sort_by_key(|b| b.timestamp);
+            if let Some(winner) = policy.select(intent_bids, 0) {
+                histories
+                    .entry(winner.solver_name.clone())
+                    .or_insert_with(|| SolverHistory::new(&winner.solver_name))
+                    .record_win();
+            }
+        }
+
+        for (intent_id, report) in fulfillments.iter() {
+            let bid = bids
+                .get(intent_id)
+                .and_then(|b| b.iter().find(|b| b.solver_name == report.solver));
+            let history = histories
+                .entry(report.solver.clone())
+                .or_insert_with(|| SolverHistory::new(&report.solver));
+            history.record_fulfillment(
+                bid.map(|b| b.offered_apy),
+                report.realized_apy_bps,
+                bid.and_then(|b| report.reported_at.checked_sub(b.timestamp)),
+            );
+            if let Some(verification) = verifications.get(intent_id) {
+                history.record_verification(verification.status);
+            }
+        }
+
+        reputation::leaderboard(histories.values(), &ReputationWeights::default())
+    }
+
+    /// Store a daemon heartbeat, one entry per solver it reports
+    pub async fn record_heartbeat(&self, heartbeat: Heartbeat, now_ms: u64) {
+        let mut liveness = self.solver_liveness.write().await;
+        for status in heartbeat.solvers {
+            let key = format!("{}/{}", heartbeat.network, status.name);
+            liveness.insert(
+                key,
+                SolverLiveness {
+                    solver: status.name,
+                    network: heartbeat.network.clone(),
+                    state: status.state,
+                    in_flight: status.in_flight,
+                    wallet: heartbeat.wallet.clone(),
+                    wallet_balance: heartbeat.wallet_balance,
+                    last_fulfillment_ms: status.last_fulfillment_ms,
+                    last_heartbeat_ms: now_ms,
+                    interval_secs: heartbeat.interval_secs,
+                    stale: false,
+                },
+            );
+        }
+    }
+
+    /// Solvers that have sent a heartbeat (on `network`, if given), with
+    /// staleness as of `now_ms`
+    pub async fn solver_liveness(&self, network: Option<&str>, now_ms: u64) -> Vec<SolverLiveness> {
+        let liveness = self.solver_liveness.read().await;
+        let mut solvers: Vec<SolverLiveness> = liveness
+            .values()
+            .filter(|l| network.is_none_or(|n| l.network.eq_ignore_ascii_case(n)))
+            .cloned()
+            .map(|mut l| {
+                l.stale = heartbeat::is_stale(l.last_heartbeat_ms, l.interval_secs, now_ms);
+                l
+            })
+            .collect();
+        solvers.sort_by(|a, b| (&a.network, &a.solver).cmp(&(&b.network, &b.solver)));
+        solvers
+    }
+
     /// Get an intent by ID
     pub async fn get_intent(&self, id: &str) -> Option<Intent> {
         let intents = self.intents.read().await;
         intents.get(id).cloned()
     }
 
+    /// ETA of a bridging intent
+    ///
+    /// Routes with no observed transfers fall back to the source chain's
+    /// finality for EVM → Sui.
+    pub async fn bridge_eta(&self, intent: &Intent) -> Option<Eta> {
+        if intent.status != IntentStatus::Bridging {
+            return None;
+        }
+        let elapsed = (now_millis() / 1000).saturating_sub(intent.updated_at.max(0) as u64);
+        let elapsed = std::time::Duration::from_secs(elapsed);
+        let route = bridge_latency::route(intent);
+        if let Some(eta) = self.bridge_latency.read().await.eta(&route, elapsed) {
+            return Some(eta);
+        }
+        (intent.direction == Direction::EvmToSui)
+            .then(|| Eta::estimated(attestation_wait(intent.evm_chain), elapsed))
+    }
+
+    /// Intent by its ID, or by the EVM `intentId` or Sui object linked to it
+    pub async fn find_intent(&self, reference: &str) -> Option<Intent> {
+        if let Some(intent) = self.get_intent(reference).await {
+            return Some(intent);
+        }
+        let (id, kind) = self.intent_index.read().await.get(reference)?;
+        if !kind.is_link() {
+            return None;
+        }
+        self.get_intent(&id).await
+    }
+
     /// Insert or update an intent
-    pub async fn upsert_intent(&self, intent: Intent) {
+    ///
+    /// Creations and status changes are audited under `actor`.
+    pub async fn upsert_intent(&self, intent: Intent, actor: &str) {
+        self.intent_index.write().await.index_intent(&intent);
         let mut intents = self.intents.write().await;
-        intents.insert(intent.id.clone(), intent);
+        let before = intents.insert(intent.id.clone(), intent.clone());
+        if before.as_ref().is_none_or(|b| b.status != intent.status) {
+            self.audit(
+                actor,
+                AuditAction::IntentStatus,
+                &intent.id,
+                before.and_then(|b| serde_json::to_value(b).ok()),
+                serde_json::to_value(&intent).ok(),
+            );
+        }
+    }
+
+    /// Store intents the reconciler read from chain
+    pub async fn upsert_chain_intents(&self, chain: HashMap<String, ChainIntent>) {
+        let mut index = self.intent_index.write().await;
+        for intent in chain.values() {
+            index.index_chain_intent(intent);
+        }
+        drop(index);
+        let mut chain_intents = self.chain_intents.write().await;
+        for (id, intent) in chain {
+            if chain_intents.get(&id) != Some(&intent) {
+                chain_intents.insert(id.clone(), intent);
+                self.notify_intent(&id);
+            }
+        }
     }
 
     /// Update intent status
-    pub async fn update_intent_status(&self, id: &str, status: IntentStatus) -> bool {
+    pub async fn update_intent_status(&self, id: &str, status: IntentStatus, actor: &str) -> bool {
         let mut intents = self.intents.write().await;
         if let Some(intent) = intents.get_mut(id) {
+            let before = intent.status;
             intent.set_status(status);
+            if before != status {
+                self.audit(
+                    actor,
+                    AuditAction::IntentStatus,
+                    id,
+                    Some(serde_json::json!({ "status": before })),
+                    Some(serde_json::json!({ "status": status })),
+                );
+            }
             true
         } else {
             false
@@ -95,6 +827,76 @@ impl AppState {
         intents.values().cloned().collect()
     }
 
+    /// Copy of the intent, bid, fulfillment and chain stores
+    pub async fn export_snapshot(&self) -> StateSnapshot {
+        StateSnapshot {
+            version: SNAPSHOT_VERSION,
+            exported_at: now_millis(),
+            network: self.network(),
+            intents: self.list_intents().await,
+            bids: self.bids.read().await.clone(),
+            bid_history: self.bid_history.read().await.clone(),
+            fulfillments: self.fulfillments.read().await.clone(),
+            receipts: self.receipts.read().await.clone(),
+            chain_intents: self.chain_intents.read().await.clone(),
+        }
+    }
+
+    /// Load a snapshot into the stores and rebuild the search index
+    ///
+    /// Refuses a non-empty instance unless `replace` is set, in which case
+    /// the snapshot's stores overwrite the current ones. Fulfillments are
+    /// restored as-is, without accruing fees again.
+    pub async fn import_snapshot(
+        &self,
+        snapshot: StateSnapshot,
+        replace: bool,
+        actor: &str,
+    ) -> Result<ImportSummary, SnapshotError> {
+        snapshot.check_version()?;
+        let mut intents = self.intents.write().await;
+        let mut bids = self.bids.write().await;
+        let mut fulfillments = self.fulfillments.write().await;
+        if !replace && (!intents.is_empty() || !bids.is_empty() || !fulfillments.is_empty()) {
+            return Err(SnapshotError::NotEmpty);
+        }
+
+        let summary = snapshot.summary();
+        let mut index = IntentIndex::default();
+        for intent in &snapshot.intents {
+            index.index_intent(intent);
+        }
+        for (intent_id, report) in &snapshot.fulfillments {
+            index.index_fulfillment(intent_id, report);
+        }
+        for intent in snapshot.chain_intents.values() {
+            index.index_chain_intent(intent);
+        }
+
+        *intents = snapshot
+            .intents
+            .into_iter()
+            .map(|i| (i.id.clone(), i))
+            .collect();
+        *bids = snapshot.bids;
+        *fulfillments = snapshot.fulfillments;
+        *self.bid_history.write().await = snapshot.bid_history;
+        *self.receipts.write().await = snapshot.receipts;
+        *self.chain_intents.write().await = snapshot.chain_intents;
+        *self.intent_index.write().await = index;
+        drop((intents, bids, fulfillments));
+
+        self.set_network(&snapshot.network, actor);
+        self.audit(
+            actor,
+            AuditAction::Admin,
+            "snapshot_import",
+            None,
+            serde_json::to_value(summary).ok(),
+        );
+        Ok(summary)
+    }
+
     /// List intents by creator address
     pub async fn list_intents_by_creator(&self, creator: &str) -> Vec<Intent> {
         let intents = self.intents.read().await;
@@ -111,3 +913,221 @@ impl Default for AppState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use naisu_core::{EvmChain, FulfilledObject, YieldStrategy};
+
+    use crate::common::response::ErrorCode;
+
+    fn bid(solver_name: &str, offered_apy: u64) -> SolverBidEntry {
+        SolverBidEntry {
+            intent_id: "intent-1".to_string(),
+            solver_name: solver_name.to_string(),
+            protocol: "scallop".to_string(),
+            offered_apy,
+            profit_bps: 10,
+            tip_bps: 0,
+            timestamp: 0,
+            confidence: None,
+            revision: 0,
+        }
+    }
+
+    fn intent(id: &str) -> Intent {
+        Intent::new_evm_to_sui(
+            id.to_string(),
+            "0xevm".to_string(),
+            "0xsui".to_string(),
+            EvmChain::Base,
+            "USDC".to_string(),
+            "1000000".to_string(),
+            YieldStrategy::ScallopUsdc,
+        )
+    }
+
+    fn report(digest: &str) -> FulfillmentReport {
+        FulfillmentReport {
+            digest: digest.to_string(),
+            solver: "scallop".to_string(),
+            protocol: "scallop".to_string(),
+            realized_apy_bps: 800,
+            created_objects: vec![],
+            reported_at: 0,
+            user: None,
+            amount: None,
+            fee: None,
+            gas_used: None,
+            failed_attempts: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_higher_bid_replaces() {
+        let state = AppState::new();
+        let (outcome, _) = state.add_bid(bid("scallop", 800)).await.unwrap();
+        assert_eq!(outcome, BidOutcome::Created);
+
+        let (outcome, stored) = state.add_bid(bid("scallop", 850)).await.unwrap();
+        assert_eq!(outcome, BidOutcome::Replaced { previous_apy: 800 });
+        assert_eq!(stored.revision, 2);
+
+        let active = state.get_bids_for_intent("intent-1").await;
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].offered_apy, 850);
+        assert_eq!(state.get_bid_history("intent-1").await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_equal_bid_is_unchanged() {
+        let state = AppState::new();
+        state.add_bid(bid("scallop", 800)).await.unwrap();
+
+        let (outcome, active) = state.add_bid(bid("scallop", 800)).await.unwrap();
+        assert_eq!(outcome, BidOutcome::Unchanged);
+        assert_eq!(active.revision, 1);
+        assert_eq!(state.get_bid_history("intent-1").await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_lower_rebid_is_rejected() {
+        let state = AppState::new();
+        state.add_bid(bid("scallop", 800)).await.unwrap();
+        // Other solvers may still bid lower
+        state.add_bid(bid("navi", 700)).await.unwrap();
+
+        let err = state.add_bid(bid("scallop", 750)).await.unwrap_err();
+        assert_eq!(err.active_apy, 800);
+        // Mapped to BID_TOO_LOW by the bid handler
+        assert_eq!(ErrorCode::BidTooLow.status(), StatusCode::CONFLICT);
+
+        let active = state.get_bids_for_intent("intent-1").await;
+        assert!(active
+            .iter()
+            .any(|b| b.solver_name == "scallop" && b.offered_apy == 800));
+    }
+
+    #[tokio::test]
+    async fn test_first_fulfillment_report_wins() {
+        let state = AppState::new();
+        assert!(state
+            .record_fulfillment("intent-1", report("FirstDigest"))
+            .await
+            .is_none());
+
+        let existing = state
+            .record_fulfillment("intent-1", report("SecondDigest"))
+            .await
+            .unwrap();
+        assert_eq!(existing.digest, "FirstDigest");
+        let stored = state.get_fulfillment("intent-1").await.unwrap();
+        assert_eq!(stored.digest, "FirstDigest");
+    }
+
+    #[tokio::test]
+    async fn test_index_finds_fulfillment_refs() {
+        let state = AppState::new();
+        let mut fulfillment = report("9xQeWvG816bUx9EP");
+        fulfillment.created_objects = vec![FulfilledObject {
+            object_id: "0xabc123".to_string(),
+            object_type: "0x3::staking_pool::StakedSui".to_string(),
+        }];
+        state.record_fulfillment("intent-1", fulfillment).await;
+
+        let index = state.intent_index.read().await;
+        assert_eq!(
+            index.get("0xABC123"),
+            Some(("intent-1".to_string(), RefKind::CreatedObject))
+        );
+        assert_eq!(
+            index.get(" 9xQeWvG816bUx9EP "),
+            Some(("intent-1".to_string(), RefKind::FulfillmentTx))
+        );
+        // Base58 digests are case-sensitive
+        assert_eq!(index.get("9XQEWVG816BUX9EP"), None);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_round_trip() {
+        let source = AppState::new();
+        let mut created = intent("intent-1");
+        created.sui_object_id = Some("0xfeed".to_string());
+        source.upsert_intent(created, "test").await;
+        source.add_bid(bid("scallop", 800)).await.unwrap();
+        source
+            .record_fulfillment("intent-1", report("FirstDigest"))
+            .await;
+
+        let json = serde_json::to_string(&source.export_snapshot().await).unwrap();
+        let snapshot: StateSnapshot = serde_json::from_str(&json).unwrap();
+
+        let target = AppState::new();
+        let summary = target
+            .import_snapshot(snapshot.clone(), false, "test")
+            .await
+            .unwrap();
+        assert_eq!(
+            (summary.intents, summary.bids, summary.fulfillments),
+            (1, 1, 1)
+        );
+        // The search index is rebuilt from the imported stores
+        let found = target.find_intent("0xFEED").await.unwrap();
+        assert_eq!(found.id, "intent-1");
+        assert_eq!(
+            target.get_fulfillment("intent-1").await.unwrap().digest,
+            "FirstDigest"
+        );
+
+        assert!(matches!(
+            target
+                .import_snapshot(snapshot.clone(), false, "test")
+                .await,
+            Err(SnapshotError::NotEmpty)
+        ));
+        assert!(target.import_snapshot(snapshot, true, "test").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_fulfillment_notifies_watchers() {
+        let state = AppState::new();
+        let mut changes = state.intent_events.subscribe();
+
+        state
+            .record_fulfillment("intent-1", report("FirstDigest"))
+            .await;
+        assert_eq!(changes.try_recv().unwrap(), "intent-1");
+
+        // A duplicate report changes nothing, so watchers hear nothing
+        state
+            .record_fulfillment("intent-1", report("SecondDigest"))
+            .await;
+        assert!(changes.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_latest_selected_winner_wins() {
+        let state = AppState::new();
+        let mut changes = state.intent_events.subscribe();
+        let winner = |solver_name: &str, fallbacks: &[&str]| SelectedWinner {
+            solver_name: solver_name.to_string(),
+            offered_apy: 800,
+            fallbacks: fallbacks.iter().map(|s| s.to_string()).collect(),
+            selected_at: 0,
+        };
+
+        state
+            .record_selected_winner("intent-1", winner("scallop", &["navi"]))
+            .await;
+        state
+            .record_selected_winner("intent-1", winner("navi", &[]))
+            .await;
+
+        let selected = state.get_selected_winner("intent-1").await.unwrap();
+        assert_eq!(selected.solver_name, "navi");
+        assert!(selected.fallbacks.is_empty());
+        assert_eq!(changes.try_recv().unwrap(), "intent-1");
+        assert!(state.get_selected_winner("intent-2").await.is_none());
+    }
+}
@@ -0,0 +1,69 @@
+//! Base-unit amount conversions
+//!
+//! Amounts travel as integer base units (MIST for SUI, 6-decimal units for
+//! USDC). Format them with these helpers rather than dividing by the scale:
+//! integer division drops the fraction, so 1.9 SUI would show as 1.
+
+use crate::validate::{parse_decimal_amount, ValidationError};
+
+/// SUI has 9 decimals (1 SUI = 10^9 MIST)
+pub const SUI_DECIMALS: u32 = 9;
+
+/// USDC has 6 decimals on Sui and the supported EVM chains
+pub const USDC_DECIMALS: u32 = 6;
+
+pub const MIST_PER_SUI: u64 = 1_000_000_000;
+
+/// Fraction digits shown for SUI amounts
+const SUI_DISPLAY_PRECISION: u32 = 4;
+
+/// Fraction digits shown for USDC amounts
+const USDC_DISPLAY_PRECISION: u32 = 2;
+
+/// `amount` base units as a decimal string, rounded half up to `precision`
+/// fraction digits, trailing zeros trimmed (`1_500_000_000, 9, 4` → `"1.5"`)
+pub fn format_units(amount: u64, decimals: u32, precision: u32) -> String {
+    let precision = precision.min(decimals);
+    let scale = 10u128.pow(decimals);
+    let shown = 10u128.pow(precision);
+    let step = scale / shown;
+    let rounded = (amount as u128 + step / 2) / step;
+
+    let whole = rounded / shown;
+    let fraction = rounded % shown;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let digits = format!("{:0width$}", fraction, width = precision as usize);
+    format!("{}.{}", whole, digits.trim_end_matches('0'))
+}
+
+/// MIST as SUI for display (`"1.2345"`)
+pub fn format_sui(mist: u64) -> String {
+    format_units(mist, SUI_DECIMALS, SUI_DISPLAY_PRECISION)
+}
+
+/// USDC base units for display (`"12.5"`)
+pub fn format_usdc(units: u64) -> String {
+    format_units(units, USDC_DECIMALS, USDC_DISPLAY_PRECISION)
+}
+
+/// Base units as a float, for rate and price math (not for display)
+pub fn to_decimal(amount: u64, decimals: u32) -> f64 {
+    amount as f64 / 10f64.powi(decimals as i32)
+}
+
+/// A float amount in base units, rounded to the nearest unit
+pub fn from_decimal(value: f64, decimals: u32) -> u64 {
+    (value * 10f64.powi(decimals as i32)).round() as u64
+}
+
+/// User input in SUI (`"1.5"`) as MIST
+pub fn parse_sui(input: &str) -> Result<u64, ValidationError> {
+    parse_decimal_amount(input, SUI_DECIMALS)
+}
+
+/// User input in USDC (`"12.5"`) as base units
+pub fn parse_usdc(input: &str) -> Result<u64, ValidationError> {
+    parse_decimal_amount(input, USDC_DECIMALS)
+}
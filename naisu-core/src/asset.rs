@@ -0,0 +1,156 @@
+//! Canonical assets
+//!
+//! Protocols name the same coin differently: Navi lists native USDC as
+//! `nUSDC`, Wormhole USDC goes by `wUSDC` or `USDCet`, and on-chain reads
+//! only have a coin type. Adapters and solvers resolve whatever they are
+//! given, symbol or Sui coin type, to an `Asset`, and ask it for the name a
+//! protocol expects.
+
+use serde::{Deserialize, Serialize};
+
+use crate::chain::SuiNetwork;
+
+/// A coin Naisu knows under every name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum Asset {
+    Sui,
+    /// Native (Circle) USDC
+    Usdc,
+    /// USDC bridged through Wormhole
+    WormholeUsdc,
+    /// USDT bridged through Wormhole
+    WormholeUsdt,
+}
+
+impl Asset {
+    pub const ALL: [Asset; 4] = [
+        Asset::Sui,
+        Asset::Usdc,
+        Asset::WormholeUsdc,
+        Asset::WormholeUsdt,
+    ];
+
+    /// Canonical symbol, used in API responses
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Asset::Sui => "SUI",
+            Asset::Usdc => "USDC",
+            Asset::WormholeUsdc => "wUSDC",
+            Asset::WormholeUsdt => "wUSDT",
+        }
+    }
+
+    /// Other symbols protocols and users use for the same coin
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Asset::Sui => &[],
+            Asset::Usdc => &["nUSDC"],
+            Asset::WormholeUsdc => &["USDCet"],
+            Asset::WormholeUsdt => &["USDT", "USDTet"],
+        }
+    }
+
+    /// Symbol a protocol lists the asset under (`scallop`, `navi`, ...)
+    pub fn symbol_on(&self, protocol: &str) -> &'static str {
+        match (self, protocol.to_ascii_lowercase().as_str()) {
+            (Asset::WormholeUsdc, "cetus") => "USDCet",
+            _ => self.symbol(),
+        }
+    }
+
+    pub fn decimals(&self) -> u32 {
+        match self {
+            Asset::Sui => 9,
+            Asset::Usdc | Asset::WormholeUsdc | Asset::WormholeUsdt => 6,
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        !matches!(self, Asset::Sui)
+    }
+
+    /// Sui coin type on a network, if the coin exists there
+    pub fn coin_type(&self, network: SuiNetwork) -> Option<&'static str> {
+        match (self, network) {
+            (Asset::Sui, _) => Some("0x2::sui::SUI"),
+            (Asset::Usdc, SuiNetwork::Mainnet) => Some(
+                "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
+            ),
+            (Asset::Usdc, SuiNetwork::Testnet) => Some(
+                "0xa1ec7fc00a6f40db9693ad1415d0c193ad3906494428cf252621037bd7117e29::usdc::USDC",
+            ),
+            (Asset::WormholeUsdc, SuiNetwork::Mainnet) => Some(
+                "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN",
+            ),
+            (Asset::WormholeUsdt, SuiNetwork::Mainnet) => Some(
+                "0xc060006111016b8a020ad5b33834984a437aaa7d3c74c18e09a95d48aceab08c::coin::COIN",
+            ),
+            _ => None,
+        }
+    }
+
+    /// Coin type as Move's `type_name` prints it: full-width address, no `0x`
+    pub fn type_name(&self, network: SuiNetwork) -> Option<String> {
+        self.coin_type(network).map(|coin_type| {
+            let (address, rest) = split_coin_type(coin_type);
+            format!("{:0>64}{}", address, rest)
+        })
+    }
+
+    /// Asset behind a symbol or coin type, on any protocol or network
+    pub fn resolve(name: &str) -> Option<Asset> {
+        let name = name.trim();
+        if name.contains("::") {
+            let key = coin_type_key(name);
+            return Asset::ALL.into_iter().find(|asset| {
+                [SuiNetwork::Mainnet, SuiNetwork::Testnet, SuiNetwork::Devnet]
+                    .into_iter()
+                    .filter_map(|network| asset.coin_type(network))
+                    .any(|coin_type| coin_type_key(coin_type) == key)
+            });
+        }
+        Asset::ALL.into_iter().find(|asset| {
+            asset.symbol().eq_ignore_ascii_case(name)
+                || asset.aliases().iter().any(|a| a.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Canonical symbol of `name`, or `name` itself when it isn't registered
+    pub fn normalize(name: &str) -> String {
+        Asset::resolve(name).map_or_else(|| name.to_string(), |asset| asset.symbol().to_string())
+    }
+}
+
+impl std::fmt::Display for Asset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Whether two symbols or coin types name the same coin
+///
+/// Names the registry doesn't know are compared case-insensitively.
+pub fn same_asset(a: &str, b: &str) -> bool {
+    match (Asset::resolve(a), Asset::resolve(b)) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => a.trim().eq_ignore_ascii_case(b.trim()),
+        _ => false,
+    }
+}
+
+/// `(address without 0x, "::module::Name")`
+fn split_coin_type(coin_type: &str) -> (&str, &str) {
+    let coin_type = coin_type.trim_start_matches("0x");
+    match coin_type.find("::") {
+        Some(i) => coin_type.split_at(i),
+        None => (coin_type, ""),
+    }
+}
+
+/// Coin type with the address's `0x` and leading zeros dropped, lowercased
+fn coin_type_key(coin_type: &str) -> String {
+    let (address, rest) = split_coin_type(coin_type);
+    format!("{}{}", address.trim_start_matches('0'), rest).to_ascii_lowercase()
+}
@@ -0,0 +1,42 @@
+//! Bid math
+//!
+//! How solvers price an intent, shared with the frontend so a quote shown
+//! to the user matches what solvers will bid.
+
+/// Calculate optimal bid for a solver
+///
+/// Formula: bid_apy = market_apy - solver_profit - gas_cost
+///
+/// A tip counts toward the solver's costs: it makes thinner spreads worth
+/// bidding on, and whatever it covers of the profit margin is passed on to
+/// the user as APY.
+///
+/// # Example
+/// - Market APY: 8.5% (850 bps)
+/// - User min: 7.5% (750 bps)
+/// - Spread: 1.0% (100 bps)
+/// - Gas cost: 0.1% (10 bps)
+/// - Solver profit: 0.2% (20 bps)
+/// - Bid APY: 8.2% (820 bps)
+pub fn calculate_bid(
+    market_apy: u64,     // e.g., 850 (8.5%)
+    user_min: u64,       // e.g., 750 (7.5%)
+    gas_cost_bps: u16,   // e.g., 10 (0.1%)
+    min_profit_bps: u16, // e.g., 20 (0.2%)
+    tip_bps: u64,        // e.g., 15 (0.15% of the amount)
+) -> Option<u64> {
+    if market_apy < user_min {
+        return None;
+    }
+    let spread = market_apy - user_min;
+    let required = gas_cost_bps as u64 + min_profit_bps as u64;
+
+    if spread.saturating_add(tip_bps) <= required {
+        // Not profitable
+        return None;
+    }
+
+    // Bid: give user most of the spread, keep small profit (less the tip)
+    let bid_apy = market_apy - (min_profit_bps as u64).saturating_sub(tip_bps);
+    Some(bid_apy)
+}
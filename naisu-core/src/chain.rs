@@ -2,8 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::explorer::ExplorerLink;
+
 /// Supported EVM chains (source chains)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[serde(rename_all = "lowercase")]
 pub enum EvmChain {
     /// Ethereum Mainnet
@@ -14,6 +17,10 @@ pub enum EvmChain {
     Arbitrum,
     /// Optimism
     Optimism,
+    /// Polygon PoS
+    Polygon,
+    /// Avalanche C-Chain
+    Avalanche,
     /// Base Sepolia (Testnet)
     BaseSepolia,
     /// Sepolia (Testnet)
@@ -21,6 +28,31 @@ pub enum EvmChain {
 }
 
 impl EvmChain {
+    pub const ALL: [EvmChain; 8] = [
+        EvmChain::Ethereum,
+        EvmChain::Base,
+        EvmChain::Arbitrum,
+        EvmChain::Optimism,
+        EvmChain::Polygon,
+        EvmChain::Avalanche,
+        EvmChain::BaseSepolia,
+        EvmChain::Sepolia,
+    ];
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "Ethereum",
+            EvmChain::Base => "Base",
+            EvmChain::Arbitrum => "Arbitrum",
+            EvmChain::Optimism => "Optimism",
+            EvmChain::Polygon => "Polygon",
+            EvmChain::Avalanche => "Avalanche",
+            EvmChain::BaseSepolia => "Base Sepolia",
+            EvmChain::Sepolia => "Sepolia",
+        }
+    }
+
     /// Get the chain ID for this EVM chain
     pub fn chain_id(&self) -> u64 {
         match self {
@@ -28,11 +60,18 @@ impl EvmChain {
             EvmChain::Base => 8453,
             EvmChain::Arbitrum => 42161,
             EvmChain::Optimism => 10,
+            EvmChain::Polygon => 137,
+            EvmChain::Avalanche => 43114,
             EvmChain::BaseSepolia => 84532,
             EvmChain::Sepolia => 11155111,
         }
     }
 
+    /// Chain with the given chain ID
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.chain_id() == chain_id)
+    }
+
     /// Get Li.Fi chain key
     pub fn lifi_chain_key(&self) -> &'static str {
         match self {
@@ -40,11 +79,118 @@ impl EvmChain {
             EvmChain::Base => "BAS",
             EvmChain::Arbitrum => "ARB",
             EvmChain::Optimism => "OPT",
+            EvmChain::Polygon => "POL",
+            EvmChain::Avalanche => "AVA",
             EvmChain::BaseSepolia => "BAS", // Li.Fi may use same key
             EvmChain::Sepolia => "ETH",
         }
     }
 
+    /// Circle USDC contract on this chain
+    pub fn usdc_address(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            EvmChain::Base => "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913",
+            EvmChain::Arbitrum => "0xaf88d065e77c8cC2239327C5EDb3A432268e5831",
+            EvmChain::Optimism => "0x0b2C639c533813f4Aa9D7837cAf62653d097Ff85",
+            EvmChain::Polygon => "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359",
+            EvmChain::Avalanche => "0xB97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E",
+            EvmChain::BaseSepolia => "0x036CbD53842c5426634e7929541eC2318f3dCF7e",
+            EvmChain::Sepolia => "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238",
+        }
+    }
+
+    /// Public RPC endpoint
+    pub fn default_rpc_url(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "https://ethereum-rpc.publicnode.com",
+            EvmChain::Base => "https://mainnet.base.org",
+            EvmChain::Arbitrum => "https://arb1.arbitrum.io/rpc",
+            EvmChain::Optimism => "https://mainnet.optimism.io",
+            EvmChain::Polygon => "https://polygon-rpc.com",
+            EvmChain::Avalanche => "https://api.avax.network/ext/bc/C/rpc",
+            EvmChain::BaseSepolia => "https://sepolia.base.org",
+            EvmChain::Sepolia => "https://ethereum-sepolia-rpc.publicnode.com",
+        }
+    }
+
+    /// Circle CCTP domain (testnets share their mainnet's domain)
+    pub fn cctp_domain(&self) -> u32 {
+        match self {
+            EvmChain::Ethereum | EvmChain::Sepolia => 0,
+            EvmChain::Avalanche => 1,
+            EvmChain::Optimism => 2,
+            EvmChain::Arbitrum => 3,
+            EvmChain::Base | EvmChain::BaseSepolia => 6,
+            EvmChain::Polygon => 7,
+        }
+    }
+
+    /// Chain for a CCTP domain, mainnet or testnet
+    pub fn from_cctp_domain(domain: u32, testnet: bool) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.cctp_domain() == domain && c.is_testnet() == testnet)
+    }
+
+    /// CCTP TokenMessenger contract (`depositForBurn`)
+    pub fn token_messenger(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "0xBd3fa81B58Ba92a82136038B25aDec7066af3155",
+            EvmChain::Base => "0x1682Ae6375C4E4A97e4B583BC394c861A46D8962",
+            EvmChain::Arbitrum => "0x19330d10D9Cc8751218eaf51E8885D058642E08A",
+            EvmChain::Optimism => "0x2B4069517957735bE00ceE0fadAE88a26365528f",
+            EvmChain::Polygon => "0x9daF8c91AEFAE50b9c0E69629D3F6Ca40cA3B3FE",
+            EvmChain::Avalanche => "0x6B25532e1060CE10cc3B0A99e5683b91BFDe6982",
+            EvmChain::BaseSepolia | EvmChain::Sepolia => {
+                "0x9f3B8679c73C2Fef8b59B4f3444d4e156fb70AA5"
+            }
+        }
+    }
+
+    /// CCTP MessageTransmitter contract (`receiveMessage`)
+    pub fn message_transmitter(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "0x0a992d191DEeC32aFe36203Ad87D7d289a738F81",
+            EvmChain::Base => "0xAD09780d193884d503182aD4588450C416D6F9D4",
+            EvmChain::Arbitrum => "0xC30362313FBBA5cf9163F0bb16a0e01f01A896ca",
+            EvmChain::Optimism => "0x4D41f22c5a0e5c74090899E5a8Fb597a8842b3e8",
+            EvmChain::Polygon => "0xF3be9355363857F3e001be68856A2f96b4C39Ba9",
+            EvmChain::Avalanche => "0x8186359aF5F57FbB40c6b14A588d2A59C0C29880",
+            EvmChain::BaseSepolia | EvmChain::Sepolia => {
+                "0x7865fAfC2db2093669d92c0F33AeEF291086BEFD"
+            }
+        }
+    }
+
+    /// Symbol of the coin gas is paid in
+    pub fn native_symbol(&self) -> &'static str {
+        match self {
+            EvmChain::Polygon => "POL",
+            EvmChain::Avalanche => "AVAX",
+            _ => "ETH",
+        }
+    }
+
+    /// Block explorer
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            EvmChain::Ethereum => "https://etherscan.io",
+            EvmChain::Base => "https://basescan.org",
+            EvmChain::Arbitrum => "https://arbiscan.io",
+            EvmChain::Optimism => "https://optimistic.etherscan.io",
+            EvmChain::Polygon => "https://polygonscan.com",
+            EvmChain::Avalanche => "https://snowtrace.io",
+            EvmChain::BaseSepolia => "https://sepolia.basescan.org",
+            EvmChain::Sepolia => "https://sepolia.etherscan.io",
+        }
+    }
+
+    /// Explorer link for a transaction
+    pub fn explorer_tx_url(&self, tx_hash: &str) -> String {
+        ExplorerLink::evm(*self).tx(tx_hash)
+    }
+
     /// Check if this is a testnet
     pub fn is_testnet(&self) -> bool {
         matches!(self, EvmChain::BaseSepolia | EvmChain::Sepolia)
@@ -69,6 +215,24 @@ impl SuiNetwork {
             SuiNetwork::Devnet => "https://fullnode.devnet.sui.io:443",
         }
     }
+
+    /// Get the GraphQL service URL for this Sui network
+    pub fn graphql_url(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => "https://sui-mainnet.mystenlabs.com/graphql",
+            SuiNetwork::Testnet => "https://sui-testnet.mystenlabs.com/graphql",
+            SuiNetwork::Devnet => "https://sui-devnet.mystenlabs.com/graphql",
+        }
+    }
+
+    /// Suiscan for this network (see `ExplorerLink`)
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            SuiNetwork::Mainnet => "https://suiscan.xyz/mainnet",
+            SuiNetwork::Testnet => "https://suiscan.xyz/testnet",
+            SuiNetwork::Devnet => "https://suiscan.xyz/devnet",
+        }
+    }
 }
 
 /// Token information
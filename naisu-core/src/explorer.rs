@@ -0,0 +1,71 @@
+//! Block explorer links
+//!
+//! Every explorer URL is built here from the network the transaction,
+//! object or address lives on, so a mainnet digest never gets a testnet link.
+
+use crate::chain::{EvmChain, SuiNetwork};
+
+/// What an explorer link points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplorerEntity {
+    Tx,
+    Object,
+    Address,
+}
+
+/// Explorer URL builder for one network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplorerLink {
+    base: String,
+    evm: bool,
+}
+
+impl ExplorerLink {
+    /// Suiscan for a Sui network
+    pub fn sui(network: SuiNetwork) -> Self {
+        Self::with_base(network.explorer_url())
+    }
+
+    /// The chain's block explorer
+    pub fn evm(chain: EvmChain) -> Self {
+        Self {
+            base: chain.explorer_url().to_string(),
+            evm: true,
+        }
+    }
+
+    /// Sui explorer at a custom base URL (localnet, custom networks)
+    pub fn with_base(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into().trim_end_matches('/').to_string(),
+            evm: false,
+        }
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn url(&self, entity: ExplorerEntity, id: &str) -> String {
+        let path = match (entity, self.evm) {
+            (ExplorerEntity::Tx, _) => "tx",
+            (ExplorerEntity::Object, false) => "object",
+            (ExplorerEntity::Address, false) => "account",
+            // Contracts are addresses on EVM explorers
+            (ExplorerEntity::Object | ExplorerEntity::Address, true) => "address",
+        };
+        format!("{}/{}/{}", self.base, path, id)
+    }
+
+    pub fn tx(&self, digest: &str) -> String {
+        self.url(ExplorerEntity::Tx, digest)
+    }
+
+    pub fn object(&self, object_id: &str) -> String {
+        self.url(ExplorerEntity::Object, object_id)
+    }
+
+    pub fn address(&self, address: &str) -> String {
+        self.url(ExplorerEntity::Address, address)
+    }
+}
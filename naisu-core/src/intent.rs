@@ -1,11 +1,14 @@
 //! Intent types - bidirectional cross-chain yield migration
 
 use crate::chain::EvmChain;
+use crate::risk::RiskTier;
 use crate::strategy::YieldStrategy;
+use crate::validate::ValidationError;
 use serde::{Deserialize, Serialize};
 
 /// Direction of the cross-chain intent
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum Direction {
     /// EVM → Sui: swap to USDC on EVM, bridge via CCTP, deposit to yield on Sui
@@ -16,6 +19,7 @@ pub enum Direction {
 
 /// Intent status throughout its lifecycle
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum IntentStatus {
     /// Created, waiting for initial action
@@ -34,6 +38,10 @@ pub enum IntentStatus {
     Failed,
     /// Cancelled by user
     Cancelled,
+    /// Refund transaction built, waiting for execution
+    RefundPending,
+    /// Funds returned to the user
+    Refunded,
 }
 
 impl IntentStatus {
@@ -47,13 +55,71 @@ impl IntentStatus {
             IntentStatus::Completed => "completed",
             IntentStatus::Failed => "failed",
             IntentStatus::Cancelled => "cancelled",
+            IntentStatus::RefundPending => "refund_pending",
+            IntentStatus::Refunded => "refunded",
         }
     }
 }
 
+/// Priority tip a user attaches to an intent, paid to the winning solver
+///
+/// Serialized as `{"bps": 25}` or `{"flat": 1000000}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum Tip {
+    /// Share of the intent amount (bps)
+    Bps(u16),
+    /// Fixed amount (raw units of the input token)
+    Flat(u64),
+}
+
+impl Tip {
+    /// Tip paid on an intent of `amount`
+    pub fn amount(&self, amount: u64) -> u64 {
+        match self {
+            Tip::Bps(bps) => (amount as u128 * *bps as u128 / 10_000) as u64,
+            Tip::Flat(flat) => *flat,
+        }
+    }
+
+    /// Tip as bps of an intent of `amount`
+    pub fn bps(&self, amount: u64) -> u64 {
+        match self {
+            Tip::Bps(bps) => *bps as u64,
+            Tip::Flat(_) if amount == 0 => 0,
+            Tip::Flat(flat) => (*flat as u128 * 10_000 / amount as u128) as u64,
+        }
+    }
+}
+
+/// How an intent signature was produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureKind {
+    /// EIP-712 typed data, signed by the EVM source wallet
+    Eip712,
+    /// Sui personal message, signed by the Sui source wallet
+    SuiPersonalMessage,
+}
+
+/// Source wallet's signature over an intent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct IntentSignature {
+    pub kind: SignatureKind,
+    /// 0x-hex `r ‖ s ‖ v` (EIP-712) or base64 Sui signature
+    pub signature: String,
+    /// Client-chosen nonce covered by the signature
+    pub nonce: u64,
+}
+
 /// Cross-chain intent (bidirectional)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct Intent {
+    /// `IntentId` (older intents: a UUID)
     pub id: String,
     /// Direction of the intent
     pub direction: Direction,
@@ -83,6 +149,42 @@ pub struct Intent {
     pub dest_tx_hash: Option<String>,
     /// Error message if failed
     pub error_message: Option<String>,
+    /// Times recovery resumed the intent after a failure
+    #[serde(default)]
+    pub retries: u32,
+    /// Refund tx hash (Sui digest or EVM hash)
+    #[serde(default)]
+    pub refund_tx_hash: Option<String>,
+    /// Address credited with referring the intent
+    #[serde(default)]
+    pub referrer: Option<String>,
+    /// Priority tip for the solver
+    #[serde(default)]
+    pub tip: Option<Tip>,
+    /// Riskiest strategy tier the intent may be deployed into (`None` = any)
+    #[serde(default)]
+    pub max_risk: Option<RiskTier>,
+    /// Only deploy into stablecoin strategies
+    #[serde(default)]
+    pub stable_only: bool,
+    /// Source wallet's signature, kept as dispute evidence
+    #[serde(default)]
+    pub signature: Option<IntentSignature>,
+    /// Commitment the intent was revealed from, for commit-reveal intents
+    #[serde(default)]
+    pub commitment: Option<String>,
+    /// SuiNS / ENS name of the source wallet, if it has one
+    #[serde(default)]
+    pub source_name: Option<String>,
+    /// SuiNS / ENS name of the destination wallet, if it has one
+    #[serde(default)]
+    pub dest_name: Option<String>,
+    /// `intentId` the EVM hook emitted for this intent
+    #[serde(default)]
+    pub evm_intent_id: Option<String>,
+    /// Sui intent object opened for this intent
+    #[serde(default)]
+    pub sui_object_id: Option<String>,
     /// Created timestamp (unix)
     pub created_at: i64,
     /// Last updated timestamp (unix)
@@ -90,6 +192,20 @@ pub struct Intent {
 }
 
 impl Intent {
+    /// Whether `other` looks like this intent submitted again: same
+    /// direction, source wallet, input token, amount and strategy, created
+    /// at most `window_secs` apart
+    pub fn is_near_duplicate(&self, other: &Intent, window_secs: u64) -> bool {
+        self.direction == other.direction
+            && self
+                .source_address
+                .eq_ignore_ascii_case(&other.source_address)
+            && self.input_token.eq_ignore_ascii_case(&other.input_token)
+            && self.input_amount == other.input_amount
+            && self.strategy == other.strategy
+            && self.created_at.abs_diff(other.created_at) <= window_secs
+    }
+
     /// Create a new EVM→Sui intent
     pub fn new_evm_to_sui(
         id: String,
@@ -117,6 +233,18 @@ impl Intent {
             bridge_nonce: None,
             dest_tx_hash: None,
             error_message: None,
+            retries: 0,
+            refund_tx_hash: None,
+            referrer: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+            signature: None,
+            commitment: None,
+            source_name: None,
+            dest_name: None,
+            evm_intent_id: None,
+            sui_object_id: None,
             created_at: now,
             updated_at: now,
         }
@@ -148,17 +276,69 @@ impl Intent {
             bridge_nonce: None,
             dest_tx_hash: None,
             error_message: None,
+            retries: 0,
+            refund_tx_hash: None,
+            referrer: None,
+            tip: None,
+            max_risk: None,
+            stable_only: false,
+            signature: None,
+            commitment: None,
+            source_name: None,
+            dest_name: None,
+            evm_intent_id: None,
+            sui_object_id: None,
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Validate a creation request and build its intent
+    ///
+    /// The request's signature is not copied; it has to be verified first.
+    pub fn from_request(id: String, req: CreateIntentRequest) -> Result<Self, ValidationError> {
+        let amount = req.validate()?;
+        let referrer = req.normalized_referrer();
+        let mut intent = match (req.direction, req.strategy) {
+            (Direction::EvmToSui, Some(strategy)) => Self::new_evm_to_sui(
+                id,
+                req.source_address,
+                req.dest_address,
+                req.evm_chain,
+                req.input_token,
+                req.input_amount,
+                strategy,
+            ),
+            (Direction::EvmToSui, None) => return Err(ValidationError::MissingStrategy),
+            (Direction::SuiToEvm, _) => Self::new_sui_to_evm(
+                id,
+                req.source_address,
+                req.dest_address,
+                req.evm_chain,
+                req.input_token,
+                req.input_amount,
+            ),
+        };
+        intent.referrer = referrer;
+        intent.tip = req.tip.filter(|t| t.amount(amount) > 0);
+        intent.max_risk = req.max_risk;
+        intent.stable_only = req.stable_only;
+        Ok(intent)
+    }
+
     /// Update status with timestamp
     pub fn set_status(&mut self, status: IntentStatus) {
         self.status = status;
         self.updated_at = chrono::Utc::now().timestamp();
     }
 
+    /// Resume a failed intent from an earlier stage
+    pub fn retry(&mut self, status: IntentStatus) {
+        self.retries += 1;
+        self.error_message = None;
+        self.set_status(status);
+    }
+
     /// Mark as failed with error message
     pub fn fail(&mut self, message: String) {
         self.status = IntentStatus::Failed;
@@ -167,21 +347,92 @@ impl Intent {
     }
 }
 
+/// An object a fulfillment created for the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FulfilledObject {
+    pub object_id: String,
+    pub object_type: String,
+}
+
+/// A solver that won an intent but failed to fulfill it, before the
+/// next-ranked bid took over
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FailedAttempt {
+    pub solver: String,
+    /// APY the solver had bid (basis points)
+    pub apy_bps: u64,
+    pub error: String,
+}
+
+/// A solver's report that it fulfilled an intent on-chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+pub struct FulfillmentReport {
+    /// Fulfillment transaction digest
+    pub digest: String,
+    pub solver: String,
+    pub protocol: String,
+    /// APY delivered to the user (basis points)
+    pub realized_apy_bps: u64,
+    /// StakedSui, sSUI, position NFT, ...
+    #[serde(default)]
+    pub created_objects: Vec<FulfilledObject>,
+    /// Set by the API on receipt (unix millis)
+    #[serde(default)]
+    pub reported_at: u64,
+    /// Recipient of the receipt object, for settlement verification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// Intent amount the receipt should reflect (MIST)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    /// Protocol fee routed to the treasury (MIST)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee: Option<u64>,
+    /// Net gas the solver paid (MIST, may be negative after rebates)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<i64>,
+    /// Higher-ranked solvers that failed first, in bid order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_attempts: Vec<FailedAttempt>,
+}
+
 /// Intent creation request from frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 pub struct CreateIntentRequest {
     pub direction: Direction,
+    /// Address or SuiNS / ENS name; the API resolves names before
+    /// validating, and signatures cover the resolved address
     pub source_address: String,
+    /// Address or SuiNS / ENS name
     pub dest_address: String,
     pub evm_chain: EvmChain,
     pub input_token: String,
     pub input_amount: String,
     /// Required for EvmToSui, ignored for SuiToEvm
     pub strategy: Option<YieldStrategy>,
+    /// Address that referred the user
+    #[serde(default)]
+    pub referrer: Option<String>,
+    /// Priority tip for the solver
+    #[serde(default)]
+    pub tip: Option<Tip>,
+    /// Riskiest strategy tier the intent may be deployed into
+    #[serde(default)]
+    pub max_risk: Option<RiskTier>,
+    /// Restrict the intent to stablecoin strategies
+    #[serde(default)]
+    pub stable_only: bool,
+    /// Source wallet's signature over the request
+    #[serde(default)]
+    pub signature: Option<IntentSignature>,
 }
 
 /// Intent event emitted by V4 Hook (EVM side, EvmToSui trigger)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IntentCreatedEvent {
     pub intent_id: String,
     pub user: String,
@@ -192,3 +443,39 @@ pub struct IntentCreatedEvent {
     pub strategy_id: u8,
     pub timestamp: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intent(id: &str, amount: &str) -> Intent {
+        Intent::new_evm_to_sui(
+            id.to_string(),
+            "0xAbC".to_string(),
+            "0xsui".to_string(),
+            EvmChain::Base,
+            "USDC".to_string(),
+            amount.to_string(),
+            YieldStrategy::ScallopUsdc,
+        )
+    }
+
+    #[test]
+    fn test_near_duplicate() {
+        let first = intent("api_1", "1000000");
+        let mut resent = intent("api_2", "1000000");
+        resent.source_address = "0xabc".to_string();
+        resent.created_at = first.created_at + 60;
+        assert!(first.is_near_duplicate(&resent, 120));
+        assert!(resent.is_near_duplicate(&first, 120));
+
+        // Outside the window
+        assert!(!first.is_near_duplicate(&resent, 30));
+        // A different amount is a separate intent
+        let other = intent("api_3", "2000000");
+        assert!(!first.is_near_duplicate(&other, 120));
+        let mut other = intent("api_4", "1000000");
+        other.strategy = Some(YieldStrategy::NaviUsdc);
+        assert!(!first.is_near_duplicate(&other, 120));
+    }
+}
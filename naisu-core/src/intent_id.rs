@@ -0,0 +1,148 @@
+//! Intent IDs
+//!
+//! `<source>_<ulid>`, e.g. `api_01J9ZK3QW8V6X4T2M0N7P5R1SA`: a ULID (48-bit
+//! creation time in milliseconds, then 80 random bits, Crockford base32)
+//! behind a prefix naming where the intent was created. IDs sort by
+//! creation time and only collide if two share both the millisecond and
+//! the 80 random bits. The EVM hook's `intentId` and the Sui intent object
+//! are linked to the ID, not used as it.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const ULID_LEN: usize = 26;
+
+/// Where an intent was created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum IntentSource {
+    /// `POST /intents` (or a commit-reveal)
+    Api,
+    /// An `IntentCreated` log of the V4 hook
+    EvmHook,
+}
+
+impl IntentSource {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            IntentSource::Api => "api",
+            IntentSource::EvmHook => "evm",
+        }
+    }
+
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        [IntentSource::Api, IntentSource::EvmHook]
+            .into_iter()
+            .find(|s| s.prefix() == prefix)
+    }
+}
+
+/// A parsed intent ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntentId {
+    source: IntentSource,
+    timestamp_ms: u64,
+    /// Low 80 bits
+    random: u128,
+}
+
+impl IntentId {
+    /// `entropy` must come from a cryptographic RNG
+    pub fn new(source: IntentSource, timestamp_ms: u64, entropy: [u8; 10]) -> Self {
+        Self {
+            source,
+            timestamp_ms: timestamp_ms & 0xFFFF_FFFF_FFFF,
+            random: entropy.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128),
+        }
+    }
+
+    pub fn source(&self) -> IntentSource {
+        self.source
+    }
+
+    /// Creation time (unix millis)
+    pub fn timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    /// `None` for IDs not in this scheme (older UUIDs, chain object IDs)
+    pub fn parse(id: &str) -> Option<Self> {
+        let (prefix, ulid) = id.trim().split_once('_')?;
+        let source = IntentSource::from_prefix(prefix)?;
+        if ulid.len() != ULID_LEN {
+            return None;
+        }
+        let mut value = 0u128;
+        for (i, c) in ulid.bytes().enumerate() {
+            let digit = ALPHABET.iter().position(|&a| a == c.to_ascii_uppercase())?;
+            // 26 digits hold 130 bits; the first may only use 3
+            if i == 0 && digit > 7 {
+                return None;
+            }
+            value = (value << 5) | digit as u128;
+        }
+        Some(Self {
+            source,
+            timestamp_ms: (value >> 80) as u64,
+            random: value & ((1 << 80) - 1),
+        })
+    }
+
+    fn ulid(&self) -> String {
+        let value = ((self.timestamp_ms as u128) << 80) | self.random;
+        (0..ULID_LEN)
+            .rev()
+            .map(|i| ALPHABET[((value >> (i * 5)) & 0x1F) as usize] as char)
+            .collect()
+    }
+}
+
+impl fmt::Display for IntentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.source.prefix(), self.ulid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_parse_round_trip() {
+        let id = IntentId::new(IntentSource::EvmHook, 1_700_000_000_000, [0xAB; 10]);
+        let text = id.to_string();
+        assert!(text.starts_with("evm_"));
+        assert_eq!(text.len(), 4 + ULID_LEN);
+
+        assert_eq!(IntentId::parse(&text), Some(id));
+        assert_eq!(IntentId::parse(&text.to_lowercase()), Some(id));
+        assert_eq!(id.timestamp_ms(), 1_700_000_000_000);
+        assert_eq!(id.source(), IntentSource::EvmHook);
+    }
+
+    #[test]
+    fn test_ids_sort_by_creation_time() {
+        let older = IntentId::new(IntentSource::Api, 1_000, [0xFF; 10]).to_string();
+        let newer = IntentId::new(IntentSource::Api, 1_001, [0x00; 10]).to_string();
+        assert!(older < newer);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_ids() {
+        assert_eq!(
+            IntentId::parse("550e8400-e29b-41d4-a716-446655440000"),
+            None
+        );
+        assert_eq!(IntentId::parse("sui_01J9ZK3QW8V6X4T2M0N7P5R1SA"), None);
+        assert_eq!(IntentId::parse("api_01J9ZK3QW8"), None);
+        // The first digit may only carry 3 bits
+        assert_eq!(IntentId::parse("api_81J9ZK3QW8V6X4T2M0N7P5R1SA"), None);
+        // `U` is not in the alphabet
+        assert_eq!(IntentId::parse("api_01J9ZK3QW8V6X4T2M0N7P5R1SU"), None);
+        assert!(IntentId::parse("api_01J9ZK3QW8V6X4T2M0N7P5R1SA").is_some());
+    }
+}
@@ -1,16 +1,43 @@
 //! Naisu Core - Core types and traits for cross-chain intent system
 //!
 //! This crate defines the fundamental types used across all Naisu components:
+//! - Amount: Base-unit formatting and parsing (MIST, USDC units)
+//! - Asset: Canonical assets with per-protocol symbols and Sui coin types
 //! - Intent: User's cross-chain yield migration request
+//! - IntentId: Time-ordered intent IDs tagged with their source
 //! - Chain: Supported blockchain networks
+//! - Explorer: Block explorer links per network
 //! - Strategy: Yield strategies on destination chain (Sui)
+//! - Recovery: Retry and refund planning for failed intents
+//! - Risk: Shared strategy risk scoring
+//! - Secret: Keys and tokens loaded from env or files, redacted and zeroized
+//! - Validate: Amount, address and intent request checks
+//! - Bid: Solver bid math
 
+pub mod amount;
+pub mod asset;
+pub mod bid;
 pub mod chain;
 pub mod error;
+pub mod explorer;
 pub mod intent;
+pub mod intent_id;
+pub mod recovery;
+pub mod risk;
+pub mod secret;
 pub mod strategy;
+pub mod validate;
 
+pub use amount::*;
+pub use asset::*;
+pub use bid::*;
 pub use chain::*;
 pub use error::*;
+pub use explorer::*;
 pub use intent::*;
+pub use intent_id::*;
+pub use recovery::*;
+pub use risk::*;
+pub use secret::*;
 pub use strategy::*;
+pub use validate::*;
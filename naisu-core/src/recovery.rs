@@ -0,0 +1,188 @@
+//! Failure recovery - where a failed intent stopped and how to get the user
+//! their funds back
+//!
+//! The stage is read from the tx hashes an intent collected before it failed:
+//! - `Source`: nothing executed, the user still holds their funds
+//! - `Bridge`: swap/withdraw done, USDC is waiting on the source chain
+//! - `Attestation`: USDC burned, CCTP mint not yet executed
+//! - `Destination`: USDC minted on the destination chain, deposit failed
+//!
+//! Burned USDC can't be refunded until it is minted, so `Attestation` can
+//! only be retried.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::chain::EvmChain;
+use crate::intent::{Direction, Intent, IntentStatus};
+
+/// Retries allowed before an intent must be refunded
+pub const MAX_RETRIES: u32 = 3;
+
+/// `transfer(address,uint256)`
+const ERC20_TRANSFER_SELECTOR: &str = "a9059cbb";
+
+/// Stage an intent failed at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureStage {
+    Source,
+    Bridge,
+    Attestation,
+    Destination,
+}
+
+impl FailureStage {
+    /// Classify from the progress recorded on the intent
+    pub fn classify(intent: &Intent) -> Self {
+        if intent.dest_tx_hash.is_some() {
+            FailureStage::Destination
+        } else if intent.bridge_tx_hash.is_some() {
+            FailureStage::Attestation
+        } else if intent.swap_tx_hash.is_some() {
+            FailureStage::Bridge
+        } else {
+            FailureStage::Source
+        }
+    }
+
+    /// Status a retry resumes from
+    pub fn resume_status(&self) -> IntentStatus {
+        match self {
+            FailureStage::Source => IntentStatus::Pending,
+            FailureStage::Bridge => IntentStatus::SwapCompleted,
+            FailureStage::Attestation => IntentStatus::Bridging,
+            FailureStage::Destination => IntentStatus::BridgeCompleted,
+        }
+    }
+}
+
+/// Chain a refund is paid out on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "chain", rename_all = "snake_case")]
+pub enum RefundChain {
+    Sui,
+    Evm(EvmChain),
+}
+
+/// Who gets what back, and where
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefundPlan {
+    pub stage: FailureStage,
+    pub chain: RefundChain,
+    pub recipient: String,
+    /// USDC (6 decimals)
+    pub amount: u64,
+}
+
+impl RefundPlan {
+    /// Plan the refund for a failed intent
+    pub fn for_intent(intent: &Intent) -> Result<Self, RecoveryError> {
+        if intent.status != IntentStatus::Failed {
+            return Err(RecoveryError::NotFailed(intent.status));
+        }
+
+        let stage = FailureStage::classify(intent);
+        let (chain, recipient) = match (stage, intent.direction) {
+            // The user never parted with their funds
+            (FailureStage::Source, _) => return Err(RecoveryError::NothingToRefund(stage)),
+            (FailureStage::Attestation, _) => return Err(RecoveryError::FundsInFlight),
+            // Delivered USDC already sits in the user's EVM wallet
+            (FailureStage::Destination, Direction::SuiToEvm) => {
+                return Err(RecoveryError::NothingToRefund(stage))
+            }
+            (FailureStage::Bridge, Direction::EvmToSui) => (
+                RefundChain::Evm(intent.evm_chain),
+                intent.source_address.clone(),
+            ),
+            (FailureStage::Bridge, Direction::SuiToEvm) => {
+                (RefundChain::Sui, intent.source_address.clone())
+            }
+            (FailureStage::Destination, Direction::EvmToSui) => {
+                (RefundChain::Sui, intent.dest_address.clone())
+            }
+        };
+
+        let amount = intent
+            .usdc_amount
+            .as_deref()
+            .and_then(|a| a.parse::<u64>().ok())
+            .ok_or(RecoveryError::UnknownAmount)?;
+
+        Ok(Self {
+            stage,
+            chain,
+            recipient,
+            amount,
+        })
+    }
+}
+
+/// Check a failed intent can be retried and return the status to resume from
+pub fn retry_status(intent: &Intent) -> Result<IntentStatus, RecoveryError> {
+    if intent.status != IntentStatus::Failed {
+        return Err(RecoveryError::NotFailed(intent.status));
+    }
+    if intent.retries >= MAX_RETRIES {
+        return Err(RecoveryError::RetriesExhausted(intent.retries));
+    }
+    Ok(FailureStage::classify(intent).resume_status())
+}
+
+/// Unsigned ERC-20 USDC transfer for an EVM refund
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvmRefundTx {
+    pub chain_id: u64,
+    /// USDC contract
+    pub to: String,
+    /// ABI-encoded `transfer(recipient, amount)`
+    pub data: String,
+    pub value: String,
+}
+
+impl EvmRefundTx {
+    pub fn usdc_transfer(
+        chain: EvmChain,
+        recipient: &str,
+        amount: u64,
+    ) -> Result<Self, RecoveryError> {
+        let address = recipient.strip_prefix("0x").unwrap_or(recipient);
+        if address.len() != 40 || !address.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(RecoveryError::InvalidRecipient(recipient.to_string()));
+        }
+
+        Ok(Self {
+            chain_id: chain.chain_id(),
+            to: chain.usdc_address().to_string(),
+            data: format!(
+                "0x{}{:0>64}{:064x}",
+                ERC20_TRANSFER_SELECTOR,
+                address.to_lowercase(),
+                amount
+            ),
+            value: "0".to_string(),
+        })
+    }
+}
+
+/// Why an intent can't be recovered as asked
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryError {
+    #[error("Intent is {}, not failed", .0.as_str())]
+    NotFailed(IntentStatus),
+
+    #[error("Nothing to refund at stage {0:?}")]
+    NothingToRefund(FailureStage),
+
+    #[error("USDC is burned but not minted yet; retry the bridge instead")]
+    FundsInFlight,
+
+    #[error("Intent has no USDC amount to refund")]
+    UnknownAmount,
+
+    #[error("Invalid refund recipient: {0}")]
+    InvalidRecipient(String),
+
+    #[error("Retried {0} times already; refund instead")]
+    RetriesExhausted(u32),
+}
@@ -0,0 +1,188 @@
+//! Strategy risk model
+//!
+//! Every place that rates a yield strategy (protocol adapters, the yield
+//! comparator, the strategies API) scores it with `RiskModel::score`.
+//! Scores run 1-10, lower is safer, and group into `RiskTier`s.
+
+use serde::{Deserialize, Serialize};
+
+const DAY_SECS: i64 = 86_400;
+
+/// Coarse risk classification of a strategy
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[serde(rename_all = "snake_case")]
+pub enum RiskTier {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl RiskTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskTier::Low => "low",
+            RiskTier::Medium => "medium",
+            RiskTier::High => "high",
+        }
+    }
+}
+
+impl std::str::FromStr for RiskTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(RiskTier::Low),
+            "medium" => Ok(RiskTier::Medium),
+            "high" => Ok(RiskTier::High),
+            _ => Err(format!("Unknown risk tier: {}", s)),
+        }
+    }
+}
+
+/// What the score is computed from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RiskFactors {
+    /// Total value supplied (USD)
+    pub tvl_usd: f64,
+    /// Borrowed / supplied (0.0 - 1.0)
+    pub utilization: f64,
+    /// The protocol has a published audit
+    pub audited: bool,
+    /// Days since the protocol launched on mainnet
+    pub protocol_age_days: u32,
+    /// Deposits are exposed to impermanent loss (LP positions)
+    pub il_exposure: bool,
+}
+
+impl RiskFactors {
+    /// Days between a launch and now (unix seconds)
+    pub fn age_days(launched_at: i64, now: i64) -> u32 {
+        ((now - launched_at).max(0) / DAY_SECS) as u32
+    }
+}
+
+/// Scoring of `RiskFactors` and the tier cut-offs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskModel {
+    /// Highest score still rated `Low`
+    pub low_max: u8,
+    /// Highest score still rated `Medium`
+    pub medium_max: u8,
+}
+
+impl Default for RiskModel {
+    fn default() -> Self {
+        Self {
+            low_max: 3,
+            medium_max: 6,
+        }
+    }
+}
+
+impl RiskModel {
+    /// Risk score, 1 (safest) to 10
+    pub fn score(&self, factors: &RiskFactors) -> u8 {
+        let mut score: i32 = 5;
+
+        // Deeper pools are harder to drain or move
+        if factors.tvl_usd > 100_000_000.0 {
+            score -= 2;
+        } else if factors.tvl_usd > 10_000_000.0 {
+            score -= 1;
+        } else if factors.tvl_usd < 1_000_000.0 {
+            score += 2;
+        }
+
+        // Near-full utilization means withdrawals can stall
+        if factors.utilization > 0.9 {
+            score += 2;
+        } else if factors.utilization > 0.8 {
+            score += 1;
+        }
+
+        if !factors.audited {
+            score += 2;
+        }
+
+        if factors.protocol_age_days < 90 {
+            score += 2;
+        } else if factors.protocol_age_days < 365 {
+            score += 1;
+        } else if factors.protocol_age_days >= 730 {
+            score -= 1;
+        }
+
+        if factors.il_exposure {
+            score += 2;
+        }
+
+        score.clamp(1, 10) as u8
+    }
+
+    /// Tier of a score
+    pub fn tier(&self, score: u8) -> RiskTier {
+        if score <= self.low_max {
+            RiskTier::Low
+        } else if score <= self.medium_max {
+            RiskTier::Medium
+        } else {
+            RiskTier::High
+        }
+    }
+
+    /// Score and tier together
+    pub fn assess(&self, factors: &RiskFactors) -> (u8, RiskTier) {
+        let score = self.score(factors);
+        (score, self.tier(score))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factors(tvl_usd: f64, utilization: f64) -> RiskFactors {
+        RiskFactors {
+            tvl_usd,
+            utilization,
+            audited: true,
+            protocol_age_days: 400,
+            il_exposure: false,
+        }
+    }
+
+    #[test]
+    fn test_assess_tiers() {
+        let model = RiskModel::default();
+        // Deep, audited, older than a year
+        assert_eq!(
+            model.assess(&factors(200_000_000.0, 0.5)),
+            (3, RiskTier::Low)
+        );
+        assert_eq!(
+            model.assess(&factors(5_000_000.0, 0.5)),
+            (5, RiskTier::Medium)
+        );
+        // Thin, near-full, unaudited LP: clamped to 10
+        let risky = RiskFactors {
+            audited: false,
+            protocol_age_days: 30,
+            il_exposure: true,
+            ..factors(500_000.0, 0.95)
+        };
+        assert_eq!(model.assess(&risky), (10, RiskTier::High));
+    }
+
+    #[test]
+    fn test_age_days_and_tier_parsing() {
+        assert_eq!(RiskFactors::age_days(0, 3 * DAY_SECS + 10), 3);
+        assert_eq!(RiskFactors::age_days(100, 0), 0);
+        assert_eq!(" High ".parse::<RiskTier>(), Ok(RiskTier::High));
+        assert!("extreme".parse::<RiskTier>().is_err());
+    }
+}
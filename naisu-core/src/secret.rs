@@ -0,0 +1,102 @@
+//! Secret values
+//!
+//! `Secret` holds a private key or API token. It prints as `[REDACTED]` in
+//! `Debug` and `Display`, so structs holding one can derive `Debug` safely,
+//! and its bytes are zeroized on drop. Secrets load from `NAME`, or from the
+//! file named by `NAME_FILE` (Docker/Kubernetes secret mounts).
+
+use std::fmt;
+use std::path::Path;
+
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// Shown in place of a secret
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Errors loading a secret
+#[derive(Error, Debug)]
+pub enum SecretError {
+    #[error("{0} must be set (or {0}_FILE)")]
+    Missing(String),
+
+    #[error("Both {0} and {0}_FILE are set")]
+    Ambiguous(String),
+
+    #[error("Failed to read {name} from {path}: {source}")]
+    Read {
+        name: String,
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+/// A key or token that never shows up in logs
+#[derive(Clone)]
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// The secret value; keep the borrow short and never log it
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Read a secret file, without its trailing newline
+    pub fn from_file(name: &str, path: &Path) -> Result<Self, SecretError> {
+        let contents =
+            Zeroizing::new(
+                std::fs::read_to_string(path).map_err(|source| SecretError::Read {
+                    name: name.to_string(),
+                    path: path.display().to_string(),
+                    source,
+                })?,
+            );
+        Ok(Self::new(contents.trim_end_matches(['\r', '\n'])))
+    }
+
+    /// `NAME`, or the file at `NAME_FILE`; `None` if neither is set or the
+    /// value is empty
+    pub fn from_env(name: &str) -> Result<Option<Self>, SecretError> {
+        let file_var = format!("{}_FILE", name);
+        let value = std::env::var(name).ok().filter(|v| !v.is_empty());
+        let file = std::env::var(&file_var).ok().filter(|v| !v.is_empty());
+
+        let secret = match (value, file) {
+            (Some(_), Some(_)) => return Err(SecretError::Ambiguous(name.to_string())),
+            (Some(value), None) => Self::new(value),
+            (None, Some(path)) => Self::from_file(name, Path::new(&path))?,
+            (None, None) => return Ok(None),
+        };
+        Ok(Some(secret).filter(|s| !s.expose().is_empty()))
+    }
+
+    /// Like `from_env`, but the secret must be set
+    pub fn required(name: &str) -> Result<Self, SecretError> {
+        Self::from_env(name)?.ok_or_else(|| SecretError::Missing(name.to_string()))
+    }
+
+    /// Constant-time comparison against a candidate (e.g., a request header)
+    pub fn matches(&self, candidate: &str) -> bool {
+        let (a, b) = (self.0.as_bytes(), candidate.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
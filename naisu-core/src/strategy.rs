@@ -2,8 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::asset::Asset;
+
 /// Available yield strategies on Sui
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
 #[serde(rename_all = "snake_case")]
 pub enum YieldStrategy {
     /// Scallop USDC lending pool
@@ -14,10 +17,34 @@ pub enum YieldStrategy {
     NaviUsdc,
     /// Navi SUI lending pool
     NaviSui,
-    /// Custom strategy (future)
+    /// Strategy from the custom strategy registry
     Custom(u8),
 }
 
+/// IDs up to this one are built-in strategies
+pub const MAX_BUILTIN_STRATEGY_ID: u8 = 4;
+
+/// Symbols treated as stablecoins by stable-only intents
+pub const STABLECOINS: &[&str] = &[
+    "USDC", "USDT", "WUSDC", "WUSDT", "DAI", "AUSD", "BUCK", "USDY",
+];
+
+/// Whether a coin symbol or type (`0x..::usdc::USDC`) is a stablecoin
+pub fn is_stablecoin(coin: &str) -> bool {
+    if let Some(asset) = Asset::resolve(coin) {
+        return asset.is_stable();
+    }
+    let symbol = coin.rsplit("::").next().unwrap_or(coin).trim();
+    STABLECOINS.iter().any(|s| s.eq_ignore_ascii_case(symbol))
+}
+
+/// Whether an asset holds only stablecoins: `USDC`, or an LP pair such as
+/// `USDC-USDT` or `USDC/USDT`
+pub fn is_stable_asset(asset: &str) -> bool {
+    let mut legs = asset.split(['-', '/']).peekable();
+    legs.peek().is_some() && legs.all(is_stablecoin)
+}
+
 impl YieldStrategy {
     /// Get strategy ID for on-chain encoding
     pub fn id(&self) -> u8 {
@@ -70,6 +97,11 @@ impl YieldStrategy {
         }
     }
 
+    /// Whether the strategy only holds stablecoins
+    pub fn is_stable(&self) -> bool {
+        is_stable_asset(self.asset())
+    }
+
     /// Whether this strategy requires a swap on Sui (USDC -> SUI)
     pub fn requires_sui_swap(&self) -> bool {
         matches!(self, YieldStrategy::ScallopSui | YieldStrategy::NaviSui)
@@ -101,3 +133,23 @@ impl StrategyInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_assets() {
+        assert!(is_stablecoin("0xdba3::usdc::USDC"));
+        assert!(!is_stablecoin("0x2::sui::SUI"));
+
+        assert!(is_stable_asset("USDC"));
+        assert!(is_stable_asset("USDC-USDT"));
+        assert!(is_stable_asset("usdc/buck"));
+        assert!(!is_stable_asset("SUI-USDC"));
+        assert!(!is_stable_asset(""));
+
+        assert!(YieldStrategy::NaviUsdc.is_stable());
+        assert!(!YieldStrategy::ScallopSui.is_stable());
+    }
+}
@@ -0,0 +1,154 @@
+//! Input validation
+//!
+//! Amount parsing, address checks and the stateless checks on intent
+//! creation requests. The API and the web frontend (through `naisu-wasm`)
+//! both run these, so they accept and reject the same inputs.
+
+use thiserror::Error;
+
+use crate::intent::{CreateIntentRequest, Direction};
+use crate::strategy::YieldStrategy;
+
+/// Validation errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("Amount must be a positive integer of base units: {0}")]
+    InvalidAmount(String),
+
+    #[error("Amount has more than {0} decimals")]
+    TooManyDecimals(u32),
+
+    #[error("Invalid Sui address: {0}")]
+    InvalidSuiAddress(String),
+
+    #[error("Invalid EVM address: {0}")]
+    InvalidEvmAddress(String),
+
+    #[error("strategy is required for evm_to_sui intents")]
+    MissingStrategy,
+
+    #[error("stable_only intents need a stablecoin strategy")]
+    NotStable,
+
+    #[error("referrer must be a 0x address")]
+    InvalidReferrer,
+
+    #[error("An intent can't be referred by its own wallet")]
+    SelfReferral,
+
+    #[error("tip must be less than input_amount")]
+    TipTooLarge,
+}
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Positive amount in base units (e.g., `"1500000"`)
+pub fn parse_amount(input: &str) -> Result<u64, ValidationError> {
+    let input = input.trim();
+    input
+        .bytes()
+        .all(|b| b.is_ascii_digit())
+        .then(|| input.parse::<u64>().ok())
+        .flatten()
+        .filter(|a| *a > 0)
+        .ok_or_else(|| ValidationError::InvalidAmount(input.to_string()))
+}
+
+/// Positive decimal amount (e.g., `"1.5"`) in base units of a coin with
+/// `decimals` decimals
+pub fn parse_decimal_amount(input: &str, decimals: u32) -> Result<u64, ValidationError> {
+    let input = input.trim();
+    let invalid = || ValidationError::InvalidAmount(input.to_string());
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !(whole.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if fraction.len() > decimals as usize {
+        return Err(ValidationError::TooManyDecimals(decimals));
+    }
+
+    let scale = 10u64.checked_pow(decimals).ok_or_else(invalid)?;
+    let whole: u64 = match whole {
+        "" => 0,
+        whole => whole.parse().map_err(|_| invalid())?,
+    };
+    let fraction: u64 = match fraction {
+        "" => 0,
+        f => {
+            let padded = format!("{:0<width$}", f, width = decimals as usize);
+            padded.parse().map_err(|_| invalid())?
+        }
+    };
+    whole
+        .checked_mul(scale)
+        .and_then(|w| w.checked_add(fraction))
+        .filter(|a| *a > 0)
+        .ok_or_else(invalid)
+}
+
+/// `0x` followed by up to 64 hex digits
+pub fn validate_sui_address(address: &str) -> Result<(), ValidationError> {
+    match address.trim().strip_prefix("0x") {
+        Some(hex) if hex.len() <= 64 && is_hex(hex) => Ok(()),
+        _ => Err(ValidationError::InvalidSuiAddress(address.to_string())),
+    }
+}
+
+/// `0x` followed by 40 hex digits (any checksum casing)
+pub fn validate_evm_address(address: &str) -> Result<(), ValidationError> {
+    match address.trim().strip_prefix("0x") {
+        Some(hex) if hex.len() == 40 && is_hex(hex) => Ok(()),
+        _ => Err(ValidationError::InvalidEvmAddress(address.to_string())),
+    }
+}
+
+impl CreateIntentRequest {
+    /// Checks that need no chain or registry access; returns the amount
+    ///
+    /// Risk tiers and custom strategies are checked by the API, which has
+    /// the strategy registry.
+    pub fn validate(&self) -> Result<u64, ValidationError> {
+        let amount = parse_amount(&self.input_amount)?;
+
+        match self.direction {
+            Direction::EvmToSui => {
+                validate_evm_address(&self.source_address)?;
+                validate_sui_address(&self.dest_address)?;
+                let strategy = self.strategy.ok_or(ValidationError::MissingStrategy)?;
+                let custom = matches!(strategy, YieldStrategy::Custom(_));
+                if self.stable_only && !custom && !strategy.is_stable() {
+                    return Err(ValidationError::NotStable);
+                }
+            }
+            Direction::SuiToEvm => {
+                validate_sui_address(&self.source_address)?;
+                validate_evm_address(&self.dest_address)?;
+            }
+        }
+
+        if let Some(referrer) = self.normalized_referrer() {
+            if !referrer.strip_prefix("0x").is_some_and(is_hex) {
+                return Err(ValidationError::InvalidReferrer);
+            }
+            let own = [&self.source_address, &self.dest_address];
+            if own.iter().any(|a| a.trim().eq_ignore_ascii_case(&referrer)) {
+                return Err(ValidationError::SelfReferral);
+            }
+        }
+
+        if self.tip.is_some_and(|tip| tip.amount(amount) >= amount) {
+            return Err(ValidationError::TipTooLarge);
+        }
+        Ok(amount)
+    }
+
+    /// Referrer, trimmed and lowercased
+    pub fn normalized_referrer(&self) -> Option<String> {
+        self.referrer.as_deref().map(|r| r.trim().to_lowercase())
+    }
+}
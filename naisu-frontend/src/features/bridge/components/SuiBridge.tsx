@@ -30,7 +30,7 @@ const MESSAGE_TRANSMITTER_STATE = '0x98234bd0fa9ac12cc0a20a144a22e36d6a32f7e0a97
 const USDC_TREASURY = '0x7170137d4a6431bf83351ac025baf462909bffe2877d87716374fb42b9629ebe'
 
 // Domain IDs
-const CCTP_DOMAIN_BASE = 5 // Base domain
+const CCTP_DOMAIN_BASE = 6 // Base domain
 
 // EVM MessageTransmitter on Base Sepolia
 const MESSAGE_TRANSMITTER_BASE_SEPOLIA = '0x7865fAfC2db2093669d92c0F33AeEF291086BEFD'
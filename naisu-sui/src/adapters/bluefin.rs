@@ -0,0 +1,227 @@
+//! Bluefin Spot API Adapter
+//!
+//! Fetches CLMM pool data (APR, TVL, pairs) from the Bluefin spot API.
+//!
+//! API Docs: https://bluefin-exchange.readme.io
+
+use async_trait::async_trait;
+use naisu_core::{same_asset, RiskFactors, RiskModel};
+use serde::Deserialize;
+
+use super::{Protocol, RawYieldData, YieldAdapter};
+
+const BLUEFIN_API_BASE: &str = "https://swap.api.sui-prod.bluefin.io/api/v1";
+
+/// Bluefin spot launch, 2024-11-01 (unix seconds)
+const LAUNCHED_AT: i64 = 1_730_419_200;
+
+/// Bluefin adapter for pool data
+#[derive(Debug, Clone)]
+pub struct BluefinAdapter {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+/// Token side of a pool
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenInfo {
+    pub symbol: String,
+    /// Coin type
+    pub address: String,
+}
+
+impl TokenInfo {
+    fn is(&self, asset: &str) -> bool {
+        same_asset(&self.symbol, asset) || same_asset(&self.address, asset)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolApr {
+    /// Percent (e.g., "24.5")
+    pub total: String,
+    #[serde(default)]
+    pub fee_apr: String,
+    #[serde(default)]
+    pub reward_apr: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PoolDayStats {
+    #[serde(default)]
+    pub apr: PoolApr,
+    /// USD traded in the last 24h
+    #[serde(default)]
+    pub volume: String,
+}
+
+/// Bluefin pool data
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolData {
+    /// Pool object ID
+    pub address: String,
+    /// e.g., "SUI-USDC"
+    pub symbol: String,
+    /// Fraction (e.g., "0.0006")
+    pub fee_rate: String,
+    /// USD
+    pub tvl: String,
+    pub token_a: TokenInfo,
+    pub token_b: TokenInfo,
+    #[serde(default)]
+    pub day: PoolDayStats,
+}
+
+impl PoolData {
+    pub fn tvl_usd(&self) -> f64 {
+        self.tvl.parse().unwrap_or(0.0)
+    }
+
+    /// Fee plus reward APR, percent
+    pub fn apr(&self) -> f64 {
+        self.day.apr.total.parse().unwrap_or(0.0)
+    }
+
+    /// Whether the pool trades `a` against `b`, in either order
+    ///
+    /// Each side is a symbol or coin type, matched through the asset
+    /// registry.
+    pub fn is_pair(&self, a: &str, b: &str) -> bool {
+        let (x, y) = (&self.token_a, &self.token_b);
+        (x.is(a) && y.is(b)) || (x.is(b) && y.is(a))
+    }
+}
+
+impl BluefinAdapter {
+    /// Create new Bluefin adapter
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: BLUEFIN_API_BASE.to_string(),
+        }
+    }
+
+    /// Create with custom base URL (for testing)
+    pub fn with_base_url(base_url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+        }
+    }
+
+    /// Fetch all pools from Bluefin
+    pub async fn get_pools(&self) -> Result<Vec<PoolData>, AdapterError> {
+        let url = format!("{}/pools/info", self.base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(AdapterError::ApiError(
+                response.status().to_string(),
+                response.text().await.unwrap_or_default(),
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| AdapterError::ParseError(e.to_string()))
+    }
+
+    /// Deepest pool for a pair (e.g., "SUI", "USDC")
+    pub async fn find_pool(&self, a: &str, b: &str) -> Result<PoolData, AdapterError> {
+        self.get_pools()
+            .await?
+            .into_iter()
+            .filter(|p| p.is_pair(a, b))
+            .max_by(|x, y| x.tvl_usd().total_cmp(&y.tvl_usd()))
+            .ok_or_else(|| AdapterError::PairNotFound(format!("{}-{}", a, b)))
+    }
+
+    /// Risk score from the shared model (1-10, lower is safer)
+    fn calculate_risk_score(&self, pool: &PoolData) -> u8 {
+        RiskModel::default().score(&RiskFactors {
+            tvl_usd: pool.tvl_usd(),
+            utilization: 0.0,
+            audited: true,
+            protocol_age_days: RiskFactors::age_days(LAUNCHED_AT, chrono::Utc::now().timestamp()),
+            il_exposure: true,
+        })
+    }
+}
+
+impl Default for BluefinAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl YieldAdapter for BluefinAdapter {
+    fn protocol(&self) -> Protocol {
+        Protocol::Bluefin
+    }
+
+    async fn opportunities(&self) -> Result<Vec<RawYieldData>, super::AdapterError> {
+        Ok(self
+            .get_pools()
+            .await?
+            .iter()
+            .map(|p| RawYieldData {
+                asset: format!("{}-{}", p.token_a.symbol, p.token_b.symbol),
+                apy: p.apr(),
+                tvl_usd: p.tvl_usd(),
+                liquidity_usd: p.tvl_usd(),
+                risk_score: self.calculate_risk_score(p),
+            })
+            .collect())
+    }
+}
+
+/// Adapter errors
+#[derive(Debug, thiserror::Error)]
+pub enum AdapterError {
+    #[error("HTTP request failed: {0}")]
+    RequestFailed(String),
+
+    #[error("API error {0}: {1}")]
+    ApiError(String, String),
+
+    #[error("Failed to parse response: {0}")]
+    ParseError(String),
+
+    #[error("Pair not found: {0}")]
+    PairNotFound(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pool() {
+        let pool: PoolData = serde_json::from_value(serde_json::json!({
+            "address": "0xpool",
+            "symbol": "SUI-USDC",
+            "feeRate": "0.0006",
+            "tvl": "25000000",
+            "tokenA": { "symbol": "SUI", "address": "0x2::sui::SUI" },
+            "tokenB": { "symbol": "USDC", "address": "0xdba3::usdc::USDC" },
+            "day": { "apr": { "total": "24.5", "feeApr": "20.1" }, "volume": "9000000" }
+        }))
+        .unwrap();
+
+        assert_eq!(pool.apr(), 24.5);
+        assert!(pool.is_pair("usdc", "SUI"));
+        assert!(!pool.is_pair("SUI", "USDT"));
+        assert!(BluefinAdapter::new().calculate_risk_score(&pool) >= 1);
+    }
+}
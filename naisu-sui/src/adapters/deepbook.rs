@@ -0,0 +1,263 @@
+//! DeepBook Order Book Adapter
+//!
+//! Reads level-2 depth from the DeepBook indexer and derives mid price,
+//! spread and the slippage of filling a given size, for pricing
+//! market-making yield.
+//!
+//! API Docs: https://docs.sui.io/standards/deepbookv3-indexer
+
+use serde::{Deserialize, Serialize};
+
+const DEEPBOOK_MAINNET_API: &str = "https://deepbook-indexer.mainnet.mystenlabs.com";
+const DEEPBOOK_TESTNET_API: &str = "https://deepbook-indexer.testnet.mystenlabs.com";
+
+/// SUI/USDC pool name on the indexer
+pub const SUI_USDC_POOL: &str = "SUI_USDC";
+
+/// Price levels fetched per side by default
+pub const DEFAULT_DEPTH: usize = 20;
+
+/// DeepBook indexer adapter
+#[derive(Debug, Clone)]
+pub struct DeepBookAdapter {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+/// Indexer `/orderbook` response; levels are `[price, quantity]` strings
+#[derive(Debug, Clone, Deserialize)]
+struct RawOrderBook {
+    /// Unix millis, as a string or number
+    #[serde(default)]
+    timestamp: Option<serde_json::Value>,
+    bids: Vec<(String, String)>,
+    asks: Vec<(String, String)>,
+}
+
+/// One price level (human units: USDC per SUI, SUI)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Level {
+    pub price: f64,
+    pub quantity: f64,
+}
+
+/// Taker direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    /// Lift asks
+    Buy,
+    /// Hit bids
+    Sell,
+}
+
+/// Level-2 book: bids best (highest) first, asks best (lowest) first
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderBook {
+    pub pool: String,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+    /// Unix millis, if the indexer reported it
+    pub timestamp: Option<u64>,
+}
+
+impl OrderBook {
+    pub fn new(pool: impl Into<String>, mut bids: Vec<Level>, mut asks: Vec<Level>) -> Self {
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+        Self {
+            pool: pool.into(),
+            bids,
+            asks,
+            timestamp: None,
+        }
+    }
+
+    pub fn best_bid(&self) -> Option<f64> {
+        self.bids.first().map(|l| l.price)
+    }
+
+    pub fn best_ask(&self) -> Option<f64> {
+        self.asks.first().map(|l| l.price)
+    }
+
+    pub fn mid_price(&self) -> Option<f64> {
+        Some((self.best_bid()? + self.best_ask()?) / 2.0)
+    }
+
+    /// Best ask over best bid, in bps of mid
+    pub fn spread_bps(&self) -> Option<f64> {
+        let spread = self.best_ask()? - self.best_bid()?;
+        Some(spread / self.mid_price()? * 10_000.0)
+    }
+
+    /// Average price of filling `size` SUI as a taker
+    ///
+    /// `None` if the fetched depth can't fill it.
+    pub fn fill_price(&self, side: Side, size: f64) -> Option<f64> {
+        let levels = match side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        };
+        if size <= 0.0 {
+            return levels.first().map(|l| l.price);
+        }
+
+        let mut remaining = size;
+        let mut cost = 0.0;
+        for level in levels {
+            let take = remaining.min(level.quantity);
+            cost += take * level.price;
+            remaining -= take;
+            if remaining <= 0.0 {
+                return Some(cost / size);
+            }
+        }
+        None
+    }
+
+    /// How far filling `size` lands from mid, in bps (always positive)
+    pub fn slippage_bps(&self, side: Side, size: f64) -> Option<f64> {
+        let mid = self.mid_price()?;
+        let fill = self.fill_price(side, size)?;
+        Some((fill - mid).abs() / mid * 10_000.0)
+    }
+
+    /// Yearly return of quoting `size` on both sides, as a fraction
+    ///
+    /// Each round trip earns the spread, less the price impact of a
+    /// position that large; capital turns over `turns_per_day` times.
+    pub fn market_making_apy(&self, size: f64, turns_per_day: f64) -> Option<f64> {
+        let impact = self.slippage_bps(Side::Buy, size)? + self.slippage_bps(Side::Sell, size)?;
+        let top = self.slippage_bps(Side::Buy, 0.0)? + self.slippage_bps(Side::Sell, 0.0)?;
+        // Deeper levels are farther from mid: that part of the fill is worse
+        let edge_bps = (self.spread_bps()? - (impact - top)).max(0.0);
+        Some(edge_bps / 10_000.0 * turns_per_day * 365.0)
+    }
+}
+
+fn parse_level((price, quantity): &(String, String)) -> Option<Level> {
+    Some(Level {
+        price: price.parse().ok()?,
+        quantity: quantity.parse().ok()?,
+    })
+}
+
+impl DeepBookAdapter {
+    /// Create adapter for the mainnet indexer
+    pub fn new() -> Self {
+        Self::with_base_url(DEEPBOOK_MAINNET_API.to_string())
+    }
+
+    /// Create adapter for the testnet indexer
+    pub fn testnet() -> Self {
+        Self::with_base_url(DEEPBOOK_TESTNET_API.to_string())
+    }
+
+    /// Create with custom base URL (for testing)
+    pub fn with_base_url(base_url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+        }
+    }
+
+    /// Fetch up to `depth` levels per side of a pool (e.g. `SUI_USDC`)
+    pub async fn get_order_book(
+        &self,
+        pool: &str,
+        depth: usize,
+    ) -> Result<OrderBook, AdapterError> {
+        let url = format!(
+            "{}/orderbook/{}?level=2&depth={}",
+            self.base_url, pool, depth
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(AdapterError::ApiError(
+                response.status().to_string(),
+                response.text().await.unwrap_or_default(),
+            ));
+        }
+
+        let raw: RawOrderBook = response
+            .json()
+            .await
+            .map_err(|e| AdapterError::ParseError(e.to_string()))?;
+
+        let mut book = OrderBook::new(
+            pool,
+            raw.bids.iter().filter_map(parse_level).collect(),
+            raw.asks.iter().filter_map(parse_level).collect(),
+        );
+        book.timestamp = raw
+            .timestamp
+            .and_then(|t| t.as_u64().or_else(|| t.as_str()?.parse().ok()));
+        if book.bids.is_empty() || book.asks.is_empty() {
+            return Err(AdapterError::EmptyBook(pool.to_string()));
+        }
+        Ok(book)
+    }
+}
+
+impl Default for DeepBookAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adapter errors
+#[derive(Debug, thiserror::Error)]
+pub enum AdapterError {
+    #[error("HTTP request failed: {0}")]
+    RequestFailed(String),
+
+    #[error("API error {0}: {1}")]
+    ApiError(String, String),
+
+    #[error("Failed to parse response: {0}")]
+    ParseError(String),
+
+    #[error("Order book is empty: {0}")]
+    EmptyBook(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, quantity: f64) -> Level {
+        Level { price, quantity }
+    }
+
+    #[test]
+    fn test_book_metrics() {
+        let book = OrderBook::new(
+            SUI_USDC_POOL,
+            vec![level(2.998, 100.0), level(2.999, 50.0)],
+            vec![level(3.003, 100.0), level(3.001, 50.0)],
+        );
+        assert_eq!(book.best_bid(), Some(2.999));
+        assert_eq!(book.best_ask(), Some(3.001));
+        assert_eq!(book.mid_price(), Some(3.0));
+        assert!((book.spread_bps().unwrap() - 6.6667).abs() < 1e-3);
+
+        // 50 at 3.001, 50 at 3.003
+        assert!((book.fill_price(Side::Buy, 100.0).unwrap() - 3.002).abs() < 1e-9);
+        assert!((book.slippage_bps(Side::Buy, 100.0).unwrap() - 6.6667).abs() < 1e-3);
+        assert_eq!(book.fill_price(Side::Sell, 500.0), None);
+
+        let small = book.market_making_apy(10.0, 1.0).unwrap();
+        let large = book.market_making_apy(140.0, 1.0).unwrap();
+        assert!((small - 6.6667 / 10_000.0 * 365.0).abs() < 1e-4);
+        assert!(large < small);
+    }
+}
@@ -3,9 +3,18 @@
 //! Provides unified interface for querying yield data from:
 //! - Scallop (scallop.io)
 //! - Navi (navi.ag)
+//! - Bluefin (bluefin.io) and Turbos (turbos.finance) pools
+//!
+//! and DeepBook order book depth, for pricing market making. `router`
+//! splits a deposit across them.
+//!
+//! Each yield protocol sits behind a Cargo feature of the same name (all
+//! on by default); DeepBook is always compiled.
 //!
 //! # Example
 //! ```rust
+//! # #[cfg(all(feature = "scallop", feature = "navi"))]
+//! # mod example {
 //! use naisu_sui::adapters::{ScallopAdapter, NaviAdapter, YieldComparator};
 //!
 //! async fn find_best_yield() {
@@ -17,16 +26,41 @@
 //!     
 //!     println!("Best APY: {} at {}", best.apy, best.protocol);
 //! }
+//! # }
 //! ```
 
+#[cfg(feature = "bluefin")]
+pub mod bluefin;
+pub mod deepbook;
+#[cfg(any(feature = "scallop", feature = "navi"))]
+mod move_json;
+#[cfg(feature = "navi")]
 pub mod navi;
+pub mod router;
+#[cfg(feature = "scallop")]
 pub mod scallop;
-
-pub use navi::{NaviAdapter, YieldOpportunity as NaviYield};
+#[cfg(feature = "turbos")]
+pub mod turbos;
+
+#[cfg(feature = "bluefin")]
+pub use bluefin::BluefinAdapter;
+pub use deepbook::{DeepBookAdapter, Level, OrderBook, Side, SUI_USDC_POOL};
+#[cfg(feature = "navi")]
+pub use navi::{DataSource, NaviAdapter, YieldOpportunity as NaviYield};
+pub use router::{ExecutionPlan, RouteLeg, RouteLimits};
+#[cfg(feature = "scallop")]
 pub use scallop::{ScallopAdapter, YieldOpportunity as ScallopYield};
+#[cfg(feature = "turbos")]
+pub use turbos::TurbosAdapter;
 
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use naisu_core::{is_stable_asset, same_asset, Asset, RiskModel, RiskTier};
 use serde::Serialize;
 
+use crate::client::SuiClient;
+
 /// Raw yield data (protocol-agnostic)
 #[derive(Debug, Clone)]
 pub struct RawYieldData {
@@ -46,14 +80,63 @@ pub struct UnifiedYield {
     pub tvl_usd: f64,
     pub liquidity_usd: f64,
     pub risk_score: u8,
+    pub risk_tier: RiskTier,
     pub score: f64, // Composite score for ranking
+    /// How `score` adds up
+    pub score_breakdown: ScoreBreakdown,
+}
+
+/// Components of a composite score: `score` is the points minus the
+/// penalties
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreBreakdown {
+    /// 5 per APY percent
+    pub apy_points: f64,
+    /// 3 per step below the riskiest score (10)
+    pub safety_points: f64,
+    /// TVL (or available liquidity) in tens of millions USD, up to 20
+    pub liquidity_points: f64,
+    pub penalties: Vec<ScorePenalty>,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> f64 {
+        self.apy_points + self.safety_points + self.liquidity_points
+            - self.penalties.iter().map(|p| p.points).sum::<f64>()
+    }
+}
+
+/// Why points were taken off a score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PenaltyReason {
+    /// Less than `THIN_LIQUIDITY_USD` can be withdrawn
+    ThinLiquidity,
+    /// APY above `APY_OUTLIER_PCT`, usually short-lived incentives; the
+    /// excess earns no points
+    ApyOutlier,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScorePenalty {
+    pub reason: PenaltyReason,
+    pub points: f64,
+}
+
+/// Available liquidity below this is penalized (USD)
+pub const THIN_LIQUIDITY_USD: f64 = 100_000.0;
+/// Points taken off for thin liquidity
+const THIN_LIQUIDITY_PENALTY: f64 = 10.0;
+/// APY above this (percent) earns no further points
+pub const APY_OUTLIER_PCT: f64 = 50.0;
+
 /// Supported protocols
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum Protocol {
     Scallop,
     Navi,
+    Bluefin,
+    Turbos,
 }
 
 impl std::fmt::Display for Protocol {
@@ -61,14 +144,63 @@ impl std::fmt::Display for Protocol {
         match self {
             Protocol::Scallop => write!(f, "Scallop"),
             Protocol::Navi => write!(f, "Navi"),
+            Protocol::Bluefin => write!(f, "Bluefin"),
+            Protocol::Turbos => write!(f, "Turbos"),
+        }
+    }
+}
+
+/// Supply side of a lending pool, enough to project a deposit's impact
+///
+/// A deposit lowers utilization, and with it the APY lenders earn. Adapters
+/// that know a pool's rate curve run the new utilization through it; the
+/// rest fall back to `diluted_apy`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LendingPool {
+    /// Current supply APY (percent)
+    pub supply_apy: f64,
+    /// Supplied and borrowed, in whole tokens
+    pub supplied: f64,
+    pub borrowed: f64,
+}
+
+impl LendingPool {
+    /// Utilization once `deposit` more is supplied
+    pub fn utilization_after(&self, deposit: f64) -> f64 {
+        let supplied = self.supplied + deposit;
+        if supplied > 0.0 {
+            (self.borrowed / supplied).min(1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Supply APY once `deposit` is supplied, with the borrow rate held
+    /// flat: the same interest split over a larger supply
+    ///
+    /// Borrow rates also fall with utilization, so this is an upper bound.
+    pub fn diluted_apy(&self, deposit: f64) -> f64 {
+        let supplied = self.supplied + deposit;
+        if supplied > 0.0 {
+            self.supply_apy * self.supplied / supplied
+        } else {
+            0.0
         }
     }
 }
 
+/// A protocol's source of yield data, registered with `YieldComparator`
+#[async_trait]
+pub trait YieldAdapter: Send + Sync {
+    fn protocol(&self) -> Protocol;
+
+    /// Every opportunity the protocol offers (APY in percent)
+    async fn opportunities(&self) -> Result<Vec<RawYieldData>, AdapterError>;
+}
+
 /// Yield comparator for finding optimal routes
 pub struct YieldComparator {
-    scallop: ScallopAdapter,
-    navi: NaviAdapter,
+    adapters: Vec<Box<dyn YieldAdapter>>,
 }
 
 /// User preferences for yield optimization
@@ -76,14 +208,109 @@ pub struct YieldComparator {
 pub struct YieldPreferences {
     pub min_apy: Option<f64>,
     pub max_risk: Option<u8>, // 1-10
+    pub max_tier: Option<RiskTier>,
     pub min_tvl_usd: Option<f64>,
     pub prefer_liquidity: bool,
+    /// Stablecoin lending and stable-stable pools only
+    pub stable_only: bool,
+}
+
+impl YieldPreferences {
+    /// Whether an opportunity meets every preference set
+    pub fn accepts(&self, o: &UnifiedYield) -> bool {
+        if let Some(min_apy) = self.min_apy {
+            if o.apy < min_apy {
+                return false;
+            }
+        }
+        if let Some(max_risk) = self.max_risk {
+            if o.risk_score > max_risk {
+                return false;
+            }
+        }
+        if self.max_tier.is_some_and(|max| o.risk_tier > max) {
+            return false;
+        }
+        if self.stable_only && !is_stable_asset(&o.asset) {
+            return false;
+        }
+        if let Some(min_tvl) = self.min_tvl_usd {
+            if o.tvl_usd < min_tvl {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 impl YieldComparator {
     /// Create new comparator with adapters
+    #[cfg(all(feature = "scallop", feature = "navi"))]
     pub fn new(scallop: ScallopAdapter, navi: NaviAdapter) -> Self {
-        Self { scallop, navi }
+        Self::empty().with_adapter(scallop).with_adapter(navi)
+    }
+
+    /// Comparator with no protocols registered
+    pub fn empty() -> Self {
+        Self {
+            adapters: Vec::new(),
+        }
+    }
+
+    /// Lending markets plus Bluefin and Turbos pools, as far as compiled in
+    pub fn mainnet() -> Self {
+        Self::mainnet_adapters(None)
+    }
+
+    /// Same as `mainnet`, reading Scallop's market and Navi's storage on
+    /// chain via `sui` (a mainnet client) when their APIs are down
+    pub fn mainnet_with_chain(sui: Arc<SuiClient>) -> Self {
+        Self::mainnet_adapters(Some(sui))
+    }
+
+    #[cfg_attr(
+        not(any(feature = "scallop", feature = "navi")),
+        allow(unused_variables)
+    )]
+    fn mainnet_adapters(sui: Option<Arc<SuiClient>>) -> Self {
+        #[allow(unused_mut)]
+        let mut comparator = Self::empty();
+        #[cfg(feature = "scallop")]
+        {
+            let scallop = match &sui {
+                Some(sui) => ScallopAdapter::new().with_onchain_fallback(sui.clone()),
+                None => ScallopAdapter::new(),
+            };
+            comparator = comparator.with_adapter(scallop);
+        }
+        #[cfg(feature = "navi")]
+        {
+            let navi = match &sui {
+                Some(sui) => NaviAdapter::new().with_onchain_fallback(sui.clone()),
+                None => NaviAdapter::new(),
+            };
+            comparator = comparator.with_adapter(navi);
+        }
+        #[cfg(feature = "bluefin")]
+        {
+            comparator = comparator.with_adapter(BluefinAdapter::new());
+        }
+        #[cfg(feature = "turbos")]
+        {
+            comparator = comparator.with_adapter(TurbosAdapter::new());
+        }
+        comparator
+    }
+
+    /// Register another protocol
+    pub fn with_adapter(mut self, adapter: impl YieldAdapter + 'static) -> Self {
+        self.adapters.push(Box::new(adapter));
+        self
+    }
+
+    /// Protocols compared, in registration order
+    pub fn protocols(&self) -> Vec<Protocol> {
+        self.adapters.iter().map(|a| a.protocol()).collect()
     }
 
     /// Find best yield for a specific asset
@@ -106,24 +333,7 @@ impl YieldComparator {
 
         let filtered: Vec<_> = opportunities
             .into_iter()
-            .filter(|o| {
-                if let Some(min_apy) = prefs.min_apy {
-                    if o.apy < min_apy {
-                        return false;
-                    }
-                }
-                if let Some(max_risk) = prefs.max_risk {
-                    if o.risk_score > max_risk {
-                        return false;
-                    }
-                }
-                if let Some(min_tvl) = prefs.min_tvl_usd {
-                    if o.tvl_usd < min_tvl {
-                        return false;
-                    }
-                }
-                true
-            })
+            .filter(|o| prefs.accepts(o))
             .collect();
 
         if filtered.is_empty() {
@@ -140,63 +350,17 @@ impl YieldComparator {
 
     /// Compare yields across all protocols for an asset
     pub async fn compare_asset(&self, asset: &str) -> Result<Vec<UnifiedYield>, AdapterError> {
-        let mut opportunities = Vec::new();
-
-        // Fetch from Scallop
-        match self.scallop.get_yield_opportunity(asset).await {
-            Ok(opp) => {
-                let raw = RawYieldData {
-                    asset: opp.asset,
-                    apy: opp.apy,
-                    tvl_usd: opp.tvl_usd,
-                    liquidity_usd: opp.liquidity_usd,
-                    risk_score: opp.risk_score,
-                };
-                let score = Self::calculate_score(&raw, false);
-                opportunities.push(UnifiedYield {
-                    protocol: Protocol::Scallop,
-                    asset: raw.asset,
-                    apy: raw.apy,
-                    tvl_usd: raw.tvl_usd,
-                    liquidity_usd: raw.liquidity_usd,
-                    risk_score: raw.risk_score,
-                    score,
-                });
-            }
-            Err(e) => tracing::warn!("Failed to fetch Scallop data: {}", e),
-        }
-
-        // Fetch from Navi
-        match self.navi.get_yield_opportunity(asset).await {
-            Ok(opp) => {
-                let raw = RawYieldData {
-                    asset: opp.asset,
-                    apy: opp.apy,
-                    tvl_usd: opp.tvl_usd,
-                    liquidity_usd: opp.liquidity_usd,
-                    risk_score: opp.risk_score,
-                };
-                let score = Self::calculate_score(&raw, false);
-                opportunities.push(UnifiedYield {
-                    protocol: Protocol::Navi,
-                    asset: raw.asset,
-                    apy: raw.apy,
-                    tvl_usd: raw.tvl_usd,
-                    liquidity_usd: raw.liquidity_usd,
-                    risk_score: raw.risk_score,
-                    score,
-                });
-            }
-            Err(e) => tracing::warn!("Failed to fetch Navi data: {}", e),
-        }
+        let opportunities: Vec<_> = self
+            .get_all_opportunities()
+            .await?
+            .into_iter()
+            .filter(|o| same_asset(&o.asset, asset))
+            .collect();
 
         if opportunities.is_empty() {
             return Err(AdapterError::NoOpportunities(asset.to_string()));
         }
 
-        // Sort by score descending
-        opportunities.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-
         Ok(opportunities)
     }
 
@@ -204,56 +368,25 @@ impl YieldComparator {
     pub async fn get_all_opportunities(&self) -> Result<Vec<UnifiedYield>, AdapterError> {
         let mut all = Vec::new();
 
-        // Fetch all from Scallop
-        match self.scallop.get_all_opportunities().await {
-            Ok(opps) => {
-                for opp in opps {
-                    let raw = RawYieldData {
-                        asset: opp.asset,
-                        apy: opp.apy,
-                        tvl_usd: opp.tvl_usd,
-                        liquidity_usd: opp.liquidity_usd,
-                        risk_score: opp.risk_score,
-                    };
-                    let score = Self::calculate_score(&raw, false);
-                    all.push(UnifiedYield {
-                        protocol: Protocol::Scallop,
-                        asset: raw.asset,
-                        apy: raw.apy,
-                        tvl_usd: raw.tvl_usd,
-                        liquidity_usd: raw.liquidity_usd,
-                        risk_score: raw.risk_score,
-                        score,
-                    });
-                }
-            }
-            Err(e) => tracing::warn!("Failed to fetch all Scallop data: {}", e),
-        }
-
-        // Fetch all from Navi
-        match self.navi.get_all_opportunities().await {
-            Ok(opps) => {
-                for opp in opps {
-                    let raw = RawYieldData {
-                        asset: opp.asset,
-                        apy: opp.apy,
-                        tvl_usd: opp.tvl_usd,
-                        liquidity_usd: opp.liquidity_usd,
-                        risk_score: opp.risk_score,
-                    };
-                    let score = Self::calculate_score(&raw, false);
-                    all.push(UnifiedYield {
-                        protocol: Protocol::Navi,
-                        asset: raw.asset,
+        for adapter in &self.adapters {
+            let protocol = adapter.protocol();
+            match adapter.opportunities().await {
+                Ok(opps) => all.extend(opps.into_iter().map(|raw| {
+                    let score_breakdown = Self::score_breakdown(&raw, false);
+                    UnifiedYield {
+                        protocol,
+                        asset: Asset::normalize(&raw.asset),
                         apy: raw.apy,
                         tvl_usd: raw.tvl_usd,
                         liquidity_usd: raw.liquidity_usd,
                         risk_score: raw.risk_score,
-                        score,
-                    });
-                }
+                        risk_tier: RiskModel::default().tier(raw.risk_score),
+                        score: score_breakdown.total(),
+                        score_breakdown,
+                    }
+                })),
+                Err(e) => tracing::warn!("Failed to fetch {} data: {}", protocol, e),
             }
-            Err(e) => tracing::warn!("Failed to fetch all Navi data: {}", e),
         }
 
         // Sort by score
@@ -262,31 +395,63 @@ impl YieldComparator {
         Ok(all)
     }
 
-    /// Calculate composite score for ranking
+    /// Composite score for ranking
     /// Weights: APY (50%), Safety (30%), Liquidity (20%)
-    fn calculate_score(opp: &RawYieldData, prefer_liquidity: bool) -> f64 {
-        let apy_score = opp.apy * 5.0; // 8% APY = 40 points
-        let safety_score = (11.0 - opp.risk_score as f64) * 3.0; // Risk 3 = 24 points
+    pub fn score_breakdown(opp: &RawYieldData, prefer_liquidity: bool) -> ScoreBreakdown {
+        let apy_points = opp.apy * 5.0; // 8% APY = 40 points
+        let safety_points = (11.0 - opp.risk_score as f64) * 3.0; // Risk 3 = 24 points
 
-        let liquidity_score = if prefer_liquidity {
+        let liquidity_points = if prefer_liquidity {
             (opp.liquidity_usd / 1_000_000.0).min(20.0) // Cap at 20 points
         } else {
             (opp.tvl_usd / 10_000_000.0).min(20.0) // Cap at 20 points
         };
 
-        apy_score + safety_score + liquidity_score
+        let mut penalties = Vec::new();
+        if opp.liquidity_usd < THIN_LIQUIDITY_USD {
+            penalties.push(ScorePenalty {
+                reason: PenaltyReason::ThinLiquidity,
+                points: THIN_LIQUIDITY_PENALTY,
+            });
+        }
+        if opp.apy > APY_OUTLIER_PCT {
+            penalties.push(ScorePenalty {
+                reason: PenaltyReason::ApyOutlier,
+                points: (opp.apy - APY_OUTLIER_PCT) * 5.0,
+            });
+        }
+
+        ScoreBreakdown {
+            apy_points,
+            safety_points,
+            liquidity_points,
+            penalties,
+        }
     }
 }
 
 /// Unified adapter error
 #[derive(Debug, thiserror::Error)]
 pub enum AdapterError {
+    #[cfg(feature = "scallop")]
     #[error("Scallop adapter error: {0}")]
     Scallop(#[from] scallop::AdapterError),
 
+    #[cfg(feature = "navi")]
     #[error("Navi adapter error: {0}")]
     Navi(#[from] navi::AdapterError),
 
+    #[cfg(feature = "bluefin")]
+    #[error("Bluefin adapter error: {0}")]
+    Bluefin(#[from] bluefin::AdapterError),
+
+    #[cfg(feature = "turbos")]
+    #[error("Turbos adapter error: {0}")]
+    Turbos(#[from] turbos::AdapterError),
+
+    #[error("DeepBook adapter error: {0}")]
+    DeepBook(#[from] deepbook::AdapterError),
+
     #[error("No opportunities found for {0}")]
     NoOpportunities(String),
 
@@ -298,9 +463,63 @@ pub enum AdapterError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_lending_pool_dilution() {
+        let pool = LendingPool {
+            supply_apy: 8.0,
+            supplied: 1_000_000.0,
+            borrowed: 600_000.0,
+        };
+        assert!((pool.utilization_after(0.0) - 0.6).abs() < 1e-12);
+        assert!((pool.utilization_after(200_000.0) - 0.5).abs() < 1e-12);
+        // A deposit the size of the pool halves the APY
+        assert!((pool.diluted_apy(1_000_000.0) - 4.0).abs() < 1e-12);
+        assert_eq!(pool.diluted_apy(0.0), 8.0);
+    }
+
+    #[test]
+    fn test_score_breakdown() {
+        let raw = |apy: f64, liquidity_usd: f64| RawYieldData {
+            asset: "USDC".to_string(),
+            apy,
+            tvl_usd: 50_000_000.0,
+            liquidity_usd,
+            risk_score: 3,
+        };
+
+        let healthy = YieldComparator::score_breakdown(&raw(8.0, 5_000_000.0), false);
+        assert_eq!(healthy.apy_points, 40.0);
+        assert_eq!(healthy.safety_points, 24.0);
+        assert_eq!(healthy.liquidity_points, 5.0);
+        assert!(healthy.penalties.is_empty());
+        assert_eq!(healthy.total(), 69.0);
+
+        // The APY above 50% earns nothing, and thin liquidity costs 10
+        let farm = YieldComparator::score_breakdown(&raw(80.0, 20_000.0), false);
+        let reasons: Vec<_> = farm.penalties.iter().map(|p| p.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![PenaltyReason::ThinLiquidity, PenaltyReason::ApyOutlier]
+        );
+        assert_eq!(farm.total(), 250.0 + 24.0 + 5.0 - 10.0);
+    }
+
     #[test]
     fn test_protocol_display() {
         assert_eq!(Protocol::Scallop.to_string(), "Scallop");
         assert_eq!(Protocol::Navi.to_string(), "Navi");
+        assert_eq!(Protocol::Bluefin.to_string(), "Bluefin");
+
+        // Registration order, minus protocols compiled out
+        let expected: Vec<_> = [
+            (Protocol::Scallop, cfg!(feature = "scallop")),
+            (Protocol::Navi, cfg!(feature = "navi")),
+            (Protocol::Bluefin, cfg!(feature = "bluefin")),
+            (Protocol::Turbos, cfg!(feature = "turbos")),
+        ]
+        .into_iter()
+        .filter_map(|(protocol, compiled)| compiled.then_some(protocol))
+        .collect();
+        assert_eq!(YieldComparator::mainnet().protocols(), expected);
     }
 }
@@ -0,0 +1,35 @@
+//! Reading Move structs from object JSON
+//!
+//! JSON-RPC nests struct fields under `fields`; GraphQL renders them flat.
+//! Numbers wider than u32 arrive as strings.
+
+use serde_json::Value;
+
+/// Field of a Move struct, nested or flat
+pub(crate) fn move_field<'a>(value: &'a Value, name: &str) -> &'a Value {
+    match value.get("fields") {
+        Some(fields) => &fields[name],
+        None => &value[name],
+    }
+}
+
+/// u64 rendered as a string or a number
+pub(crate) fn move_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        other => other.as_u64(),
+    }
+}
+
+/// u128/u256 as a float (precision beyond 2^53 is dropped)
+pub(crate) fn move_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        other => other.as_f64(),
+    }
+}
+
+/// ID held by a `UID` (`{"id": ..}`) or already flattened to a string
+pub(crate) fn uid(value: &Value) -> Option<&str> {
+    value.as_str().or_else(|| value["id"].as_str())
+}
@@ -2,17 +2,57 @@
 //!
 //! Fetches yield data from Navi API for AI Agent optimization.
 //!
+//! With `with_onchain_fallback`, an API outage falls back to Navi's
+//! `Storage` object: every reserve's rates, indices and caps are read via
+//! `SuiClient`. Each `ReserveData` records which source it came from.
+//!
+//! `projected_supply_apy` estimates the APY after a deposit. Only the chain
+//! carries a reserve's rate curve, so the projection reads storage when it
+//! can and otherwise dilutes the API's APY.
+//!
 //! API Docs: https://docs.navi.ag
 
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use naisu_core::{is_stable_asset, same_asset, Asset, RiskFactors, RiskModel};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::move_json::{move_f64, move_field, move_u64, uid};
+use super::{LendingPool, Protocol, RawYieldData, YieldAdapter};
+use crate::client::SuiClient;
 
 const NAVI_API_BASE: &str = "https://api.navi.ag/v1";
 
+/// Sui mainnet launch, 2023-07-01 (unix seconds)
+const LAUNCHED_AT: i64 = 1_688_169_600;
+
+/// Navi `Storage` shared object on mainnet
+pub const MAINNET_STORAGE: &str =
+    "0xbb4e2f4b6205c2e2a2db47aeb4f830796ec7c005f88537ee775986639bc442fe";
+
+/// Navi keeps rates, indices and ratios in ray (1e27)
+const RAY: f64 = 1e27;
+
+/// Navi normalizes every balance to 9 decimals
+const NORMALIZED_DECIMALS: f64 = 1e9;
+
 /// Navi protocol adapter for yield data
 #[derive(Debug, Clone)]
 pub struct NaviAdapter {
     client: reqwest::Client,
     base_url: String,
+    onchain: Option<StorageReader>,
+}
+
+/// Where reserve data came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSource {
+    #[default]
+    Api,
+    Chain,
 }
 
 /// Navi pool/reserve data
@@ -28,6 +68,91 @@ pub struct ReserveData {
     pub price_usd: f64,
     pub ltv: f64,
     pub liquidation_threshold: f64,
+    /// Supply cap in tokens (`None` when uncapped or unknown)
+    #[serde(default)]
+    pub supply_cap: Option<f64>,
+    /// Borrow cap as a share of supply
+    #[serde(default)]
+    pub borrow_cap: Option<f64>,
+    #[serde(default)]
+    pub source: DataSource,
+    /// Rate curve, read from chain only
+    #[serde(skip)]
+    pub rate_factors: Option<BorrowRateFactors>,
+}
+
+impl ReserveData {
+    /// Whether this is the reserve of `asset` (symbol or coin type)
+    pub fn is(&self, asset: &str) -> bool {
+        same_asset(&self.symbol, asset) || same_asset(&self.asset, asset)
+    }
+
+    /// Supply side of the reserve, in whole tokens
+    pub fn lending_pool(&self) -> LendingPool {
+        let supplied = self.total_supply.parse().unwrap_or(0.0);
+        LendingPool {
+            supply_apy: self.supply_apy,
+            supplied,
+            borrowed: supplied * self.utilization_rate,
+        }
+    }
+
+    /// Supply APY once `amount` (whole tokens) more is supplied, along the
+    /// rate curve when known
+    pub fn projected_supply_apy(&self, amount: f64) -> f64 {
+        let pool = self.lending_pool();
+        match &self.rate_factors {
+            Some(factors) => {
+                let utilization = pool.utilization_after(amount);
+                factors.supply_rate(utilization).exp_m1() * 100.0
+            }
+            None => pool.diluted_apy(amount),
+        }
+    }
+}
+
+/// A reserve's `BorrowRateFactors` (annual rates)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorrowRateFactors {
+    pub base_rate: f64,
+    /// Slope below the optimal utilization
+    pub multiplier: f64,
+    /// Slope above it
+    pub jump_rate_multiplier: f64,
+    /// Share of interest kept by the protocol
+    pub reserve_factor: f64,
+    pub optimal_utilization: f64,
+}
+
+impl BorrowRateFactors {
+    /// Parse the `Factors` inside a `BorrowRateFactors` struct
+    fn from_move(value: &Value) -> Option<Self> {
+        let factors = move_field(value, "value");
+        let ray = |name: &str| Some(move_f64(move_field(factors, name))? / RAY);
+        Some(Self {
+            base_rate: ray("base_rate")?,
+            multiplier: ray("multiplier")?,
+            jump_rate_multiplier: ray("jump_rate_multiplier")?,
+            reserve_factor: ray("reserve_factor")?,
+            optimal_utilization: ray("optimal_utilization")?,
+        })
+    }
+
+    /// Borrow rate at `utilization`, jumping past the optimum
+    pub fn borrow_rate(&self, utilization: f64) -> f64 {
+        if utilization <= self.optimal_utilization {
+            self.base_rate + utilization * self.multiplier
+        } else {
+            self.base_rate
+                + self.optimal_utilization * self.multiplier
+                + (utilization - self.optimal_utilization) * self.jump_rate_multiplier
+        }
+    }
+
+    /// Rate lenders earn at `utilization`
+    pub fn supply_rate(&self, utilization: f64) -> f64 {
+        self.borrow_rate(utilization) * utilization * (1.0 - self.reserve_factor)
+    }
 }
 
 /// Navi market overview
@@ -55,6 +180,7 @@ impl NaviAdapter {
         Self {
             client: reqwest::Client::new(),
             base_url: NAVI_API_BASE.to_string(),
+            onchain: None,
         }
     }
 
@@ -63,11 +189,38 @@ impl NaviAdapter {
         Self {
             client: reqwest::Client::new(),
             base_url,
+            onchain: None,
         }
     }
 
-    /// Fetch all reserve data from Navi
+    /// Read the mainnet storage on chain when the API fails
+    pub fn with_onchain_fallback(self, client: Arc<SuiClient>) -> Self {
+        self.with_storage(client, MAINNET_STORAGE)
+    }
+
+    /// Read `storage_id` on chain when the API fails
+    pub fn with_storage(mut self, client: Arc<SuiClient>, storage_id: impl Into<String>) -> Self {
+        self.onchain = Some(StorageReader {
+            client,
+            storage_id: storage_id.into(),
+        });
+        self
+    }
+
+    /// Fetch all reserve data, from the API or else the chain
     pub async fn get_reserves(&self) -> Result<Vec<ReserveData>, AdapterError> {
+        match (self.get_api_reserves().await, &self.onchain) {
+            (Ok(reserves), _) => Ok(reserves),
+            (Err(e), Some(reader)) => {
+                tracing::warn!("Navi API unavailable ({}), reading storage on chain", e);
+                reader.reserves().await
+            }
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    /// Fetch all reserve data from the Navi API
+    pub async fn get_api_reserves(&self) -> Result<Vec<ReserveData>, AdapterError> {
         let url = format!("{}/reserves", self.base_url);
 
         let response = self
@@ -99,12 +252,36 @@ impl NaviAdapter {
 
         let reserve = reserves
             .into_iter()
-            .find(|r| r.symbol.to_uppercase() == asset.to_uppercase())
+            .find(|r| r.is(asset))
             .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
 
         Ok(reserve.supply_apy)
     }
 
+    /// Supply APY of `asset` once `amount` (whole tokens) is deposited
+    pub async fn projected_supply_apy(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> Result<f64, AdapterError> {
+        let reserves = match &self.onchain {
+            Some(reader) => match reader.reserves().await {
+                Ok(reserves) => reserves,
+                Err(e) => {
+                    tracing::warn!("Navi storage unavailable ({}), diluting the API APY", e);
+                    self.get_api_reserves().await?
+                }
+            },
+            None => self.get_reserves().await?,
+        };
+
+        let reserve = reserves
+            .into_iter()
+            .find(|r| r.is(asset))
+            .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+        Ok(reserve.projected_supply_apy(amount))
+    }
+
     /// Get yield opportunity for comparison engine
     pub async fn get_yield_opportunity(
         &self,
@@ -114,80 +291,75 @@ impl NaviAdapter {
 
         let reserve = reserves
             .into_iter()
-            .find(|r| r.symbol.to_uppercase() == asset.to_uppercase())
+            .find(|r| r.is(asset))
             .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
 
+        Ok(self.opportunity(reserve))
+    }
+
+    /// Whether the `asset` reserve can safely take a deposit of `amount`
+    /// (whole tokens): within its supply cap and `can_accommodate` on
+    /// current data
+    ///
+    /// Reserves read on chain have no price and are compared in tokens.
+    pub async fn can_absorb(&self, asset: &str, amount: f64) -> Result<bool, AdapterError> {
+        let reserves = self.get_reserves().await?;
+
+        let reserve = reserves
+            .into_iter()
+            .find(|r| r.is(asset))
+            .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+        Ok(self.absorbs(reserve, amount))
+    }
+
+    fn absorbs(&self, mut reserve: ReserveData, amount: f64) -> bool {
+        let supplied = reserve.total_supply.parse::<f64>().unwrap_or(0.0);
+        if reserve
+            .supply_cap
+            .is_some_and(|cap| supplied + amount > cap)
+        {
+            return false;
+        }
+        if reserve.price_usd <= 0.0 {
+            reserve.price_usd = 1.0;
+        }
+
+        let price = reserve.price_usd;
+        self.can_accommodate(&self.opportunity(reserve), amount * price)
+    }
+
+    /// Get all yield opportunities
+    pub async fn get_all_opportunities(&self) -> Result<Vec<YieldOpportunity>, AdapterError> {
+        let reserves = self.get_reserves().await?;
+
+        Ok(reserves.into_iter().map(|r| self.opportunity(r)).collect())
+    }
+
+    fn opportunity(&self, reserve: ReserveData) -> YieldOpportunity {
         let tvl_usd = reserve.total_supply.parse::<f64>().unwrap_or(0.0) * reserve.price_usd;
         let liquidity_usd =
             reserve.available_liquidity.parse::<f64>().unwrap_or(0.0) * reserve.price_usd;
         let risk_score = self.calculate_risk_score(&reserve);
 
-        Ok(YieldOpportunity {
+        YieldOpportunity {
             protocol: "Navi".to_string(),
             asset: reserve.symbol,
             apy: reserve.supply_apy,
             tvl_usd,
             liquidity_usd,
             risk_score,
-        })
-    }
-
-    /// Get all yield opportunities
-    pub async fn get_all_opportunities(&self) -> Result<Vec<YieldOpportunity>, AdapterError> {
-        let reserves = self.get_reserves().await?;
-
-        let opportunities: Vec<YieldOpportunity> = reserves
-            .into_iter()
-            .map(|r| {
-                let tvl_usd = r.total_supply.parse::<f64>().unwrap_or(0.0) * r.price_usd;
-                let liquidity_usd =
-                    r.available_liquidity.parse::<f64>().unwrap_or(0.0) * r.price_usd;
-                let risk = self.calculate_risk_score(&r);
-
-                YieldOpportunity {
-                    protocol: "Navi".to_string(),
-                    asset: r.symbol,
-                    apy: r.supply_apy,
-                    tvl_usd,
-                    liquidity_usd,
-                    risk_score: risk,
-                }
-            })
-            .collect();
-
-        Ok(opportunities)
+        }
     }
 
-    /// Calculate risk score based on reserve metrics
-    /// Lower is safer (1-10 scale)
+    /// Risk score from the shared model (1-10, lower is safer)
     fn calculate_risk_score(&self, reserve: &ReserveData) -> u8 {
-        let mut score = 5; // Base score
-
-        // Higher TVL = lower risk
-        let tvl = reserve.total_supply.parse::<f64>().unwrap_or(0.0) * reserve.price_usd;
-        if tvl > 100_000_000.0 {
-            score -= 2;
-        } else if tvl > 10_000_000.0 {
-            score -= 1;
-        } else if tvl < 1_000_000.0 {
-            score += 2;
-        }
-
-        // High utilization = higher risk
-        if reserve.utilization_rate > 0.9 {
-            score += 2;
-        } else if reserve.utilization_rate > 0.8 {
-            score += 1;
-        }
-
-        // Lower LTV = safer
-        if reserve.ltv < 0.7 {
-            score -= 1;
-        } else if reserve.ltv > 0.8 {
-            score += 1;
-        }
-
-        score.clamp(1, 10)
+        RiskModel::default().score(&RiskFactors {
+            tvl_usd: reserve.total_supply.parse::<f64>().unwrap_or(0.0) * reserve.price_usd,
+            utilization: reserve.utilization_rate,
+            audited: true,
+            protocol_age_days: RiskFactors::age_days(LAUNCHED_AT, chrono::Utc::now().timestamp()),
+            il_exposure: false,
+        })
     }
 
     /// Check if reserve can accommodate deposit
@@ -202,6 +374,130 @@ impl Default for NaviAdapter {
     }
 }
 
+#[async_trait]
+impl YieldAdapter for NaviAdapter {
+    fn protocol(&self) -> Protocol {
+        Protocol::Navi
+    }
+
+    async fn opportunities(&self) -> Result<Vec<RawYieldData>, super::AdapterError> {
+        Ok(self
+            .get_all_opportunities()
+            .await?
+            .into_iter()
+            .map(|o| RawYieldData {
+                asset: o.asset,
+                apy: o.apy,
+                tvl_usd: o.tvl_usd,
+                liquidity_usd: o.liquidity_usd,
+                risk_score: o.risk_score,
+            })
+            .collect())
+    }
+}
+
+// ─── On-chain storage ────────────────────────────────────────────────────────
+
+/// Reads the `Storage` object's reserves table
+#[derive(Clone)]
+struct StorageReader {
+    client: Arc<SuiClient>,
+    storage_id: String,
+}
+
+impl std::fmt::Debug for StorageReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageReader")
+            .field("storage_id", &self.storage_id)
+            .finish()
+    }
+}
+
+impl StorageReader {
+    async fn reserves(&self) -> Result<Vec<ReserveData>, AdapterError> {
+        let storage = self
+            .client
+            .get_object(&self.storage_id)
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+        let content = storage
+            .content
+            .ok_or_else(|| AdapterError::ParseError("storage has no content".to_string()))?;
+        let table = uid(move_field(move_field(&content, "reserves"), "id"))
+            .ok_or_else(|| AdapterError::ParseError("reserves table ID".to_string()))?
+            .to_string();
+        let count = move_u64(move_field(&content, "reserves_count"))
+            .ok_or_else(|| AdapterError::ParseError("reserves_count".to_string()))?;
+
+        let mut reserves = Vec::new();
+        for id in 0..count {
+            let field = self
+                .client
+                .get_dynamic_field_object(&table, json!({ "type": "u8", "value": id }))
+                .await
+                .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+            let reserve = field
+                .content
+                .as_ref()
+                .and_then(|c| reserve_from_move(move_field(c, "value")))
+                .ok_or_else(|| AdapterError::ParseError(format!("reserve {}", id)))?;
+            reserves.push(reserve);
+        }
+        Ok(reserves)
+    }
+}
+
+/// Parse a `storage::ReserveData` struct
+///
+/// The chain has no prices: stablecoins count at $1, anything else at 0,
+/// so its TVL reads as unknown.
+fn reserve_from_move(value: &Value) -> Option<ReserveData> {
+    let ray = |name: &str| Some(move_f64(move_field(value, name))? / RAY);
+    let coin_type = move_field(value, "coin_type").as_str()?.to_string();
+    let symbol = match Asset::resolve(&coin_type) {
+        Some(asset) => asset.symbol().to_string(),
+        None => coin_type.rsplit("::").next()?.to_string(),
+    };
+
+    // Balances are scaled down by their index
+    let balance = |name: &str, index: &str| {
+        let scaled = move_f64(move_field(move_field(value, name), "total_supply"))?;
+        Some(scaled * ray(index)? / NORMALIZED_DECIMALS)
+    };
+    let supplied = balance("supply_balance", "current_supply_index")?;
+    let borrowed = balance("borrow_balance", "current_borrow_index")?;
+    let utilization_rate = if supplied > 0.0 {
+        borrowed / supplied
+    } else {
+        0.0
+    };
+
+    let supply_cap = ray("supply_cap_ceiling")? / NORMALIZED_DECIMALS;
+    let borrow_cap = ray("borrow_cap_ceiling")?;
+    let threshold = move_f64(move_field(
+        move_field(value, "liquidation_factors"),
+        "threshold",
+    ))? / RAY;
+
+    Some(ReserveData {
+        price_usd: if is_stable_asset(&symbol) { 1.0 } else { 0.0 },
+        asset: coin_type,
+        symbol,
+        // Annual rates, compounding continuously
+        supply_apy: ray("current_supply_rate")?.exp_m1() * 100.0,
+        borrow_apy: ray("current_borrow_rate")?.exp_m1() * 100.0,
+        total_supply: supplied.to_string(),
+        available_liquidity: (supplied - borrowed).max(0.0).to_string(),
+        utilization_rate,
+        ltv: ray("ltv")?,
+        liquidation_threshold: threshold,
+        supply_cap: (supply_cap > 0.0).then_some(supply_cap),
+        borrow_cap: (borrow_cap > 0.0).then_some(borrow_cap),
+        source: DataSource::Chain,
+        rate_factors: BorrowRateFactors::from_move(move_field(value, "borrow_rate_factors")),
+    })
+}
+
 /// Adapter errors
 #[derive(Debug, thiserror::Error)]
 pub enum AdapterError {
@@ -237,6 +533,10 @@ mod tests {
             price_usd: 1.0,
             ltv: 0.75,
             liquidation_threshold: 0.8,
+            supply_cap: None,
+            borrow_cap: None,
+            source: DataSource::Api,
+            rate_factors: None,
         };
 
         let score = adapter.calculate_risk_score(&safe_reserve);
@@ -246,4 +546,86 @@ mod tests {
             score
         );
     }
+
+    #[test]
+    fn test_absorbs_within_cap_and_liquidity() {
+        let adapter = NaviAdapter::new();
+        // Read on chain: no price, compared in tokens
+        let reserve = |supply_cap| ReserveData {
+            asset: "SUI".to_string(),
+            symbol: "SUI".to_string(),
+            supply_apy: 3.0,
+            borrow_apy: 5.0,
+            total_supply: "1500".to_string(),
+            available_liquidity: "1000".to_string(),
+            utilization_rate: 0.33,
+            price_usd: 0.0,
+            ltv: 0.75,
+            liquidation_threshold: 0.8,
+            supply_cap,
+            borrow_cap: None,
+            source: DataSource::Chain,
+            rate_factors: None,
+        };
+
+        assert!(adapter.absorbs(reserve(Some(2_000.0)), 400.0));
+        // Over the supply cap
+        assert!(!adapter.absorbs(reserve(Some(2_000.0)), 600.0));
+        // Within the 90% liquidity buffer only
+        assert!(adapter.absorbs(reserve(None), 850.0));
+        assert!(!adapter.absorbs(reserve(None), 950.0));
+    }
+
+    #[test]
+    fn test_reserve_from_chain() {
+        let ray = |x: f64| format!("{:.0}", x * RAY);
+        let reserve = json!({
+            "type": "0xnavi::storage::ReserveData",
+            "fields": {
+                "coin_type": "dba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
+                "current_supply_rate": ray(0.05),
+                "current_borrow_rate": ray(0.08),
+                "current_supply_index": ray(1.1),
+                "current_borrow_index": ray(1.2),
+                // 1M and 500k (scaled, 9 decimals)
+                "supply_balance": { "fields": { "total_supply": "1000000000000000" } },
+                "borrow_balance": { "fields": { "total_supply": "500000000000000" } },
+                "supply_cap_ceiling": ray(30_000_000.0 * NORMALIZED_DECIMALS),
+                "borrow_cap_ceiling": ray(0.9),
+                "ltv": ray(0.8),
+                "liquidation_factors": { "fields": { "threshold": ray(0.85) } },
+                "borrow_rate_factors": { "fields": { "value": { "fields": {
+                    "base_rate": ray(0.0),
+                    "multiplier": ray(0.1),
+                    "jump_rate_multiplier": ray(1.0),
+                    "reserve_factor": ray(0.2),
+                    "optimal_utilization": ray(0.8),
+                } } } },
+            }
+        });
+
+        let reserve = reserve_from_move(&reserve).unwrap();
+        assert_eq!(reserve.symbol, "USDC");
+        assert_eq!(reserve.source, DataSource::Chain);
+        assert_eq!(reserve.price_usd, 1.0);
+        assert!((reserve.supply_apy - 0.05f64.exp_m1() * 100.0).abs() < 1e-9);
+        // 1.1M supplied, 600k borrowed
+        let supplied: f64 = reserve.total_supply.parse().unwrap();
+        assert!((supplied - 1_100_000.0).abs() < 1e-3);
+        assert!((reserve.utilization_rate - 6.0 / 11.0).abs() < 1e-9);
+        assert!((reserve.supply_cap.unwrap() - 30_000_000.0).abs() < 1e-3);
+        assert!((reserve.ltv - 0.8).abs() < 1e-9);
+
+        // Depositing 100k brings utilization to 50%: 5% borrow rate, 2%
+        // to lenders after the reserve factor
+        let projected = reserve.projected_supply_apy(100_000.0);
+        assert!((projected - 0.02f64.exp_m1() * 100.0).abs() < 1e-6);
+        // Without the curve, the current APY is diluted
+        let api = ReserveData {
+            rate_factors: None,
+            ..reserve
+        };
+        let diluted = api.projected_supply_apy(1_100_000.0);
+        assert!((diluted - api.supply_apy / 2.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,297 @@
+//! Best-execution routing across protocols
+//!
+//! `find_best_for_asset` sends a deposit to one pool, however large. The
+//! router splits it instead: the amount is handed out in equal chunks, each
+//! to the pool whose next dollar earns the most once the deposits already
+//! routed there dilute its APY. Expected yield is concave in the deposit, so
+//! the greedy split is within one chunk of the optimum.
+//!
+//! Every pool takes at most `max_pool_share` of its TVL and `max_leg_share`
+//! of the amount, and `High` tier pools together at most
+//! `max_high_risk_share`. What no pool can take stays unallocated.
+
+use naisu_core::RiskTier;
+use serde::Serialize;
+
+use super::{AdapterError, Protocol, UnifiedYield, YieldComparator, YieldPreferences};
+
+/// Caps on how a deposit is split
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteLimits {
+    /// Largest deposit into one pool, as a fraction of its TVL
+    pub max_pool_share: f64,
+    /// Largest fraction of the amount in one pool
+    pub max_leg_share: f64,
+    /// Largest fraction of the amount in `High` tier pools
+    pub max_high_risk_share: f64,
+    /// Chunks the amount is handed out in
+    pub steps: usize,
+}
+
+impl Default for RouteLimits {
+    fn default() -> Self {
+        Self {
+            max_pool_share: 0.05,
+            max_leg_share: 1.0,
+            max_high_risk_share: 0.25,
+            steps: 100,
+        }
+    }
+}
+
+/// One pool's part of a split deposit
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RouteLeg {
+    pub protocol: Protocol,
+    pub asset: String,
+    pub amount_usd: f64,
+    /// Fraction of the requested amount
+    pub share: f64,
+    /// Pool APY once this leg is deposited (percent)
+    pub expected_apy: f64,
+    pub risk_score: u8,
+    pub risk_tier: RiskTier,
+}
+
+/// How to split a deposit, largest leg first
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutionPlan {
+    pub asset: String,
+    /// Requested amount (USD)
+    pub amount_usd: f64,
+    pub legs: Vec<RouteLeg>,
+    /// APY over the allocated amount, weighted by leg (percent)
+    pub blended_apy: f64,
+    /// Expected yield over a year (USD)
+    pub expected_yield_usd: f64,
+    /// What no pool could take within the limits (USD)
+    pub unallocated_usd: f64,
+}
+
+impl ExecutionPlan {
+    /// Split `amount` base units along the legs, in leg order
+    ///
+    /// Rounding dust goes to the first leg when the plan is fully
+    /// allocated; otherwise the unallocated part is left out.
+    pub fn split(&self, amount: u64) -> Vec<(Protocol, u64)> {
+        let mut parts: Vec<(Protocol, u64)> = self
+            .legs
+            .iter()
+            .map(|leg| (leg.protocol, (amount as f64 * leg.share).floor() as u64))
+            .collect();
+        if self.is_complete() {
+            let routed: u64 = parts.iter().map(|(_, units)| units).sum();
+            if let Some((_, first)) = parts.first_mut() {
+                *first += amount.saturating_sub(routed);
+            }
+        }
+        parts
+    }
+
+    /// Whether the whole amount found a pool
+    pub fn is_complete(&self) -> bool {
+        self.unallocated_usd <= self.amount_usd * 1e-9
+    }
+}
+
+/// APY of a pool once `deposit` (USD) is added, diluted over its TVL
+fn diluted_apy(pool: &UnifiedYield, deposit: f64) -> f64 {
+    if pool.tvl_usd > 0.0 {
+        pool.apy * pool.tvl_usd / (pool.tvl_usd + deposit)
+    } else {
+        0.0
+    }
+}
+
+/// Yearly yield (USD) of `deposit` in a pool
+fn expected_yield(pool: &UnifiedYield, deposit: f64) -> f64 {
+    deposit * diluted_apy(pool, deposit) / 100.0
+}
+
+/// Split `amount_usd` across `pools` (all of one asset) within `limits`
+pub fn plan(pools: &[UnifiedYield], amount_usd: f64, limits: &RouteLimits) -> ExecutionPlan {
+    let asset = pools.first().map(|p| p.asset.clone()).unwrap_or_default();
+    let steps = limits.steps.max(1);
+    let chunk = amount_usd / steps as f64;
+    let caps: Vec<f64> = pools
+        .iter()
+        .map(|p| (p.tvl_usd * limits.max_pool_share).min(amount_usd * limits.max_leg_share))
+        .collect();
+    let high_cap = amount_usd * limits.max_high_risk_share;
+
+    let mut allocated = vec![0.0; pools.len()];
+    let mut remaining = amount_usd;
+    while remaining > amount_usd * 1e-9 {
+        let high_used: f64 = pools
+            .iter()
+            .zip(&allocated)
+            .filter(|(p, _)| p.risk_tier == RiskTier::High)
+            .map(|(_, a)| a)
+            .sum();
+
+        // Highest yield per dollar on the next chunk
+        let best = pools
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pool)| {
+                let mut room = caps[i] - allocated[i];
+                if pool.risk_tier == RiskTier::High {
+                    room = room.min(high_cap - high_used);
+                }
+                let step = chunk.min(room).min(remaining);
+                if step <= amount_usd * 1e-9 {
+                    return None;
+                }
+                let gain =
+                    expected_yield(pool, allocated[i] + step) - expected_yield(pool, allocated[i]);
+                (gain > 0.0).then_some((i, step, gain / step))
+            })
+            .max_by(|a, b| a.2.total_cmp(&b.2));
+        let Some((i, step, _)) = best else {
+            break;
+        };
+        allocated[i] += step;
+        remaining -= step;
+    }
+
+    let mut legs: Vec<RouteLeg> = pools
+        .iter()
+        .zip(&allocated)
+        .filter(|(_, &amount)| amount > 0.0)
+        .map(|(pool, &amount)| RouteLeg {
+            protocol: pool.protocol,
+            asset: pool.asset.clone(),
+            amount_usd: amount,
+            share: amount / amount_usd,
+            expected_apy: diluted_apy(pool, amount),
+            risk_score: pool.risk_score,
+            risk_tier: pool.risk_tier,
+        })
+        .collect();
+    legs.sort_by(|a, b| b.amount_usd.total_cmp(&a.amount_usd));
+
+    let routed: f64 = legs.iter().map(|l| l.amount_usd).sum();
+    let expected_yield_usd: f64 = legs
+        .iter()
+        .map(|l| l.amount_usd * l.expected_apy / 100.0)
+        .sum();
+    ExecutionPlan {
+        asset,
+        amount_usd,
+        legs,
+        blended_apy: if routed > 0.0 {
+            expected_yield_usd / routed * 100.0
+        } else {
+            0.0
+        },
+        expected_yield_usd,
+        unallocated_usd: (amount_usd - routed).max(0.0),
+    }
+}
+
+impl YieldComparator {
+    /// Split a deposit of `amount_usd` in `asset` across every protocol's
+    /// pools that meet `prefs`
+    pub async fn route(
+        &self,
+        asset: &str,
+        amount_usd: f64,
+        prefs: &YieldPreferences,
+        limits: &RouteLimits,
+    ) -> Result<ExecutionPlan, AdapterError> {
+        let pools: Vec<UnifiedYield> = self
+            .compare_asset(asset)
+            .await?
+            .into_iter()
+            .filter(|o| prefs.accepts(o))
+            .collect();
+
+        let plan = plan(&pools, amount_usd, limits);
+        if plan.legs.is_empty() {
+            return Err(AdapterError::NoMatchingOpportunities(asset.to_string()));
+        }
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ScoreBreakdown;
+
+    fn pool(protocol: Protocol, apy: f64, tvl_usd: f64, risk_tier: RiskTier) -> UnifiedYield {
+        UnifiedYield {
+            protocol,
+            asset: "USDC".to_string(),
+            apy,
+            tvl_usd,
+            liquidity_usd: tvl_usd,
+            risk_score: 3,
+            risk_tier,
+            score: 0.0,
+            score_breakdown: ScoreBreakdown {
+                apy_points: 0.0,
+                safety_points: 0.0,
+                liquidity_points: 0.0,
+                penalties: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_small_deposit_takes_best_pool() {
+        let pools = [
+            pool(Protocol::Scallop, 8.0, 50_000_000.0, RiskTier::Low),
+            pool(Protocol::Navi, 7.0, 50_000_000.0, RiskTier::Low),
+        ];
+        let plan = plan(&pools, 10_000.0, &RouteLimits::default());
+
+        assert_eq!(plan.legs.len(), 1);
+        assert_eq!(plan.legs[0].protocol, Protocol::Scallop);
+        assert!(plan.is_complete());
+        assert_eq!(plan.split(1_000_000), vec![(Protocol::Scallop, 1_000_000)]);
+    }
+
+    #[test]
+    fn test_large_deposit_is_split_by_dilution() {
+        // A deposit the size of Scallop's pool halves its APY
+        let pools = [
+            pool(Protocol::Scallop, 8.0, 1_000_000.0, RiskTier::Low),
+            pool(Protocol::Navi, 6.0, 10_000_000.0, RiskTier::Low),
+        ];
+        let limits = RouteLimits {
+            max_pool_share: 1.0,
+            ..Default::default()
+        };
+        let plan = plan(&pools, 1_000_000.0, &limits);
+
+        assert_eq!(plan.legs.len(), 2);
+        assert!(plan.is_complete());
+        // Better than either pool alone
+        let alone = pools
+            .iter()
+            .map(|p| expected_yield(p, 1_000_000.0))
+            .fold(0.0, f64::max);
+        assert!(plan.expected_yield_usd > alone);
+
+        let parts = plan.split(1_000_000_000);
+        assert_eq!(parts.iter().map(|(_, u)| u).sum::<u64>(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_caps_limit_each_leg() {
+        let pools = [
+            pool(Protocol::Bluefin, 30.0, 10_000_000.0, RiskTier::High),
+            pool(Protocol::Scallop, 8.0, 2_000_000.0, RiskTier::Low),
+        ];
+        let plan = plan(&pools, 200_000.0, &RouteLimits::default());
+
+        let bluefin = plan.legs.iter().find(|l| l.protocol == Protocol::Bluefin);
+        assert!((bluefin.unwrap().share - 0.25).abs() < 1e-9);
+        // 5% of Scallop's TVL, the rest finds no pool
+        let scallop = plan.legs.iter().find(|l| l.protocol == Protocol::Scallop);
+        assert!((scallop.unwrap().amount_usd - 100_000.0).abs() < 1e-6);
+        assert!((plan.unallocated_usd - 50_000.0).abs() < 1e-6);
+        assert!(!plan.is_complete());
+    }
+}
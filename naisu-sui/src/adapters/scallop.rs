@@ -2,17 +2,45 @@
 //!
 //! Fetches yield data from Scallop API for AI Agent optimization.
 //!
+//! With `with_onchain_fallback`, an API outage falls back to the market
+//! object itself: each asset's interest model and balance sheet are read
+//! via `SuiClient` and the supply APY is derived locally, the way the
+//! protocol accrues it.
+//!
+//! `projected_supply_apy` estimates the APY after a deposit, so a solver
+//! can bid against the rate its own deposit leaves behind.
+//!
 //! API Docs: https://docs.scallop.io
 
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use naisu_core::{
+    format_units, from_decimal, is_stable_asset, same_asset, Asset, RiskFactors, RiskModel,
+    SuiNetwork,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::move_json::{move_field, move_u64, uid};
+use super::{LendingPool, Protocol, RawYieldData, YieldAdapter};
+use crate::client::SuiClient;
 
 const SCALLOP_API_BASE: &str = "https://api.scallop.io/v1";
 
+/// Sui mainnet launch, 2023-05-01 (unix seconds)
+const LAUNCHED_AT: i64 = 1_682_899_200;
+
+/// Scallop `Market` shared object on mainnet
+pub const MAINNET_MARKET: &str =
+    "0xa757975255146dc9686aa823b7838b507f315d704f428cbadad2f4ea061939d9";
+
 /// Scallop protocol adapter for yield data
 #[derive(Debug, Clone)]
 pub struct ScallopAdapter {
     client: reqwest::Client,
     base_url: String,
+    onchain: Option<MarketReader>,
 }
 
 /// Market data for a single asset
@@ -28,6 +56,17 @@ pub struct MarketData {
     pub price: f64,        // Asset price in USD
 }
 
+impl MarketData {
+    /// Supply side of the market, in whole tokens
+    pub fn lending_pool(&self) -> LendingPool {
+        LendingPool {
+            supply_apy: self.supply_apy,
+            supplied: self.total_supply.parse().unwrap_or(0.0),
+            borrowed: self.total_borrow.parse().unwrap_or(0.0),
+        }
+    }
+}
+
 /// Scallop market response
 #[derive(Debug, Clone, Deserialize)]
 pub struct MarketResponse {
@@ -52,6 +91,7 @@ impl ScallopAdapter {
         Self {
             client: reqwest::Client::new(),
             base_url: SCALLOP_API_BASE.to_string(),
+            onchain: None,
         }
     }
 
@@ -60,11 +100,41 @@ impl ScallopAdapter {
         Self {
             client: reqwest::Client::new(),
             base_url,
+            onchain: None,
         }
     }
 
-    /// Fetch all market data from Scallop
+    /// Read the mainnet market on chain when the API fails
+    pub fn with_onchain_fallback(self, client: Arc<SuiClient>) -> Self {
+        self.with_market(client, MAINNET_MARKET)
+    }
+
+    /// Read `market_id` on chain when the API fails
+    pub fn with_market(mut self, client: Arc<SuiClient>, market_id: impl Into<String>) -> Self {
+        self.onchain = Some(MarketReader {
+            client,
+            market_id: market_id.into(),
+        });
+        self
+    }
+
+    /// Fetch all market data, from the API or else the chain
     pub async fn get_markets(&self) -> Result<Vec<MarketData>, AdapterError> {
+        match (self.get_api_markets().await, &self.onchain) {
+            (Ok(markets), _) => Ok(markets),
+            (Err(e), Some(reader)) => {
+                tracing::warn!(
+                    "Scallop API unavailable ({}), reading the market on chain",
+                    e
+                );
+                reader.markets().await
+            }
+            (Err(e), None) => Err(e),
+        }
+    }
+
+    /// Fetch all market data from the Scallop API
+    pub async fn get_api_markets(&self) -> Result<Vec<MarketData>, AdapterError> {
         let url = format!("{}/markets", self.base_url);
 
         let response = self
@@ -96,12 +166,40 @@ impl ScallopAdapter {
 
         let market = markets
             .into_iter()
-            .find(|m| m.asset.to_uppercase() == asset.to_uppercase())
+            .find(|m| same_asset(&m.asset, asset))
             .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
 
         Ok(market.supply_apy)
     }
 
+    /// Supply APY of `asset` once `amount` (whole tokens) is deposited
+    ///
+    /// With the on-chain reader the deposit runs through the asset's
+    /// interest model; otherwise the current APY is diluted over the
+    /// larger supply.
+    pub async fn projected_supply_apy(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> Result<f64, AdapterError> {
+        if let Some(reader) = &self.onchain {
+            match reader.projected_supply_apy(asset, amount).await {
+                Ok(apy) => return Ok(apy),
+                Err(e) => tracing::warn!(
+                    "Scallop interest model unavailable ({}), diluting the current APY",
+                    e
+                ),
+            }
+        }
+
+        let markets = self.get_markets().await?;
+        let market = markets
+            .into_iter()
+            .find(|m| same_asset(&m.asset, asset))
+            .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+        Ok(market.lending_pool().diluted_apy(amount))
+    }
+
     /// Get yield opportunity for comparison engine
     pub async fn get_yield_opportunity(
         &self,
@@ -111,92 +209,337 @@ impl ScallopAdapter {
 
         let market = markets
             .into_iter()
-            .find(|m| m.asset.to_uppercase() == asset.to_uppercase())
+            .find(|m| same_asset(&m.asset, asset))
+            .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+
+        Ok(self.opportunity(market))
+    }
+
+    /// Whether the `asset` market can safely take a deposit of `amount`
+    /// (whole tokens), per `can_accommodate` on current data
+    ///
+    /// Markets read on chain have no price and are compared in tokens.
+    pub async fn can_absorb(&self, asset: &str, amount: f64) -> Result<bool, AdapterError> {
+        let markets = self.get_markets().await?;
+
+        let mut market = markets
+            .into_iter()
+            .find(|m| same_asset(&m.asset, asset))
             .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+        if market.price <= 0.0 {
+            market.price = 1.0;
+        }
+
+        let price = market.price;
+        Ok(self.can_accommodate(&self.opportunity(market), amount * price))
+    }
+
+    /// Get all yield opportunities for an asset
+    pub async fn get_all_opportunities(&self) -> Result<Vec<YieldOpportunity>, AdapterError> {
+        let markets = self.get_markets().await?;
+
+        Ok(markets.into_iter().map(|m| self.opportunity(m)).collect())
+    }
 
+    fn opportunity(&self, market: MarketData) -> YieldOpportunity {
         let tvl_usd = market.total_supply.parse::<f64>().unwrap_or(0.0) * market.price;
         let liquidity_usd = market.liquidity.parse::<f64>().unwrap_or(0.0) * market.price;
         let risk_score = self.calculate_risk_score(&market);
 
-        Ok(YieldOpportunity {
+        YieldOpportunity {
             protocol: "Scallop".to_string(),
             asset: market.asset,
             apy: market.supply_apy,
             tvl_usd,
             liquidity_usd,
             risk_score,
+        }
+    }
+
+    /// Risk score from the shared model (1-10, lower is safer)
+    fn calculate_risk_score(&self, market: &MarketData) -> u8 {
+        let supply = market.total_supply.parse::<f64>().unwrap_or(0.0);
+        let borrow = market.total_borrow.parse::<f64>().unwrap_or(0.0);
+        RiskModel::default().score(&RiskFactors {
+            tvl_usd: supply * market.price,
+            utilization: if supply > 0.0 { borrow / supply } else { 0.0 },
+            audited: true,
+            protocol_age_days: RiskFactors::age_days(LAUNCHED_AT, chrono::Utc::now().timestamp()),
+            il_exposure: false,
         })
     }
 
-    /// Get all yield opportunities for an asset
-    pub async fn get_all_opportunities(&self) -> Result<Vec<YieldOpportunity>, AdapterError> {
-        let markets = self.get_markets().await?;
+    /// Get recommended deposit amount based on liquidity
+    pub fn can_accommodate(&self, opportunity: &YieldOpportunity, amount_usd: f64) -> bool {
+        opportunity.liquidity_usd * 0.9 > amount_usd // 90% buffer
+    }
+}
+
+impl Default for ScallopAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl YieldAdapter for ScallopAdapter {
+    fn protocol(&self) -> Protocol {
+        Protocol::Scallop
+    }
 
-        let opportunities: Vec<YieldOpportunity> = markets
+    async fn opportunities(&self) -> Result<Vec<RawYieldData>, super::AdapterError> {
+        Ok(self
+            .get_all_opportunities()
+            .await?
             .into_iter()
-            .map(|m| {
-                let tvl_usd = m.total_supply.parse::<f64>().unwrap_or(0.0) * m.price;
-                let liquidity_usd = m.liquidity.parse::<f64>().unwrap_or(0.0) * m.price;
-                let risk = self.calculate_risk_score(&m);
-
-                YieldOpportunity {
-                    protocol: "Scallop".to_string(),
-                    asset: m.asset,
-                    apy: m.supply_apy,
-                    tvl_usd,
-                    liquidity_usd,
-                    risk_score: risk,
-                }
+            .map(|o| RawYieldData {
+                asset: o.asset,
+                apy: o.apy,
+                tvl_usd: o.tvl_usd,
+                liquidity_usd: o.liquidity_usd,
+                risk_score: o.risk_score,
             })
-            .collect();
+            .collect())
+    }
+}
+
+// ─── On-chain market ─────────────────────────────────────────────────────────
+
+/// Assets read on chain (mainnet markets)
+const ONCHAIN_ASSETS: [Asset; 2] = [Asset::Sui, Asset::Usdc];
+
+/// Symbol, `TypeName` of the coin and decimals of an on-chain asset
+fn onchain_asset(asset: Asset) -> (&'static str, String, u32) {
+    let type_name = asset
+        .type_name(SuiNetwork::Mainnet)
+        .expect("on-chain assets exist on mainnet");
+    (asset.symbol_on("scallop"), type_name, asset.decimals())
+}
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 3600.0;
+
+/// Scale of Move `FixedPoint32` values (2^32)
+const FIXED_POINT_ONE: f64 = 4_294_967_296.0;
+
+/// Interest model of one asset (rates are per second)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterestModel {
+    pub base_rate: f64,
+    pub mid_kink: f64,
+    pub rate_on_mid_kink: f64,
+    pub high_kink: f64,
+    pub rate_on_high_kink: f64,
+    pub max_rate: f64,
+    /// Share of interest kept by the protocol
+    pub revenue_factor: f64,
+}
 
-        Ok(opportunities)
+impl InterestModel {
+    /// Parse an `interest_model::InterestModel` struct
+    pub fn from_move(value: &Value) -> Option<Self> {
+        let scale = move_u64(move_field(value, "interest_rate_scale"))? as f64;
+        let rate = |name: &str| Some(fixed_point(move_field(value, name))? / scale);
+        let ratio = |name: &str| fixed_point(move_field(value, name));
+        Some(Self {
+            base_rate: rate("base_borrow_rate_per_sec")?,
+            mid_kink: ratio("mid_kink")?,
+            rate_on_mid_kink: rate("borrow_rate_on_mid_kink")?,
+            high_kink: ratio("high_kink")?,
+            rate_on_high_kink: rate("borrow_rate_on_high_kink")?,
+            max_rate: rate("max_borrow_rate")?,
+            revenue_factor: ratio("revenue_factor")?,
+        })
     }
 
-    /// Calculate risk score based on market metrics
-    /// Lower is safer (1-10 scale)
-    fn calculate_risk_score(&self, market: &MarketData) -> u8 {
-        let mut score = 5; // Base score
-
-        // Higher TVL = lower risk
-        let tvl = market.total_supply.parse::<f64>().unwrap_or(0.0);
-        if tvl > 100_000_000.0 {
-            score -= 2;
-        } else if tvl > 10_000_000.0 {
-            score -= 1;
-        } else if tvl < 1_000_000.0 {
-            score += 2;
+    /// Borrow rate per second at `utilization`, linear between the kinks
+    pub fn borrow_rate(&self, utilization: f64) -> f64 {
+        let lerp = |from: f64, to: f64, start: f64, end: f64| {
+            let span = end - start;
+            if span <= 0.0 {
+                return to;
+            }
+            from + (to - from) * (utilization - start) / span
+        };
+        if utilization < self.mid_kink {
+            lerp(self.base_rate, self.rate_on_mid_kink, 0.0, self.mid_kink)
+        } else if utilization < self.high_kink {
+            lerp(
+                self.rate_on_mid_kink,
+                self.rate_on_high_kink,
+                self.mid_kink,
+                self.high_kink,
+            )
+        } else {
+            lerp(self.rate_on_high_kink, self.max_rate, self.high_kink, 1.0)
         }
+    }
+}
 
-        // Higher utilization = higher risk
-        let utilization = if market.total_supply.parse::<f64>().unwrap_or(1.0) > 0.0 {
-            market.total_borrow.parse::<f64>().unwrap_or(0.0)
-                / market.total_supply.parse::<f64>().unwrap_or(1.0)
+/// Reserve balances of one asset (base units)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BalanceSheet {
+    pub cash: u64,
+    pub debt: u64,
+    pub revenue: u64,
+}
+
+impl BalanceSheet {
+    /// Parse a `reserve::BalanceSheet` struct
+    pub fn from_move(value: &Value) -> Option<Self> {
+        Some(Self {
+            cash: move_u64(move_field(value, "cash"))?,
+            debt: move_u64(move_field(value, "debt"))?,
+            revenue: move_u64(move_field(value, "revenue"))?,
+        })
+    }
+
+    /// Borrowed share of the supply
+    pub fn utilization(&self) -> f64 {
+        let total = self.cash as f64 + self.debt as f64;
+        if total > 0.0 {
+            self.debt as f64 / total
         } else {
             0.0
-        };
+        }
+    }
 
-        if utilization > 0.9 {
-            score += 2;
-        } else if utilization > 0.8 {
-            score += 1;
+    /// The sheet once `amount` more cash is supplied
+    pub fn with_deposit(self, amount: u64) -> Self {
+        Self {
+            cash: self.cash.saturating_add(amount),
+            ..self
         }
+    }
 
-        score.clamp(1, 10)
+    /// Supplied to lenders (cash and debt, less protocol revenue)
+    pub fn supplied(&self) -> u64 {
+        (self.cash + self.debt).saturating_sub(self.revenue)
     }
+}
 
-    /// Get recommended deposit amount based on liquidity
-    pub fn can_accommodate(&self, opportunity: &YieldOpportunity, amount_usd: f64) -> bool {
-        opportunity.liquidity_usd * 0.9 > amount_usd // 90% buffer
+/// Supply and borrow APY (percent), compounding every second
+pub fn derive_apys(model: &InterestModel, sheet: &BalanceSheet) -> (f64, f64) {
+    let utilization = sheet.utilization();
+    let borrow_apr = model.borrow_rate(utilization) * SECONDS_PER_YEAR;
+    let supply_apr = borrow_apr * utilization * (1.0 - model.revenue_factor);
+    (supply_apr.exp_m1() * 100.0, borrow_apr.exp_m1() * 100.0)
+}
+
+/// Reads the `Market` object's tables
+#[derive(Clone)]
+struct MarketReader {
+    client: Arc<SuiClient>,
+    market_id: String,
+}
+
+impl std::fmt::Debug for MarketReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketReader")
+            .field("market_id", &self.market_id)
+            .finish()
     }
 }
 
-impl Default for ScallopAdapter {
-    fn default() -> Self {
-        Self::new()
+impl MarketReader {
+    /// Market data of every asset in `ONCHAIN_ASSETS`
+    ///
+    /// The chain has no prices: stablecoins count at $1, anything else at
+    /// 0, so its TVL reads as unknown.
+    async fn markets(&self) -> Result<Vec<MarketData>, AdapterError> {
+        let tables = self.tables().await?;
+
+        let mut markets = Vec::new();
+        for (asset, type_name, decimals) in ONCHAIN_ASSETS.map(onchain_asset) {
+            let (model, sheet) = self.asset(&tables, asset, &type_name).await?;
+
+            let (supply_apy, borrow_apy) = derive_apys(&model, &sheet);
+            let units = |amount: u64| format_units(amount, decimals, decimals);
+            markets.push(MarketData {
+                asset: asset.to_string(),
+                supply_apy,
+                borrow_apy,
+                total_supply: units(sheet.supplied()),
+                total_borrow: units(sheet.debt),
+                liquidity: units(sheet.cash),
+                ltv: 0.0,
+                price: if is_stable_asset(asset) { 1.0 } else { 0.0 },
+            });
+        }
+        Ok(markets)
+    }
+
+    /// Supply APY of `asset` with `amount` (whole tokens) added to its cash
+    async fn projected_supply_apy(&self, asset: &str, amount: f64) -> Result<f64, AdapterError> {
+        let (asset, type_name, decimals) = Asset::resolve(asset)
+            .filter(|a| ONCHAIN_ASSETS.contains(a))
+            .map(onchain_asset)
+            .ok_or_else(|| AdapterError::AssetNotFound(asset.to_string()))?;
+        let tables = self.tables().await?;
+        let (model, sheet) = self.asset(&tables, asset, &type_name).await?;
+        let deposit = from_decimal(amount, decimals);
+        Ok(derive_apys(&model, &sheet.with_deposit(deposit)).0)
+    }
+
+    /// IDs of the interest model and balance sheet tables
+    async fn tables(&self) -> Result<(String, String), AdapterError> {
+        let market = self
+            .client
+            .get_object(&self.market_id)
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+        let content = market
+            .content
+            .ok_or_else(|| AdapterError::ParseError("market has no content".to_string()))?;
+        let models = table_id(move_field(&content, "interest_models"))?;
+        let sheets = table_id(move_field(move_field(&content, "vault"), "balance_sheets"))?;
+        Ok((models, sheets))
+    }
+
+    /// Interest model and balance sheet of one asset
+    async fn asset(
+        &self,
+        (models, sheets): &(String, String),
+        asset: &str,
+        type_name: &str,
+    ) -> Result<(InterestModel, BalanceSheet), AdapterError> {
+        let model = self.entry(models, type_name).await?;
+        let model = InterestModel::from_move(&model)
+            .ok_or_else(|| AdapterError::ParseError(format!("{} interest model", asset)))?;
+        let sheet = self.entry(sheets, type_name).await?;
+        let sheet = BalanceSheet::from_move(&sheet)
+            .ok_or_else(|| AdapterError::ParseError(format!("{} balance sheet", asset)))?;
+        Ok((model, sheet))
+    }
+
+    /// Value stored under a coin's `TypeName` in a table
+    async fn entry(&self, table: &str, type_name: &str) -> Result<Value, AdapterError> {
+        let name = json!({
+            "type": "0x1::type_name::TypeName",
+            "value": { "name": type_name },
+        });
+        let field = self
+            .client
+            .get_dynamic_field_object(table, name)
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+        let content = field
+            .content
+            .ok_or_else(|| AdapterError::ParseError(format!("{} has no content", type_name)))?;
+        Ok(move_field(&content, "value").clone())
     }
 }
 
+fn fixed_point(value: &Value) -> Option<f64> {
+    Some(move_u64(move_field(value, "value"))? as f64 / FIXED_POINT_ONE)
+}
+
+/// ID of the `Table` inside a Scallop `WitTable` / `AcTable`
+fn table_id(value: &Value) -> Result<String, AdapterError> {
+    uid(move_field(move_field(value, "table"), "id"))
+        .map(str::to_string)
+        .ok_or_else(|| AdapterError::ParseError("market table ID".to_string()))
+}
+
 /// Adapter errors
 #[derive(Debug, thiserror::Error)]
 pub enum AdapterError {
@@ -235,4 +578,59 @@ mod tests {
         let score = adapter.calculate_risk_score(&high_tvl_market);
         assert!(score <= 5, "High TVL should have lower risk score");
     }
+
+    #[test]
+    fn test_onchain_apy() {
+        let fixed = |ratio: f64| json!({ "fields": { "value": ((ratio * FIXED_POINT_ONE) as u64).to_string() } });
+        // Per-second rates scaled by 1e9: 0% base, 10% APR at the 60% kink,
+        // 30% at 90%, 300% max
+        let per_sec = |apr: f64| fixed(apr / SECONDS_PER_YEAR * 1e9);
+        let model = json!({
+            "type": "0xscallop::interest_model::InterestModel",
+            "fields": {
+                "interest_rate_scale": "1000000000",
+                "base_borrow_rate_per_sec": per_sec(0.0),
+                "mid_kink": fixed(0.6),
+                "borrow_rate_on_mid_kink": per_sec(0.1),
+                "high_kink": fixed(0.9),
+                "borrow_rate_on_high_kink": per_sec(0.3),
+                "max_borrow_rate": per_sec(3.0),
+                "revenue_factor": fixed(0.2),
+            }
+        });
+        let model = InterestModel::from_move(&model).unwrap();
+        let sheet = BalanceSheet::from_move(&json!({
+            "cash": "700", "debt": "300", "revenue": "10"
+        }))
+        .unwrap();
+        assert_eq!(sheet.supplied(), 990);
+
+        // 30% utilization: half way to the mid kink, 5% borrow APR
+        let (supply, borrow) = derive_apys(&model, &sheet);
+        assert!((borrow - 0.05f64.exp_m1() * 100.0).abs() < 1e-3);
+        // Lenders earn 5% x 30% utilization x 80% after revenue
+        assert!((supply - 0.012f64.exp_m1() * 100.0).abs() < 1e-3);
+
+        let full = model.borrow_rate(1.0) * SECONDS_PER_YEAR;
+        assert!((full - 3.0).abs() < 1e-3);
+
+        // Depositing 500 more drops utilization to 20%: 3.33% borrow APR
+        // over a fifth of the pool, well below plain dilution's 0.8%
+        let (projected, _) = derive_apys(&model, &sheet.with_deposit(500));
+        let apr: f64 = 0.1 * (0.2 / 0.6) * 0.2 * 0.8;
+        assert!((projected - apr.exp_m1() * 100.0).abs() < 1e-3);
+        let diluted = MarketData {
+            asset: "SUI".to_string(),
+            supply_apy: supply,
+            borrow_apy: borrow,
+            total_supply: "1000".to_string(),
+            total_borrow: "300".to_string(),
+            liquidity: "700".to_string(),
+            ltv: 0.0,
+            price: 0.0,
+        }
+        .lending_pool()
+        .diluted_apy(500.0);
+        assert!(projected < diluted);
+    }
 }
@@ -0,0 +1,218 @@
+//! Turbos Finance API Adapter
+//!
+//! Fetches CLMM pool data (APR, TVL, pairs) from the Turbos API.
+//!
+//! API Docs: https://docs.turbos.finance
+
+use async_trait::async_trait;
+use naisu_core::{same_asset, RiskFactors, RiskModel};
+use serde::Deserialize;
+
+use super::{Protocol, RawYieldData, YieldAdapter};
+
+const TURBOS_API_BASE: &str = "https://api.turbos.finance";
+
+/// Turbos mainnet launch, 2023-05-12 (unix seconds)
+const LAUNCHED_AT: i64 = 1_683_849_600;
+
+/// Turbos adapter for pool data
+#[derive(Debug, Clone)]
+pub struct TurbosAdapter {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+/// Turbos pool data
+#[derive(Debug, Clone, Deserialize)]
+pub struct PoolData {
+    pub pool_id: String,
+    pub coin_symbol_a: String,
+    pub coin_symbol_b: String,
+    pub coin_type_a: String,
+    pub coin_type_b: String,
+    /// Parts per million (e.g., 3000 = 0.3%)
+    pub fee: u64,
+    /// Fee plus reward APR, percent
+    pub apr: f64,
+    pub liquidity_usd: f64,
+    #[serde(default)]
+    pub volume_24h_usd: f64,
+}
+
+impl PoolData {
+    /// Whether the pool trades `a` against `b`, in either order
+    ///
+    /// Each side is a symbol or coin type, matched through the asset
+    /// registry.
+    pub fn is_pair(&self, a: &str, b: &str) -> bool {
+        let x = |asset: &str| {
+            same_asset(&self.coin_symbol_a, asset) || same_asset(&self.coin_type_a, asset)
+        };
+        let y = |asset: &str| {
+            same_asset(&self.coin_symbol_b, asset) || same_asset(&self.coin_type_b, asset)
+        };
+        (x(a) && y(b)) || (x(b) && y(a))
+    }
+}
+
+/// Turbos `/pools` response
+#[derive(Debug, Clone, Deserialize)]
+pub struct PoolsResponse {
+    pub list: Vec<PoolData>,
+}
+
+impl TurbosAdapter {
+    /// Create new Turbos adapter
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: TURBOS_API_BASE.to_string(),
+        }
+    }
+
+    /// Create with custom base URL (for testing)
+    pub fn with_base_url(base_url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+        }
+    }
+
+    /// Fetch all pools from Turbos
+    pub async fn get_pools(&self) -> Result<Vec<PoolData>, AdapterError> {
+        let url = format!("{}/pools", self.base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| AdapterError::RequestFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(AdapterError::ApiError(
+                response.status().to_string(),
+                response.text().await.unwrap_or_default(),
+            ));
+        }
+
+        let pools: PoolsResponse = response
+            .json()
+            .await
+            .map_err(|e| AdapterError::ParseError(e.to_string()))?;
+
+        Ok(pools.list)
+    }
+
+    /// Deepest pool for a pair (e.g., "SUI", "USDC")
+    pub async fn find_pool(&self, a: &str, b: &str) -> Result<PoolData, AdapterError> {
+        self.get_pools()
+            .await?
+            .into_iter()
+            .filter(|p| p.is_pair(a, b))
+            .max_by(|x, y| x.liquidity_usd.total_cmp(&y.liquidity_usd))
+            .ok_or_else(|| AdapterError::PairNotFound(format!("{}-{}", a, b)))
+    }
+
+    /// Risk score from the shared model (1-10, lower is safer)
+    fn calculate_risk_score(&self, pool: &PoolData) -> u8 {
+        RiskModel::default().score(&RiskFactors {
+            tvl_usd: pool.liquidity_usd,
+            utilization: 0.0,
+            audited: true,
+            protocol_age_days: RiskFactors::age_days(LAUNCHED_AT, chrono::Utc::now().timestamp()),
+            il_exposure: true,
+        })
+    }
+}
+
+impl Default for TurbosAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl YieldAdapter for TurbosAdapter {
+    fn protocol(&self) -> Protocol {
+        Protocol::Turbos
+    }
+
+    async fn opportunities(&self) -> Result<Vec<RawYieldData>, super::AdapterError> {
+        Ok(self
+            .get_pools()
+            .await?
+            .iter()
+            .map(|p| RawYieldData {
+                asset: format!("{}-{}", p.coin_symbol_a, p.coin_symbol_b),
+                apy: p.apr,
+                tvl_usd: p.liquidity_usd,
+                liquidity_usd: p.liquidity_usd,
+                risk_score: self.calculate_risk_score(p),
+            })
+            .collect())
+    }
+}
+
+/// Adapter errors
+#[derive(Debug, thiserror::Error)]
+pub enum AdapterError {
+    #[error("HTTP request failed: {0}")]
+    RequestFailed(String),
+
+    #[error("API error {0}: {1}")]
+    ApiError(String, String),
+
+    #[error("Failed to parse response: {0}")]
+    ParseError(String),
+
+    #[error("Pair not found: {0}")]
+    PairNotFound(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_pair() {
+        let response: PoolsResponse = serde_json::from_value(serde_json::json!({
+            "list": [{
+                "pool_id": "0xpool",
+                "coin_symbol_a": "USDC",
+                "coin_symbol_b": "SUI",
+                "coin_type_a": "0xdba3::usdc::USDC",
+                "coin_type_b": "0x2::sui::SUI",
+                "fee": 3000,
+                "apr": 18.2,
+                "liquidity_usd": 4000000.0
+            }]
+        }))
+        .unwrap();
+
+        let pool = &response.list[0];
+        assert!(pool.is_pair("SUI", "USDC"));
+        assert_eq!(pool.volume_24h_usd, 0.0);
+    }
+
+    #[test]
+    fn test_pair_matches_asset_aliases() {
+        let pool: PoolData = serde_json::from_value(serde_json::json!({
+            "pool_id": "0xpool",
+            "coin_symbol_a": "USDCet",
+            "coin_symbol_b": "SUI",
+            "coin_type_a": "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN",
+            "coin_type_b": "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI",
+            "fee": 500,
+            "apr": 6.1,
+            "liquidity_usd": 900000.0
+        }))
+        .unwrap();
+
+        assert!(pool.is_pair("wUSDC", "SUI"));
+        assert!(pool.is_pair("0x2::sui::SUI", "usdcet"));
+        // Wormhole USDC is not native USDC
+        assert!(!pool.is_pair("USDC", "SUI"));
+    }
+}
@@ -0,0 +1,339 @@
+//! Bridge fund detection
+//!
+//! For EVM → Sui intents, CCTP mints USDC straight to the intent's Sui
+//! address once the attestation is relayed. The detector watches those
+//! addresses for new USDC coins, matches each to a pending intent by coin
+//! type and amount, and hands the arrival to an `ArrivalHandler`, which
+//! starts the deposit leg.
+//!
+//! Only coins that appear after an address is first watched count: its
+//! existing coins are snapshotted then. If several pending intents could
+//! take the same coin, the oldest one does.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use naisu_core::{Direction, Intent, IntentStatus};
+use tracing::{info, warn};
+
+use crate::cctp::USDC_COIN_TYPE;
+use crate::client::{CoinObject, SuiClient, SuiClientError};
+
+/// Default delay between balance checks
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Bridged USDC expected at a Sui address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingDeposit {
+    pub intent_id: String,
+    /// Sui address the USDC is minted to
+    pub recipient: String,
+    /// Base units (6 decimals)
+    pub amount: u64,
+    pub coin_type: String,
+}
+
+impl PendingDeposit {
+    pub fn new(intent_id: impl Into<String>, recipient: impl Into<String>, amount: u64) -> Self {
+        Self {
+            intent_id: intent_id.into(),
+            recipient: recipient.into(),
+            amount,
+            coin_type: USDC_COIN_TYPE.to_string(),
+        }
+    }
+
+    pub fn with_coin_type(mut self, coin_type: impl Into<String>) -> Self {
+        self.coin_type = coin_type.into();
+        self
+    }
+
+    /// Deposit awaited by a bridging EVM → Sui intent
+    pub fn from_intent(intent: &Intent) -> Option<Self> {
+        if intent.direction != Direction::EvmToSui || intent.status != IntentStatus::Bridging {
+            return None;
+        }
+        let amount = intent.usdc_amount.as_deref()?.parse().ok()?;
+        Some(Self::new(&intent.id, &intent.dest_address, amount))
+    }
+
+    /// Whether a coin of `balance` can be this deposit
+    ///
+    /// Fees taken in flight (CCTP fast transfers) may leave it up to
+    /// `max_shortfall_bps` short; it is never more than expected.
+    fn matches(&self, coin: &CoinObject, balance: u64, max_shortfall_bps: u16) -> bool {
+        let shortfall = (self.amount as u128 * max_shortfall_bps as u128 / 10_000) as u64;
+        coin.coin_type == self.coin_type
+            && balance <= self.amount
+            && balance >= self.amount.saturating_sub(shortfall)
+    }
+}
+
+/// USDC that reached an intent's address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeArrival {
+    pub intent_id: String,
+    pub recipient: String,
+    pub coin_type: String,
+    /// Minted coin
+    pub coin_object_id: String,
+    /// Amount received
+    pub amount: u64,
+    /// Amount the intent expected
+    pub expected: u64,
+}
+
+/// Starts the deposit leg once bridged funds arrive
+#[async_trait]
+pub trait ArrivalHandler: Send + Sync {
+    async fn on_arrival(&self, arrival: &BridgeArrival);
+}
+
+/// Watches destination addresses for bridged USDC
+pub struct BridgeDetector {
+    client: SuiClient,
+    /// Oldest first
+    pending: Mutex<Vec<PendingDeposit>>,
+    /// Coin IDs already at each (address, coin type)
+    known: Mutex<HashMap<(String, String), HashSet<String>>>,
+    max_shortfall_bps: u16,
+    interval: Duration,
+}
+
+impl BridgeDetector {
+    pub fn new(client: SuiClient) -> Self {
+        Self {
+            client,
+            pending: Mutex::new(Vec::new()),
+            known: Mutex::new(HashMap::new()),
+            max_shortfall_bps: 0,
+            interval: DEFAULT_CHECK_INTERVAL,
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Accept coins short of the expected amount by up to this much
+    pub fn with_max_shortfall_bps(mut self, bps: u16) -> Self {
+        self.max_shortfall_bps = bps.min(10_000);
+        self
+    }
+
+    /// Start watching for a deposit
+    ///
+    /// Snapshots the recipient's coins the first time it is watched, so
+    /// funds already there aren't taken for the bridged ones.
+    pub async fn watch(&self, deposit: PendingDeposit) -> Result<(), SuiClientError> {
+        let key = (deposit.recipient.clone(), deposit.coin_type.clone());
+        let watched = self
+            .known
+            .lock()
+            .expect("known coins lock poisoned")
+            .contains_key(&key);
+        if !watched {
+            let coins = self
+                .client
+                .get_coins(&deposit.recipient, Some(&deposit.coin_type))
+                .await?;
+            self.baseline(&key.0, &key.1, &coins);
+        }
+
+        info!(
+            "🌉 Watching {} for {} of {} (intent {})",
+            deposit.recipient, deposit.amount, deposit.coin_type, deposit.intent_id
+        );
+        self.pending
+            .lock()
+            .expect("pending deposits lock poisoned")
+            .push(deposit);
+        Ok(())
+    }
+
+    /// Stop watching for an intent's deposit (e.g. it was cancelled)
+    pub fn unwatch(&self, intent_id: &str) -> Option<PendingDeposit> {
+        let mut pending = self.pending.lock().expect("pending deposits lock poisoned");
+        let index = pending.iter().position(|d| d.intent_id == intent_id)?;
+        Some(pending.remove(index))
+    }
+
+    pub fn pending(&self) -> Vec<PendingDeposit> {
+        self.pending
+            .lock()
+            .expect("pending deposits lock poisoned")
+            .clone()
+    }
+
+    /// Record coins already at an address without matching them
+    fn baseline(&self, recipient: &str, coin_type: &str, coins: &[CoinObject]) {
+        self.known
+            .lock()
+            .expect("known coins lock poisoned")
+            .entry((recipient.to_string(), coin_type.to_string()))
+            .or_default()
+            .extend(coins.iter().map(|c| c.coin_object_id.clone()));
+    }
+
+    /// Match coins newly seen at an address to its pending deposits
+    fn detect(&self, recipient: &str, coin_type: &str, coins: &[CoinObject]) -> Vec<BridgeArrival> {
+        let mut known = self.known.lock().expect("known coins lock poisoned");
+        let known = known
+            .entry((recipient.to_string(), coin_type.to_string()))
+            .or_default();
+        let mut pending = self.pending.lock().expect("pending deposits lock poisoned");
+
+        let mut arrivals = Vec::new();
+        for coin in coins {
+            if !known.insert(coin.coin_object_id.clone()) {
+                continue;
+            }
+            let Ok(balance) = coin.balance.parse::<u64>() else {
+                continue;
+            };
+            let Some(index) = pending.iter().position(|d| {
+                d.recipient == recipient && d.matches(coin, balance, self.max_shortfall_bps)
+            }) else {
+                continue;
+            };
+
+            let deposit = pending.remove(index);
+            arrivals.push(BridgeArrival {
+                intent_id: deposit.intent_id,
+                recipient: deposit.recipient,
+                coin_type: deposit.coin_type,
+                coin_object_id: coin.coin_object_id.clone(),
+                amount: balance,
+                expected: deposit.amount,
+            });
+        }
+        arrivals
+    }
+
+    /// Check every watched address once
+    pub async fn check_once(&self) -> Result<Vec<BridgeArrival>, SuiClientError> {
+        let mut targets: Vec<(String, String)> = self
+            .pending()
+            .into_iter()
+            .map(|d| (d.recipient, d.coin_type))
+            .collect();
+        targets.sort();
+        targets.dedup();
+
+        let mut arrivals = Vec::new();
+        for (recipient, coin_type) in targets {
+            let coins = self.client.get_coins(&recipient, Some(&coin_type)).await?;
+            arrivals.extend(self.detect(&recipient, &coin_type, &coins));
+        }
+        Ok(arrivals)
+    }
+
+    /// Check forever, handing each arrival to `handler`
+    pub async fn run(&self, handler: &dyn ArrivalHandler) {
+        info!("🌉 Bridge detector started (every {:?})", self.interval);
+        loop {
+            match self.check_once().await {
+                Ok(arrivals) => {
+                    for arrival in &arrivals {
+                        info!(
+                            "🌉 Bridged funds for intent {}: {} in {}",
+                            arrival.intent_id, arrival.amount, arrival.coin_object_id
+                        );
+                        handler.on_arrival(arrival).await;
+                    }
+                }
+                Err(e) => warn!("⚠️  Bridge detection failed: {}", e),
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SuiConfig;
+
+    const USER: &str = "0xuser";
+
+    fn coin(id: &str, balance: u64) -> CoinObject {
+        CoinObject {
+            coin_type: USDC_COIN_TYPE.to_string(),
+            coin_object_id: id.to_string(),
+            version: "1".to_string(),
+            digest: "d".to_string(),
+            balance: balance.to_string(),
+        }
+    }
+
+    fn detector(pending: Vec<PendingDeposit>) -> BridgeDetector {
+        let detector = BridgeDetector::new(SuiClient::new(SuiConfig::testnet()));
+        *detector.pending.lock().unwrap() = pending;
+        detector
+    }
+
+    #[test]
+    fn test_new_coins_match_oldest_pending() {
+        let detector = detector(vec![
+            PendingDeposit::new("i1", USER, 5_000_000),
+            PendingDeposit::new("i2", USER, 5_000_000),
+            PendingDeposit::new("i3", USER, 9_000_000),
+        ]);
+        // Funds already there before watching
+        detector.baseline(USER, USDC_COIN_TYPE, &[coin("0xold", 5_000_000)]);
+
+        let arrivals = detector.detect(
+            USER,
+            USDC_COIN_TYPE,
+            &[
+                coin("0xold", 5_000_000),
+                coin("0xa", 5_000_000),
+                coin("0xb", 7_000_000), // Matches nothing
+            ],
+        );
+        assert_eq!(arrivals.len(), 1);
+        assert_eq!(arrivals[0].intent_id, "i1");
+        assert_eq!(arrivals[0].coin_object_id, "0xa");
+
+        // Seen coins aren't matched twice
+        let arrivals = detector.detect(
+            USER,
+            USDC_COIN_TYPE,
+            &[coin("0xa", 5_000_000), coin("0xc", 5_000_000)],
+        );
+        assert_eq!(arrivals[0].intent_id, "i2");
+        assert_eq!(detector.pending().len(), 1);
+        assert!(detector.unwatch("i3").is_some());
+        assert!(detector.pending().is_empty());
+    }
+
+    #[test]
+    fn test_shortfall_tolerance() {
+        let deposit = PendingDeposit::new("i1", USER, 10_000_000);
+        let c = coin("0xa", 9_990_000);
+        assert!(!deposit.matches(&c, 9_990_000, 0));
+        assert!(deposit.matches(&c, 9_990_000, 10));
+        assert!(!deposit.matches(&c, 10_000_001, 10));
+        assert!(!deposit
+            .clone()
+            .with_coin_type("0x2::sui::SUI")
+            .matches(&c, 10_000_000, 0));
+
+        let mut intent = Intent::new_evm_to_sui(
+            "i1".to_string(),
+            "0xevm".to_string(),
+            USER.to_string(),
+            naisu_core::EvmChain::BaseSepolia,
+            "0xtoken".to_string(),
+            "1".to_string(),
+            naisu_core::YieldStrategy::ScallopUsdc,
+        );
+        assert!(PendingDeposit::from_intent(&intent).is_none());
+        intent.status = IntentStatus::Bridging;
+        intent.usdc_amount = Some("10000000".to_string());
+        assert_eq!(PendingDeposit::from_intent(&intent), Some(deposit));
+    }
+}
@@ -3,8 +3,13 @@
 //! Provides PTB construction for burning USDC on Sui via CCTP.
 //! The user signs and submits the transaction; we just build it.
 
+use naisu_core::{format_units, EvmChain, USDC_DECIMALS};
 use serde::{Deserialize, Serialize};
 
+use crate::client::CoinObject;
+use crate::ptb::{ProgrammableTransactionBlock, PtbArgument, PtbBuilder};
+use crate::zklogin::{ZkLoginError, ZkLoginSender};
+
 // ─── CCTP Package IDs (Sui Testnet) ──────────────────────────────────────────
 // Source: https://github.com/circlefin/sui-cctp (testnet branch Move.lock)
 
@@ -35,8 +40,9 @@ pub const USDC_TREASURY: &str =
 
 // ─── CCTP Domain IDs ─────────────────────────────────────────────────────────
 
-pub const CCTP_DOMAIN_BASE: u32 = 5;
-pub const CCTP_DOMAIN_SUI: u32 = 10;
+// EVM domains come from `EvmChain::cctp_domain`
+
+pub const CCTP_DOMAIN_SUI: u32 = 8;
 
 // ─── Types ───────────────────────────────────────────────────────────────────
 
@@ -49,8 +55,13 @@ pub struct DepositForBurnRequest {
     pub amount: u64,
     /// Destination EVM address (will be padded to 32 bytes)
     pub evm_destination: String,
-    /// Destination CCTP domain (e.g., 5 for Base)
+    /// Destination CCTP domain (`EvmChain::cctp_domain`, e.g. 6 for Base)
     pub dest_domain: u32,
+    /// Set when the sender is a zkLogin address; the PTB is identical, but
+    /// the sender is checked against the derived address and the client
+    /// signs with `assemble_zklogin_signature`
+    #[serde(default)]
+    pub zklogin: Option<ZkLoginSender>,
 }
 
 /// Response containing the PTB for the user to sign
@@ -88,12 +99,20 @@ pub fn build_deposit_for_burn_ptb(
     // The actual PTB construction requires the Sui SDK's TransactionBlock builder
     // For now, we return the parameters needed for the frontend to build it
 
+    if let Some(zklogin) = &request.zklogin {
+        zklogin.verify_sender(&request.sender)?;
+    }
+
     // Pad EVM address to 32 bytes (required by CCTP)
     let _padded_dest = pad_evm_address(&request.evm_destination)?;
 
+    // This package is the testnet deployment
+    let dest = EvmChain::from_cctp_domain(request.dest_domain, true)
+        .ok_or(CctpSuiError::UnsupportedDomain(request.dest_domain))?;
     let summary = format!(
-        "Burn {} USDC on Sui → Mint on Base (domain {})",
-        request.amount as f64 / 1_000_000.0,
+        "Burn {} USDC on Sui → Mint on {} (domain {})",
+        format_units(request.amount, USDC_DECIMALS, USDC_DECIMALS),
+        dest.name(),
         request.dest_domain
     );
 
@@ -112,6 +131,132 @@ pub fn build_deposit_for_burn_ptb(
     })
 }
 
+fn coin_balance(coin: &CoinObject) -> u64 {
+    coin.balance.parse().unwrap_or(0)
+}
+
+/// Fewest USDC coins covering `amount`, largest first, and their total
+fn select_usdc_coins(
+    coins: &[CoinObject],
+    amount: u64,
+) -> Result<(Vec<&CoinObject>, u64), CctpSuiError> {
+    let mut coins: Vec<&CoinObject> = coins
+        .iter()
+        .filter(|c| c.coin_type == USDC_COIN_TYPE)
+        .collect();
+    coins.sort_by_key(|c| std::cmp::Reverse(coin_balance(c)));
+
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for coin in coins {
+        if total >= amount {
+            break;
+        }
+        total += coin_balance(coin);
+        selected.push(coin);
+    }
+    if selected.is_empty() {
+        return Err(CctpSuiError::CoinNotFound(USDC_COIN_TYPE.to_string()));
+    }
+    if total < amount {
+        return Err(CctpSuiError::InsufficientBalance);
+    }
+    Ok((selected, total))
+}
+
+/// Add `coins` as inputs and merge them into the first; returns it
+fn merge_selected(
+    ptb: &mut PtbBuilder,
+    coins: &[&CoinObject],
+) -> Result<PtbArgument, CctpSuiError> {
+    let mut inputs = Vec::new();
+    for coin in coins {
+        let version = coin.version.parse().map_err(|_| {
+            CctpSuiError::PtbBuildError(format!("bad version for {}", coin.coin_object_id))
+        })?;
+        inputs.push(ptb.add_object(&coin.coin_object_id, version, &coin.digest));
+    }
+    let primary = inputs.remove(0);
+    if !inputs.is_empty() {
+        ptb.merge_coins(primary.clone(), inputs);
+    }
+    Ok(primary)
+}
+
+/// Build a PTB returning stuck USDC to a user (failed intent refunds)
+///
+/// Uses as few of the holder's `coins` as cover `amount`, merges them,
+/// splits off the refund and transfers it to `recipient`.
+pub fn build_usdc_refund_ptb(
+    coins: &[CoinObject],
+    amount: u64,
+    recipient: &str,
+) -> Result<ProgrammableTransactionBlock, CctpSuiError> {
+    let (selected, _) = select_usdc_coins(coins, amount)?;
+
+    let mut ptb = PtbBuilder::new();
+    let primary = merge_selected(&mut ptb, &selected)?;
+    let amount = ptb.add_pure(&amount);
+    let refund = ptb.split_coins(primary, vec![amount]);
+    let recipient = ptb.add_pure(&recipient);
+    ptb.transfer_objects(vec![refund], recipient);
+
+    Ok(ptb.build())
+}
+
+/// What a holder must do to get a USDC coin of an exact amount
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum CoinPreparation {
+    /// A coin of exactly the amount already exists
+    Ready { coin_object_id: String },
+    /// Sign `ptb` first; it merges `merged` coins and, unless they add up
+    /// exactly, splits the amount off into a new coin for the owner
+    Prepare {
+        ptb: ProgrammableTransactionBlock,
+        merged: usize,
+        split: bool,
+    },
+}
+
+/// Get `owner` a USDC coin of exactly `amount` (burns take a whole coin)
+pub fn prepare_usdc_coin(
+    coins: &[CoinObject],
+    amount: u64,
+    owner: &str,
+) -> Result<CoinPreparation, CctpSuiError> {
+    if amount == 0 {
+        return Err(CctpSuiError::PtbBuildError(
+            "amount must be positive".to_string(),
+        ));
+    }
+    if let Some(coin) = coins
+        .iter()
+        .find(|c| c.coin_type == USDC_COIN_TYPE && coin_balance(c) == amount)
+    {
+        return Ok(CoinPreparation::Ready {
+            coin_object_id: coin.coin_object_id.clone(),
+        });
+    }
+
+    let (selected, total) = select_usdc_coins(coins, amount)?;
+    let mut ptb = PtbBuilder::new();
+    let primary = merge_selected(&mut ptb, &selected)?;
+    let split = total != amount;
+    if split {
+        let amount = ptb.add_pure(&amount);
+        let exact = ptb.split_coins(primary, vec![amount]);
+        let owner = ptb.add_pure(&owner);
+        ptb.transfer_objects(vec![exact], owner);
+    }
+
+    Ok(CoinPreparation::Prepare {
+        ptb: ptb.build(),
+        merged: selected.len(),
+        split,
+    })
+}
+
 /// Extract CCTP nonce from Sui transaction events
 pub fn extract_nonce_from_events(events: &[serde_json::Value]) -> Option<String> {
     // Look for DepositForBurn event and extract nonce
@@ -157,6 +302,12 @@ pub enum CctpSuiError {
 
     #[error("Insufficient balance")]
     InsufficientBalance,
+
+    #[error("Unsupported CCTP domain: {0}")]
+    UnsupportedDomain(u32),
+
+    #[error("zkLogin error: {0}")]
+    ZkLogin(#[from] ZkLoginError),
 }
 
 #[cfg(test)]
@@ -173,4 +324,94 @@ mod tests {
         );
         assert_eq!(padded.len(), 66); // 0x + 64 hex chars
     }
+
+    #[test]
+    fn test_usdc_refund_ptb_merges_coins() {
+        let coin = |id: &str, balance: u64| CoinObject {
+            coin_type: USDC_COIN_TYPE.to_string(),
+            coin_object_id: id.to_string(),
+            version: "7".to_string(),
+            digest: "d".to_string(),
+            balance: balance.to_string(),
+        };
+        let coins = vec![coin("0xa", 300_000), coin("0xb", 900_000), coin("0xc", 100)];
+
+        let ptb = build_usdc_refund_ptb(&coins, 1_000_000, "0xuser").unwrap();
+        // 0xb + 0xa cover the refund; merge, split, transfer
+        assert_eq!(ptb.commands.len(), 3);
+        assert!(build_usdc_refund_ptb(&coins, 2_000_000, "0xuser").is_err());
+    }
+
+    #[test]
+    fn test_prepare_exact_usdc_coin() {
+        let coin = |id: &str, balance: u64| CoinObject {
+            coin_type: USDC_COIN_TYPE.to_string(),
+            coin_object_id: id.to_string(),
+            version: "7".to_string(),
+            digest: "d".to_string(),
+            balance: balance.to_string(),
+        };
+        let coins = vec![coin("0xa", 300_000), coin("0xb", 700_000)];
+
+        assert!(matches!(
+            prepare_usdc_coin(&coins, 300_000, "0xuser").unwrap(),
+            CoinPreparation::Ready { coin_object_id } if coin_object_id == "0xa"
+        ));
+        // Merging both is exact, no split
+        let CoinPreparation::Prepare { ptb, merged, split } =
+            prepare_usdc_coin(&coins, 1_000_000, "0xuser").unwrap()
+        else {
+            panic!("expected a PTB");
+        };
+        assert_eq!((merged, split), (2, false));
+        assert_eq!(ptb.commands.len(), 1);
+        // One coin covers it: split, transfer
+        assert!(matches!(
+            prepare_usdc_coin(&coins, 500_000, "0xuser").unwrap(),
+            CoinPreparation::Prepare {
+                merged: 1,
+                split: true,
+                ..
+            }
+        ));
+        assert!(prepare_usdc_coin(&coins, 0, "0xuser").is_err());
+    }
+
+    #[test]
+    fn test_zklogin_sender_must_match() {
+        let zklogin = ZkLoginSender {
+            iss: "https://accounts.google.com".to_string(),
+            address_seed: "12345".to_string(),
+        };
+        let mut request = DepositForBurnRequest {
+            sender: "0x1".to_string(),
+            amount: 1_000_000,
+            evm_destination: "0x1234567890123456789012345678901234567890".to_string(),
+            dest_domain: EvmChain::BaseSepolia.cctp_domain(),
+            zklogin: Some(zklogin.clone()),
+        };
+        assert!(build_deposit_for_burn_ptb(&request, "0xcoin").is_err());
+
+        request.sender = zklogin.address().unwrap();
+        assert!(build_deposit_for_burn_ptb(&request, "0xcoin").is_ok());
+    }
+
+    #[test]
+    fn test_dest_domain_must_be_evm() {
+        let mut request = DepositForBurnRequest {
+            sender: "0x1".to_string(),
+            amount: 1_000_000,
+            evm_destination: "0x1234567890123456789012345678901234567890".to_string(),
+            dest_domain: EvmChain::BaseSepolia.cctp_domain(),
+            zklogin: None,
+        };
+        let response = build_deposit_for_burn_ptb(&request, "0xcoin").unwrap();
+        assert!(response.summary.contains("Base Sepolia (domain 6)"));
+
+        request.dest_domain = CCTP_DOMAIN_SUI;
+        assert!(matches!(
+            build_deposit_for_burn_ptb(&request, "0xcoin"),
+            Err(CctpSuiError::UnsupportedDomain(8))
+        ));
+    }
 }
@@ -1,24 +1,206 @@
-//! Sui RPC client using JSON-RPC
+//! Sui client facade
+//!
+//! `SuiClient` picks a transport (JSON-RPC or GraphQL) from `SuiConfig`
+//! and exposes the same API regardless of which one is in use.
 
+use std::sync::Arc;
+
+use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::SuiConfig;
+use crate::effects::{ExecutionStatus, FulfillmentOutcome, GasCostSummary, ObjectChange};
+use crate::graphql::GraphQlTransport;
+use crate::rate_limit::{self, method_weight, RateLimitConfig, RateLimiter};
+use crate::suins::normalize_suins;
+use crate::transport::{
+    CoinPage, EventPage, SuiEvent, SuiTransport, ValidatorInfo, ZkLoginVerifyResult,
+};
+use crate::{SuiConfig, SuiTransportKind};
 
-/// Sui RPC client
+/// Sui client
 pub struct SuiClient {
     config: SuiConfig,
-    client: Client,
+    transport: Box<dyn SuiTransport>,
 }
 
 impl SuiClient {
     pub fn new(config: SuiConfig) -> Self {
+        let transport: Box<dyn SuiTransport> = match config.transport {
+            SuiTransportKind::JsonRpc => Box::new(
+                JsonRpcTransport::new(config.rpc_url.clone()).with_rate_limit(config.rate_limit),
+            ),
+            SuiTransportKind::GraphQl => Box::new(
+                GraphQlTransport::new(config.graphql_url.clone())
+                    .with_rate_limit(config.rate_limit),
+            ),
+        };
+
+        Self { config, transport }
+    }
+
+    /// Create a client with a custom transport
+    pub fn with_transport(config: SuiConfig, transport: Box<dyn SuiTransport>) -> Self {
+        Self { config, transport }
+    }
+
+    /// Name of the active transport
+    pub fn transport_name(&self) -> &'static str {
+        self.transport.name()
+    }
+
+    /// Get every coin owned by an address, following page cursors
+    pub async fn get_coins(
+        &self,
+        owner: &str,
+        coin_type: Option<&str>,
+    ) -> Result<Vec<CoinObject>, SuiClientError> {
+        let mut coins = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .transport
+                .get_coins(owner, coin_type, cursor.as_deref())
+                .await?;
+            coins.extend(page.data);
+
+            // A cursor that doesn't move would loop forever
+            if !page.has_next_page || page.next_cursor.is_none() || page.next_cursor == cursor {
+                return Ok(coins);
+            }
+            cursor = page.next_cursor;
+        }
+    }
+
+    /// Get USDC balance for an address
+    pub async fn get_usdc_balance(&self, owner: &str) -> Result<u64, SuiClientError> {
+        let coins = self
+            .get_coins(owner, Some(&self.config.usdc_coin_type))
+            .await?;
+        let total: u64 = coins
+            .iter()
+            .map(|c| c.balance.parse::<u64>().unwrap_or(0))
+            .sum();
+        Ok(total)
+    }
+
+    /// Balance and coin layout of one coin type at an address
+    pub async fn get_coin_summary(
+        &self,
+        owner: &str,
+        coin_type: &str,
+    ) -> Result<CoinSummary, SuiClientError> {
+        let coins = self.get_coins(owner, Some(coin_type)).await?;
+        Ok(CoinSummary::from_coins(coin_type, &coins))
+    }
+
+    /// Get object by ID
+    pub async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
+        self.transport.get_object(object_id).await
+    }
+
+    /// Get a dynamic field object by its name (`{"type": .., "value": ..}`)
+    pub async fn get_dynamic_field_object(
+        &self,
+        parent_id: &str,
+        name: serde_json::Value,
+    ) -> Result<SuiObject, SuiClientError> {
+        self.transport
+            .get_dynamic_field_object(parent_id, name)
+            .await
+    }
+
+    /// Query Move events by type
+    pub async fn query_events(
+        &self,
+        event_type: &str,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError> {
+        self.transport.query_events(event_type, cursor, limit).await
+    }
+
+    /// Execute a transaction
+    pub async fn execute_transaction(
+        &self,
+        tx_bytes: &str,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiClientError> {
+        self.transport
+            .execute_transaction(tx_bytes, signatures)
+            .await
+    }
+
+    /// Dry run a transaction
+    pub async fn dry_run_transaction(
+        &self,
+        tx_bytes: &str,
+    ) -> Result<DryRunResponse, SuiClientError> {
+        self.transport.dry_run_transaction(tx_bytes).await
+    }
+
+    /// Reference gas price of the current epoch (MIST per gas unit)
+    pub async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+        self.transport.get_reference_gas_price().await
+    }
+
+    /// Check a zkLogin signature over a personal message (base64 bytes)
+    pub async fn verify_zklogin_signature(
+        &self,
+        message: &str,
+        signature: &str,
+        author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+        self.transport
+            .verify_zklogin_signature(message, signature, author)
+            .await
+    }
+
+    /// Active validators of the current epoch
+    pub async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+        self.transport.get_validators().await
+    }
+
+    /// Address a SuiNS name (`alice.sui`, `@alice`) points at; `None` if
+    /// it isn't registered
+    pub async fn resolve_suins(&self, name: &str) -> Result<Option<String>, SuiClientError> {
+        let name = normalize_suins(name)
+            .ok_or_else(|| SuiClientError::Parse(format!("not a SuiNS name: {}", name)))?;
+        self.transport.resolve_name(&name).await
+    }
+
+    /// Default SuiNS name of an address
+    pub async fn suins_name(&self, address: &str) -> Result<Option<String>, SuiClientError> {
+        self.transport.default_name(address).await
+    }
+}
+
+/// JSON-RPC transport
+pub struct JsonRpcTransport {
+    rpc_url: String,
+    client: Client,
+    /// Shared with every other transport on `rpc_url`
+    limiter: Arc<RateLimiter>,
+}
+
+impl JsonRpcTransport {
+    pub fn new(rpc_url: String) -> Self {
+        let limiter = RateLimiter::shared(&rpc_url, RateLimitConfig::from_env());
         Self {
-            config,
+            rpc_url,
             client: Client::new(),
+            limiter,
         }
     }
 
+    /// Throttle with `config` instead of the environment's limits
+    ///
+    /// Only takes effect if no transport on the endpoint has a limiter yet.
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.limiter = RateLimiter::shared(&self.rpc_url, config);
+        self
+    }
+
     /// Make a JSON-RPC call
     async fn rpc_call<T: for<'de> Deserialize<'de>>(
         &self,
@@ -32,13 +214,10 @@ impl SuiClient {
             "params": params
         });
 
-        let response = self
-            .client
-            .post(&self.config.rpc_url)
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| SuiClientError::Request(e.to_string()))?;
+        let response = rate_limit::send(&self.limiter, method_weight(method), || {
+            self.client.post(&self.rpc_url).json(&request)
+        })
+        .await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -68,36 +247,33 @@ impl SuiClient {
             }
         }
     }
+}
 
-    /// Get coins owned by an address
-    pub async fn get_coins(
+#[async_trait]
+impl SuiTransport for JsonRpcTransport {
+    fn name(&self) -> &'static str {
+        "jsonrpc"
+    }
+
+    async fn get_coins(
         &self,
         owner: &str,
         coin_type: Option<&str>,
-    ) -> Result<Vec<CoinObject>, SuiClientError> {
+        cursor: Option<&str>,
+    ) -> Result<CoinPage, SuiClientError> {
         let params = serde_json::json!([
-            owner, coin_type, null, // cursor
-            null  // limit
+            owner, coin_type, cursor, null // limit
         ]);
 
         let response: CoinsResponse = self.rpc_call("suix_getCoins", params).await?;
-        Ok(response.data)
+        Ok(CoinPage {
+            data: response.data,
+            next_cursor: response.next_cursor,
+            has_next_page: response.has_next_page,
+        })
     }
 
-    /// Get USDC balance for an address
-    pub async fn get_usdc_balance(&self, owner: &str) -> Result<u64, SuiClientError> {
-        let coins = self
-            .get_coins(owner, Some(&self.config.usdc_coin_type))
-            .await?;
-        let total: u64 = coins
-            .iter()
-            .map(|c| c.balance.parse::<u64>().unwrap_or(0))
-            .sum();
-        Ok(total)
-    }
-
-    /// Get object by ID
-    pub async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
+    async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
         let params = serde_json::json!([
             object_id,
             {
@@ -113,8 +289,53 @@ impl SuiClient {
             .ok_or(SuiClientError::ObjectNotFound(object_id.to_string()))
     }
 
-    /// Execute a transaction
-    pub async fn execute_transaction(
+    async fn get_dynamic_field_object(
+        &self,
+        parent_id: &str,
+        name: serde_json::Value,
+    ) -> Result<SuiObject, SuiClientError> {
+        let params = serde_json::json!([parent_id, name.clone()]);
+        let response: ObjectResponse = self.rpc_call("suix_getDynamicFieldObject", params).await?;
+        response
+            .data
+            .ok_or_else(|| SuiClientError::ObjectNotFound(format!("{}[{}]", parent_id, name)))
+    }
+
+    async fn query_events(
+        &self,
+        event_type: &str,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError> {
+        // JSON-RPC cursors are {txDigest, eventSeq}; we flatten them to "digest:seq"
+        let cursor = cursor.and_then(|c| {
+            let (tx_digest, event_seq) = c.split_once(':')?;
+            Some(serde_json::json!({ "txDigest": tx_digest, "eventSeq": event_seq }))
+        });
+        let params = serde_json::json!([{ "MoveEventType": event_type }, cursor, limit, false]);
+
+        let response: EventsResponse = self.rpc_call("suix_queryEvents", params).await?;
+
+        Ok(EventPage {
+            data: response
+                .data
+                .into_iter()
+                .map(|e| SuiEvent {
+                    event_type: e.r#type,
+                    parsed_json: e.parsed_json,
+                    sender: e.sender,
+                    tx_digest: Some(e.id.tx_digest),
+                    timestamp_ms: e.timestamp_ms.and_then(|t| t.parse().ok()),
+                })
+                .collect(),
+            next_cursor: response
+                .next_cursor
+                .map(|c| format!("{}:{}", c.tx_digest, c.event_seq)),
+            has_next_page: response.has_next_page,
+        })
+    }
+
+    async fn execute_transaction(
         &self,
         tx_bytes: &str,
         signatures: Vec<String>,
@@ -125,7 +346,8 @@ impl SuiClient {
             {
                 "showInput": true,
                 "showEffects": true,
-                "showEvents": true
+                "showEvents": true,
+                "showObjectChanges": true
             },
             "WaitForLocalExecution"
         ]);
@@ -133,14 +355,96 @@ impl SuiClient {
         self.rpc_call("sui_executeTransactionBlock", params).await
     }
 
-    /// Dry run a transaction
-    pub async fn dry_run_transaction(
-        &self,
-        tx_bytes: &str,
-    ) -> Result<DryRunResponse, SuiClientError> {
+    async fn dry_run_transaction(&self, tx_bytes: &str) -> Result<DryRunResponse, SuiClientError> {
         let params = serde_json::json!([tx_bytes]);
         self.rpc_call("sui_dryRunTransactionBlock", params).await
     }
+
+    async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+        // BigInt, returned as a string
+        let price: serde_json::Value = self
+            .rpc_call("suix_getReferenceGasPrice", serde_json::json!([]))
+            .await?;
+        price
+            .as_str()
+            .and_then(|p| p.parse().ok())
+            .or_else(|| price.as_u64())
+            .ok_or_else(|| SuiClientError::Parse(format!("invalid gas price: {}", price)))
+    }
+
+    async fn verify_zklogin_signature(
+        &self,
+        message: &str,
+        signature: &str,
+        author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+        let params = serde_json::json!([message, signature, "PersonalMessage", author]);
+        self.rpc_call("sui_verifyZkLoginSignature", params).await
+    }
+
+    async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+        let state: serde_json::Value = self
+            .rpc_call("suix_getLatestSuiSystemState", serde_json::json!([]))
+            .await?;
+        let apys: serde_json::Value = self
+            .rpc_call("suix_getValidatorsApy", serde_json::json!([]))
+            .await?;
+        Ok(parse_validators(&state, &apys))
+    }
+
+    async fn resolve_name(&self, name: &str) -> Result<Option<String>, SuiClientError> {
+        self.rpc_call("suix_resolveNameServiceAddress", serde_json::json!([name]))
+            .await
+    }
+
+    async fn default_name(&self, address: &str) -> Result<Option<String>, SuiClientError> {
+        // Paginated; the first name is the default one
+        let names: serde_json::Value = self
+            .rpc_call(
+                "suix_resolveNameServiceNames",
+                serde_json::json!([address, null, 1]),
+            )
+            .await?;
+        Ok(names["data"][0].as_str().map(|n| n.to_string()))
+    }
+}
+
+/// Map `suix_getLatestSuiSystemState` validators, with `suix_getValidatorsApy`
+fn parse_validators(state: &serde_json::Value, apys: &serde_json::Value) -> Vec<ValidatorInfo> {
+    // u64 fields are BigInt strings
+    let number = |v: &serde_json::Value| {
+        v.as_str()
+            .and_then(|s| s.parse().ok())
+            .or_else(|| v.as_u64())
+            .unwrap_or(0)
+    };
+    let apy = |address: &str| {
+        apys["apys"]
+            .as_array()
+            .and_then(|apys| apys.iter().find(|a| a["address"] == address))
+            .and_then(|a| a["apy"].as_f64())
+            .unwrap_or(0.0)
+    };
+
+    state["activeValidators"]
+        .as_array()
+        .map(|validators| {
+            validators
+                .iter()
+                .filter_map(|v| {
+                    let address = v["suiAddress"].as_str()?;
+                    Some(ValidatorInfo::new(
+                        address,
+                        v["name"].as_str().unwrap_or_default(),
+                        apy(address),
+                        number(&v["commissionRate"]),
+                        number(&v["stakingPoolSuiBalance"]),
+                        number(&v["votingPower"]),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 // RPC Types
@@ -161,6 +465,8 @@ pub struct CoinsResponse {
     pub data: Vec<CoinObject>,
     #[serde(rename = "nextCursor")]
     pub next_cursor: Option<String>,
+    #[serde(default, rename = "hasNextPage")]
+    pub has_next_page: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -173,6 +479,73 @@ pub struct CoinObject {
     pub balance: String,
 }
 
+/// Balance of one coin type, with what a PTB spending it needs to know
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CoinSummary {
+    pub coin_type: String,
+    /// Total balance (base units)
+    pub balance: u64,
+    pub coin_count: usize,
+    /// Coin with the highest balance
+    pub largest_coin_id: Option<String>,
+    pub largest_coin_balance: u64,
+}
+
+impl CoinSummary {
+    pub fn from_coins(coin_type: &str, coins: &[CoinObject]) -> Self {
+        let balances: Vec<(&CoinObject, u64)> = coins
+            .iter()
+            .filter(|c| c.coin_type == coin_type)
+            .map(|c| (c, c.balance.parse::<u64>().unwrap_or(0)))
+            .collect();
+        let largest = balances.iter().max_by_key(|(_, balance)| *balance);
+        Self {
+            coin_type: coin_type.to_string(),
+            balance: balances.iter().map(|(_, balance)| balance).sum(),
+            coin_count: balances.len(),
+            largest_coin_id: largest.map(|(c, _)| c.coin_object_id.clone()),
+            largest_coin_balance: largest.map_or(0, |(_, balance)| *balance),
+        }
+    }
+
+    /// Whether the balance covers `amount`
+    pub fn covers(&self, amount: u64) -> bool {
+        self.balance >= amount
+    }
+
+    /// Whether spending `amount` needs coins merged first
+    pub fn needs_merge(&self, amount: u64) -> bool {
+        self.covers(amount) && self.largest_coin_balance < amount
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventsResponse {
+    data: Vec<RpcEvent>,
+    next_cursor: Option<EventId>,
+    #[serde(default)]
+    has_next_page: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcEvent {
+    id: EventId,
+    r#type: String,
+    #[serde(default)]
+    parsed_json: serde_json::Value,
+    sender: Option<String>,
+    timestamp_ms: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventId {
+    tx_digest: String,
+    event_seq: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ObjectResponse {
     pub data: Option<SuiObject>,
@@ -195,6 +568,8 @@ pub struct TransactionResponse {
     pub digest: String,
     pub effects: TransactionEffects,
     pub events: Option<Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub object_changes: Vec<ObjectChange>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -207,6 +582,9 @@ pub struct TransactionEffects {
 #[derive(Debug, Deserialize)]
 pub struct TransactionStatus {
     pub status: String, // "success" or "failure"
+    /// Abort or execution error of a failed transaction
+    #[serde(default)]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -214,6 +592,38 @@ pub struct TransactionStatus {
 pub struct GasUsed {
     pub computation_cost: String,
     pub storage_cost: String,
+    #[serde(default)]
+    pub storage_rebate: Option<String>,
+}
+
+impl From<TransactionResponse> for FulfillmentOutcome {
+    fn from(response: TransactionResponse) -> Self {
+        let created_objects = response
+            .object_changes
+            .iter()
+            .filter_map(ObjectChange::created)
+            .collect();
+        let mist = |value: Option<&str>| value.and_then(|v| v.parse().ok()).unwrap_or(0);
+        let TransactionEffects { status, gas_used } = response.effects;
+
+        FulfillmentOutcome {
+            digest: response.digest,
+            created_objects,
+            gas_used: GasCostSummary {
+                computation_cost: mist(Some(&gas_used.computation_cost)),
+                storage_cost: mist(Some(&gas_used.storage_cost)),
+                storage_rebate: mist(gas_used.storage_rebate.as_deref()),
+                non_refundable_storage_fee: 0,
+            },
+            status: if status.status == "success" {
+                ExecutionStatus::Success
+            } else {
+                ExecutionStatus::Failure {
+                    error: status.error.unwrap_or(status.status),
+                }
+            },
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -221,6 +631,8 @@ pub struct GasUsed {
 pub struct DryRunResponse {
     pub effects: TransactionEffects,
     pub events: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub object_changes: Vec<ObjectChange>,
 }
 
 /// Sui client errors
@@ -244,3 +656,190 @@ pub enum SuiClientError {
     #[error("Insufficient balance")]
     InsufficientBalance,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(id: &str, coin_type: &str, balance: u64) -> CoinObject {
+        CoinObject {
+            coin_type: coin_type.to_string(),
+            coin_object_id: id.to_string(),
+            version: "1".to_string(),
+            digest: "d".to_string(),
+            balance: balance.to_string(),
+        }
+    }
+
+    /// Serves coins `per_page` at a time, with the index as cursor
+    struct PagedCoins {
+        coins: Vec<CoinObject>,
+        per_page: usize,
+    }
+
+    #[async_trait]
+    impl SuiTransport for PagedCoins {
+        fn name(&self) -> &'static str {
+            "paged-coins"
+        }
+
+        async fn get_coins(
+            &self,
+            _owner: &str,
+            _coin_type: Option<&str>,
+            cursor: Option<&str>,
+        ) -> Result<CoinPage, SuiClientError> {
+            let start = cursor.map_or(0, |c| c.parse().unwrap());
+            let end = (start + self.per_page).min(self.coins.len());
+            Ok(CoinPage {
+                data: self.coins[start..end].to_vec(),
+                next_cursor: Some(end.to_string()),
+                has_next_page: end < self.coins.len(),
+            })
+        }
+
+        async fn get_object(&self, _: &str) -> Result<SuiObject, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn get_dynamic_field_object(
+            &self,
+            _: &str,
+            _: serde_json::Value,
+        ) -> Result<SuiObject, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn query_events(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: usize,
+        ) -> Result<EventPage, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn dry_run_transaction(&self, _: &str) -> Result<DryRunResponse, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn execute_transaction(
+            &self,
+            _: &str,
+            _: Vec<String>,
+        ) -> Result<TransactionResponse, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn verify_zklogin_signature(
+            &self,
+            _: &str,
+            _: &str,
+            _: &str,
+        ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+            unimplemented!()
+        }
+
+        async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coin_summary_spans_pages() {
+        // 120 coins of 10, then one of 5_000 on the last page
+        let mut coins: Vec<CoinObject> = (0..120)
+            .map(|i| coin(&format!("0x{:x}", i), "0x2::sui::SUI", 10))
+            .collect();
+        coins.push(coin("0xbig", "0x2::sui::SUI", 5_000));
+        let client = SuiClient::with_transport(
+            SuiConfig::testnet(),
+            Box::new(PagedCoins {
+                coins,
+                per_page: 50,
+            }),
+        );
+
+        let summary = client
+            .get_coin_summary("0xowner", "0x2::sui::SUI")
+            .await
+            .unwrap();
+        assert_eq!((summary.balance, summary.coin_count), (6_200, 121));
+        assert_eq!(summary.largest_coin_id.as_deref(), Some("0xbig"));
+        assert!(summary.covers(6_000));
+        assert!(summary.needs_merge(6_000));
+    }
+
+    #[test]
+    fn test_coin_summary() {
+        let coins = vec![
+            coin("0xa", "0x2::usdc::USDC", 400),
+            coin("0xb", "0x2::usdc::USDC", 700),
+            coin("0xc", "0x2::sui::SUI", 5_000),
+        ];
+
+        let summary = CoinSummary::from_coins("0x2::usdc::USDC", &coins);
+        assert_eq!((summary.balance, summary.coin_count), (1_100, 2));
+        assert_eq!(summary.largest_coin_id.as_deref(), Some("0xb"));
+        assert!(!summary.needs_merge(700));
+        assert!(summary.needs_merge(1_000));
+        assert!(!summary.covers(1_200));
+
+        let empty = CoinSummary::from_coins("0x2::usdc::USDC", &[]);
+        assert_eq!(empty.largest_coin_id, None);
+        assert!(!empty.needs_merge(1));
+    }
+
+    #[test]
+    fn test_parse_validators() {
+        let state = serde_json::json!({
+            "activeValidators": [
+                {
+                    "suiAddress": "0xv1",
+                    "name": "Blockscope",
+                    "commissionRate": "200",
+                    "stakingPoolSuiBalance": "5000000000",
+                    "votingPower": "1000"
+                },
+                { "suiAddress": "0xv2", "name": "Other", "votingPower": 250 }
+            ]
+        });
+        let apys = serde_json::json!({ "apys": [{ "address": "0xv1", "apy": 0.031 }] });
+
+        let validators = parse_validators(&state, &apys);
+        assert_eq!(
+            validators[0],
+            ValidatorInfo::new("0xv1", "Blockscope", 0.031, 200, 5_000_000_000, 1_000)
+        );
+        assert!(validators[0].is_saturated());
+        assert_eq!(validators[1].apy, 0.0);
+        assert_eq!(validators[1].saturation, 0.25);
+    }
+
+    #[test]
+    fn test_failed_response_to_outcome() {
+        let response: TransactionResponse = serde_json::from_value(serde_json::json!({
+            "digest": "0xd",
+            "effects": {
+                "status": { "status": "failure", "error": "MoveAbort(1)" },
+                "gasUsed": { "computationCost": "1000", "storageCost": "300" }
+            },
+            "objectChanges": [{ "type": "unknownKind" }]
+        }))
+        .unwrap();
+
+        let outcome = FulfillmentOutcome::from(response);
+        assert_eq!(
+            outcome.status,
+            ExecutionStatus::Failure {
+                error: "MoveAbort(1)".to_string()
+            }
+        );
+        assert!(outcome.created_objects.is_empty());
+        assert_eq!(outcome.gas_used.net(), 1_300);
+    }
+}
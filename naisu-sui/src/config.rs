@@ -1,12 +1,40 @@
 //! Sui configuration
 
-use naisu_core::SuiNetwork;
+use naisu_core::{ExplorerLink, SuiNetwork};
+
+use crate::rate_limit::RateLimitConfig;
+
+/// Transport used to talk to Sui full nodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SuiTransportKind {
+    /// Classic JSON-RPC (being deprecated upstream)
+    #[default]
+    JsonRpc,
+    /// Sui GraphQL service
+    GraphQl,
+}
+
+impl std::str::FromStr for SuiTransportKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jsonrpc" | "json-rpc" | "rpc" => Ok(SuiTransportKind::JsonRpc),
+            "graphql" | "gql" => Ok(SuiTransportKind::GraphQl),
+            _ => Err(format!("Unknown Sui transport: {}", s)),
+        }
+    }
+}
 
 /// Sui chain configuration
 #[derive(Debug, Clone)]
 pub struct SuiConfig {
     pub network: SuiNetwork,
     pub rpc_url: String,
+    /// GraphQL endpoint (used when `transport` is GraphQl)
+    pub graphql_url: String,
+    /// Which transport the SuiClient should use
+    pub transport: SuiTransportKind,
     pub private_key: Option<String>,
     /// Scallop protocol package ID
     pub scallop_package: Option<String>,
@@ -14,6 +42,8 @@ pub struct SuiConfig {
     pub navi_package: Option<String>,
     /// USDC coin type on Sui
     pub usdc_coin_type: String,
+    /// Client-side throttling of calls to the endpoint
+    pub rate_limit: RateLimitConfig,
 }
 
 impl SuiConfig {
@@ -21,6 +51,8 @@ impl SuiConfig {
         Self {
             network: SuiNetwork::Testnet,
             rpc_url: SuiNetwork::Testnet.rpc_url().to_string(),
+            graphql_url: SuiNetwork::Testnet.graphql_url().to_string(),
+            transport: SuiTransportKind::default(),
             private_key: None,
             scallop_package: None,
             navi_package: None,
@@ -28,6 +60,7 @@ impl SuiConfig {
             usdc_coin_type:
                 "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
                     .to_string(),
+            rate_limit: RateLimitConfig::from_env(),
         }
     }
 
@@ -35,15 +68,23 @@ impl SuiConfig {
         Self {
             network: SuiNetwork::Mainnet,
             rpc_url: SuiNetwork::Mainnet.rpc_url().to_string(),
+            graphql_url: SuiNetwork::Mainnet.graphql_url().to_string(),
+            transport: SuiTransportKind::default(),
             private_key: None,
             scallop_package: Some("0x...".to_string()), // Actual Scallop package
             navi_package: Some("0x...".to_string()),    // Actual Navi package
             usdc_coin_type:
                 "0x5d4b302506645c37ff133b98c4b50a5ae14841659738d6d733d59d0d217a93bf::coin::COIN"
                     .to_string(),
+            rate_limit: RateLimitConfig::from_env(),
         }
     }
 
+    /// Explorer links for this config's network
+    pub fn explorer(&self) -> ExplorerLink {
+        ExplorerLink::sui(self.network)
+    }
+
     pub fn with_private_key(mut self, key: String) -> Self {
         self.private_key = Some(key);
         self
@@ -58,4 +99,19 @@ impl SuiConfig {
         self.navi_package = Some(package);
         self
     }
+
+    pub fn with_transport(mut self, transport: SuiTransportKind) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn with_graphql_url(mut self, url: String) -> Self {
+        self.graphql_url = url;
+        self
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
 }
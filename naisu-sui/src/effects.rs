@@ -0,0 +1,473 @@
+//! Typed transaction effects and object changes
+//!
+//! Parses the JSON returned by `sui_executeTransactionBlock` (and
+//! `sui client ptb --json`) into typed structures, so executors can report
+//! which objects a fulfillment created (StakedSui, sSUI, Position, ...).
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+// ─── Object changes ──────────────────────────────────────────────────────────
+
+/// Object owner as reported by Sui
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Owner {
+    AddressOwner(String),
+    ObjectOwner(String),
+    Shared {
+        #[serde(deserialize_with = "de_u64")]
+        initial_shared_version: u64,
+    },
+    Immutable,
+    /// An owner kind this code doesn't know yet (e.g. `ConsensusAddressOwner`)
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl Owner {
+    /// Address that owns the object, if address-owned
+    pub fn address(&self) -> Option<&str> {
+        match self {
+            Owner::AddressOwner(addr) => Some(addr),
+            _ => None,
+        }
+    }
+}
+
+/// A single entry of `objectChanges`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ObjectChange {
+    #[serde(rename_all = "camelCase")]
+    Created {
+        sender: String,
+        owner: Owner,
+        object_type: String,
+        object_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Mutated {
+        sender: String,
+        owner: Owner,
+        object_type: String,
+        object_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+        #[serde(deserialize_with = "de_u64")]
+        previous_version: u64,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Transferred {
+        sender: String,
+        recipient: Owner,
+        object_type: String,
+        object_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Deleted {
+        sender: String,
+        object_type: String,
+        object_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Wrapped {
+        sender: String,
+        object_type: String,
+        object_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    Published {
+        package_id: String,
+        #[serde(deserialize_with = "de_u64")]
+        version: u64,
+        digest: String,
+        modules: Vec<String>,
+    },
+    /// A change type this code doesn't know yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl ObjectChange {
+    /// The created object, if this change is a creation
+    pub fn created(&self) -> Option<CreatedObject> {
+        match self {
+            ObjectChange::Created {
+                owner,
+                object_type,
+                object_id,
+                version,
+                ..
+            } => Some(CreatedObject {
+                object_id: object_id.clone(),
+                object_type: object_type.clone(),
+                owner: owner.clone(),
+                version: *version,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// An object created by a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedObject {
+    pub object_id: String,
+    pub object_type: String,
+    pub owner: Owner,
+    pub version: u64,
+}
+
+impl CreatedObject {
+    /// Check whether the Move type matches, e.g. `::staking_pool::StakedSui`
+    pub fn is_type(&self, type_fragment: &str) -> bool {
+        self.object_type.contains(type_fragment)
+    }
+}
+
+/// An object a transaction must leave with `recipient`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedTransfer {
+    pub recipient: String,
+    /// Move type fragment, e.g. `::position::Position`
+    pub type_fragment: String,
+}
+
+impl ExpectedTransfer {
+    pub fn new(recipient: impl Into<String>, type_fragment: impl Into<String>) -> Self {
+        Self {
+            recipient: recipient.into(),
+            type_fragment: type_fragment.into(),
+        }
+    }
+}
+
+// ─── Effects ─────────────────────────────────────────────────────────────────
+
+/// Gas cost summary (all values in MIST)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasCostSummary {
+    #[serde(deserialize_with = "de_u64")]
+    pub computation_cost: u64,
+    #[serde(deserialize_with = "de_u64")]
+    pub storage_cost: u64,
+    #[serde(deserialize_with = "de_u64")]
+    pub storage_rebate: u64,
+    #[serde(default, deserialize_with = "de_u64")]
+    pub non_refundable_storage_fee: u64,
+}
+
+impl GasCostSummary {
+    /// Net gas paid (computation + storage - rebate), may be negative
+    pub fn net(&self) -> i64 {
+        self.computation_cost as i64 + self.storage_cost as i64 - self.storage_rebate as i64
+    }
+}
+
+/// Execution status of a transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum ExecutionStatus {
+    Success,
+    Failure { error: String },
+}
+
+impl ExecutionStatus {
+    pub fn is_success(&self) -> bool {
+        matches!(self, ExecutionStatus::Success)
+    }
+}
+
+/// The subset of `effects` we care about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedEffects {
+    pub status: ExecutionStatus,
+    pub gas_used: GasCostSummary,
+}
+
+/// Executed transaction with typed effects and object changes
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutedTransaction {
+    pub digest: String,
+    pub effects: TypedEffects,
+    #[serde(default)]
+    pub object_changes: Vec<ObjectChange>,
+}
+
+impl ExecutedTransaction {
+    /// Parse from a JSON-RPC / CLI `--json` response
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, EffectsError> {
+        serde_json::from_value(value.clone()).map_err(|e| EffectsError::Parse(e.to_string()))
+    }
+
+    /// Objects created by this transaction
+    pub fn created_objects(&self) -> Vec<CreatedObject> {
+        self.object_changes
+            .iter()
+            .filter_map(ObjectChange::created)
+            .collect()
+    }
+}
+
+// ─── Fulfillment outcome ─────────────────────────────────────────────────────
+
+/// Structured result of a fulfillment transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulfillmentOutcome {
+    pub digest: String,
+    /// Objects created for the user/solver (StakedSui, sSUI, Position, ...)
+    pub created_objects: Vec<CreatedObject>,
+    pub gas_used: GasCostSummary,
+    pub status: ExecutionStatus,
+}
+
+impl FulfillmentOutcome {
+    /// Parse from a JSON-RPC / CLI `--json` response
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, EffectsError> {
+        Ok(ExecutedTransaction::from_json(value)?.into())
+    }
+
+    /// Parse a dry run response (`sui_dryRunTransactionBlock`, CLI
+    /// `--dry-run --json`), which carries the digest inside its effects
+    pub fn from_dry_run_json(value: &serde_json::Value) -> Result<Self, EffectsError> {
+        let mut value = value.clone();
+        let digest = value["effects"]["transactionDigest"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        if let Some(object) = value.as_object_mut() {
+            object.insert("digest".to_string(), digest.into());
+        }
+        Self::from_json(&value)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    /// Find the first created object whose type matches
+    pub fn created_of_type(&self, type_fragment: &str) -> Option<&CreatedObject> {
+        self.created_objects
+            .iter()
+            .find(|o| o.is_type(type_fragment))
+    }
+
+    /// Objects created and owned by `address`
+    pub fn created_for(&self, address: &str) -> Vec<&CreatedObject> {
+        self.created_objects
+            .iter()
+            .filter(|o| o.owner.address() == Some(address))
+            .collect()
+    }
+
+    /// Check that every expected object was created for its recipient
+    ///
+    /// Meant for dry runs: a transfer command fed the wrong result still
+    /// executes, leaving the object with the solver or handing the user
+    /// something else.
+    pub fn verify_transfers(&self, expected: &[ExpectedTransfer]) -> Result<(), EffectsError> {
+        for transfer in expected {
+            let received = self.created_for(&transfer.recipient);
+            if !received.iter().any(|o| o.is_type(&transfer.type_fragment)) {
+                return Err(EffectsError::MissingTransfer {
+                    recipient: transfer.recipient.clone(),
+                    expected: transfer.type_fragment.clone(),
+                    received: received.iter().map(|o| o.object_type.clone()).collect(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<ExecutedTransaction> for FulfillmentOutcome {
+    fn from(tx: ExecutedTransaction) -> Self {
+        Self {
+            created_objects: tx.created_objects(),
+            digest: tx.digest,
+            gas_used: tx.effects.gas_used,
+            status: tx.effects.status,
+        }
+    }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+/// Sui encodes u64 as strings in JSON-RPC but as numbers in some places
+fn de_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum U64OrString {
+        Num(u64),
+        Str(String),
+    }
+
+    match U64OrString::deserialize(deserializer)? {
+        U64OrString::Num(n) => Ok(n),
+        U64OrString::Str(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum EffectsError {
+    #[error("Failed to parse transaction effects: {0}")]
+    Parse(String),
+    #[error("{recipient} would not receive a `{expected}` (receives: {received:?})")]
+    MissingTransfer {
+        recipient: String,
+        expected: String,
+        received: Vec<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_response() -> serde_json::Value {
+        serde_json::json!({
+            "digest": "9xQmTx",
+            "effects": {
+                "status": { "status": "success" },
+                "gasUsed": {
+                    "computationCost": "1000000",
+                    "storageCost": "2000000",
+                    "storageRebate": "500000",
+                    "nonRefundableStorageFee": "5000"
+                }
+            },
+            "objectChanges": [
+                {
+                    "type": "mutated",
+                    "sender": "0xsolver",
+                    "owner": { "AddressOwner": "0xsolver" },
+                    "objectType": "0x2::coin::Coin<0x2::sui::SUI>",
+                    "objectId": "0xgas",
+                    "version": "11",
+                    "previousVersion": "10",
+                    "digest": "d1"
+                },
+                {
+                    "type": "created",
+                    "sender": "0xsolver",
+                    "owner": { "AddressOwner": "0xuser" },
+                    "objectType": "0x3::staking_pool::StakedSui",
+                    "objectId": "0xstaked",
+                    "version": "11",
+                    "digest": "d2"
+                },
+                {
+                    "type": "mutated",
+                    "sender": "0xsolver",
+                    "owner": { "Shared": { "initial_shared_version": 1 } },
+                    "objectType": "0x3::sui_system::SuiSystemState",
+                    "objectId": "0x5",
+                    "version": "11",
+                    "previousVersion": "10",
+                    "digest": "d3"
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_parse_fulfillment_outcome() {
+        let outcome = FulfillmentOutcome::from_json(&sample_response()).unwrap();
+
+        assert_eq!(outcome.digest, "9xQmTx");
+        assert!(outcome.is_success());
+        assert_eq!(outcome.created_objects.len(), 1);
+        assert_eq!(outcome.gas_used.net(), 2_500_000);
+
+        let staked = outcome
+            .created_of_type("::staking_pool::StakedSui")
+            .unwrap();
+        assert_eq!(staked.object_id, "0xstaked");
+        assert_eq!(outcome.created_for("0xuser").len(), 1);
+    }
+
+    #[test]
+    fn test_verify_transfers() {
+        let outcome = FulfillmentOutcome::from_json(&sample_response()).unwrap();
+        let staked = ExpectedTransfer::new("0xuser", "::staking_pool::StakedSui");
+        assert!(outcome.verify_transfers(&[staked]).is_ok());
+
+        // Wrong type, or the right type left with someone else
+        let position = ExpectedTransfer::new("0xuser", "::position::Position");
+        let err = outcome.verify_transfers(&[position]).unwrap_err();
+        assert!(err.to_string().contains("StakedSui"));
+        let stray = ExpectedTransfer::new("0xsolver", "::staking_pool::StakedSui");
+        assert!(outcome.verify_transfers(&[stray]).is_err());
+    }
+
+    #[test]
+    fn test_parse_unknown_owner_and_change() {
+        let mut response = sample_response();
+        let changes = response["objectChanges"].as_array_mut().unwrap();
+        changes[0]["owner"] = serde_json::json!({
+            "ConsensusAddressOwner": { "owner": "0xsolver", "start_version": "3" }
+        });
+        changes.push(serde_json::json!({
+            "type": "unwrappedThenDeleted",
+            "sender": "0xsolver",
+            "objectId": "0xgone",
+            "version": "11"
+        }));
+
+        let tx = ExecutedTransaction::from_json(&response).unwrap();
+        assert!(matches!(tx.object_changes[3], ObjectChange::Unknown));
+        match &tx.object_changes[0] {
+            ObjectChange::Mutated { owner, .. } => {
+                assert!(matches!(owner, Owner::Unknown(_)));
+                assert_eq!(owner.address(), None);
+            }
+            other => panic!("expected a mutation, got {:?}", other),
+        }
+        // Known entries still come through
+        assert_eq!(FulfillmentOutcome::from(tx).created_for("0xuser").len(), 1);
+    }
+
+    #[test]
+    fn test_parse_dry_run() {
+        let mut response = sample_response();
+        let object = response.as_object_mut().unwrap();
+        object.remove("digest");
+        object["effects"]["transactionDigest"] = "7dryRun".into();
+
+        let outcome = FulfillmentOutcome::from_dry_run_json(&response).unwrap();
+        assert_eq!(outcome.digest, "7dryRun");
+        assert_eq!(outcome.created_for("0xuser").len(), 1);
+        assert_eq!(outcome.gas_used.net(), 2_500_000);
+    }
+
+    #[test]
+    fn test_parse_failure_status() {
+        let status: ExecutionStatus = serde_json::from_value(serde_json::json!({
+            "status": "failure",
+            "error": "InsufficientGas"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            status,
+            ExecutionStatus::Failure {
+                error: "InsufficientGas".to_string()
+            }
+        );
+    }
+}
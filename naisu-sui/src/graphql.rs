@@ -0,0 +1,940 @@
+//! Sui GraphQL transport
+//!
+//! Implements `SuiTransport` against the Sui GraphQL service. Responses are
+//! mapped onto the same types the JSON-RPC transport returns so callers
+//! don't need to care which one is configured.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+use crate::client::{
+    CoinObject, DryRunResponse, GasUsed, SuiClientError, SuiObject, TransactionEffects,
+    TransactionResponse, TransactionStatus,
+};
+use crate::effects::ObjectChange;
+use crate::rate_limit::{self, RateLimitConfig, RateLimiter};
+use crate::transaction::address;
+use crate::transport::{
+    CoinPage, EventPage, SuiEvent, SuiTransport, ValidatorInfo, ZkLoginVerifyResult,
+};
+
+// ─── Queries ─────────────────────────────────────────────────────────────────
+
+const COINS_QUERY: &str = r#"
+query ($owner: SuiAddress!, $type: String, $after: String) {
+  address(address: $owner) {
+    coins(type: $type, first: 50, after: $after) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        address
+        version
+        digest
+        coinBalance
+        contents { type { repr } }
+      }
+    }
+  }
+}"#;
+
+const OBJECT_QUERY: &str = r#"
+query ($id: SuiAddress!) {
+  object(address: $id) {
+    address
+    version
+    digest
+    owner {
+      __typename
+      ... on AddressOwner { owner { address } }
+      ... on Parent { parent { address } }
+      ... on Shared { initialSharedVersion }
+    }
+    asMoveObject { contents { type { repr } json } }
+  }
+}"#;
+
+const DYNAMIC_FIELD_QUERY: &str = r#"
+query ($parent: SuiAddress!, $type: String!, $bcs: Base64!) {
+  owner(address: $parent) {
+    dynamicField(name: { type: $type, bcs: $bcs }) {
+      address
+      name { type { repr } json }
+      value {
+        __typename
+        ... on MoveValue { type { repr } json }
+        ... on MoveObject { address version digest contents { type { repr } json } }
+      }
+    }
+  }
+}"#;
+
+const EVENTS_QUERY: &str = r#"
+query ($type: String!, $first: Int, $after: String) {
+  events(first: $first, after: $after, filter: { eventType: $type }) {
+    pageInfo { hasNextPage endCursor }
+    nodes {
+      type { repr }
+      sender { address }
+      timestamp
+      json
+      transactionBlock { digest }
+    }
+  }
+}"#;
+
+const DRY_RUN_QUERY: &str = r#"
+query ($txBytes: String!) {
+  dryRunTransactionBlock(txBytes: $txBytes) {
+    error
+    transaction {
+      sender { address }
+      effects {
+        status
+        gasEffects { gasSummary { computationCost storageCost storageRebate } }
+        events { nodes { type { repr } sender { address } json } }
+        objectChanges(first: 50) {
+          nodes {
+            address
+            idCreated
+            idDeleted
+            inputState { version asMoveObject { contents { type { repr } } } }
+            outputState {
+              version
+              digest
+              owner {
+                __typename
+                ... on AddressOwner { owner { address } }
+                ... on Parent { parent { address } }
+                ... on Shared { initialSharedVersion }
+              }
+              asMoveObject { contents { type { repr } } }
+            }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+const EXECUTE_MUTATION: &str = r#"
+mutation ($txBytes: String!, $signatures: [String!]!) {
+  executeTransactionBlock(txBytes: $txBytes, signatures: $signatures) {
+    errors
+    effects {
+      status
+      transactionBlock { digest sender { address } }
+      gasEffects { gasSummary { computationCost storageCost storageRebate } }
+      events { nodes { type { repr } sender { address } json } }
+      objectChanges(first: 50) {
+        nodes {
+          address
+          idCreated
+          idDeleted
+          inputState { version asMoveObject { contents { type { repr } } } }
+          outputState {
+            version
+            digest
+            owner {
+              __typename
+              ... on AddressOwner { owner { address } }
+              ... on Parent { parent { address } }
+              ... on Shared { initialSharedVersion }
+            }
+            asMoveObject { contents { type { repr } } }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+const GAS_PRICE_QUERY: &str = r#"
+query {
+  epoch {
+    referenceGasPrice
+  }
+}"#;
+
+const VERIFY_ZKLOGIN_QUERY: &str = r#"
+query ($bytes: Base64!, $signature: Base64!, $author: SuiAddress!) {
+  verifyZkloginSignature(
+    bytes: $bytes
+    signature: $signature
+    intentScope: PERSONAL_MESSAGE
+    author: $author
+  ) {
+    success
+    errors
+  }
+}"#;
+
+const VALIDATORS_QUERY: &str = r#"
+query ($after: String) {
+  epoch {
+    validatorSet {
+      activeValidators(first: 50, after: $after) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          name
+          address { address }
+          apy
+          commissionRate
+          stakingPoolSuiBalance
+          votingPower
+        }
+      }
+    }
+  }
+}"#;
+
+const RESOLVE_NAME_QUERY: &str = r#"
+query ($name: String!) {
+  resolveSuinsAddress(domain: $name) { address }
+}"#;
+
+const DEFAULT_NAME_QUERY: &str = r#"
+query ($address: SuiAddress!) {
+  address(address: $address) { defaultSuinsName }
+}"#;
+
+// ─── Transport ───────────────────────────────────────────────────────────────
+
+/// GraphQL transport
+pub struct GraphQlTransport {
+    url: String,
+    client: Client,
+    /// Shared with every other transport on `url`
+    limiter: Arc<RateLimiter>,
+}
+
+impl GraphQlTransport {
+    pub fn new(url: String) -> Self {
+        let limiter = RateLimiter::shared(&url, RateLimitConfig::from_env());
+        Self {
+            url,
+            client: Client::new(),
+            limiter,
+        }
+    }
+
+    /// Throttle with `config` instead of the environment's limits
+    ///
+    /// Only takes effect if no transport on the endpoint has a limiter yet.
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.limiter = RateLimiter::shared(&self.url, config);
+        self
+    }
+
+    /// Run a GraphQL query and return its `data` payload
+    async fn query(&self, query: &str, variables: Value) -> Result<Value, SuiClientError> {
+        let body = json!({ "query": query, "variables": variables });
+        let response = rate_limit::send(&self.limiter, query_weight(query), || {
+            self.client.post(&self.url).json(&body)
+        })
+        .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(SuiClientError::Rpc {
+                code: status.as_u16() as i32,
+                message: body,
+            });
+        }
+
+        let mut body: Value = response
+            .json()
+            .await
+            .map_err(|e| SuiClientError::Parse(e.to_string()))?;
+
+        if let Some(errors) = body.get("errors").and_then(|e| e.as_array()) {
+            if !errors.is_empty() {
+                let message = errors
+                    .iter()
+                    .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                return Err(SuiClientError::Rpc { code: -1, message });
+            }
+        }
+
+        Ok(body.get_mut("data").map(Value::take).unwrap_or(Value::Null))
+    }
+}
+
+/// Tokens a query costs, weighted like its JSON-RPC counterpart
+fn query_weight(query: &str) -> u32 {
+    let method = match query {
+        EVENTS_QUERY => "suix_queryEvents",
+        DRY_RUN_QUERY => "sui_dryRunTransactionBlock",
+        EXECUTE_MUTATION => "sui_executeTransactionBlock",
+        VALIDATORS_QUERY => "suix_getLatestSuiSystemState",
+        _ => "",
+    };
+    rate_limit::method_weight(method)
+}
+
+#[async_trait]
+impl SuiTransport for GraphQlTransport {
+    fn name(&self) -> &'static str {
+        "graphql"
+    }
+
+    async fn get_coins(
+        &self,
+        owner: &str,
+        coin_type: Option<&str>,
+        cursor: Option<&str>,
+    ) -> Result<CoinPage, SuiClientError> {
+        let data = self
+            .query(
+                COINS_QUERY,
+                json!({ "owner": owner, "type": coin_type, "after": cursor }),
+            )
+            .await?;
+        Ok(parse_coin_page(&data["address"]["coins"]))
+    }
+
+    async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError> {
+        let data = self.query(OBJECT_QUERY, json!({ "id": object_id })).await?;
+
+        match data.get("object") {
+            Some(object) if !object.is_null() => parse_object(object),
+            _ => Err(SuiClientError::ObjectNotFound(object_id.to_string())),
+        }
+    }
+
+    async fn get_dynamic_field_object(
+        &self,
+        parent_id: &str,
+        name: Value,
+    ) -> Result<SuiObject, SuiClientError> {
+        // GraphQL takes the name as BCS rather than JSON
+        let name_type = name["type"]
+            .as_str()
+            .ok_or_else(|| SuiClientError::Parse(format!("dynamic field name {}", name)))?;
+        let bcs = name_bcs(name_type, &name["value"])?;
+        let data = self
+            .query(
+                DYNAMIC_FIELD_QUERY,
+                json!({ "parent": parent_id, "type": name_type, "bcs": BASE64.encode(bcs) }),
+            )
+            .await?;
+
+        match data["owner"].get("dynamicField") {
+            Some(field) if !field.is_null() => parse_dynamic_field(field),
+            _ => Err(SuiClientError::ObjectNotFound(format!(
+                "{}[{}]",
+                parent_id, name
+            ))),
+        }
+    }
+
+    async fn query_events(
+        &self,
+        event_type: &str,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError> {
+        let data = self
+            .query(
+                EVENTS_QUERY,
+                json!({ "type": event_type, "first": limit, "after": cursor }),
+            )
+            .await?;
+
+        let events = &data["events"];
+        let nodes = events["nodes"].as_array().cloned().unwrap_or_default();
+
+        Ok(EventPage {
+            data: nodes.iter().map(parse_event).collect(),
+            next_cursor: events["pageInfo"]["endCursor"]
+                .as_str()
+                .map(|s| s.to_string()),
+            has_next_page: events["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false),
+        })
+    }
+
+    async fn dry_run_transaction(&self, tx_bytes: &str) -> Result<DryRunResponse, SuiClientError> {
+        let data = self
+            .query(DRY_RUN_QUERY, json!({ "txBytes": tx_bytes }))
+            .await?;
+
+        let result = &data["dryRunTransactionBlock"];
+        if let Some(error) = result["error"].as_str() {
+            return Err(SuiClientError::TransactionFailed(error.to_string()));
+        }
+
+        let transaction = &result["transaction"];
+        let effects = &transaction["effects"];
+        Ok(DryRunResponse {
+            effects: parse_effects(effects),
+            events: parse_effect_events(effects),
+            object_changes: parse_object_changes(effects, &transaction["sender"]["address"]),
+        })
+    }
+
+    async fn execute_transaction(
+        &self,
+        tx_bytes: &str,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiClientError> {
+        let data = self
+            .query(
+                EXECUTE_MUTATION,
+                json!({ "txBytes": tx_bytes, "signatures": signatures }),
+            )
+            .await?;
+
+        let result = &data["executeTransactionBlock"];
+        if let Some(errors) = result["errors"].as_array() {
+            if !errors.is_empty() {
+                let message = errors
+                    .iter()
+                    .filter_map(|e| e.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                return Err(SuiClientError::TransactionFailed(message));
+            }
+        }
+
+        let effects = &result["effects"];
+        let digest = effects["transactionBlock"]["digest"]
+            .as_str()
+            .ok_or_else(|| SuiClientError::Parse("missing transaction digest".to_string()))?
+            .to_string();
+
+        Ok(TransactionResponse {
+            digest,
+            effects: parse_effects(effects),
+            events: Some(parse_effect_events(effects)),
+            object_changes: parse_object_changes(
+                effects,
+                &effects["transactionBlock"]["sender"]["address"],
+            ),
+        })
+    }
+
+    async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError> {
+        let data = self.query(GAS_PRICE_QUERY, json!({})).await?;
+        value_to_string(&data["epoch"]["referenceGasPrice"])
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(|| SuiClientError::Parse("missing referenceGasPrice".to_string()))
+    }
+
+    async fn verify_zklogin_signature(
+        &self,
+        message: &str,
+        signature: &str,
+        author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError> {
+        let data = self
+            .query(
+                VERIFY_ZKLOGIN_QUERY,
+                json!({ "bytes": message, "signature": signature, "author": author }),
+            )
+            .await?;
+        serde_json::from_value(data["verifyZkloginSignature"].clone())
+            .map_err(|e| SuiClientError::Parse(e.to_string()))
+    }
+
+    async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError> {
+        let mut validators = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let data = self
+                .query(VALIDATORS_QUERY, json!({ "after": after }))
+                .await?;
+            let page = &data["epoch"]["validatorSet"]["activeValidators"];
+            let nodes = page["nodes"].as_array().cloned().unwrap_or_default();
+            validators.extend(nodes.iter().filter_map(parse_validator));
+
+            after = page["pageInfo"]["endCursor"]
+                .as_str()
+                .map(|s| s.to_string());
+            if !page["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false) || after.is_none() {
+                return Ok(validators);
+            }
+        }
+    }
+
+    async fn resolve_name(&self, name: &str) -> Result<Option<String>, SuiClientError> {
+        let data = self
+            .query(RESOLVE_NAME_QUERY, json!({ "name": name }))
+            .await?;
+        Ok(data["resolveSuinsAddress"]["address"]
+            .as_str()
+            .map(|a| a.to_string()))
+    }
+
+    async fn default_name(&self, address: &str) -> Result<Option<String>, SuiClientError> {
+        let data = self
+            .query(DEFAULT_NAME_QUERY, json!({ "address": address }))
+            .await?;
+        Ok(data["address"]["defaultSuinsName"]
+            .as_str()
+            .map(|n| n.to_string()))
+    }
+}
+
+// ─── Response mapping ────────────────────────────────────────────────────────
+
+/// Extract `T` from `0x2::coin::Coin<T>`
+fn coin_inner_type(repr: &str) -> String {
+    repr.strip_prefix("0x2::coin::Coin<")
+        .or_else(|| {
+            repr.find("::coin::Coin<")
+                .map(|i| &repr[i + "::coin::Coin<".len()..])
+        })
+        .and_then(|s| s.strip_suffix('>'))
+        .unwrap_or(repr)
+        .to_string()
+}
+
+/// GraphQL returns u64 values either as strings or numbers
+fn value_to_string(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .map(|s| s.to_string())
+        .or_else(|| value.as_u64().map(|n| n.to_string()))
+}
+
+fn parse_validator(node: &Value) -> Option<ValidatorInfo> {
+    let number = |v: &Value| value_to_string(v).and_then(|s| s.parse().ok()).unwrap_or(0);
+    Some(ValidatorInfo::new(
+        node["address"]["address"].as_str()?,
+        node["name"].as_str().unwrap_or_default(),
+        // Basis points here; JSON-RPC gives a fraction
+        number(&node["apy"]) as f64 / 10_000.0,
+        number(&node["commissionRate"]),
+        number(&node["stakingPoolSuiBalance"]),
+        number(&node["votingPower"]),
+    ))
+}
+
+fn parse_coin(node: &Value) -> Option<CoinObject> {
+    Some(CoinObject {
+        coin_type: coin_inner_type(node["contents"]["type"]["repr"].as_str()?),
+        coin_object_id: node["address"].as_str()?.to_string(),
+        version: value_to_string(&node["version"])?,
+        digest: node["digest"].as_str().unwrap_or_default().to_string(),
+        balance: value_to_string(&node["coinBalance"]).unwrap_or_else(|| "0".to_string()),
+    })
+}
+
+fn parse_object(object: &Value) -> Result<SuiObject, SuiClientError> {
+    let object_id = object["address"]
+        .as_str()
+        .ok_or_else(|| SuiClientError::Parse("missing object address".to_string()))?
+        .to_string();
+
+    let owner = owner_json(&object["owner"]);
+    let contents = &object["asMoveObject"]["contents"];
+    let r#type = contents["type"]["repr"].as_str().map(|s| s.to_string());
+    let content = if contents["json"].is_null() {
+        None
+    } else {
+        Some(json!({
+            "dataType": "moveObject",
+            "type": r#type,
+            "fields": contents["json"]
+        }))
+    };
+
+    Ok(SuiObject {
+        object_id,
+        version: value_to_string(&object["version"]).unwrap_or_default(),
+        digest: object["digest"].as_str().unwrap_or_default().to_string(),
+        r#type,
+        owner,
+        content,
+    })
+}
+
+/// Map an owner onto the JSON-RPC shape so downstream matching keeps working
+fn owner_json(owner: &Value) -> Option<Value> {
+    match owner["__typename"].as_str()? {
+        "AddressOwner" => Some(json!({ "AddressOwner": owner["owner"]["address"] })),
+        "Parent" => Some(json!({ "ObjectOwner": owner["parent"]["address"] })),
+        "Shared" => Some(json!({
+            "Shared": { "initial_shared_version": owner["initialSharedVersion"] }
+        })),
+        other => Some(json!(other)),
+    }
+}
+
+fn parse_coin_page(coins: &Value) -> CoinPage {
+    CoinPage {
+        data: coins["nodes"]
+            .as_array()
+            .map(|nodes| nodes.iter().filter_map(parse_coin).collect())
+            .unwrap_or_default(),
+        next_cursor: coins["pageInfo"]["endCursor"]
+            .as_str()
+            .map(|s| s.to_string()),
+        has_next_page: coins["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false),
+    }
+}
+
+/// A dynamic field in the shape `suix_getDynamicFieldObject` returns: the
+/// `Field<K, V>` wrapper, or the object itself for dynamic object fields
+fn parse_dynamic_field(field: &Value) -> Result<SuiObject, SuiClientError> {
+    let value = &field["value"];
+    if value["__typename"] == "MoveObject" {
+        return parse_object(&json!({
+            "address": value["address"],
+            "version": value["version"],
+            "digest": value["digest"],
+            "asMoveObject": { "contents": value["contents"] },
+        }));
+    }
+
+    let object_id = field["address"]
+        .as_str()
+        .ok_or_else(|| SuiClientError::Parse("missing dynamic field address".to_string()))?
+        .to_string();
+    let r#type = format!(
+        "0x2::dynamic_field::Field<{}, {}>",
+        field["name"]["type"]["repr"].as_str().unwrap_or_default(),
+        value["type"]["repr"].as_str().unwrap_or_default()
+    );
+    Ok(SuiObject {
+        content: Some(json!({
+            "dataType": "moveObject",
+            "type": r#type,
+            "fields": {
+                "id": { "id": object_id },
+                "name": field["name"]["json"],
+                "value": value["json"],
+            }
+        })),
+        object_id,
+        version: String::new(),
+        digest: String::new(),
+        r#type: Some(r#type),
+        owner: None,
+    })
+}
+
+/// BCS bytes of a dynamic field name value of Move type `name_type`
+///
+/// Covers the key types tables are keyed by: integers, bools, addresses
+/// and IDs, strings and `TypeName`.
+fn name_bcs(name_type: &str, value: &Value) -> Result<Vec<u8>, SuiClientError> {
+    let invalid = || SuiClientError::Parse(format!("{} name {}", name_type, value));
+    let int = || {
+        value_to_string(value)
+            .and_then(|v| v.parse::<u128>().ok())
+            .ok_or_else(invalid)
+    };
+    let string = |v: &Value| v.as_str().map(|s| s.to_string()).ok_or_else(invalid);
+    let narrow = |n: u128, bytes: usize| {
+        if n >> (bytes * 8) == 0 {
+            Ok(n.to_le_bytes()[..bytes].to_vec())
+        } else {
+            Err(invalid())
+        }
+    };
+
+    let encoded = match name_type {
+        "u8" => narrow(int()?, 1)?,
+        "u16" => narrow(int()?, 2)?,
+        "u32" => narrow(int()?, 4)?,
+        "u64" => narrow(int()?, 8)?,
+        "u128" => int()?.to_le_bytes().to_vec(),
+        "bool" => vec![value.as_bool().ok_or_else(invalid)? as u8],
+        "address" => address(&string(value)?).map_err(|_| invalid())?.to_vec(),
+        t if t.ends_with("::object::ID") => {
+            address(&string(value)?).map_err(|_| invalid())?.to_vec()
+        }
+        t if t.ends_with("::string::String") => bcs_string(&string(value)?)?,
+        t if t.ends_with("::type_name::TypeName") => bcs_string(&string(&value["name"])?)?,
+        _ => {
+            return Err(SuiClientError::Request(format!(
+                "graphql transport cannot encode {} dynamic field names",
+                name_type
+            )))
+        }
+    };
+    Ok(encoded)
+}
+
+fn bcs_string(value: &str) -> Result<Vec<u8>, SuiClientError> {
+    bcs::to_bytes(value).map_err(|e| SuiClientError::Parse(e.to_string()))
+}
+
+/// `objectChanges` in the JSON-RPC shape
+///
+/// GraphQL reports input and output states; creation, deletion and
+/// wrapping are told apart by which of them exist.
+fn parse_object_changes(effects: &Value, sender: &Value) -> Vec<ObjectChange> {
+    let Some(nodes) = effects["objectChanges"]["nodes"].as_array() else {
+        return Vec::new();
+    };
+    let type_of = |state: &Value| state["asMoveObject"]["contents"]["type"]["repr"].clone();
+
+    nodes
+        .iter()
+        .filter_map(|node| {
+            let (input, output) = (&node["inputState"], &node["outputState"]);
+            let mut change = json!({
+                "sender": sender,
+                "objectId": node["address"],
+            });
+            let fields = change.as_object_mut()?;
+            if !output.is_null() {
+                fields.insert("owner".into(), owner_json(&output["owner"])?);
+                fields.insert("objectType".into(), type_of(output));
+                fields.insert("version".into(), output["version"].clone());
+                fields.insert("digest".into(), output["digest"].clone());
+                if node["idCreated"].as_bool().unwrap_or(false) || input.is_null() {
+                    fields.insert("type".into(), "created".into());
+                } else {
+                    fields.insert("type".into(), "mutated".into());
+                    fields.insert("previousVersion".into(), input["version"].clone());
+                }
+            } else if !input.is_null() {
+                let kind = if node["idDeleted"].as_bool().unwrap_or(false) {
+                    "deleted"
+                } else {
+                    "wrapped"
+                };
+                fields.insert("type".into(), kind.into());
+                fields.insert("objectType".into(), type_of(input));
+                fields.insert("version".into(), input["version"].clone());
+            } else {
+                return None;
+            }
+            serde_json::from_value(change).ok()
+        })
+        .collect()
+}
+
+fn parse_event(node: &Value) -> SuiEvent {
+    SuiEvent {
+        event_type: node["type"]["repr"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        parsed_json: node["json"].clone(),
+        sender: node["sender"]["address"].as_str().map(|s| s.to_string()),
+        tx_digest: node["transactionBlock"]["digest"]
+            .as_str()
+            .map(|s| s.to_string()),
+        timestamp_ms: node["timestamp"]
+            .as_str()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.timestamp_millis() as u64),
+    }
+}
+
+fn parse_effects(effects: &Value) -> TransactionEffects {
+    let gas = &effects["gasEffects"]["gasSummary"];
+    TransactionEffects {
+        status: TransactionStatus {
+            // GraphQL uses SUCCESS / FAILURE; JSON-RPC uses lowercase
+            status: effects["status"]
+                .as_str()
+                .unwrap_or("failure")
+                .to_lowercase(),
+            error: None,
+        },
+        gas_used: GasUsed {
+            computation_cost: value_to_string(&gas["computationCost"])
+                .unwrap_or_else(|| "0".to_string()),
+            storage_cost: value_to_string(&gas["storageCost"]).unwrap_or_else(|| "0".to_string()),
+            storage_rebate: value_to_string(&gas["storageRebate"]),
+        },
+    }
+}
+
+/// Events in JSON-RPC shape (`type` / `parsedJson` / `sender`)
+fn parse_effect_events(effects: &Value) -> Vec<Value> {
+    effects["events"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .map(|n| {
+                    json!({
+                        "type": n["type"]["repr"],
+                        "sender": n["sender"]["address"],
+                        "parsedJson": n["json"],
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coin_inner_type() {
+        assert_eq!(
+            coin_inner_type("0x2::coin::Coin<0x2::sui::SUI>"),
+            "0x2::sui::SUI"
+        );
+        assert_eq!(
+            coin_inner_type(
+                "0x0000000000000000000000000000000000000000000000000000000000000002::coin::Coin<0xa::usdc::USDC>"
+            ),
+            "0xa::usdc::USDC"
+        );
+    }
+
+    #[test]
+    fn test_parse_coin() {
+        let node = json!({
+            "address": "0xabc",
+            "version": 12,
+            "digest": "Dg1",
+            "coinBalance": "1000",
+            "contents": { "type": { "repr": "0x2::coin::Coin<0x2::sui::SUI>" } }
+        });
+        let coin = parse_coin(&node).unwrap();
+        assert_eq!(coin.coin_object_id, "0xabc");
+        assert_eq!(coin.coin_type, "0x2::sui::SUI");
+        assert_eq!(coin.version, "12");
+        assert_eq!(coin.balance, "1000");
+    }
+
+    #[test]
+    fn test_parse_coin_page() {
+        let coins = json!({
+            "pageInfo": { "hasNextPage": true, "endCursor": "c50" },
+            "nodes": [{
+                "address": "0xabc",
+                "version": "3",
+                "coinBalance": "10",
+                "contents": { "type": { "repr": "0x2::coin::Coin<0x2::sui::SUI>" } }
+            }]
+        });
+        let page = parse_coin_page(&coins);
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.next_cursor.as_deref(), Some("c50"));
+        assert!(page.has_next_page);
+
+        assert!(!parse_coin_page(&json!({ "nodes": [] })).has_next_page);
+    }
+
+    #[test]
+    fn test_name_bcs() {
+        assert_eq!(name_bcs("u8", &json!(3)).unwrap(), vec![3]);
+        assert_eq!(
+            name_bcs("u64", &json!("258")).unwrap(),
+            vec![2, 1, 0, 0, 0, 0, 0, 0]
+        );
+        assert!(name_bcs("u8", &json!(256)).is_err());
+
+        // TypeName { name: ascii::String }: length prefix, then the bytes
+        let type_name = name_bcs(
+            "0x1::type_name::TypeName",
+            &json!({ "name": "0x2::sui::SUI" }),
+        )
+        .unwrap();
+        assert_eq!(type_name[0] as usize, "0x2::sui::SUI".len());
+        assert_eq!(&type_name[1..], b"0x2::sui::SUI");
+
+        let id = name_bcs("0x2::object::ID", &json!("0x5")).unwrap();
+        assert_eq!((id.len(), id[31]), (32, 5));
+        assert!(name_bcs("vector<u64>", &json!([1])).is_err());
+    }
+
+    #[test]
+    fn test_parse_dynamic_field() {
+        let field = json!({
+            "address": "0xf1",
+            "name": { "type": { "repr": "u8" }, "json": 0 },
+            "value": {
+                "__typename": "MoveValue",
+                "type": { "repr": "0xpkg::storage::ReserveData" },
+                "json": { "coin_type": "0x2::sui::SUI" }
+            }
+        });
+        let object = parse_dynamic_field(&field).unwrap();
+        assert_eq!(object.object_id, "0xf1");
+        let content = object.content.unwrap();
+        assert_eq!(content["fields"]["value"]["coin_type"], "0x2::sui::SUI");
+        assert_eq!(
+            object.r#type.as_deref(),
+            Some("0x2::dynamic_field::Field<u8, 0xpkg::storage::ReserveData>")
+        );
+    }
+
+    #[test]
+    fn test_parse_object_changes() {
+        let state = |version: u64, owner: Value, repr: &str| {
+            json!({
+                "version": version,
+                "digest": "D",
+                "owner": owner,
+                "asMoveObject": { "contents": { "type": { "repr": repr } } }
+            })
+        };
+        let effects = json!({
+            "objectChanges": { "nodes": [
+                {
+                    "address": "0xstaked",
+                    "idCreated": true,
+                    "inputState": null,
+                    "outputState": state(
+                        11,
+                        json!({ "__typename": "AddressOwner", "owner": { "address": "0xuser" } }),
+                        "0x3::staking_pool::StakedSui"
+                    )
+                },
+                {
+                    "address": "0x5",
+                    "inputState": state(10, Value::Null, "0x3::sui_system::SuiSystemState"),
+                    "outputState": state(
+                        11,
+                        json!({ "__typename": "Shared", "initialSharedVersion": 1 }),
+                        "0x3::sui_system::SuiSystemState"
+                    )
+                },
+                {
+                    "address": "0xcoin",
+                    "idDeleted": true,
+                    "inputState": state(10, Value::Null, "0x2::coin::Coin<0x2::sui::SUI>"),
+                    "outputState": null
+                }
+            ]}
+        });
+
+        let changes = parse_object_changes(&effects, &json!("0xsolver"));
+        assert!(matches!(
+            &changes[0],
+            ObjectChange::Created { owner, object_type, .. }
+                if owner.address() == Some("0xuser") && object_type.ends_with("StakedSui")
+        ));
+        assert!(matches!(
+            changes[1],
+            ObjectChange::Mutated {
+                previous_version: 10,
+                version: 11,
+                ..
+            }
+        ));
+        assert!(matches!(changes[2], ObjectChange::Deleted { .. }));
+    }
+
+    #[test]
+    fn test_parse_effects_status() {
+        let effects = json!({
+            "status": "SUCCESS",
+            "gasEffects": { "gasSummary": { "computationCost": "100", "storageCost": 200 } }
+        });
+        let parsed = parse_effects(&effects);
+        assert_eq!(parsed.status.status, "success");
+        assert_eq!(parsed.gas_used.computation_cost, "100");
+        assert_eq!(parsed.gas_used.storage_cost, "200");
+    }
+}
@@ -0,0 +1,250 @@
+//! Typed intent package objects
+//!
+//! Decodes the objects `naisu::intent` leaves on chain from their JSON
+//! content: the shared `YieldIntent<T>` while an intent is open, and the
+//! `YieldReceipt<Y>` the user gets when it is fulfilled (the intent object
+//! itself is deleted then). Both transports' content shapes are accepted.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{SuiClient, SuiClientError, SuiObject};
+use crate::effects::Owner;
+
+/// `YieldIntent` status: open for bids
+pub const STATUS_OPEN: u8 = 0;
+
+/// An open (or not yet deleted) `YieldIntent<T>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct YieldIntentObject {
+    pub object_id: String,
+    /// `T`, the locked coin's type
+    pub coin_type: String,
+    pub user: String,
+    /// Locked amount (base units of `coin_type`)
+    pub amount: u64,
+    /// Basis points
+    pub min_apy: u64,
+    /// Unix millis
+    pub deadline: u64,
+    pub status: u8,
+    /// Unix millis
+    pub created_at: u64,
+    pub target_protocol: String,
+}
+
+impl YieldIntentObject {
+    pub fn is_open(&self) -> bool {
+        self.status == STATUS_OPEN
+    }
+}
+
+/// A fulfilled intent, as recorded by its `YieldReceipt<Y>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FulfilledIntent {
+    /// The `YieldReceipt` object
+    pub receipt_id: String,
+    /// `Y`, the yield coin handed to the user
+    pub yield_type: String,
+    /// User the intent was created by
+    pub user: String,
+    /// Address owning the receipt now
+    pub owner: Option<String>,
+    pub protocol: String,
+    /// APY delivered (basis points)
+    pub apy: u64,
+    /// Intent amount (base units of the intent's coin)
+    pub amount: u64,
+    /// Unix millis
+    pub fulfilled_at: u64,
+}
+
+/// Decode a `YieldIntent<T>`
+pub fn decode_yield_intent(object: &SuiObject) -> Result<YieldIntentObject, SuiClientError> {
+    let (coin_type, fields) = move_object(object, "YieldIntent")?;
+    Ok(YieldIntentObject {
+        object_id: object.object_id.clone(),
+        coin_type,
+        user: string_field(fields, "user")?,
+        amount: coin_value(fields.get("input"))
+            .ok_or_else(|| missing(&object.object_id, "input"))?,
+        min_apy: u64_field(fields, "min_apy")?,
+        deadline: u64_field(fields, "deadline")?,
+        status: u64_field(fields, "status")? as u8,
+        created_at: u64_field(fields, "created_at")?,
+        target_protocol: string_field(fields, "target_protocol")?,
+    })
+}
+
+/// Decode a `YieldReceipt<Y>`
+pub fn decode_fulfilled_intent(object: &SuiObject) -> Result<FulfilledIntent, SuiClientError> {
+    let (yield_type, fields) = move_object(object, "YieldReceipt")?;
+    let owner = object
+        .owner
+        .clone()
+        .and_then(|o| serde_json::from_value::<Owner>(o).ok())
+        .and_then(|o| o.address().map(str::to_string));
+    Ok(FulfilledIntent {
+        receipt_id: object.object_id.clone(),
+        yield_type,
+        user: string_field(fields, "user")?,
+        owner,
+        protocol: string_field(fields, "protocol")?,
+        apy: u64_field(fields, "apy")?,
+        amount: u64_field(fields, "amount")?,
+        fulfilled_at: u64_field(fields, "fulfilled_at")?,
+    })
+}
+
+impl SuiClient {
+    /// Read and decode a `YieldIntent`
+    pub async fn get_yield_intent(
+        &self,
+        object_id: &str,
+    ) -> Result<YieldIntentObject, SuiClientError> {
+        decode_yield_intent(&self.get_object(object_id).await?)
+    }
+
+    /// Read and decode the `YieldReceipt` of a fulfilled intent
+    pub async fn get_fulfilled_intent(
+        &self,
+        receipt_id: &str,
+    ) -> Result<FulfilledIntent, SuiClientError> {
+        decode_fulfilled_intent(&self.get_object(receipt_id).await?)
+    }
+}
+
+/// Type argument and content fields of an `intent::<name><_>` object
+fn move_object<'a>(
+    object: &'a SuiObject,
+    name: &str,
+) -> Result<(String, &'a Value), SuiClientError> {
+    let object_type = object.r#type.as_deref().unwrap_or_default();
+    let type_arg = object_type
+        .split_once(&format!("::intent::{}<", name))
+        .and_then(|(_, rest)| rest.strip_suffix('>'))
+        .ok_or_else(|| {
+            SuiClientError::Parse(format!(
+                "{} is a `{}`, not a {}",
+                object.object_id, object_type, name
+            ))
+        })?;
+    let fields = object
+        .content
+        .as_ref()
+        .and_then(|c| c.get("fields"))
+        .ok_or_else(|| missing(&object.object_id, "content"))?;
+    Ok((type_arg.to_string(), fields))
+}
+
+fn missing(object_id: &str, field: &str) -> SuiClientError {
+    SuiClientError::Parse(format!("{} has no `{}`", object_id, field))
+}
+
+/// u64s come as strings over JSON-RPC and GraphQL alike
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        other => other.as_u64(),
+    }
+}
+
+fn u64_field(fields: &Value, name: &str) -> Result<u64, SuiClientError> {
+    fields
+        .get(name)
+        .and_then(as_u64)
+        .ok_or_else(|| SuiClientError::Parse(format!("missing or invalid `{}`", name)))
+}
+
+fn string_field(fields: &Value, name: &str) -> Result<String, SuiClientError> {
+    fields
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| SuiClientError::Parse(format!("missing or invalid `{}`", name)))
+}
+
+/// Value of an embedded `Coin`: `{"fields": {"balance": ..}}` over JSON-RPC,
+/// `{"balance": {"value": ..}}` over GraphQL
+fn coin_value(coin: Option<&Value>) -> Option<u64> {
+    let coin = coin?;
+    let balance = coin
+        .get("fields")
+        .and_then(|f| f.get("balance"))
+        .or_else(|| coin.get("balance"))?;
+    as_u64(balance).or_else(|| balance.get("value").and_then(as_u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn object(r#type: &str, owner: Value, fields: Value) -> SuiObject {
+        SuiObject {
+            object_id: "0x1a".to_string(),
+            version: "7".to_string(),
+            digest: "d".to_string(),
+            r#type: Some(r#type.to_string()),
+            owner: Some(owner),
+            content: Some(json!({ "dataType": "moveObject", "fields": fields })),
+        }
+    }
+
+    #[test]
+    fn test_decode_yield_intent() {
+        let rpc = object(
+            "0xabc::intent::YieldIntent<0x2::sui::SUI>",
+            json!({ "Shared": { "initial_shared_version": 3 } }),
+            json!({
+                "id": { "id": "0x1a" },
+                "user": "0xuser",
+                "input": {
+                    "type": "0x2::coin::Coin<0x2::sui::SUI>",
+                    "fields": { "balance": "1000000000", "id": { "id": "0xc" } }
+                },
+                "min_apy": "750",
+                "deadline": "1700000600000",
+                "status": 0,
+                "created_at": "1700000000000",
+                "target_protocol": "any"
+            }),
+        );
+        let intent = decode_yield_intent(&rpc).unwrap();
+        assert_eq!(intent.coin_type, "0x2::sui::SUI");
+        assert_eq!(intent.amount, 1_000_000_000);
+        assert_eq!(intent.min_apy, 750);
+        assert!(intent.is_open());
+
+        // GraphQL nests the balance
+        let mut graphql = rpc.clone();
+        graphql.content.as_mut().unwrap()["fields"]["input"] =
+            json!({ "id": "0xc", "balance": { "value": "1000000000" } });
+        assert_eq!(decode_yield_intent(&graphql).unwrap(), intent);
+
+        let mut wrong = rpc;
+        wrong.r#type = Some("0x2::coin::Coin<0x2::sui::SUI>".to_string());
+        assert!(decode_yield_intent(&wrong).is_err());
+    }
+
+    #[test]
+    fn test_decode_fulfilled_intent() {
+        let receipt = object(
+            "0xabc::intent::YieldReceipt<0xd384::s_coin::sCoin<0x2::sui::SUI>>",
+            json!({ "AddressOwner": "0xuser" }),
+            json!({
+                "id": { "id": "0x1a" },
+                "user": "0xuser",
+                "protocol": "scallop",
+                "apy": "820",
+                "amount": "1000000000",
+                "fulfilled_at": "1700000100000"
+            }),
+        );
+        let fulfilled = decode_fulfilled_intent(&receipt).unwrap();
+        assert_eq!(fulfilled.yield_type, "0xd384::s_coin::sCoin<0x2::sui::SUI>");
+        assert_eq!(fulfilled.owner.as_deref(), Some("0xuser"));
+        assert_eq!(fulfilled.apy, 820);
+        assert_eq!(fulfilled.amount, 1_000_000_000);
+    }
+}
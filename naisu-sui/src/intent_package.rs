@@ -0,0 +1,403 @@
+//! Naisu intent package calls
+//!
+//! Typed builders for the entry functions of `naisu::intent`: creating an
+//! intent, fulfilling it, and reclaiming its coin by cancelling (the
+//! owner's `user_cancel_intent`, or `cancel_expired_intent` after the
+//! deadline). A call is added to a `PtbBuilder`, or rendered as
+//! `sui client ptb` arguments for the CLI executors. `create_intent_ptb`
+//! builds a whole intent creation for a user's wallet to sign.
+//!
+//! `YieldIntent<T>` is a shared object consumed by value, so intents are
+//! passed as mutable shared inputs and every call takes `T` as its first
+//! type argument.
+
+use crate::protocols::{ProtocolConfig, ProtocolError};
+use crate::ptb::{ProgrammableTransactionBlock, PtbArgument, PtbBuilder};
+use crate::transaction::ObjectRef;
+
+/// Module of the intent package
+pub const INTENT_MODULE: &str = "intent";
+
+/// Coin type paid for gas; intents in it split off the gas coin
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// `target_protocol` of an intent any protocol may fill
+pub const ANY_PROTOCOL: &str = "any";
+
+/// A shared `YieldIntent<T>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntentObject {
+    pub object_id: String,
+    pub initial_shared_version: u64,
+    /// `T`, the locked coin's type
+    pub coin_type: String,
+}
+
+impl IntentObject {
+    pub fn new(object_id: &str, initial_shared_version: u64, coin_type: &str) -> Self {
+        Self {
+            object_id: object_id.to_string(),
+            initial_shared_version,
+            coin_type: coin_type.to_string(),
+        }
+    }
+}
+
+/// An intent package entry call; `C` is how the call's coin is referred
+/// to (a `PtbArgument`, or a `--assign`ed name on the CLI)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntentCall<C> {
+    /// `create_intent<T>`: lock `input` in a new shared intent
+    Create {
+        coin_type: String,
+        input: C,
+        /// Basis points
+        min_apy: u64,
+        /// Seconds from now
+        deadline_secs: u64,
+        /// Protocol name, or `"any"`
+        target_protocol: String,
+    },
+    /// `fulfill_intent<T, Y>`: hand `output` (a `Coin<Y>`) and a receipt to
+    /// the user; the intent's coin goes to the solver
+    Fulfill {
+        intent: IntentObject,
+        output_type: String,
+        output: C,
+        protocol: String,
+        /// Basis points, at least the intent's `min_apy`
+        apy: u64,
+    },
+    /// `cancel_expired_intent<T>`: the owner reclaims an expired intent
+    CancelExpired { intent: IntentObject },
+    /// `user_cancel_intent<T>`: the owner reclaims an open intent
+    UserCancel { intent: IntentObject },
+}
+
+impl<C> IntentCall<C> {
+    pub fn function(&self) -> &'static str {
+        match self {
+            IntentCall::Create { .. } => "create_intent",
+            IntentCall::Fulfill { .. } => "fulfill_intent",
+            IntentCall::CancelExpired { .. } => "cancel_expired_intent",
+            IntentCall::UserCancel { .. } => "user_cancel_intent",
+        }
+    }
+
+    pub fn type_args(&self) -> Vec<String> {
+        match self {
+            IntentCall::Create { coin_type, .. } => vec![coin_type.clone()],
+            IntentCall::Fulfill {
+                intent,
+                output_type,
+                ..
+            } => vec![intent.coin_type.clone(), output_type.clone()],
+            IntentCall::CancelExpired { intent } | IntentCall::UserCancel { intent } => {
+                vec![intent.coin_type.clone()]
+            }
+        }
+    }
+}
+
+/// An intent to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewIntent {
+    /// `T`, the coin to lock
+    pub coin_type: String,
+    /// Base units of `coin_type`
+    pub amount: u64,
+    /// Basis points
+    pub min_apy: u64,
+    /// Seconds from execution
+    pub deadline_secs: u64,
+    /// `None` lets any protocol fill it
+    pub target_protocol: Option<String>,
+}
+
+/// The deployed intent package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntentPackage {
+    package_id: String,
+}
+
+impl IntentPackage {
+    pub fn new(package_id: &str) -> Self {
+        Self {
+            package_id: package_id.to_string(),
+        }
+    }
+
+    /// Package of `config.intent_package`
+    pub fn from_config(config: &ProtocolConfig) -> Result<Self, ProtocolError> {
+        config
+            .intent_package
+            .as_deref()
+            .map(Self::new)
+            .ok_or(ProtocolError::NotConfigured("Intent package"))
+    }
+
+    pub fn package_id(&self) -> &str {
+        &self.package_id
+    }
+
+    /// `<package>::intent::<function>`
+    pub fn target(&self, function: &str) -> String {
+        format!("{}::{}::{}", self.package_id, INTENT_MODULE, function)
+    }
+
+    /// Add `call` to a PTB
+    pub fn add_call(&self, ptb: &mut PtbBuilder, call: IntentCall<PtbArgument>) -> PtbArgument {
+        let function = call.function();
+        let type_args = call.type_args();
+        let args = match call {
+            IntentCall::Create {
+                input,
+                min_apy,
+                deadline_secs,
+                target_protocol,
+                ..
+            } => vec![
+                input,
+                ptb.add_pure(&min_apy),
+                ptb.add_pure(&deadline_secs),
+                ptb.add_pure(&target_protocol),
+            ],
+            IntentCall::Fulfill {
+                intent,
+                output,
+                protocol,
+                apy,
+                ..
+            } => vec![
+                Self::intent_input(ptb, &intent),
+                output,
+                ptb.add_pure(&protocol),
+                ptb.add_pure(&apy),
+            ],
+            IntentCall::CancelExpired { intent } | IntentCall::UserCancel { intent } => {
+                vec![Self::intent_input(ptb, &intent)]
+            }
+        };
+        ptb.move_call(&self.package_id, INTENT_MODULE, function, type_args, args)
+    }
+
+    /// `call` as `sui client ptb` arguments (`--move-call ...`)
+    pub fn ptb_args(&self, call: &IntentCall<&str>) -> Vec<String> {
+        let string = |value: &str| format!("\"{}\"", value);
+        let mut args = vec![
+            "--move-call".to_string(),
+            self.target(call.function()),
+            format!("<{}>", call.type_args().join(",")),
+        ];
+        match call {
+            IntentCall::Create {
+                input,
+                min_apy,
+                deadline_secs,
+                target_protocol,
+                ..
+            } => args.extend([
+                input.to_string(),
+                min_apy.to_string(),
+                deadline_secs.to_string(),
+                string(target_protocol),
+            ]),
+            IntentCall::Fulfill {
+                intent,
+                output,
+                protocol,
+                apy,
+                ..
+            } => args.extend([
+                format!("@{}", intent.object_id),
+                output.to_string(),
+                string(protocol),
+                apy.to_string(),
+            ]),
+            IntentCall::CancelExpired { intent } | IntentCall::UserCancel { intent } => {
+                args.push(format!("@{}", intent.object_id))
+            }
+        }
+        args
+    }
+
+    /// PTB locking `intent.amount` in a new intent
+    ///
+    /// SUI is split off the gas coin and `coins` are ignored; other coin
+    /// types are split off `coins` (the sender's, of `intent.coin_type`),
+    /// merged into the first one.
+    pub fn create_intent_ptb(
+        &self,
+        intent: &NewIntent,
+        coins: &[ObjectRef],
+    ) -> Result<ProgrammableTransactionBlock, ProtocolError> {
+        if intent.amount == 0 {
+            return Err(ProtocolError::InvalidIntent(
+                "amount must be positive".to_string(),
+            ));
+        }
+
+        let mut ptb = PtbBuilder::new();
+        let source = if intent.coin_type == SUI_COIN_TYPE {
+            PtbArgument::GasCoin
+        } else {
+            let mut inputs = coins
+                .iter()
+                .map(|c| ptb.add_object(&c.object_id, c.version, &c.digest));
+            let primary = inputs.next().ok_or_else(|| {
+                ProtocolError::InvalidIntent(format!("no {} coins to lock", intent.coin_type))
+            })?;
+            let rest: Vec<_> = inputs.collect();
+            if !rest.is_empty() {
+                ptb.merge_coins(primary.clone(), rest);
+            }
+            primary
+        };
+        let amount = ptb.add_pure(&intent.amount);
+        let input = ptb.split_coins(source, vec![amount]);
+
+        self.add_call(
+            &mut ptb,
+            IntentCall::Create {
+                coin_type: intent.coin_type.clone(),
+                input,
+                min_apy: intent.min_apy,
+                deadline_secs: intent.deadline_secs,
+                target_protocol: intent
+                    .target_protocol
+                    .clone()
+                    .unwrap_or_else(|| ANY_PROTOCOL.to_string()),
+            },
+        );
+        Ok(ptb.build())
+    }
+
+    fn intent_input(ptb: &mut PtbBuilder, intent: &IntentObject) -> PtbArgument {
+        ptb.add_shared_object(&intent.object_id, intent.initial_shared_version, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::{PtbCommand, PtbInput};
+
+    const SSUI: &str = "0xd384::s_coin::sCoin<0x2::sui::SUI>";
+
+    fn intent() -> IntentObject {
+        IntentObject::new("0x1a", 42, "0x2::sui::SUI")
+    }
+
+    #[test]
+    fn test_fulfill_ptb() {
+        let package = IntentPackage::new("0xabc");
+        let mut ptb = PtbBuilder::new();
+        let coin = ptb.add_object("0xc0", 3, "digest");
+        package.add_call(
+            &mut ptb,
+            IntentCall::Fulfill {
+                intent: intent(),
+                output_type: SSUI.to_string(),
+                output: coin,
+                protocol: "scallop".to_string(),
+                apy: 820,
+            },
+        );
+        let ptb = ptb.build();
+
+        let PtbCommand::MoveCall(call) = &ptb.commands[0] else {
+            panic!("expected a move call");
+        };
+        assert_eq!(
+            (
+                call.package.as_str(),
+                call.module.as_str(),
+                call.function.as_str()
+            ),
+            ("0xabc", "intent", "fulfill_intent")
+        );
+        assert_eq!(call.type_arguments, vec!["0x2::sui::SUI", SSUI]);
+        assert!(matches!(
+            &ptb.inputs[1],
+            PtbInput::SharedObject { object_id, initial_shared_version: 42, mutable: true }
+                if object_id == "0x1a"
+        ));
+    }
+
+    #[test]
+    fn test_create_intent_ptb() {
+        let package = IntentPackage::new("0xabc");
+        let mut intent = NewIntent {
+            coin_type: SUI_COIN_TYPE.to_string(),
+            amount: 2_000_000_000,
+            min_apy: 500,
+            deadline_secs: 3_600,
+            target_protocol: None,
+        };
+
+        let sui = package.create_intent_ptb(&intent, &[]).unwrap();
+        assert!(matches!(
+            &sui.commands[0],
+            PtbCommand::SplitCoins(split) if matches!(split.coin, PtbArgument::GasCoin)
+        ));
+        let PtbCommand::MoveCall(call) = &sui.commands[1] else {
+            panic!("expected a move call");
+        };
+        assert_eq!(call.function, "create_intent");
+        assert_eq!(call.type_arguments, vec![SUI_COIN_TYPE]);
+        // amount, min_apy, deadline, "any"
+        assert_eq!(sui.inputs.len(), 4);
+
+        intent.coin_type = "0xdba3::usdc::USDC".to_string();
+        assert!(package.create_intent_ptb(&intent, &[]).is_err());
+        let coin = |id: &str| ObjectRef {
+            object_id: id.to_string(),
+            version: 1,
+            digest: "d".to_string(),
+        };
+        let usdc = package
+            .create_intent_ptb(&intent, &[coin("0xc1"), coin("0xc2")])
+            .unwrap();
+        assert!(matches!(&usdc.commands[0], PtbCommand::MergeCoins(_)));
+        assert_eq!(usdc.commands.len(), 3);
+
+        intent.amount = 0;
+        assert!(package.create_intent_ptb(&intent, &[coin("0xc1")]).is_err());
+    }
+
+    #[test]
+    fn test_cli_args() {
+        let package = IntentPackage::from_config(&ProtocolConfig {
+            intent_package: Some("0xabc".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(
+            package.ptb_args(&IntentCall::Fulfill {
+                intent: intent(),
+                output_type: SSUI.to_string(),
+                output: "s_sui_coin",
+                protocol: "scallop".to_string(),
+                apy: 820,
+            }),
+            [
+                "--move-call",
+                "0xabc::intent::fulfill_intent",
+                "<0x2::sui::SUI,0xd384::s_coin::sCoin<0x2::sui::SUI>>",
+                "@0x1a",
+                "s_sui_coin",
+                "\"scallop\"",
+                "820",
+            ]
+        );
+        assert_eq!(
+            package.ptb_args(&IntentCall::<&str>::UserCancel { intent: intent() })[1..],
+            [
+                "0xabc::intent::user_cancel_intent",
+                "<0x2::sui::SUI>",
+                "@0x1a"
+            ]
+        );
+        assert!(IntentPackage::from_config(&ProtocolConfig::default()).is_err());
+    }
+}
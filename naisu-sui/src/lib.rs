@@ -1,22 +1,52 @@
 //! Naisu Sui - Sui blockchain integration
 //!
 //! This crate provides:
-//! - Sui RPC client for transaction building
+//! - Sui client over JSON-RPC or GraphQL
 //! - PTB (Programmable Transaction Block) construction
-//! - Scallop/Navi protocol integration
+//! - Signable `TransactionData` bytes for built PTBs
+//! - Typed calls into the Naisu intent package
+//! - Decoding of intent package objects (open intents, fulfillment receipts)
+//! - Typed transaction effects / object change parsing
+//! - Scallop/Navi protocol integration (`scallop` / `navi` features)
+//! - zkLogin address derivation and signature assembly
+//! - Signature verification for personal messages
+//! - SuiNS name resolution
+//! - Client-side RPC rate limiting shared per endpoint
 //! - Bridge fund detection
 //! - Protocol adapters for yield optimization
 
 pub mod adapters;
+pub mod bridge;
 pub mod cctp;
 pub mod client;
 pub mod config;
+pub mod effects;
+pub mod graphql;
+pub mod intent_object;
+pub mod intent_package;
 pub mod protocols;
 pub mod ptb;
+pub mod rate_limit;
+pub mod suins;
+pub mod transaction;
+pub mod transport;
+pub mod verify;
+pub mod zklogin;
 
 pub use adapters::*;
+pub use bridge::*;
 pub use cctp::*;
 pub use client::*;
 pub use config::*;
+pub use effects::*;
+pub use graphql::GraphQlTransport;
+pub use intent_object::*;
+pub use intent_package::*;
 pub use protocols::*;
 pub use ptb::*;
+pub use rate_limit::{RateLimitConfig, RateLimiter};
+pub use suins::*;
+pub use transaction::*;
+pub use transport::*;
+pub use verify::*;
+pub use zklogin::*;
@@ -1,14 +1,19 @@
-//! Sui DeFi protocol integrations (Scallop, Navi)
+//! Sui DeFi protocol integrations (Scallop, Navi, registered custom strategies)
+
+use std::collections::BTreeMap;
 
 use crate::ptb::{PtbArgument, PtbBuilder};
-use naisu_core::YieldStrategy;
+use naisu_core::{RiskTier, YieldStrategy, MAX_BUILTIN_STRATEGY_ID};
+use serde::{Deserialize, Serialize};
 
 /// Scallop protocol integration
+#[cfg(feature = "scallop")]
 pub struct ScallopProtocol {
     pub package_id: String,
     pub market_id: String,
 }
 
+#[cfg(feature = "scallop")]
 impl ScallopProtocol {
     pub fn new(package_id: String, market_id: String) -> Self {
         Self {
@@ -59,11 +64,13 @@ impl ScallopProtocol {
 }
 
 /// Navi protocol integration
+#[cfg(feature = "navi")]
 pub struct NaviProtocol {
     pub package_id: String,
     pub pool_id: String,
 }
 
+#[cfg(feature = "navi")]
 impl NaviProtocol {
     pub fn new(package_id: String, pool_id: String) -> Self {
         Self {
@@ -124,6 +131,7 @@ impl ProtocolFactory {
         let mut ptb = PtbBuilder::new();
 
         match strategy {
+            #[cfg(feature = "scallop")]
             YieldStrategy::ScallopUsdc => {
                 let scallop = ScallopProtocol::new(
                     protocol_config
@@ -142,6 +150,9 @@ impl ProtocolFactory {
                 );
                 scallop.build_deposit_usdc(&mut ptb, usdc_coin, market);
             }
+            #[cfg(not(feature = "scallop"))]
+            YieldStrategy::ScallopUsdc => return Err(ProtocolError::NotCompiled("scallop")),
+            #[cfg(feature = "navi")]
             YieldStrategy::NaviUsdc => {
                 let navi = NaviProtocol::new(
                     protocol_config
@@ -157,6 +168,8 @@ impl ProtocolFactory {
                     ptb.add_shared_object(&protocol_config.navi_pool.clone().unwrap(), 1, true);
                 navi.build_deposit_usdc(&mut ptb, usdc_coin, pool);
             }
+            #[cfg(not(feature = "navi"))]
+            YieldStrategy::NaviUsdc => return Err(ProtocolError::NotCompiled("navi")),
             YieldStrategy::ScallopSui | YieldStrategy::NaviSui => {
                 // For SUI strategies, need to swap USDC -> SUI first
                 // This would involve DeepBook integration
@@ -164,8 +177,12 @@ impl ProtocolFactory {
                     "SUI deposit strategies require swap",
                 ));
             }
-            YieldStrategy::Custom(_) => {
-                return Err(ProtocolError::NotImplemented("Custom strategies"));
+            YieldStrategy::Custom(id) => {
+                protocol_config
+                    .strategies
+                    .get(id)
+                    .ok_or(ProtocolError::UnknownStrategy(id))?
+                    .build_deposit(&mut ptb, usdc_coin);
             }
         }
 
@@ -181,6 +198,196 @@ pub struct ProtocolConfig {
     pub navi_package: Option<String>,
     pub navi_pool: Option<String>,
     pub deepbook_package: Option<String>,
+    /// Naisu intent package (`IntentPackage`)
+    pub intent_package: Option<String>,
+    /// Registered custom strategies
+    pub strategies: StrategyRegistry,
+}
+
+// ─── Custom strategies ───────────────────────────────────────────────────────
+
+/// Argument of a custom strategy's deposit function, in call order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StrategyArg {
+    /// The deposited coin
+    Coin,
+    /// Shared object such as a pool or market
+    Shared {
+        object_id: String,
+        #[serde(default = "default_initial_version")]
+        initial_version: u64,
+        #[serde(default = "default_mutable")]
+        mutable: bool,
+    },
+    /// The system clock (`0x6`)
+    Clock,
+    /// Pure `u64`
+    U64 { value: u64 },
+}
+
+fn default_initial_version() -> u64 {
+    1
+}
+
+fn default_mutable() -> bool {
+    true
+}
+
+/// A strategy deposited into by calling `package::module::function`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomStrategy {
+    /// On-chain strategy ID (`YieldStrategy::Custom(id)`), above the built-ins
+    pub id: u8,
+    pub name: String,
+    pub protocol: String,
+    pub package: String,
+    pub module: String,
+    pub function: String,
+    /// Type of the deposited coin
+    pub coin_type: String,
+    /// Type arguments of the call (`[coin_type]` when empty)
+    #[serde(default)]
+    pub type_args: Vec<String>,
+    pub args: Vec<StrategyArg>,
+    #[serde(default)]
+    pub risk_tier: RiskTier,
+}
+
+impl CustomStrategy {
+    /// Check the definition is callable
+    pub fn validate(&self) -> Result<(), ProtocolError> {
+        let invalid = |reason: String| ProtocolError::InvalidStrategy {
+            id: self.id,
+            reason,
+        };
+
+        if self.id <= MAX_BUILTIN_STRATEGY_ID {
+            return Err(invalid(format!(
+                "IDs up to {} are built-in strategies",
+                MAX_BUILTIN_STRATEGY_ID
+            )));
+        }
+        if !is_object_id(&self.package) {
+            return Err(invalid(format!("invalid package `{}`", self.package)));
+        }
+        for name in [&self.module, &self.function] {
+            if !is_identifier(name) {
+                return Err(invalid(format!("invalid Move identifier `{}`", name)));
+            }
+        }
+        for ty in std::iter::once(&self.coin_type).chain(&self.type_args) {
+            if ty.split("::").count() < 3 {
+                return Err(invalid(format!("invalid type `{}`", ty)));
+            }
+        }
+        if self
+            .args
+            .iter()
+            .filter(|a| **a == StrategyArg::Coin)
+            .count()
+            != 1
+        {
+            return Err(invalid("args must take the coin exactly once".to_string()));
+        }
+        for arg in &self.args {
+            if let StrategyArg::Shared { object_id, .. } = arg {
+                if !is_object_id(object_id) {
+                    return Err(invalid(format!("invalid object `{}`", object_id)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the deposit call to a PTB
+    pub fn build_deposit(&self, ptb: &mut PtbBuilder, coin: PtbArgument) -> PtbArgument {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| match arg {
+                StrategyArg::Coin => coin.clone(),
+                StrategyArg::Shared {
+                    object_id,
+                    initial_version,
+                    mutable,
+                } => ptb.add_shared_object(object_id, *initial_version, *mutable),
+                StrategyArg::Clock => ptb.add_shared_object("0x6", 1, false),
+                StrategyArg::U64 { value } => ptb.add_pure(value),
+            })
+            .collect();
+        let type_args = if self.type_args.is_empty() {
+            vec![self.coin_type.clone()]
+        } else {
+            self.type_args.clone()
+        };
+        ptb.move_call(&self.package, &self.module, &self.function, type_args, args)
+    }
+}
+
+/// Custom strategies by ID
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StrategyRegistry {
+    strategies: BTreeMap<u8, CustomStrategy>,
+}
+
+impl StrategyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry of `strategies`, rejecting invalid or duplicate entries
+    pub fn from_strategies(
+        strategies: impl IntoIterator<Item = CustomStrategy>,
+    ) -> Result<Self, ProtocolError> {
+        let mut registry = Self::new();
+        for strategy in strategies {
+            if registry.strategies.contains_key(&strategy.id) {
+                return Err(ProtocolError::InvalidStrategy {
+                    id: strategy.id,
+                    reason: "duplicate ID".to_string(),
+                });
+            }
+            registry.register(strategy)?;
+        }
+        Ok(registry)
+    }
+
+    /// Add or replace a strategy
+    pub fn register(&mut self, strategy: CustomStrategy) -> Result<(), ProtocolError> {
+        strategy.validate()?;
+        self.strategies.insert(strategy.id, strategy);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: u8) -> Option<CustomStrategy> {
+        self.strategies.remove(&id)
+    }
+
+    pub fn get(&self, id: u8) -> Option<&CustomStrategy> {
+        self.strategies.get(&id)
+    }
+
+    /// Registered strategies by ascending ID
+    pub fn list(&self) -> Vec<CustomStrategy> {
+        self.strategies.values().cloned().collect()
+    }
+}
+
+/// `0x` followed by 1-64 hex digits
+fn is_object_id(value: &str) -> bool {
+    value.strip_prefix("0x").is_some_and(|hex| {
+        !hex.is_empty() && hex.len() <= 64 && hex.bytes().all(|b| b.is_ascii_hexdigit())
+    })
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
 /// Protocol errors
@@ -192,6 +399,100 @@ pub enum ProtocolError {
     #[error("Not implemented: {0}")]
     NotImplemented(&'static str),
 
+    #[error("Protocol not compiled in (enable the `{0}` feature)")]
+    NotCompiled(&'static str),
+
+    #[error("Unknown strategy ID: {0}")]
+    UnknownStrategy(u8),
+
+    #[error("Invalid strategy {id}: {reason}")]
+    InvalidStrategy { id: u8, reason: String },
+
     #[error("Execution failed: {0}")]
     ExecutionFailed(String),
+
+    #[error("Invalid intent: {0}")]
+    InvalidIntent(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::PtbCommand;
+
+    fn strategy(id: u8) -> CustomStrategy {
+        CustomStrategy {
+            id,
+            name: "Suilend USDC".to_string(),
+            protocol: "Suilend".to_string(),
+            package: "0xabc".to_string(),
+            module: "lending_market".to_string(),
+            function: "deposit_liquidity_and_mint_ctokens".to_string(),
+            coin_type: "0xdba3::usdc::USDC".to_string(),
+            type_args: vec![],
+            args: vec![
+                StrategyArg::Shared {
+                    object_id: "0x84030".to_string(),
+                    initial_version: 7,
+                    mutable: true,
+                },
+                StrategyArg::U64 { value: 0 },
+                StrategyArg::Clock,
+                StrategyArg::Coin,
+            ],
+            risk_tier: RiskTier::Medium,
+        }
+    }
+
+    #[test]
+    fn test_registry_validation() {
+        assert!(matches!(
+            StrategyRegistry::from_strategies([strategy(3)]),
+            Err(ProtocolError::InvalidStrategy { id: 3, .. })
+        ));
+        assert!(StrategyRegistry::from_strategies([strategy(5), strategy(5)]).is_err());
+
+        let mut no_coin = strategy(6);
+        no_coin.args.retain(|a| *a != StrategyArg::Coin);
+        assert!(no_coin.validate().is_err());
+
+        let mut bad_module = strategy(6);
+        bad_module.module = "lending-market".to_string();
+        assert!(bad_module.validate().is_err());
+
+        let registry = StrategyRegistry::from_strategies([strategy(9), strategy(5)]).unwrap();
+        let ids: Vec<u8> = registry.list().iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![5, 9]);
+    }
+
+    #[test]
+    fn test_custom_deposit_ptb() {
+        let config = ProtocolConfig {
+            strategies: StrategyRegistry::from_strategies([strategy(5)]).unwrap(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            ProtocolFactory::build_deposit_ptb(
+                YieldStrategy::Custom(6),
+                PtbArgument::GasCoin,
+                &config
+            ),
+            Err(ProtocolError::UnknownStrategy(6))
+        ));
+
+        let ptb = ProtocolFactory::build_deposit_ptb(
+            YieldStrategy::Custom(5),
+            PtbArgument::GasCoin,
+            &config,
+        )
+        .unwrap()
+        .build();
+        assert_eq!(ptb.inputs.len(), 3);
+        let PtbCommand::MoveCall(call) = &ptb.commands[0] else {
+            panic!("expected a move call");
+        };
+        assert_eq!(call.function, "deposit_liquidity_and_mint_ctokens");
+        assert_eq!(call.type_arguments, vec!["0xdba3::usdc::USDC".to_string()]);
+        assert!(matches!(call.arguments[3], PtbArgument::GasCoin));
+    }
 }
@@ -162,6 +162,18 @@ impl PtbBuilder {
         PtbArgument::Result { index }
     }
 
+    /// Split `amount` off `coin` and send it to `recipient` (e.g. a fee
+    /// treasury)
+    pub fn route_fee(&mut self, coin: PtbArgument, amount: PtbArgument, recipient: PtbArgument) {
+        let index = self.commands.len() as u16;
+        self.split_coins(coin, vec![amount]);
+        let fee_coin = PtbArgument::NestedResult {
+            index,
+            result_index: 0,
+        };
+        self.transfer_objects(vec![fee_coin], recipient);
+    }
+
     /// Add a merge coins command
     pub fn merge_coins(&mut self, destination: PtbArgument, sources: Vec<PtbArgument>) {
         self.commands
@@ -180,6 +192,17 @@ impl PtbBuilder {
     }
 }
 
+/// Build a PTB paying `amount` MIST out of the sender's gas coin to
+/// `recipient` (treasury payouts such as referral rewards)
+pub fn build_sui_payout_ptb(amount: u64, recipient: &str) -> ProgrammableTransactionBlock {
+    let mut ptb = PtbBuilder::new();
+    let amount = ptb.add_pure(&amount);
+    let payout = ptb.split_coins(PtbArgument::GasCoin, vec![amount]);
+    let recipient = ptb.add_pure(&recipient);
+    ptb.transfer_objects(vec![payout], recipient);
+    ptb.build()
+}
+
 /// Complete PTB structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProgrammableTransactionBlock {
@@ -187,9 +210,7 @@ pub struct ProgrammableTransactionBlock {
     pub commands: Vec<PtbCommand>,
 }
 
-/// Simple BCS serialization (placeholder - use actual bcs crate in production)
-fn bcs_serialize<T: Serialize>(_value: &T) -> Vec<u8> {
-    // TODO: Use proper BCS serialization
-    // For MVP, this is a placeholder
-    vec![]
+/// BCS bytes of a pure value (plain integers and strings never fail)
+fn bcs_serialize<T: Serialize>(value: &T) -> Vec<u8> {
+    bcs::to_bytes(value).unwrap_or_default()
 }
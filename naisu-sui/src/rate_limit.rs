@@ -0,0 +1,286 @@
+//! Client-side RPC rate limiting
+//!
+//! Public full nodes answer aggressive pollers with 429s, then bans. Every
+//! transport talking to the same endpoint draws from one token bucket
+//! (`RateLimiter::shared`), so daemon polling, on-chain adapter reads and the
+//! indexer can't add up past the limit during an intent burst.
+//!
+//! Calls cost tokens by method (`method_weight`). A 429 halves the refill
+//! rate and pauses the bucket for the server's `Retry-After`; every call
+//! that gets through wins back a little of the configured rate.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use reqwest::{RequestBuilder, Response, StatusCode};
+
+use crate::client::SuiClientError;
+
+/// Calls retried after a 429 before giving up
+pub const MAX_THROTTLE_RETRIES: u32 = 3;
+
+/// Pause after a 429 without `Retry-After`
+const DEFAULT_BACKOFF: Duration = Duration::from_secs(2);
+
+/// The refill rate never drops below the configured one divided by this
+const MAX_SLOWDOWN: f64 = 16.0;
+
+/// Share of the configured rate won back per successful call
+const RECOVERY_STEP: f64 = 0.02;
+
+/// Token bucket size and refill rate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitConfig {
+    /// Tokens added per second; 0 disables limiting
+    pub requests_per_sec: f64,
+    /// Bucket size: how many tokens a burst can spend at once
+    pub burst: f64,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_sec: 10.0,
+            burst: 20.0,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Defaults overridden by `SUI_RPC_RATE_LIMIT` and `SUI_RPC_BURST`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| *v >= 0.0)
+        };
+        if let Some(rate) = var("SUI_RPC_RATE_LIMIT") {
+            config.requests_per_sec = rate;
+        }
+        if let Some(burst) = var("SUI_RPC_BURST") {
+            config.burst = burst.max(1.0);
+        }
+        config
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.requests_per_sec > 0.0
+    }
+}
+
+/// Tokens a JSON-RPC method costs
+///
+/// Event queries scan an index and dry runs execute the transaction, so
+/// full nodes count them against their limits more heavily than point reads.
+pub fn method_weight(method: &str) -> u32 {
+    match method {
+        "suix_queryEvents" => 3,
+        "sui_dryRunTransactionBlock"
+        | "sui_executeTransactionBlock"
+        | "suix_getLatestSuiSystemState" => 2,
+        _ => 1,
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    /// Current refill rate, lowered by 429s
+    rate: f64,
+    updated: Instant,
+    paused_until: Option<Instant>,
+}
+
+/// Token bucket shared by the transports of one endpoint
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            bucket: Mutex::new(Bucket {
+                tokens: config.burst,
+                rate: config.requests_per_sec,
+                updated: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// The process-wide limiter of `endpoint`, created with `config` on
+    /// first use
+    pub fn shared(endpoint: &str, config: RateLimitConfig) -> Arc<RateLimiter> {
+        static LIMITERS: OnceLock<Mutex<HashMap<String, Arc<RateLimiter>>>> = OnceLock::new();
+        LIMITERS
+            .get_or_init(Default::default)
+            .lock()
+            .expect("rate limiter registry poisoned")
+            .entry(endpoint.trim_end_matches('/').to_string())
+            .or_insert_with(|| Arc::new(RateLimiter::new(config)))
+            .clone()
+    }
+
+    /// Wait until `weight` tokens are available and take them
+    pub async fn acquire(&self, weight: u32) {
+        if !self.config.is_enabled() {
+            return;
+        }
+        loop {
+            match self.try_acquire(weight, Instant::now()) {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Take `weight` tokens, or say how long until there might be enough
+    fn try_acquire(&self, weight: u32, now: Instant) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().expect("rate limiter poisoned");
+        if let Some(until) = bucket.paused_until {
+            if now < until {
+                return Some(until - now);
+            }
+            bucket.paused_until = None;
+        }
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * bucket.rate).min(self.config.burst);
+        bucket.updated = now;
+
+        // A call heavier than the whole bucket waits for a full one
+        let weight = (weight as f64).min(self.config.burst);
+        if bucket.tokens >= weight {
+            bucket.tokens -= weight;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (weight - bucket.tokens) / bucket.rate,
+            ))
+        }
+    }
+
+    /// The endpoint answered 429: slow down and pause
+    pub fn on_throttled(&self, retry_after: Option<Duration>) {
+        let mut bucket = self.bucket.lock().expect("rate limiter poisoned");
+        let floor = self.config.requests_per_sec / MAX_SLOWDOWN;
+        bucket.rate = (bucket.rate / 2.0).max(floor);
+        bucket.tokens = 0.0;
+        let until = Instant::now() + retry_after.unwrap_or(DEFAULT_BACKOFF);
+        bucket.paused_until = Some(bucket.paused_until.map_or(until, |p| p.max(until)));
+        tracing::warn!("Sui RPC rate limited, slowing to {:.1} req/s", bucket.rate);
+    }
+
+    /// A call got through: win back some of the configured rate
+    pub fn on_success(&self) {
+        let mut bucket = self.bucket.lock().expect("rate limiter poisoned");
+        bucket.rate = (bucket.rate + self.config.requests_per_sec * RECOVERY_STEP)
+            .min(self.config.requests_per_sec);
+    }
+
+    /// Current refill rate (tokens per second)
+    pub fn rate(&self) -> f64 {
+        self.bucket.lock().expect("rate limiter poisoned").rate
+    }
+}
+
+/// Send a request through `limiter`, retrying it when the endpoint answers
+/// 429
+///
+/// A 429 means the node turned the call away unprocessed, so resending is
+/// safe even for transaction execution. Other statuses are returned as-is.
+pub(crate) async fn send(
+    limiter: &RateLimiter,
+    weight: u32,
+    request: impl Fn() -> RequestBuilder,
+) -> Result<Response, SuiClientError> {
+    let mut attempt = 0;
+    loop {
+        limiter.acquire(weight).await;
+        let response = request()
+            .send()
+            .await
+            .map_err(|e| SuiClientError::Request(e.to_string()))?;
+
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            if response.status().is_success() {
+                limiter.on_success();
+            }
+            return Ok(response);
+        }
+        limiter.on_throttled(retry_after(response.headers()));
+        if attempt == MAX_THROTTLE_RETRIES {
+            return Ok(response);
+        }
+        attempt += 1;
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds
+pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            requests_per_sec: 10.0,
+            burst: 5.0,
+        })
+    }
+
+    #[test]
+    fn test_bucket_spends_burst_then_refills() {
+        let limiter = limiter();
+        let start = Instant::now();
+
+        assert_eq!(limiter.try_acquire(3, start), None);
+        assert_eq!(limiter.try_acquire(2, start), None);
+        // Empty: one token takes 100ms at 10/s
+        let wait = limiter.try_acquire(1, start).unwrap();
+        assert!((wait.as_secs_f64() - 0.1).abs() < 1e-9);
+        assert_eq!(limiter.try_acquire(1, start + wait), None);
+    }
+
+    #[test]
+    fn test_throttling_slows_down_and_recovers() {
+        let limiter = limiter();
+        limiter.on_throttled(Some(Duration::from_secs(30)));
+        assert_eq!(limiter.rate(), 5.0);
+        assert!(limiter.try_acquire(1, Instant::now()).unwrap() > Duration::from_secs(29));
+
+        for _ in 0..4 {
+            limiter.on_throttled(None);
+        }
+        // Never below 1/16 of the configured rate
+        assert_eq!(limiter.rate(), 10.0 / MAX_SLOWDOWN);
+
+        for _ in 0..100 {
+            limiter.on_success();
+        }
+        assert_eq!(limiter.rate(), 10.0);
+    }
+
+    #[test]
+    fn test_method_weights() {
+        assert_eq!(method_weight("suix_queryEvents"), 3);
+        assert_eq!(method_weight("sui_dryRunTransactionBlock"), 2);
+        assert_eq!(method_weight("sui_getObject"), 1);
+    }
+}
@@ -0,0 +1,65 @@
+//! SuiNS names
+//!
+//! Names come as `alice.sui` or in the short `@alice` form, subnames as
+//! `bob.alice.sui` or `bob@alice`. Both forms map onto the `.sui` form the
+//! registry is keyed by. Lookups read the SuiNS registry through the
+//! client's transport (`SuiClient::resolve_suins` / `suins_name`).
+
+/// Longest label the registry accepts
+const MAX_LABEL_LEN: usize = 63;
+
+/// Canonical `*.sui` form of a SuiNS name; `None` if `input` isn't one
+pub fn normalize_suins(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let labels: Vec<&str> = match input.split_once('@') {
+        Some((sub, parent)) => sub
+            .split('.')
+            .filter(|_| !sub.is_empty())
+            .chain([parent])
+            .collect(),
+        None => input.strip_suffix(".sui")?.split('.').collect(),
+    };
+    if labels.is_empty() || !labels.iter().all(|l| is_label(l)) {
+        return None;
+    }
+    Some(format!("{}.sui", labels.join(".")))
+}
+
+/// Whether `input` is a SuiNS name rather than an address
+pub fn is_suins_name(input: &str) -> bool {
+    normalize_suins(input).is_some()
+}
+
+fn is_label(label: &str) -> bool {
+    (1..=MAX_LABEL_LEN).contains(&label.len())
+        && label
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_suins() {
+        assert_eq!(normalize_suins("Alice.sui").as_deref(), Some("alice.sui"));
+        assert_eq!(normalize_suins("@alice").as_deref(), Some("alice.sui"));
+        assert_eq!(
+            normalize_suins("bob@alice").as_deref(),
+            Some("bob.alice.sui")
+        );
+        assert_eq!(
+            normalize_suins("bob.alice.sui").as_deref(),
+            Some("bob.alice.sui")
+        );
+
+        assert!(!is_suins_name("0x2"));
+        assert!(!is_suins_name(".sui"));
+        assert!(!is_suins_name("-alice.sui"));
+        assert!(!is_suins_name("alice.eth"));
+        assert!(!is_suins_name("bob@"));
+    }
+}
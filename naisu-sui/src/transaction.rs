@@ -0,0 +1,472 @@
+//! Signable transaction bytes
+//!
+//! Encodes a `ProgrammableTransactionBlock` with its sender and gas as BCS
+//! `TransactionData` (the `V1` layout of `sui-types`), base64'd into the
+//! `tx_bytes` wallets sign and nodes execute. Only the inputs and commands
+//! `PtbBuilder` produces are covered.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+use crate::client::CoinObject;
+use crate::ptb::{ProgrammableTransactionBlock, PtbArgument, PtbCommand, PtbInput};
+
+/// An owned object at a version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectRef {
+    pub object_id: String,
+    pub version: u64,
+    /// Base58
+    pub digest: String,
+}
+
+impl TryFrom<&CoinObject> for ObjectRef {
+    type Error = TransactionError;
+
+    fn try_from(coin: &CoinObject) -> Result<Self, Self::Error> {
+        Ok(Self {
+            object_id: coin.coin_object_id.clone(),
+            version: coin
+                .version
+                .parse()
+                .map_err(|_| TransactionError::InvalidVersion(coin.version.clone()))?,
+            digest: coin.digest.clone(),
+        })
+    }
+}
+
+/// Who pays for a transaction, with which coins and how much
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasData {
+    /// SUI coins owned by `owner`
+    pub payment: Vec<ObjectRef>,
+    pub owner: String,
+    /// Reference gas price or above (MIST)
+    pub price: u64,
+    /// MIST
+    pub budget: u64,
+}
+
+impl ProgrammableTransactionBlock {
+    /// BCS `TransactionData` of this PTB sent by `sender`
+    pub fn transaction_data(
+        &self,
+        sender: &str,
+        gas: &GasData,
+    ) -> Result<Vec<u8>, TransactionError> {
+        if gas.payment.is_empty() {
+            return Err(TransactionError::NoGas);
+        }
+        let data = wire::TransactionData::V1(wire::TransactionDataV1 {
+            kind: wire::TransactionKind::ProgrammableTransaction(wire::ProgrammableTransaction {
+                inputs: self.inputs.iter().map(call_arg).collect::<Result<_, _>>()?,
+                commands: self
+                    .commands
+                    .iter()
+                    .map(command)
+                    .collect::<Result<_, _>>()?,
+            }),
+            sender: address(sender)?,
+            gas_data: wire::GasData {
+                payment: gas
+                    .payment
+                    .iter()
+                    .map(object_ref)
+                    .collect::<Result<_, _>>()?,
+                owner: address(&gas.owner)?,
+                price: gas.price,
+                budget: gas.budget,
+            },
+            expiration: wire::TransactionExpiration::None,
+        });
+        bcs::to_bytes(&data).map_err(|e| TransactionError::Bcs(e.to_string()))
+    }
+
+    /// Base64 `tx_bytes` to sign
+    pub fn tx_bytes(&self, sender: &str, gas: &GasData) -> Result<String, TransactionError> {
+        Ok(BASE64.encode(self.transaction_data(sender, gas)?))
+    }
+}
+
+// ─── BCS layout ──────────────────────────────────────────────────────────────
+
+/// Mirrors of the `sui-types` structures; variant order is the wire format
+mod wire {
+    use serde::Serialize;
+
+    pub type Address = [u8; 32];
+    pub type ObjectRef = (Address, u64, Vec<u8>);
+
+    #[derive(Serialize)]
+    pub enum TransactionData {
+        V1(TransactionDataV1),
+    }
+
+    #[derive(Serialize)]
+    pub struct TransactionDataV1 {
+        pub kind: TransactionKind,
+        pub sender: Address,
+        pub gas_data: GasData,
+        pub expiration: TransactionExpiration,
+    }
+
+    #[derive(Serialize)]
+    pub enum TransactionKind {
+        ProgrammableTransaction(ProgrammableTransaction),
+    }
+
+    #[derive(Serialize)]
+    pub struct ProgrammableTransaction {
+        pub inputs: Vec<CallArg>,
+        pub commands: Vec<Command>,
+    }
+
+    #[derive(Serialize)]
+    pub enum CallArg {
+        Pure(Vec<u8>),
+        Object(ObjectArg),
+    }
+
+    #[derive(Serialize)]
+    pub enum ObjectArg {
+        ImmOrOwnedObject(ObjectRef),
+        SharedObject {
+            id: Address,
+            initial_shared_version: u64,
+            mutable: bool,
+        },
+    }
+
+    #[derive(Serialize)]
+    pub enum Command {
+        MoveCall(ProgrammableMoveCall),
+        TransferObjects(Vec<Argument>, Argument),
+        SplitCoins(Argument, Vec<Argument>),
+        MergeCoins(Argument, Vec<Argument>),
+    }
+
+    #[derive(Serialize)]
+    pub struct ProgrammableMoveCall {
+        pub package: Address,
+        pub module: String,
+        pub function: String,
+        pub type_arguments: Vec<TypeTag>,
+        pub arguments: Vec<Argument>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serialize)]
+    pub enum TypeTag {
+        Bool,
+        U8,
+        U64,
+        U128,
+        Address,
+        Signer,
+        Vector(Box<TypeTag>),
+        Struct(Box<StructTag>),
+        U16,
+        U32,
+        U256,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serialize)]
+    pub struct StructTag {
+        pub address: Address,
+        pub module: String,
+        pub name: String,
+        pub type_params: Vec<TypeTag>,
+    }
+
+    #[derive(Serialize)]
+    pub enum Argument {
+        GasCoin,
+        Input(u16),
+        Result(u16),
+        NestedResult(u16, u16),
+    }
+
+    #[derive(Serialize)]
+    pub struct GasData {
+        pub payment: Vec<ObjectRef>,
+        pub owner: Address,
+        pub price: u64,
+        pub budget: u64,
+    }
+
+    #[derive(Serialize)]
+    pub enum TransactionExpiration {
+        None,
+    }
+}
+
+fn call_arg(input: &PtbInput) -> Result<wire::CallArg, TransactionError> {
+    Ok(match input {
+        PtbInput::Pure { value } => wire::CallArg::Pure(value.clone()),
+        PtbInput::Object {
+            object_id,
+            version,
+            digest,
+        } => wire::CallArg::Object(wire::ObjectArg::ImmOrOwnedObject((
+            address(object_id)?,
+            *version,
+            object_digest(digest)?,
+        ))),
+        PtbInput::SharedObject {
+            object_id,
+            initial_shared_version,
+            mutable,
+        } => wire::CallArg::Object(wire::ObjectArg::SharedObject {
+            id: address(object_id)?,
+            initial_shared_version: *initial_shared_version,
+            mutable: *mutable,
+        }),
+    })
+}
+
+fn command(command: &PtbCommand) -> Result<wire::Command, TransactionError> {
+    let arguments = |args: &[PtbArgument]| args.iter().map(argument).collect::<Vec<_>>();
+    Ok(match command {
+        PtbCommand::MoveCall(call) => wire::Command::MoveCall(wire::ProgrammableMoveCall {
+            package: address(&call.package)?,
+            module: call.module.clone(),
+            function: call.function.clone(),
+            type_arguments: call
+                .type_arguments
+                .iter()
+                .map(|t| type_tag(t))
+                .collect::<Result<_, _>>()?,
+            arguments: arguments(&call.arguments),
+        }),
+        PtbCommand::TransferObjects(transfer) => wire::Command::TransferObjects(
+            arguments(&transfer.objects),
+            argument(&transfer.address),
+        ),
+        PtbCommand::SplitCoins(split) => {
+            wire::Command::SplitCoins(argument(&split.coin), arguments(&split.amounts))
+        }
+        PtbCommand::MergeCoins(merge) => {
+            wire::Command::MergeCoins(argument(&merge.destination), arguments(&merge.sources))
+        }
+    })
+}
+
+fn argument(argument: &PtbArgument) -> wire::Argument {
+    match *argument {
+        PtbArgument::GasCoin => wire::Argument::GasCoin,
+        PtbArgument::Input { index } => wire::Argument::Input(index),
+        PtbArgument::Result { index } => wire::Argument::Result(index),
+        PtbArgument::NestedResult {
+            index,
+            result_index,
+        } => wire::Argument::NestedResult(index, result_index),
+    }
+}
+
+fn object_ref(object: &ObjectRef) -> Result<wire::ObjectRef, TransactionError> {
+    Ok((
+        address(&object.object_id)?,
+        object.version,
+        object_digest(&object.digest)?,
+    ))
+}
+
+// ─── Parsing ─────────────────────────────────────────────────────────────────
+
+/// `0x`-prefixed hex address or object ID, left-padded to 32 bytes
+pub(crate) fn address(value: &str) -> Result<wire::Address, TransactionError> {
+    let invalid = || TransactionError::InvalidAddress(value.to_string());
+    let hex = value.strip_prefix("0x").ok_or_else(invalid)?;
+    if hex.is_empty() || hex.len() > 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let padded = format!("{:0>64}", hex);
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&padded[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/// Base58 object digest (32 bytes)
+fn object_digest(value: &str) -> Result<Vec<u8>, TransactionError> {
+    base58_decode(value)
+        .filter(|bytes| bytes.len() == 32)
+        .ok_or_else(|| TransactionError::InvalidDigest(value.to_string()))
+}
+
+fn base58_decode(value: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    // Little-endian while accumulating
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in value.bytes() {
+        let mut carry = ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Leading '1's are leading zero bytes
+    bytes.extend(value.bytes().take_while(|&c| c == b'1').map(|_| 0));
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// Move type such as `u64`, `vector<u8>` or `0x2::coin::Coin<0x2::sui::SUI>`
+fn type_tag(value: &str) -> Result<wire::TypeTag, TransactionError> {
+    use wire::TypeTag;
+
+    let value = value.trim();
+    let invalid = || TransactionError::InvalidType(value.to_string());
+    Ok(match value {
+        "bool" => TypeTag::Bool,
+        "u8" => TypeTag::U8,
+        "u16" => TypeTag::U16,
+        "u32" => TypeTag::U32,
+        "u64" => TypeTag::U64,
+        "u128" => TypeTag::U128,
+        "u256" => TypeTag::U256,
+        "address" => TypeTag::Address,
+        "signer" => TypeTag::Signer,
+        _ => {
+            if let Some(inner) = value
+                .strip_prefix("vector<")
+                .and_then(|v| v.strip_suffix('>'))
+            {
+                return Ok(TypeTag::Vector(Box::new(type_tag(inner)?)));
+            }
+            let (path, params) = match value.split_once('<') {
+                Some((path, rest)) => (path, rest.strip_suffix('>').ok_or_else(invalid)?),
+                None => (value, ""),
+            };
+            let parts: Vec<&str> = path.split("::").collect();
+            let [package, module, name] = parts[..] else {
+                return Err(invalid());
+            };
+            TypeTag::Struct(Box::new(wire::StructTag {
+                address: address(package).map_err(|_| invalid())?,
+                module: module.to_string(),
+                name: name.to_string(),
+                type_params: type_params(params)
+                    .into_iter()
+                    .map(type_tag)
+                    .collect::<Result<_, _>>()?,
+            }))
+        }
+    })
+}
+
+/// Split `A, B<C, D>` at its top-level commas
+fn type_params(params: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in params.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&params[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if !params[start..].trim().is_empty() {
+        parts.push(&params[start..]);
+    }
+    parts
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransactionError {
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Invalid object digest: {0}")]
+    InvalidDigest(String),
+
+    #[error("Invalid object version: {0}")]
+    InvalidVersion(String),
+
+    #[error("Invalid Move type: {0}")]
+    InvalidType(String),
+
+    #[error("No gas coin to pay with")]
+    NoGas,
+
+    #[error("BCS serialization failed: {0}")]
+    Bcs(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptb::PtbBuilder;
+
+    #[test]
+    fn test_type_tag() {
+        assert_eq!(
+            type_tag("vector<u8>").unwrap(),
+            wire::TypeTag::Vector(Box::new(wire::TypeTag::U8))
+        );
+        let wire::TypeTag::Struct(coin) = type_tag("0x2::coin::Coin<0x2::sui::SUI>").unwrap()
+        else {
+            panic!("expected a struct");
+        };
+        assert_eq!(coin.address[31], 2);
+        assert_eq!((coin.module.as_str(), coin.name.as_str()), ("coin", "Coin"));
+        assert_eq!(coin.type_params.len(), 1);
+
+        let wire::TypeTag::Struct(pair) = type_tag("0xa::pool::Pool<0x2::sui::SUI, u64>").unwrap()
+        else {
+            panic!("expected a struct");
+        };
+        assert_eq!(pair.type_params[1], wire::TypeTag::U64);
+        assert!(type_tag("0x2::sui").is_err());
+    }
+
+    #[test]
+    fn test_transaction_data() {
+        assert_eq!(base58_decode("11").unwrap(), vec![0, 0]);
+        assert_eq!(base58_decode("5Q").unwrap(), vec![0xff]);
+
+        let mut ptb = PtbBuilder::new();
+        let amount = ptb.add_pure(&1_000u64);
+        ptb.split_coins(PtbArgument::GasCoin, vec![amount]);
+        let ptb = ptb.build();
+        let gas = GasData {
+            payment: vec![ObjectRef {
+                object_id: "0xc0".to_string(),
+                version: 7,
+                digest: "11111111111111111111111111111111".to_string(),
+            }],
+            owner: "0xa11ce".to_string(),
+            price: 1_000,
+            budget: 10_000_000,
+        };
+        let bytes = ptb.transaction_data("0xa11ce", &gas).unwrap();
+
+        // V1, ProgrammableTransaction, one pure input of 8 bytes
+        assert_eq!(&bytes[..4], &[0, 0, 1, 0]);
+        assert_eq!(bytes[4], 8);
+        assert_eq!(&bytes[5..13], &1_000u64.to_le_bytes());
+        // No expiration
+        assert_eq!(*bytes.last().unwrap(), 0);
+
+        let no_gas = GasData {
+            payment: Vec::new(),
+            ..gas
+        };
+        assert!(matches!(
+            ptb.tx_bytes("0xa11ce", &no_gas),
+            Err(TransactionError::NoGas)
+        ));
+    }
+}
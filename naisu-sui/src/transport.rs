@@ -0,0 +1,171 @@
+//! Transport abstraction for talking to Sui
+//!
+//! `SuiClient` delegates every network call to a `SuiTransport`, so the
+//! classic JSON-RPC API and the Sui GraphQL service can be swapped via
+//! `SuiConfig::transport` without touching callers.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{CoinObject, DryRunResponse, SuiClientError, SuiObject, TransactionResponse};
+
+/// Operations every Sui transport must support
+#[async_trait]
+pub trait SuiTransport: Send + Sync {
+    /// Transport name (for logging)
+    fn name(&self) -> &'static str;
+
+    /// One page of the coins owned by an address, optionally filtered by
+    /// coin type
+    async fn get_coins(
+        &self,
+        owner: &str,
+        coin_type: Option<&str>,
+        cursor: Option<&str>,
+    ) -> Result<CoinPage, SuiClientError>;
+
+    /// Get object by ID
+    async fn get_object(&self, object_id: &str) -> Result<SuiObject, SuiClientError>;
+
+    /// Dynamic field of `parent_id` by name (`{"type": .., "value": ..}`)
+    async fn get_dynamic_field_object(
+        &self,
+        parent_id: &str,
+        name: serde_json::Value,
+    ) -> Result<SuiObject, SuiClientError>;
+
+    /// Query Move events of a given type (e.g. `0xpkg::intent::IntentCreated`)
+    async fn query_events(
+        &self,
+        event_type: &str,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<EventPage, SuiClientError>;
+
+    /// Dry run a transaction
+    async fn dry_run_transaction(&self, tx_bytes: &str) -> Result<DryRunResponse, SuiClientError>;
+
+    /// Execute a signed transaction
+    async fn execute_transaction(
+        &self,
+        tx_bytes: &str,
+        signatures: Vec<String>,
+    ) -> Result<TransactionResponse, SuiClientError>;
+
+    /// Reference gas price of the current epoch (MIST per gas unit)
+    async fn get_reference_gas_price(&self) -> Result<u64, SuiClientError>;
+
+    /// Check a zkLogin signature over a personal message (base64 bytes)
+    async fn verify_zklogin_signature(
+        &self,
+        message: &str,
+        signature: &str,
+        author: &str,
+    ) -> Result<ZkLoginVerifyResult, SuiClientError>;
+
+    /// Active validators of the current epoch
+    async fn get_validators(&self) -> Result<Vec<ValidatorInfo>, SuiClientError>;
+
+    /// Address a SuiNS name (`alice.sui`) points at; `None` if unregistered
+    async fn resolve_name(&self, name: &str) -> Result<Option<String>, SuiClientError> {
+        let _ = name;
+        Err(SuiClientError::Request(format!(
+            "{} transport cannot resolve SuiNS names",
+            self.name()
+        )))
+    }
+
+    /// Default SuiNS name of an address; `None` if it has none
+    async fn default_name(&self, address: &str) -> Result<Option<String>, SuiClientError> {
+        let _ = address;
+        Err(SuiClientError::Request(format!(
+            "{} transport cannot resolve SuiNS names",
+            self.name()
+        )))
+    }
+}
+
+/// Fullnode verdict on a zkLogin signature
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ZkLoginVerifyResult {
+    pub success: bool,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+/// Voting power (bps of the total) at which a validator is saturated
+pub const MAX_VOTING_POWER_BPS: u64 = 1_000;
+
+/// Active validator, as shown for staking
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    pub address: String,
+    pub name: String,
+    /// Annual yield as a fraction (0.03 = 3%)
+    pub apy: f64,
+    pub commission_bps: u64,
+    /// Stake in the validator's pool (MIST)
+    pub total_stake: u64,
+    /// Share of the committee's voting power (bps)
+    pub voting_power: u64,
+    /// `voting_power` over the cap; stake past 1.0 earns no extra weight
+    pub saturation: f64,
+}
+
+impl ValidatorInfo {
+    pub fn new(
+        address: impl Into<String>,
+        name: impl Into<String>,
+        apy: f64,
+        commission_bps: u64,
+        total_stake: u64,
+        voting_power: u64,
+    ) -> Self {
+        Self {
+            address: address.into(),
+            name: name.into(),
+            apy,
+            commission_bps,
+            total_stake,
+            voting_power,
+            saturation: voting_power as f64 / MAX_VOTING_POWER_BPS as f64,
+        }
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.voting_power >= MAX_VOTING_POWER_BPS
+    }
+}
+
+/// Transport-agnostic Move event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiEvent {
+    /// Full Move event type
+    pub event_type: String,
+    /// Event fields as JSON (same shape as JSON-RPC `parsedJson`)
+    pub parsed_json: serde_json::Value,
+    pub sender: Option<String>,
+    pub tx_digest: Option<String>,
+    pub timestamp_ms: Option<u64>,
+}
+
+/// One page of coins
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinPage {
+    pub data: Vec<CoinObject>,
+    /// Opaque cursor to pass back for the next page
+    pub next_cursor: Option<String>,
+    pub has_next_page: bool,
+}
+
+/// One page of events
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+    pub data: Vec<SuiEvent>,
+    /// Opaque cursor to pass back for the next page
+    pub next_cursor: Option<String>,
+    pub has_next_page: bool,
+}
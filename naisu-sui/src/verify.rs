@@ -0,0 +1,579 @@
+//! Sui signature verification
+//!
+//! Checks serialized Sui signatures (base64 `flag ‖ ...`) over a personal
+//! message against the address that claims to have signed it:
+//! - ed25519 / secp256k1 / secp256r1: `flag ‖ sig ‖ pubkey`
+//! - multisig (`0x03`): member signatures whose weights reach the threshold
+//! - zkLogin (`0x05`): the ephemeral signature and the derived address are
+//!   checked locally; the Groth16 proof is checked by the fullnode
+//!
+//! Used by the API to authenticate user operations (intent creation,
+//! cancellation) to a Sui wallet.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use k256::ecdsa::signature::Verifier;
+use serde::Deserialize;
+
+use crate::client::{SuiClient, SuiClientError};
+use crate::zklogin::{
+    hex_encode, normalize_address, zklogin_address, ZkLoginError, ZkLoginInputs, ZKLOGIN_FLAG,
+};
+
+/// Signature scheme flag for multisig
+pub const MULTISIG_FLAG: u8 = 0x03;
+
+type Blake2b256 = Blake2b<U32>;
+
+// ─── Keys ────────────────────────────────────────────────────────────────────
+
+/// Single-key signature schemes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyScheme {
+    Ed25519,
+    Secp256k1,
+    Secp256r1,
+}
+
+impl KeyScheme {
+    pub fn flag(&self) -> u8 {
+        match self {
+            KeyScheme::Ed25519 => 0x00,
+            KeyScheme::Secp256k1 => 0x01,
+            KeyScheme::Secp256r1 => 0x02,
+        }
+    }
+
+    pub fn from_flag(flag: u8) -> Option<Self> {
+        match flag {
+            0x00 => Some(KeyScheme::Ed25519),
+            0x01 => Some(KeyScheme::Secp256k1),
+            0x02 => Some(KeyScheme::Secp256r1),
+            _ => None,
+        }
+    }
+
+    /// Public key length in bytes
+    pub fn public_key_len(&self) -> usize {
+        match self {
+            KeyScheme::Ed25519 => 32,
+            KeyScheme::Secp256k1 | KeyScheme::Secp256r1 => 33,
+        }
+    }
+}
+
+/// A single-key public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    pub scheme: KeyScheme,
+    pub bytes: Vec<u8>,
+}
+
+impl PublicKey {
+    /// Sui address: `blake2b256(flag ‖ pubkey)`
+    pub fn address(&self) -> String {
+        let mut hasher = Blake2b256::new();
+        hasher.update([self.scheme.flag()]);
+        hasher.update(&self.bytes);
+        format!("0x{}", hex_encode(&hasher.finalize()))
+    }
+
+    /// Check a 64-byte signature over a personal-message digest
+    ///
+    /// ECDSA schemes sign `sha256(digest)`, which `Verifier` applies.
+    fn verifies(&self, digest: &[u8; 32], signature: &[u8]) -> bool {
+        match self.scheme {
+            KeyScheme::Ed25519 => {
+                let (Ok(key), Ok(sig)) = (
+                    <[u8; 32]>::try_from(self.bytes.as_slice()),
+                    <[u8; 64]>::try_from(signature),
+                ) else {
+                    return false;
+                };
+                ed25519_dalek::VerifyingKey::from_bytes(&key).is_ok_and(|key| {
+                    key.verify(digest, &ed25519_dalek::Signature::from_bytes(&sig))
+                        .is_ok()
+                })
+            }
+            KeyScheme::Secp256k1 => match (
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(&self.bytes),
+                k256::ecdsa::Signature::from_slice(signature),
+            ) {
+                (Ok(key), Ok(sig)) => key.verify(digest, &sig).is_ok(),
+                _ => false,
+            },
+            KeyScheme::Secp256r1 => match (
+                p256::ecdsa::VerifyingKey::from_sec1_bytes(&self.bytes),
+                p256::ecdsa::Signature::from_slice(signature),
+            ) {
+                (Ok(key), Ok(sig)) => key.verify(digest, &sig).is_ok(),
+                _ => false,
+            },
+        }
+    }
+}
+
+// ─── Signatures ──────────────────────────────────────────────────────────────
+
+/// A parsed Sui signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SuiSignature {
+    Single {
+        signature: Vec<u8>,
+        public_key: PublicKey,
+    },
+    MultiSig {
+        /// Member signatures, ordered by member index
+        signatures: Vec<(KeyScheme, Vec<u8>)>,
+        /// Bit `i` set = member `i` signed
+        bitmap: u16,
+        /// Members and their weights
+        members: Vec<(PublicKey, u8)>,
+        threshold: u16,
+    },
+    ZkLogin {
+        inputs: ZkLoginInputs,
+        max_epoch: u64,
+        /// Ephemeral key's signature
+        user_signature: Box<SuiSignature>,
+    },
+}
+
+/// BCS layout of `ZkLoginSignature` after the flag
+#[derive(Deserialize)]
+struct BcsZkLoginSignature {
+    inputs: ZkLoginInputs,
+    max_epoch: u64,
+    user_signature: Vec<u8>,
+}
+
+impl SuiSignature {
+    /// Parse a base64 serialized signature
+    pub fn parse(encoded: &str) -> Result<Self, VerifyError> {
+        let bytes = BASE64
+            .decode(encoded)
+            .map_err(|_| VerifyError::Malformed("signature is not base64".into()))?;
+        Self::from_bytes(&bytes)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, VerifyError> {
+        let (&flag, rest) = bytes
+            .split_first()
+            .ok_or_else(|| VerifyError::Malformed("empty signature".into()))?;
+
+        if let Some(scheme) = KeyScheme::from_flag(flag) {
+            if rest.len() != 64 + scheme.public_key_len() {
+                return Err(VerifyError::Malformed(format!(
+                    "{:?} signature must be {} bytes",
+                    scheme,
+                    65 + scheme.public_key_len()
+                )));
+            }
+            let (signature, public_key) = rest.split_at(64);
+            return Ok(SuiSignature::Single {
+                signature: signature.to_vec(),
+                public_key: PublicKey {
+                    scheme,
+                    bytes: public_key.to_vec(),
+                },
+            });
+        }
+
+        match flag {
+            MULTISIG_FLAG => parse_multisig(rest),
+            ZKLOGIN_FLAG => {
+                let sig: BcsZkLoginSignature = bcs::from_bytes(rest)
+                    .map_err(|e| VerifyError::Malformed(format!("zkLogin signature: {}", e)))?;
+                Ok(SuiSignature::ZkLogin {
+                    inputs: sig.inputs,
+                    max_epoch: sig.max_epoch,
+                    user_signature: Box::new(Self::from_bytes(&sig.user_signature)?),
+                })
+            }
+            other => Err(VerifyError::UnsupportedScheme(other)),
+        }
+    }
+
+    /// Address of the signer
+    pub fn address(&self) -> Result<String, VerifyError> {
+        match self {
+            SuiSignature::Single { public_key, .. } => Ok(public_key.address()),
+            // blake2b256(0x03 ‖ threshold(u16 LE) ‖ [flag ‖ pk ‖ weight]...)
+            SuiSignature::MultiSig {
+                members, threshold, ..
+            } => {
+                let mut hasher = Blake2b256::new();
+                hasher.update([MULTISIG_FLAG]);
+                hasher.update(threshold.to_le_bytes());
+                for (key, weight) in members {
+                    hasher.update([key.scheme.flag()]);
+                    hasher.update(&key.bytes);
+                    hasher.update([*weight]);
+                }
+                Ok(format!("0x{}", hex_encode(&hasher.finalize())))
+            }
+            SuiSignature::ZkLogin { inputs, .. } => Ok(zklogin_address(
+                &inputs.iss_base64_details.iss()?,
+                &inputs.address_seed,
+            )?),
+        }
+    }
+
+    /// Check everything that can be checked without the fullnode
+    fn verify_local(&self, digest: &[u8; 32]) -> Result<(), VerifyError> {
+        match self {
+            SuiSignature::Single {
+                signature,
+                public_key,
+            } => {
+                if !public_key.verifies(digest, signature) {
+                    return Err(VerifyError::InvalidSignature);
+                }
+                Ok(())
+            }
+            SuiSignature::MultiSig {
+                signatures,
+                bitmap,
+                members,
+                threshold,
+            } => {
+                let signers: Vec<usize> = (0..16).filter(|i| bitmap & (1 << i) != 0).collect();
+                if signers.len() != signatures.len() {
+                    return Err(VerifyError::Malformed(
+                        "multisig bitmap doesn't match its signatures".into(),
+                    ));
+                }
+
+                let mut weight = 0u16;
+                for (index, (scheme, signature)) in signers.into_iter().zip(signatures) {
+                    let (key, member_weight) = members.get(index).ok_or_else(|| {
+                        VerifyError::Malformed(format!("no multisig member {}", index))
+                    })?;
+                    if key.scheme != *scheme || !key.verifies(digest, signature) {
+                        return Err(VerifyError::InvalidSignature);
+                    }
+                    weight += *member_weight as u16;
+                }
+                if weight < *threshold {
+                    return Err(VerifyError::ThresholdNotMet {
+                        weight,
+                        threshold: *threshold,
+                    });
+                }
+                Ok(())
+            }
+            SuiSignature::ZkLogin { user_signature, .. } => match **user_signature {
+                SuiSignature::Single { .. } => user_signature.verify_local(digest),
+                _ => Err(VerifyError::Malformed(
+                    "zkLogin ephemeral signature must be single-key".into(),
+                )),
+            },
+        }
+    }
+}
+
+/// Digest a Sui wallet signs for a personal message:
+/// `blake2b256([3, 0, 0] ‖ bcs(message))`
+pub fn personal_message_digest(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Blake2b256::new();
+    hasher.update([3u8, 0, 0]);
+    hasher.update(bcs::to_bytes(message).expect("byte vectors always serialize"));
+    hasher.finalize().into()
+}
+
+/// Verify a personal-message signature by `address` without the fullnode
+///
+/// zkLogin proofs are not checked here; use `SignatureVerifier` to accept
+/// zkLogin signers.
+pub fn verify_personal_message(
+    message: &[u8],
+    signature: &str,
+    address: &str,
+) -> Result<SuiSignature, VerifyError> {
+    let sig = SuiSignature::parse(signature)?;
+
+    let signer = sig.address()?;
+    if normalize_address(&signer) != normalize_address(address) {
+        return Err(VerifyError::AddressMismatch {
+            expected: address.to_string(),
+            actual: signer,
+        });
+    }
+
+    sig.verify_local(&personal_message_digest(message))?;
+    Ok(sig)
+}
+
+// ─── Verifier ────────────────────────────────────────────────────────────────
+
+/// Personal-message verifier that asks the fullnode about zkLogin proofs
+pub struct SignatureVerifier {
+    client: Option<SuiClient>,
+}
+
+impl SignatureVerifier {
+    pub fn new(client: SuiClient) -> Self {
+        Self {
+            client: Some(client),
+        }
+    }
+
+    /// Verifier without a fullnode; rejects zkLogin signatures
+    pub fn offline() -> Self {
+        Self { client: None }
+    }
+
+    /// Verify a personal-message signature by `address`
+    pub async fn verify_personal_message(
+        &self,
+        message: &[u8],
+        signature: &str,
+        address: &str,
+    ) -> Result<SuiSignature, VerifyError> {
+        let sig = verify_personal_message(message, signature, address)?;
+
+        if matches!(sig, SuiSignature::ZkLogin { .. }) {
+            let client = self.client.as_ref().ok_or(VerifyError::ProofUnchecked)?;
+            let result = client
+                .verify_zklogin_signature(&BASE64.encode(message), signature, address)
+                .await?;
+            if !result.success {
+                return Err(VerifyError::ProofRejected(result.errors.join("; ")));
+            }
+        }
+        Ok(sig)
+    }
+}
+
+// ─── BCS ─────────────────────────────────────────────────────────────────────
+
+/// BCS `MultiSig { sigs, bitmap: u16, multisig_pk: { pk_map, threshold: u16 } }`
+///
+/// `CompressedSignature` and `PublicKey` are enums whose variant index is the
+/// scheme flag, followed by fixed-size bytes.
+fn parse_multisig(bytes: &[u8]) -> Result<SuiSignature, VerifyError> {
+    let mut reader = Reader(bytes);
+
+    let mut signatures = Vec::new();
+    for _ in 0..reader.uleb128()? {
+        let scheme = reader.scheme()?;
+        signatures.push((scheme, reader.take(64)?.to_vec()));
+    }
+    let bitmap = u16::from_le_bytes([reader.byte()?, reader.byte()?]);
+
+    let mut members = Vec::new();
+    for _ in 0..reader.uleb128()? {
+        let scheme = reader.scheme()?;
+        let bytes = reader.take(scheme.public_key_len())?.to_vec();
+        members.push((PublicKey { scheme, bytes }, reader.byte()?));
+    }
+    let threshold = u16::from_le_bytes([reader.byte()?, reader.byte()?]);
+
+    if !reader.0.is_empty() {
+        return Err(VerifyError::Malformed(
+            "trailing bytes after multisig".into(),
+        ));
+    }
+    Ok(SuiSignature::MultiSig {
+        signatures,
+        bitmap,
+        members,
+        threshold,
+    })
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], VerifyError> {
+        if self.0.len() < n {
+            return Err(VerifyError::Malformed("truncated multisig".into()));
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8, VerifyError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn uleb128(&mut self) -> Result<u64, VerifyError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(VerifyError::Malformed("invalid uleb128".into()))
+    }
+
+    fn scheme(&mut self) -> Result<KeyScheme, VerifyError> {
+        let variant = self.uleb128()?;
+        u8::try_from(variant)
+            .ok()
+            .and_then(KeyScheme::from_flag)
+            .ok_or(VerifyError::UnsupportedScheme(variant as u8))
+    }
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyError {
+    #[error("Malformed signature: {0}")]
+    Malformed(String),
+
+    #[error("Unsupported signature scheme: {0:#04x}")]
+    UnsupportedScheme(u8),
+
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    #[error("Signature is from {actual}, not {expected}")]
+    AddressMismatch { expected: String, actual: String },
+
+    #[error("Multisig weight {weight} below threshold {threshold}")]
+    ThresholdNotMet { weight: u16, threshold: u16 },
+
+    #[error("zkLogin proofs need a fullnode to verify")]
+    ProofUnchecked,
+
+    #[error("zkLogin proof rejected: {0}")]
+    ProofRejected(String),
+
+    #[error(transparent)]
+    ZkLogin(#[from] ZkLoginError),
+
+    #[error(transparent)]
+    Client(#[from] SuiClientError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Signer as _;
+
+    const MESSAGE: &[u8] = b"Cancel Naisu intent 42";
+
+    fn ed25519(seed: u8) -> (ed25519_dalek::SigningKey, PublicKey) {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+        let public_key = PublicKey {
+            scheme: KeyScheme::Ed25519,
+            bytes: key.verifying_key().to_bytes().to_vec(),
+        };
+        (key, public_key)
+    }
+
+    fn serialize(public_key: &PublicKey, signature: &[u8]) -> String {
+        let mut bytes = vec![public_key.scheme.flag()];
+        bytes.extend_from_slice(signature);
+        bytes.extend_from_slice(&public_key.bytes);
+        BASE64.encode(bytes)
+    }
+
+    #[test]
+    fn test_single_key_signatures() {
+        let digest = personal_message_digest(MESSAGE);
+
+        let (key, public_key) = ed25519(1);
+        let signature = serialize(&public_key, &key.sign(&digest).to_bytes());
+        assert!(verify_personal_message(MESSAGE, &signature, &public_key.address()).is_ok());
+        assert!(matches!(
+            verify_personal_message(b"other", &signature, &public_key.address()),
+            Err(VerifyError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_personal_message(MESSAGE, &signature, &ed25519(2).1.address()),
+            Err(VerifyError::AddressMismatch { .. })
+        ));
+
+        let key = k256::ecdsa::SigningKey::from_slice(&[3u8; 32]).unwrap();
+        let public_key = PublicKey {
+            scheme: KeyScheme::Secp256k1,
+            bytes: key
+                .verifying_key()
+                .to_encoded_point(true)
+                .as_bytes()
+                .to_vec(),
+        };
+        let sig: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(&key, &digest);
+        let signature = serialize(&public_key, &sig.to_bytes());
+        assert!(verify_personal_message(MESSAGE, &signature, &public_key.address()).is_ok());
+    }
+
+    #[test]
+    fn test_multisig_threshold() {
+        let digest = personal_message_digest(MESSAGE);
+        let members: Vec<_> = (1..=3).map(ed25519).collect();
+
+        // 2-of-3, signed by members 0 and 2
+        let multisig = |signers: &[usize]| {
+            let mut bytes = vec![MULTISIG_FLAG, signers.len() as u8];
+            for &i in signers {
+                bytes.push(0);
+                bytes.extend(members[i].0.sign(&digest).to_bytes());
+            }
+            let bitmap = signers.iter().fold(0u16, |acc, i| acc | (1 << i));
+            bytes.extend(bitmap.to_le_bytes());
+            bytes.push(3);
+            for (_, key) in &members {
+                bytes.push(0);
+                bytes.extend(&key.bytes);
+                bytes.push(1);
+            }
+            bytes.extend(2u16.to_le_bytes());
+            BASE64.encode(bytes)
+        };
+
+        let signature = multisig(&[0, 2]);
+        let address = SuiSignature::parse(&signature).unwrap().address().unwrap();
+        assert!(verify_personal_message(MESSAGE, &signature, &address).is_ok());
+        assert!(matches!(
+            verify_personal_message(MESSAGE, &multisig(&[1]), &address),
+            Err(VerifyError::ThresholdNotMet {
+                weight: 1,
+                threshold: 2
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_zklogin_needs_fullnode() {
+        let (key, public_key) = ed25519(4);
+        let digest = personal_message_digest(MESSAGE);
+        let inputs = ZkLoginInputs {
+            proof_points: crate::zklogin::ZkLoginProofPoints {
+                a: vec!["1".into()],
+                b: vec![vec!["2".into()]],
+                c: vec!["3".into()],
+            },
+            iss_base64_details: crate::zklogin::ZkLoginClaim {
+                value: "wiaXNzIjoiaHR0cHM6Ly9hY2NvdW50cy5nb29nbGUuY29tIiw".into(),
+                index_mod4: 2,
+            },
+            header_base64: "eyJhbGciOiJSUzI1NiJ9".into(),
+            address_seed: "12345".into(),
+        };
+        let signature = crate::zklogin::assemble_zklogin_signature(
+            &inputs,
+            10,
+            &serialize(&public_key, &key.sign(&digest).to_bytes()),
+        )
+        .unwrap();
+        let address = zklogin_address("https://accounts.google.com", "12345").unwrap();
+
+        let sig = verify_personal_message(MESSAGE, &signature, &address).unwrap();
+        assert!(matches!(sig, SuiSignature::ZkLogin { max_epoch: 10, .. }));
+        assert!(matches!(
+            SignatureVerifier::offline()
+                .verify_personal_message(MESSAGE, &signature, &address)
+                .await,
+            Err(VerifyError::ProofUnchecked)
+        ));
+    }
+}
@@ -0,0 +1,384 @@
+//! zkLogin support
+//!
+//! zkLogin addresses are derived from the OAuth issuer and an address seed
+//! rather than a public key, and their signatures wrap an ephemeral-key
+//! signature together with a Groth16 proof. The server never sees the JWT or
+//! salt: the frontend (or prover) hands us the proof inputs and the user's
+//! ephemeral signature, and we assemble the final envelope.
+//!
+//! Reference: https://docs.sui.io/concepts/cryptography/zklogin
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use serde::{Deserialize, Serialize};
+
+/// Signature scheme flag for zkLogin
+pub const ZKLOGIN_FLAG: u8 = 0x05;
+
+type Blake2b256 = Blake2b<U32>;
+
+// ─── Types ───────────────────────────────────────────────────────────────────
+
+/// Groth16 proof points, as returned by the zkLogin prover
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZkLoginProofPoints {
+    pub a: Vec<String>,
+    pub b: Vec<Vec<String>>,
+    pub c: Vec<String>,
+}
+
+/// The `iss` claim location inside the JWT payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginClaim {
+    pub value: String,
+    pub index_mod4: u8,
+}
+
+impl ZkLoginClaim {
+    /// Issuer named by the claim
+    ///
+    /// `value` is a slice of the base64url JWT payload covering
+    /// `"iss":"...",`; `index_mod4` is where the slice starts within a
+    /// 4-character group, which says how many bits to drop at each end.
+    pub fn iss(&self) -> Result<String, ZkLoginError> {
+        let invalid = || ZkLoginError::InvalidClaim(self.value.clone());
+
+        let mut bits = Vec::with_capacity(self.value.len() * 6);
+        for c in self.value.bytes() {
+            let sextet = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => return Err(invalid()),
+            };
+            bits.extend((0..6).rev().map(|i| (sextet >> i) & 1));
+        }
+
+        let skip = match self.index_mod4 % 4 {
+            0 => 0,
+            1 => 2,
+            2 => 4,
+            _ => return Err(invalid()),
+        };
+        let trim = match (self.index_mod4 as usize + self.value.len().max(1) - 1) % 4 {
+            3 => 0,
+            2 => 2,
+            1 => 4,
+            _ => return Err(invalid()),
+        };
+        if self.value.len() < 2 || (bits.len() - skip - trim) % 8 != 0 {
+            return Err(invalid());
+        }
+        let bytes: Vec<u8> = bits[skip..bits.len() - trim]
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | bit))
+            .collect();
+
+        // `"iss":"...",` or `"iss":"..."}` as a one-field JSON object
+        let claim = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let claim = claim
+            .strip_suffix(',')
+            .or_else(|| claim.strip_suffix('}'))
+            .ok_or_else(invalid)?;
+        let parsed: serde_json::Value =
+            serde_json::from_str(&format!("{{{}}}", claim)).map_err(|_| invalid())?;
+        parsed["iss"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(invalid)
+    }
+}
+
+/// Proof inputs provided by the client (prover response + address seed)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginInputs {
+    pub proof_points: ZkLoginProofPoints,
+    pub iss_base64_details: ZkLoginClaim,
+    pub header_base64: String,
+    /// Address seed as a decimal string (Poseidon hash of sub/aud/salt)
+    pub address_seed: String,
+}
+
+/// A zkLogin sender, as declared by the client for server-built PTBs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZkLoginSender {
+    /// OAuth issuer, e.g. `https://accounts.google.com`
+    pub iss: String,
+    /// Address seed as a decimal string
+    pub address_seed: String,
+}
+
+impl ZkLoginSender {
+    /// Derive the Sui address for this sender
+    pub fn address(&self) -> Result<String, ZkLoginError> {
+        zklogin_address(&self.iss, &self.address_seed)
+    }
+
+    /// Check that `sender` is the address this zkLogin identity controls
+    pub fn verify_sender(&self, sender: &str) -> Result<(), ZkLoginError> {
+        let derived = self.address()?;
+        if normalize_address(sender) != derived {
+            return Err(ZkLoginError::AddressMismatch {
+                expected: derived,
+                actual: sender.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+// ─── Address derivation ──────────────────────────────────────────────────────
+
+/// Derive a zkLogin address:
+/// `blake2b256(0x05 ‖ len(iss) ‖ iss ‖ address_seed as 32-byte big-endian)`
+pub fn zklogin_address(iss: &str, address_seed: &str) -> Result<String, ZkLoginError> {
+    let iss = normalize_iss(iss);
+    let iss_bytes = iss.as_bytes();
+    if iss_bytes.len() > u8::MAX as usize {
+        return Err(ZkLoginError::InvalidIssuer(iss.to_string()));
+    }
+
+    let seed = decimal_to_be_bytes(address_seed)?;
+
+    let mut hasher = Blake2b256::new();
+    hasher.update([ZKLOGIN_FLAG]);
+    hasher.update([iss_bytes.len() as u8]);
+    hasher.update(iss_bytes);
+    hasher.update(seed);
+
+    Ok(format!("0x{}", hex_encode(&hasher.finalize())))
+}
+
+/// Google tokens may carry `iss` without the scheme; Sui normalizes it
+fn normalize_iss(iss: &str) -> &str {
+    if iss == "accounts.google.com" {
+        "https://accounts.google.com"
+    } else {
+        iss
+    }
+}
+
+/// Convert a decimal string to a 32-byte big-endian integer
+fn decimal_to_be_bytes(value: &str) -> Result<[u8; 32], ZkLoginError> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ZkLoginError::InvalidAddressSeed(value.to_string()));
+    }
+
+    let mut out = [0u8; 32];
+    for digit in value.bytes().map(|b| (b - b'0') as u16) {
+        // out = out * 10 + digit
+        let mut carry = digit;
+        for byte in out.iter_mut().rev() {
+            let v = (*byte as u16) * 10 + carry;
+            *byte = (v & 0xff) as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err(ZkLoginError::InvalidAddressSeed(value.to_string()));
+        }
+    }
+    Ok(out)
+}
+
+// ─── Signature envelope ──────────────────────────────────────────────────────
+
+/// BCS layout of `ZkLoginInputs` on chain (field order matters)
+#[derive(Serialize)]
+struct BcsZkLoginInputs<'a> {
+    proof_points: &'a ZkLoginProofPoints,
+    iss_base64_details: BcsClaim<'a>,
+    header_base64: &'a str,
+    address_seed: &'a str,
+}
+
+#[derive(Serialize)]
+struct BcsClaim<'a> {
+    value: &'a str,
+    index_mod_4: u8,
+}
+
+#[derive(Serialize)]
+struct BcsZkLoginSignature<'a> {
+    inputs: BcsZkLoginInputs<'a>,
+    max_epoch: u64,
+    user_signature: Vec<u8>,
+}
+
+/// Assemble a serialized zkLogin signature ready for `execute_transaction`
+///
+/// * `inputs` - proof inputs from the prover plus the address seed
+/// * `max_epoch` - the max epoch the ephemeral key was registered for
+/// * `user_signature` - base64 ephemeral signature (`flag ‖ sig ‖ pubkey`)
+///
+/// Returns base64 of `0x05 ‖ bcs(ZkLoginSignature)`.
+pub fn assemble_zklogin_signature(
+    inputs: &ZkLoginInputs,
+    max_epoch: u64,
+    user_signature: &str,
+) -> Result<String, ZkLoginError> {
+    let user_signature = BASE64
+        .decode(user_signature)
+        .map_err(|e| ZkLoginError::InvalidSignature(e.to_string()))?;
+    if user_signature.is_empty() {
+        return Err(ZkLoginError::InvalidSignature(
+            "empty ephemeral signature".to_string(),
+        ));
+    }
+
+    let envelope = BcsZkLoginSignature {
+        inputs: BcsZkLoginInputs {
+            proof_points: &inputs.proof_points,
+            iss_base64_details: BcsClaim {
+                value: &inputs.iss_base64_details.value,
+                index_mod_4: inputs.iss_base64_details.index_mod4,
+            },
+            header_base64: &inputs.header_base64,
+            address_seed: &inputs.address_seed,
+        },
+        max_epoch,
+        user_signature,
+    };
+
+    let mut bytes = vec![ZKLOGIN_FLAG];
+    bytes.extend(bcs::to_bytes(&envelope).map_err(|e| ZkLoginError::Bcs(e.to_string()))?);
+
+    Ok(BASE64.encode(bytes))
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Lowercase and left-pad a Sui address to 32 bytes
+pub(crate) fn normalize_address(addr: &str) -> String {
+    let clean = addr.strip_prefix("0x").unwrap_or(addr).to_lowercase();
+    format!("0x{:0>64}", clean)
+}
+
+// ─── Errors ──────────────────────────────────────────────────────────────────
+
+#[derive(Debug, thiserror::Error)]
+pub enum ZkLoginError {
+    #[error("Invalid issuer: {0}")]
+    InvalidIssuer(String),
+
+    #[error("Invalid iss claim: {0}")]
+    InvalidClaim(String),
+
+    #[error("Invalid address seed: {0}")]
+    InvalidAddressSeed(String),
+
+    #[error("Invalid ephemeral signature: {0}")]
+    InvalidSignature(String),
+
+    #[error("zkLogin address mismatch: derived {expected}, got {actual}")]
+    AddressMismatch { expected: String, actual: String },
+
+    #[error("BCS serialization failed: {0}")]
+    Bcs(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_inputs() -> ZkLoginInputs {
+        ZkLoginInputs {
+            proof_points: ZkLoginProofPoints {
+                a: vec!["1".into(), "2".into(), "1".into()],
+                b: vec![
+                    vec!["3".into(), "4".into()],
+                    vec!["5".into(), "6".into()],
+                    vec!["1".into(), "0".into()],
+                ],
+                c: vec!["7".into(), "8".into(), "1".into()],
+            },
+            iss_base64_details: ZkLoginClaim {
+                value: "wiaXNzIjoiaHR0cHM6Ly9hY2NvdW50cy5nb29nbGUuY29tIiw".into(),
+                index_mod4: 1,
+            },
+            header_base64: "eyJhbGciOiJSUzI1NiJ9".into(),
+            address_seed: "12345".into(),
+        }
+    }
+
+    #[test]
+    fn test_decimal_to_be_bytes() {
+        let bytes = decimal_to_be_bytes("258").unwrap();
+        assert_eq!(bytes[30], 1);
+        assert_eq!(bytes[31], 2);
+        assert!(decimal_to_be_bytes("12a").is_err());
+        // 2^256 overflows 32 bytes
+        assert!(decimal_to_be_bytes(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_zklogin_address_shape() {
+        let addr = zklogin_address("https://accounts.google.com", "12345").unwrap();
+        assert!(addr.starts_with("0x"));
+        assert_eq!(addr.len(), 66);
+
+        // Google issuer is normalized before hashing
+        let short = zklogin_address("accounts.google.com", "12345").unwrap();
+        assert_eq!(addr, short);
+
+        let other = zklogin_address("https://accounts.google.com", "12346").unwrap();
+        assert_ne!(addr, other);
+    }
+
+    #[test]
+    fn test_verify_sender() {
+        let sender = ZkLoginSender {
+            iss: "https://accounts.google.com".into(),
+            address_seed: "12345".into(),
+        };
+        let addr = sender.address().unwrap();
+        assert!(sender.verify_sender(&addr).is_ok());
+        assert!(sender
+            .verify_sender(&addr.to_uppercase().replace("0X", "0x"))
+            .is_ok());
+        assert!(sender.verify_sender("0x1").is_err());
+    }
+
+    #[test]
+    fn test_assemble_signature_has_zklogin_flag() {
+        let user_sig = BASE64.encode([0u8; 97]); // flag + 64-byte sig + 32-byte pk
+        let sig = assemble_zklogin_signature(&sample_inputs(), 42, &user_sig).unwrap();
+        let bytes = BASE64.decode(sig).unwrap();
+        assert_eq!(bytes[0], ZKLOGIN_FLAG);
+        // Trailing bytes: uleb128(97) then the user signature
+        assert_eq!(&bytes[bytes.len() - 97..], &[0u8; 97][..]);
+    }
+
+    #[test]
+    fn test_claim_iss() {
+        let claim = ZkLoginClaim {
+            value: "wiaXNzIjoiaHR0cHM6Ly9hY2NvdW50cy5nb29nbGUuY29tIiw".into(),
+            index_mod4: 2,
+        };
+        assert_eq!(claim.iss().unwrap(), "https://accounts.google.com");
+
+        let misaligned = ZkLoginClaim {
+            index_mod4: 1,
+            ..claim
+        };
+        assert!(misaligned.iss().is_err());
+    }
+
+    #[test]
+    fn test_assemble_rejects_bad_signature() {
+        assert!(assemble_zklogin_signature(&sample_inputs(), 42, "not base64!").is_err());
+    }
+}
@@ -0,0 +1,98 @@
+//! Naisu WASM - naisu-core for the web frontend
+//!
+//! wasm-bindgen wrappers over naisu-core's amount parsing, address checks,
+//! intent construction and bid math, so the browser validates inputs with
+//! the same code as the API.
+//!
+//! Build: wasm-pack build naisu-wasm --target web
+//!
+//! Amounts cross the boundary as decimal strings, like `Intent`'s own
+//! amount fields; APYs and tips are basis points.
+
+use naisu_core::{CreateIntentRequest, Intent, Tip, ValidationError};
+use wasm_bindgen::prelude::*;
+
+fn js_error(e: ValidationError) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+fn from_js<T: serde::de::DeserializeOwned>(value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Base-unit amount (e.g., "1500000"), normalized
+#[wasm_bindgen(js_name = parseAmount)]
+pub fn parse_amount(input: &str) -> Result<String, JsError> {
+    naisu_core::parse_amount(input)
+        .map(|a| a.to_string())
+        .map_err(js_error)
+}
+
+/// Decimal amount (e.g., "1.5") in base units of a coin with `decimals`
+#[wasm_bindgen(js_name = parseDecimalAmount)]
+pub fn parse_decimal_amount(input: &str, decimals: u32) -> Result<String, JsError> {
+    naisu_core::parse_decimal_amount(input, decimals)
+        .map(|a| a.to_string())
+        .map_err(js_error)
+}
+
+#[wasm_bindgen(js_name = validateSuiAddress)]
+pub fn validate_sui_address(address: &str) -> Result<(), JsError> {
+    naisu_core::validate_sui_address(address).map_err(js_error)
+}
+
+#[wasm_bindgen(js_name = validateEvmAddress)]
+pub fn validate_evm_address(address: &str) -> Result<(), JsError> {
+    naisu_core::validate_evm_address(address).map_err(js_error)
+}
+
+/// Check a `CreateIntentRequest` before signing it
+#[wasm_bindgen(js_name = validateIntentRequest)]
+pub fn validate_intent_request(request: JsValue) -> Result<(), JsError> {
+    from_js::<CreateIntentRequest>(request)?
+        .validate()
+        .map(|_| ())
+        .map_err(js_error)
+}
+
+/// The `Intent` the API would create from `request` (as a preview)
+#[wasm_bindgen(js_name = buildIntent)]
+pub fn build_intent(id: String, request: JsValue) -> Result<JsValue, JsError> {
+    let intent = Intent::from_request(id, from_js(request)?).map_err(js_error)?;
+    serde_wasm_bindgen::to_value(&intent).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// APY a solver would bid, or `undefined` when the intent isn't profitable
+#[wasm_bindgen(js_name = calculateBid)]
+pub fn calculate_bid(
+    market_apy: u32,
+    user_min: u32,
+    gas_cost_bps: u16,
+    min_profit_bps: u16,
+    tip_bps: u32,
+) -> Option<u32> {
+    naisu_core::calculate_bid(
+        market_apy as u64,
+        user_min as u64,
+        gas_cost_bps,
+        min_profit_bps,
+        tip_bps as u64,
+    )
+    .map(|apy| apy as u32)
+}
+
+/// Tip (`{"bps": n}` or `{"flat": n}`) owed on an intent of `amount`
+#[wasm_bindgen(js_name = tipAmount)]
+pub fn tip_amount(tip: JsValue, amount: &str) -> Result<String, JsError> {
+    let tip: Tip = from_js(tip)?;
+    let amount = naisu_core::parse_amount(amount).map_err(js_error)?;
+    Ok(tip.amount(amount).to_string())
+}
+
+/// Tip as bps of an intent of `amount`
+#[wasm_bindgen(js_name = tipBps)]
+pub fn tip_bps(tip: JsValue, amount: &str) -> Result<u32, JsError> {
+    let tip: Tip = from_js(tip)?;
+    let amount = naisu_core::parse_amount(amount).map_err(js_error)?;
+    Ok(tip.bps(amount) as u32)
+}